@@ -5,12 +5,18 @@
 //! Module for working with multiple calendars at once
 
 use crate::buddhist::Buddhist;
+use crate::chinese::Chinese;
 use crate::coptic::Coptic;
+use crate::dangi::Dangi;
 use crate::ethiopic::{Ethiopic, EthiopicEraStyle};
 use crate::gregorian::Gregorian;
+use crate::hebrew::Hebrew;
 use crate::indian::Indian;
+use crate::islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura};
 use crate::iso::Iso;
 use crate::japanese::{Japanese, JapaneseExtended};
+use crate::persian::Persian;
+use crate::roc::Roc;
 use crate::{
     types, AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError, Ref,
 };
@@ -76,6 +82,7 @@ use core::fmt;
 /// // This is a DateTime<AnyCalendar>
 /// let any_japanese_datetime = japanese_datetime.to_any();
 /// ```
+#[derive(Clone)]
 #[non_exhaustive]
 pub enum AnyCalendar {
     Gregorian(Gregorian),
@@ -85,6 +92,15 @@ pub enum AnyCalendar {
     Ethiopic(Ethiopic),
     Indian(Indian),
     Coptic(Coptic),
+    Hebrew(Hebrew),
+    Persian(Persian),
+    Roc(Roc),
+    Chinese(Chinese),
+    Dangi(Dangi),
+    IslamicCivil(IslamicCivil),
+    IslamicTabular(IslamicTabular),
+    IslamicUmmAlQura(IslamicUmmAlQura),
+    IslamicObservational(IslamicObservational),
     Iso(Iso),
 }
 
@@ -99,6 +115,15 @@ pub enum AnyDateInner {
     Ethiopic(<Ethiopic as Calendar>::DateInner),
     Indian(<Indian as Calendar>::DateInner),
     Coptic(<Coptic as Calendar>::DateInner),
+    Hebrew(<Hebrew as Calendar>::DateInner),
+    Persian(<Persian as Calendar>::DateInner),
+    Roc(<Roc as Calendar>::DateInner),
+    Chinese(<Chinese as Calendar>::DateInner),
+    Dangi(<Dangi as Calendar>::DateInner),
+    IslamicCivil(<IslamicCivil as Calendar>::DateInner),
+    IslamicTabular(<IslamicTabular as Calendar>::DateInner),
+    IslamicUmmAlQura(<IslamicUmmAlQura as Calendar>::DateInner),
+    IslamicObservational(<IslamicObservational as Calendar>::DateInner),
     Iso(<Iso as Calendar>::DateInner),
 }
 
@@ -115,6 +140,27 @@ macro_rules! match_cal_and_date {
             (&Self::Ethiopic(ref $cal_matched), &AnyDateInner::Ethiopic(ref $date_matched)) => $e,
             (&Self::Indian(ref $cal_matched), &AnyDateInner::Indian(ref $date_matched)) => $e,
             (&Self::Coptic(ref $cal_matched), &AnyDateInner::Coptic(ref $date_matched)) => $e,
+            (&Self::Hebrew(ref $cal_matched), &AnyDateInner::Hebrew(ref $date_matched)) => $e,
+            (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
+            (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
+            (&Self::Chinese(ref $cal_matched), &AnyDateInner::Chinese(ref $date_matched)) => $e,
+            (&Self::Dangi(ref $cal_matched), &AnyDateInner::Dangi(ref $date_matched)) => $e,
+            (
+                &Self::IslamicCivil(ref $cal_matched),
+                &AnyDateInner::IslamicCivil(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicTabular(ref $cal_matched),
+                &AnyDateInner::IslamicTabular(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicUmmAlQura(ref $cal_matched),
+                &AnyDateInner::IslamicUmmAlQura(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicObservational(ref $cal_matched),
+                &AnyDateInner::IslamicObservational(ref $date_matched),
+            ) => $e,
             (&Self::Iso(ref $cal_matched), &AnyDateInner::Iso(ref $date_matched)) => $e,
             _ => panic!(
                 "Found AnyCalendar with mixed calendar type {} and date type {}!",
@@ -156,6 +202,31 @@ impl Calendar for AnyCalendar {
             Self::Coptic(ref c) => {
                 AnyDateInner::Coptic(c.date_from_codes(era, year, month_code, day)?)
             }
+            Self::Hebrew(ref c) => {
+                AnyDateInner::Hebrew(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Persian(ref c) => {
+                AnyDateInner::Persian(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_codes(era, year, month_code, day)?),
+            Self::Chinese(ref c) => {
+                AnyDateInner::Chinese(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Dangi(ref c) => {
+                AnyDateInner::Dangi(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicCivil(ref c) => {
+                AnyDateInner::IslamicCivil(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicTabular(ref c) => {
+                AnyDateInner::IslamicTabular(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicUmmAlQura(ref c) => {
+                AnyDateInner::IslamicUmmAlQura(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicObservational(ref c) => {
+                AnyDateInner::IslamicObservational(c.date_from_codes(era, year, month_code, day)?)
+            }
             Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_codes(era, year, month_code, day)?),
         };
         Ok(ret)
@@ -169,6 +240,17 @@ impl Calendar for AnyCalendar {
             Self::Ethiopic(ref c) => AnyDateInner::Ethiopic(c.date_from_iso(iso)),
             Self::Indian(ref c) => AnyDateInner::Indian(c.date_from_iso(iso)),
             Self::Coptic(ref c) => AnyDateInner::Coptic(c.date_from_iso(iso)),
+            Self::Hebrew(ref c) => AnyDateInner::Hebrew(c.date_from_iso(iso)),
+            Self::Persian(ref c) => AnyDateInner::Persian(c.date_from_iso(iso)),
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_iso(iso)),
+            Self::Chinese(ref c) => AnyDateInner::Chinese(c.date_from_iso(iso)),
+            Self::Dangi(ref c) => AnyDateInner::Dangi(c.date_from_iso(iso)),
+            Self::IslamicCivil(ref c) => AnyDateInner::IslamicCivil(c.date_from_iso(iso)),
+            Self::IslamicTabular(ref c) => AnyDateInner::IslamicTabular(c.date_from_iso(iso)),
+            Self::IslamicUmmAlQura(ref c) => AnyDateInner::IslamicUmmAlQura(c.date_from_iso(iso)),
+            Self::IslamicObservational(ref c) => {
+                AnyDateInner::IslamicObservational(c.date_from_iso(iso))
+            }
             Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_iso(iso)),
         }
     }
@@ -212,6 +294,34 @@ impl Calendar for AnyCalendar {
             (&Self::Coptic(ref c), &mut AnyDateInner::Coptic(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (&Self::Hebrew(ref c), &mut AnyDateInner::Hebrew(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Persian(ref c), &mut AnyDateInner::Persian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Roc(ref c), &mut AnyDateInner::Roc(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Chinese(ref c), &mut AnyDateInner::Chinese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Dangi(ref c), &mut AnyDateInner::Dangi(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::IslamicCivil(ref c), &mut AnyDateInner::IslamicCivil(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::IslamicTabular(ref c), &mut AnyDateInner::IslamicTabular(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::IslamicUmmAlQura(ref c), &mut AnyDateInner::IslamicUmmAlQura(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (
+                &Self::IslamicObservational(ref c),
+                &mut AnyDateInner::IslamicObservational(ref mut d),
+            ) => c.offset_date(d, offset.cast_unit()),
             (&Self::Iso(ref c), &mut AnyDateInner::Iso(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
@@ -290,6 +400,78 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                &Self::Hebrew(ref c1),
+                &Self::Hebrew(ref c2),
+                &AnyDateInner::Hebrew(ref d1),
+                &AnyDateInner::Hebrew(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Persian(ref c1),
+                &Self::Persian(ref c2),
+                &AnyDateInner::Persian(ref d1),
+                &AnyDateInner::Persian(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Roc(ref c1),
+                &Self::Roc(ref c2),
+                &AnyDateInner::Roc(ref d1),
+                &AnyDateInner::Roc(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Chinese(ref c1),
+                &Self::Chinese(ref c2),
+                &AnyDateInner::Chinese(ref d1),
+                &AnyDateInner::Chinese(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Dangi(ref c1),
+                &Self::Dangi(ref c2),
+                &AnyDateInner::Dangi(ref d1),
+                &AnyDateInner::Dangi(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicCivil(ref c1),
+                &Self::IslamicCivil(ref c2),
+                &AnyDateInner::IslamicCivil(ref d1),
+                &AnyDateInner::IslamicCivil(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicTabular(ref c1),
+                &Self::IslamicTabular(ref c2),
+                &AnyDateInner::IslamicTabular(ref d1),
+                &AnyDateInner::IslamicTabular(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicUmmAlQura(ref c1),
+                &Self::IslamicUmmAlQura(ref c2),
+                &AnyDateInner::IslamicUmmAlQura(ref d1),
+                &AnyDateInner::IslamicUmmAlQura(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicObservational(ref c1),
+                &Self::IslamicObservational(ref c2),
+                &AnyDateInner::IslamicObservational(ref d1),
+                &AnyDateInner::IslamicObservational(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 &Self::Iso(ref c1),
                 &Self::Iso(ref c2),
@@ -342,6 +524,15 @@ impl Calendar for AnyCalendar {
             Self::Ethiopic(_) => "AnyCalendar (Ethiopic)",
             Self::Indian(_) => "AnyCalendar (Indian)",
             Self::Coptic(_) => "AnyCalendar (Coptic)",
+            Self::Hebrew(_) => "AnyCalendar (Hebrew)",
+            Self::Persian(_) => "AnyCalendar (Persian)",
+            Self::Roc(_) => "AnyCalendar (ROC)",
+            Self::Chinese(_) => "AnyCalendar (Chinese)",
+            Self::Dangi(_) => "AnyCalendar (Dangi)",
+            Self::IslamicCivil(_) => "AnyCalendar (Islamic (civil))",
+            Self::IslamicTabular(_) => "AnyCalendar (Islamic (tabular))",
+            Self::IslamicUmmAlQura(_) => "AnyCalendar (Islamic (Umm al-Qura))",
+            Self::IslamicObservational(_) => "AnyCalendar (Islamic (observational))",
             Self::Iso(_) => "AnyCalendar (Iso)",
         }
     }
@@ -375,6 +566,17 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopic => {
                 AnyCalendar::Ethiopic(Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteMihret))
@@ -411,6 +613,17 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopic => {
                 AnyCalendar::Ethiopic(Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteMihret))
@@ -443,6 +656,17 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopic => {
                 AnyCalendar::Ethiopic(Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteMihret))
@@ -462,6 +686,15 @@ impl AnyCalendar {
             Self::Ethiopic(_) => "Ethiopic",
             Self::Indian(_) => "Indian",
             Self::Coptic(_) => "Coptic",
+            Self::Hebrew(_) => "Hebrew",
+            Self::Persian(_) => "Persian",
+            Self::Roc(_) => "ROC",
+            Self::Chinese(_) => "Chinese",
+            Self::Dangi(_) => "Dangi",
+            Self::IslamicCivil(_) => "Islamic (civil)",
+            Self::IslamicTabular(_) => "Islamic (tabular)",
+            Self::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
+            Self::IslamicObservational(_) => "Islamic (observational)",
             Self::Iso(_) => "Iso",
         }
     }
@@ -478,6 +711,15 @@ impl AnyCalendar {
                 .expect("Ethiopic calendar known to have an AnyCalendarKind"),
             Self::Indian(_) => AnyCalendarKind::Indian,
             Self::Coptic(_) => AnyCalendarKind::Coptic,
+            Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            Self::Persian(_) => AnyCalendarKind::Persian,
+            Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::Chinese(_) => AnyCalendarKind::Chinese,
+            Self::Dangi(_) => AnyCalendarKind::Dangi,
+            Self::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
+            Self::IslamicTabular(_) => AnyCalendarKind::IslamicTabular,
+            Self::IslamicUmmAlQura(_) => AnyCalendarKind::IslamicUmmAlQura,
+            Self::IslamicObservational(_) => AnyCalendarKind::IslamicObservational,
             Self::Iso(_) => AnyCalendarKind::Iso,
         }
     }
@@ -521,6 +763,15 @@ impl AnyDateInner {
             AnyDateInner::Ethiopic(_) => "Ethiopic",
             AnyDateInner::Indian(_) => "Indian",
             AnyDateInner::Coptic(_) => "Coptic",
+            AnyDateInner::Hebrew(_) => "Hebrew",
+            AnyDateInner::Persian(_) => "Persian",
+            AnyDateInner::Roc(_) => "ROC",
+            AnyDateInner::Chinese(_) => "Chinese",
+            AnyDateInner::Dangi(_) => "Dangi",
+            AnyDateInner::IslamicCivil(_) => "Islamic (civil)",
+            AnyDateInner::IslamicTabular(_) => "Islamic (tabular)",
+            AnyDateInner::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
+            AnyDateInner::IslamicObservational(_) => "Islamic (observational)",
             AnyDateInner::Iso(_) => "Iso",
         }
     }
@@ -540,6 +791,24 @@ pub enum AnyCalendarKind {
     Ethiopic,
     /// Ethiopic with Amete Alem era
     Ethioaa,
+    /// Arithmetical Hebrew calendar with leap months
+    Hebrew,
+    /// Arithmetical Persian (Solar Hijri) calendar
+    Persian,
+    /// Republic of China (Minguo) calendar
+    Roc,
+    /// Astronomical Chinese calendar observed in Beijing
+    Chinese,
+    /// Astronomical Korean Dangi calendar observed in Seoul
+    Dangi,
+    /// Tabular Islamic calendar with the civil (Friday) epoch
+    IslamicCivil,
+    /// Tabular Islamic calendar with the astronomical (Thursday) epoch
+    IslamicTabular,
+    /// Islamic calendar as used in Saudi Arabia
+    IslamicUmmAlQura,
+    /// Islamic calendar based on the observed lunar crescent
+    IslamicObservational,
 }
 
 impl AnyCalendarKind {
@@ -554,6 +823,15 @@ impl AnyCalendarKind {
             "iso" => AnyCalendarKind::Iso,
             "ethiopic" => AnyCalendarKind::Ethiopic,
             "ethioaa" => AnyCalendarKind::Ethioaa,
+            "hebrew" => AnyCalendarKind::Hebrew,
+            "persian" => AnyCalendarKind::Persian,
+            "roc" => AnyCalendarKind::Roc,
+            "chinese" => AnyCalendarKind::Chinese,
+            "dangi" => AnyCalendarKind::Dangi,
+            "islamic-civil" => AnyCalendarKind::IslamicCivil,
+            "islamic-tbla" => AnyCalendarKind::IslamicTabular,
+            "islamic-umalqura" => AnyCalendarKind::IslamicUmmAlQura,
+            "islamic" => AnyCalendarKind::IslamicObservational,
             _ => return None,
         })
     }
@@ -577,6 +855,24 @@ impl AnyCalendarKind {
             AnyCalendarKind::Ethiopic
         } else if *x == value!("ethioaa") {
             AnyCalendarKind::Ethioaa
+        } else if *x == value!("hebrew") {
+            AnyCalendarKind::Hebrew
+        } else if *x == value!("persian") {
+            AnyCalendarKind::Persian
+        } else if *x == value!("roc") {
+            AnyCalendarKind::Roc
+        } else if *x == value!("chinese") {
+            AnyCalendarKind::Chinese
+        } else if *x == value!("dangi") {
+            AnyCalendarKind::Dangi
+        } else if *x == value!("islamic") {
+            AnyCalendarKind::IslamicObservational
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "civil")] {
+            AnyCalendarKind::IslamicCivil
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "tbla")] {
+            AnyCalendarKind::IslamicTabular
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "umalqura")] {
+            AnyCalendarKind::IslamicUmmAlQura
         } else {
             let mut string = x.to_string();
             string.truncate(16);
@@ -596,6 +892,15 @@ impl AnyCalendarKind {
             AnyCalendarKind::Iso => "iso",
             AnyCalendarKind::Ethiopic => "ethiopic",
             AnyCalendarKind::Ethioaa => "ethioaa",
+            AnyCalendarKind::Hebrew => "hebrew",
+            AnyCalendarKind::Persian => "persian",
+            AnyCalendarKind::Roc => "roc",
+            AnyCalendarKind::Chinese => "chinese",
+            AnyCalendarKind::Dangi => "dangi",
+            AnyCalendarKind::IslamicCivil => "islamic-civil",
+            AnyCalendarKind::IslamicTabular => "islamic-tbla",
+            AnyCalendarKind::IslamicUmmAlQura => "islamic-umalqura",
+            AnyCalendarKind::IslamicObservational => "islamic",
         }
     }
 
@@ -741,6 +1046,114 @@ impl IncludedInAnyCalendar for Coptic {
     }
 }
 
+impl IncludedInAnyCalendar for Hebrew {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Hebrew(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for Persian {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Persian(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for Roc {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Roc(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for Chinese {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Chinese(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for Dangi {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Dangi(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for IslamicCivil {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicCivil(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for IslamicTabular {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicTabular(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for IslamicUmmAlQura {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicUmmAlQura(*d)
+    }
+}
+
+impl IncludedInAnyCalendar for IslamicObservational {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicObservational(IslamicObservational)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicObservational(IslamicObservational)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicObservational(*d)
+    }
+}
+
 impl IncludedInAnyCalendar for Iso {
     fn to_any(self) -> AnyCalendar {
         AnyCalendar::Iso(Iso)
@@ -833,8 +1246,13 @@ mod tests {
         let buddhist =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Buddhist)
                 .expect("Calendar construction must succeed");
+        let chinese =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Chinese)
+                .expect("Calendar construction must succeed");
         let coptic = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Coptic)
             .expect("Calendar construction must succeed");
+        let dangi = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Dangi)
+            .expect("Calendar construction must succeed");
         let ethiopic =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Ethiopic)
                 .expect("Calendar construction must succeed");
@@ -844,22 +1262,52 @@ mod tests {
         let gregorian =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Gregorian)
                 .expect("Calendar construction must succeed");
+        let hebrew = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Hebrew)
+            .expect("Calendar construction must succeed");
         let indian = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Indian)
             .expect("Calendar construction must succeed");
+        let islamic_civil =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicCivil)
+                .expect("Calendar construction must succeed");
+        let islamic_tabular =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicTabular)
+                .expect("Calendar construction must succeed");
+        let islamic_umm_al_qura =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicUmmAlQura)
+                .expect("Calendar construction must succeed");
+        let islamic_observational = AnyCalendar::try_new_with_buffer_provider(
+            &provider,
+            AnyCalendarKind::IslamicObservational,
+        )
+        .expect("Calendar construction must succeed");
         let japanese =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Japanese)
                 .expect("Calendar construction must succeed");
         let japanext =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::JapaneseExtended)
                 .expect("Calendar construction must succeed");
+        let persian =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Persian)
+                .expect("Calendar construction must succeed");
+        let roc = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Roc)
+            .expect("Calendar construction must succeed");
         let buddhist = Ref(&buddhist);
+        let chinese = Ref(&chinese);
         let coptic = Ref(&coptic);
+        let dangi = Ref(&dangi);
         let ethiopic = Ref(&ethiopic);
         let ethioaa = Ref(&ethioaa);
         let gregorian = Ref(&gregorian);
+        let hebrew = Ref(&hebrew);
         let indian = Ref(&indian);
+        let islamic_civil = Ref(&islamic_civil);
+        let islamic_tabular = Ref(&islamic_tabular);
+        let islamic_umm_al_qura = Ref(&islamic_umm_al_qura);
+        let islamic_observational = Ref(&islamic_observational);
         let japanese = Ref(&japanese);
         let japanext = Ref(&japanext);
+        let persian = Ref(&persian);
+        let roc = Ref(&roc);
 
         single_test_roundtrip(buddhist, "be", 100, "M03", 1);
         single_test_roundtrip(buddhist, "be", 2000, "M03", 1);
@@ -873,6 +1321,42 @@ mod tests {
             DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Buddhist"),
         );
 
+        single_test_roundtrip(chinese, "chinese", 4660, "M02", 30);
+        single_test_roundtrip(chinese, "chinese", 4660, "M02L", 1);
+        single_test_roundtrip(chinese, "chinese", 4661, "M12", 1);
+        single_test_error(
+            chinese,
+            "chinese",
+            4661,
+            "M02L",
+            1,
+            DateTimeError::UnknownMonthCode("M02L".parse().unwrap(), "Chinese"),
+        );
+        single_test_error(
+            chinese,
+            "chinese",
+            4660,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Chinese"),
+        );
+        single_test_error(
+            chinese,
+            "chinese",
+            4660,
+            "M02L",
+            30,
+            DateTimeError::OutOfRange,
+        );
+        single_test_error(
+            chinese,
+            "dangi",
+            4660,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("dangi".parse().unwrap(), "Chinese"),
+        );
+
         single_test_roundtrip(coptic, "ad", 100, "M03", 1);
         single_test_roundtrip(coptic, "ad", 2000, "M03", 1);
         // fails ISO roundtrip
@@ -889,6 +1373,25 @@ mod tests {
         single_test_error(coptic, "ad", 0, "M03", 1, DateTimeError::OutOfRange);
         single_test_error(coptic, "bd", 0, "M03", 1, DateTimeError::OutOfRange);
 
+        single_test_roundtrip(dangi, "dangi", 4356, "M02L", 1);
+        single_test_roundtrip(dangi, "dangi", 4361, "M05L", 29);
+        single_test_error(
+            dangi,
+            "dangi",
+            4357,
+            "M02L",
+            1,
+            DateTimeError::UnknownMonthCode("M02L".parse().unwrap(), "Dangi"),
+        );
+        single_test_error(
+            dangi,
+            "chinese",
+            4356,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("chinese".parse().unwrap(), "Dangi"),
+        );
+
         single_test_roundtrip(ethiopic, "incar", 100, "M03", 1);
         single_test_roundtrip(ethiopic, "incar", 2000, "M03", 1);
         single_test_roundtrip(ethiopic, "incar", 2000, "M13", 1);
@@ -940,6 +1443,28 @@ mod tests {
             DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Gregorian"),
         );
 
+        single_test_roundtrip(hebrew, "am", 5782, "M05L", 1);
+        single_test_roundtrip(hebrew, "am", 5782, "M06", 29);
+        single_test_roundtrip(hebrew, "am", 5783, "M06", 1);
+        single_test_roundtrip(hebrew, "am", 5783, "M12", 29);
+        single_test_error(
+            hebrew,
+            "am",
+            5783,
+            "M05L",
+            1,
+            DateTimeError::UnknownMonthCode("M05L".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(
+            hebrew,
+            "am",
+            5783,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(hebrew, "am", 5783, "M06", 30, DateTimeError::OutOfRange);
+
         single_test_roundtrip(indian, "saka", 100, "M03", 1);
         single_test_roundtrip(indian, "saka", 2000, "M12", 1);
         single_test_roundtrip(indian, "saka", -100, "M03", 1);
@@ -952,6 +1477,62 @@ mod tests {
             1,
             DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Indian"),
         );
+
+        single_test_roundtrip(islamic_civil, "ah", 1444, "M01", 1);
+        single_test_roundtrip(islamic_civil, "ah", 1444, "M09", 29);
+        single_test_roundtrip(islamic_civil, "ah", 1200, "M12", 1);
+        single_test_error(
+            islamic_civil,
+            "ah",
+            1444,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (civil)"),
+        );
+
+        single_test_roundtrip(islamic_tabular, "ah", 1444, "M01", 1);
+        single_test_roundtrip(islamic_tabular, "ah", 1444, "M09", 29);
+        single_test_roundtrip(islamic_tabular, "ah", 1200, "M12", 1);
+        single_test_error(
+            islamic_tabular,
+            "ah",
+            1444,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (tabular)"),
+        );
+
+        single_test_roundtrip(islamic_umm_al_qura, "ah", 1444, "M01", 1);
+        single_test_roundtrip(islamic_umm_al_qura, "ah", 1444, "M09", 29);
+        single_test_roundtrip(islamic_umm_al_qura, "ah", 1200, "M12", 1);
+        single_test_error(
+            islamic_umm_al_qura,
+            "ah",
+            1444,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (Umm al-Qura)"),
+        );
+
+        single_test_roundtrip(islamic_observational, "ah", 1444, "M01", 1);
+        single_test_roundtrip(islamic_observational, "ah", 1444, "M09", 29);
+        single_test_roundtrip(islamic_observational, "ah", 1200, "M12", 1);
+        single_test_error(
+            islamic_observational,
+            "ah",
+            1444,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (observational)"),
+        );
+        single_test_error(
+            islamic_civil,
+            "ad",
+            1444,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("ad".parse().unwrap(), "Islamic (civil)"),
+        );
         single_test_roundtrip(japanese, "reiwa", 3, "M03", 1);
         single_test_roundtrip(japanese, "heisei", 6, "M12", 1);
         single_test_roundtrip(japanese, "meiji", 10, "M03", 1);
@@ -989,5 +1570,102 @@ mod tests {
                 "Japanese (With historical eras)",
             ),
         );
+
+        single_test_roundtrip(persian, "ap", 1402, "M01", 1);
+        single_test_roundtrip(persian, "ap", 1403, "M12", 30);
+        single_test_roundtrip(persian, "ap", -100, "M06", 31);
+        single_test_error(persian, "ap", 1402, "M12", 30, DateTimeError::OutOfRange);
+        single_test_error(persian, "ap", 1402, "M07", 31, DateTimeError::OutOfRange);
+        single_test_error(
+            persian,
+            "ap",
+            1402,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Persian"),
+        );
+        single_test_error(
+            persian,
+            "ah",
+            1402,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("ah".parse().unwrap(), "Persian"),
+        );
+
+        single_test_roundtrip(roc, "roc", 112, "M03", 1);
+        single_test_roundtrip(roc, "roc", 1, "M01", 1);
+        single_test_roundtrip(roc, "roc-inverse", 1, "M12", 31);
+        single_test_roundtrip(roc, "roc-inverse", 100, "M03", 1);
+        single_test_error(roc, "roc", 0, "M03", 1, DateTimeError::OutOfRange);
+        single_test_error(roc, "roc-inverse", 0, "M03", 1, DateTimeError::OutOfRange);
+        single_test_error(
+            roc,
+            "roc",
+            112,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "ROC"),
+        );
+    }
+
+    #[test]
+    fn test_any_until_roundtrip() {
+        let provider = icu_testdata::get_provider();
+        let kinds = [
+            AnyCalendarKind::Buddhist,
+            AnyCalendarKind::Chinese,
+            AnyCalendarKind::Coptic,
+            AnyCalendarKind::Dangi,
+            AnyCalendarKind::Ethiopic,
+            AnyCalendarKind::Ethioaa,
+            AnyCalendarKind::Gregorian,
+            AnyCalendarKind::Hebrew,
+            AnyCalendarKind::Indian,
+            AnyCalendarKind::IslamicCivil,
+            AnyCalendarKind::IslamicTabular,
+            AnyCalendarKind::IslamicUmmAlQura,
+            // IslamicObservational is left out: computing crescent visibility
+            // for every intermediate date makes this test far too slow.
+            AnyCalendarKind::Iso,
+            AnyCalendarKind::Japanese,
+            AnyCalendarKind::JapaneseExtended,
+            AnyCalendarKind::Persian,
+            AnyCalendarKind::Roc,
+        ];
+        let iso_dates = [
+            (1989, 1, 7),
+            (2019, 9, 11),
+            (2020, 2, 29),
+            (2020, 3, 21),
+            (2021, 9, 10),
+            (2023, 8, 31),
+        ];
+        let units = [
+            DateDurationUnit::Years,
+            DateDurationUnit::Months,
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Days,
+        ];
+        for kind in kinds {
+            let calendar = AnyCalendar::try_new_with_buffer_provider(&provider, kind)
+                .expect("Calendar construction must succeed");
+            let calendar = Ref(&calendar);
+            for start in iso_dates {
+                for end in iso_dates {
+                    let start = Date::new_iso_date(start.0, start.1, start.2)
+                        .unwrap()
+                        .to_calendar(calendar);
+                    let end = Date::new_iso_date(end.0, end.1, end.2)
+                        .unwrap()
+                        .to_calendar(calendar);
+                    for unit in units {
+                        let duration = start.until(&end, unit, DateDurationUnit::Days);
+                        let message = format!("{start:?} + {duration:?} ({unit:?})");
+                        assert_eq!(start.clone().added(duration), end, "{message}");
+                    }
+                }
+            }
+        }
     }
 }
@@ -5,6 +5,7 @@
 //! Miscellaneous helper functions.
 pub mod week_of {
     use crate::{error::DateTimeError, types::IsoWeekday};
+    use core::fmt;
 
     pub const MIN_UNIT_DAYS: u16 = 14;
 
@@ -25,6 +26,13 @@ pub mod week_of {
     }
 
     impl CalendarInfo {
+        /// The week rules of ISO 8601: weeks start on Monday, and the first week of a year is the
+        /// first week with at least four days in that year.
+        pub const ISO_8601: Self = Self {
+            first_weekday: IsoWeekday::Monday,
+            min_week_days: 4,
+        };
+
         /// Returns the zero based index of `weekday` vs this calendar's start of week.
         fn weekday_index(&self, weekday: IsoWeekday) -> i8 {
             (7 + (weekday as i8) - (self.first_weekday as i8)) % 7
@@ -124,7 +132,7 @@ pub mod week_of {
     }
 
     /// The year or month that a calendar assigns a week to relative to the year/month that it is in.
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     #[allow(clippy::exhaustive_enums)] // this type is stable
     pub enum RelativeUnit {
         /// A week that is assigned to previous year/month. e.g. 2021-01-01 is week 54 of 2020 per the ISO calendar.
@@ -136,7 +144,7 @@ pub mod week_of {
     }
 
     /// The week number assigned to a given week according to a calendar.
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     #[allow(clippy::exhaustive_structs)] // this type is stable
     pub struct WeekOf {
         /// Week of month/year. 1 based.
@@ -145,6 +153,31 @@ pub mod week_of {
         pub unit: RelativeUnit,
     }
 
+    /// A date in the [ISO 8601 week date](https://en.wikipedia.org/wiki/ISO_week_date) format.
+    ///
+    /// The [`Display`](fmt::Display) implementation writes the date as `YYYY-Www-D`, e.g. `2020-W53-5`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[allow(clippy::exhaustive_structs)] // this type is stable
+    pub struct IsoWeekDate {
+        /// The week-based year, which differs from the ISO year for some days at the start and end of a year.
+        pub year: i32,
+        /// Week of the week-based year. 1 based.
+        pub week: u16,
+        /// The day of the week.
+        pub weekday: IsoWeekday,
+    }
+
+    impl fmt::Display for IsoWeekDate {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if self.year < 0 {
+                write!(f, "-{:04}", -i64::from(self.year))?;
+            } else {
+                write!(f, "{:04}", self.year)?;
+            }
+            write!(f, "-W{:02}-{}", self.week, self.weekday as u8)
+        }
+    }
+
     /// Computes & returns the week of given month/year according to `calendar`.
     ///
     /// # Arguments
@@ -217,7 +250,9 @@ pub mod week_of {
 
     #[cfg(test)]
     mod tests {
-        use super::{week_of, CalendarInfo, RelativeUnit, RelativeWeek, UnitInfo, WeekOf};
+        use super::{
+            week_of, CalendarInfo, IsoWeekDate, RelativeUnit, RelativeWeek, UnitInfo, WeekOf,
+        };
         use crate::{error::DateTimeError, types::IsoWeekday, Date, DateDuration};
 
         static ISO_CALENDAR: CalendarInfo = CalendarInfo {
@@ -455,6 +490,33 @@ pub mod week_of {
 
             Ok(())
         }
+
+        #[test]
+        fn test_iso_week_date() -> Result<(), DateTimeError> {
+            for (year, month, day, expected) in [
+                (2005, 1, 1, "2004-W53-6"),
+                (2007, 1, 1, "2007-W01-1"),
+                (2008, 12, 29, "2009-W01-1"),
+                (2009, 12, 31, "2009-W53-4"),
+                (2010, 1, 3, "2009-W53-7"),
+                (2010, 1, 4, "2010-W01-1"),
+            ] {
+                let date = Date::new_iso_date(year, month, day)?;
+                assert_eq!(date.iso_week_date().to_string(), expected);
+                assert_eq!(
+                    date.week_year(&CalendarInfo::ISO_8601)?.number,
+                    date.iso_week_date().year
+                );
+            }
+
+            let before_common_era = IsoWeekDate {
+                year: -1,
+                week: 1,
+                weekday: IsoWeekday::Monday,
+            };
+            assert_eq!(before_common_era.to_string(), "-0001-W01-1");
+            Ok(())
+        }
     }
 
     #[test]
@@ -0,0 +1,892 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Astronomical calculations for calendars that are based on observations of the sun and moon.
+//!
+//! Moments are represented as fractional "fixed" dates (R.D.), in Universal Time, so that
+//! `floor(moment)` is the fixed date on which the moment falls in Greenwich. The algorithms are from
+//! Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008,
+//! which in turn uses the lunar and solar theories from Meeus, Jean. _Astronomical Algorithms_. Willmann-Bell, 1998.
+//!
+//! Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+
+use crate::iso::Iso;
+use core::f64::consts::PI;
+
+/// The mean time between two new moons, in days
+pub(crate) const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// The mean time between two vernal equinoxes, in days
+pub(crate) const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// Noon on January 1st, 2000 (Gregorian), the epoch of the astronomical formulae
+const J2000: f64 = 730120.5;
+
+/// A location on Earth, used for calculations that depend on the observer
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Location {
+    /// Latitude in degrees, positive to the north
+    pub(crate) latitude: f64,
+    /// Longitude in degrees, positive to the east
+    pub(crate) longitude: f64,
+    /// The offset of local standard time from Universal Time, in days
+    pub(crate) zone: f64,
+}
+
+fn sin_degrees(x: f64) -> f64 {
+    libm::sin(x * PI / 180.0)
+}
+
+fn cos_degrees(x: f64) -> f64 {
+    libm::cos(x * PI / 180.0)
+}
+
+fn tan_degrees(x: f64) -> f64 {
+    libm::tan(x * PI / 180.0)
+}
+
+fn arcsin_degrees(x: f64) -> f64 {
+    libm::asin(x) * 180.0 / PI
+}
+
+fn arccos_degrees(x: f64) -> f64 {
+    libm::acos(x) * 180.0 / PI
+}
+
+fn arctan_degrees(y: f64, x: f64) -> f64 {
+    rem_degrees(libm::atan2(y, x) * 180.0 / PI)
+}
+
+/// Reduces an angle to the range `[0, 360)`
+fn rem_degrees(x: f64) -> f64 {
+    x - 360.0 * libm::floor(x / 360.0)
+}
+
+/// Evaluates the polynomial with the given coefficients, starting at the constant term
+fn poly(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// The fixed date on which `moment` falls
+pub(crate) fn fixed_from_moment(moment: f64) -> i32 {
+    libm::floor(moment) as i32
+}
+
+// Lisp code reference: `ephemeris-correction`
+//
+// The difference between Dynamical Time and Universal Time, in days
+fn ephemeris_correction(moment: f64) -> f64 {
+    let year = Iso::iso_year_from_fixed(fixed_from_moment(moment));
+    let y = year as f64;
+    if (2051..=2150).contains(&year) {
+        (-20.0 + 32.0 * ((y - 1820.0) / 100.0) * ((y - 1820.0) / 100.0) + 0.5628 * (2150.0 - y))
+            / 86400.0
+    } else if (2006..=2050).contains(&year) {
+        poly(y - 2000.0, &[62.92, 0.32217, 0.005589]) / 86400.0
+    } else if (1987..=2005).contains(&year) {
+        poly(
+            y - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ) / 86400.0
+    } else if (1800..=1986).contains(&year) {
+        // Julian centuries from 1900-01-01 to July 1st of the year
+        let c = (Iso::fixed_from_iso_integers(year, 7, 1)
+            - Iso::fixed_from_iso_integers(1900, 1, 1)) as f64
+            / 36525.0;
+        if year >= 1900 {
+            poly(
+                c,
+                &[
+                    -0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066,
+                    -0.212591,
+                ],
+            )
+        } else {
+            poly(
+                c,
+                &[
+                    -0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535, 31.332267,
+                    38.291999, 28.316289, 11.636204, 2.043794,
+                ],
+            )
+        }
+    } else if (1700..=1799).contains(&year) {
+        poly(
+            y - 1700.0,
+            &[8.118780842, -0.005092142, 0.003336121, -0.0000266484],
+        ) / 86400.0
+    } else if (1600..=1699).contains(&year) {
+        poly(y - 1600.0, &[120.0, -0.9808, -0.01532, 0.000140272128]) / 86400.0
+    } else if (500..=1599).contains(&year) {
+        poly(
+            (y - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ) / 86400.0
+    } else if (-499..=499).contains(&year) {
+        poly(
+            y / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ) / 86400.0
+    } else {
+        poly((y - 1820.0) / 100.0, &[-20.0, 0.0, 32.0]) / 86400.0
+    }
+}
+
+fn dynamical_from_universal(moment: f64) -> f64 {
+    moment + ephemeris_correction(moment)
+}
+
+fn universal_from_dynamical(moment: f64) -> f64 {
+    moment - ephemeris_correction(moment)
+}
+
+fn julian_centuries(moment: f64) -> f64 {
+    (dynamical_from_universal(moment) - J2000) / 36525.0
+}
+
+// Lisp code reference: `obliquity`
+fn obliquity(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    23.0 + 26.0 / 60.0 + 21.448 / 3600.0 + poly(c, &[0.0, -46.8150, -0.00059, 0.001813]) / 3600.0
+}
+
+// Lisp code reference: `nutation`
+fn nutation(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let a = poly(c, &[124.90, -1934.134, 0.002063]);
+    let b = poly(c, &[201.11, 72001.5377, 0.00057]);
+    -0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b)
+}
+
+// Lisp code reference: `aberration`
+fn aberration(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    0.0000974 * cos_degrees(177.63 + 35999.01848 * c) - 0.005575
+}
+
+/// The periodic terms of the solar longitude, as (coefficient, addend, multiplier)
+const SOLAR_LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+    (403406.0, 270.54861, 0.9287892),
+    (195207.0, 340.19128, 35999.1376958),
+    (119433.0, 63.91854, 35999.4089666),
+    (112392.0, 331.26220, 35998.7287385),
+    (3891.0, 317.843, 71998.20261),
+    (2819.0, 86.631, 71998.4403),
+    (1721.0, 240.052, 36000.35726),
+    (660.0, 310.26, 71997.4812),
+    (350.0, 247.23, 32964.4678),
+    (334.0, 260.87, -19.4410),
+    (314.0, 297.82, 445267.1117),
+    (268.0, 343.14, 45036.8840),
+    (242.0, 166.79, 3.1008),
+    (234.0, 81.53, 22518.4434),
+    (158.0, 3.50, -19.9739),
+    (132.0, 132.75, 65928.9345),
+    (129.0, 182.95, 9038.0293),
+    (114.0, 162.03, 3034.7684),
+    (99.0, 29.8, 33718.148),
+    (93.0, 266.4, 3034.448),
+    (86.0, 249.2, -2280.773),
+    (78.0, 157.6, 29929.992),
+    (72.0, 257.8, 31556.493),
+    (68.0, 185.1, 149.588),
+    (64.0, 69.9, 9037.750),
+    (46.0, 8.0, 107997.405),
+    (38.0, 197.1, -4444.176),
+    (37.0, 250.4, 151.771),
+    (32.0, 65.3, 67555.316),
+    (29.0, 162.7, 31556.080),
+    (28.0, 341.5, -4561.540),
+    (27.0, 291.6, 107996.706),
+    (27.0, 98.5, 1221.655),
+    (25.0, 146.7, 62894.167),
+    (24.0, 110.0, 31437.369),
+    (21.0, 5.2, 14578.298),
+    (21.0, 342.6, -31931.757),
+    (20.0, 230.9, 34777.243),
+    (18.0, 256.1, 1221.999),
+    (17.0, 45.3, 62894.511),
+    (14.0, 242.9, -4442.039),
+    (13.0, 115.2, 107997.909),
+    (13.0, 151.8, 119.066),
+    (13.0, 285.3, 16859.071),
+    (12.0, 53.3, -4.578),
+    (10.0, 126.6, 26895.292),
+    (10.0, 205.7, -39.127),
+    (10.0, 85.9, 12297.536),
+    (10.0, 146.1, 90073.778),
+];
+
+// Lisp code reference: `solar-longitude`
+//
+// The longitude of the sun at `moment`, in degrees
+pub(crate) fn solar_longitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let sum: f64 = SOLAR_LONGITUDE_TERMS
+        .iter()
+        .map(|&(x, y, z)| x * sin_degrees(y + z * c))
+        .sum();
+    let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+    rem_degrees(lambda + aberration(moment) + nutation(moment))
+}
+
+// Lisp code reference: `estimate-prior-solar-longitude`
+//
+// An approximation (within a day) of the last moment before `moment` at which the solar
+// longitude was `lambda` degrees
+pub(crate) fn estimate_prior_solar_longitude(lambda: f64, moment: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let tau = moment - rate * rem_degrees(solar_longitude(moment) - lambda);
+    let delta = rem_degrees(solar_longitude(tau) - lambda + 180.0) - 180.0;
+    libm::fmin(moment, tau - rate * delta)
+}
+
+// Lisp code reference: `equation-of-time`
+//
+// The difference between apparent and mean solar time, in days
+fn equation_of_time(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let lambda = poly(c, &[280.46645, 36000.76983, 0.0003032]);
+    let anomaly = poly(c, &[357.52910, 35999.05030, -0.0001559, -0.00000048]);
+    let eccentricity = poly(c, &[0.016708617, -0.000042037, -0.0000001236]);
+    let y = tan_degrees(obliquity(moment) / 2.0) * tan_degrees(obliquity(moment) / 2.0);
+    let equation = (y * sin_degrees(2.0 * lambda) - 2.0 * eccentricity * sin_degrees(anomaly)
+        + 4.0 * eccentricity * y * sin_degrees(anomaly) * cos_degrees(2.0 * lambda)
+        - 0.5 * y * y * sin_degrees(4.0 * lambda)
+        - 1.25 * eccentricity * eccentricity * sin_degrees(2.0 * anomaly))
+        / (2.0 * PI);
+    // The equation of time is never more than half an hour
+    libm::fmin(libm::fmax(equation, -1.0 / 48.0), 1.0 / 48.0)
+}
+
+fn universal_from_local(moment: f64, location: Location) -> f64 {
+    moment - location.longitude / 360.0
+}
+
+pub(crate) fn standard_from_universal(moment: f64, location: Location) -> f64 {
+    moment + location.zone
+}
+
+pub(crate) fn universal_from_standard(moment: f64, location: Location) -> f64 {
+    moment - location.zone
+}
+
+fn local_from_apparent(moment: f64, location: Location) -> f64 {
+    moment - equation_of_time(universal_from_local(moment, location))
+}
+
+// Lisp code reference: `declination`
+fn declination(moment: f64, beta: f64, lambda: f64) -> f64 {
+    let epsilon = obliquity(moment);
+    arcsin_degrees(
+        sin_degrees(beta) * cos_degrees(epsilon)
+            + cos_degrees(beta) * sin_degrees(epsilon) * sin_degrees(lambda),
+    )
+}
+
+// Lisp code reference: `right-ascension`
+fn right_ascension(moment: f64, beta: f64, lambda: f64) -> f64 {
+    let epsilon = obliquity(moment);
+    arctan_degrees(
+        sin_degrees(lambda) * cos_degrees(epsilon) - tan_degrees(beta) * sin_degrees(epsilon),
+        cos_degrees(lambda),
+    )
+}
+
+// Lisp code reference: `sidereal-from-moment`
+fn sidereal_from_moment(moment: f64) -> f64 {
+    let c = (moment - J2000) / 36525.0;
+    rem_degrees(poly(
+        c,
+        &[
+            280.46061837,
+            36525.0 * 360.98564736629,
+            0.000387933,
+            -1.0 / 38710000.0,
+        ],
+    ))
+}
+
+// Lisp code reference: `mean-lunar-longitude`
+fn mean_lunar_longitude(c: f64) -> f64 {
+    rem_degrees(poly(
+        c,
+        &[
+            218.3164477,
+            481267.88123421,
+            -0.0015786,
+            1.0 / 538841.0,
+            -1.0 / 65194000.0,
+        ],
+    ))
+}
+
+// Lisp code reference: `lunar-elongation`
+fn lunar_elongation(c: f64) -> f64 {
+    rem_degrees(poly(
+        c,
+        &[
+            297.8501921,
+            445267.1114034,
+            -0.0018819,
+            1.0 / 545868.0,
+            -1.0 / 113065000.0,
+        ],
+    ))
+}
+
+// Lisp code reference: `solar-anomaly`
+fn solar_anomaly(c: f64) -> f64 {
+    rem_degrees(poly(
+        c,
+        &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0],
+    ))
+}
+
+// Lisp code reference: `lunar-anomaly`
+fn lunar_anomaly(c: f64) -> f64 {
+    rem_degrees(poly(
+        c,
+        &[
+            134.9633964,
+            477198.8675055,
+            0.0087414,
+            1.0 / 69699.0,
+            -1.0 / 14712000.0,
+        ],
+    ))
+}
+
+// Lisp code reference: `moon-node`
+fn moon_node(c: f64) -> f64 {
+    rem_degrees(poly(
+        c,
+        &[
+            93.2720950,
+            483202.0175233,
+            -0.0036539,
+            -1.0 / 3526000.0,
+            1.0 / 863310000.0,
+        ],
+    ))
+}
+
+/// The periodic terms of the lunar longitude, as (coefficient, multiples of the lunar elongation,
+/// solar anomaly, lunar anomaly and moon node)
+const LUNAR_LONGITUDE_TERMS: [(f64, i8, i8, i8, i8); 59] = [
+    (6288774.0, 0, 0, 1, 0),
+    (1274027.0, 2, 0, -1, 0),
+    (658314.0, 2, 0, 0, 0),
+    (213618.0, 0, 0, 2, 0),
+    (-185116.0, 0, 1, 0, 0),
+    (-114332.0, 0, 0, 0, 2),
+    (58793.0, 2, 0, -2, 0),
+    (57066.0, 2, -1, -1, 0),
+    (53322.0, 2, 0, 1, 0),
+    (45758.0, 2, -1, 0, 0),
+    (-40923.0, 0, 1, -1, 0),
+    (-34720.0, 1, 0, 0, 0),
+    (-30383.0, 0, 1, 1, 0),
+    (15327.0, 2, 0, 0, -2),
+    (-12528.0, 0, 0, 1, 2),
+    (10980.0, 0, 0, 1, -2),
+    (10675.0, 4, 0, -1, 0),
+    (10034.0, 0, 0, 3, 0),
+    (8548.0, 4, 0, -2, 0),
+    (-7888.0, 2, 1, -1, 0),
+    (-6766.0, 2, 1, 0, 0),
+    (-5163.0, 1, 0, -1, 0),
+    (4987.0, 1, 1, 0, 0),
+    (4036.0, 2, -1, 1, 0),
+    (3994.0, 2, 0, 2, 0),
+    (3861.0, 4, 0, 0, 0),
+    (3665.0, 2, 0, -3, 0),
+    (-2689.0, 0, 1, -2, 0),
+    (-2602.0, 2, 0, -1, 2),
+    (2390.0, 2, -1, -2, 0),
+    (-2348.0, 1, 0, 1, 0),
+    (2236.0, 2, -2, 0, 0),
+    (-2120.0, 0, 1, 2, 0),
+    (-2069.0, 0, 2, 0, 0),
+    (2048.0, 2, -2, -1, 0),
+    (-1773.0, 2, 0, 1, -2),
+    (-1595.0, 2, 0, 0, 2),
+    (1215.0, 4, -1, -1, 0),
+    (-1110.0, 0, 0, 2, 2),
+    (-892.0, 3, 0, -1, 0),
+    (-810.0, 2, 1, 1, 0),
+    (759.0, 4, -1, -2, 0),
+    (-713.0, 0, 2, -1, 0),
+    (-700.0, 2, 2, -1, 0),
+    (691.0, 2, 1, -2, 0),
+    (596.0, 2, -1, 0, -2),
+    (549.0, 4, 0, 1, 0),
+    (537.0, 0, 0, 4, 0),
+    (520.0, 4, -1, 0, 0),
+    (-487.0, 1, 0, -2, 0),
+    (-399.0, 2, 1, 0, -2),
+    (-381.0, 0, 0, 2, -2),
+    (351.0, 1, 1, 1, 0),
+    (-340.0, 3, 0, -2, 0),
+    (330.0, 4, 0, -3, 0),
+    (327.0, 2, -1, 2, 0),
+    (-323.0, 0, 2, 1, 0),
+    (299.0, 1, 1, -1, 0),
+    (294.0, 2, 0, 3, 0),
+];
+
+/// The periodic terms of the lunar latitude, in the same format as [`LUNAR_LONGITUDE_TERMS`]
+const LUNAR_LATITUDE_TERMS: [(f64, i8, i8, i8, i8); 60] = [
+    (5128122.0, 0, 0, 0, 1),
+    (280602.0, 0, 0, 1, 1),
+    (277693.0, 0, 0, 1, -1),
+    (173237.0, 2, 0, 0, -1),
+    (55413.0, 2, 0, -1, 1),
+    (46271.0, 2, 0, -1, -1),
+    (32573.0, 2, 0, 0, 1),
+    (17198.0, 0, 0, 2, 1),
+    (9266.0, 2, 0, 1, -1),
+    (8822.0, 0, 0, 2, -1),
+    (8216.0, 2, -1, 0, -1),
+    (4324.0, 2, 0, -2, -1),
+    (4200.0, 2, 0, 1, 1),
+    (-3359.0, 2, 1, 0, -1),
+    (2463.0, 2, -1, -1, 1),
+    (2211.0, 2, -1, 0, 1),
+    (2065.0, 2, -1, -1, -1),
+    (-1870.0, 0, 1, -1, -1),
+    (1828.0, 4, 0, -1, -1),
+    (-1794.0, 0, 1, 0, 1),
+    (-1749.0, 0, 0, 0, 3),
+    (-1565.0, 0, 1, -1, 1),
+    (-1491.0, 1, 0, 0, 1),
+    (-1475.0, 0, 1, 1, 1),
+    (-1410.0, 0, 1, 1, -1),
+    (-1344.0, 0, 1, 0, -1),
+    (-1335.0, 1, 0, 0, -1),
+    (1107.0, 0, 0, 3, 1),
+    (1021.0, 4, 0, 0, -1),
+    (833.0, 4, 0, -1, 1),
+    (777.0, 0, 0, 1, -3),
+    (671.0, 4, 0, -2, 1),
+    (607.0, 2, 0, 0, -3),
+    (596.0, 2, 0, 2, -1),
+    (491.0, 2, -1, 1, -1),
+    (-451.0, 2, 0, -2, 1),
+    (439.0, 0, 0, 3, -1),
+    (422.0, 2, 0, 2, 1),
+    (421.0, 2, 0, -3, -1),
+    (-366.0, 2, 1, -1, 1),
+    (-351.0, 2, 1, 0, 1),
+    (331.0, 4, 0, 0, 1),
+    (315.0, 2, -1, 1, 1),
+    (302.0, 2, -2, 0, -1),
+    (-283.0, 0, 0, 1, 3),
+    (-229.0, 2, 1, 1, -1),
+    (223.0, 1, 1, 0, -1),
+    (223.0, 1, 1, 0, 1),
+    (-220.0, 0, 1, -2, -1),
+    (-220.0, 2, 1, -1, -1),
+    (-185.0, 1, 0, 1, 1),
+    (181.0, 2, -1, -2, -1),
+    (-177.0, 0, 1, 2, 1),
+    (176.0, 4, 0, -2, -1),
+    (166.0, 4, -1, -1, -1),
+    (-164.0, 1, 0, 1, -1),
+    (132.0, 4, 0, 1, -1),
+    (-119.0, 1, 0, -1, -1),
+    (115.0, 4, -1, 0, -1),
+    (107.0, 2, -2, 0, 1),
+];
+
+/// Sums periodic lunar terms, scaling the terms that depend on the solar anomaly
+/// by the decreasing eccentricity of the Earth's orbit
+fn sum_lunar_terms(terms: &[(f64, i8, i8, i8, i8)], c: f64) -> f64 {
+    let elongation = lunar_elongation(c);
+    let solar_anomaly = solar_anomaly(c);
+    let lunar_anomaly = lunar_anomaly(c);
+    let moon_node = moon_node(c);
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    terms
+        .iter()
+        .map(|&(v, w, x, y, z)| {
+            let e_factor = match x {
+                0 => 1.0,
+                1 | -1 => e,
+                _ => e * e,
+            };
+            v * e_factor
+                * sin_degrees(
+                    w as f64 * elongation
+                        + x as f64 * solar_anomaly
+                        + y as f64 * lunar_anomaly
+                        + z as f64 * moon_node,
+                )
+        })
+        .sum()
+}
+
+// Lisp code reference: `lunar-longitude`
+//
+// The longitude of the moon at `moment`, in degrees
+pub(crate) fn lunar_longitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let mean_longitude = mean_lunar_longitude(c);
+    let correction = sum_lunar_terms(&LUNAR_LONGITUDE_TERMS, c) / 1000000.0;
+    let venus = 3958.0 / 1000000.0 * sin_degrees(119.75 + 131.849 * c);
+    let jupiter = 318.0 / 1000000.0 * sin_degrees(53.09 + 479264.29 * c);
+    let flat_earth = 1962.0 / 1000000.0 * sin_degrees(mean_longitude - moon_node(c));
+    rem_degrees(mean_longitude + correction + venus + jupiter + flat_earth + nutation(moment))
+}
+
+// Lisp code reference: `lunar-latitude`
+//
+// The latitude of the moon at `moment`, in degrees
+fn lunar_latitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let mean_longitude = mean_lunar_longitude(c);
+    let lunar_anomaly = lunar_anomaly(c);
+    let moon_node = moon_node(c);
+    let latitude = sum_lunar_terms(&LUNAR_LATITUDE_TERMS, c) / 1000000.0;
+    let venus = (175.0 / 1000000.0)
+        * (sin_degrees(119.75 + 131.849 * c + moon_node)
+            + sin_degrees(119.75 + 131.849 * c - moon_node));
+    let flat_earth = (-2235.0 / 1000000.0) * sin_degrees(mean_longitude)
+        + (127.0 / 1000000.0) * sin_degrees(mean_longitude - lunar_anomaly)
+        + (-115.0 / 1000000.0) * sin_degrees(mean_longitude + lunar_anomaly);
+    let extra = (382.0 / 1000000.0) * sin_degrees(313.45 + 481266.484 * c);
+    latitude + venus + flat_earth + extra
+}
+
+// Lisp code reference: `lunar-altitude`
+//
+// The geocentric altitude of the moon above the horizon at `location`, in degrees
+fn lunar_altitude(moment: f64, location: Location) -> f64 {
+    let lambda = lunar_longitude(moment);
+    let beta = lunar_latitude(moment);
+    let alpha = right_ascension(moment, beta, lambda);
+    let delta = declination(moment, beta, lambda);
+    let hour_angle = rem_degrees(sidereal_from_moment(moment) + location.longitude - alpha);
+    arcsin_degrees(
+        sin_degrees(location.latitude) * sin_degrees(delta)
+            + cos_degrees(location.latitude) * cos_degrees(delta) * cos_degrees(hour_angle),
+    )
+}
+
+// Lisp code reference: `nth-new-moon`
+//
+// The moment of the `n`-th new moon after (or before) the new moon of January 11th, 1 CE
+pub(crate) fn nth_new_moon(n: i32) -> f64 {
+    // The number of new moons from R.D. 0 to the one of January 6th, 2000
+    let k = (n - 24724) as f64;
+    let c = k / 1236.85;
+    let approx = J2000
+        + poly(
+            c,
+            &[
+                5.09766,
+                MEAN_SYNODIC_MONTH * 1236.85,
+                0.00015437,
+                -0.000000150,
+                0.00000000073,
+            ],
+        );
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let solar_anomaly = poly(c, &[2.5534, 1236.85 * 29.10535670, -0.0000014, -0.00000011]);
+    let lunar_anomaly = poly(
+        c,
+        &[
+            201.5643,
+            385.81693528 * 1236.85,
+            0.0107582,
+            0.00001238,
+            -0.000000058,
+        ],
+    );
+    let moon_argument = poly(
+        c,
+        &[
+            160.7108,
+            390.67050284 * 1236.85,
+            -0.0016118,
+            -0.00000227,
+            0.000000011,
+        ],
+    );
+    let omega = poly(c, &[124.7746, -1.56375588 * 1236.85, 0.0020672, 0.00000215]);
+    // (coefficient, power of the eccentricity, multiples of the solar anomaly,
+    // lunar anomaly and moon argument)
+    let terms: [(f64, i32, f64, f64, f64); 24] = [
+        (-0.40720, 0, 0.0, 1.0, 0.0),
+        (0.17241, 1, 1.0, 0.0, 0.0),
+        (0.01608, 0, 0.0, 2.0, 0.0),
+        (0.01039, 0, 0.0, 0.0, 2.0),
+        (0.00739, 1, -1.0, 1.0, 0.0),
+        (-0.00514, 1, 1.0, 1.0, 0.0),
+        (0.00208, 2, 2.0, 0.0, 0.0),
+        (-0.00111, 0, 0.0, 1.0, -2.0),
+        (-0.00057, 0, 0.0, 1.0, 2.0),
+        (0.00056, 1, 1.0, 2.0, 0.0),
+        (-0.00042, 0, 0.0, 3.0, 0.0),
+        (0.00042, 1, 1.0, 0.0, 2.0),
+        (0.00038, 1, 1.0, 0.0, -2.0),
+        (-0.00024, 1, -1.0, 2.0, 0.0),
+        (-0.00007, 0, 2.0, 1.0, 0.0),
+        (0.00004, 0, 0.0, 2.0, -2.0),
+        (0.00004, 0, 3.0, 0.0, 0.0),
+        (0.00003, 0, 1.0, 1.0, -2.0),
+        (0.00003, 0, 0.0, 2.0, 2.0),
+        (-0.00003, 0, 1.0, 1.0, 2.0),
+        (0.00003, 0, -1.0, 1.0, 2.0),
+        (-0.00002, 0, -1.0, 1.0, -2.0),
+        (-0.00002, 0, 1.0, 3.0, 0.0),
+        (0.00002, 0, 0.0, 4.0, 0.0),
+    ];
+    let correction = -0.00017 * sin_degrees(omega)
+        + terms
+            .iter()
+            .map(|&(v, w, x, y, z)| {
+                v * libm::pow(e, w as f64)
+                    * sin_degrees(x * solar_anomaly + y * lunar_anomaly + z * moon_argument)
+            })
+            .sum::<f64>();
+    let extra = 0.000325 * sin_degrees(poly(c, &[299.77, 132.8475848, -0.009173]));
+    // (constant, coefficient, amplitude) of the planetary arguments
+    let planetary: [(f64, f64, f64); 13] = [
+        (251.88, 0.016321, 0.000165),
+        (251.83, 26.651886, 0.000164),
+        (349.42, 36.412478, 0.000126),
+        (84.66, 18.206239, 0.000110),
+        (141.74, 53.303771, 0.000062),
+        (207.14, 2.453732, 0.000060),
+        (154.84, 7.306860, 0.000056),
+        (34.52, 27.261239, 0.000047),
+        (207.19, 0.121824, 0.000042),
+        (291.34, 1.844379, 0.000040),
+        (161.72, 24.198154, 0.000037),
+        (239.56, 25.513099, 0.000035),
+        (331.55, 3.592518, 0.000023),
+    ];
+    let additional: f64 = planetary
+        .iter()
+        .map(|&(i, j, l)| l * sin_degrees(i + j * k))
+        .sum();
+    universal_from_dynamical(approx + correction + extra + additional)
+}
+
+// Lisp code reference: `lunar-phase`
+//
+// The difference between the lunar and solar longitudes at `moment`, in degrees:
+// 0 is a new moon, 90 the first quarter, and so on
+pub(crate) fn lunar_phase(moment: f64) -> f64 {
+    let phi = rem_degrees(lunar_longitude(moment) - solar_longitude(moment));
+    let n = libm::round((moment - nth_new_moon(0)) / MEAN_SYNODIC_MONTH) as i32;
+    // Near the new moon the longitudes are not precise enough, so use the new moon instead
+    let phi_prime = 360.0 * rem_euclid((moment - nth_new_moon(n)) / MEAN_SYNODIC_MONTH, 1.0);
+    if libm::fabs(phi - phi_prime) > 180.0 {
+        phi_prime
+    } else {
+        phi
+    }
+}
+
+fn rem_euclid(x: f64, y: f64) -> f64 {
+    x - y * libm::floor(x / y)
+}
+
+// Lisp code reference: `new-moon-before`
+//
+// The moment of the last new moon before `moment`
+pub(crate) fn new_moon_before(moment: f64) -> f64 {
+    let n =
+        libm::round((moment - nth_new_moon(0)) / MEAN_SYNODIC_MONTH - lunar_phase(moment) / 360.0)
+            as i32;
+    let mut k = n - 1;
+    while nth_new_moon(k + 1) < moment {
+        k += 1;
+    }
+    nth_new_moon(k)
+}
+
+// Lisp code reference: `new-moon-at-or-after`
+//
+// The moment of the first new moon at or after `moment`
+pub(crate) fn new_moon_at_or_after(moment: f64) -> f64 {
+    let n =
+        libm::round((moment - nth_new_moon(0)) / MEAN_SYNODIC_MONTH - lunar_phase(moment) / 360.0)
+            as i32;
+    let mut k = n;
+    while nth_new_moon(k) < moment {
+        k += 1;
+    }
+    nth_new_moon(k)
+}
+
+// Lisp code reference: `sine-offset`
+fn sine_offset(moment: f64, location: Location, alpha: f64) -> f64 {
+    let universal = universal_from_local(moment, location);
+    let delta = declination(universal, 0.0, solar_longitude(universal));
+    tan_degrees(location.latitude) * tan_degrees(delta)
+        + sin_degrees(alpha) / (cos_degrees(delta) * cos_degrees(location.latitude))
+}
+
+// Lisp code reference: `approx-moment-of-depression`
+//
+// Approximates the local time in the evening of the fixed date of `moment` at which the sun
+// is `alpha` degrees below the horizon
+fn approx_evening_depression(moment: f64, location: Location, alpha: f64) -> Option<f64> {
+    let date = libm::floor(moment);
+    let value = sine_offset(moment, location, alpha);
+    let value = if libm::fabs(value) > 1.0 {
+        let alt = if alpha >= 0.0 { date + 1.0 } else { date + 0.5 };
+        sine_offset(alt, location, alpha)
+    } else {
+        value
+    };
+    if libm::fabs(value) <= 1.0 {
+        let offset = arcsin_degrees(value) / 360.0;
+        Some(local_from_apparent(date + 0.75 + offset, location))
+    } else {
+        None
+    }
+}
+
+// Lisp code reference: `dusk`
+//
+// The moment (in Universal Time) in the evening of `date` at which the sun is `alpha` degrees
+// below the horizon at `location`, if it gets that low
+fn dusk(date: i32, location: Location, alpha: f64) -> Option<f64> {
+    let mut approx = date as f64 + 0.75;
+    // Iterate until the approximation is accurate to 30 seconds
+    loop {
+        let moment = approx_evening_depression(approx, location, alpha)?;
+        if libm::fabs(approx - moment) < 30.0 / 86400.0 {
+            return Some(universal_from_local(moment, location));
+        }
+        approx = moment;
+    }
+}
+
+// Lisp code reference: `visible-crescent`
+//
+// Whether the crescent moon is visible at `location` in the evening before `date`,
+// using Shaukat's criterion
+fn visible_crescent(date: i32, location: Location) -> bool {
+    let moment = match dusk(date - 1, location, 4.5) {
+        Some(moment) => moment,
+        None => return false,
+    };
+    let phase = lunar_phase(moment);
+    let altitude = lunar_altitude(moment, location);
+    let arc_of_light = arccos_degrees(cos_degrees(lunar_latitude(moment)) * cos_degrees(phase));
+    phase > 0.0 && phase < 90.0 && (10.6..=90.0).contains(&arc_of_light) && altitude > 4.1
+}
+
+// Lisp code reference: `phasis-on-or-before`
+//
+// The last fixed date on or before `date` on which the crescent moon first
+// became visible at `location`
+pub(crate) fn phasis_on_or_before(date: i32, location: Location) -> i32 {
+    let moon = fixed_from_moment(new_moon_before(date as f64));
+    let age = date - moon;
+    let mut day = if age <= 3 && !visible_crescent(date, location) {
+        // The crescent of this month is not visible yet, so go back a month
+        moon - 30
+    } else {
+        moon
+    };
+    while !visible_crescent(day, location) {
+        day += 1;
+    }
+    day
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn moment_from_iso(year: i32, month: i32, day: i32, hour: f64, minute: f64) -> f64 {
+        Iso::fixed_from_iso_integers(year, month, day) as f64 + hour / 24.0 + minute / 1440.0
+    }
+
+    #[test]
+    fn test_solar_longitude() {
+        // Equinoxes and solstices, to the minute
+        let cases = [
+            (moment_from_iso(2000, 3, 20, 7.0, 35.0), 0.0),
+            (moment_from_iso(2000, 6, 21, 1.0, 48.0), 90.0),
+            (moment_from_iso(2022, 9, 23, 1.0, 4.0), 180.0),
+            (moment_from_iso(2020, 12, 21, 10.0, 2.0), 270.0),
+        ];
+        for (moment, expected) in cases {
+            let longitude = solar_longitude(moment);
+            let difference = rem_degrees(longitude - expected + 180.0) - 180.0;
+            // The sun moves about 0.0007 degrees per minute
+            assert!(
+                libm::fabs(difference) < 0.002,
+                "{}: {} != {}",
+                moment,
+                longitude,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimate_prior_solar_longitude() {
+        let equinox = moment_from_iso(2000, 3, 20, 7.0, 35.0);
+        let estimate = estimate_prior_solar_longitude(0.0, equinox + 100.0);
+        assert!(libm::fabs(estimate - equinox) < 1.0);
+    }
+
+    #[test]
+    fn test_new_moons() {
+        let cases = [
+            moment_from_iso(2000, 1, 6, 18.0, 14.0),
+            moment_from_iso(2022, 7, 28, 17.0, 55.0),
+            moment_from_iso(2023, 1, 21, 20.0, 53.0),
+            moment_from_iso(1970, 1, 7, 20.0, 35.0),
+        ];
+        for expected in cases {
+            let new_moon = new_moon_at_or_after(expected - 10.0);
+            assert!(
+                libm::fabs(new_moon - expected) < 5.0 / 1440.0,
+                "{} != {}",
+                new_moon,
+                expected
+            );
+            assert_eq!(new_moon_before(expected + 10.0), new_moon);
+            let phase = lunar_phase(new_moon);
+            assert!(
+                !(0.01..=359.99).contains(&phase),
+                "{} at {}",
+                phase,
+                new_moon
+            );
+        }
+    }
+}
@@ -209,5 +209,6 @@ fn iso_year_as_buddhist(year: i32) -> types::FormattableYear {
         era: types::Era(tinystr!(16, "be")),
         number: buddhist_year,
         related_iso: None,
+        cyclic: None,
     }
 }
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::{types, Calendar, DateDuration, DateDurationUnit, DateTimeError};
+use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::marker::PhantomData;
 use tinystr::tinystr;
@@ -20,8 +21,26 @@ pub struct ArithmeticDate<C: CalendarArithmetic> {
 
 pub trait CalendarArithmetic: Calendar {
     fn month_days(year: i32, month: u8) -> u8;
-    fn months_for_every_year() -> u8;
+    fn months_for_every_year(year: i32) -> u8;
     fn is_leap_year(year: i32) -> bool;
+
+    /// The number of days in `year`. The default implementation sums the lengths
+    /// of the months; solar calendars may override this with a direct computation.
+    fn days_in_provided_year(year: i32) -> u32 {
+        let months_in_year = Self::months_for_every_year(year);
+        let mut days: u32 = 0;
+        for month in 1..=months_in_year {
+            days += Self::month_days(year, month) as u32;
+        }
+        days
+    }
+
+    /// The ordinal month in `new_year` corresponding to the ordinal `month` in `old_year`,
+    /// used when adding years to a date. Calendars with leap months should override this
+    /// so that the month code is kept where possible.
+    fn month_in_offset_year(_old_year: i32, month: u8, _new_year: i32) -> u8 {
+        month
+    }
 }
 
 impl<C: CalendarArithmetic> ArithmeticDate<C> {
@@ -34,69 +53,215 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
             marker: PhantomData,
         }
     }
+
+    /// Add `years` to the year, mapping the month onto the new year and constraining
+    /// it to those available. The day is not constrained.
     #[inline]
-    pub fn offset_date(&mut self, mut offset: DateDuration<C>) {
-        self.year += offset.years;
-        self.month += offset.months as u8;
-        offset.months = 0;
+    fn offset_years(&mut self, years: i32) {
+        let old_year = self.year;
+        self.year += years;
+        self.month = C::month_in_offset_year(old_year, self.month, self.year);
+        let months_in_year = C::months_for_every_year(self.year);
+        if self.month > months_in_year {
+            self.month = months_in_year;
+        }
+    }
 
-        offset.days += offset.weeks * 7;
+    /// Add `months` to the month, balancing it into the year. The day is not constrained.
+    #[inline]
+    fn offset_months(&mut self, mut months: i32) {
+        while months > 0 {
+            let remaining_in_year = (C::months_for_every_year(self.year) - self.month) as i32;
+            if months <= remaining_in_year {
+                self.month += months as u8;
+                months = 0;
+            } else {
+                months -= remaining_in_year + 1;
+                self.year += 1;
+                self.month = 1;
+            }
+        }
+        while months < 0 {
+            if -months < self.month as i32 {
+                self.month -= (-months) as u8;
+                months = 0;
+            } else {
+                months += self.month as i32;
+                self.year -= 1;
+                self.month = C::months_for_every_year(self.year);
+            }
+        }
+    }
+
+    /// Clamp the day to the number of days in the current month
+    #[inline]
+    fn constrain_day(&mut self) {
+        let month_days = C::month_days(self.year, self.month);
+        if self.day > month_days {
+            self.day = month_days;
+        }
+    }
 
-        offset.days += self.day as i32 - 1;
+    /// Add `days` to the date, walking over months and years as necessary
+    #[inline]
+    fn offset_days(&mut self, mut days: i32) {
+        // Normalize date to beginning of month
+        days += self.day as i32 - 1;
         self.day = 1;
 
-        while offset.days != 0 {
-            if offset.days < 0 {
-                self.month -= 1;
-                let month_days = C::month_days(self.year, self.month);
-                if (-offset.days) > month_days as i32 {
-                    offset.days += month_days as i32;
-                } else {
-                    self.day = 1 + (month_days as i8 + offset.days as i8) as u8;
-                    offset.days = 0;
-                }
-            } else {
-                let month_days = C::month_days(self.year, self.month);
-
-                if offset.days >= month_days as i32 {
-                    self.month += 1;
-                    offset.days -= month_days as i32;
-                } else {
-                    self.day += offset.days as u8;
-                    offset.days = 0;
-                }
+        while days < 0 {
+            self.offset_months(-1);
+            days += C::month_days(self.year, self.month) as i32;
+        }
+        loop {
+            let month_days = C::month_days(self.year, self.month) as i32;
+            if days < month_days {
+                break;
             }
+            days -= month_days;
+            self.offset_months(1);
         }
+        self.day += days as u8;
+    }
+
+    /// Add `offset` to the date.
+    ///
+    /// Years and months are added first, after which the day is constrained
+    /// to the length of the resulting month (so that January 31 plus one month is
+    /// the last day of February). Weeks and days are added afterwards.
+    #[inline]
+    pub fn offset_date(&mut self, offset: DateDuration<C>) {
+        self.offset_years(offset.years);
+        self.offset_months(offset.months);
+        self.constrain_day();
+        self.offset_days(offset.weeks * 7 + offset.days);
     }
 
+    /// Returns this date offset by `years` and `months`, with the day constrained
+    /// to the resulting month
     #[inline]
+    fn offset_years_months(&self, years: i32, months: i32) -> Self {
+        let mut date = Self::new(self.year, self.month, self.day);
+        date.offset_years(years);
+        date.offset_months(months);
+        date.constrain_day();
+        date
+    }
+
+    #[inline]
+    fn cmp_fields(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+
+    /// The number of days from `self` until `other`, negative if `other` is earlier
+    fn days_until(&self, other: &Self) -> i32 {
+        let mut days = other.day_of_year() as i32 - self.day_of_year() as i32;
+        for year in self.year..other.year {
+            days += C::days_in_provided_year(year) as i32;
+        }
+        for year in other.year..self.year {
+            days -= C::days_in_provided_year(year) as i32;
+        }
+        days
+    }
+
+    /// Calculate `date2 - self` as a duration, balanced according to `largest_unit`
+    /// and truncated to `smallest_unit`.
+    ///
+    /// This follows the `DifferenceISODate` algorithm from Temporal, generalized to
+    /// calendars with any number of months in a year: the largest number of years and
+    /// then months is taken such that adding them to `self` (constraining the day to the
+    /// length of the resulting month) does not go past `date2`, and the remainder is
+    /// expressed in days (and weeks, if `largest_unit` is [`DateDurationUnit::Weeks`]).
+    ///
+    /// If `smallest_unit` is larger than `largest_unit`, `smallest_unit` is used as the
+    /// largest unit as well.
     pub fn until(
         &self,
         date2: ArithmeticDate<C>,
-        _largest_unit: DateDurationUnit,
-        _smaller_unti: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<C> {
-        DateDuration::new(
-            self.year - date2.year,
-            self.month as i32 - date2.month as i32,
-            0,
-            self.day as i32 - date2.day as i32,
-        )
+        let largest_unit = if unit_rank(smallest_unit) > unit_rank(largest_unit) {
+            smallest_unit
+        } else {
+            largest_unit
+        };
+        let sign = match date2.cmp_fields(self) {
+            Ordering::Greater => 1,
+            Ordering::Less => -1,
+            Ordering::Equal => return DateDuration::default(),
+        };
+        // Whether `self + (years, months)` is past `date2` in the direction of `sign`
+        let surpasses = |years: i32, months: i32| -> bool {
+            let mid = self.offset_years_months(years, months);
+            mid.cmp_fields(&date2) == date2.cmp_fields(self)
+        };
+
+        let mut years = 0;
+        let mut months = 0;
+        if matches!(
+            largest_unit,
+            DateDurationUnit::Years | DateDurationUnit::Months
+        ) {
+            years = date2.year - self.year;
+            if surpasses(years, 0) {
+                years -= sign;
+            }
+            if largest_unit == DateDurationUnit::Months {
+                // Start with the number of months in the years being skipped over,
+                // then correct for constrained months.
+                for k in 0..years.abs() {
+                    let year = if sign > 0 {
+                        self.year + k
+                    } else {
+                        self.year - k - 1
+                    };
+                    months += sign * C::months_for_every_year(year) as i32;
+                }
+                years = 0;
+                while months != 0 && surpasses(0, months) {
+                    months -= sign;
+                }
+            }
+            while !surpasses(years, months + sign) {
+                months += sign;
+            }
+        }
+
+        let mid = self.offset_years_months(years, months);
+        let mut days = mid.days_until(&date2);
+        let mut weeks = 0;
+        if largest_unit == DateDurationUnit::Weeks || smallest_unit == DateDurationUnit::Weeks {
+            weeks = days / 7;
+            days %= 7;
+        }
+        match smallest_unit {
+            DateDurationUnit::Years => {
+                months = 0;
+                weeks = 0;
+                days = 0;
+            }
+            DateDurationUnit::Months => {
+                weeks = 0;
+                days = 0;
+            }
+            DateDurationUnit::Weeks => {
+                days = 0;
+            }
+            DateDurationUnit::Days => {}
+        }
+        DateDuration::new(years, months, weeks, days)
     }
 
     #[inline]
     pub fn days_in_year(&self) -> u32 {
-        let months_in_year = C::months_for_every_year();
-        let mut days: u32 = 0;
-        for month in 1..=months_in_year {
-            days += C::month_days(self.year, month) as u32;
-        }
-        days
+        C::days_in_provided_year(self.year)
     }
 
     #[inline]
     pub fn months_in_year(&self) -> u8 {
-        C::months_for_every_year() as u8
+        C::months_for_every_year(self.year)
     }
 
     #[inline]
@@ -117,7 +282,7 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     pub fn date_from_year_day(year: i32, year_day: u32) -> ArithmeticDate<C> {
         let mut month = 1;
         let mut day = year_day as i32;
-        while month <= C::months_for_every_year() {
+        while month <= C::months_for_every_year(year) {
             let month_days = C::month_days(year, month) as i32;
             if day <= month_days {
                 break;
@@ -152,7 +317,7 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     #[inline]
     pub fn solar_month(&self) -> types::FormattableMonth {
         let code = match self.month {
-            a if a > C::months_for_every_year() => tinystr!(4, "und"),
+            a if a > C::months_for_every_year(self.year) => tinystr!(4, "und"),
             1 => tinystr!(4, "M01"),
             2 => tinystr!(4, "M02"),
             3 => tinystr!(4, "M03"),
@@ -193,7 +358,7 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
             ));
         };
 
-        if month > C::months_for_every_year() {
+        if month > C::months_for_every_year(year) {
             return Err(DateTimeError::UnknownMonthCode(
                 month_code.0,
                 cal.debug_name(),
@@ -208,6 +373,16 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     }
 }
 
+/// Ordering of [`DateDurationUnit`]s from smallest to largest
+pub(crate) fn unit_rank(unit: DateDurationUnit) -> u8 {
+    match unit {
+        DateDurationUnit::Days => 0,
+        DateDurationUnit::Weeks => 1,
+        DateDurationUnit::Months => 2,
+        DateDurationUnit::Years => 3,
+    }
+}
+
 /// For solar calendars, get the month number from the month code
 pub fn ordinal_solar_month_from_code(code: types::MonthCode) -> Option<u8> {
     // Match statements on tinystrs are annoying so instead
@@ -223,7 +398,7 @@ pub fn ordinal_solar_month_from_code(code: types::MonthCode) -> Option<u8> {
         if bytes[2] >= b'1' && bytes[2] <= b'9' {
             return Some(bytes[2] - b'0');
         }
-    } else if bytes[1] == b'1' && bytes[2] >= b'1' && bytes[2] <= b'3' {
+    } else if bytes[1] == b'1' && bytes[2] >= b'0' && bytes[2] <= b'3' {
         return Some(10 + bytes[2] - b'0');
     }
     None
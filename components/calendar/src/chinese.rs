@@ -0,0 +1,363 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Chinese calendar.
+//!
+//! ```rust
+//! use icu::calendar::{chinese::Chinese, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(2023, 6, 23)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_chinese = Date::new_from_iso(date_iso, Chinese);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(2023, 6, 23, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_chinese = DateTime::new_from_iso(datetime_iso, Chinese);
+//!
+//! // `Date` checks
+//! assert_eq!(date_chinese.year().number, 4660);
+//! assert_eq!(date_chinese.year().related_iso, Some(2023));
+//! assert_eq!(date_chinese.year().cyclic.map(|c| c.get()), Some(40));
+//! assert_eq!(date_chinese.month().ordinal, 6);
+//! assert_eq!(date_chinese.month().code.0, "M05");
+//! assert_eq!(date_chinese.day_of_month().0, 6);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_chinese.date.year().number, 4660);
+//! assert_eq!(datetime_chinese.date.month().ordinal, 6);
+//! assert_eq!(datetime_chinese.date.day_of_month().0, 6);
+//! assert_eq!(datetime_chinese.time.hour.number(), 13);
+//! assert_eq!(datetime_chinese.time.minute.number(), 1);
+//! assert_eq!(datetime_chinese.time.second.number(), 0);
+//! ```
+//!
+//! Years are counted from 2637 BCE, so the year starting in ISO year 2023 is 4660.
+//! The ISO year in which a year starts is available as
+//! [`FormattableYear::related_iso`](crate::types::FormattableYear::related_iso), and its
+//! position in the 60-year sexagenary cycle as
+//! [`FormattableYear::cyclic`](crate::types::FormattableYear::cyclic).
+//!
+//! Years have 12 or 13 months. A leap month has the month code of the month before it
+//! followed by `"L"`; for example, in 4660 the 3rd month is the leap month `"M02L"`.
+
+use crate::astronomy::Location;
+use crate::chinese_based::{self, ChineseBased, ChineseBasedYearInfo};
+use crate::iso::Iso;
+use crate::{types, ArithmeticDate, Date, DateTime, DateTimeError};
+
+/// The Chinese calendar
+///
+/// This is the astronomical Chinese calendar, with months starting on the day of the
+/// new moon in the standard time of Beijing.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Chinese;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<Chinese>
+pub struct ChineseDateInner(
+    pub(crate) ArithmeticDate<Chinese>,
+    pub(crate) ChineseBasedYearInfo,
+);
+
+impl ChineseBased for Chinese {
+    fn location(date: i32) -> Location {
+        // Before 1929, the local mean time of Beijing, 1397/180 hours ahead of UT
+        let zone = if date < Iso::fixed_from_iso_integers(1929, 1, 1) {
+            1397.0 / 180.0 / 24.0
+        } else {
+            8.0 / 24.0
+        };
+        Location {
+            latitude: 39.0 + 55.0 / 60.0,
+            longitude: 116.0 + 25.0 / 60.0,
+            zone,
+        }
+    }
+
+    const RELATED_ISO_OFFSET: i32 = -2637;
+}
+
+chinese_based::impl_chinese_based!(Chinese, ChineseDateInner, "chinese");
+
+impl Date<Chinese> {
+    /// Construct new Chinese Date.
+    ///
+    /// The month is the ordinal month, so in a year with a leap month the months after
+    /// the leap month have an ordinal one larger than their number.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_chinese =
+    ///     Date::new_chinese_date(4660, 3, 1).expect("Failed to initialize Chinese Date instance.");
+    ///
+    /// assert_eq!(date_chinese.year().number, 4660);
+    /// assert_eq!(date_chinese.month().ordinal, 3);
+    /// assert_eq!(date_chinese.month().code.0, "M02L");
+    /// assert_eq!(date_chinese.day_of_month().0, 1);
+    /// ```
+    pub fn new_chinese_date(year: i32, month: u8, day: u8) -> Result<Date<Chinese>, DateTimeError> {
+        let (date, info) = chinese_based::new_date::<Chinese>(year, month, day)?;
+        Ok(Date::from_raw(ChineseDateInner(date, info), Chinese))
+    }
+}
+
+impl DateTime<Chinese> {
+    /// Construct a new Chinese datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_chinese = DateTime::new_chinese_datetime(4660, 6, 6, 13, 1, 0)
+    ///     .expect("Failed to initialize Chinese DateTime instance.");
+    ///
+    /// assert_eq!(datetime_chinese.date.year().number, 4660);
+    /// assert_eq!(datetime_chinese.date.month().ordinal, 6);
+    /// assert_eq!(datetime_chinese.date.day_of_month().0, 6);
+    /// assert_eq!(datetime_chinese.time.hour.number(), 13);
+    /// assert_eq!(datetime_chinese.time.minute.number(), 1);
+    /// assert_eq!(datetime_chinese.time.second.number(), 0);
+    /// ```
+    pub fn new_chinese_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Chinese>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_chinese_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DateDuration, DateDurationUnit};
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_new_year() {
+        // (related ISO year, ISO date of the new year, ordinal of the leap month)
+        let cases = [
+            (1900, (1900, 1, 31), Some(9)),
+            (1949, (1949, 1, 29), Some(8)),
+            (1984, (1984, 2, 2), Some(11)),
+            (2000, (2000, 2, 5), None),
+            (2017, (2017, 1, 28), Some(7)),
+            (2020, (2020, 1, 25), Some(5)),
+            (2023, (2023, 1, 22), Some(3)),
+            (2024, (2024, 2, 10), None),
+            (2025, (2025, 1, 29), Some(7)),
+            (2033, (2033, 1, 31), Some(12)),
+        ];
+        for (related_iso, (y, m, d), leap_month) in cases {
+            let year = related_iso - Chinese::RELATED_ISO_OFFSET;
+            let iso = Date::new_iso_date(y, m, d).unwrap();
+            let date = Date::new_from_iso(iso.clone(), Chinese);
+            assert_eq!(date.year().number, year, "{}", related_iso);
+            assert_eq!(date.year().related_iso, Some(related_iso));
+            assert_eq!(date.month().ordinal, 1, "{}", related_iso);
+            assert_eq!(date.day_of_month().0, 1, "{}", related_iso);
+            assert_eq!(
+                date.months_in_year(),
+                if leap_month.is_some() { 13 } else { 12 },
+                "{}",
+                related_iso
+            );
+            if let Some(leap_month) = leap_month {
+                let leap = Date::new_chinese_date(year, leap_month, 1).unwrap();
+                assert!(leap.month().code.0.ends_with('L'), "{}", related_iso);
+            }
+            let day_before = Date::new_from_iso(
+                Iso::iso_from_fixed(Iso::fixed_from_iso(*iso.inner()) - 1),
+                Chinese,
+            );
+            assert_eq!(day_before.year().number, year - 1, "{}", related_iso);
+            assert_eq!(
+                day_before.month().ordinal,
+                day_before.months_in_year() as u32
+            );
+        }
+    }
+
+    #[test]
+    fn test_month_codes() {
+        // 4660 has a leap month after the 2nd month
+        let expected = [
+            "M01", "M02", "M02L", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11",
+            "M12",
+        ];
+        for (i, code) in expected.iter().enumerate() {
+            let date = Date::new_chinese_date(4660, i as u8 + 1, 1).unwrap();
+            assert_eq!(date.month().code.0, *code);
+            let from_codes = Date::new_from_codes(
+                types::Era(tinystr!(16, "chinese")),
+                4660,
+                date.month().code,
+                1,
+                Chinese,
+            )
+            .unwrap();
+            assert_eq!(from_codes, date);
+        }
+        // 4661 has no leap month
+        assert_eq!(
+            Date::new_from_codes(
+                types::Era(tinystr!(16, "chinese")),
+                4661,
+                types::MonthCode(tinystr!(4, "M02L")),
+                1,
+                Chinese,
+            ),
+            Err(DateTimeError::UnknownMonthCode(
+                tinystr!(4, "M02L"),
+                "Chinese"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cyclic_year() {
+        // 1984 is the first year of the current cycle
+        let cases = [
+            (1983, 60),
+            (1984, 1),
+            (2000, 17),
+            (2023, 40),
+            (2043, 60),
+            (2044, 1),
+        ];
+        for (related_iso, cyclic) in cases {
+            let year =
+                Date::new_from_iso(Date::new_iso_date(related_iso, 7, 1).unwrap(), Chinese).year();
+            assert_eq!(
+                year.cyclic.map(|c| c.get()),
+                Some(cyclic),
+                "{}",
+                related_iso
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut fixed = Iso::fixed_from_iso_integers(2019, 12, 1);
+        let end = Iso::fixed_from_iso_integers(2021, 3, 1);
+        while fixed < end {
+            let iso = Iso::iso_from_fixed(fixed);
+            let chinese = Date::new_from_iso(iso.clone(), Chinese);
+            assert_eq!(chinese.to_iso(), iso);
+            fixed += 1;
+        }
+    }
+
+    #[test]
+    fn test_offset() {
+        // A leap month maps to the month with the same number in a year without one
+        let mut date = Date::new_chinese_date(4660, 3, 15).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date.year().number, 4661);
+        assert_eq!(date.month().code.0, "M02");
+        assert_eq!(date.day_of_month().0, 15);
+
+        let mut date = Date::new_chinese_date(4660, 1, 1).unwrap();
+        date.add(DateDuration::new(0, 0, 0, 384));
+        assert_eq!(date, Date::new_chinese_date(4661, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_leap_month_near_solstice() {
+        // 2033 has its leap month after the 11th month, which contains the winter solstice,
+        // so the leap month comes from the sui starting in the following year
+        for related_iso in 2030..=2036 {
+            let year = related_iso - Chinese::RELATED_ISO_OFFSET;
+            let new_year = Date::new_chinese_date(year, 1, 1).unwrap();
+            let next_new_year =
+                new_year
+                    .clone()
+                    .added(DateDuration::new(0, 0, 0, new_year.days_in_year() as i32));
+            assert_eq!(
+                next_new_year,
+                Date::new_chinese_date(year + 1, 1, 1).unwrap(),
+                "{}",
+                related_iso
+            );
+            let leap_months = (1..=new_year.months_in_year())
+                .filter(|&month| {
+                    Date::new_chinese_date(year, month, 1)
+                        .unwrap()
+                        .month()
+                        .code
+                        .0
+                        .ends_with('L')
+                })
+                .count();
+            assert_eq!(
+                leap_months,
+                (new_year.months_in_year() - 12) as usize,
+                "{}",
+                related_iso
+            );
+        }
+        let year = 2033 - Chinese::RELATED_ISO_OFFSET;
+        let leap = Date::new_chinese_date(year, 12, 1).unwrap();
+        assert_eq!(leap.month().code.0, "M11L");
+        assert_eq!(leap.to_iso(), Date::new_iso_date(2033, 12, 22).unwrap());
+    }
+
+    #[test]
+    fn test_until_matches_arithmetic() {
+        // 4660 has a leap month after the 2nd month and 4670 after the 11th;
+        // day 0 stands for the last day of the month
+        let dates = [
+            (4658, 12, 0),
+            (4660, 2, 0),
+            (4660, 3, 15),
+            (4660, 4, 1),
+            (4661, 1, 29),
+            (4661, 3, 0),
+            (4670, 12, 29),
+            (4670, 13, 10),
+        ];
+        let new_date = |(year, month, day)| {
+            let date = Date::new_chinese_date(year, month, 1).unwrap();
+            let day = if day == 0 { date.days_in_month() } else { day };
+            Date::new_chinese_date(year, month, day).unwrap()
+        };
+        let dates = dates.map(new_date);
+        // Weeks and days are computed from the fixed dates, so only the units that
+        // step through months are compared
+        let units = [
+            (DateDurationUnit::Years, DateDurationUnit::Days),
+            (DateDurationUnit::Years, DateDurationUnit::Months),
+            (DateDurationUnit::Months, DateDurationUnit::Days),
+        ];
+        for date1 in &dates {
+            for date2 in &dates {
+                for (largest_unit, smallest_unit) in units {
+                    let duration = date1.until(date2, largest_unit, smallest_unit);
+                    let expected = date1
+                        .inner
+                        .0
+                        .until(date2.inner.0, largest_unit, smallest_unit);
+                    assert_eq!(
+                        duration, expected,
+                        "{:?} until {:?} in {:?}/{:?}",
+                        date1, date2, largest_unit, smallest_unit
+                    );
+                    if smallest_unit == DateDurationUnit::Days {
+                        assert_eq!(&date1.clone().added(duration), date2);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,718 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Shared implementation of the Chinese-based lunisolar calendars ([`Chinese`](crate::chinese::Chinese)
+//! and [`Dangi`](crate::dangi::Dangi)).
+//!
+//! Months start on the day (in the local time of the calendar) of a new moon, and a year
+//! has 12 or 13 months. The winter solstice always falls in the 11th month; if there are
+//! 13 new moons between two such months, the first month of that period without a major
+//! solar term is a leap month, which repeats the number of the month before it.
+//!
+//! Years are identified by their related ISO year, the ISO year in which they start.
+//!
+//! The algorithms are from
+//! Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+//!
+//! Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L4814
+
+use crate::astronomy::{self, Location, MEAN_SYNODIC_MONTH};
+use crate::calendar_arithmetic::unit_rank;
+use crate::iso::Iso;
+use crate::{
+    types, ArithmeticDate, Calendar, CalendarArithmetic, DateDuration, DateDurationUnit,
+    DateTimeError,
+};
+use core::cmp::Ordering;
+use core::num::NonZeroU8;
+use tinystr::tinystr;
+
+/// A Chinese-based calendar, which is determined by the time zone in which the
+/// astronomical events are observed
+pub(crate) trait ChineseBased: CalendarArithmetic {
+    /// The location whose standard time determines the calendar at the fixed date `date`
+    fn location(date: i32) -> Location;
+
+    /// The related ISO year of the year numbered 0 in this calendar,
+    /// such that `year + RELATED_ISO_OFFSET` is the related ISO year of `year`
+    const RELATED_ISO_OFFSET: i32;
+}
+
+/// The year data of a Chinese-based calendar, which is expensive to compute.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct ChineseBasedYearInfo {
+    /// The fixed date of the first day of the year
+    pub(crate) new_year: i32,
+    /// Bit `n` is set if the `n + 1`-th month of the year has 30 days instead of 29
+    month_lengths: u16,
+    /// The ordinal of the leap month, or 0 if the year has no leap month
+    leap_month: u8,
+}
+
+impl ChineseBasedYearInfo {
+    /// Computes the year data of the year with the related ISO year `related_iso`
+    ///
+    /// The year has 12 months, plus a leap month if the leap month of one of the two suis
+    /// it overlaps with falls in it.
+    pub(crate) fn compute<C: ChineseBased>(related_iso: i32) -> Self {
+        let new_year = new_year::<C>(related_iso);
+        let solstice = winter_solstice_on_or_before::<C>(new_year);
+        let next_solstice = winter_solstice_on_or_before::<C>(solstice + 370);
+        let leap_months = [
+            leap_month_in_sui::<C>(solstice),
+            leap_month_in_sui::<C>(next_solstice),
+        ];
+        let mut month_lengths = 0;
+        let mut leap_month = 0;
+        let mut months_in_year = 12;
+        let mut month_start = new_year;
+        let mut ordinal = 1;
+        while ordinal <= months_in_year {
+            if leap_month == 0 && leap_months.contains(&Some(month_start)) {
+                leap_month = ordinal;
+                months_in_year = 13;
+            }
+            let next_month_start = new_moon_on_or_after::<C>(month_start + 1);
+            if next_month_start - month_start == 30 {
+                month_lengths |= 1 << (ordinal - 1);
+            }
+            month_start = next_month_start;
+            ordinal += 1;
+        }
+        Self {
+            new_year,
+            month_lengths,
+            leap_month,
+        }
+    }
+
+    /// Computes the year data of the year `year` of the calendar `C`
+    pub(crate) fn for_year<C: ChineseBased>(year: i32) -> Self {
+        Self::compute::<C>(year + C::RELATED_ISO_OFFSET)
+    }
+
+    pub(crate) fn months_in_year(&self) -> u8 {
+        if self.leap_month == 0 {
+            12
+        } else {
+            13
+        }
+    }
+
+    pub(crate) fn month_days(&self, month: u8) -> u8 {
+        if month == 0 || month > self.months_in_year() {
+            0
+        } else if self.month_lengths & (1 << (month - 1)) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    pub(crate) fn days_in_year(&self) -> u32 {
+        (1..=self.months_in_year())
+            .map(|month| self.month_days(month) as u32)
+            .sum()
+    }
+
+    /// The number of days in the year before the first day of the ordinal `month`
+    pub(crate) fn days_before_month(&self, month: u8) -> u32 {
+        (1..month).map(|month| self.month_days(month) as u32).sum()
+    }
+
+    /// The month code of the ordinal `month`; the leap month has the number of the
+    /// month before it, followed by "L"
+    pub(crate) fn month_code(&self, month: u8) -> types::MonthCode {
+        let (number, leap) = if self.leap_month == 0 || month < self.leap_month {
+            (month, false)
+        } else {
+            (month - 1, month == self.leap_month)
+        };
+        let code = match (number, leap) {
+            (1, false) => tinystr!(4, "M01"),
+            (2, false) => tinystr!(4, "M02"),
+            (3, false) => tinystr!(4, "M03"),
+            (4, false) => tinystr!(4, "M04"),
+            (5, false) => tinystr!(4, "M05"),
+            (6, false) => tinystr!(4, "M06"),
+            (7, false) => tinystr!(4, "M07"),
+            (8, false) => tinystr!(4, "M08"),
+            (9, false) => tinystr!(4, "M09"),
+            (10, false) => tinystr!(4, "M10"),
+            (11, false) => tinystr!(4, "M11"),
+            (12, false) => tinystr!(4, "M12"),
+            (1, true) => tinystr!(4, "M01L"),
+            (2, true) => tinystr!(4, "M02L"),
+            (3, true) => tinystr!(4, "M03L"),
+            (4, true) => tinystr!(4, "M04L"),
+            (5, true) => tinystr!(4, "M05L"),
+            (6, true) => tinystr!(4, "M06L"),
+            (7, true) => tinystr!(4, "M07L"),
+            (8, true) => tinystr!(4, "M08L"),
+            (9, true) => tinystr!(4, "M09L"),
+            (10, true) => tinystr!(4, "M10L"),
+            (11, true) => tinystr!(4, "M11L"),
+            (12, true) => tinystr!(4, "M12L"),
+            _ => tinystr!(4, "und"),
+        };
+        types::MonthCode(code)
+    }
+
+    /// The ordinal month for a month code, if that month exists in this year
+    pub(crate) fn month_from_code(&self, code: types::MonthCode) -> Option<u8> {
+        let (number, leap) = parse_month_code(code)?;
+        self.month_from_number(number, leap)
+    }
+
+    /// The ordinal of month number `number` (the leap month following it if `leap`),
+    /// if that month exists in this year
+    fn month_from_number(&self, number: u8, leap: bool) -> Option<u8> {
+        if leap {
+            if self.leap_month == number + 1 {
+                Some(number + 1)
+            } else {
+                None
+            }
+        } else if self.leap_month == 0 || number < self.leap_month {
+            Some(number)
+        } else {
+            Some(number + 1)
+        }
+    }
+
+    /// The ordinal of the month that corresponds to the ordinal `month` of the year `other`
+    /// in this year: the month with the same month code, or, if `month` is a leap month
+    /// that this year does not have, the month with the same number.
+    pub(crate) fn month_from_other_year(&self, other: &Self, month: u8) -> u8 {
+        let (number, leap) = if other.leap_month == 0 || month < other.leap_month {
+            (month, false)
+        } else {
+            (month - 1, month == other.leap_month)
+        };
+        self.month_from_number(number, leap)
+            .or_else(|| self.month_from_number(number, false))
+            .unwrap_or(month)
+    }
+}
+
+/// Splits a month code of the form "Mxx" or "MxxL" into the month number and
+/// whether it is a leap month
+fn parse_month_code(code: types::MonthCode) -> Option<(u8, bool)> {
+    let bytes = code.0.all_bytes();
+    let leap = match code.0.len() {
+        3 => false,
+        4 if bytes[3] == b'L' => true,
+        _ => return None,
+    };
+    if bytes[0] != b'M' || !bytes[1].is_ascii_digit() || !bytes[2].is_ascii_digit() {
+        return None;
+    }
+    let number = 10 * (bytes[1] - b'0') + (bytes[2] - b'0');
+    if (1..=12).contains(&number) {
+        Some((number, leap))
+    } else {
+        None
+    }
+}
+
+/// The [`types::FormattableYear`] of `year`, with the given era
+pub(crate) fn formattable_year<C: ChineseBased>(
+    era: types::Era,
+    year: i32,
+) -> types::FormattableYear {
+    let related_iso = year + C::RELATED_ISO_OFFSET;
+    // 4 CE is the first year of a cycle
+    let cyclic = (related_iso - 4).rem_euclid(60) as u8 + 1;
+    types::FormattableYear {
+        era,
+        number: year,
+        related_iso: Some(related_iso),
+        cyclic: NonZeroU8::new(cyclic),
+    }
+}
+
+/// Midnight at the start of the fixed date `date` in the calendar's standard time, in Universal Time
+fn midnight<C: ChineseBased>(date: i32) -> f64 {
+    astronomy::universal_from_standard(date as f64, C::location(date))
+}
+
+/// The fixed date (in the calendar's standard time) of `moment`
+fn fixed_from_universal<C: ChineseBased>(moment: f64) -> i32 {
+    let date = astronomy::fixed_from_moment(moment);
+    astronomy::fixed_from_moment(astronomy::standard_from_universal(
+        moment,
+        C::location(date),
+    ))
+}
+
+// Lisp code reference: `chinese-new-moon-on-or-after`
+fn new_moon_on_or_after<C: ChineseBased>(date: i32) -> i32 {
+    fixed_from_universal::<C>(astronomy::new_moon_at_or_after(midnight::<C>(date)))
+}
+
+// Lisp code reference: `chinese-new-moon-before`
+fn new_moon_before<C: ChineseBased>(date: i32) -> i32 {
+    fixed_from_universal::<C>(astronomy::new_moon_before(midnight::<C>(date)))
+}
+
+// Lisp code reference: `current-major-solar-term`
+//
+// The index of the last major solar term on or before `date`, where the term in which the
+// sun reaches 330° is 1
+fn current_major_solar_term<C: ChineseBased>(date: i32) -> i32 {
+    let longitude = astronomy::solar_longitude(midnight::<C>(date));
+    (2 + libm::floor(longitude / 30.0) as i32 - 1).rem_euclid(12) + 1
+}
+
+// Lisp code reference: `chinese-no-major-solar-term?`
+//
+// Whether the month starting on `date` has no major solar term
+fn no_major_solar_term<C: ChineseBased>(date: i32) -> bool {
+    current_major_solar_term::<C>(date)
+        == current_major_solar_term::<C>(new_moon_on_or_after::<C>(date + 1))
+}
+
+// Lisp code reference: `chinese-winter-solstice-on-or-before`
+fn winter_solstice_on_or_before<C: ChineseBased>(date: i32) -> i32 {
+    let approx = astronomy::estimate_prior_solar_longitude(270.0, midnight::<C>(date + 1));
+    let mut day = astronomy::fixed_from_moment(approx) - 1;
+    while astronomy::solar_longitude(midnight::<C>(day + 1)) <= 270.0 {
+        day += 1;
+    }
+    day
+}
+
+/// The first month of the sui (the period between two winter solstices) starting after the
+/// winter solstice `solstice`, and whether the sui has 13 months
+fn sui<C: ChineseBased>(solstice: i32) -> (i32, bool) {
+    let next_solstice = winter_solstice_on_or_before::<C>(solstice + 370);
+    // The month after the 11th month, which contains the solstice
+    let month_12 = new_moon_on_or_after::<C>(solstice + 1);
+    let next_month_11 = new_moon_before::<C>(next_solstice + 1);
+    let months = libm::round((next_month_11 - month_12) as f64 / MEAN_SYNODIC_MONTH) as i32;
+    (month_12, months == 12)
+}
+
+// Lisp code reference: `chinese-new-year-in-sui`
+//
+// The new year in the sui containing `date`
+fn new_year_in_sui<C: ChineseBased>(date: i32) -> i32 {
+    let solstice = winter_solstice_on_or_before::<C>(date);
+    let (month_12, leap_sui) = sui::<C>(solstice);
+    let month_13 = new_moon_on_or_after::<C>(month_12 + 1);
+    if leap_sui && (no_major_solar_term::<C>(month_12) || no_major_solar_term::<C>(month_13)) {
+        // One of the 12th and 13th months is a leap month
+        new_moon_on_or_after::<C>(month_13 + 1)
+    } else {
+        month_13
+    }
+}
+
+/// The new year of the year with the related ISO year `related_iso`
+fn new_year<C: ChineseBased>(related_iso: i32) -> i32 {
+    // The new year is between January 21st and February 21st, so in the sui containing July 1st
+    new_year_in_sui::<C>(Iso::fixed_from_iso_integers(related_iso, 7, 1))
+}
+
+/// The first day of the leap month of the sui starting after the winter solstice `solstice`,
+/// if it has 13 months: its first month without a major solar term
+fn leap_month_in_sui<C: ChineseBased>(solstice: i32) -> Option<i32> {
+    let (month_12, leap_sui) = sui::<C>(solstice);
+    if !leap_sui {
+        return None;
+    }
+    let next_solstice = winter_solstice_on_or_before::<C>(solstice + 370);
+    let mut month = month_12;
+    while month < next_solstice {
+        if no_major_solar_term::<C>(month) {
+            return Some(month);
+        }
+        month = new_moon_on_or_after::<C>(month + 1);
+    }
+    None
+}
+
+/// The date and year data of the fixed date `date`
+pub(crate) fn date_from_fixed<C: ChineseBased>(
+    date: i32,
+) -> (ArithmeticDate<C>, ChineseBasedYearInfo) {
+    let mut related_iso = Iso::iso_year_from_fixed(date);
+    let mut info = ChineseBasedYearInfo::compute::<C>(related_iso);
+    if date < info.new_year {
+        related_iso -= 1;
+        info = ChineseBasedYearInfo::compute::<C>(related_iso);
+    }
+    let mut day = date - info.new_year + 1;
+    let mut month = 1;
+    while month < info.months_in_year() && day > info.month_days(month) as i32 {
+        day -= info.month_days(month) as i32;
+        month += 1;
+    }
+    (
+        ArithmeticDate::new(related_iso - C::RELATED_ISO_OFFSET, month, day as u8),
+        info,
+    )
+}
+
+/// The fixed date of `date`, whose year data is `info`
+pub(crate) fn fixed_from_date<C: ChineseBased>(
+    date: &ArithmeticDate<C>,
+    info: &ChineseBasedYearInfo,
+) -> i32 {
+    info.new_year + info.days_before_month(date.month) as i32 + date.day as i32 - 1
+}
+
+/// Adds `years` and `months` to `date`, whose year data is `info`, without constraining the day
+///
+/// The year data of every year that is stepped over is computed once.
+fn offset_years_months<C: ChineseBased>(
+    date: &mut ArithmeticDate<C>,
+    info: &mut ChineseBasedYearInfo,
+    years: i32,
+    mut months: i32,
+) {
+    if years != 0 {
+        let new_info = ChineseBasedYearInfo::for_year::<C>(date.year + years);
+        date.year += years;
+        date.month = new_info
+            .month_from_other_year(info, date.month)
+            .min(new_info.months_in_year());
+        *info = new_info;
+    }
+    while months > 0 {
+        let remaining_in_year = (info.months_in_year() - date.month) as i32;
+        if months <= remaining_in_year {
+            date.month += months as u8;
+            months = 0;
+        } else {
+            months -= remaining_in_year + 1;
+            date.year += 1;
+            date.month = 1;
+            *info = ChineseBasedYearInfo::for_year::<C>(date.year);
+        }
+    }
+    while months < 0 {
+        if -months < date.month as i32 {
+            date.month -= (-months) as u8;
+            months = 0;
+        } else {
+            months += date.month as i32;
+            date.year -= 1;
+            *info = ChineseBasedYearInfo::for_year::<C>(date.year);
+            date.month = info.months_in_year();
+        }
+    }
+}
+
+/// The number of months from the start of the ordinal `month1` of the year `year1`, whose
+/// year data is `info1`, until the start of the ordinal `month2` of the year `year2`, whose
+/// year data is `info2`
+fn months_until<C: ChineseBased>(
+    (year1, month1, info1): (i32, u8, &ChineseBasedYearInfo),
+    (year2, month2, info2): (i32, u8, &ChineseBasedYearInfo),
+) -> i32 {
+    let months_in_years_between = |years: core::ops::Range<i32>| -> i32 {
+        years
+            .map(|year| ChineseBasedYearInfo::for_year::<C>(year).months_in_year() as i32)
+            .sum()
+    };
+    match year1.cmp(&year2) {
+        Ordering::Equal => month2 as i32 - month1 as i32,
+        Ordering::Less => {
+            (info1.months_in_year() - month1) as i32
+                + months_in_years_between(year1 + 1..year2)
+                + month2 as i32
+        }
+        Ordering::Greater => {
+            -((info2.months_in_year() - month2) as i32
+                + months_in_years_between(year2 + 1..year1)
+                + month1 as i32)
+        }
+    }
+}
+
+/// Adds `offset` to `date`, updating the year data `info`
+///
+/// Years and months are added first, after which the day is constrained to the length of
+/// the resulting month, as for [`ArithmeticDate::offset_date`]. Weeks and days are added to
+/// the fixed date rather than month by month, since computing the length of a month is expensive.
+pub(crate) fn offset_date<C: ChineseBased + Calendar>(
+    date: &mut ArithmeticDate<C>,
+    info: &mut ChineseBasedYearInfo,
+    offset: DateDuration<C>,
+) {
+    offset_years_months(date, info, offset.years, offset.months);
+    date.day = date.day.min(info.month_days(date.month));
+    let days = offset.weeks * 7 + offset.days;
+    if days != 0 {
+        let (new_date, new_info) = date_from_fixed::<C>(fixed_from_date(date, info) + days);
+        *date = new_date;
+        *info = new_info;
+    }
+}
+
+/// The duration from `date1`, whose year data is `info1`, until `date2`, whose year
+/// data is `info2`
+///
+/// This gives the same result as [`ArithmeticDate::until`], but counts months with the year
+/// data instead of adding candidate durations to `date1` month by month, and computes the
+/// remaining days from the fixed dates.
+pub(crate) fn until<C: ChineseBased + Calendar>(
+    date1: &ArithmeticDate<C>,
+    info1: &ChineseBasedYearInfo,
+    date2: &ArithmeticDate<C>,
+    info2: &ChineseBasedYearInfo,
+    largest_unit: DateDurationUnit,
+    smallest_unit: DateDurationUnit,
+) -> DateDuration<C> {
+    let largest_unit = if unit_rank(smallest_unit) > unit_rank(largest_unit) {
+        smallest_unit
+    } else {
+        largest_unit
+    };
+    let fields = |date: &ArithmeticDate<C>| (date.year, date.month, date.day);
+    let sign = match fields(date2).cmp(&fields(date1)) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => return DateDuration::default(),
+    };
+    // Whether `date`, with its day constrained to its month, is past `date2` in the
+    // direction of `sign`
+    let surpasses = |date: &ArithmeticDate<C>, info: &ChineseBasedYearInfo| -> bool {
+        let day = date.day.min(info.month_days(date.month));
+        (date.year, date.month, day).cmp(&fields(date2)) == fields(date2).cmp(&fields(date1))
+    };
+
+    let mut years = 0;
+    let mut months = 0;
+    let mut mid = ArithmeticDate::<C>::new(date1.year, date1.month, date1.day);
+    let mut mid_info = *info1;
+    if largest_unit == DateDurationUnit::Years {
+        years = date2.year - date1.year;
+        offset_years_months(&mut mid, &mut mid_info, years, 0);
+        if surpasses(&mid, &mid_info) {
+            years -= sign;
+            mid = ArithmeticDate::new(date1.year, date1.month, date1.day);
+            mid_info = *info1;
+            offset_years_months(&mut mid, &mut mid_info, years, 0);
+        }
+    }
+    if matches!(
+        largest_unit,
+        DateDurationUnit::Years | DateDurationUnit::Months
+    ) {
+        // Adding the months until the month of `date2` lands in that month, and adding
+        // one month less lands in the month before it (in the direction of `sign`)
+        months = months_until::<C>(
+            (mid.year, mid.month, &mid_info),
+            (date2.year, date2.month, info2),
+        );
+        offset_years_months(&mut mid, &mut mid_info, 0, months);
+        if surpasses(&mid, &mid_info) {
+            months -= sign;
+            offset_years_months(&mut mid, &mut mid_info, 0, -sign);
+        }
+        mid.day = mid.day.min(mid_info.month_days(mid.month));
+    }
+
+    let mut days = fixed_from_date(date2, info2) - fixed_from_date(&mid, &mid_info);
+    let mut weeks = 0;
+    if largest_unit == DateDurationUnit::Weeks || smallest_unit == DateDurationUnit::Weeks {
+        weeks = days / 7;
+        days %= 7;
+    }
+    match smallest_unit {
+        DateDurationUnit::Years => {
+            months = 0;
+            weeks = 0;
+            days = 0;
+        }
+        DateDurationUnit::Months => {
+            weeks = 0;
+            days = 0;
+        }
+        DateDurationUnit::Weeks => {
+            days = 0;
+        }
+        DateDurationUnit::Days => {}
+    }
+    DateDuration::new(years, months, weeks, days)
+}
+
+/// The date in the year `year` with the ordinal `month` and `day`, and its year data
+pub(crate) fn new_date<C: ChineseBased>(
+    year: i32,
+    month: u8,
+    day: u8,
+) -> Result<(ArithmeticDate<C>, ChineseBasedYearInfo), DateTimeError> {
+    let info = ChineseBasedYearInfo::for_year::<C>(year);
+    if month == 0 || month > info.months_in_year() {
+        return Err(DateTimeError::OutOfRange);
+    }
+    if day == 0 || day > info.month_days(month) {
+        return Err(DateTimeError::OutOfRange);
+    }
+    Ok((ArithmeticDate::new(year, month, day), info))
+}
+
+/// Implements [`CalendarArithmetic`] and [`Calendar`] for the Chinese-based calendar
+/// `$calendar`, whose dates are `$inner(ArithmeticDate<$calendar>, ChineseBasedYearInfo)`
+/// and whose only era is `$era`
+macro_rules! impl_chinese_based {
+    ($calendar:ident, $inner:ident, $era:literal) => {
+        // These compute the year data on every call, so the `Calendar` implementation
+        // below uses the year data stored in the date instead.
+        impl $crate::CalendarArithmetic for $calendar {
+            fn month_days(year: i32, month: u8) -> u8 {
+                $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(year)
+                    .month_days(month)
+            }
+
+            fn months_for_every_year(year: i32) -> u8 {
+                $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(year).months_in_year()
+            }
+
+            fn days_in_provided_year(year: i32) -> u32 {
+                $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(year).days_in_year()
+            }
+
+            fn is_leap_year(year: i32) -> bool {
+                <Self as $crate::CalendarArithmetic>::months_for_every_year(year) == 13
+            }
+
+            fn month_in_offset_year(old_year: i32, month: u8, new_year: i32) -> u8 {
+                let old_info =
+                    $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(old_year);
+                let new_info =
+                    $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(new_year);
+                new_info.month_from_other_year(&old_info, month)
+            }
+        }
+
+        impl $crate::Calendar for $calendar {
+            type DateInner = $inner;
+            fn date_from_codes(
+                &self,
+                era: $crate::types::Era,
+                year: i32,
+                month_code: $crate::types::MonthCode,
+                day: u8,
+            ) -> Result<Self::DateInner, $crate::DateTimeError> {
+                if era.0 != tinystr::tinystr!(16, $era) {
+                    return Err($crate::DateTimeError::UnknownEra(
+                        era.0,
+                        $crate::Calendar::debug_name(self),
+                    ));
+                }
+                let info = $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(year);
+                let month = info.month_from_code(month_code).ok_or(
+                    $crate::DateTimeError::UnknownMonthCode(
+                        month_code.0,
+                        $crate::Calendar::debug_name(self),
+                    ),
+                )?;
+                if day == 0 || day > info.month_days(month) {
+                    return Err($crate::DateTimeError::OutOfRange);
+                }
+                Ok($inner($crate::ArithmeticDate::new(year, month, day), info))
+            }
+
+            fn date_from_iso(&self, iso: $crate::Date<$crate::iso::Iso>) -> Self::DateInner {
+                let fixed_iso = $crate::iso::Iso::fixed_from_iso(*iso.inner());
+                let (date, info) = $crate::chinese_based::date_from_fixed::<Self>(fixed_iso);
+                $inner(date, info)
+            }
+
+            fn date_to_iso(&self, date: &Self::DateInner) -> $crate::Date<$crate::iso::Iso> {
+                $crate::iso::Iso::iso_from_fixed($crate::chinese_based::fixed_from_date(
+                    &date.0, &date.1,
+                ))
+            }
+
+            fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+                date.1.months_in_year()
+            }
+
+            fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+                date.1.days_in_year()
+            }
+
+            fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+                date.1.month_days(date.0.month)
+            }
+
+            fn day_of_week(&self, date: &Self::DateInner) -> $crate::types::IsoWeekday {
+                $crate::Calendar::day_of_week(
+                    &$crate::iso::Iso,
+                    $crate::Calendar::date_to_iso(self, date).inner(),
+                )
+            }
+
+            fn offset_date(&self, date: &mut Self::DateInner, offset: $crate::DateDuration<Self>) {
+                $crate::chinese_based::offset_date(&mut date.0, &mut date.1, offset);
+            }
+
+            fn until(
+                &self,
+                date1: &Self::DateInner,
+                date2: &Self::DateInner,
+                _calendar2: &Self,
+                largest_unit: $crate::DateDurationUnit,
+                smallest_unit: $crate::DateDurationUnit,
+            ) -> $crate::DateDuration<Self> {
+                $crate::chinese_based::until(
+                    &date1.0,
+                    &date1.1,
+                    &date2.0,
+                    &date2.1,
+                    largest_unit,
+                    smallest_unit,
+                )
+            }
+
+            fn year(&self, date: &Self::DateInner) -> $crate::types::FormattableYear {
+                $crate::chinese_based::formattable_year::<Self>(
+                    $crate::types::Era(tinystr::tinystr!(16, $era)),
+                    date.0.year,
+                )
+            }
+
+            fn month(&self, date: &Self::DateInner) -> $crate::types::FormattableMonth {
+                $crate::types::FormattableMonth {
+                    ordinal: date.0.month as u32,
+                    code: date.1.month_code(date.0.month),
+                }
+            }
+
+            fn day_of_month(&self, date: &Self::DateInner) -> $crate::types::DayOfMonth {
+                date.0.day_of_month()
+            }
+
+            fn day_of_year_info(&self, date: &Self::DateInner) -> $crate::types::DayOfYearInfo {
+                let era = $crate::types::Era(tinystr::tinystr!(16, $era));
+                let prev_year = date.0.year - 1;
+                let next_year = date.0.year + 1;
+                $crate::types::DayOfYearInfo {
+                    day_of_year: date.1.days_before_month(date.0.month) + date.0.day as u32,
+                    days_in_year: date.1.days_in_year(),
+                    prev_year: $crate::chinese_based::formattable_year::<Self>(era, prev_year),
+                    days_in_prev_year:
+                        $crate::chinese_based::ChineseBasedYearInfo::for_year::<Self>(prev_year)
+                            .days_in_year(),
+                    next_year: $crate::chinese_based::formattable_year::<Self>(era, next_year),
+                }
+            }
+
+            fn debug_name(&self) -> &'static str {
+                stringify!($calendar)
+            }
+
+            fn any_calendar_kind(&self) -> Option<$crate::any_calendar::AnyCalendarKind> {
+                Some($crate::any_calendar::AnyCalendarKind::$calendar)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_chinese_based;
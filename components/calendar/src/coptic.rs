@@ -65,7 +65,7 @@ impl CalendarArithmetic for Coptic {
         }
     }
 
-    fn months_for_every_year() -> u8 {
+    fn months_for_every_year(_: i32) -> u8 {
         13
     }
 
@@ -292,12 +292,14 @@ fn year_as_coptic(year: i32) -> types::FormattableYear {
             era: types::Era(tinystr!(16, "ad")),
             number: year,
             related_iso: None,
+            cyclic: None,
         }
     } else {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "bd")),
             number: 1 - year,
             related_iso: None,
+            cyclic: None,
         }
     }
 }
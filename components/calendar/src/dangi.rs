@@ -0,0 +1,282 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Korean Dangi calendar.
+//!
+//! ```rust
+//! use icu::calendar::{dangi::Dangi, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(2023, 6, 23)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_dangi = Date::new_from_iso(date_iso, Dangi);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(2023, 6, 23, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_dangi = DateTime::new_from_iso(datetime_iso, Dangi);
+//!
+//! // `Date` checks
+//! assert_eq!(date_dangi.year().number, 4356);
+//! assert_eq!(date_dangi.year().related_iso, Some(2023));
+//! assert_eq!(date_dangi.year().cyclic.map(|c| c.get()), Some(40));
+//! assert_eq!(date_dangi.month().ordinal, 6);
+//! assert_eq!(date_dangi.month().code.0, "M05");
+//! assert_eq!(date_dangi.day_of_month().0, 6);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_dangi.date.year().number, 4356);
+//! assert_eq!(datetime_dangi.date.month().ordinal, 6);
+//! assert_eq!(datetime_dangi.date.day_of_month().0, 6);
+//! assert_eq!(datetime_dangi.time.hour.number(), 13);
+//! assert_eq!(datetime_dangi.time.minute.number(), 1);
+//! assert_eq!(datetime_dangi.time.second.number(), 0);
+//! ```
+//!
+//! Years are counted from 2333 BCE, the traditional founding of Gojoseon, so the year starting in ISO year 2023 is 4356.
+//! The ISO year in which a year starts is available as
+//! [`FormattableYear::related_iso`](crate::types::FormattableYear::related_iso), and its
+//! position in the 60-year sexagenary cycle as
+//! [`FormattableYear::cyclic`](crate::types::FormattableYear::cyclic).
+//!
+//! Years have 12 or 13 months. A leap month has the month code of the month before it
+//! followed by `"L"`; for example, in 4356 the 3rd month is the leap month `"M02L"`.
+
+use crate::astronomy::Location;
+use crate::chinese_based::{self, ChineseBased, ChineseBasedYearInfo};
+use crate::iso::Iso;
+use crate::{types, ArithmeticDate, Date, DateTime, DateTimeError};
+
+/// The Korean Dangi calendar
+///
+/// This is the astronomical Korean Dangi calendar, with months starting on the day of the
+/// new moon in the standard time of Beijing.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Dangi;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<Dangi>
+pub struct DangiDateInner(
+    pub(crate) ArithmeticDate<Dangi>,
+    pub(crate) ChineseBasedYearInfo,
+);
+
+impl ChineseBased for Dangi {
+    fn location(date: i32) -> Location {
+        // The standard time of Seoul has changed several times; before 1908 it was
+        // local mean time, 3809/450 hours ahead of UT
+        let zone = if date < Iso::fixed_from_iso_integers(1908, 4, 1) {
+            3809.0 / 450.0
+        } else if date < Iso::fixed_from_iso_integers(1912, 1, 1) {
+            8.5
+        } else if date < Iso::fixed_from_iso_integers(1954, 3, 21) {
+            9.0
+        } else if date < Iso::fixed_from_iso_integers(1961, 8, 10) {
+            8.5
+        } else {
+            9.0
+        };
+        Location {
+            latitude: 37.0 + 34.0 / 60.0,
+            longitude: 126.0 + 58.0 / 60.0,
+            zone: zone / 24.0,
+        }
+    }
+
+    const RELATED_ISO_OFFSET: i32 = -2333;
+}
+
+chinese_based::impl_chinese_based!(Dangi, DangiDateInner, "dangi");
+
+impl Date<Dangi> {
+    /// Construct new Dangi Date.
+    ///
+    /// The month is the ordinal month, so in a year with a leap month the months after
+    /// the leap month have an ordinal one larger than their number.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_dangi =
+    ///     Date::new_dangi_date(4356, 3, 1).expect("Failed to initialize Dangi Date instance.");
+    ///
+    /// assert_eq!(date_dangi.year().number, 4356);
+    /// assert_eq!(date_dangi.month().ordinal, 3);
+    /// assert_eq!(date_dangi.month().code.0, "M02L");
+    /// assert_eq!(date_dangi.day_of_month().0, 1);
+    /// ```
+    pub fn new_dangi_date(year: i32, month: u8, day: u8) -> Result<Date<Dangi>, DateTimeError> {
+        let (date, info) = chinese_based::new_date::<Dangi>(year, month, day)?;
+        Ok(Date::from_raw(DangiDateInner(date, info), Dangi))
+    }
+}
+
+impl DateTime<Dangi> {
+    /// Construct a new Dangi datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_dangi = DateTime::new_dangi_datetime(4356, 6, 6, 13, 1, 0)
+    ///     .expect("Failed to initialize Dangi DateTime instance.");
+    ///
+    /// assert_eq!(datetime_dangi.date.year().number, 4356);
+    /// assert_eq!(datetime_dangi.date.month().ordinal, 6);
+    /// assert_eq!(datetime_dangi.date.day_of_month().0, 6);
+    /// assert_eq!(datetime_dangi.time.hour.number(), 13);
+    /// assert_eq!(datetime_dangi.time.minute.number(), 1);
+    /// assert_eq!(datetime_dangi.time.second.number(), 0);
+    /// ```
+    pub fn new_dangi_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Dangi>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_dangi_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DateDuration;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_new_year() {
+        // (related ISO year, ISO date of the new year, ordinal of the leap month)
+        let cases = [
+            (1900, (1900, 1, 31), Some(9)),
+            (1916, (1916, 2, 4), None),
+            (1997, (1997, 2, 8), None),
+            (2000, (2000, 2, 5), None),
+            (2017, (2017, 1, 28), Some(6)),
+            (2020, (2020, 1, 25), Some(5)),
+            (2023, (2023, 1, 22), Some(3)),
+            (2024, (2024, 2, 10), None),
+            (2025, (2025, 1, 29), Some(7)),
+            (2028, (2028, 1, 27), Some(6)),
+        ];
+        for (related_iso, (y, m, d), leap_month) in cases {
+            let year = related_iso - Dangi::RELATED_ISO_OFFSET;
+            let iso = Date::new_iso_date(y, m, d).unwrap();
+            let date = Date::new_from_iso(iso.clone(), Dangi);
+            assert_eq!(date.year().number, year, "{}", related_iso);
+            assert_eq!(date.year().related_iso, Some(related_iso));
+            assert_eq!(date.month().ordinal, 1, "{}", related_iso);
+            assert_eq!(date.day_of_month().0, 1, "{}", related_iso);
+            assert_eq!(
+                date.months_in_year(),
+                if leap_month.is_some() { 13 } else { 12 },
+                "{}",
+                related_iso
+            );
+            if let Some(leap_month) = leap_month {
+                let leap = Date::new_dangi_date(year, leap_month, 1).unwrap();
+                assert!(leap.month().code.0.ends_with('L'), "{}", related_iso);
+            }
+            let day_before = Date::new_from_iso(
+                Iso::iso_from_fixed(Iso::fixed_from_iso(*iso.inner()) - 1),
+                Dangi,
+            );
+            assert_eq!(day_before.year().number, year - 1, "{}", related_iso);
+            assert_eq!(
+                day_before.month().ordinal,
+                day_before.months_in_year() as u32
+            );
+        }
+    }
+
+    #[test]
+    fn test_month_codes() {
+        // 4356 has a leap month after the 2nd month
+        let expected = [
+            "M01", "M02", "M02L", "M03", "M04", "M05", "M06", "M07", "M08", "M09", "M10", "M11",
+            "M12",
+        ];
+        for (i, code) in expected.iter().enumerate() {
+            let date = Date::new_dangi_date(4356, i as u8 + 1, 1).unwrap();
+            assert_eq!(date.month().code.0, *code);
+            let from_codes = Date::new_from_codes(
+                types::Era(tinystr!(16, "dangi")),
+                4356,
+                date.month().code,
+                1,
+                Dangi,
+            )
+            .unwrap();
+            assert_eq!(from_codes, date);
+        }
+        // 4357 has no leap month
+        assert_eq!(
+            Date::new_from_codes(
+                types::Era(tinystr!(16, "dangi")),
+                4357,
+                types::MonthCode(tinystr!(4, "M02L")),
+                1,
+                Dangi,
+            ),
+            Err(DateTimeError::UnknownMonthCode(
+                tinystr!(4, "M02L"),
+                "Dangi"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cyclic_year() {
+        // 1984 is the first year of the current cycle
+        let cases = [
+            (1983, 60),
+            (1984, 1),
+            (2000, 17),
+            (2023, 40),
+            (2043, 60),
+            (2044, 1),
+        ];
+        for (related_iso, cyclic) in cases {
+            let year =
+                Date::new_from_iso(Date::new_iso_date(related_iso, 7, 1).unwrap(), Dangi).year();
+            assert_eq!(
+                year.cyclic.map(|c| c.get()),
+                Some(cyclic),
+                "{}",
+                related_iso
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut fixed = Iso::fixed_from_iso_integers(2019, 12, 1);
+        let end = Iso::fixed_from_iso_integers(2021, 3, 1);
+        while fixed < end {
+            let iso = Iso::iso_from_fixed(fixed);
+            let dangi = Date::new_from_iso(iso.clone(), Dangi);
+            assert_eq!(dangi.to_iso(), iso);
+            fixed += 1;
+        }
+    }
+
+    #[test]
+    fn test_offset() {
+        // A leap month maps to the month with the same number in a year without one
+        let mut date = Date::new_dangi_date(4356, 3, 15).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date.year().number, 4357);
+        assert_eq!(date.month().code.0, "M02");
+        assert_eq!(date.day_of_month().0, 15);
+
+        let mut date = Date::new_dangi_date(4356, 1, 1).unwrap();
+        date.add(DateDuration::new(0, 0, 0, 384));
+        assert_eq!(date, Date::new_dangi_date(4357, 1, 1).unwrap());
+    }
+}
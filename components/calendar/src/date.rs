@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IncludedInAnyCalendar};
+use crate::arithmetic::week_of;
 use crate::{types, Calendar, DateDuration, DateDurationUnit, DateTimeError, Iso};
 use alloc::rc::Rc;
 use core::fmt;
@@ -216,6 +217,90 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().day_of_year_info(&self.inner)
     }
 
+    /// The week of the year that contains this date, according to the week rules of `calendar`.
+    ///
+    /// The week can be assigned to the previous or the next year, in which case the week number
+    /// refers to that year, see [`Self::week_year()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::arithmetic::week_of::{CalendarInfo, RelativeUnit};
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::new_iso_date(2021, 1, 1).unwrap();
+    /// let week_of = date.week_of_year(&CalendarInfo::ISO_8601).unwrap();
+    ///
+    /// assert_eq!(week_of.week, 53);
+    /// assert_eq!(week_of.unit, RelativeUnit::Previous);
+    /// ```
+    pub fn week_of_year(
+        &self,
+        calendar: &week_of::CalendarInfo,
+    ) -> Result<week_of::WeekOf, DateTimeError> {
+        let day_of_year_info = self.day_of_year_info();
+        week_of::week_of(
+            calendar,
+            day_of_year_info.days_in_prev_year as u16,
+            day_of_year_info.days_in_year as u16,
+            day_of_year_info.day_of_year as u16,
+            self.day_of_week(),
+        )
+    }
+
+    /// The year that the week of this date is assigned to, according to the week rules of `calendar`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::arithmetic::week_of::CalendarInfo;
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::new_iso_date(2019, 12, 30).unwrap();
+    ///
+    /// assert_eq!(date.week_year(&CalendarInfo::ISO_8601).unwrap().number, 2020);
+    /// ```
+    pub fn week_year(
+        &self,
+        calendar: &week_of::CalendarInfo,
+    ) -> Result<types::FormattableYear, DateTimeError> {
+        Ok(match self.week_of_year(calendar)?.unit {
+            week_of::RelativeUnit::Previous => self.day_of_year_info().prev_year,
+            week_of::RelativeUnit::Current => self.year(),
+            week_of::RelativeUnit::Next => self.day_of_year_info().next_year,
+        })
+    }
+
+    /// The [ISO 8601 week date](week_of::IsoWeekDate) of this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::new_iso_date(2021, 1, 1).unwrap();
+    ///
+    /// assert_eq!(date.iso_week_date().to_string(), "2020-W53-5");
+    /// ```
+    pub fn iso_week_date(&self) -> week_of::IsoWeekDate {
+        let iso = self.to_iso();
+        let calendar = week_of::CalendarInfo::ISO_8601;
+        #[allow(clippy::expect_used)] // ISO years are always long enough to compute their weeks
+        let week_of = iso
+            .week_of_year(&calendar)
+            .expect("ISO years have more than MIN_UNIT_DAYS days");
+        let year = match week_of.unit {
+            week_of::RelativeUnit::Previous => iso.inner.0.year - 1,
+            week_of::RelativeUnit::Current => iso.inner.0.year,
+            week_of::RelativeUnit::Next => iso.inner.0.year + 1,
+        };
+        week_of::IsoWeekDate {
+            year,
+            week: week_of.week,
+            weekday: iso.day_of_week(),
+        }
+    }
+
     /// Construct a date from raw values for a given calendar. This does not check any
     /// invariants for the date and calendar, and should only be called by calendar implementations.
     ///
@@ -3,8 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IncludedInAnyCalendar};
+use crate::duration::{time_unit_nanoseconds, NANOSECONDS_IN_DAY};
 use crate::types::{self, Time};
-use crate::{AsCalendar, Calendar, Date, DateTimeError, Iso};
+use crate::{
+    AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTimeDuration,
+    DateTimeDurationUnit, DateTimeError, Iso, Ref, TimeDuration, TimeDurationUnit,
+};
+use core::cmp::Ordering;
 
 /// A date+time for a given calendar.
 ///
@@ -80,6 +85,201 @@ impl<A: AsCalendar> DateTime<A> {
             time: self.time,
         }
     }
+
+    /// Add a `duration` to this datetime, mutating it
+    ///
+    /// The time part of the duration is added first, and any whole days it overflows
+    /// by are added to the date along with the date part of the duration.
+    ///
+    /// Currently unstable for ICU4X 1.0
+    #[doc(hidden)]
+    pub fn add(&mut self, duration: DateTimeDuration<A::Calendar>) {
+        let overflow_days = self.time.add(duration.time);
+        let mut date_duration = duration.date;
+        date_duration.days = (date_duration.days as i64 + overflow_days)
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        self.date.add(date_duration);
+    }
+
+    /// Add a `duration` to this datetime, returning the new one
+    ///
+    /// Currently unstable for ICU4X 1.0
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, DateTimeDuration, TimeDuration};
+    ///
+    /// let now = DateTime::new_iso_datetime(2022, 12, 31, 20, 30, 0).unwrap();
+    /// let deadline = now.added(DateTimeDuration::new(
+    ///     Default::default(),
+    ///     TimeDuration::new(36, 15, 0, 0),
+    /// ));
+    ///
+    /// assert_eq!(
+    ///     deadline,
+    ///     DateTime::new_iso_datetime(2023, 1, 2, 8, 45, 0).unwrap()
+    /// );
+    /// ```
+    #[doc(hidden)]
+    pub fn added(mut self, duration: DateTimeDuration<A::Calendar>) -> Self {
+        self.add(duration);
+        self
+    }
+
+    /// Calculating the duration between `other - self`
+    ///
+    /// If `largest_unit` is a time unit, the whole duration is expressed in time units.
+    /// Otherwise the time part is at most 24 hours. All fields of the result have the
+    /// same sign, and units smaller than `smallest_unit` are truncated.
+    ///
+    /// Currently unstable for ICU4X 1.0
+    ///
+    /// ```rust
+    /// use icu::calendar::{
+    ///     DateDurationUnit, DateTime, TimeDuration, TimeDurationUnit,
+    /// };
+    ///
+    /// let start = DateTime::new_iso_datetime(2022, 12, 31, 20, 30, 0).unwrap();
+    /// let end = DateTime::new_iso_datetime(2023, 1, 2, 8, 45, 10).unwrap();
+    ///
+    /// let duration = start.until(
+    ///     &end,
+    ///     DateDurationUnit::Days.into(),
+    ///     TimeDurationUnit::Minutes.into(),
+    /// );
+    /// assert_eq!(duration.date.days, 1);
+    /// assert_eq!(duration.time, TimeDuration::new(12, 15, 0, 0));
+    ///
+    /// let duration = start.until(
+    ///     &end,
+    ///     TimeDurationUnit::Hours.into(),
+    ///     TimeDurationUnit::Seconds.into(),
+    /// );
+    /// assert_eq!(duration.date.days, 0);
+    /// assert_eq!(duration.time, TimeDuration::new(36, 15, 10, 0));
+    /// ```
+    #[doc(hidden)]
+    pub fn until<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &DateTime<B>,
+        largest_unit: DateTimeDurationUnit,
+        smallest_unit: DateTimeDurationUnit,
+    ) -> DateTimeDuration<A::Calendar> {
+        let day = NANOSECONDS_IN_DAY as i128;
+        let mut time_difference = other.time.nanoseconds_since_midnight() as i128
+            - self.time.nanoseconds_since_midnight() as i128;
+        let time_sign = time_difference.signum();
+        let date_sign = (Iso::fixed_from_iso(*other.date.to_iso().inner()) as i128
+            - Iso::fixed_from_iso(*self.date.to_iso().inner()) as i128)
+            .signum();
+
+        // If the times point the opposite way to the dates, borrow a day from the
+        // dates so that all fields of the result have the same sign
+        let mut adjusted = Date::from_raw(other.date.inner().clone(), Ref(other.date.calendar()));
+        if time_sign != 0 && time_sign == -date_sign {
+            adjusted.add(DateDuration::new(0, 0, 0, time_sign as i32));
+            time_difference -= time_sign * day;
+        }
+
+        let (date_largest, time_largest) = match largest_unit {
+            DateTimeDurationUnit::Date(unit) => (unit, TimeDurationUnit::Hours),
+            DateTimeDurationUnit::Time(unit) => (DateDurationUnit::Days, unit),
+        };
+        let date_smallest = match smallest_unit {
+            DateTimeDurationUnit::Date(unit) => unit,
+            DateTimeDurationUnit::Time(_) => DateDurationUnit::Days,
+        };
+        let mut date = self.date.until(&adjusted, date_largest, date_smallest);
+        if let DateTimeDurationUnit::Time(_) = largest_unit {
+            time_difference += date.days as i128 * day;
+            date.days = 0;
+        }
+
+        let time = match smallest_unit {
+            DateTimeDurationUnit::Date(_) => TimeDuration::default(),
+            DateTimeDurationUnit::Time(unit) => {
+                TimeDuration::from_nanoseconds(time_difference, time_largest).truncated(unit)
+            }
+        };
+        DateTimeDuration::new(date, time)
+    }
+
+    /// Round this datetime to the nearest multiple of `unit`, mutating it
+    ///
+    /// Halfway values are rounded up, overflowing into the next day if necessary.
+    ///
+    /// Currently unstable for ICU4X 1.0
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, TimeDurationUnit};
+    ///
+    /// let mut datetime = DateTime::new_iso_datetime(2022, 12, 31, 11, 29, 30).unwrap();
+    /// datetime.round(TimeDurationUnit::Minutes);
+    ///
+    /// assert_eq!(
+    ///     datetime,
+    ///     DateTime::new_iso_datetime(2022, 12, 31, 11, 30, 0).unwrap()
+    /// );
+    /// ```
+    #[doc(hidden)]
+    pub fn round(&mut self, unit: TimeDurationUnit) {
+        let size = time_unit_nanoseconds(unit);
+        let nanoseconds = self.time.nanoseconds_since_midnight();
+        let rounded = (nanoseconds + size / 2).div_euclid(size) * size;
+        self.add(DateTimeDuration::new(
+            DateDuration::default(),
+            TimeDuration::new(0, 0, 0, rounded - nanoseconds),
+        ));
+    }
+
+    /// Round this datetime to the nearest multiple of `unit`, returning the new one
+    ///
+    /// Halfway values are rounded up, overflowing into the next day if necessary.
+    ///
+    /// Currently unstable for ICU4X 1.0
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, TimeDurationUnit};
+    ///
+    /// let datetime = DateTime::new_iso_datetime(2022, 12, 31, 23, 30, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     datetime.rounded(TimeDurationUnit::Hours),
+    ///     DateTime::new_iso_datetime(2023, 1, 1, 0, 0, 0).unwrap()
+    /// );
+    /// ```
+    #[doc(hidden)]
+    pub fn rounded(mut self, unit: TimeDurationUnit) -> Self {
+        self.round(unit);
+        self
+    }
+
+    /// Compare this datetime with a datetime in any calendar, by converting both to ISO
+    ///
+    /// Currently unstable for ICU4X 1.0
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use icu::calendar::{buddhist::Buddhist, DateTime};
+    ///
+    /// let iso = DateTime::new_iso_datetime(2022, 12, 31, 12, 0, 0).unwrap();
+    /// let buddhist = DateTime::new_from_iso(iso.clone(), Buddhist);
+    ///
+    /// assert_eq!(iso.cmp_iso(&buddhist), Ordering::Equal);
+    /// assert_eq!(
+    ///     DateTime::new_iso_datetime(2023, 1, 1, 0, 0, 0)
+    ///         .unwrap()
+    ///         .cmp_iso(&buddhist),
+    ///     Ordering::Greater
+    /// );
+    /// ```
+    #[doc(hidden)]
+    pub fn cmp_iso<B: AsCalendar>(&self, other: &DateTime<B>) -> Ordering {
+        let fixed = Iso::fixed_from_iso(*self.date.to_iso().inner());
+        let other_fixed = Iso::fixed_from_iso(*other.date.to_iso().inner());
+        fixed
+            .cmp(&other_fixed)
+            .then_with(|| self.time.cmp(&other.time))
+    }
 }
 
 impl<C: IncludedInAnyCalendar, A: AsCalendar<Calendar = C>> DateTime<A> {
@@ -114,3 +314,75 @@ impl<A: AsCalendar + Clone> Clone for DateTime<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_until_added_roundtrip() {
+        let datetimes = [
+            DateTime::new_iso_datetime(2020, 2, 29, 0, 0, 0).unwrap(),
+            DateTime::new_iso_datetime(2020, 3, 1, 23, 59, 59).unwrap(),
+            DateTime::new_iso_datetime(2021, 1, 30, 23, 0, 0).unwrap(),
+            DateTime::new_iso_datetime(2021, 1, 31, 12, 30, 0).unwrap(),
+            DateTime::new_iso_datetime(2021, 3, 1, 1, 0, 0).unwrap(),
+            DateTime::new_iso_datetime(2022, 12, 31, 20, 30, 0).unwrap(),
+            DateTime::new_iso_datetime(2023, 1, 2, 8, 45, 10).unwrap(),
+        ];
+        let largest_units = [
+            DateDurationUnit::Years.into(),
+            DateDurationUnit::Months.into(),
+            DateDurationUnit::Weeks.into(),
+            DateDurationUnit::Days.into(),
+            TimeDurationUnit::Hours.into(),
+            TimeDurationUnit::Minutes.into(),
+            TimeDurationUnit::Seconds.into(),
+            TimeDurationUnit::Nanoseconds.into(),
+        ];
+        for start in &datetimes {
+            for end in &datetimes {
+                for largest_unit in largest_units {
+                    let duration =
+                        start.until(end, largest_unit, TimeDurationUnit::Nanoseconds.into());
+                    let fields = [
+                        duration.date.years as i64,
+                        duration.date.months as i64,
+                        duration.date.weeks as i64,
+                        duration.date.days as i64,
+                        duration.time.hours,
+                        duration.time.minutes,
+                        duration.time.seconds,
+                        duration.time.nanoseconds,
+                    ];
+                    assert!(
+                        fields.iter().all(|f| *f >= 0) || fields.iter().all(|f| *f <= 0),
+                        "{:?} {:?} {:?}",
+                        start,
+                        end,
+                        duration
+                    );
+                    assert_eq!(&start.clone().added(duration), end, "{:?}", largest_unit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round() {
+        let datetime = DateTime::new_iso_datetime(2022, 12, 31, 11, 29, 30).unwrap();
+        let cases = [
+            (TimeDurationUnit::Hours, (2022, 12, 31, 11, 0, 0)),
+            (TimeDurationUnit::Minutes, (2022, 12, 31, 11, 30, 0)),
+            (TimeDurationUnit::Seconds, (2022, 12, 31, 11, 29, 30)),
+        ];
+        for (unit, (y, mo, d, h, mi, s)) in cases {
+            assert_eq!(
+                datetime.clone().rounded(unit),
+                DateTime::new_iso_datetime(y, mo, d, h, mi, s).unwrap(),
+                "{:?}",
+                unit
+            );
+        }
+    }
+}
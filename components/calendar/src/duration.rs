@@ -24,7 +24,7 @@ pub struct DateDuration<C: Calendar + ?Sized> {
 
 /// A "duration unit" used to specify the minimum or maximum duration of time to
 /// care about
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 #[allow(clippy::exhaustive_enums)] // this type should be stable
 pub enum DateDurationUnit {
     /// Duration in years
@@ -89,3 +89,215 @@ impl<C: Calendar> fmt::Debug for DateDuration<C> {
             .finish()
     }
 }
+
+/// The number of nanoseconds in a day (ignoring leap seconds)
+pub(crate) const NANOSECONDS_IN_DAY: i64 = 24 * NANOSECONDS_IN_HOUR;
+const NANOSECONDS_IN_HOUR: i64 = 60 * NANOSECONDS_IN_MINUTE;
+const NANOSECONDS_IN_MINUTE: i64 = 60 * NANOSECONDS_IN_SECOND;
+const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash)]
+/// A duration of clock time
+///
+/// The fields are not required to be balanced or to have the same sign: a duration of
+/// 36 hours and -15 minutes is valid. When it is added to a [`DateTime`](crate::DateTime),
+/// whole days overflow into the date.
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+pub struct TimeDuration {
+    /// The number of hours
+    pub hours: i64,
+    /// The number of minutes
+    pub minutes: i64,
+    /// The number of seconds
+    pub seconds: i64,
+    /// The number of nanoseconds
+    pub nanoseconds: i64,
+}
+
+/// A "duration unit" for durations of clock time, used to specify the minimum or
+/// maximum duration of time to care about
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[allow(clippy::exhaustive_enums)] // this type should be stable
+pub enum TimeDurationUnit {
+    /// Duration in hours
+    Hours,
+    /// Duration in minutes
+    Minutes,
+    /// Duration in seconds
+    Seconds,
+    /// Duration in nanoseconds
+    Nanoseconds,
+}
+
+impl TimeDuration {
+    /// Construct a TimeDuration
+    ///
+    /// ```rust
+    /// # use icu_calendar::*;
+    /// // 36 hours and 15 minutes
+    /// let duration = TimeDuration::new(36, 15, 0, 0);
+    /// ```
+    pub fn new(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        TimeDuration {
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        }
+    }
+
+    /// The total length of this duration in nanoseconds
+    ///
+    /// ```rust
+    /// # use icu_calendar::*;
+    /// assert_eq!(TimeDuration::new(1, -30, 0, 5).total_nanoseconds(), 1_800_000_000_005);
+    /// ```
+    pub fn total_nanoseconds(&self) -> i128 {
+        self.hours as i128 * NANOSECONDS_IN_HOUR as i128
+            + self.minutes as i128 * NANOSECONDS_IN_MINUTE as i128
+            + self.seconds as i128 * NANOSECONDS_IN_SECOND as i128
+            + self.nanoseconds as i128
+    }
+
+    /// Construct a balanced duration of `nanoseconds`, in which all fields have the same
+    /// sign and no unit smaller than `largest_unit` exceeds the size of the next larger one.
+    ///
+    /// ```rust
+    /// # use icu_calendar::*;
+    /// let duration = TimeDuration::from_nanoseconds(-5_400_000_000_000, TimeDurationUnit::Hours);
+    /// assert_eq!(duration, TimeDuration::new(-1, -30, 0, 0));
+    ///
+    /// let duration = TimeDuration::from_nanoseconds(5_400_000_000_000, TimeDurationUnit::Seconds);
+    /// assert_eq!(duration, TimeDuration::new(0, 0, 5400, 0));
+    /// ```
+    pub fn from_nanoseconds(nanoseconds: i128, largest_unit: TimeDurationUnit) -> Self {
+        let sign = if nanoseconds < 0 { -1 } else { 1 };
+        let mut remainder = nanoseconds.abs();
+        let mut take = |unit: TimeDurationUnit, size: i64| -> i64 {
+            if time_unit_rank(unit) > time_unit_rank(largest_unit) {
+                return 0;
+            }
+            let value = remainder / size as i128;
+            remainder %= size as i128;
+            // Saturate rather than wrap for durations longer than ~292 years of nanoseconds
+            sign * value.min(i64::MAX as i128) as i64
+        };
+        let hours = take(TimeDurationUnit::Hours, NANOSECONDS_IN_HOUR);
+        let minutes = take(TimeDurationUnit::Minutes, NANOSECONDS_IN_MINUTE);
+        let seconds = take(TimeDurationUnit::Seconds, NANOSECONDS_IN_SECOND);
+        let nanoseconds = take(TimeDurationUnit::Nanoseconds, 1);
+        TimeDuration::new(hours, minutes, seconds, nanoseconds)
+    }
+
+    /// Truncate this duration to a multiple of `smallest_unit`, setting all smaller
+    /// fields to zero
+    pub(crate) fn truncated(self, smallest_unit: TimeDurationUnit) -> Self {
+        let rank = time_unit_rank(smallest_unit);
+        let keep = |unit: TimeDurationUnit, value: i64| {
+            if time_unit_rank(unit) >= rank {
+                value
+            } else {
+                0
+            }
+        };
+        TimeDuration::new(
+            self.hours,
+            keep(TimeDurationUnit::Minutes, self.minutes),
+            keep(TimeDurationUnit::Seconds, self.seconds),
+            keep(TimeDurationUnit::Nanoseconds, self.nanoseconds),
+        )
+    }
+}
+
+/// Ordering of [`TimeDurationUnit`]s from smallest to largest
+fn time_unit_rank(unit: TimeDurationUnit) -> u8 {
+    match unit {
+        TimeDurationUnit::Nanoseconds => 0,
+        TimeDurationUnit::Seconds => 1,
+        TimeDurationUnit::Minutes => 2,
+        TimeDurationUnit::Hours => 3,
+    }
+}
+
+/// The length of `unit` in nanoseconds
+pub(crate) fn time_unit_nanoseconds(unit: TimeDurationUnit) -> i64 {
+    match unit {
+        TimeDurationUnit::Nanoseconds => 1,
+        TimeDurationUnit::Seconds => NANOSECONDS_IN_SECOND,
+        TimeDurationUnit::Minutes => NANOSECONDS_IN_MINUTE,
+        TimeDurationUnit::Hours => NANOSECONDS_IN_HOUR,
+    }
+}
+
+/// A duration between two datetimes, consisting of a date part and a clock time part
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+pub struct DateTimeDuration<C: Calendar + ?Sized> {
+    /// The years, months, weeks and days
+    pub date: DateDuration<C>,
+    /// The hours, minutes, seconds and nanoseconds
+    pub time: TimeDuration,
+}
+
+/// A "duration unit" for durations between datetimes, used to specify the minimum or
+/// maximum duration of time to care about
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[allow(clippy::exhaustive_enums)] // this type should be stable
+pub enum DateTimeDurationUnit {
+    /// A unit of a date duration
+    Date(DateDurationUnit),
+    /// A unit of a time duration
+    Time(TimeDurationUnit),
+}
+
+impl From<DateDurationUnit> for DateTimeDurationUnit {
+    fn from(unit: DateDurationUnit) -> Self {
+        DateTimeDurationUnit::Date(unit)
+    }
+}
+
+impl From<TimeDurationUnit> for DateTimeDurationUnit {
+    fn from(unit: TimeDurationUnit) -> Self {
+        DateTimeDurationUnit::Time(unit)
+    }
+}
+
+impl<C: Calendar + ?Sized> Default for DateTimeDuration<C> {
+    fn default() -> Self {
+        Self {
+            date: DateDuration::default(),
+            time: TimeDuration::default(),
+        }
+    }
+}
+
+impl<C: Calendar + ?Sized> DateTimeDuration<C> {
+    /// Construct a DateTimeDuration
+    ///
+    /// ```rust
+    /// # use icu_calendar::*;
+    /// // two days, 36 hours and 15 minutes
+    /// let duration: DateTimeDuration<Iso> =
+    ///     DateTimeDuration::new(DateDuration::new(0, 0, 0, 2), TimeDuration::new(36, 15, 0, 0));
+    /// ```
+    pub fn new(date: DateDuration<C>, time: TimeDuration) -> Self {
+        DateTimeDuration { date, time }
+    }
+
+    /// Explicitly cast duration to one for a different calendar
+    pub fn cast_unit<C2: Calendar + ?Sized>(self) -> DateTimeDuration<C2> {
+        DateTimeDuration {
+            date: self.date.cast_unit(),
+            time: self.time,
+        }
+    }
+}
+
+impl<C: Calendar> fmt::Debug for DateTimeDuration<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("DateTimeDuration")
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .finish()
+    }
+}
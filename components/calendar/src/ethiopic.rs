@@ -79,7 +79,7 @@ impl CalendarArithmetic for Ethiopic {
         }
     }
 
-    fn months_for_every_year() -> u8 {
+    fn months_for_every_year(_: i32) -> u8 {
         13
     }
 
@@ -260,18 +260,21 @@ impl Ethiopic {
                 era: types::Era(tinystr!(16, "mundi")),
                 number: year + AMETE_ALEM_OFFSET,
                 related_iso: None,
+                cyclic: None,
             }
         } else if year > 0 {
             types::FormattableYear {
                 era: types::Era(tinystr!(16, "incar")),
                 number: year,
                 related_iso: None,
+                cyclic: None,
             }
         } else {
             types::FormattableYear {
                 era: types::Era(tinystr!(16, "pre-incar")),
                 number: 1 - year,
                 related_iso: None,
+                cyclic: None,
             }
         }
     }
@@ -214,12 +214,14 @@ pub fn year_as_gregorian(year: i32) -> types::FormattableYear {
             era: types::Era(tinystr!(16, "ce")),
             number: year,
             related_iso: None,
+            cyclic: None,
         }
     } else {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "bce")),
             number: 1 - year,
             related_iso: None,
+            cyclic: None,
         }
     }
 }
@@ -0,0 +1,510 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hebrew calendar.
+//!
+//! ```rust
+//! use icu::calendar::{hebrew::Hebrew, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_hebrew = Date::new_from_iso(date_iso, Hebrew);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_hebrew = DateTime::new_from_iso(datetime_iso, Hebrew);
+//!
+//! // `Date` checks
+//! assert_eq!(date_hebrew.year().number, 5730);
+//! assert_eq!(date_hebrew.month().ordinal, 4);
+//! assert_eq!(date_hebrew.day_of_month().0, 24);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_hebrew.date.year().number, 5730);
+//! assert_eq!(datetime_hebrew.date.month().ordinal, 4);
+//! assert_eq!(datetime_hebrew.date.day_of_month().0, 24);
+//! assert_eq!(datetime_hebrew.time.hour.number(), 13);
+//! assert_eq!(datetime_hebrew.time.minute.number(), 1);
+//! assert_eq!(datetime_hebrew.time.second.number(), 0);
+//! ```
+//!
+//! Months are numbered from Tishri, the first month of the civil year. In leap years
+//! the 6th month is Adar I (month code `"M05L"`) and the 7th month is Adar II (month
+//! code `"M06"`, the same as Adar in common years), so that month codes stay
+//! stable across leap and common years while ordinals do not.
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::ordinal_solar_month_from_code;
+use crate::iso::Iso;
+use crate::{
+    types, ArithmeticDate, Calendar, CalendarArithmetic, Date, DateDuration, DateDurationUnit,
+    DateTime, DateTimeError,
+};
+use tinystr::tinystr;
+
+// The Hebrew epoch, 1 Tishri AM 1, is October 7th of 3761 BCE in the proleptic Julian calendar
+const HEBREW_EPOCH: i32 = -1373427;
+
+/// The Hebrew calendar
+///
+/// This is the arithmetical Hebrew calendar, in which year lengths are determined by the
+/// molad (mean conjunction) of Tishri and the postponement rules.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Hebrew;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<Hebrew>
+pub struct HebrewDateInner(pub(crate) ArithmeticDate<Hebrew>);
+
+impl CalendarArithmetic for Hebrew {
+    fn month_days(year: i32, month: u8) -> u8 {
+        // Map the ordinal onto the months of a leap year, in which Adar I is the 6th month
+        let month = if !Self::is_leap_year(year) && month >= 6 {
+            month + 1
+        } else {
+            month
+        };
+        match month {
+            // Heshvan
+            2 => {
+                if Self::days_in_year_direct(year) % 10 == 5 {
+                    30
+                } else {
+                    29
+                }
+            }
+            // Kislev
+            3 => {
+                if Self::days_in_year_direct(year) % 10 == 3 {
+                    29
+                } else {
+                    30
+                }
+            }
+            // Tishri, Shevat, Adar I, Nisan, Sivan, Av
+            1 | 5 | 6 | 8 | 10 | 12 => 30,
+            // Tevet, Adar (II), Iyar, Tammuz, Elul
+            4 | 7 | 9 | 11 | 13 => 29,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        if Self::is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        Self::days_in_year_direct(year)
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (7 * year as i64 + 1).rem_euclid(19) < 7
+    }
+
+    fn month_in_offset_year(old_year: i32, month: u8, new_year: i32) -> u8 {
+        match (Self::is_leap_year(old_year), Self::is_leap_year(new_year)) {
+            // Adar I and Adar II both become Adar
+            (true, false) if month >= 7 => month - 1,
+            // Adar becomes Adar II
+            (false, true) if month >= 6 => month + 1,
+            _ => month,
+        }
+    }
+}
+
+impl Calendar for Hebrew {
+    type DateInner = HebrewDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "am") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let month = if month_code.0 == tinystr!(4, "M05L") {
+            if Self::is_leap_year(year) {
+                Some(6)
+            } else {
+                None
+            }
+        } else {
+            ordinal_solar_month_from_code(month_code)
+                .filter(|&m| m <= 12)
+                .map(|m| {
+                    if m >= 6 && Self::is_leap_year(year) {
+                        m + 1
+                    } else {
+                        m
+                    }
+                })
+        };
+        let month = if let Some(month) = month {
+            month
+        } else {
+            return Err(DateTimeError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ));
+        };
+
+        if day > Self::month_days(year, month) {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(HebrewDateInner(ArithmeticDate::new(year, month, day)))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> HebrewDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::hebrew_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_hebrew = Self::fixed_from_hebrew(date.0);
+        Iso::iso_from_fixed(fixed_hebrew)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Hebrew.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_hebrew(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        if !Self::is_leap_year(date.0.year) {
+            return date.0.solar_month();
+        }
+        let code = match date.0.month {
+            1 => tinystr!(4, "M01"),
+            2 => tinystr!(4, "M02"),
+            3 => tinystr!(4, "M03"),
+            4 => tinystr!(4, "M04"),
+            5 => tinystr!(4, "M05"),
+            6 => tinystr!(4, "M05L"),
+            7 => tinystr!(4, "M06"),
+            8 => tinystr!(4, "M07"),
+            9 => tinystr!(4, "M08"),
+            10 => tinystr!(4, "M09"),
+            11 => tinystr!(4, "M10"),
+            12 => tinystr!(4, "M11"),
+            13 => tinystr!(4, "M12"),
+            _ => tinystr!(4, "und"),
+        };
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: types::MonthCode(code),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_hebrew(prev_year),
+            days_in_prev_year: Hebrew::days_in_year_direct(prev_year),
+            next_year: year_as_hebrew(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hebrew"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Hebrew)
+    }
+}
+
+impl Hebrew {
+    // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+    // The fixed date algorithms are from
+    // Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+    //
+    // Lisp code reference: `hebrew-calendar-elapsed-days` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    //
+    // The number of days from the epoch to the molad of Tishri of `year`, postponed by
+    // a day if the molad would otherwise put Rosh Hashanah on a Sunday, Wednesday or Friday
+    fn calendar_elapsed_days(year: i32) -> i64 {
+        let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    // Lisp code reference: `hebrew-year-length-correction` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    //
+    // Further postponement of the new year so that no year is 356 or 382 days long
+    fn year_length_correction(year: i32) -> i64 {
+        let ny0 = Self::calendar_elapsed_days(year - 1);
+        let ny1 = Self::calendar_elapsed_days(year);
+        let ny2 = Self::calendar_elapsed_days(year + 1);
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Lisp code reference: `hebrew-new-year` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    fn new_year(year: i32) -> i32 {
+        (HEBREW_EPOCH as i64
+            + Self::calendar_elapsed_days(year)
+            + Self::year_length_correction(year)) as i32
+    }
+
+    /// Convenience function so we can call days_in_year without
+    /// needing to construct a full ArithmeticDate
+    ///
+    /// This is one of 353, 354 or 355 days for common years and 383, 384 or 385 days
+    /// for leap years.
+    fn days_in_year_direct(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+
+    // Lisp code reference: `fixed-from-hebrew` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    //
+    // Since months are numbered from Tishri, this is simpler than the Lisp code.
+    fn fixed_from_hebrew(date: ArithmeticDate<Hebrew>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    // Lisp code reference: `hebrew-from-fixed` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    fn hebrew_from_fixed(date: i32) -> HebrewDateInner {
+        // The average length of a year is 35975351/98496 days
+        let approx = ((date as i64 - HEBREW_EPOCH as i64) * 98496).div_euclid(35975351) + 1;
+        // The year is the last one starting at `approx - 1` whose new year is not after `date`
+        let mut year = approx as i32 - 1;
+        while Self::new_year(year + 1) <= date {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        HebrewDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl Date<Hebrew> {
+    /// Construct new Hebrew Date.
+    ///
+    /// The month is the ordinal month counted from Tishri, so in leap years
+    /// month 6 is Adar I and month 7 is Adar II.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_hebrew =
+    ///     Date::new_hebrew_date(5782, 6, 14).expect("Failed to initialize Hebrew Date instance.");
+    ///
+    /// assert_eq!(date_hebrew.year().number, 5782);
+    /// assert_eq!(date_hebrew.month().ordinal, 6);
+    /// assert_eq!(date_hebrew.month().code.0, "M05L");
+    /// assert_eq!(date_hebrew.day_of_month().0, 14);
+    /// ```
+    pub fn new_hebrew_date(year: i32, month: u8, day: u8) -> Result<Date<Hebrew>, DateTimeError> {
+        let inner = ArithmeticDate::new(year, month, day);
+
+        if month == 0 || month > inner.months_in_year() {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(HebrewDateInner(inner), Hebrew))
+    }
+}
+
+impl DateTime<Hebrew> {
+    /// Construct a new Hebrew datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_hebrew = DateTime::new_hebrew_datetime(5783, 7, 15, 13, 1, 0)
+    ///     .expect("Failed to initialize Hebrew DateTime instance.");
+    ///
+    /// assert_eq!(datetime_hebrew.date.year().number, 5783);
+    /// assert_eq!(datetime_hebrew.date.month().ordinal, 7);
+    /// assert_eq!(datetime_hebrew.date.day_of_month().0, 15);
+    /// assert_eq!(datetime_hebrew.time.hour.number(), 13);
+    /// assert_eq!(datetime_hebrew.time.minute.number(), 1);
+    /// assert_eq!(datetime_hebrew.time.second.number(), 0);
+    /// ```
+    pub fn new_hebrew_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Hebrew>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_hebrew_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_hebrew(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "am")),
+        number: year,
+        related_iso: None,
+        cyclic: None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        // (hebrew year, iso date of 1 Tishri, days in year)
+        let cases = [
+            (5730, (1969, 9, 13), 383),
+            (5781, (2020, 9, 19), 353),
+            (5782, (2021, 9, 7), 384),
+            (5783, (2022, 9, 26), 355),
+            (5784, (2023, 9, 16), 383),
+            (5785, (2024, 10, 3), 355),
+        ];
+        for (year, (iso_y, iso_m, iso_d), days) in cases {
+            let hebrew = Date::new_hebrew_date(year, 1, 1).unwrap();
+            let iso = Date::new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            assert_eq!(hebrew.to_iso(), iso, "1 Tishri {}", year);
+            assert_eq!(iso.to_calendar(Hebrew), hebrew, "1 Tishri {}", year);
+            assert_eq!(hebrew.days_in_year(), days, "days in {}", year);
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        // Purim Katan, 14 Adar I 5782
+        let adar_i = Date::new_from_iso(Date::new_iso_date(2022, 2, 15).unwrap(), Hebrew);
+        assert_eq!(adar_i.month().ordinal, 6);
+        assert_eq!(adar_i.month().code.0, "M05L");
+        assert_eq!(adar_i.day_of_month().0, 14);
+
+        // Purim, 14 Adar II 5782
+        let adar_ii = Date::new_from_iso(Date::new_iso_date(2022, 3, 17).unwrap(), Hebrew);
+        assert_eq!(adar_ii.month().ordinal, 7);
+        assert_eq!(adar_ii.month().code.0, "M06");
+        assert_eq!(adar_ii.day_of_month().0, 14);
+
+        // Purim, 14 Adar 5783
+        let adar = Date::new_from_iso(Date::new_iso_date(2023, 3, 7).unwrap(), Hebrew);
+        assert_eq!(adar.month().ordinal, 6);
+        assert_eq!(adar.month().code.0, "M06");
+        assert_eq!(adar.day_of_month().0, 14);
+
+        // Passover, 15 Nisan 5783
+        let nisan = Date::new_from_iso(Date::new_iso_date(2023, 4, 6).unwrap(), Hebrew);
+        assert_eq!(nisan.month().ordinal, 7);
+        assert_eq!(nisan.month().code.0, "M07");
+        assert_eq!(nisan.day_of_month().0, 15);
+
+        let am = "am".parse().unwrap();
+        let from_codes =
+            |year, code: &str, day| Hebrew.date_from_codes(am, year, code.parse().unwrap(), day);
+        assert_eq!(from_codes(5782, "M05L", 14), Ok(*adar_i.inner()));
+        assert_eq!(from_codes(5782, "M06", 14), Ok(*adar_ii.inner()));
+        assert_eq!(from_codes(5783, "M06", 14), Ok(*adar.inner()));
+        assert_eq!(from_codes(5783, "M07", 15), Ok(*nisan.inner()));
+        assert_eq!(
+            from_codes(5783, "M05L", 14),
+            Err(DateTimeError::UnknownMonthCode(
+                "M05L".parse().unwrap(),
+                "Hebrew"
+            ))
+        );
+        assert_eq!(
+            from_codes(5783, "M13", 1),
+            Err(DateTimeError::UnknownMonthCode(
+                "M13".parse().unwrap(),
+                "Hebrew"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_offset_years_keeps_month_code() {
+        let mut adar_i = Date::new_hebrew_date(5782, 6, 10).unwrap();
+        adar_i.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(adar_i.month().code.0, "M06");
+        assert_eq!(adar_i.year().number, 5783);
+
+        let mut nisan = Date::new_hebrew_date(5782, 8, 10).unwrap();
+        nisan.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(nisan.month().code.0, "M07");
+
+        let mut adar = Date::new_hebrew_date(5783, 6, 10).unwrap();
+        adar.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(adar.month().code.0, "M06");
+        assert_eq!(adar.month().ordinal, 7);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(1900, 1, 1).unwrap().inner());
+        for fixed in (start..start + 365 * 200).step_by(7) {
+            let iso = Iso::iso_from_fixed(fixed);
+            let hebrew = iso.to_calendar(Hebrew);
+            assert_eq!(hebrew.to_iso(), iso);
+        }
+    }
+}
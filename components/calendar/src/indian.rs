@@ -65,7 +65,7 @@ impl CalendarArithmetic for Indian {
         }
     }
 
-    fn months_for_every_year() -> u8 {
+    fn months_for_every_year(_: i32) -> u8 {
         12
     }
 
@@ -139,6 +139,7 @@ impl Calendar for Indian {
             era: types::Era(tinystr!(16, "saka")),
             number: date.0.year,
             related_iso: None,
+            cyclic: None,
         }
     }
 
@@ -155,11 +156,13 @@ impl Calendar for Indian {
             era: types::Era(tinystr!(16, "saka")),
             number: date.0.year - 1,
             related_iso: None,
+            cyclic: None,
         };
         let next_year = types::FormattableYear {
             era: types::Era(tinystr!(16, "saka")),
             number: date.0.year + 1,
             related_iso: None,
+            cyclic: None,
         };
         types::DayOfYearInfo {
             day_of_year: date.0.day_of_year(),
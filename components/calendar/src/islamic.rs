@@ -0,0 +1,1125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Islamic calendars.
+//!
+//! ```rust
+//! use icu::calendar::{islamic::IslamicCivil, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_islamic = Date::new_from_iso(date_iso, IslamicCivil);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_islamic = DateTime::new_from_iso(datetime_iso, IslamicCivil);
+//!
+//! // `Date` checks
+//! assert_eq!(date_islamic.year().number, 1389);
+//! assert_eq!(date_islamic.month().ordinal, 10);
+//! assert_eq!(date_islamic.day_of_month().0, 23);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_islamic.date.year().number, 1389);
+//! assert_eq!(datetime_islamic.date.month().ordinal, 10);
+//! assert_eq!(datetime_islamic.date.day_of_month().0, 23);
+//! assert_eq!(datetime_islamic.time.hour.number(), 13);
+//! assert_eq!(datetime_islamic.time.minute.number(), 1);
+//! assert_eq!(datetime_islamic.time.second.number(), 0);
+//! ```
+//!
+//! There are four variants of the Islamic calendar, which differ in how they determine
+//! the start of each month:
+//!
+//! - [`IslamicCivil`] and [`IslamicTabular`] are arithmetical calendars with a 30-year
+//!   cycle of 11 leap years, using a Friday and a Thursday epoch respectively.
+//! - [`IslamicUmmAlQura`] is the official calendar of Saudi Arabia, which is based on
+//!   astronomical calculations for Mecca. It uses a table of month lengths for the years
+//!   1300 AH to 1600 AH, and falls back to [`IslamicCivil`] outside of that range.
+//! - [`IslamicObservational`] starts each month when the crescent moon is first expected
+//!   to be visible in the evening in Cairo.
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::astronomy::{self, Location};
+use crate::iso::Iso;
+use crate::{
+    types, ArithmeticDate, Calendar, CalendarArithmetic, Date, DateDuration, DateDurationUnit,
+    DateTime, DateTimeError,
+};
+use core::convert::TryFrom;
+use tinystr::tinystr;
+
+// 1 Muharram 1 AH is July 16th, 622 in the proleptic Julian calendar, a Friday
+const ISLAMIC_EPOCH_FRIDAY: i32 = 227015;
+
+// Astronomers use the day before, Thursday July 15th, 622 (Julian)
+const ISLAMIC_EPOCH_THURSDAY: i32 = 227014;
+
+// The location used to observe the crescent moon, Cairo
+const CAIRO: Location = Location {
+    latitude: 30.1,
+    longitude: 31.3,
+    zone: 2.0 / 24.0,
+};
+
+/// The first year covered by [`UMM_AL_QURA_MONTHS`]
+const UMM_AL_QURA_FIRST_YEAR: i32 = 1300;
+
+/// The fixed date of 1 Muharram 1300 AH in the Umm al-Qura calendar
+const UMM_AL_QURA_FIRST_NEW_YEAR: i32 = 687337;
+
+/// The month lengths of the Umm al-Qura calendar for the years 1300 AH to 1600 AH:
+/// bit `n` is set if the `n + 1`-th month of the year has 30 days instead of 29.
+///
+/// This data matches the Umm al-Qura calendar of ICU4C.
+const UMM_AL_QURA_MONTHS: [u16; 301] = [
+    0x555, 0x2ab, 0x937, 0x2b6, 0x576, 0x36c, 0xb55, 0xaaa, 0x956, 0x49e, 0x95d, 0x2ba, 0x5b5,
+    0x3aa, 0xb4b, 0xa96, 0x52e, 0x2ad, 0x56d, 0xb5a, 0x752, 0xf25, 0xe8a, 0xd16, 0xa56, 0xab5,
+    0x6b4, 0xda9, 0xb92, 0xb25, 0x64b, 0xa9b, 0x35a, 0x6d9, 0x5d4, 0xda5, 0xd4a, 0xa95, 0x536,
+    0x975, 0x2f4, 0x6e9, 0x6d4, 0x6a9, 0x535, 0x25d, 0x4bd, 0x9ba, 0x3b4, 0xb69, 0xb2a, 0xa55,
+    0x4ad, 0xa5d, 0x2da, 0x6d9, 0xeaa, 0xe94, 0xd2a, 0xc56, 0x4ae, 0xa6d, 0x56a, 0xd55, 0xd4a,
+    0xa93, 0x52b, 0xa5b, 0x53a, 0x6b5, 0xea9, 0xd52, 0xd29, 0xa55, 0x4ad, 0x56d, 0xaea, 0x6e4,
+    0xed1, 0xda2, 0xaaa, 0x95a, 0x2da, 0x5b9, 0xbb2, 0x764, 0x6c9, 0x555, 0x2ab, 0x4db, 0xaba,
+    0x5b4, 0xda9, 0xd52, 0xaa5, 0x92d, 0x26d, 0x8ed, 0x2da, 0xad5, 0xaa5, 0xa4b, 0x497, 0x937,
+    0x2b6, 0x975, 0xd69, 0xd52, 0xc95, 0x92b, 0x25b, 0x4db, 0x9d5, 0x5d2, 0xda5, 0xd4a, 0xa95,
+    0x54d, 0xaad, 0x3aa, 0xbd2, 0xbc4, 0xb89, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6d4, 0xdc9, 0xd92,
+    0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d, 0x2ba, 0x5b5, 0x5aa,
+    0xd55, 0xa9a, 0x92e, 0x26e, 0x55d, 0xada, 0x6d4, 0x6a5, 0xb27, 0xa4d, 0x4ad, 0x56d, 0xb5a,
+    0x754, 0xf49, 0xe92, 0xd26, 0xa56, 0x356, 0x6b5, 0xbaa, 0xb92, 0xb25, 0x68b, 0xa9b, 0x55a,
+    0xada, 0x5b4, 0xda9, 0xb52, 0xa9a, 0x536, 0x276, 0x575, 0xaf2, 0x6d4, 0x6a9, 0x555, 0x2ad,
+    0x4bd, 0x9ba, 0x574, 0xb69, 0xb52, 0xa95, 0x52d, 0xa5d, 0x4da, 0xad9, 0x6b2, 0xe95, 0xe2a,
+    0xc96, 0x92e, 0xaad, 0x56a, 0xd65, 0xd4a, 0xd15, 0x62b, 0xc5b, 0x53a, 0x6b5, 0xdb2, 0xd64,
+    0xd29, 0xa55, 0x4ad, 0x96d, 0xaea, 0x6e8, 0xed1, 0xda4, 0xd4a, 0xa6a, 0x2da, 0x5b9, 0xb72,
+    0xb68, 0x6d1, 0x655, 0x4ab, 0x95b, 0x2ba, 0x5b5, 0xda9, 0xd52, 0xca6, 0x94e, 0x46e, 0x95d,
+    0x4da, 0xad5, 0xaaa, 0xa4d, 0x49b, 0x937, 0x4b6, 0x975, 0xd6a, 0xd52, 0xaa5, 0x94b, 0x2ab,
+    0x55b, 0xad9, 0x5d2, 0xdc5, 0xd92, 0xb25, 0x555, 0xab5, 0x5b4, 0xba9, 0x7a2, 0x745, 0x593,
+    0xaab, 0x4d6, 0x9d6, 0x5d2, 0xba5, 0xb4a, 0xa95, 0x4ad, 0x15d, 0x2dd, 0x9da, 0x5b4, 0x5a9,
+    0x52d, 0x25b, 0x8b7, 0x176, 0x56d, 0xb6a, 0xaca, 0xa96, 0x52b, 0x15b, 0x2bb, 0x5b6, 0xdaa,
+    0xb94, 0xd46, 0xa8d, 0x52d, 0xa9d, 0x55a, 0x755, 0x749, 0xf13, 0xe4a, 0xa96, 0x556, 0x6b5,
+    0xbaa, 0xb94,
+];
+
+/// The civil Islamic calendar
+///
+/// This is the tabular Islamic calendar with a Friday epoch, in which the years 2, 5, 7, 10,
+/// 13, 16, 18, 21, 24, 26 and 29 of each 30-year cycle are leap years with 355 days.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicCivil;
+
+/// The tabular Islamic calendar
+///
+/// This is the same as [`IslamicCivil`], but with the astronomical Thursday epoch, so every
+/// date is one day later.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicTabular;
+
+/// The Umm al-Qura Islamic calendar, used in Saudi Arabia
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicUmmAlQura;
+
+/// The observational Islamic calendar
+///
+/// This calendar starts every month on the day after the crescent moon first becomes visible
+/// in Cairo, using the astronomical criterion from _Calendrical Calculations_. Actual
+/// observations may differ from this calculation by a day or two.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicObservational;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<IslamicCivil>
+pub struct IslamicCivilDateInner(pub(crate) ArithmeticDate<IslamicCivil>);
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<IslamicTabular>
+pub struct IslamicTabularDateInner(pub(crate) ArithmeticDate<IslamicTabular>);
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<IslamicUmmAlQura>
+pub struct IslamicUmmAlQuraDateInner(pub(crate) ArithmeticDate<IslamicUmmAlQura>);
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<IslamicObservational>
+pub struct IslamicObservationalDateInner(pub(crate) ArithmeticDate<IslamicObservational>);
+
+// "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+// The fixed date algorithms are from
+// Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+//
+// Lisp code reference: `islamic-leap-year?` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+fn is_tabular_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+fn tabular_month_days(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 9 | 11 => 30,
+        2 | 4 | 6 | 8 | 10 => 29,
+        12 if is_tabular_leap_year(year) => 30,
+        12 => 29,
+        _ => 0,
+    }
+}
+
+// Lisp code reference: `fixed-from-islamic` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+fn fixed_from_tabular_islamic(epoch: i32, year: i32, month: i32, day: i32) -> i32 {
+    epoch - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + month / 2
+        + day
+}
+
+// Lisp code reference: `islamic-from-fixed` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+fn tabular_islamic_from_fixed<C: CalendarArithmetic>(epoch: i32, date: i32) -> ArithmeticDate<C> {
+    let year = (30 * (date - epoch) + 10646).div_euclid(10631);
+    let prior_days = date - fixed_from_tabular_islamic(epoch, year, 1, 1);
+    let month = (11 * prior_days + 330).div_euclid(325);
+    let day = date - fixed_from_tabular_islamic(epoch, year, month, 1) + 1;
+    ArithmeticDate::new(year, month as u8, day as u8)
+}
+
+fn year_as_islamic(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "ah")),
+        number: year,
+        related_iso: None,
+        cyclic: None,
+    }
+}
+
+fn check_era(era: types::Era, calendar: &impl Calendar) -> Result<(), DateTimeError> {
+    if era.0 == tinystr!(16, "ah") {
+        Ok(())
+    } else {
+        Err(DateTimeError::UnknownEra(era.0, calendar.debug_name()))
+    }
+}
+
+fn new_islamic_date<C: CalendarArithmetic>(
+    year: i32,
+    month: u8,
+    day: u8,
+) -> Result<ArithmeticDate<C>, DateTimeError> {
+    let inner = ArithmeticDate::new(year, month, day);
+
+    if month == 0 || month > inner.months_in_year() {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    let bound = inner.days_in_month();
+    if day == 0 || day > bound {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    Ok(inner)
+}
+
+impl CalendarArithmetic for IslamicCivil {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            355
+        } else {
+            354
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_tabular_leap_year(year)
+    }
+}
+
+impl Calendar for IslamicCivil {
+    type DateInner = IslamicCivilDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self)?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IslamicCivilDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        IslamicCivilDateInner(tabular_islamic_from_fixed(ISLAMIC_EPOCH_FRIDAY, fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_islamic = fixed_from_tabular_islamic(
+            ISLAMIC_EPOCH_FRIDAY,
+            date.0.year,
+            date.0.month as i32,
+            date.0.day as i32,
+        );
+        Iso::iso_from_fixed(fixed_islamic)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (civil)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicCivil)
+    }
+}
+
+impl CalendarArithmetic for IslamicTabular {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            355
+        } else {
+            354
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_tabular_leap_year(year)
+    }
+}
+
+impl Calendar for IslamicTabular {
+    type DateInner = IslamicTabularDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self)?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IslamicTabularDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        IslamicTabularDateInner(tabular_islamic_from_fixed(
+            ISLAMIC_EPOCH_THURSDAY,
+            fixed_iso,
+        ))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_islamic = fixed_from_tabular_islamic(
+            ISLAMIC_EPOCH_THURSDAY,
+            date.0.year,
+            date.0.month as i32,
+            date.0.day as i32,
+        );
+        Iso::iso_from_fixed(fixed_islamic)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (tabular)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicTabular)
+    }
+}
+
+impl IslamicUmmAlQura {
+    /// The month lengths of `year`, if it is covered by [`UMM_AL_QURA_MONTHS`]
+    fn year_data(year: i32) -> Option<u16> {
+        UMM_AL_QURA_MONTHS
+            .get(usize::try_from(year - UMM_AL_QURA_FIRST_YEAR).ok()?)
+            .copied()
+    }
+
+    /// The fixed date of the first day of `year`
+    fn new_year(year: i32) -> i32 {
+        if Self::year_data(year).is_none() {
+            return fixed_from_tabular_islamic(ISLAMIC_EPOCH_FRIDAY, year, 1, 1);
+        }
+        UMM_AL_QURA_FIRST_NEW_YEAR
+            + UMM_AL_QURA_MONTHS
+                .iter()
+                .take((year - UMM_AL_QURA_FIRST_YEAR) as usize)
+                .map(|months| 29 * 12 + months.count_ones() as i32)
+                .sum::<i32>()
+    }
+
+    fn fixed_from_umm_al_qura(date: ArithmeticDate<Self>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    fn umm_al_qura_from_fixed(date: i32) -> IslamicUmmAlQuraDateInner {
+        // The Umm al-Qura new year is never more than a few days from the civil one
+        let mut year = tabular_islamic_from_fixed::<IslamicCivil>(ISLAMIC_EPOCH_FRIDAY, date).year;
+        if Self::new_year(year) > date {
+            year -= 1;
+        } else if Self::new_year(year + 1) <= date {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        IslamicUmmAlQuraDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl CalendarArithmetic for IslamicUmmAlQura {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match Self::year_data(year) {
+            Some(months) if (1..=12).contains(&month) => {
+                if months & (1 << (month - 1)) != 0 {
+                    30
+                } else {
+                    29
+                }
+            }
+            Some(_) => 0,
+            None => tabular_month_days(year, month),
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        Self::days_in_provided_year(year) > 354
+    }
+}
+
+impl Calendar for IslamicUmmAlQura {
+    type DateInner = IslamicUmmAlQuraDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self)?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IslamicUmmAlQuraDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::umm_al_qura_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_islamic = Self::fixed_from_umm_al_qura(date.0);
+        Iso::iso_from_fixed(fixed_islamic)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (Umm al-Qura)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicUmmAlQura)
+    }
+}
+
+impl IslamicObservational {
+    // Lisp code reference: `fixed-from-observational-islamic` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    //
+    // `month` may be 13, for the first month of the next year
+    fn fixed_from_observational_islamic(year: i32, month: u8, day: u8) -> i32 {
+        let midmonth = ISLAMIC_EPOCH_FRIDAY as f64
+            + libm::floor(
+                ((year - 1) as f64 * 12.0 + month as f64 - 0.5) * astronomy::MEAN_SYNODIC_MONTH,
+            );
+        astronomy::phasis_on_or_before(midmonth as i32, CAIRO) + day as i32 - 1
+    }
+
+    // Lisp code reference: `observational-islamic-from-fixed` in https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l
+    fn observational_islamic_from_fixed(date: i32) -> IslamicObservationalDateInner {
+        let crescent = astronomy::phasis_on_or_before(date, CAIRO);
+        let elapsed_months =
+            libm::round((crescent - ISLAMIC_EPOCH_FRIDAY) as f64 / astronomy::MEAN_SYNODIC_MONTH)
+                as i32;
+        let year = elapsed_months.div_euclid(12) + 1;
+        let month = elapsed_months.rem_euclid(12) + 1;
+        let day = date - crescent + 1;
+        IslamicObservationalDateInner(ArithmeticDate::new(year, month as u8, day as u8))
+    }
+}
+
+impl CalendarArithmetic for IslamicObservational {
+    fn month_days(year: i32, month: u8) -> u8 {
+        if !(1..=12).contains(&month) {
+            return 0;
+        }
+        (Self::fixed_from_observational_islamic(year, month + 1, 1)
+            - Self::fixed_from_observational_islamic(year, month, 1)) as u8
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        (Self::fixed_from_observational_islamic(year, 13, 1)
+            - Self::fixed_from_observational_islamic(year, 1, 1)) as u32
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        Self::days_in_provided_year(year) > 354
+    }
+}
+
+impl Calendar for IslamicObservational {
+    type DateInner = IslamicObservationalDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self)?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day)
+            .map(IslamicObservationalDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::observational_islamic_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_islamic =
+            Self::fixed_from_observational_islamic(date.0.year, date.0.month, date.0.day);
+        Iso::iso_from_fixed(fixed_islamic)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (observational)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicObservational)
+    }
+}
+
+impl Date<IslamicCivil> {
+    /// Construct new civil Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_civil_date(1444, 9, 1)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1444);
+    /// assert_eq!(date_islamic.month().ordinal, 9);
+    /// assert_eq!(date_islamic.day_of_month().0, 1);
+    /// ```
+    pub fn new_islamic_civil_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicCivil>, DateTimeError> {
+        new_islamic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicCivilDateInner(inner), IslamicCivil))
+    }
+}
+
+impl DateTime<IslamicCivil> {
+    /// Construct a new civil Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_civil_datetime(1444, 9, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 1444);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 9);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_civil_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicCivil>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_civil_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl Date<IslamicTabular> {
+    /// Construct new tabular Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_tabular_date(1444, 9, 1)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1444);
+    /// assert_eq!(date_islamic.month().ordinal, 9);
+    /// assert_eq!(date_islamic.day_of_month().0, 1);
+    /// ```
+    pub fn new_islamic_tabular_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicTabular>, DateTimeError> {
+        new_islamic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicTabularDateInner(inner), IslamicTabular))
+    }
+}
+
+impl DateTime<IslamicTabular> {
+    /// Construct a new tabular Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_tabular_datetime(1444, 9, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 1444);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 9);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_tabular_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicTabular>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_tabular_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl Date<IslamicUmmAlQura> {
+    /// Construct new Umm al-Qura Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_umm_al_qura_date(1444, 9, 1)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1444);
+    /// assert_eq!(date_islamic.month().ordinal, 9);
+    /// assert_eq!(date_islamic.day_of_month().0, 1);
+    /// ```
+    pub fn new_islamic_umm_al_qura_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicUmmAlQura>, DateTimeError> {
+        new_islamic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicUmmAlQuraDateInner(inner), IslamicUmmAlQura))
+    }
+}
+
+impl DateTime<IslamicUmmAlQura> {
+    /// Construct a new Umm al-Qura Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_umm_al_qura_datetime(1444, 9, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 1444);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 9);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_umm_al_qura_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicUmmAlQura>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_umm_al_qura_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl Date<IslamicObservational> {
+    /// Construct new observational Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_observational_date(1444, 9, 1)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1444);
+    /// assert_eq!(date_islamic.month().ordinal, 9);
+    /// assert_eq!(date_islamic.day_of_month().0, 1);
+    /// ```
+    pub fn new_islamic_observational_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicObservational>, DateTimeError> {
+        new_islamic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicObservationalDateInner(inner), IslamicObservational))
+    }
+}
+
+impl DateTime<IslamicObservational> {
+    /// Construct a new observational Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_observational_datetime(1444, 9, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 1444);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 9);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_observational_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicObservational>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_observational_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::julian::Julian;
+
+    #[test]
+    fn test_epochs() {
+        assert_eq!(
+            ISLAMIC_EPOCH_FRIDAY,
+            Julian::fixed_from_julian_integers(622, 7, 16)
+        );
+        assert_eq!(
+            ISLAMIC_EPOCH_THURSDAY,
+            Julian::fixed_from_julian_integers(622, 7, 15)
+        );
+        assert_eq!(
+            UMM_AL_QURA_FIRST_NEW_YEAR,
+            fixed_from_tabular_islamic(ISLAMIC_EPOCH_FRIDAY, UMM_AL_QURA_FIRST_YEAR, 1, 1)
+        );
+    }
+
+    // (iso date, [civil, tabular, umm al-qura, observational])
+    //
+    // The first three columns match ICU4C; the observational column uses crescent
+    // visibility from Cairo.
+    #[allow(clippy::type_complexity)]
+    const CASES: [((i32, u8, u8), [(i32, u8, u8); 4]); 7] = [
+        (
+            (1900, 1, 1),
+            [(1317, 8, 28), (1317, 8, 29), (1317, 8, 29), (1317, 8, 28)],
+        ),
+        (
+            (1970, 1, 2),
+            [
+                (1389, 10, 23),
+                (1389, 10, 24),
+                (1389, 10, 23),
+                (1389, 10, 23),
+            ],
+        ),
+        // The crescent was not yet visible from Cairo on the evening of 1999-12-08,
+        // so observational Ramadan starts a day later than in the tabular calendars
+        (
+            (2000, 1, 1),
+            [(1420, 9, 24), (1420, 9, 25), (1420, 9, 24), (1420, 9, 23)],
+        ),
+        (
+            (2022, 7, 30),
+            [(1444, 1, 1), (1444, 1, 2), (1444, 1, 1), (1444, 1, 1)],
+        ),
+        // Ramadan and Eid al-Fitr 1444
+        (
+            (2023, 3, 23),
+            [(1444, 9, 1), (1444, 9, 2), (1444, 9, 1), (1444, 9, 1)],
+        ),
+        // Only thirteen hours after the new moon, the crescent is too young to be seen
+        (
+            (2023, 4, 21),
+            [(1444, 9, 30), (1444, 10, 1), (1444, 10, 1), (1444, 9, 30)],
+        ),
+        // Ramadan 1445; the new moon falls on the morning of 2024-03-10, too late
+        // for the crescent to be seen from Cairo that evening
+        (
+            (2024, 3, 11),
+            [(1445, 9, 1), (1445, 9, 2), (1445, 9, 1), (1445, 8, 30)],
+        ),
+    ];
+
+    #[test]
+    fn test_from_iso() {
+        for ((y, m, d), expected) in CASES {
+            let iso = Date::new_iso_date(y, m, d).unwrap();
+            let actual = [
+                (
+                    iso.to_calendar(IslamicCivil).year().number,
+                    iso.to_calendar(IslamicCivil).month().ordinal as u8,
+                    iso.to_calendar(IslamicCivil).day_of_month().0 as u8,
+                ),
+                (
+                    iso.to_calendar(IslamicTabular).year().number,
+                    iso.to_calendar(IslamicTabular).month().ordinal as u8,
+                    iso.to_calendar(IslamicTabular).day_of_month().0 as u8,
+                ),
+                (
+                    iso.to_calendar(IslamicUmmAlQura).year().number,
+                    iso.to_calendar(IslamicUmmAlQura).month().ordinal as u8,
+                    iso.to_calendar(IslamicUmmAlQura).day_of_month().0 as u8,
+                ),
+                (
+                    iso.to_calendar(IslamicObservational).year().number,
+                    iso.to_calendar(IslamicObservational).month().ordinal as u8,
+                    iso.to_calendar(IslamicObservational).day_of_month().0 as u8,
+                ),
+            ];
+            assert_eq!(actual, expected, "{:?}", iso);
+        }
+    }
+
+    #[test]
+    fn test_to_iso() {
+        for ((y, m, d), [civil, tabular, umm_al_qura, observational]) in CASES {
+            let iso = Date::new_iso_date(y, m, d).unwrap();
+            let (y, m, d) = civil;
+            assert_eq!(Date::new_islamic_civil_date(y, m, d).unwrap().to_iso(), iso);
+            let (y, m, d) = tabular;
+            assert_eq!(
+                Date::new_islamic_tabular_date(y, m, d).unwrap().to_iso(),
+                iso
+            );
+            let (y, m, d) = umm_al_qura;
+            assert_eq!(
+                Date::new_islamic_umm_al_qura_date(y, m, d)
+                    .unwrap()
+                    .to_iso(),
+                iso
+            );
+            let (y, m, d) = observational;
+            assert_eq!(
+                Date::new_islamic_observational_date(y, m, d)
+                    .unwrap()
+                    .to_iso(),
+                iso
+            );
+        }
+    }
+
+    #[test]
+    fn test_umm_al_qura_years() {
+        let mut new_year = UMM_AL_QURA_FIRST_NEW_YEAR;
+        for year in UMM_AL_QURA_FIRST_YEAR..=1601 {
+            assert_eq!(IslamicUmmAlQura::new_year(year), new_year, "{}", year);
+            let days = IslamicUmmAlQura::days_in_provided_year(year);
+            assert!(days == 354 || days == 355, "{}", year);
+            let months: u32 = (1..=12)
+                .map(|month| IslamicUmmAlQura::month_days(year, month) as u32)
+                .sum();
+            assert_eq!(months, days, "{}", year);
+            new_year += days as i32;
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(1880, 1, 1).unwrap().inner());
+        for fixed in (start..start + 365 * 250).step_by(5) {
+            let iso = Iso::iso_from_fixed(fixed);
+            assert_eq!(iso.to_calendar(IslamicCivil).to_iso(), iso);
+            assert_eq!(iso.to_calendar(IslamicTabular).to_iso(), iso);
+            assert_eq!(iso.to_calendar(IslamicUmmAlQura).to_iso(), iso);
+        }
+        // The observational calendar is slow to compute, so check fewer dates
+        for fixed in (start..start + 365 * 250).step_by(293) {
+            let iso = Iso::iso_from_fixed(fixed);
+            let observational = iso.to_calendar(IslamicObservational);
+            assert_eq!(observational.to_iso(), iso);
+            let days_in_month = observational.days_in_month();
+            assert!(
+                days_in_month == 29 || days_in_month == 30,
+                "{:?}",
+                observational
+            );
+        }
+    }
+}
@@ -32,12 +32,23 @@
 use crate::any_calendar::AnyCalendarKind;
 use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
 use crate::{ArithmeticDate, CalendarArithmetic};
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 use tinystr::tinystr;
 
 // The georgian epoch is equivalent to first day in fixed day measurement
 const EPOCH: i32 = 1;
 
+// The fixed date of the Unix epoch, 1970-01-01
+const UNIX_EPOCH: i32 = 719163;
+
+// The range of fixed dates that timestamps are converted to, roughly ±5 million years. This
+// keeps the fixed date calculations from overflowing.
+const MAX_FIXED: i32 = 365 * 5_000_000;
+const MIN_FIXED: i32 = -MAX_FIXED;
+
+const NANOSECONDS_IN_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_IN_DAY: i128 = 86400 * NANOSECONDS_IN_SECOND;
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[allow(clippy::exhaustive_structs)] // this type is stable
 /// The ISO Calendar
@@ -58,13 +69,17 @@ impl CalendarArithmetic for Iso {
         }
     }
 
-    fn months_for_every_year() -> u8 {
+    fn months_for_every_year(_: i32) -> u8 {
         12
     }
 
     fn is_leap_year(year: i32) -> bool {
         year % 4 == 0 && (year % 400 == 0 || year % 100 != 0)
     }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        Self::days_in_year_direct(year)
+    }
 }
 
 impl Calendar for Iso {
@@ -149,54 +164,19 @@ impl Calendar for Iso {
         types::IsoWeekday::from((day_offset + 1) as usize)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, mut offset: DateDuration<Self>) {
-        date.0.year += offset.years;
-        date.add_months(offset.months);
-        offset.months = 0;
-
-        offset.days += offset.weeks * 7;
-
-        // Normalize date to beginning of month
-        offset.days += date.0.day as i32 - 1;
-        date.0.day = 1;
-
-        while offset.days != 0 {
-            if offset.days < 0 {
-                date.add_months(-1);
-                let month_days = self.days_in_month(date);
-                if (-offset.days) > month_days as i32 {
-                    offset.days += month_days as i32;
-                } else {
-                    // Add 1 since we are subtracting from the first day of the
-                    // *next* month
-                    date.0.day = 1 + (month_days as i8 + offset.days as i8) as u8;
-                    offset.days = 0;
-                }
-            } else {
-                let month_days = self.days_in_month(date);
-                // >= because we date.day is 1, so adding the number of days in the month
-                // will still have the same effect
-                if offset.days >= month_days as i32 {
-                    date.add_months(1);
-                    offset.days -= month_days as i32;
-                } else {
-                    date.0.day += offset.days as u8;
-                    offset.days = 0;
-                }
-            }
-        }
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
     }
 
-    #[allow(clippy::field_reassign_with_default)]
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
         _calendar2: &Self,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+        date1.0.until(date2.0, largest_unit, smallest_unit)
     }
 
     /// The calendar-specific year represented by `date`
@@ -365,6 +345,139 @@ impl DateTime<Iso> {
             unreachable!("DateTime should be created successfully")
         }
     }
+
+    /// Nanosecond count since 00:00:00 on Jan 1st, 1970, ignoring leap seconds.
+    ///
+    /// This conversion is lossless. A leap second (`23:59:60`) has the same value as
+    /// `00:00:00` on the following day.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    /// use icu::calendar::types::Time;
+    ///
+    /// let datetime = DateTime::new_iso_datetime(2020, 2, 29, 12, 30, 15).unwrap();
+    /// let datetime = DateTime::new(datetime.date, Time::try_new(12, 30, 15, 500).unwrap());
+    ///
+    /// assert_eq!(datetime.nanoseconds_since_unix_epoch(), 1582979415000000500);
+    /// assert_eq!(
+    ///     DateTime::from_nanoseconds_since_unix_epoch(1582979415000000500),
+    ///     Ok(datetime)
+    /// );
+    /// ```
+    pub fn nanoseconds_since_unix_epoch(&self) -> i128 {
+        let days = Iso::fixed_from_iso(*self.date.inner()) - UNIX_EPOCH;
+        days as i128 * NANOSECONDS_IN_DAY + self.time.nanoseconds_since_midnight() as i128
+    }
+
+    /// Convert a nanosecond count since 00:00:00 on Jan 1st, 1970 to an ISO DateTime.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the result is more than about 5 million years
+    /// away from the Unix epoch.
+    pub fn from_nanoseconds_since_unix_epoch(
+        nanoseconds: i128,
+    ) -> Result<DateTime<Iso>, DateTimeError> {
+        let days = nanoseconds.div_euclid(NANOSECONDS_IN_DAY);
+        let fixed = i32::try_from(days)
+            .ok()
+            .and_then(|days| days.checked_add(UNIX_EPOCH))
+            .filter(|fixed| (MIN_FIXED..=MAX_FIXED).contains(fixed))
+            .ok_or(DateTimeError::OutOfRange)?;
+        Ok(DateTime {
+            date: Iso::iso_from_fixed(fixed),
+            time: types::Time::from_nanoseconds_since_midnight(
+                nanoseconds.rem_euclid(NANOSECONDS_IN_DAY) as i64,
+            ),
+        })
+    }
+
+    /// Millisecond count since 00:00:00 on Jan 1st, 1970, ignoring leap seconds.
+    ///
+    /// Sub-millisecond precision is rounded towards negative infinity.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime = DateTime::new_iso_datetime(1969, 12, 31, 23, 59, 59).unwrap();
+    ///
+    /// assert_eq!(datetime.milliseconds_since_unix_epoch(), -1000);
+    /// assert_eq!(
+    ///     DateTime::from_milliseconds_since_unix_epoch(-1000),
+    ///     Ok(datetime)
+    /// );
+    /// ```
+    pub fn milliseconds_since_unix_epoch(&self) -> i64 {
+        // The full range of ISO dates fits in an i64 at millisecond precision
+        self.nanoseconds_since_unix_epoch().div_euclid(1_000_000) as i64
+    }
+
+    /// Convert a millisecond count since 00:00:00 on Jan 1st, 1970 to an ISO DateTime.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the result is more than about 5 million years
+    /// away from the Unix epoch.
+    pub fn from_milliseconds_since_unix_epoch(
+        milliseconds: i64,
+    ) -> Result<DateTime<Iso>, DateTimeError> {
+        Self::from_nanoseconds_since_unix_epoch(milliseconds as i128 * 1_000_000)
+    }
+
+    /// Second count since 00:00:00 on Jan 1st, 1970, ignoring leap seconds.
+    ///
+    /// Sub-second precision is rounded towards negative infinity.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime = DateTime::new_iso_datetime(2020, 2, 29, 12, 30, 15).unwrap();
+    ///
+    /// assert_eq!(datetime.seconds_since_unix_epoch(), 1582979415);
+    /// assert_eq!(
+    ///     DateTime::from_seconds_since_unix_epoch(1582979415),
+    ///     Ok(datetime)
+    /// );
+    /// ```
+    pub fn seconds_since_unix_epoch(&self) -> i64 {
+        self.nanoseconds_since_unix_epoch()
+            .div_euclid(NANOSECONDS_IN_SECOND) as i64
+    }
+
+    /// Convert a second count since 00:00:00 on Jan 1st, 1970 to an ISO DateTime.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the result is more than about 5 million years
+    /// away from the Unix epoch.
+    pub fn from_seconds_since_unix_epoch(seconds: i64) -> Result<DateTime<Iso>, DateTimeError> {
+        Self::from_nanoseconds_since_unix_epoch(seconds as i128 * NANOSECONDS_IN_SECOND)
+    }
+
+    /// Convert a [`SystemTime`](std::time::SystemTime) to an ISO DateTime in UTC.
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the result is more than about 5 million years
+    /// away from the Unix epoch.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> Result<DateTime<Iso>, DateTimeError> {
+        let nanoseconds = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        };
+        Self::from_nanoseconds_since_unix_epoch(nanoseconds)
+    }
+
+    /// Convert this DateTime, interpreted as UTC, to a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// Returns [`DateTimeError::OutOfRange`] if the platform cannot represent the time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Result<std::time::SystemTime, DateTimeError> {
+        let nanoseconds = self.nanoseconds_since_unix_epoch();
+        let duration = std::time::Duration::new(
+            (nanoseconds.abs() / NANOSECONDS_IN_SECOND) as u64,
+            (nanoseconds.abs() % NANOSECONDS_IN_SECOND) as u32,
+        );
+        if nanoseconds < 0 {
+            std::time::UNIX_EPOCH.checked_sub(duration)
+        } else {
+            std::time::UNIX_EPOCH.checked_add(duration)
+        }
+        .ok_or(DateTimeError::OutOfRange)
+    }
 }
 
 impl Iso {
@@ -399,7 +512,8 @@ impl Iso {
         // Calculate days per year
         let mut fixed: i32 = EPOCH - 1 + 365 * (date.0.year - 1);
         // Adjust for leap year logic
-        fixed += ((date.0.year - 1) / 4) - ((date.0.year - 1) / 100) + ((date.0.year - 1) / 400);
+        fixed += (date.0.year - 1).div_euclid(4) - (date.0.year - 1).div_euclid(100)
+            + (date.0.year - 1).div_euclid(400);
         // Days of current year
         fixed += (367 * (date.0.month as i32) - 362) / 12;
         // Leap year adjustment for the current year
@@ -414,7 +528,7 @@ impl Iso {
         fixed + (date.0.day as i32)
     }
 
-    fn fixed_from_iso_integers(year: i32, month: i32, day: i32) -> i32 {
+    pub(crate) fn fixed_from_iso_integers(year: i32, month: i32, day: i32) -> i32 {
         #[allow(clippy::unwrap_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
         Self::fixed_from_iso(
             *Date::new_iso_date(year, month as u8, day as u8)
@@ -440,10 +554,11 @@ impl Iso {
     }
 
     // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1191-L1217
-    fn iso_year_from_fixed(date: i32) -> i32 {
+    pub(crate) fn iso_year_from_fixed(date: i32) -> i32 {
+        let date = date - EPOCH;
         // 400 year cycles have 146097 days
-        let n_400 = date / 146097;
-        let date = date % 146097;
+        let n_400 = date.div_euclid(146097);
+        let date = date.rem_euclid(146097);
 
         // 100 year cycles have 36524 days
         let n_100 = date / 36524;
@@ -506,6 +621,7 @@ impl Iso {
             era: types::Era(tinystr!(16, "default")),
             number: year,
             related_iso: None,
+            cyclic: None,
         }
     }
 }
@@ -517,22 +633,6 @@ impl IsoDateInner {
     pub(crate) fn dec_31(year: i32) -> Self {
         Self(ArithmeticDate::new(year, 12, 1))
     }
-
-    fn add_months(&mut self, months: i32) {
-        // Get a zero-indexed new month
-        let new_month = (self.0.month as i32 - 1) + months;
-        if new_month >= 0 {
-            self.0.year += new_month / 12;
-            self.0.month = ((new_month % 12) + 1) as u8;
-        } else {
-            // subtract full years
-            self.0.year -= (-new_month) / 12;
-            // subtract a partial year
-            self.0.year -= 1;
-            // adding 13 since months are 1-indexed
-            self.0.month = (13 + (new_month % 12)) as u8
-        }
-    }
 }
 
 impl From<&'_ IsoDateInner> for crate::provider::EraStartDate {
@@ -597,6 +697,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fixed_roundtrip() {
+        // The last day of a leap year ends a four-year cycle
+        for (year, month, day) in [(2020, 12, 31), (2000, 12, 31), (2021, 1, 1), (1900, 3, 1)] {
+            let date = Date::new_iso_date(year, month, day).unwrap();
+            let fixed = Iso::fixed_from_iso(*date.inner());
+            assert_eq!(Iso::iso_from_fixed(fixed), date);
+        }
+    }
+
+    #[test]
+    fn test_fixed_across_year_zero() {
+        // Truncating division would put the leap days of negative years in the wrong place
+        let fixed = |year, month, day| {
+            Iso::fixed_from_iso(*Date::new_iso_date(year, month, day).unwrap().inner())
+        };
+        assert_eq!(fixed(1, 1, 1), 1);
+        assert_eq!(fixed(0, 12, 31), 0);
+        assert_eq!(fixed(-1, 12, 31) + 1, fixed(0, 1, 1));
+        assert_eq!(fixed(-4, 12, 31) + 1, fixed(-3, 1, 1));
+        assert_eq!(fixed(0, 1, 1) - fixed(-400, 1, 1), 146097);
+
+        let mut date = Date::new_iso_date(-5, 12, 1).unwrap();
+        let end = Date::new_iso_date(2, 2, 1).unwrap();
+        let mut expected = fixed(-5, 12, 1);
+        while date != end {
+            let fixed = Iso::fixed_from_iso(*date.inner());
+            assert_eq!(fixed, expected, "{:?}", date);
+            assert_eq!(Iso::iso_from_fixed(fixed), date);
+            date.add(DateDuration::new(0, 0, 0, 1));
+            expected += 1;
+        }
+    }
+
     fn simple_subtract(a: &Date<Iso>, b: &Date<Iso>) -> DateDuration<Iso> {
         let a = a.inner();
         let b = b.inner();
@@ -661,4 +795,267 @@ mod test {
         let offset = today.added(DateDuration::new(0, 0, 0, -1));
         assert_eq!(offset, today_minus_1);
     }
+
+    #[test]
+    fn test_offset_constrains_day() {
+        let jan_31 = Date::new_iso_date(2021, 1, 31).unwrap();
+        let offset = jan_31.added(DateDuration::new(0, 1, 0, 0));
+        assert_eq!(offset, Date::new_iso_date(2021, 2, 28).unwrap());
+
+        let feb_29 = Date::new_iso_date(2020, 2, 29).unwrap();
+        let offset = feb_29.clone().added(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(offset, Date::new_iso_date(2021, 2, 28).unwrap());
+        let offset = feb_29.added(DateDuration::new(4, 0, 0, 0));
+        assert_eq!(offset, Date::new_iso_date(2024, 2, 29).unwrap());
+
+        let mar_31 = Date::new_iso_date(2021, 3, 31).unwrap();
+        let offset = mar_31.added(DateDuration::new(0, -13, 0, 0));
+        assert_eq!(offset, Date::new_iso_date(2020, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_until() {
+        #[derive(Debug)]
+        struct TestCase {
+            start: (i32, u8, u8),
+            end: (i32, u8, u8),
+            largest_unit: DateDurationUnit,
+            smallest_unit: DateDurationUnit,
+            expected: (i32, i32, i32, i32),
+        }
+        use DateDurationUnit::*;
+        let cases = [
+            TestCase {
+                start: (2021, 1, 31),
+                end: (2021, 2, 28),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (0, 1, 0, 0),
+            },
+            TestCase {
+                start: (2021, 2, 28),
+                end: (2021, 1, 31),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (0, 0, 0, -28),
+            },
+            TestCase {
+                start: (2020, 2, 29),
+                end: (2021, 2, 28),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (1, 0, 0, 0),
+            },
+            TestCase {
+                start: (2021, 2, 28),
+                end: (2020, 2, 29),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (0, -11, 0, -28),
+            },
+            TestCase {
+                start: (2019, 12, 15),
+                end: (2020, 1, 10),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (0, 0, 0, 26),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Years,
+                smallest_unit: Days,
+                expected: (2, 9, 0, 9),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Months,
+                smallest_unit: Days,
+                expected: (0, 33, 0, 9),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Years,
+                smallest_unit: Weeks,
+                expected: (2, 9, 1, 0),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Years,
+                smallest_unit: Months,
+                expected: (2, 9, 0, 0),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Days,
+                smallest_unit: Years,
+                expected: (2, 0, 0, 0),
+            },
+            TestCase {
+                start: (2000, 5, 20),
+                end: (2003, 3, 1),
+                largest_unit: Weeks,
+                smallest_unit: Days,
+                expected: (0, 0, 145, 0),
+            },
+            TestCase {
+                start: (2003, 3, 1),
+                end: (2000, 5, 20),
+                largest_unit: Days,
+                smallest_unit: Days,
+                expected: (0, 0, 0, -1015),
+            },
+        ];
+        for case in cases {
+            let start = Date::new_iso_date(case.start.0, case.start.1, case.start.2).unwrap();
+            let end = Date::new_iso_date(case.end.0, case.end.1, case.end.2).unwrap();
+            let duration = start.until(&end, case.largest_unit, case.smallest_unit);
+            assert_eq!(
+                (
+                    duration.years,
+                    duration.months,
+                    duration.weeks,
+                    duration.days
+                ),
+                case.expected,
+                "{case:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_until_roundtrip() {
+        let dates = [
+            (2019, 1, 31),
+            (2019, 2, 28),
+            (2019, 12, 31),
+            (2020, 1, 1),
+            (2020, 2, 29),
+            (2020, 3, 31),
+            (2020, 7, 15),
+            (2021, 2, 28),
+            (2024, 2, 29),
+            (1999, 11, 30),
+        ];
+        let units = [
+            DateDurationUnit::Years,
+            DateDurationUnit::Months,
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Days,
+        ];
+        for start in dates {
+            for end in dates {
+                let start = Date::new_iso_date(start.0, start.1, start.2).unwrap();
+                let end = Date::new_iso_date(end.0, end.1, end.2).unwrap();
+                for unit in units {
+                    let duration = start.until(&end, unit, DateDurationUnit::Days);
+                    assert_eq!(
+                        start.clone().added(duration),
+                        end,
+                        "{start:?} + {duration:?} ({unit:?})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_month_codes() {
+        for (code, ordinal) in [
+            ("M01", 1),
+            ("M09", 9),
+            ("M10", 10),
+            ("M11", 11),
+            ("M12", 12),
+        ] {
+            let date = Date::new_from_codes(
+                types::Era(tinystr!(16, "default")),
+                2000,
+                types::MonthCode(code.parse().unwrap()),
+                5,
+                Iso,
+            )
+            .unwrap();
+            assert_eq!(date.month().ordinal, ordinal, "{code}");
+        }
+        for code in ["M00", "M13", "M1", "M05L"] {
+            assert!(Date::new_from_codes(
+                types::Era(tinystr!(16, "default")),
+                2000,
+                types::MonthCode(code.parse().unwrap()),
+                5,
+                Iso,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_unix_epoch() {
+        assert_eq!(UNIX_EPOCH, Iso::fixed_from_iso_integers(1970, 1, 1));
+    }
+
+    #[test]
+    fn test_unix_timestamp_roundtrip() {
+        let nanoseconds_cases = [
+            0,
+            1,
+            -1,
+            1_582_979_415_000_000_500,
+            -1_582_979_415_123_456_789,
+            253_402_300_799_999_999_999,
+            -62_135_596_801_000_000_000,
+            -100_000_000_000_000_000_000,
+            -157_000_000_000_000_000_000_000,
+        ];
+        for nanoseconds in nanoseconds_cases {
+            let datetime = DateTime::from_nanoseconds_since_unix_epoch(nanoseconds).unwrap();
+            assert_eq!(datetime.nanoseconds_since_unix_epoch(), nanoseconds);
+            assert_eq!(
+                datetime.milliseconds_since_unix_epoch(),
+                nanoseconds.div_euclid(1_000_000) as i64
+            );
+            assert_eq!(
+                datetime.seconds_since_unix_epoch(),
+                nanoseconds.div_euclid(1_000_000_000) as i64
+            );
+        }
+
+        let datetime = DateTime::from_seconds_since_unix_epoch(253_402_300_799).unwrap();
+        assert_eq!(
+            datetime,
+            DateTime::new_iso_datetime(9999, 12, 31, 23, 59, 59).unwrap()
+        );
+
+        assert_eq!(
+            DateTime::from_seconds_since_unix_epoch(i64::MAX),
+            Err(DateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            DateTime::from_seconds_since_unix_epoch(i64::MIN),
+            Err(DateTimeError::OutOfRange)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_system_time() {
+        use std::time::{Duration, UNIX_EPOCH};
+        let before = UNIX_EPOCH - Duration::new(1, 500);
+        let datetime = DateTime::from_system_time(before).unwrap();
+        assert_eq!(datetime.nanoseconds_since_unix_epoch(), -1_000_000_500);
+        assert_eq!(datetime.to_system_time(), Ok(before));
+
+        let after = UNIX_EPOCH + Duration::new(1_582_979_415, 500);
+        let datetime = DateTime::from_system_time(after).unwrap();
+        assert_eq!(
+            datetime.nanoseconds_since_unix_epoch(),
+            1_582_979_415_000_000_500
+        );
+        assert_eq!(datetime.to_system_time(), Ok(after));
+    }
 }
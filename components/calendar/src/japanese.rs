@@ -195,6 +195,7 @@ impl Calendar for Japanese {
             era: types::Era(date.era),
             number: date.adjusted_year,
             related_iso: None,
+            cyclic: None,
         }
     }
 
@@ -57,11 +57,25 @@
 //! let newer_date_iso = Date::new_iso_date(2022, 1, 30)
 //!     .expect("Failed to initialize ISO Date instance.");
 //!
-//! // Comparing dates: 2022-01-30 and 1992-09-02.
-//! let duration = newer_date_iso.until(&date_iso, DateDurationUnit::Years, DateDurationUnit::Days);
-//! assert_eq!(duration.years, 30);
-//! assert_eq!(duration.months, -8);
+//! // Comparing dates: 1992-09-02 and 2022-01-30.
+//! let duration = date_iso.until(&newer_date_iso, DateDurationUnit::Years, DateDurationUnit::Days);
+//! assert_eq!(duration.years, 29);
+//! assert_eq!(duration.months, 4);
+//! assert_eq!(duration.days, 28);
+//!
+//! // The same duration, expressed in months and in weeks.
+//! let duration = date_iso.until(&newer_date_iso, DateDurationUnit::Months, DateDurationUnit::Days);
+//! assert_eq!(duration.months, 352);
 //! assert_eq!(duration.days, 28);
+//! let duration = date_iso.until(&newer_date_iso, DateDurationUnit::Weeks, DateDurationUnit::Days);
+//! assert_eq!(duration.weeks, 1534);
+//! assert_eq!(duration.days, 4);
+//!
+//! // Durations going back in time are negative.
+//! let duration = newer_date_iso.until(&date_iso, DateDurationUnit::Years, DateDurationUnit::Days);
+//! assert_eq!(duration.years, -29);
+//! assert_eq!(duration.months, -4);
+//! assert_eq!(duration.days, -28);
 //!
 //! // Create new date with date advancement. Reassign to new variable.
 //! let mutated_date_iso = date_iso.added(DateDuration::new(1, 2, 3, 4));
@@ -151,21 +165,29 @@ extern crate alloc;
 
 pub mod any_calendar;
 pub mod arithmetic;
+mod astronomy;
 pub mod buddhist;
 mod calendar;
 mod calendar_arithmetic;
+pub mod chinese;
+mod chinese_based;
 pub mod coptic;
+pub mod dangi;
 mod date;
 mod datetime;
 mod duration;
 mod error;
 pub mod ethiopic;
 pub mod gregorian;
+pub mod hebrew;
 pub mod indian;
+pub mod islamic;
 pub mod iso;
 pub mod japanese;
 pub mod julian;
+pub mod persian;
 pub mod provider;
+pub mod roc;
 pub mod types;
 
 pub use any_calendar::{AnyCalendar, AnyCalendarKind};
@@ -173,7 +195,10 @@ pub use calendar::Calendar;
 pub use calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
 pub use date::{AsCalendar, Date, Ref};
 pub use datetime::DateTime;
-pub use duration::{DateDuration, DateDurationUnit};
+pub use duration::{
+    DateDuration, DateDurationUnit, DateTimeDuration, DateTimeDurationUnit, TimeDuration,
+    TimeDurationUnit,
+};
 pub use error::DateTimeError;
 pub use gregorian::Gregorian;
 pub use iso::Iso;
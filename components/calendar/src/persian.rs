@@ -0,0 +1,316 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Persian calendar.
+//!
+//! ```rust
+//! use icu::calendar::{persian::Persian, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_persian = Date::new_from_iso(date_iso, Persian);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_persian = DateTime::new_from_iso(datetime_iso, Persian);
+//!
+//! // `Date` checks
+//! assert_eq!(date_persian.year().number, 1348);
+//! assert_eq!(date_persian.month().ordinal, 10);
+//! assert_eq!(date_persian.day_of_month().0, 12);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_persian.date.year().number, 1348);
+//! assert_eq!(datetime_persian.date.month().ordinal, 10);
+//! assert_eq!(datetime_persian.date.day_of_month().0, 12);
+//! assert_eq!(datetime_persian.time.hour.number(), 13);
+//! assert_eq!(datetime_persian.time.minute.number(), 1);
+//! assert_eq!(datetime_persian.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::iso::Iso;
+use crate::{
+    types, ArithmeticDate, Calendar, CalendarArithmetic, Date, DateDuration, DateDurationUnit,
+    DateTime, DateTimeError,
+};
+use tinystr::tinystr;
+
+// The epoch of the 33-year arithmetical rule, March 18th of 622 CE in the proleptic Julian
+// calendar. This is one day before the traditional epoch, so that the rule gives the correct
+// new years for the years in which it agrees with the astronomical calendar.
+const PERSIAN_EPOCH: i32 = 226895;
+
+/// The Persian (Solar Hijri) calendar
+///
+/// This is the arithmetical Persian calendar used by ICU, in which 8 out of every 33 years
+/// are leap years. It agrees with the astronomical calendar used in Iran for several
+/// centuries around the present.
+///
+/// The first six months have 31 days, the next five have 30 days, and the last month,
+/// Esfand, has 29 days, or 30 in leap years. There is a single era, `"ap"`.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Persian;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+// The inner date type used for representing Date<Persian>
+pub struct PersianDateInner(pub(crate) ArithmeticDate<Persian>);
+
+impl CalendarArithmetic for Persian {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            12 if Self::is_leap_year(year) => 30,
+            12 => 29,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (25 * year as i64 + 11).rem_euclid(33) < 8
+    }
+}
+
+impl Calendar for Persian {
+    type DateInner = PersianDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "ap") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(PersianDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> PersianDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::persian_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_persian = Self::fixed_from_persian(date.0);
+        Iso::iso_from_fixed(fixed_persian)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Persian.date_to_iso(date).inner())
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_persian(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_persian(prev_year),
+            days_in_prev_year: Persian::days_in_provided_year(prev_year),
+            next_year: year_as_persian(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Persian"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Persian)
+    }
+}
+
+impl Persian {
+    // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+    //
+    // These are the 33-year cycle algorithms used by ICU4C (`PersianCalendar::handleComputeMonthStart`
+    // and `PersianCalendar::handleComputeFields`).
+    //
+    // The fixed date of 1 Farvardin of `year`
+    fn new_year(year: i32) -> i32 {
+        PERSIAN_EPOCH + 365 * (year - 1) + (8 * year as i64 + 21).div_euclid(33) as i32
+    }
+
+    fn fixed_from_persian(date: ArithmeticDate<Persian>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    fn persian_from_fixed(date: i32) -> PersianDateInner {
+        let days_since_epoch = date as i64 - PERSIAN_EPOCH as i64;
+        let year = 1 + (33 * days_since_epoch + 3).div_euclid(12053) as i32;
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        PersianDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl Date<Persian> {
+    /// Construct new Persian Date.
+    ///
+    /// Years before 1 AP are numbered 0, -1, -2, ... in the same era.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_persian =
+    ///     Date::new_persian_date(1402, 1, 1).expect("Failed to initialize Persian Date instance.");
+    ///
+    /// assert_eq!(date_persian.year().number, 1402);
+    /// assert_eq!(date_persian.month().ordinal, 1);
+    /// assert_eq!(date_persian.day_of_month().0, 1);
+    /// ```
+    pub fn new_persian_date(year: i32, month: u8, day: u8) -> Result<Date<Persian>, DateTimeError> {
+        let inner = ArithmeticDate::new(year, month, day);
+
+        if month == 0 || month > inner.months_in_year() {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(PersianDateInner(inner), Persian))
+    }
+}
+
+impl DateTime<Persian> {
+    /// Construct a new Persian datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_persian = DateTime::new_persian_datetime(1402, 12, 29, 13, 1, 0)
+    ///     .expect("Failed to initialize Persian DateTime instance.");
+    ///
+    /// assert_eq!(datetime_persian.date.year().number, 1402);
+    /// assert_eq!(datetime_persian.date.month().ordinal, 12);
+    /// assert_eq!(datetime_persian.date.day_of_month().0, 29);
+    /// assert_eq!(datetime_persian.time.hour.number(), 13);
+    /// assert_eq!(datetime_persian.time.minute.number(), 1);
+    /// assert_eq!(datetime_persian.time.second.number(), 0);
+    /// ```
+    pub fn new_persian_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Persian>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_persian_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_persian(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "ap")),
+        number: year,
+        related_iso: None,
+        cyclic: None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        // (persian year, iso date of 1 Farvardin, leap year)
+        let cases = [
+            (1, (622, 3, 21), true),
+            (1178, (1799, 3, 21), false),
+            (1300, (1921, 3, 21), true),
+            (1348, (1969, 3, 21), false),
+            (1354, (1975, 3, 21), true),
+            (1375, (1996, 3, 20), true),
+            (1399, (2020, 3, 20), true),
+            (1402, (2023, 3, 21), false),
+            (1403, (2024, 3, 20), true),
+            (1404, (2025, 3, 21), false),
+        ];
+        for (year, (y, m, d), leap) in cases {
+            let iso = Date::new_iso_date(y, m, d).unwrap();
+            let persian = Date::new_persian_date(year, 1, 1).unwrap();
+            assert_eq!(persian.to_iso(), iso, "{}", year);
+            assert_eq!(Persian::is_leap_year(year), leap, "{}", year);
+            assert_eq!(Date::new_from_iso(iso, Persian), persian, "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let start = Persian::new_year(-100);
+        let end = Persian::new_year(2000);
+        let mut expected = Persian::persian_from_fixed(start).0;
+        assert_eq!((expected.year, expected.month, expected.day), (-100, 1, 1));
+        for fixed in start..end {
+            let date = Persian::persian_from_fixed(fixed);
+            assert_eq!(date.0, expected, "{}", fixed);
+            assert_eq!(Persian::fixed_from_persian(date.0), fixed);
+            expected.offset_date(DateDuration::new(0, 0, 0, 1));
+        }
+    }
+}
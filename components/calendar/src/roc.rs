@@ -0,0 +1,261 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Republic of China calendar.
+//!
+//! ```rust
+//! use icu::calendar::{roc::Roc, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_roc = Date::new_from_iso(date_iso, Roc);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_roc = DateTime::new_from_iso(datetime_iso, Roc);
+//!
+//! // `Date` checks
+//! assert_eq!(date_roc.year().number, 59);
+//! assert_eq!(date_roc.year().era.0, "roc");
+//! assert_eq!(date_roc.month().ordinal, 1);
+//! assert_eq!(date_roc.day_of_month().0, 2);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_roc.date.year().number, 59);
+//! assert_eq!(datetime_roc.date.month().ordinal, 1);
+//! assert_eq!(datetime_roc.date.day_of_month().0, 2);
+//! assert_eq!(datetime_roc.time.hour.number(), 13);
+//! assert_eq!(datetime_roc.time.minute.number(), 1);
+//! assert_eq!(datetime_roc.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::iso::{Iso, IsoDateInner};
+use crate::{
+    types, ArithmeticDate, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError,
+};
+use tinystr::tinystr;
+
+/// The number of years the ISO calendar is ahead of the Republic of China calendar by
+///
+/// (1912 AD = 1 Minguo)
+const ROC_ERA_OFFSET: i32 = 1911;
+
+#[derive(Copy, Clone, Debug, Default)]
+/// The [Republic of China Calendar][cal] (Minguo calendar)
+///
+/// This is the same as the Gregorian calendar, but with years counted from the
+/// founding of the Republic of China in 1912 AD. It has two eras: `"roc"` for 1912 AD
+/// and later (Minguo), and `"roc-inverse"` for earlier years (Before R.O.C.), counted
+/// backwards from 1911 AD = 1 Before R.O.C.
+///
+/// [cal]: https://en.wikipedia.org/wiki/Republic_of_China_calendar
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Roc;
+
+impl Calendar for Roc {
+    type DateInner = IsoDateInner;
+
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        let year = if era.0 == tinystr!(16, "roc") {
+            if year <= 0 {
+                return Err(DateTimeError::OutOfRange);
+            }
+            year + ROC_ERA_OFFSET
+        } else if era.0 == tinystr!(16, "roc-inverse") {
+            if year <= 0 {
+                return Err(DateTimeError::OutOfRange);
+            }
+            1 - year + ROC_ERA_OFFSET
+        } else {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        };
+
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IsoDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
+        *iso.inner()
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(*date, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(date)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(date)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(date)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(date, offset.cast_unit())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(date1, date2, &Iso, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    /// The calendar-specific year represented by `date`
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        iso_year_as_roc(date.0.year)
+    }
+
+    /// The calendar-specific month represented by `date`
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        Iso.month(date)
+    }
+
+    /// The calendar-specific day-of-month represented by `date`
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(date)
+    }
+
+    /// Information of the day of the year
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(*date),
+            days_in_year: Iso::days_in_year_direct(date.0.year),
+            prev_year: iso_year_as_roc(prev_year),
+            days_in_prev_year: Iso::days_in_year_direct(prev_year),
+            next_year: iso_year_as_roc(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "ROC"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Roc)
+    }
+}
+
+impl Date<Roc> {
+    /// Construct a new Republic of China Date.
+    ///
+    /// Years are specified as extended Minguo years: 1 is 1912 AD, and 0 and negative
+    /// years are in the `"roc-inverse"` era, starting with 0 = 1 Before R.O.C.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_roc = Date::new_roc_date(112, 1, 2).expect("Failed to initialize ROC Date instance.");
+    ///
+    /// assert_eq!(date_roc.year().number, 112);
+    /// assert_eq!(date_roc.month().ordinal, 1);
+    /// assert_eq!(date_roc.day_of_month().0, 2);
+    /// assert_eq!(date_roc.to_iso().year().number, 2023);
+    /// ```
+    pub fn new_roc_date(year: i32, month: u8, day: u8) -> Result<Date<Roc>, DateTimeError> {
+        Date::new_iso_date(year + ROC_ERA_OFFSET, month, day).map(|d| Date::new_from_iso(d, Roc))
+    }
+}
+
+impl DateTime<Roc> {
+    /// Construct a new Republic of China datetime from integers.
+    ///
+    /// Years are specified as extended Minguo years, see [`Date::new_roc_date()`].
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_roc = DateTime::new_roc_datetime(112, 1, 2, 13, 1, 0)
+    ///     .expect("Failed to initialize ROC DateTime instance.");
+    ///
+    /// assert_eq!(datetime_roc.date.year().number, 112);
+    /// assert_eq!(datetime_roc.date.month().ordinal, 1);
+    /// assert_eq!(datetime_roc.date.day_of_month().0, 2);
+    /// assert_eq!(datetime_roc.time.hour.number(), 13);
+    /// assert_eq!(datetime_roc.time.minute.number(), 1);
+    /// assert_eq!(datetime_roc.time.second.number(), 0);
+    /// ```
+    pub fn new_roc_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Roc>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_roc_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn iso_year_as_roc(year: i32) -> types::FormattableYear {
+    if year > ROC_ERA_OFFSET {
+        types::FormattableYear {
+            era: types::Era(tinystr!(16, "roc")),
+            number: year - ROC_ERA_OFFSET,
+            related_iso: None,
+            cyclic: None,
+        }
+    } else {
+        types::FormattableYear {
+            era: types::Era(tinystr!(16, "roc-inverse")),
+            number: ROC_ERA_OFFSET + 1 - year,
+            related_iso: None,
+            cyclic: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eras() {
+        // (ISO year, era, year in era)
+        let cases = [
+            (2023, "roc", 112),
+            (1912, "roc", 1),
+            (1911, "roc-inverse", 1),
+            (1900, "roc-inverse", 12),
+        ];
+        for (iso_year, era, year) in cases {
+            let iso = Date::new_iso_date(iso_year, 6, 1).unwrap();
+            let roc = Date::new_from_iso(iso, Roc);
+            assert_eq!(roc.year().era.0, era, "{}", iso_year);
+            assert_eq!(roc.year().number, year, "{}", iso_year);
+
+            let from_codes = Date::new_from_codes(
+                roc.year().era,
+                year,
+                types::MonthCode(tinystr!(4, "M06")),
+                1,
+                Roc,
+            )
+            .unwrap();
+            assert_eq!(from_codes.to_iso().year().number, iso_year);
+        }
+    }
+}
@@ -4,10 +4,12 @@
 
 //! This module contains various types used by `icu_calendar` and `icu_datetime`
 
+use crate::duration::{TimeDuration, TimeDurationUnit, NANOSECONDS_IN_DAY};
 use crate::error::DateTimeError;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt;
+use core::num::NonZeroU8;
 use core::str::FromStr;
 use tinystr::{TinyStr16, TinyStr4};
 use zerovec::maps::ZeroMapKV;
@@ -33,7 +35,7 @@ impl FromStr for Era {
 /// Representation of a formattable year.
 ///
 /// More fields may be added in the future, for things like
-/// the cyclic or extended year
+/// the extended year
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct FormattableYear {
@@ -49,6 +51,13 @@ pub struct FormattableYear {
     /// Can be None if the calendar does not typically use related_iso (and CLDR does not contain patterns
     /// using it)
     pub related_iso: Option<i32>,
+
+    /// The year in the 60-year sexagenary cycle (1-based), for calendars that name
+    /// their years by their position in the cycle, like the Chinese and Dangi calendars.
+    /// It is used to format cyclic year names.
+    ///
+    /// None for calendars that do not use a year cycle
+    pub cyclic: Option<NonZeroU8>,
 }
 
 impl FormattableYear {
@@ -61,6 +70,7 @@ impl FormattableYear {
             era,
             number,
             related_iso: None,
+            cyclic: None,
         }
     }
 }
@@ -457,6 +467,72 @@ impl Time {
             nanosecond: nanosecond.try_into()?,
         })
     }
+
+    /// Adds a [`TimeDuration`] to this time, wrapping around midnight.
+    ///
+    /// Returns the number of days by which the result overflowed, which is negative
+    /// if the result is on an earlier day.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::Time;
+    /// use icu::calendar::TimeDuration;
+    ///
+    /// let mut time = Time::try_new(20, 30, 0, 0).unwrap();
+    /// let days = time.add(TimeDuration::new(36, 15, 0, 0));
+    ///
+    /// assert_eq!(days, 2);
+    /// assert_eq!(time, Time::try_new(8, 45, 0, 0).unwrap());
+    /// ```
+    pub fn add(&mut self, duration: TimeDuration) -> i64 {
+        let total = self.nanoseconds_since_midnight() as i128 + duration.total_nanoseconds();
+        let day = NANOSECONDS_IN_DAY as i128;
+        *self = Self::from_nanoseconds_since_midnight(total.rem_euclid(day) as i64);
+        total.div_euclid(day) as i64
+    }
+
+    /// Calculates the [`TimeDuration`] from this time to `other` on the same day,
+    /// balanced up to `largest_unit`.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::Time;
+    /// use icu::calendar::{TimeDuration, TimeDurationUnit};
+    ///
+    /// let start = Time::try_new(20, 30, 0, 0).unwrap();
+    /// let end = Time::try_new(8, 45, 10, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     start.until(&end, TimeDurationUnit::Hours),
+    ///     TimeDuration::new(-11, -44, -50, 0)
+    /// );
+    /// assert_eq!(
+    ///     start.until(&end, TimeDurationUnit::Minutes),
+    ///     TimeDuration::new(0, -704, -50, 0)
+    /// );
+    /// ```
+    pub fn until(&self, other: &Time, largest_unit: TimeDurationUnit) -> TimeDuration {
+        let difference = other.nanoseconds_since_midnight() - self.nanoseconds_since_midnight();
+        TimeDuration::from_nanoseconds(difference as i128, largest_unit)
+    }
+
+    /// The number of nanoseconds since midnight
+    ///
+    /// This exceeds [`NANOSECONDS_IN_DAY`] for leap seconds and for 24:00.
+    pub(crate) fn nanoseconds_since_midnight(&self) -> i64 {
+        ((self.hour.0 as i64 * 60 + self.minute.0 as i64) * 60 + self.second.0 as i64)
+            * 1_000_000_000
+            + self.nanosecond.0 as i64
+    }
+
+    /// The time `nanoseconds` after midnight, for `0 <= nanoseconds < NANOSECONDS_IN_DAY`
+    pub(crate) fn from_nanoseconds_since_midnight(nanoseconds: i64) -> Self {
+        let seconds = nanoseconds.div_euclid(1_000_000_000);
+        Self {
+            hour: IsoHour((seconds / 3600) as u8),
+            minute: IsoMinute((seconds / 60 % 60) as u8),
+            second: IsoSecond((seconds % 60) as u8),
+            nanosecond: NanoSecond(nanoseconds.rem_euclid(1_000_000_000) as u32),
+        }
+    }
 }
 
 /// A weekday in a 7-day week, according to ISO-8601.
@@ -63,7 +63,7 @@ impl DateFormatter {
     /// [`AnyProvider`].
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/quarters@1`, `datetime/numbering@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
 
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
@@ -86,7 +86,7 @@ impl DateFormatter {
     /// [`BufferProvider`].
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/quarters@1`, `datetime/numbering@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
@@ -173,6 +173,8 @@ impl DateFormatter {
             + DataProvider<DateSkeletonPatternsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<GregorianDateLengthsV1Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
@@ -181,6 +183,9 @@ impl DateFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopicDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<JapaneseDateSymbolsV1Marker>
@@ -188,6 +193,9 @@ impl DateFormatter {
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopicDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             + ?Sized,
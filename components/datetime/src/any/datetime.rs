@@ -107,14 +107,14 @@ use icu_provider::DataLocale;
 /// This model replicates that of `ICU` and `ECMA402`.
 ///
 /// [data provider]: icu_provider
-pub struct DateTimeFormatter(pub(crate) raw::DateTimeFormatter, AnyCalendar);
+pub struct DateTimeFormatter(pub(crate) raw::DateTimeFormatter, pub(crate) AnyCalendar);
 
 impl DateTimeFormatter {
     /// Construct a new [`DateTimeFormatter`] from a data provider that implements
     /// [`AnyProvider`].
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/quarters@1`, `datetime/numbering@1`, `datetime/dayperiods@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
 
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
@@ -137,7 +137,7 @@ impl DateTimeFormatter {
     /// [`BufferProvider`].
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/quarters@1`, `datetime/numbering@1`, `datetime/dayperiods@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
@@ -224,6 +224,9 @@ impl DateTimeFormatter {
             + DataProvider<DateSkeletonPatternsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<GregorianDateLengthsV1Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
@@ -232,6 +235,9 @@ impl DateTimeFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopicDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<JapaneseDateSymbolsV1Marker>
@@ -239,6 +245,9 @@ impl DateTimeFormatter {
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopicDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             + ?Sized,
@@ -0,0 +1,282 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{
+    calendar,
+    options::{components, DateTimeFormatterOptions},
+    raw,
+};
+use alloc::string::String;
+
+use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
+
+use icu_provider::prelude::*;
+
+use crate::provider::{calendar::*, week_data::WeekDataV1Marker};
+use crate::{input::DateTimeInput, DateTimeFormatterError, FormattedDateTimeInterval};
+use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
+use icu_calendar::provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
+use icu_calendar::{types::Time, DateTime};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_plurals::provider::OrdinalV1Marker;
+use icu_provider::DataLocale;
+
+/// [`DateTimeIntervalFormatter`] is a formatter capable of formatting the interval between two
+/// date/times from any calendar, selected at runtime. For the difference between this and
+/// [`TypedDateTimeIntervalFormatter`](crate::TypedDateTimeIntervalFormatter), please read the
+/// [crate root docs][crate].
+///
+/// See [`TypedDateTimeIntervalFormatter`](crate::TypedDateTimeIntervalFormatter) for how the
+/// interval pattern is selected.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::datetime::{options::length, DateTimeIntervalFormatter};
+/// use icu::locid::Locale;
+/// use std::str::FromStr;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let options = length::Bag::from_time_style(length::Time::Short);
+///
+/// let dtif = DateTimeIntervalFormatter::try_new_with_buffer_provider(&provider, &Locale::from_str("en-u-ca-gregory").unwrap().into(), options.into())
+///     .expect("Failed to create DateTimeIntervalFormatter instance.");
+///
+/// let first = DateTime::new_gregorian_datetime(2020, 9, 1, 10, 0, 0)
+///     .expect("Failed to construct DateTime.");
+/// let second = DateTime::new_gregorian_datetime(2020, 9, 1, 11, 30, 0)
+///     .expect("Failed to construct DateTime.");
+///
+/// let value = dtif.format_to_string(&first.to_any(), &second.to_any()).expect("calendars should match");
+/// assert_eq!(value, "10:00 – 11:30 AM");
+/// ```
+///
+/// [data provider]: icu_provider
+pub struct DateTimeIntervalFormatter(pub(crate) raw::DateTimeIntervalFormatter, AnyCalendar);
+
+impl DateTimeIntervalFormatter {
+    /// Construct a new [`DateTimeIntervalFormatter`] from a data provider that implements
+    /// [`AnyProvider`].
+    ///
+    /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
+    /// `datetime/timelengths@1`, `datetime/skeletons@1`, `datetime/intervalformats@1`, `datetime/quarters@1`, `datetime/dayperiods@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    ///
+    /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
+    ///
+    /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    #[inline]
+    pub fn try_new_with_any_provider<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: AnyProvider,
+    {
+        let downcasting = data_provider.as_downcasting();
+        Self::try_new_unstable(&downcasting, locale, options)
+    }
+
+    /// Construct a new [`DateTimeIntervalFormatter`] from a data provider that implements
+    /// [`BufferProvider`].
+    ///
+    /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
+    /// `datetime/timelengths@1`, `datetime/skeletons@1`, `datetime/intervalformats@1`, `datetime/quarters@1`, `datetime/dayperiods@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    ///
+    /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
+    ///
+    /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: BufferProvider,
+    {
+        let deserializing = data_provider.as_deserializing();
+        Self::try_new_unstable(&deserializing, locale, options)
+    }
+
+    /// Construct a new [`DateTimeIntervalFormatter`] from a data provider that can provide all of the requested data.
+    ///
+    /// This method is **unstable**, more bounds may be added in the future as calendar support is added. It is
+    /// preferable to use a provider that implements `DataProvider<D>` for all `D`, and ensure data is loaded as
+    /// appropriate. The [`Self::try_new_with_buffer_provider()`], [`Self::try_new_with_any_provider()`] constructors
+    /// may also be used if compile stability is desired.
+    #[inline(never)]
+    pub fn try_new_unstable<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<DateSkeletonPatternsV1Marker>
+            + DataProvider<DateIntervalFormatsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<EthiopicDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<EthiopicDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + ?Sized,
+    {
+        // TODO(#2188): Avoid cloning the DataLocale by passing the calendar
+        // separately into the raw formatter.
+        let mut locale_with_cal = locale.clone();
+
+        // TODO (#2038), DO NOT SHIP 1.0 without fixing this
+        let kind = if let Ok(kind) = AnyCalendarKind::from_data_locale(&locale_with_cal) {
+            kind
+        } else {
+            locale_with_cal.set_unicode_ext(key!("ca"), value!("gregory"));
+            AnyCalendarKind::Gregorian
+        };
+
+        // We share data under ethiopic
+        if kind == AnyCalendarKind::Ethioaa {
+            locale_with_cal.set_unicode_ext(key!("ca"), value!("ethiopic"));
+        }
+
+        let calendar = AnyCalendar::try_new_unstable(data_provider, kind)?;
+
+        Ok(Self(
+            raw::DateTimeIntervalFormatter::try_new(
+                data_provider,
+                calendar::load_lengths_for_any_calendar_kind(data_provider, locale, kind)?,
+                || calendar::load_symbols_for_any_calendar_kind(data_provider, locale, kind),
+                locale_with_cal,
+                options,
+            )?,
+            calendar,
+        ))
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns an instance of a
+    /// [`FormattedDateTimeInterval`] that contains all information necessary to display the
+    /// formatted interval between them.
+    ///
+    /// This function will fail if the dates passed in use a different calendar than that of the
+    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
+    /// will automatically convert and format dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format<'l>(
+        &'l self,
+        first: &impl DateTimeInput<Calendar = AnyCalendar>,
+        second: &impl DateTimeInput<Calendar = AnyCalendar>,
+    ) -> Result<FormattedDateTimeInterval<'l>, DateTimeFormatterError> {
+        Ok(
+            match (
+                self.convert_if_necessary(first)?,
+                self.convert_if_necessary(second)?,
+            ) {
+                (Some(first), Some(second)) => self.0.format(&first, &second),
+                (Some(first), None) => self.0.format(&first, second),
+                (None, Some(second)) => self.0.format(first, &second),
+                (None, None) => self.0.format(first, second),
+            },
+        )
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and two [`DateTimeInput`] implementers and populates the buffer with the formatted interval.
+    ///
+    /// This function will fail if the dates passed in use a different calendar than that of the
+    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
+    /// will automatically convert and format dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format_to_write(
+        &self,
+        w: &mut impl core::fmt::Write,
+        first: &impl DateTimeInput<Calendar = AnyCalendar>,
+        second: &impl DateTimeInput<Calendar = AnyCalendar>,
+    ) -> Result<(), DateTimeFormatterError> {
+        use writeable::Writeable;
+        self.format(first, second)?.write_to(w)?;
+        Ok(())
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns the interval between them formatted
+    /// as a string.
+    ///
+    /// This function will fail if the dates passed in use a different calendar than that of the
+    /// AnyCalendar. Please convert dates before passing them in if necessary. This function
+    /// will automatically convert and format dates that are associated with the ISO calendar.
+    #[inline]
+    pub fn format_to_string(
+        &self,
+        first: &impl DateTimeInput<Calendar = AnyCalendar>,
+        second: &impl DateTimeInput<Calendar = AnyCalendar>,
+    ) -> Result<String, DateTimeFormatterError> {
+        let mut s = String::new();
+        self.format_to_write(&mut s, first, second)?;
+        Ok(s)
+    }
+
+    /// Returns a [`components::Bag`] that represents the resolved components for the
+    /// options that were provided to the [`DateTimeIntervalFormatter`].
+    pub fn resolve_components(&self) -> components::Bag {
+        self.0.resolve_components()
+    }
+
+    /// Converts a date to the correct calendar if necessary
+    ///
+    /// Returns Err if the date is not ISO or compatible with the current calendar, returns Ok(None)
+    /// if the date is compatible with the current calendar and doesn't need conversion
+    fn convert_if_necessary<'a>(
+        &'a self,
+        value: &impl DateTimeInput<Calendar = AnyCalendar>,
+    ) -> Result<Option<DateTime<icu_calendar::Ref<'a, AnyCalendar>>>, DateTimeFormatterError> {
+        let this_calendar = self.1.kind();
+        let date_calendar = value.any_calendar_kind();
+
+        if Some(this_calendar) != date_calendar {
+            if date_calendar != Some(AnyCalendarKind::Iso) {
+                return Err(DateTimeFormatterError::MismatchedAnyCalendar(
+                    this_calendar,
+                    date_calendar,
+                ));
+            }
+            let date = value.to_iso();
+            let time = Time::new(
+                value.hour().unwrap_or_default(),
+                value.minute().unwrap_or_default(),
+                value.second().unwrap_or_default(),
+                value.nanosecond().unwrap_or_default(),
+            );
+            let datetime = DateTime::new(date, time).to_any();
+            let converted = self.1.convert_any_datetime(&datetime);
+            Ok(Some(converted))
+        } else {
+            Ok(None)
+        }
+    }
+}
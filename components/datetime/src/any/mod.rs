@@ -7,8 +7,10 @@
 
 mod date;
 mod datetime;
+mod interval;
 mod zoned_datetime;
 
 pub use date::DateFormatter;
 pub use datetime::DateTimeFormatter;
+pub use interval::DateTimeIntervalFormatter;
 pub use zoned_datetime::ZonedDateTimeFormatter;
@@ -8,8 +8,8 @@ use alloc::string::ToString;
 use core::any;
 use icu_calendar::any_calendar::AnyCalendarKind;
 use icu_calendar::{
-    buddhist::Buddhist, coptic::Coptic, ethiopic::Ethiopic, indian::Indian, japanese::Japanese,
-    japanese::JapaneseExtended, Gregorian,
+    buddhist::Buddhist, coptic::Coptic, ethiopic::Ethiopic, hebrew::Hebrew, indian::Indian,
+    japanese::Japanese, japanese::JapaneseExtended, persian::Persian, roc::Roc, Gregorian,
 };
 use icu_locid::extensions::unicode::Value;
 use icu_locid::extensions_unicode_key as key;
@@ -86,6 +86,24 @@ impl CldrCalendar for Ethiopic {
     }
 }
 
+impl CldrCalendar for Hebrew {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("hebrew");
+    type DateSymbolsV1Marker = HebrewDateSymbolsV1Marker;
+    type DateLengthsV1Marker = HebrewDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Persian {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("persian");
+    type DateSymbolsV1Marker = PersianDateSymbolsV1Marker;
+    type DateLengthsV1Marker = PersianDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Roc {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("roc");
+    type DateSymbolsV1Marker = RocDateSymbolsV1Marker;
+    type DateLengthsV1Marker = RocDateLengthsV1Marker;
+}
+
 pub(crate) fn potentially_fixup_calendar<C: CldrCalendar>(
     locale: &mut DataLocale,
 ) -> Result<(), DateTimeFormatterError> {
@@ -155,6 +173,9 @@ where
         + DataProvider<CopticDateLengthsV1Marker>
         + DataProvider<IndianDateLengthsV1Marker>
         + DataProvider<EthiopicDateLengthsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
         + ?Sized,
 {
     let req = DataRequest {
@@ -202,6 +223,21 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
         _ => {
             return Err(
                 DataError::custom("Don't know how to load data for specified calendar")
@@ -225,6 +261,9 @@ where
         + DataProvider<CopticDateSymbolsV1Marker>
         + DataProvider<IndianDateSymbolsV1Marker>
         + DataProvider<EthiopicDateSymbolsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
         + ?Sized,
 {
     let req = DataRequest {
@@ -272,6 +311,21 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
         _ => {
             return Err(
                 DataError::custom("Don't know how to load data for specified calendar")
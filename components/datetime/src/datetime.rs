@@ -7,7 +7,10 @@
 
 use crate::{
     options::{components, length, preferences, DateTimeFormatterOptions},
-    provider::calendar::{DateSkeletonPatternsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker},
+    provider::calendar::{
+        DateNumberingOverridesV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+        QuarterSymbolsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker,
+    },
     provider::week_data::WeekDataV1Marker,
     raw,
 };
@@ -91,6 +94,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
@@ -264,6 +268,8 @@ impl<C: CldrCalendar> TypedDateFormatter<C> {
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + ?Sized,
     {
         // TODO(#2188): Avoid cloning the DataLocale by passing the calendar
@@ -500,6 +506,9 @@ where {
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + ?Sized,
     {
         // TODO(#2188): Avoid cloning the DataLocale by passing the calendar
@@ -71,6 +71,52 @@ pub enum DateTimeFormatterError {
 #[cfg(feature = "std")]
 impl std::error::Error for DateTimeFormatterError {}
 
+/// A list of possible error outcomes for the [`DateTimeParser`](crate::DateTimeParser) struct.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DateTimeParseError {
+    /// The input does not contain the literal text of the pattern.
+    #[displaydoc("Expected {expected:?} at byte {position}")]
+    ExpectedLiteral {
+        /// The byte offset in the input at which the literal was expected.
+        position: usize,
+        /// The character of the pattern that was expected.
+        expected: char,
+    },
+    /// The input does not contain a valid value for a field of the pattern.
+    #[displaydoc("Invalid value for field {field:?} at byte {position}")]
+    InvalidField {
+        /// The byte offset in the input at which the value was expected.
+        position: usize,
+        /// The field of the pattern that was being parsed.
+        field: FieldSymbol,
+    },
+    /// The input continues after the end of the pattern.
+    #[displaydoc("Unexpected input at byte {0}")]
+    TrailingInput(usize),
+    /// The pattern contains a field that cannot be parsed.
+    #[displaydoc("Unsupported field: {0:?}")]
+    UnsupportedField(FieldSymbol),
+    /// The pattern does not contain a field that is required to construct a date.
+    #[displaydoc("Missing field: {0}")]
+    MissingField(&'static str),
+    /// The weekday in the input does not match the weekday of the parsed date.
+    #[displaydoc("Weekday does not match the date")]
+    MismatchedWeekday,
+    /// The parsed fields do not form a valid date or time.
+    #[displaydoc("{0}")]
+    DateTimeInput(DateTimeError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateTimeParseError {}
+
+impl From<DateTimeError> for DateTimeParseError {
+    fn from(e: DateTimeError) -> Self {
+        DateTimeParseError::DateTimeInput(e)
+    }
+}
+
 impl From<PatternError> for DateTimeFormatterError {
     fn from(e: PatternError) -> Self {
         DateTimeFormatterError::Pattern(e)
@@ -68,6 +68,7 @@ impl Field {
             FieldSymbol::Minute => TextOrNumeric::Numeric,
             FieldSymbol::Second(second) => second.get_length_type(self.length),
             FieldSymbol::TimeZone(zone) => zone.get_length_type(self.length),
+            FieldSymbol::Quarter(quarter) => quarter.get_length_type(self.length),
         }
     }
 }
@@ -58,6 +58,8 @@ pub enum FieldSymbol {
     Second(Second),
     /// Time zone as a name, a zone ID, or a ISO 8601 numerical offset.
     TimeZone(TimeZone),
+    /// Quarter number or quarter name.
+    Quarter(Quarter),
 }
 
 impl FieldSymbol {
@@ -109,6 +111,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => (8, 0),
             FieldSymbol::Second(second) => (9, second.idx()),
             FieldSymbol::TimeZone(tz) => (10, tz.idx()),
+            FieldSymbol::Quarter(quarter) => (11, quarter.idx()),
         };
         let result = high << 4;
         result | low
@@ -133,6 +136,7 @@ impl FieldSymbol {
             8 if low == 0 => Self::Minute,
             9 => Self::Second(Second::from_idx(low)?),
             10 => Self::TimeZone(TimeZone::from_idx(low)?),
+            11 => Self::Quarter(Quarter::from_idx(low)?),
             _ => return Err(SymbolError::InvalidIndex(idx)),
         })
     }
@@ -151,6 +155,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => 8,
             FieldSymbol::Second(_) => 9,
             FieldSymbol::TimeZone(_) => 10,
+            FieldSymbol::Quarter(_) => 11,
         }
     }
 
@@ -231,34 +236,40 @@ impl FieldSymbol {
             Self::Era => 0,
             Self::Year(Year::Calendar) => 1,
             Self::Year(Year::WeekOf) => 2,
-            Self::Month(Month::Format) => 3,
-            Self::Month(Month::StandAlone) => 4,
-            Self::Week(Week::WeekOfYear) => 5,
-            Self::Week(Week::WeekOfMonth) => 6,
-            Self::Day(Day::DayOfMonth) => 7,
-            Self::Day(Day::DayOfYear) => 8,
-            Self::Day(Day::DayOfWeekInMonth) => 9,
-            Self::Day(Day::ModifiedJulianDay) => 10,
-            Self::Weekday(Weekday::Format) => 11,
-            Self::Weekday(Weekday::Local) => 12,
-            Self::Weekday(Weekday::StandAlone) => 13,
-            Self::DayPeriod(DayPeriod::AmPm) => 14,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
-            Self::Hour(Hour::H11) => 16,
-            Self::Hour(Hour::H12) => 17,
-            Self::Hour(Hour::H23) => 18,
-            Self::Hour(Hour::H24) => 19,
-            Self::Minute => 20,
-            Self::Second(Second::Second) => 21,
-            Self::Second(Second::FractionalSecond) => 22,
-            Self::Second(Second::Millisecond) => 23,
-            Self::TimeZone(TimeZone::LowerZ) => 24,
-            Self::TimeZone(TimeZone::UpperZ) => 25,
-            Self::TimeZone(TimeZone::UpperO) => 26,
-            Self::TimeZone(TimeZone::LowerV) => 27,
-            Self::TimeZone(TimeZone::UpperV) => 28,
-            Self::TimeZone(TimeZone::LowerX) => 29,
-            Self::TimeZone(TimeZone::UpperX) => 30,
+            Self::Year(Year::Extended) => 3,
+            Self::Year(Year::Cyclic) => 4,
+            Self::Year(Year::RelatedIso) => 5,
+            Self::Quarter(Quarter::Format) => 6,
+            Self::Quarter(Quarter::StandAlone) => 7,
+            Self::Month(Month::Format) => 8,
+            Self::Month(Month::StandAlone) => 9,
+            Self::Week(Week::WeekOfYear) => 10,
+            Self::Week(Week::WeekOfMonth) => 11,
+            Self::Day(Day::DayOfMonth) => 12,
+            Self::Day(Day::DayOfYear) => 13,
+            Self::Day(Day::DayOfWeekInMonth) => 14,
+            Self::Day(Day::ModifiedJulianDay) => 15,
+            Self::Weekday(Weekday::Format) => 16,
+            Self::Weekday(Weekday::Local) => 17,
+            Self::Weekday(Weekday::StandAlone) => 18,
+            Self::DayPeriod(DayPeriod::AmPm) => 19,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 20,
+            Self::DayPeriod(DayPeriod::Flexible) => 21,
+            Self::Hour(Hour::H11) => 22,
+            Self::Hour(Hour::H12) => 23,
+            Self::Hour(Hour::H23) => 24,
+            Self::Hour(Hour::H24) => 25,
+            Self::Minute => 26,
+            Self::Second(Second::Second) => 27,
+            Self::Second(Second::FractionalSecond) => 28,
+            Self::Second(Second::Millisecond) => 29,
+            Self::TimeZone(TimeZone::LowerZ) => 30,
+            Self::TimeZone(TimeZone::UpperZ) => 31,
+            Self::TimeZone(TimeZone::UpperO) => 32,
+            Self::TimeZone(TimeZone::LowerV) => 33,
+            Self::TimeZone(TimeZone::UpperV) => 34,
+            Self::TimeZone(TimeZone::LowerX) => 35,
+            Self::TimeZone(TimeZone::UpperX) => 36,
         }
     }
 }
@@ -276,6 +287,7 @@ impl TryFrom<char> for FieldSymbol {
             Err(SymbolError::Unknown(ch))
         })
         .or_else(|_| Year::try_from(ch).map(Self::Year))
+        .or_else(|_| Quarter::try_from(ch).map(Self::Quarter))
         .or_else(|_| Month::try_from(ch).map(Self::Month))
         .or_else(|_| Week::try_from(ch).map(Self::Week))
         .or_else(|_| Day::try_from(ch).map(Self::Day))
@@ -308,6 +320,7 @@ impl From<FieldSymbol> for char {
             FieldSymbol::Minute => 'm',
             FieldSymbol::Second(second) => second.into(),
             FieldSymbol::TimeZone(time_zone) => time_zone.into(),
+            FieldSymbol::Quarter(quarter) => quarter.into(),
         }
     }
 }
@@ -443,11 +456,51 @@ field_type! (
         ///
         /// This works for “week of year” based calendars in which the year transition occurs on a week boundary; may differ from calendar year [`Year::Calendar`] near a year transition. This numeric year designation is used in conjunction with [`Week::WeekOfYear`], but can be used in non-Gregorian based calendar systems where week date processing is desired. The field length is interpreted in the same way as for [`Year::Calendar`].
         'Y' => WeekOf = 1,
+        /// Field symbol for extended year (numeric).
+        ///
+        /// This is a single number designating the year of this calendar system, encompassing all supra-year fields. For example, for the Julian calendar system, year numbers are positive, with an era of BCE or CE. An extended year value for the Julian calendar system assigns positive values to CE years and negative values to BCE years, with 1 BCE being year 0.
+        'u' => Extended = 2,
+        /// Field symbol for cyclic year name.
+        ///
+        /// Calendars such as the Chinese lunar calendar (and related calendars) and the Hindu calendars use 60-year cycles of year names. If the calendar does not provide cyclic year name data, or if the year value to be formatted is out of the range of years for which cyclic name data is provided, then numeric formatting is used (behaves like [`Year::Calendar`]).
+        'U' => Cyclic = 3,
+        /// Field symbol for related Gregorian year (numeric).
+        ///
+        /// For non-Gregorian calendars, this corresponds to the extended Gregorian year in which the calendar’s year begins. Related Gregorian years are often displayed, for example, when formatting dates in the Japanese calendar — e.g. "2012(平成24)年1月15日" — or in the Chinese calendar — e.g. "2012壬辰年腊月初四".
+        'r' => RelatedIso = 4,
     };
-    Numeric;
     YearULE
 );
 
+impl LengthType for Year {
+    fn get_length_type(&self, _length: FieldLength) -> TextOrNumeric {
+        match self {
+            Self::Cyclic => TextOrNumeric::Text,
+            _ => TextOrNumeric::Numeric,
+        }
+    }
+}
+
+field_type!(
+    /// An enum for the possible symbols of a quarter field in a date pattern.
+    Quarter; {
+        /// Field symbol for quarter number or name in a pattern that contains multiple fields.
+        'Q' => Format = 0,
+        /// Field symbol for a "stand-alone" quarter number or name.
+        ///
+        /// The stand-alone quarter name is used when the quarter is displayed by itself. This may differ from the standard form based on the language and context.
+        'q' => StandAlone = 1,
+}; QuarterULE);
+
+impl LengthType for Quarter {
+    fn get_length_type(&self, length: FieldLength) -> TextOrNumeric {
+        match length {
+            FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+            _ => TextOrNumeric::Text,
+        }
+    }
+}
+
 field_type!(
     /// An enum for the possible symbols of a month field in a date pattern.
     Month; {
@@ -580,6 +633,8 @@ field_type!(
         'a' => AmPm = 0,
         /// Field symbol for the am, pm, noon, midnight day period.
         'b' => NoonMidnight = 1,
+        /// Field symbol for flexible day periods, such as "in the morning" or "at night".
+        'B' => Flexible = 2,
     };
     Text;
     DayPeriodULE
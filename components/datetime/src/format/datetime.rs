@@ -4,8 +4,11 @@
 
 use crate::error::DateTimeFormatterError as Error;
 use crate::fields::{self, Field, FieldLength, FieldSymbol, Second, Week, Year};
+use crate::format::numeric::NumericFormatter;
+use crate::format::parts;
 use crate::input::{
-    DateTimeInput, DateTimeInputWithLocale, ExtractedDateTimeInput, LocalizedDateTimeInput,
+    DateInput, DateTimeInput, DateTimeInputWithLocale, ExtractedDateTimeInput,
+    LocalizedDateTimeInput,
 };
 use crate::pattern::{
     runtime::{Pattern, PatternPlurals},
@@ -13,16 +16,17 @@ use crate::pattern::{
 };
 use crate::provider;
 use crate::provider::calendar::patterns::PatternPluralsFromPatternsV1Marker;
-use crate::provider::date_time::{DateSymbols, TimeSymbols};
+use crate::provider::date_time::{DateSymbols, DayPeriodSymbols, QuarterSymbols, TimeSymbols};
 use crate::provider::week_data::WeekDataV1;
 
-use core::fmt;
+use core::fmt::{self, Write};
 use fixed_decimal::FixedDecimal;
+use icu_calendar::AnyCalendarKind;
 use icu_decimal::FixedDecimalFormatter;
 use icu_plurals::PluralRules;
 use icu_provider::DataLocale;
 use icu_provider::DataPayload;
-use writeable::Writeable;
+use writeable::{Part, PartsWrite, Writeable};
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter).
@@ -52,24 +56,49 @@ use writeable::Writeable;
 pub struct FormattedDateTime<'l> {
     pub(crate) patterns: &'l DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub(crate) date_symbols: Option<&'l provider::calendar::DateSymbolsV1<'l>>,
+    pub(crate) quarter_symbols: Option<&'l provider::calendar::QuarterSymbolsV1<'l>>,
     pub(crate) time_symbols: Option<&'l provider::calendar::TimeSymbolsV1<'l>>,
+    pub(crate) day_periods: Option<&'l provider::calendar::DayPeriodsV1<'l>>,
     pub(crate) datetime: ExtractedDateTimeInput,
     pub(crate) week_data: Option<&'l WeekDataV1>,
     pub(crate) locale: &'l DataLocale,
     pub(crate) ordinal_rules: Option<&'l PluralRules>,
-    pub(crate) fixed_decimal_format: &'l FixedDecimalFormatter,
+    pub(crate) numeric_format: &'l NumericFormatter,
+}
+
+impl<'l> FormattedDateTime<'l> {
+    /// Writes the datetime using `pattern` instead of the formatter's own patterns.
+    pub(crate) fn write_with_pattern<W>(&self, pattern: &Pattern, sink: &mut W) -> Result<(), Error>
+    where
+        W: PartsWrite + ?Sized,
+    {
+        let loc_datetime =
+            DateTimeInputWithLocale::new(&self.datetime, self.week_data.map(|d| &d.0), self.locale);
+        write_pattern(
+            pattern,
+            self.date_symbols,
+            self.quarter_symbols,
+            self.time_symbols,
+            self.day_periods,
+            &loc_datetime,
+            self.numeric_format,
+            sink,
+        )
+    }
 }
 
 impl<'l> Writeable for FormattedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
         write_pattern_plurals(
             &self.patterns.get().0,
             self.date_symbols,
+            self.quarter_symbols,
             self.time_symbols,
+            self.day_periods,
             &self.datetime,
             self.week_data,
             self.ordinal_rules,
-            self.fixed_decimal_format,
+            self.numeric_format,
             self.locale,
             sink,
         )
@@ -123,32 +152,90 @@ where
     formatted.write_to(result)
 }
 
+/// Writes the output of `f` to `w`, annotated with `part`. Unlike [`PartsWrite::with_part`],
+/// this preserves the [`Error`] returned by `f`.
+pub(super) fn write_part<W>(
+    w: &mut W,
+    part: Part,
+    mut f: impl FnMut(&mut W::SubPartsWrite) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+    W: PartsWrite + ?Sized,
+{
+    let mut error = None;
+    let result = w.with_part(part, |w| {
+        f(w).map_err(|e| {
+            error = Some(e);
+            fmt::Error
+        })
+    });
+    match error {
+        Some(e) => Err(e),
+        None => result.map_err(Error::from),
+    }
+}
+
+/// Writes the literal at the front of `iter`, together with all literals that directly
+/// follow it, as a single [`parts::LITERAL`].
+pub(super) fn write_literals<W>(
+    first: char,
+    iter: &mut core::iter::Peekable<impl Iterator<Item = PatternItem>>,
+    w: &mut W,
+) -> fmt::Result
+where
+    W: PartsWrite + ?Sized,
+{
+    w.with_part(parts::LITERAL, |w| {
+        w.write_char(first)?;
+        while let Some(&PatternItem::Literal(ch)) = iter.peek() {
+            w.write_char(ch)?;
+            iter.next();
+        }
+        Ok(())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn write_pattern<T, W>(
     pattern: &crate::pattern::runtime::Pattern,
     date_symbols: Option<&provider::calendar::DateSymbolsV1>,
+    quarter_symbols: Option<&provider::calendar::QuarterSymbolsV1>,
     time_symbols: Option<&provider::calendar::TimeSymbolsV1>,
+    day_periods: Option<&provider::calendar::DayPeriodsV1>,
     loc_datetime: &impl LocalizedDateTimeInput<T>,
-    fixed_decimal_format: &FixedDecimalFormatter,
+    numeric_format: &NumericFormatter,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let mut iter = pattern.items.iter().peekable();
     loop {
         match iter.next() {
-            Some(PatternItem::Field(field)) => write_field(
-                pattern,
-                field,
-                iter.peek(),
-                date_symbols,
-                time_symbols,
-                loc_datetime,
-                fixed_decimal_format,
-                w,
-            )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            // Fractional seconds are written as part of the preceding seconds field.
+            Some(PatternItem::Field(Field {
+                symbol: FieldSymbol::Second(Second::FractionalSecond),
+                ..
+            })) => {}
+            Some(PatternItem::Field(field)) => {
+                let next_item = iter.peek().copied();
+                write_part(w, parts::for_field_symbol(field.symbol), |w| {
+                    write_field(
+                        pattern,
+                        field,
+                        next_item.as_ref(),
+                        date_symbols,
+                        quarter_symbols,
+                        time_symbols,
+                        day_periods,
+                        loc_datetime,
+                        numeric_format,
+                        w,
+                    )
+                })?
+            }
+            Some(PatternItem::Literal(ch)) => write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
@@ -156,29 +243,33 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn write_pattern_plurals<T, W>(
+pub(crate) fn write_pattern_plurals<T, W>(
     patterns: &PatternPlurals,
     date_symbols: Option<&provider::calendar::DateSymbolsV1>,
+    quarter_symbols: Option<&provider::calendar::QuarterSymbolsV1>,
     time_symbols: Option<&provider::calendar::TimeSymbolsV1>,
+    day_periods: Option<&provider::calendar::DayPeriodsV1>,
     datetime: &T,
     week_data: Option<&WeekDataV1>,
     ordinal_rules: Option<&PluralRules>,
-    fixed_decimal_format: &FixedDecimalFormatter,
+    numeric_format: &NumericFormatter,
     locale: &DataLocale,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let loc_datetime = DateTimeInputWithLocale::new(datetime, week_data.map(|d| &d.0), locale);
     let pattern = patterns.select(&loc_datetime, ordinal_rules)?;
     write_pattern(
         pattern,
         date_symbols,
+        quarter_symbols,
         time_symbols,
+        day_periods,
         &loc_datetime,
-        fixed_decimal_format,
+        numeric_format,
         w,
     )
 }
@@ -194,15 +285,18 @@ pub(super) fn write_field<T, W>(
     field: fields::Field,
     next_item: Option<&PatternItem>,
     date_symbols: Option<&crate::provider::calendar::DateSymbolsV1>,
+    quarter_symbols: Option<&crate::provider::calendar::QuarterSymbolsV1>,
     time_symbols: Option<&crate::provider::calendar::TimeSymbolsV1>,
+    day_periods: Option<&crate::provider::calendar::DayPeriodsV1>,
     datetime: &impl LocalizedDateTimeInput<T>,
-    fixed_decimal_format: &FixedDecimalFormatter,
+    numeric_format: &NumericFormatter,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
+    let fixed_decimal_format = numeric_format.for_symbol(field.symbol);
     match field.symbol {
         FieldSymbol::Era => {
             let era = datetime
@@ -235,7 +329,69 @@ where
                 FixedDecimal::from(datetime.year_week()?.number),
                 field.length,
             )?,
+            Year::Extended => format_number(
+                w,
+                fixed_decimal_format,
+                FixedDecimal::from(extended_year(datetime.datetime())?),
+                field.length,
+            )?,
+            Year::RelatedIso => {
+                let year = datetime
+                    .datetime()
+                    .year()
+                    .ok_or(Error::MissingInputField(Some("year")))?;
+                format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(year.related_iso.unwrap_or(year.number)),
+                    // The related Gregorian year is never truncated.
+                    FieldLength::One,
+                )?
+            }
+            Year::Cyclic => {
+                let year = datetime
+                    .datetime()
+                    .year()
+                    .ok_or(Error::MissingInputField(Some("year")))?;
+                // There is no data for cyclic year names yet, so they are formatted
+                // numerically, falling back to the calendar year.
+                let number = match year.cyclic {
+                    Some(cyclic) => i32::from(cyclic.get()),
+                    None => year.number,
+                };
+                format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(number),
+                    FieldLength::One,
+                )?
+            }
         },
+        FieldSymbol::Quarter(quarter) => {
+            let month = datetime
+                .datetime()
+                .month()
+                .ok_or(Error::MissingInputField(Some("month")))?;
+            // Months beyond the twelfth, such as the Coptic epagomenal month, are part
+            // of the last quarter.
+            let number = ((month.ordinal.clamp(1, 12) - 1) / 3 + 1) as u8;
+            let symbol = match field.length {
+                FieldLength::One | FieldLength::TwoDigit => None,
+                #[allow(clippy::expect_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
+                length => quarter_symbols
+                    .expect("Expect quarter symbols to be present")
+                    .get_symbol_for_quarter(quarter, length, number),
+            };
+            match symbol {
+                Some(symbol) => w.write_str(symbol)?,
+                None => format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(number),
+                    field.length,
+                )?,
+            }
+        }
         FieldSymbol::Month(month) => match field.length {
             FieldLength::One | FieldLength::TwoDigit => format_number(
                 w,
@@ -251,17 +407,12 @@ where
             )?,
             length => {
                 #[allow(clippy::expect_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
-                let symbol = date_symbols
-                    .expect("Expect date symbols to be present")
-                    .get_symbol_for_month(
-                        month,
-                        length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField(Some("month")))?
-                            .code,
-                    )?;
+                let date_symbols = date_symbols.expect("Expect date symbols to be present");
+                let formattable_month = datetime
+                    .datetime()
+                    .month()
+                    .ok_or(Error::MissingInputField(Some("month")))?;
+                let symbol = date_symbols.get_symbol_for_month(month, length, formattable_month)?;
                 w.write_str(symbol)?
             }
         },
@@ -380,6 +531,8 @@ where
 
                     // We store fractional seconds as nanoseconds, convert to seconds.
                     fraction.multiply_pow10(-9);
+                    // Extra digits are truncated rather than rounded, so that the seconds don't change.
+                    fraction.trunc(-(precision as i16));
 
                     seconds
                         .concatenate_right(fraction)
@@ -397,21 +550,31 @@ where
         }
         FieldSymbol::DayPeriod(period) => {
             #[allow(clippy::expect_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
-            let symbol = time_symbols
-                .expect("Expect time symbols to be present")
-                .get_symbol_for_day_period(
+            let time_symbols = time_symbols.expect("Expect time symbols to be present");
+            let hour = datetime
+                .datetime()
+                .hour()
+                .ok_or(Error::MissingInputField(Some("hour")))?;
+            let is_top_of_hour = pattern.time_granularity.is_top_of_hour(
+                datetime.datetime().minute().map(u8::from).unwrap_or(0),
+                datetime.datetime().second().map(u8::from).unwrap_or(0),
+                datetime.datetime().nanosecond().map(u32::from).unwrap_or(0),
+            );
+            let symbol = match (period, day_periods) {
+                (fields::DayPeriod::Flexible, Some(day_periods)) => day_periods
+                    .get_symbol_for_flexible_day_period(
+                        time_symbols,
+                        field.length,
+                        hour,
+                        is_top_of_hour,
+                    )?,
+                _ => time_symbols.get_symbol_for_day_period(
                     period,
                     field.length,
-                    datetime
-                        .datetime()
-                        .hour()
-                        .ok_or(Error::MissingInputField(Some("hour")))?,
-                    pattern.time_granularity.is_top_of_hour(
-                        datetime.datetime().minute().map(u8::from).unwrap_or(0),
-                        datetime.datetime().second().map(u8::from).unwrap_or(0),
-                        datetime.datetime().nanosecond().map(u32::from).unwrap_or(0),
-                    ),
-                )?;
+                    hour,
+                    is_top_of_hour,
+                )?,
+            };
             w.write_str(symbol)?
         }
         field @ FieldSymbol::TimeZone(_) => return Err(Error::UnsupportedField(field)),
@@ -419,13 +582,42 @@ where
     Ok(())
 }
 
+/// Returns the year of `datetime` as a single number that does not depend on the era,
+/// as used by the extended year field.
+fn extended_year<T: DateInput>(datetime: &T) -> Result<i32, Error> {
+    let year = datetime
+        .year()
+        .ok_or(Error::MissingInputField(Some("year")))?;
+    Ok(match datetime.any_calendar_kind() {
+        // Japanese eras are too short to count years across them, so the extended year
+        // is the Gregorian year.
+        Some(AnyCalendarKind::Japanese) | Some(AnyCalendarKind::JapaneseExtended) => {
+            year.related_iso.unwrap_or(year.number)
+        }
+        _ => match year.era.0.as_str() {
+            // Eras that count backwards from the epoch.
+            "bce" | "bd" | "pre-incar" | "roc-inverse" => 1 - year.number,
+            // The Amete Alem era of the Ethiopic calendar.
+            "mundi" => year.number - AMETE_ALEM_OFFSET,
+            _ => year.number,
+        },
+    })
+}
+
+/// The number of years between the Amete Alem and Amete Mihret epochs of the Ethiopic calendar.
+const AMETE_ALEM_OFFSET: i32 = 5500;
+
 /// What data is required to format a given pattern.
 #[derive(Default)]
 pub struct RequiredData {
     // DateSymbolsV1 is required.
     pub date_symbols_data: bool,
+    // QuarterSymbolsV1 is required.
+    pub quarter_symbols_data: bool,
     // TimeSymbolsV1 is required.
     pub time_symbols_data: bool,
+    // DayPeriodsV1 is required.
+    pub day_periods_data: bool,
     // WeekDataV1 is required.
     pub week_data: bool,
 }
@@ -456,9 +648,19 @@ impl RequiredData {
                     _ => false,
                 }
             }
+            if !self.quarter_symbols_data {
+                self.quarter_symbols_data = matches!(field.symbol, FieldSymbol::Quarter(_))
+                    && !matches!(field.length, FieldLength::One | FieldLength::TwoDigit);
+            }
             if !self.time_symbols_data {
                 self.time_symbols_data = matches!(field.symbol, FieldSymbol::DayPeriod(_));
             }
+            if !self.day_periods_data {
+                self.day_periods_data = matches!(
+                    field.symbol,
+                    FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
+                );
+            }
 
             if !self.week_data {
                 self.week_data = matches!(
@@ -468,7 +670,12 @@ impl RequiredData {
             }
 
             if supports_time_zones {
-                if self.date_symbols_data && self.time_symbols_data && self.week_data {
+                if self.date_symbols_data
+                    && self.quarter_symbols_data
+                    && self.time_symbols_data
+                    && self.day_periods_data
+                    && self.week_data
+                {
                     // If we support time zones, and require everything else, we
                     // know all we need to return already.
                     return Ok(true);
@@ -548,22 +755,43 @@ mod tests {
             provider.load(req).unwrap().take_payload().unwrap();
         let pattern = "MMM".parse().unwrap();
         let datetime = DateTime::new_gregorian_datetime(2020, 8, 1, 12, 34, 28).unwrap();
-        let fixed_decimal_format =
-            FixedDecimalFormatter::try_new_unstable(&provider, &locale, Default::default())
-                .unwrap();
+        let numeric_format = NumericFormatter::try_new(&provider, &locale, None).unwrap();
+
+        /// A sink that discards the parts.
+        struct Sink(String);
+
+        impl fmt::Write for Sink {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.write_str(s)
+            }
+        }
+
+        impl PartsWrite for Sink {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                _part: Part,
+                mut f: impl FnMut(&mut Self) -> fmt::Result,
+            ) -> fmt::Result {
+                f(self)
+            }
+        }
 
-        let mut sink = String::new();
+        let mut sink = Sink(String::new());
         let loc_datetime = DateTimeInputWithLocale::new(&datetime, None, &Locale::UND.into());
         write_pattern(
             &pattern,
             Some(date_data.get()),
+            None,
             Some(time_data.get()),
+            None,
             &loc_datetime,
-            &fixed_decimal_format,
+            &numeric_format,
             &mut sink,
         )
         .unwrap();
-        println!("{}", sink);
+        println!("{}", sink.0);
     }
 
     #[test]
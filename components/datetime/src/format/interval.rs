@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A collection of code for formatting the interval between two DateTimes.
+
+use crate::error::DateTimeFormatterError as Error;
+use crate::format::parts;
+use crate::pattern::{runtime, GenericPatternItem};
+use crate::FormattedDateTime;
+use core::fmt::{self, Write};
+use writeable::{PartsWrite, Writeable};
+
+/// The way in which the two values of a [`FormattedDateTimeInterval`] are written.
+pub(crate) enum IntervalPattern<'l> {
+    /// The values are the same in all displayed fields, so only the first one is written.
+    Single,
+    /// The first value is written with the first pattern, and the second value with the second.
+    Split(&'l runtime::Pattern<'l>, &'l runtime::Pattern<'l>),
+    /// Both values are written in full, and combined with the fallback pattern.
+    Fallback(&'l runtime::GenericPattern<'l>),
+}
+
+/// [`FormattedDateTimeInterval`] is a intermediate structure which can be retrieved as
+/// an output from [`TypedDateTimeIntervalFormatter`](crate::TypedDateTimeIntervalFormatter).
+///
+/// The structure contains all the information needed to display the formatted interval.
+pub struct FormattedDateTimeInterval<'l> {
+    pub(crate) interval_pattern: IntervalPattern<'l>,
+    pub(crate) first: FormattedDateTime<'l>,
+    pub(crate) second: FormattedDateTime<'l>,
+}
+
+impl<'l> Writeable for FormattedDateTimeInterval<'l> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write_interval(self, sink).map_err(|_| core::fmt::Error)
+    }
+
+    // TODO(#489): Implement write_len
+}
+
+impl<'l> fmt::Display for FormattedDateTimeInterval<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn write_interval<W>(interval: &FormattedDateTimeInterval, w: &mut W) -> Result<(), Error>
+where
+    W: PartsWrite + ?Sized,
+{
+    match interval.interval_pattern {
+        IntervalPattern::Single => interval.first.write_to_parts(w)?,
+        IntervalPattern::Split(first, second) => {
+            interval.first.write_with_pattern(first, w)?;
+            interval.second.write_with_pattern(second, w)?;
+        }
+        IntervalPattern::Fallback(fallback) => {
+            for item in fallback.items.iter() {
+                match item {
+                    GenericPatternItem::Placeholder(0) => interval.first.write_to_parts(w)?,
+                    GenericPatternItem::Placeholder(1) => interval.second.write_to_parts(w)?,
+                    GenericPatternItem::Placeholder(_) => return Err(Error::Format(fmt::Error)),
+                    GenericPatternItem::Literal(ch) => {
+                        w.with_part(parts::LITERAL, |w| w.write_char(ch))?
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
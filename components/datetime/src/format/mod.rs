@@ -3,5 +3,8 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod datetime;
+pub mod interval;
+pub(crate) mod numeric;
+pub mod parts;
 pub mod time_zone;
 pub mod zoned_datetime;
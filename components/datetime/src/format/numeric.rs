@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The numbering systems used for the numeric fields of a pattern.
+
+use crate::error::DateTimeFormatterError;
+use crate::fields::FieldSymbol;
+use alloc::vec::Vec;
+use icu_decimal::{
+    options::{FixedDecimalFormatterOptions, GroupingStrategy},
+    provider::DecimalSymbolsV1Marker,
+    FixedDecimalFormatter, FixedDecimalFormatterError,
+};
+use icu_locid::extensions::unicode::Value;
+use icu_locid::extensions_unicode_key as key;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+/// Formats the numeric fields of a pattern.
+///
+/// Numeric fields are formatted in the numbering system of the locale, which can be selected
+/// with the `-u-nu` extension. Patterns can additionally override the numbering system of
+/// individual fields, such as `d=hanidec`.
+pub(crate) struct NumericFormatter {
+    default: FixedDecimalFormatter,
+    overrides: Vec<(char, FixedDecimalFormatter)>,
+}
+
+impl NumericFormatter {
+    /// Creates a [`NumericFormatter`] for the numbering system of the locale, and the given
+    /// pattern overrides.
+    pub(crate) fn try_new<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        overrides: Option<&ZeroMap<char, TinyAsciiStr<8>>>,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+    {
+        let default = load_fixed_decimal_formatter(data_provider, locale)?;
+
+        let mut numeric_formatter = Self {
+            default,
+            overrides: Vec::new(),
+        };
+        let overrides = match overrides {
+            Some(overrides) => overrides,
+            None => return Ok(numeric_formatter),
+        };
+
+        for (symbol, numbering_system) in overrides.iter_copied() {
+            let numbering_system = Value::from_tinystr(Some(numbering_system));
+            let mut locale = locale.clone();
+            locale.set_unicode_ext(key!("nu"), numbering_system);
+            match load_fixed_decimal_formatter(data_provider, &locale) {
+                Ok(fixed_decimal_format) => numeric_formatter
+                    .overrides
+                    .push((symbol, fixed_decimal_format)),
+                // Keep the numbering system of the locale if there is no data for the override.
+                Err(DateTimeFormatterError::FixedDecimalFormatter(
+                    FixedDecimalFormatterError::Data(DataError {
+                        kind: DataErrorKind::MissingLocale,
+                        ..
+                    }),
+                )) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(numeric_formatter)
+    }
+
+    /// Returns the [`FixedDecimalFormatter`] for the numbering system of the locale.
+    pub(crate) fn locale_default(&self) -> &FixedDecimalFormatter {
+        &self.default
+    }
+
+    /// Returns the [`FixedDecimalFormatter`] for fields with the given symbol.
+    pub(crate) fn for_symbol(&self, symbol: FieldSymbol) -> &FixedDecimalFormatter {
+        let ch = char::from(symbol);
+        self.overrides
+            .iter()
+            .find(|(override_ch, _)| *override_ch == ch)
+            .map(|(_, fixed_decimal_format)| fixed_decimal_format)
+            .unwrap_or(&self.default)
+    }
+}
+
+fn load_fixed_decimal_formatter<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<FixedDecimalFormatter, DateTimeFormatterError>
+where
+    D: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+{
+    let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
+    fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+
+    FixedDecimalFormatter::try_new_unstable(data_provider, locale, fixed_decimal_format_options)
+        .map_err(DateTimeFormatterError::FixedDecimalFormatter)
+}
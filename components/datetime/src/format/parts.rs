@@ -0,0 +1,144 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s used by [`FormattedDateTime`](crate::FormattedDateTime),
+//! [`FormattedZonedDateTime`](crate::FormattedZonedDateTime) and
+//! [`FormattedDateTimeInterval`](crate::FormattedDateTimeInterval).
+//!
+//! Each part marks the span of the output written for a single field of the pattern,
+//! or for a run of literal text between fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::{DateTime, Gregorian};
+//! use icu::datetime::{options::length, parts, TypedDateTimeFormatter};
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_parts_eq;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let options = length::Bag::from_date_style(length::Date::Medium).into();
+//! let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_buffer_provider(
+//!     &provider,
+//!     &locale!("en").into(),
+//!     options,
+//! )
+//! .expect("Failed to create TypedDateTimeFormatter instance.");
+//!
+//! let datetime = DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 28)
+//!     .expect("Failed to construct DateTime.");
+//!
+//! assert_writeable_parts_eq!(
+//!     dtf.format(&datetime),
+//!     "Sep 1, 2020",
+//!     [
+//!         (0, 3, parts::MONTH),
+//!         (3, 4, parts::LITERAL),
+//!         (4, 5, parts::DAY),
+//!         (5, 7, parts::LITERAL),
+//!         (7, 11, parts::YEAR),
+//!     ]
+//! );
+//! ```
+
+use crate::fields::FieldSymbol;
+use writeable::Part;
+
+/// The [`Part`] used to mark an era, such as "AD".
+pub const ERA: Part = Part {
+    category: "datetime",
+    value: "era",
+};
+
+/// The [`Part`] used to mark a year, including the year of a week-based calendar,
+/// the extended year, the cyclic year and the related Gregorian year.
+pub const YEAR: Part = Part {
+    category: "datetime",
+    value: "year",
+};
+
+/// The [`Part`] used to mark a quarter number or name, such as "Q2".
+pub const QUARTER: Part = Part {
+    category: "datetime",
+    value: "quarter",
+};
+
+/// The [`Part`] used to mark a month number or name.
+pub const MONTH: Part = Part {
+    category: "datetime",
+    value: "month",
+};
+
+/// The [`Part`] used to mark a week of the year or of the month.
+pub const WEEK: Part = Part {
+    category: "datetime",
+    value: "week",
+};
+
+/// The [`Part`] used to mark a day number relative to a time period longer than a week,
+/// such as the day of the month.
+pub const DAY: Part = Part {
+    category: "datetime",
+    value: "day",
+};
+
+/// The [`Part`] used to mark a weekday name.
+pub const WEEKDAY: Part = Part {
+    category: "datetime",
+    value: "weekday",
+};
+
+/// The [`Part`] used to mark a day period, such as "AM" or "noon".
+pub const DAY_PERIOD: Part = Part {
+    category: "datetime",
+    value: "dayPeriod",
+};
+
+/// The [`Part`] used to mark an hour.
+pub const HOUR: Part = Part {
+    category: "datetime",
+    value: "hour",
+};
+
+/// The [`Part`] used to mark a minute.
+pub const MINUTE: Part = Part {
+    category: "datetime",
+    value: "minute",
+};
+
+/// The [`Part`] used to mark a second, including any fractional digits.
+pub const SECOND: Part = Part {
+    category: "datetime",
+    value: "second",
+};
+
+/// The [`Part`] used to mark a time zone, such as "GMT+2" or "Pacific Time".
+pub const TIME_ZONE_NAME: Part = Part {
+    category: "datetime",
+    value: "timeZoneName",
+};
+
+/// The [`Part`] used to mark literal text from the pattern, such as ", " or ":".
+pub const LITERAL: Part = Part {
+    category: "datetime",
+    value: "literal",
+};
+
+/// Returns the [`Part`] that marks fields with the given symbol.
+pub(crate) fn for_field_symbol(symbol: FieldSymbol) -> Part {
+    match symbol {
+        FieldSymbol::Era => ERA,
+        FieldSymbol::Year(_) => YEAR,
+        FieldSymbol::Quarter(_) => QUARTER,
+        FieldSymbol::Month(_) => MONTH,
+        FieldSymbol::Week(_) => WEEK,
+        FieldSymbol::Day(_) => DAY,
+        FieldSymbol::Weekday(_) => WEEKDAY,
+        FieldSymbol::DayPeriod(_) => DAY_PERIOD,
+        FieldSymbol::Hour(_) => HOUR,
+        FieldSymbol::Minute => MINUTE,
+        FieldSymbol::Second(_) => SECOND,
+        FieldSymbol::TimeZone(_) => TIME_ZONE_NAME,
+    }
+}
@@ -5,7 +5,8 @@
 //! A collection of code for formatting DateTimes with time zones.
 
 use crate::error::DateTimeFormatterError as Error;
-use crate::fields::{self, FieldSymbol};
+use crate::fields::{self, Field, FieldSymbol, Second};
+use crate::format::parts;
 use crate::input::{
     DateTimeInput, DateTimeInputWithLocale, ExtractedDateTimeInput, ExtractedTimeZoneInput,
     LocalizedDateTimeInput, TimeZoneInput,
@@ -13,7 +14,7 @@ use crate::input::{
 use crate::pattern::{runtime, PatternItem};
 use crate::{raw, FormattedTimeZone};
 use core::fmt;
-use writeable::Writeable;
+use writeable::{PartsWrite, Writeable};
 
 use super::datetime;
 
@@ -25,7 +26,7 @@ pub struct FormattedZonedDateTime<'l> {
 }
 
 impl<'l> Writeable for FormattedZonedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
         write_pattern(
             self.zoned_datetime_format,
             &self.datetime,
@@ -40,13 +41,7 @@ impl<'l> Writeable for FormattedZonedDateTime<'l> {
 
 impl<'l> fmt::Display for FormattedZonedDateTime<'l> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(
-            self.zoned_datetime_format,
-            &self.datetime,
-            &self.time_zone,
-            f,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -59,7 +54,7 @@ pub(crate) fn write_pattern<D, Z, W>(
 where
     D: DateTimeInput,
     Z: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let locale = &zoned_datetime_format.datetime_format.locale;
     let patterns = &zoned_datetime_format.datetime_format.patterns;
@@ -81,16 +76,26 @@ where
     let mut iter = pattern.items.iter().peekable();
     loop {
         match iter.next() {
-            Some(PatternItem::Field(field)) => write_field(
-                pattern,
-                field,
-                iter.peek(),
-                zoned_datetime_format,
-                &loc_datetime,
-                time_zone,
-                w,
-            )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            // Fractional seconds are written as part of the preceding seconds field.
+            Some(PatternItem::Field(Field {
+                symbol: FieldSymbol::Second(Second::FractionalSecond),
+                ..
+            })) => {}
+            Some(PatternItem::Field(field)) => {
+                let next_item = iter.peek().copied();
+                datetime::write_part(w, parts::for_field_symbol(field.symbol), |w| {
+                    write_field(
+                        pattern,
+                        field,
+                        next_item.as_ref(),
+                        zoned_datetime_format,
+                        &loc_datetime,
+                        time_zone,
+                        w,
+                    )
+                })?
+            }
+            Some(PatternItem::Literal(ch)) => datetime::write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
@@ -117,12 +122,24 @@ where
         .as_ref()
         .map(|s| s.get());
 
+    let quarter_symbols = zoned_datetime_format
+        .datetime_format
+        .quarter_symbols
+        .as_ref()
+        .map(|s| s.get());
+
     let time_symbols = zoned_datetime_format
         .datetime_format
         .time_symbols
         .as_ref()
         .map(|s| s.get());
 
+    let day_periods = zoned_datetime_format
+        .datetime_format
+        .day_periods
+        .as_ref()
+        .map(|s| s.get());
+
     match field.symbol {
         FieldSymbol::TimeZone(_time_zone) => FormattedTimeZone {
             time_zone_format: &zoned_datetime_format.time_zone_format,
@@ -134,9 +151,11 @@ where
             field,
             next_item,
             date_symbols,
+            quarter_symbols,
             time_symbols,
+            day_periods,
             loc_datetime,
-            &zoned_datetime_format.datetime_format.fixed_decimal_format,
+            &zoned_datetime_format.datetime_format.numeric_format,
             w,
         )?,
     }
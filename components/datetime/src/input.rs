@@ -155,11 +155,22 @@ pub(crate) struct ExtractedTimeZoneInput {
     time_variant: Option<TimeVariant>,
 }
 
+/// Returns the year of the input, with the related ISO year filled in, since the
+/// [`ExtractedDateTimeInput`] can no longer be converted to ISO at format time.
+fn extract_year<T: DateInput>(input: &T) -> Option<FormattableYear> {
+    input.year().map(|mut year| {
+        if year.related_iso.is_none() {
+            year.related_iso = Some(input.to_iso().year().number);
+        }
+        year
+    })
+}
+
 impl ExtractedDateTimeInput {
     /// Construct given an instance of a [`DateTimeInput`].
     pub(crate) fn extract_from<T: DateTimeInput>(input: &T) -> Self {
         Self {
-            year: input.year(),
+            year: extract_year(input),
             month: input.month(),
             day_of_month: input.day_of_month(),
             iso_weekday: input.iso_weekday(),
@@ -174,7 +185,7 @@ impl ExtractedDateTimeInput {
     /// Construct given an instance of a [`DateTimeInput`].
     pub(crate) fn extract_from_date<T: DateInput>(input: &T) -> Self {
         Self {
-            year: input.year(),
+            year: extract_year(input),
             month: input.month(),
             day_of_month: input.day_of_month(),
             iso_weekday: input.iso_weekday(),
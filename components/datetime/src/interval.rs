@@ -0,0 +1,194 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collection of code that is needed for formatting the interval between two DateTimes.
+//! Central to this is the [`TypedDateTimeIntervalFormatter`].
+
+use crate::{
+    calendar,
+    input::DateTimeInput,
+    options::{components, DateTimeFormatterOptions},
+    provider::calendar::{
+        DateIntervalFormatsV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+        QuarterSymbolsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker,
+    },
+    provider::week_data::WeekDataV1Marker,
+    raw, CldrCalendar, DateTimeFormatterError, FormattedDateTimeInterval,
+};
+use alloc::string::String;
+use core::marker::PhantomData;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_plurals::provider::OrdinalV1Marker;
+use icu_provider::prelude::*;
+
+/// [`TypedDateTimeIntervalFormatter`] is the main structure of the [`icu_datetime`] component for
+/// formatting the interval between two dates and times, such as "Jan 3 – 7, 2023" or
+/// "10:00 – 11:30 AM".
+///
+/// The formatter finds the most significant field in which the two values differ, and uses the
+/// locale's interval pattern for that field, so that the fields the values have in common are
+/// only displayed once. If the locale has no interval pattern for the resolved options and that
+/// field, both values are formatted in full and combined with the locale's fallback pattern,
+/// such as "{0} – {1}". If the values are the same in all displayed fields, a single value is
+/// formatted.
+///
+/// [`icu_datetime`]: crate
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::{DateTime, Gregorian};
+/// use icu::datetime::{options::components, TypedDateTimeIntervalFormatter};
+/// use icu::locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let mut bag = components::Bag::default();
+/// bag.year = Some(components::Year::Numeric);
+/// bag.month = Some(components::Month::Short);
+/// bag.day = Some(components::Day::NumericDayOfMonth);
+///
+/// let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("en").into(),
+///     bag.into(),
+/// )
+/// .expect("Failed to create TypedDateTimeIntervalFormatter instance.");
+///
+/// let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0)
+///     .expect("Failed to construct DateTime.");
+/// let second = DateTime::new_gregorian_datetime(2023, 1, 7, 11, 30, 0)
+///     .expect("Failed to construct DateTime.");
+///
+/// assert_eq!(dtif.format_to_string(&first, &second), "Jan 3 – 7, 2023");
+/// ```
+pub struct TypedDateTimeIntervalFormatter<C>(raw::DateTimeIntervalFormatter, PhantomData<C>);
+
+impl<C: CldrCalendar> TypedDateTimeIntervalFormatter<C> {
+    /// Constructor that takes a selected locale, reference to a [data provider] and
+    /// a list of options, then collects all data necessary to format intervals between date
+    /// and time values into the given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Gregorian;
+    /// use icu::datetime::{options::length, TypedDateTimeIntervalFormatter};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let options = length::Bag::from_time_style(length::Time::Short);
+    ///
+    /// TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     options.into(),
+    /// )
+    /// .unwrap();
+    /// ```
+    ///
+    /// [data provider]: icu_provider
+    #[inline]
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<DateSkeletonPatternsV1Marker>
+            + DataProvider<DateIntervalFormatsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + ?Sized,
+    {
+        let mut locale_with_cal = locale.clone();
+
+        calendar::potentially_fixup_calendar::<C>(&mut locale_with_cal)?;
+        Ok(Self(
+            raw::DateTimeIntervalFormatter::try_new(
+                data_provider,
+                calendar::load_lengths_for_cldr_calendar::<C, _>(data_provider, locale)?,
+                || calendar::load_symbols_for_cldr_calendar::<C, _>(data_provider, locale),
+                locale_with_cal,
+                options,
+            )?,
+            PhantomData,
+        ))
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DateTimeFormatterOptions,
+        error: DateTimeFormatterError
+    );
+
+    /// Takes two [`DateTimeInput`] implementers and returns an instance of a
+    /// [`FormattedDateTimeInterval`] that contains all information necessary to display the
+    /// formatted interval between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::TypedDateTimeIntervalFormatter;
+    /// use writeable::assert_writeable_eq;
+    /// # let locale = icu::locid::locale!("en");
+    /// # let provider = icu_testdata::get_provider();
+    /// # let options = icu::datetime::options::length::Bag::from_time_style(icu::datetime::options::length::Time::Short);
+    /// let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(&provider, &locale.into(), options.into())
+    ///     .expect("Failed to create TypedDateTimeIntervalFormatter instance.");
+    ///
+    /// let first = DateTime::new_gregorian_datetime(2020, 9, 1, 10, 0, 0)
+    ///     .expect("Failed to construct DateTime.");
+    /// let second = DateTime::new_gregorian_datetime(2020, 9, 1, 11, 30, 0)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// assert_writeable_eq!(dtif.format(&first, &second), "10:00 – 11:30 AM");
+    /// ```
+    #[inline]
+    pub fn format<'l>(
+        &'l self,
+        first: &impl DateTimeInput<Calendar = C>,
+        second: &impl DateTimeInput<Calendar = C>,
+    ) -> FormattedDateTimeInterval<'l> {
+        self.0.format(first, second)
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and two [`DateTimeInput`] implementers and populates the buffer with the formatted interval.
+    #[inline]
+    pub fn format_to_write(
+        &self,
+        w: &mut impl core::fmt::Write,
+        first: &impl DateTimeInput<Calendar = C>,
+        second: &impl DateTimeInput<Calendar = C>,
+    ) -> core::fmt::Result {
+        self.0.format_to_write(w, first, second)
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns the interval between them formatted
+    /// as a string.
+    #[inline]
+    pub fn format_to_string(
+        &self,
+        first: &impl DateTimeInput<Calendar = C>,
+        second: &impl DateTimeInput<Calendar = C>,
+    ) -> String {
+        self.0.format_to_string(first, second)
+    }
+
+    /// Returns a [`components::Bag`] that represents the resolved components for the
+    /// options that were provided to the [`TypedDateTimeIntervalFormatter`].
+    pub fn resolve_components(&self) -> components::Bag {
+        self.0.resolve_components()
+    }
+}
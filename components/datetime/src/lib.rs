@@ -11,7 +11,8 @@
 //! allow it to collect necessary data from the [data provider], and once instantiated, can be
 //! used to quickly format any date and time provided. There are variants of these types that can format greater or fewer components,
 //! including [`TypedDateFormatter`] & [`DateFormatter`], [`TypedZonedDateTimeFormatter`] & [`ZonedDateTimeFormatter`], [`TimeFormatter`],
-//! and [`TimeZoneFormatter`]
+//! and [`TimeZoneFormatter`]. [`TypedDateTimeIntervalFormatter`] & [`DateTimeIntervalFormatter`] format the interval
+//! between two date/times, such as "Jan 3 – 7, 2023".
 //!
 //! These formatters work with types from the [`icu_calendar`] crate, like [`Date`], [`DateTime`], and [`Time`],
 //! and [`icu_timezone::CustomTimeZone`], however other types may be used provided they implement the traits from the [`input`] module.
@@ -112,8 +113,10 @@ mod error;
 pub mod fields;
 mod format;
 pub mod input;
+mod interval;
 pub mod mock;
 pub mod options;
+mod parse;
 #[doc(hidden)]
 pub mod pattern;
 pub mod provider;
@@ -127,14 +130,20 @@ mod zoned_datetime;
 
 mod any;
 
-pub use any::{DateFormatter, DateTimeFormatter, ZonedDateTimeFormatter};
+pub use any::{
+    DateFormatter, DateTimeFormatter, DateTimeIntervalFormatter, ZonedDateTimeFormatter,
+};
 pub use calendar::CldrCalendar;
 pub use datetime::{TimeFormatter, TypedDateFormatter, TypedDateTimeFormatter};
-pub use error::DateTimeFormatterError;
+pub use error::{DateTimeFormatterError, DateTimeParseError};
 pub use format::datetime::FormattedDateTime;
+pub use format::interval::FormattedDateTimeInterval;
+pub use format::parts;
 pub use format::time_zone::FormattedTimeZone;
 pub use format::zoned_datetime::FormattedZonedDateTime;
+pub use interval::TypedDateTimeIntervalFormatter;
 pub use options::DateTimeFormatterOptions;
+pub use parse::DateTimeParser;
 pub use time_zone::TimeZoneFormatter;
 pub use time_zone::TimeZoneFormatterConfig;
 pub use time_zone::TimeZoneFormatterOptions;
@@ -68,11 +68,12 @@
 //! time. Formatted result should be treated as opaque and displayed to the user as-is,
 //! and it is strongly recommended to never write tests that expect a particular formatted output.
 use crate::{
-    fields::{self, Field, FieldLength, FieldSymbol},
+    fields::{self, Field, FieldLength, FieldSymbol, LengthError},
     pattern::{runtime::PatternPlurals, PatternItem},
 };
 
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 use super::preferences;
 #[cfg(feature = "serde")]
@@ -87,6 +88,8 @@ pub struct Bag {
     pub era: Option<Text>,
     /// Include the year, such as "1970" or "70".
     pub year: Option<Year>,
+    /// Include the quarter, such as "Q2" or "2nd quarter".
+    pub quarter: Option<Quarter>,
     /// Include the month, such as "April" or "Apr".
     pub month: Option<Month>,
     /// Include the week number, such as "51st" or "51" for week 51.
@@ -102,8 +105,9 @@ pub struct Bag {
     pub minute: Option<Numeric>,
     /// Include the second such as "3" or "03".
     pub second: Option<Numeric>,
-    /// Specify the number of fractional second digits such as 1 (".3") or 3 (".003").
-    pub fractional_second: Option<u8>,
+    /// Include the fractional second, such as "45.1" or "45.123". It is only displayed
+    /// together with the second.
+    pub fractional_second_digits: Option<FractionalSecondDigits>,
 
     /// Include the time zone, such as "GMT+05:00".
     pub time_zone_name: Option<TimeZoneName>,
@@ -143,14 +147,13 @@ impl Bag {
         }
 
         if let Some(year) = self.year {
-            // Unimplemented year fields:
-            // u - Extended year
-            // U - Cyclic year name
-            // r - Related Gregorian year
             fields.push(Field {
                 symbol: FieldSymbol::Year(match year {
                     Year::Numeric | Year::TwoDigit => fields::Year::Calendar,
                     Year::NumericWeekOf | Year::TwoDigitWeekOf => fields::Year::WeekOf,
+                    Year::NumericExtended => fields::Year::Extended,
+                    Year::Cyclic => fields::Year::Cyclic,
+                    Year::NumericRelatedIso => fields::Year::RelatedIso,
                 }),
                 length: match year {
                     // Calendar year (numeric).
@@ -159,15 +162,43 @@ impl Bag {
                     // yyy     002, 020, 201, 2017, 20173    (not implemented)
                     // yyyy    0002, 0020, 0201, 2017, 20173 (not implemented)
                     // yyyyy+  ...                           (not implemented)
-                    Year::Numeric | Year::NumericWeekOf => FieldLength::One,
+                    //
+                    // Extended year (numeric).
+                    // u       4601
+                    //
+                    // Cyclic year name.
+                    // U..UUU  甲子        Abbreviated
+                    //
+                    // Related Gregorian year (numeric).
+                    // r       2017
+                    Year::Numeric
+                    | Year::NumericWeekOf
+                    | Year::NumericExtended
+                    | Year::NumericRelatedIso => FieldLength::One,
                     Year::TwoDigit | Year::TwoDigitWeekOf => FieldLength::TwoDigit,
+                    Year::Cyclic => FieldLength::Abbreviated,
                 },
             });
         }
 
-        // TODO(#501) - Unimplemented quarter fields:
-        // Q - Quarter number/name
-        // q - Stand-alone quarter
+        if let Some(quarter) = self.quarter {
+            fields.push(Field {
+                // Always choose Quarter::Format as Quarter::StandAlone is not used in skeletons.
+                symbol: FieldSymbol::Quarter(fields::Quarter::Format),
+                length: match quarter {
+                    // Q      2              Numeric: 1 digit
+                    // QQ     02             Numeric: 2 digits + zero pad
+                    // QQQ    Q2             Abbreviated
+                    // QQQQ   2nd quarter    Wide
+                    // QQQQQ  2              Narrow
+                    Quarter::Numeric => FieldLength::One,
+                    Quarter::TwoDigit => FieldLength::TwoDigit,
+                    Quarter::Short => FieldLength::Abbreviated,
+                    Quarter::Long => FieldLength::Wide,
+                    Quarter::Narrow => FieldLength::Narrow,
+                },
+            });
+        }
 
         if let Some(month) = self.month {
             fields.push(Field {
@@ -314,11 +345,11 @@ impl Bag {
             // A - Milliseconds in day. Not used in skeletons.
         }
 
-        if let Some(precision) = self.fractional_second {
+        if let Some(digits) = self.fractional_second_digits {
             // S - Fractional seconds.
             fields.push(Field {
                 symbol: FieldSymbol::Second(fields::Second::FractionalSecond),
-                length: FieldLength::Fixed(precision),
+                length: FieldLength::Fixed(digits.into()),
             });
         }
 
@@ -361,6 +392,77 @@ pub enum Numeric {
     TwoDigit,
 }
 
+/// The number of fractional second digits for the `components::`[`Bag`], such as three digits
+/// for "45.123". This corresponds to the `fractionalSecondDigits` option of ECMA-402, extended
+/// to nanosecond precision.
+///
+/// The fractional second is truncated to the number of digits, and formatted with the decimal
+/// separator of the locale.
+///
+/// # Examples
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use icu::datetime::options::components::FractionalSecondDigits;
+///
+/// assert_eq!(FractionalSecondDigits::try_from(3), Ok(FractionalSecondDigits::F3));
+/// assert!(FractionalSecondDigits::try_from(0).is_err());
+/// assert!(FractionalSecondDigits::try_from(10).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
+#[allow(clippy::exhaustive_enums)] // nanoseconds are the most precise time input
+pub enum FractionalSecondDigits {
+    /// One digit, such as "45.1".
+    F1 = 1,
+    /// Two digits, such as "45.12".
+    F2,
+    /// Three digits, such as "45.123".
+    F3,
+    /// Four digits, such as "45.1234".
+    F4,
+    /// Five digits, such as "45.12345".
+    F5,
+    /// Six digits, such as "45.123456".
+    F6,
+    /// Seven digits, such as "45.1234567".
+    F7,
+    /// Eight digits, such as "45.12345678".
+    F8,
+    /// Nine digits, such as "45.123456789".
+    F9,
+}
+
+impl TryFrom<u8> for FractionalSecondDigits {
+    type Error = LengthError;
+
+    fn try_from(digits: u8) -> Result<Self, Self::Error> {
+        use FractionalSecondDigits::*;
+        Ok(match digits {
+            1 => F1,
+            2 => F2,
+            3 => F3,
+            4 => F4,
+            5 => F5,
+            6 => F6,
+            7 => F7,
+            8 => F8,
+            9 => F9,
+            _ => return Err(LengthError::InvalidLength),
+        })
+    }
+}
+
+impl From<FractionalSecondDigits> for u8 {
+    fn from(digits: FractionalSecondDigits) -> u8 {
+        digits as u8
+    }
+}
+
 /// A text component for the `components::`[`Bag`]. It is used for the era and weekday.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -397,6 +499,35 @@ pub enum Year {
     /// The numeric value of the year in "week-of-year", such as "19" in
     /// "week 01 '19" for the week of 2018-12-31 according to the ISO calendar.
     TwoDigitWeekOf,
+    /// The extended year, a single number that does not depend on the era, such as
+    /// "-43" for the year 44 BCE.
+    NumericExtended,
+    /// The name of the year in a 60-year cycle, such as "甲子", falling back to
+    /// its numeric position in the cycle if the calendar data has no cyclic names.
+    Cyclic,
+    /// The related Gregorian year, such as "2012" for a date in 平成24.
+    NumericRelatedIso,
+}
+
+/// Options for displaying a Quarter for the `components::`[`Bag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum Quarter {
+    /// The numeric value of the quarter, such as "2".
+    Numeric,
+    /// The two-digit value of the quarter, such as "02".
+    TwoDigit,
+    /// The long value of the quarter, such as "2nd quarter".
+    Long,
+    /// The short value of the quarter, such as "Q2".
+    Short,
+    /// The narrow value of the quarter, such as "2".
+    Narrow,
 }
 
 /// Options for displaying a Month for the `components::`[`Bag`].
@@ -570,6 +701,22 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                             FieldLength::TwoDigit => Year::TwoDigitWeekOf,
                             _ => Year::NumericWeekOf,
                         },
+                        fields::Year::Extended => Year::NumericExtended,
+                        fields::Year::Cyclic => Year::Cyclic,
+                        fields::Year::RelatedIso => Year::NumericRelatedIso,
+                    });
+                }
+                FieldSymbol::Quarter(_) => {
+                    // `Quarter::StandAlone` is only relevant in the pattern, so only differentiate
+                    // on the field length.
+                    bag.quarter = Some(match field.length {
+                        FieldLength::One => Quarter::Numeric,
+                        FieldLength::TwoDigit => Quarter::TwoDigit,
+                        FieldLength::Abbreviated => Quarter::Short,
+                        FieldLength::Wide => Quarter::Long,
+                        FieldLength::Narrow | FieldLength::Six | FieldLength::Fixed(_) => {
+                            Quarter::Narrow
+                        }
                     });
                 }
                 FieldSymbol::Month(_) => {
@@ -683,9 +830,8 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         }
                         fields::Second::FractionalSecond => {
                             if let FieldLength::Fixed(p) = field.length {
-                                if p > 0 {
-                                    bag.fractional_second = Some(p);
-                                }
+                                bag.fractional_second_digits =
+                                    FractionalSecondDigits::try_from(p).ok();
                             }
                         }
                         fields::Second::Millisecond => {
@@ -736,7 +882,7 @@ mod test {
             hour: Some(Numeric::Numeric),
             minute: Some(Numeric::Numeric),
             second: Some(Numeric::Numeric),
-            fractional_second: Some(3),
+            fractional_second_digits: Some(FractionalSecondDigits::F3),
 
             ..Default::default()
         };
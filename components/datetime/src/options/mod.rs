@@ -26,6 +26,11 @@
 pub mod components;
 pub mod length;
 pub mod preferences;
+pub mod skeleton;
+
+use crate::pattern::{reference, PatternError};
+use core::str::FromStr;
+
 /// A bag of options which, together with [`Locale`](icu_locid::Locale), defines how
 /// dates will be formatted with a [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter) instance.
 ///
@@ -50,6 +55,47 @@ pub enum DateTimeFormatterOptions {
     Length(length::Bag),
     /// Bag of components describing which fields and how should be displayed.
     Components(components::Bag),
+    /// A CLDR skeleton string, resolved to the best matching pattern for the locale.
+    Skeleton(skeleton::Bag),
+    /// A raw pattern, which is used as-is.
+    ///
+    /// ⚠️ The pattern is not localized: the order of the fields and the literal text
+    /// will be the same for all locales. Prefer [`DateTimeFormatterOptions::Skeleton`]
+    /// or [`DateTimeFormatterOptions::Components`] unless the exact pattern is required,
+    /// for instance by a design specification.
+    RawPattern(reference::Pattern),
+}
+
+impl DateTimeFormatterOptions {
+    /// Creates options from a CLDR skeleton string, such as `"yMMMdEjm"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::DateTimeFormatterOptions;
+    ///
+    /// assert!(DateTimeFormatterOptions::try_from_skeleton("yMMMdEjm").is_ok());
+    /// assert!(DateTimeFormatterOptions::try_from_skeleton("yMMMp").is_err());
+    /// ```
+    pub fn try_from_skeleton(skeleton: &str) -> Result<Self, crate::skeleton::SkeletonError> {
+        skeleton::Bag::from_str(skeleton).map(Self::Skeleton)
+    }
+
+    /// Creates options from a raw pattern string, such as `"y-MM-dd HH:mm"`.
+    ///
+    /// ⚠️ The pattern is not localized, see [`DateTimeFormatterOptions::RawPattern`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::DateTimeFormatterOptions;
+    ///
+    /// assert!(DateTimeFormatterOptions::try_from_raw_pattern("y-MM-dd HH:mm").is_ok());
+    /// assert!(DateTimeFormatterOptions::try_from_raw_pattern("y-MM-dd 'HH:mm").is_err());
+    /// ```
+    pub fn try_from_raw_pattern(pattern: &str) -> Result<Self, PatternError> {
+        reference::Pattern::from_str(pattern).map(Self::RawPattern)
+    }
 }
 
 impl Default for DateTimeFormatterOptions {
@@ -69,3 +115,9 @@ impl From<components::Bag> for DateTimeFormatterOptions {
         Self::Components(input)
     }
 }
+
+impl From<skeleton::Bag> for DateTimeFormatterOptions {
+    fn from(input: skeleton::Bag) -> Self {
+        Self::Skeleton(input)
+    }
+}
@@ -0,0 +1,115 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Skeleton is a model of encoding information on how to format date and time by specifying
+//! a [CLDR skeleton string](https://unicode.org/reports/tr35/tr35-dates.html#availableFormats_appendItems),
+//! such as `"yMMMdEjm"`.
+//!
+//! A skeleton lists the fields that should be displayed, and their lengths, without any
+//! ordering or literal text. The skeleton is resolved to the best localized pattern for a
+//! given locale using the same skeleton matching algorithm as the [`components::Bag`].
+//!
+//! In addition to the regular field symbols, the skeleton may contain the `j` symbol, which
+//! requests an hour field in the preferred hour cycle of the locale (`h` or `H`).
+//!
+//! [`components::Bag`]: super::components::Bag
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::datetime::options::skeleton;
+//! use icu::datetime::DateTimeFormatterOptions;
+//!
+//! let bag: skeleton::Bag = "yMMMdEjm".parse().expect("Valid skeleton");
+//!
+//! let options = DateTimeFormatterOptions::Skeleton(bag);
+//! ```
+//!
+//! Invalid skeletons are rejected when they are parsed.
+//!
+//! ```
+//! use icu::datetime::options::skeleton;
+//!
+//! assert!("yMMMdy".parse::<skeleton::Bag>().is_err());
+//! assert!("yMMMjH".parse::<skeleton::Bag>().is_err());
+//! ```
+//!
+//! *Note*: The exact result returned from [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter) is a subject to change over
+//! time. Formatted result should be treated as opaque and displayed to the user as-is,
+//! and it is strongly recommended to never write tests that expect a particular formatted output.
+
+use crate::fields::{self, Field, FieldSymbol};
+use crate::skeleton::{reference, SkeletonError};
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+/// A validated skeleton, see the [module-level](./index.html) docs for more information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bag {
+    skeleton: reference::Skeleton,
+    /// Whether the hour field was requested with the `j` symbol, and should follow the
+    /// preferred hour cycle of the locale.
+    preferred_hour_cycle: bool,
+}
+
+impl Bag {
+    /// Returns the requested fields, ordered from the most significant to the least significant.
+    ///
+    /// An hour requested with the `j` symbol is represented by an [`Hour::H23`](fields::Hour::H23)
+    /// field until it is resolved for a locale.
+    pub fn fields(&self) -> &[Field] {
+        self.skeleton.as_slice()
+    }
+
+    /// Returns the requested fields, with the `j` symbol resolved to the given hour symbol.
+    pub(crate) fn fields_with_hour(&self, hour: fields::Hour) -> reference::Skeleton {
+        let mut skeleton = self.skeleton.clone();
+        if self.preferred_hour_cycle {
+            for field in skeleton.0.iter_mut() {
+                if let FieldSymbol::Hour(_) = field.symbol {
+                    field.symbol = FieldSymbol::Hour(hour);
+                }
+            }
+        }
+        skeleton
+    }
+
+    /// Whether the hour field should follow the preferred hour cycle of the locale.
+    pub(crate) fn has_preferred_hour_cycle(&self) -> bool {
+        self.preferred_hour_cycle
+    }
+}
+
+impl From<reference::Skeleton> for Bag {
+    fn from(skeleton: reference::Skeleton) -> Self {
+        Self {
+            skeleton,
+            preferred_hour_cycle: false,
+        }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = SkeletonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains('j') {
+            return reference::Skeleton::try_from(s).map(Self::from);
+        }
+        // The `j` symbol can't be combined with an explicit hour field.
+        if s.contains(|ch| matches!(ch, 'h' | 'H' | 'K' | 'k')) {
+            return Err(SkeletonError::DuplicateField);
+        }
+        let s: String = s
+            .chars()
+            .map(|ch| if ch == 'j' { 'H' } else { ch })
+            .collect();
+        let skeleton = reference::Skeleton::try_from(s.as_str())?;
+        Ok(Self {
+            skeleton,
+            preferred_hour_cycle: true,
+        })
+    }
+}
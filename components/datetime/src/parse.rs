@@ -0,0 +1,855 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A collection of code for parsing localized date/time strings.
+
+use crate::error::DateTimeParseError as Error;
+use crate::fields::{self, Field, FieldLength, FieldSymbol, Second};
+use crate::options::DateTimeFormatterOptions;
+use crate::pattern::{runtime::Pattern, PatternItem};
+use crate::provider::calendar::*;
+use crate::provider::week_data::WeekDataV1Marker;
+use crate::{DateTimeFormatter, DateTimeFormatterError};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use icu_calendar::provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
+use icu_calendar::types::{Era, IsoWeekday, MonthCode, Time};
+use icu_calendar::{AnyCalendar, Date, DateTime, DateTimeError, Ref};
+use icu_decimal::provider::{DecimalSymbolsV1, DecimalSymbolsV1Marker};
+use icu_plurals::provider::OrdinalV1Marker;
+use icu_provider::prelude::*;
+use tinystr::{TinyStr16, TinyStr4};
+use zerovec::ule::AsULE;
+
+/// The maximum number of digits read for a single numeric field.
+const MAX_DIGITS: usize = 9;
+
+/// The ISO year whose era is used when the input has no era, and around which
+/// two-digit years are resolved.
+const REFERENCE_ISO_YEAR: i32 = 2020;
+
+/// [`DateTimeParser`] is the inverse of [`DateTimeFormatter`]: it turns localized date/time strings
+/// back into a [`DateTime`] in the calendar selected by the locale.
+///
+/// The parser uses the same patterns and symbols as a [`DateTimeFormatter`] constructed with
+/// the same locale and options, and it can parse strings in one of two modes:
+///
+/// - [`DateTimeParser::parse_strict()`] only accepts the exact output of the formatter.
+/// - [`DateTimeParser::parse_lenient()`] accepts input as it is commonly typed by users: it
+///   ignores whitespace and case, accepts any width of month, weekday, era and day period
+///   names, numeric months, ASCII digits in addition to the locale's digits, and missing or
+///   different punctuation.
+///
+/// In both modes, years without an era are interpreted in the era used by the calendar for
+/// present-day dates, and two-digit years are resolved to the century around the present.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::datetime::{options::length, DateTimeParser};
+/// use icu::locid::Locale;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let options = length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short);
+/// let locale: Locale = "en-u-ca-gregory".parse().unwrap();
+///
+/// let parser = DateTimeParser::try_new_with_buffer_provider(&provider, &locale.into(), options.into())
+///     .expect("Failed to create DateTimeParser instance.");
+///
+/// let expected = DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 0)
+///     .expect("Failed to construct DateTime.")
+///     .to_any();
+///
+/// let strict = parser.parse_strict("Sep 1, 2020, 12:34 PM").expect("Failed to parse");
+/// assert_eq!(strict.date, expected.date);
+/// assert_eq!(strict.time, expected.time);
+///
+/// let lenient = parser.parse_lenient("  september 1 2020 12:34 pm").expect("Failed to parse");
+/// assert_eq!(lenient.date, expected.date);
+/// assert_eq!(lenient.time, expected.time);
+///
+/// assert!(parser.parse_strict("september 1 2020 12:34 pm").is_err());
+/// ```
+pub struct DateTimeParser {
+    formatter: DateTimeFormatter,
+    decimal_symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+impl DateTimeParser {
+    /// Construct a new [`DateTimeParser`] from a data provider that implements
+    /// [`AnyProvider`].
+    ///
+    /// The provider must be able to provide the same data as for
+    /// [`DateTimeFormatter::try_new_with_any_provider()`].
+    #[inline]
+    pub fn try_new_with_any_provider<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: AnyProvider,
+    {
+        let downcasting = data_provider.as_downcasting();
+        Self::try_new_unstable(&downcasting, locale, options)
+    }
+
+    /// Construct a new [`DateTimeParser`] from a data provider that implements
+    /// [`BufferProvider`].
+    ///
+    /// The provider must be able to provide the same data as for
+    /// [`DateTimeFormatter::try_new_with_buffer_provider()`].
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: BufferProvider,
+    {
+        let deserializing = data_provider.as_deserializing();
+        Self::try_new_unstable(&deserializing, locale, options)
+    }
+
+    /// Construct a new [`DateTimeParser`] from a data provider that can provide all of the requested data.
+    ///
+    /// This method is **unstable**, more bounds may be added in the future as calendar support is added. It is
+    /// preferable to use a provider that implements `DataProvider<D>` for all `D`, and ensure data is loaded as
+    /// appropriate. The [`Self::try_new_with_buffer_provider()`], [`Self::try_new_with_any_provider()`] constructors
+    /// may also be used if compile stability is desired.
+    #[inline(never)]
+    pub fn try_new_unstable<P>(
+        data_provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        P: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<DateSkeletonPatternsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<EthiopicDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<EthiopicDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + ?Sized,
+    {
+        let formatter = DateTimeFormatter::try_new_unstable(data_provider, locale, options)?;
+        let decimal_symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            formatter,
+            decimal_symbols,
+        })
+    }
+
+    /// Parses a string that exactly matches the output of the equivalent [`DateTimeFormatter`].
+    pub fn parse_strict(&self, input: &str) -> Result<DateTime<AnyCalendar>, Error> {
+        self.parse(input, false)
+    }
+
+    /// Parses a string that loosely matches the output of the equivalent [`DateTimeFormatter`],
+    /// as described in the [type-level documentation](DateTimeParser).
+    pub fn parse_lenient(&self, input: &str) -> Result<DateTime<AnyCalendar>, Error> {
+        self.parse(input, true)
+    }
+
+    fn parse(&self, input: &str, lenient: bool) -> Result<DateTime<AnyCalendar>, Error> {
+        let raw = &self.formatter.0;
+        let mut result = Err(Error::MissingField("pattern"));
+        for pattern in raw.patterns.get().0.patterns_iter() {
+            let mut parser = Parser {
+                input,
+                position: 0,
+                lenient,
+                date_symbols: raw.date_symbols.as_ref().map(|s| s.get()),
+                time_symbols: raw.time_symbols.as_ref().map(|s| s.get()),
+                decimal_symbols: self.decimal_symbols.get(),
+            };
+            result = parser
+                .parse_pattern(pattern)
+                .and_then(|fields| fields.resolve(&self.formatter.1, lenient));
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// A day period parsed from the input.
+#[derive(Copy, Clone, PartialEq)]
+enum ParsedDayPeriod {
+    Am,
+    Pm,
+    Noon,
+    Midnight,
+}
+
+/// A month parsed from the input.
+#[derive(Copy, Clone)]
+enum ParsedMonth {
+    /// A month name, which identifies the month code.
+    Code(MonthCode),
+    /// A numeric month, which is the ordinal month in the year, as formatted.
+    Ordinal(u8),
+}
+
+/// The values of the fields found in the input.
+#[derive(Default)]
+struct ParsedFields {
+    era: Option<Era>,
+    /// The year, and whether it was given with two digits.
+    year: Option<(i32, bool)>,
+    month: Option<ParsedMonth>,
+    day: Option<u8>,
+    weekday: Option<IsoWeekday>,
+    hour: Option<(u8, fields::Hour)>,
+    day_period: Option<ParsedDayPeriod>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    nanosecond: Option<u32>,
+}
+
+impl ParsedFields {
+    fn resolve(
+        self,
+        calendar: &AnyCalendar,
+        lenient: bool,
+    ) -> Result<DateTime<AnyCalendar>, Error> {
+        let reference =
+            Date::new_from_iso(Date::new_iso_date(REFERENCE_ISO_YEAR, 1, 1)?, Ref(calendar)).year();
+        let year = match self.year {
+            Some((year, false)) => year,
+            Some((year, true)) => {
+                let start = reference.number - 50;
+                start + (year - start).rem_euclid(100)
+            }
+            None => return Err(Error::MissingField("year")),
+        };
+        let era = self.era.unwrap_or(reference.era);
+        let day = self.day.ok_or(Error::MissingField("day"))?;
+        let date = match self.month.ok_or(Error::MissingField("month"))? {
+            ParsedMonth::Code(code) => {
+                Date::new_from_codes(era, year, code, day, calendar.clone())?
+            }
+            ParsedMonth::Ordinal(ordinal) => {
+                date_from_ordinal_month(era, year, ordinal, day, calendar)?
+            }
+        };
+        if let Some(weekday) = self.weekday {
+            if !lenient && date.day_of_week() != weekday {
+                return Err(Error::MismatchedWeekday);
+            }
+        }
+
+        let hour = match self.hour {
+            Some((hour, fields::Hour::H11 | fields::Hour::H12)) => {
+                let hour = hour % 12;
+                match self.day_period {
+                    Some(ParsedDayPeriod::Pm | ParsedDayPeriod::Noon) => hour + 12,
+                    _ => hour,
+                }
+            }
+            Some((hour, fields::Hour::H23)) => hour,
+            Some((hour, fields::Hour::H24)) => hour % 24,
+            None => 0,
+        };
+        let time = Time::try_new(
+            hour,
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+            self.nanosecond.unwrap_or(0),
+        )?;
+        Ok(DateTime::new(date, time))
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    /// The byte offset of the next character to be parsed.
+    position: usize,
+    lenient: bool,
+    date_symbols: Option<&'a DateSymbolsV1<'a>>,
+    time_symbols: Option<&'a TimeSymbolsV1<'a>>,
+    decimal_symbols: &'a DecimalSymbolsV1<'a>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.position..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(ch) = self.peek().filter(|ch| ch.is_whitespace()) {
+            self.position += ch.len_utf8();
+        }
+    }
+
+    fn parse_pattern(&mut self, pattern: &Pattern) -> Result<ParsedFields, Error> {
+        let mut fields = ParsedFields::default();
+        let mut iter = pattern.items.iter().peekable();
+        while let Some(item) = iter.next() {
+            match item {
+                // Fractional seconds are parsed as part of the preceding seconds field.
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::Second(Second::FractionalSecond),
+                    ..
+                }) => {}
+                PatternItem::Field(field) => {
+                    if self.lenient {
+                        self.skip_whitespace();
+                    }
+                    self.parse_field(field, iter.peek(), &mut fields)?
+                }
+                PatternItem::Literal(ch) => self.parse_literal(ch)?,
+            }
+        }
+        if self.lenient {
+            self.skip_whitespace();
+        }
+        if self.position < self.input.len() {
+            return Err(Error::TrailingInput(self.position));
+        }
+        Ok(fields)
+    }
+
+    fn parse_literal(&mut self, expected: char) -> Result<(), Error> {
+        if !self.lenient {
+            return match self.peek() {
+                Some(ch) if ch == expected => {
+                    self.position += ch.len_utf8();
+                    Ok(())
+                }
+                _ => Err(Error::ExpectedLiteral {
+                    position: self.position,
+                    expected,
+                }),
+            };
+        }
+        // Whitespace is optional, other literals may be omitted, and whitespace and
+        // punctuation may be replaced by other punctuation.
+        self.skip_whitespace();
+        if let Some(ch) = self.peek() {
+            let is_punctuation = |ch: char| !ch.is_alphanumeric() && !ch.is_whitespace();
+            if chars_eq_ignore_case(ch, expected)
+                || (is_punctuation(ch) && !expected.is_alphanumeric())
+            {
+                self.position += ch.len_utf8();
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_field(
+        &mut self,
+        field: Field,
+        next_item: Option<&PatternItem>,
+        fields: &mut ParsedFields,
+    ) -> Result<(), Error> {
+        // Numeric fields that are directly followed by another field are delimited by
+        // their width alone.
+        let abutting = matches!(
+            next_item,
+            Some(PatternItem::Field(next)) if next.symbol != FieldSymbol::Second(Second::FractionalSecond)
+        );
+        let invalid = Error::InvalidField {
+            position: self.position,
+            field: field.symbol,
+        };
+        match field.symbol {
+            FieldSymbol::Era => {
+                let eras = &self.date_symbols(field)?.eras;
+                let code = if self.lenient {
+                    self.parse_symbol(
+                        field,
+                        eras.names
+                            .iter()
+                            .chain(eras.abbr.iter())
+                            .chain(eras.narrow.iter()),
+                    )?
+                } else {
+                    let symbols = match field.length {
+                        FieldLength::Wide => &eras.names,
+                        FieldLength::Narrow => &eras.narrow,
+                        _ => &eras.abbr,
+                    };
+                    self.parse_symbol(field, symbols.iter())?
+                };
+                fields.era = Some(Era(TinyStr16::from_str(code).map_err(|_| invalid)?));
+            }
+            FieldSymbol::Year(fields::Year::Calendar) => {
+                let (year, digits) = self.parse_number(field, abutting)?;
+                fields.year = Some((
+                    year as i32,
+                    field.length == FieldLength::TwoDigit && digits == 2,
+                ));
+            }
+            FieldSymbol::Month(month) => {
+                fields.month = Some(match field.length {
+                    FieldLength::One | FieldLength::TwoDigit => {
+                        ParsedMonth::Ordinal(self.parse_numeric_month(field, abutting)?)
+                    }
+                    _ if self.lenient => {
+                        let months = &self.date_symbols(field)?.months;
+                        let mut candidates = Vec::new();
+                        push_month_symbols(&months.format.wide, &mut candidates);
+                        push_month_symbols(&months.format.abbreviated, &mut candidates);
+                        if let Some(stand_alone) = &months.stand_alone {
+                            for symbols in stand_alone.wide.iter().chain(&stand_alone.abbreviated) {
+                                push_month_symbols(symbols, &mut candidates);
+                            }
+                        }
+                        match self.parse_symbol(field, candidates.into_iter()) {
+                            Ok(code) => ParsedMonth::Code(code),
+                            Err(_) => {
+                                ParsedMonth::Ordinal(self.parse_numeric_month(field, abutting)?)
+                            }
+                        }
+                    }
+                    length => {
+                        let months = &self.date_symbols(field)?.months;
+                        let stand_alone = match month {
+                            fields::Month::Format => None,
+                            fields::Month::StandAlone => months.stand_alone.as_ref(),
+                        };
+                        let symbols = match length {
+                            FieldLength::Wide => stand_alone
+                                .and_then(|s| s.wide.as_ref())
+                                .unwrap_or(&months.format.wide),
+                            FieldLength::Narrow => stand_alone
+                                .and_then(|s| s.narrow.as_ref())
+                                .unwrap_or(&months.format.narrow),
+                            _ => stand_alone
+                                .and_then(|s| s.abbreviated.as_ref())
+                                .unwrap_or(&months.format.abbreviated),
+                        };
+                        let mut candidates = Vec::new();
+                        push_month_symbols(symbols, &mut candidates);
+                        ParsedMonth::Code(self.parse_symbol(field, candidates.into_iter())?)
+                    }
+                });
+            }
+            FieldSymbol::Weekday(fields::Weekday::Local) => {
+                return Err(Error::UnsupportedField(field.symbol))
+            }
+            FieldSymbol::Weekday(weekday) => {
+                let weekdays = &self.date_symbols(field)?.weekdays;
+                let mut candidates = Vec::new();
+                if self.lenient {
+                    let format = &weekdays.format;
+                    candidates.push(&format.wide);
+                    candidates.push(&format.abbreviated);
+                    candidates.extend(format.short.as_ref());
+                    if let Some(stand_alone) = &weekdays.stand_alone {
+                        candidates.extend(stand_alone.wide.as_ref());
+                        candidates.extend(stand_alone.abbreviated.as_ref());
+                        candidates.extend(stand_alone.short.as_ref());
+                    }
+                } else {
+                    let stand_alone = match weekday {
+                        fields::Weekday::StandAlone => weekdays.stand_alone.as_ref(),
+                        _ => None,
+                    };
+                    let format = &weekdays.format;
+                    candidates.push(match field.length {
+                        FieldLength::Wide => stand_alone
+                            .and_then(|s| s.wide.as_ref())
+                            .unwrap_or(&format.wide),
+                        FieldLength::Narrow => stand_alone
+                            .and_then(|s| s.narrow.as_ref())
+                            .unwrap_or(&format.narrow),
+                        FieldLength::Six => stand_alone
+                            .and_then(|s| s.short.as_ref().or(s.abbreviated.as_ref()))
+                            .or(format.short.as_ref())
+                            .unwrap_or(&format.abbreviated),
+                        _ => stand_alone
+                            .and_then(|s| s.abbreviated.as_ref())
+                            .unwrap_or(&format.abbreviated),
+                    });
+                }
+                let index = self.parse_symbol(
+                    field,
+                    candidates.into_iter().flat_map(|symbols| {
+                        symbols
+                            .0
+                            .iter()
+                            .enumerate()
+                            .map(|(index, symbol)| (index, &**symbol))
+                    }),
+                )?;
+                fields.weekday = Some(IsoWeekday::from(index));
+            }
+            FieldSymbol::Day(fields::Day::DayOfMonth) => {
+                let (day, _) = self.parse_number(field, abutting)?;
+                fields.day = Some(u8::try_from(day).map_err(|_| invalid)?);
+            }
+            FieldSymbol::DayPeriod(day_period) => {
+                let widths = &self.time_symbols(field)?.day_periods.format;
+                let widths = if self.lenient {
+                    vec![&widths.wide, &widths.abbreviated, &widths.narrow]
+                } else {
+                    vec![match field.length {
+                        FieldLength::Wide => &widths.wide,
+                        FieldLength::Narrow => &widths.narrow,
+                        _ => &widths.abbreviated,
+                    }]
+                };
+                let mut candidates = Vec::new();
+                for symbols in widths {
+                    candidates.push((ParsedDayPeriod::Am, &*symbols.am));
+                    candidates.push((ParsedDayPeriod::Pm, &*symbols.pm));
+                    if self.lenient || day_period == fields::DayPeriod::NoonMidnight {
+                        candidates
+                            .extend(symbols.noon.as_deref().map(|s| (ParsedDayPeriod::Noon, s)));
+                        candidates.extend(
+                            symbols
+                                .midnight
+                                .as_deref()
+                                .map(|s| (ParsedDayPeriod::Midnight, s)),
+                        );
+                    }
+                }
+                fields.day_period = Some(self.parse_symbol(field, candidates.into_iter())?);
+            }
+            FieldSymbol::Hour(hour) => {
+                let (value, _) = self.parse_number(field, abutting)?;
+                let range = match hour {
+                    fields::Hour::H11 => 0..=11,
+                    fields::Hour::H12 => 1..=12,
+                    fields::Hour::H23 => 0..=23,
+                    fields::Hour::H24 => 1..=24,
+                };
+                if !range.contains(&value) {
+                    return Err(invalid);
+                }
+                fields.hour = Some((value as u8, hour));
+            }
+            FieldSymbol::Minute => {
+                let (minute, _) = self.parse_number(field, abutting)?;
+                fields.minute = Some(u8::try_from(minute).map_err(|_| invalid)?);
+            }
+            FieldSymbol::Second(Second::Second) => {
+                let (second, _) = self.parse_number(field, abutting)?;
+                fields.second = Some(u8::try_from(second).map_err(|_| invalid)?);
+                if let Some(PatternItem::Field(next)) = next_item {
+                    if next.symbol == FieldSymbol::Second(Second::FractionalSecond) {
+                        fields.nanosecond = Some(self.parse_fraction(*next)?);
+                    }
+                }
+            }
+            symbol => return Err(Error::UnsupportedField(symbol)),
+        }
+        Ok(())
+    }
+
+    fn date_symbols(&self, field: Field) -> Result<&'a DateSymbolsV1<'a>, Error> {
+        self.date_symbols
+            .ok_or(Error::UnsupportedField(field.symbol))
+    }
+
+    fn time_symbols(&self, field: Field) -> Result<&'a TimeSymbolsV1<'a>, Error> {
+        self.time_symbols
+            .ok_or(Error::UnsupportedField(field.symbol))
+    }
+
+    /// Parses the longest of the given symbols found at the current position, and returns the
+    /// value associated with it.
+    fn parse_symbol<T>(
+        &mut self,
+        field: Field,
+        candidates: impl Iterator<Item = (T, &'a str)>,
+    ) -> Result<T, Error> {
+        let rest = self.rest();
+        let lenient = self.lenient;
+        let (value, length) = candidates
+            .filter_map(|(value, symbol)| {
+                match_prefix(rest, symbol, lenient).map(|length| (value, length))
+            })
+            .max_by_key(|&(_, length)| length)
+            .ok_or(Error::InvalidField {
+                position: self.position,
+                field: field.symbol,
+            })?;
+        self.position += length;
+        Ok(value)
+    }
+
+    fn digit_value(&self, ch: char) -> Option<u32> {
+        match self.decimal_symbols.digits.iter().position(|&d| d == ch) {
+            Some(digit) => Some(digit as u32),
+            None if self.lenient => ch.to_digit(10),
+            None => None,
+        }
+    }
+
+    /// Parses a number, and returns it together with its number of digits.
+    fn parse_number(&mut self, field: Field, abutting: bool) -> Result<(u32, usize), Error> {
+        let width = match field.length {
+            FieldLength::One => 1,
+            FieldLength::TwoDigit => 2,
+            FieldLength::Abbreviated => 3,
+            FieldLength::Wide => 4,
+            FieldLength::Narrow => 5,
+            FieldLength::Six => 6,
+            FieldLength::Fixed(p) => p as usize,
+        };
+        let (min_digits, max_digits) = if abutting {
+            (width, width)
+        } else if self.lenient {
+            (1, MAX_DIGITS)
+        } else if field.length == FieldLength::TwoDigit {
+            (2, 2)
+        } else {
+            (width, MAX_DIGITS)
+        };
+        self.parse_digits(field, min_digits, max_digits)
+    }
+
+    fn parse_digits(
+        &mut self,
+        field: Field,
+        min_digits: usize,
+        max_digits: usize,
+    ) -> Result<(u32, usize), Error> {
+        let start = self.position;
+        let mut value = 0u32;
+        let mut digits = 0;
+        while digits < max_digits {
+            match self
+                .peek()
+                .and_then(|ch| self.digit_value(ch).map(|d| (ch, d)))
+            {
+                Some((ch, digit)) => {
+                    value = match value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                    {
+                        Some(value) => value,
+                        None => {
+                            self.position = start;
+                            return Err(Error::InvalidField {
+                                position: start,
+                                field: field.symbol,
+                            });
+                        }
+                    };
+                    digits += 1;
+                    self.position += ch.len_utf8();
+                }
+                None => break,
+            }
+        }
+        if digits < min_digits {
+            self.position = start;
+            return Err(Error::InvalidField {
+                position: start,
+                field: field.symbol,
+            });
+        }
+        Ok((value, digits))
+    }
+
+    /// Parses a numeric month, which is the ordinal month in the year. It is resolved to a
+    /// month code once the year is known, see [`date_from_ordinal_month()`].
+    fn parse_numeric_month(&mut self, field: Field, abutting: bool) -> Result<u8, Error> {
+        let position = self.position;
+        let (month, _) = self.parse_number(field, abutting)?;
+        u8::try_from(month)
+            .ok()
+            .filter(|month| (1..=99).contains(month))
+            .ok_or(Error::InvalidField {
+                position,
+                field: field.symbol,
+            })
+    }
+
+    /// Parses the decimal separator and fractional digits that follow the seconds, and returns
+    /// them as nanoseconds.
+    fn parse_fraction(&mut self, field: Field) -> Result<u32, Error> {
+        let invalid = Error::InvalidField {
+            position: self.position,
+            field: field.symbol,
+        };
+        let separator = &*self.decimal_symbols.decimal_separator;
+        let rest = self.rest();
+        if rest.starts_with(separator) {
+            self.position += separator.len();
+        } else if self.lenient && (rest.starts_with('.') || rest.starts_with(',')) {
+            self.position += 1;
+        } else {
+            return Err(invalid);
+        }
+        let precision = match field.length {
+            FieldLength::Fixed(p) if p as usize <= MAX_DIGITS => p as usize,
+            _ => return Err(invalid),
+        };
+        let (min_digits, max_digits) = if self.lenient {
+            (1, MAX_DIGITS)
+        } else {
+            (precision, precision)
+        };
+        let (fraction, digits) = self.parse_digits(field, min_digits, max_digits)?;
+        Ok(fraction * 10u32.pow((MAX_DIGITS - digits) as u32))
+    }
+}
+
+/// Returns the length in bytes of the prefix of `input` that matches `symbol`, if any.
+/// The date in `year` whose ordinal month is `ordinal`.
+///
+/// The ordinal month `n` has the month code `Mnn`, unless a leap month comes before it in the
+/// year, in which case it is `Mnn-1`, or it is itself the leap month `Mnn-1L`.
+fn date_from_ordinal_month(
+    era: Era,
+    year: i32,
+    ordinal: u8,
+    day: u8,
+    calendar: &AnyCalendar,
+) -> Result<Date<AnyCalendar>, Error> {
+    let month_code = |number: u8, leap: bool| {
+        let bytes = [b'M', b'0' + number / 10, b'0' + number % 10, b'L'];
+        TinyStr4::from_bytes(&bytes[..if leap { 4 } else { 3 }])
+            .ok()
+            .map(MonthCode)
+    };
+    let candidates = [
+        month_code(ordinal, false),
+        month_code(ordinal - 1, false),
+        month_code(ordinal - 1, true),
+    ];
+    candidates
+        .iter()
+        .flatten()
+        .filter_map(|code| Date::new_from_codes(era, year, *code, day, calendar.clone()).ok())
+        .find(|date| date.month().ordinal == ordinal as u32)
+        .ok_or_else(|| DateTimeError::OutOfRange.into())
+}
+
+fn match_prefix(input: &str, symbol: &str, lenient: bool) -> Option<usize> {
+    if symbol.is_empty() {
+        return None;
+    }
+    if !lenient {
+        return input.starts_with(symbol).then(|| symbol.len());
+    }
+    let mut chars = input.char_indices();
+    for expected in symbol.chars() {
+        match chars.next() {
+            Some((_, ch)) if chars_eq_ignore_case(ch, expected) => {}
+            _ => return None,
+        }
+    }
+    Some(chars.next().map_or(input.len(), |(index, _)| index))
+}
+
+fn chars_eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Appends the symbols of a width of month names to `candidates`, together with their month codes.
+fn push_month_symbols<'a>(
+    symbols: &'a months::SymbolsV1<'a>,
+    candidates: &mut Vec<(MonthCode, &'a str)>,
+) {
+    match symbols {
+        months::SymbolsV1::SolarTwelve(symbols) => {
+            for (month, symbol) in (1u8..).zip(symbols.iter()) {
+                if let Ok(code) =
+                    TinyStr4::from_bytes(&[b'M', b'0' + month / 10, b'0' + month % 10])
+                {
+                    candidates.push((MonthCode(code), symbol));
+                }
+            }
+        }
+        months::SymbolsV1::Other(symbols) => {
+            candidates.extend(
+                symbols
+                    .iter()
+                    .map(|(code, symbol)| (MonthCode::from_unaligned(*code), symbol)),
+            );
+        }
+        months::SymbolsV1::OtherWithLeapYearVariants {
+            symbols,
+            leap_year_variants,
+        } => {
+            candidates.extend(
+                symbols
+                    .iter()
+                    .chain(leap_year_variants.iter())
+                    .map(|(code, symbol)| (MonthCode::from_unaligned(*code), symbol)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlong_numeric_field() {
+        let decimal_symbols = DecimalSymbolsV1::default();
+        let mut parser = Parser {
+            input: "1234567890123401",
+            position: 0,
+            lenient: false,
+            date_symbols: None,
+            time_symbols: None,
+            decimal_symbols: &decimal_symbols,
+        };
+        // The abutting year field has to be read with all twelve digits, which do not fit a u32.
+        let year = Field {
+            symbol: FieldSymbol::Year(fields::Year::Calendar),
+            length: FieldLength::Fixed(12),
+        };
+        let month = Field {
+            symbol: FieldSymbol::Month(fields::Month::Format),
+            length: FieldLength::TwoDigit,
+        };
+        let pattern = Pattern::from(vec![PatternItem::Field(year), PatternItem::Field(month)]);
+        assert_eq!(
+            parser.parse_pattern(&pattern).map(|_| ()),
+            Err(Error::InvalidField {
+                position: 0,
+                field: year.symbol,
+            })
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::pattern::{runtime::GenericPattern, PatternItem};
+use icu_provider::{yoke, zerofrom};
+use zerovec::{ZeroMap2d, ZeroSlice};
+
+/// Patterns for formatting the interval between two dates or times, from the CLDR
+/// `intervalFormats` data.
+#[icu_provider::data_struct(marker(
+    DateIntervalFormatsV1Marker,
+    "datetime/intervalformats@1",
+    extension_key = "ca"
+))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct DateIntervalFormatsV1<'data> {
+    /// The pattern used to combine two fully formatted values when there is no interval
+    /// pattern for them, such as `"{0} – {1}"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fallback: GenericPattern<'data>,
+
+    /// The interval patterns, keyed by skeleton and then by the pattern letter of the greatest
+    /// field in which the two values differ. For example, the skeleton `"yMMMd"` and the
+    /// letter `'d'` map to `"MMM d – d, y"` in English.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, deserialize_with = "serde_patterns::deserialize")
+    )]
+    #[cfg_attr(
+        feature = "datagen",
+        serde(serialize_with = "serde_patterns::serialize")
+    )]
+    pub patterns: ZeroMap2d<'data, str, char, ZeroSlice<PatternItem>>,
+}
+
+/// Human-readable formats store the interval patterns as pattern strings, such as
+/// `"MMM d – d, y"`, rather than as lists of pattern items.
+#[cfg(feature = "serde")]
+mod serde_patterns {
+    use super::*;
+    use crate::pattern::runtime::Pattern;
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+    use serde::{de, Deserialize};
+
+    type Patterns<'data> = ZeroMap2d<'data, str, char, ZeroSlice<PatternItem>>;
+
+    pub fn deserialize<'de: 'data, 'data, D>(deserializer: D) -> Result<Patterns<'data>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let strings = BTreeMap::<String, BTreeMap<char, String>>::deserialize(deserializer)?;
+            let mut patterns = ZeroMap2d::new();
+            for (skeleton, by_field) in &strings {
+                for (field, pattern) in by_field {
+                    let pattern: Pattern = pattern.parse().map_err(de::Error::custom)?;
+                    patterns.insert(skeleton.as_str(), field, &*pattern.items);
+                }
+            }
+            Ok(patterns)
+        } else {
+            Patterns::deserialize(deserializer)
+        }
+    }
+
+    #[cfg(feature = "datagen")]
+    pub fn serialize<S>(patterns: &Patterns, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+        use serde::Serialize;
+        use zerovec::ule::AsULE;
+
+        if serializer.is_human_readable() {
+            patterns
+                .iter0()
+                .map(|cursor| {
+                    let by_field = cursor
+                        .iter1()
+                        .map(|(field, items)| {
+                            let pattern = Pattern::from(items.iter().collect::<Vec<_>>());
+                            (char::from_unaligned(*field), pattern.to_string())
+                        })
+                        .collect::<BTreeMap<_, _>>();
+                    (cursor.key0(), by_field)
+                })
+                .collect::<BTreeMap<_, _>>()
+                .serialize(serializer)
+        } else {
+            patterns.serialize(serializer)
+        }
+    }
+}
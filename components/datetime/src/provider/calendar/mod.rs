@@ -6,14 +6,19 @@
 
 #![allow(missing_docs)] // TODO(#686) - Add missing docs.
 
+mod intervals;
 mod skeletons;
 mod symbols;
 
+use crate::options::length;
 use crate::pattern;
 use icu_provider::prelude::*;
 use icu_provider::{yoke, zerofrom};
+pub use intervals::*;
 pub use skeletons::*;
 pub use symbols::*;
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
 
 #[icu_provider::data_struct(
     marker(GregorianDateLengthsV1Marker, "datetime/gregory/datelengths@1"),
@@ -22,7 +27,10 @@ pub use symbols::*;
     marker(JapaneseExtendedDateLengthsV1Marker, "datetime/japanext/datelengths@1"),
     marker(CopticDateLengthsV1Marker, "datetime/coptic/datelengths@1"),
     marker(IndianDateLengthsV1Marker, "datetime/indian/datelengths@1"),
-    marker(EthiopicDateLengthsV1Marker, "datetime/ethiopic/datelengths@1")
+    marker(EthiopicDateLengthsV1Marker, "datetime/ethiopic/datelengths@1"),
+    marker(HebrewDateLengthsV1Marker, "datetime/hebrew/datelengths@1"),
+    marker(PersianDateLengthsV1Marker, "datetime/persian/datelengths@1"),
+    marker(RocDateLengthsV1Marker, "datetime/roc/datelengths@1")
 )]
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(
@@ -71,6 +79,54 @@ pub struct TimeLengthsV1<'data> {
     pub preferred_hour_cycle: pattern::CoarseHourCycle,
 }
 
+/// Numbering system overrides for the numeric fields of the date length patterns, such as
+/// the `d=hanidec` override of a CLDR pattern.
+///
+/// Each map goes from a pattern character to the numbering system that is used to format
+/// the fields with that character. Only numeric numbering systems are included.
+#[icu_provider::data_struct(marker(
+    DateNumberingOverridesV1Marker,
+    "datetime/numbering@1",
+    extension_key = "ca"
+))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct DateNumberingOverridesV1<'data> {
+    /// Overrides for the full date pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub full: ZeroMap<'data, char, TinyAsciiStr<8>>,
+    /// Overrides for the long date pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long: ZeroMap<'data, char, TinyAsciiStr<8>>,
+    /// Overrides for the medium date pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub medium: ZeroMap<'data, char, TinyAsciiStr<8>>,
+    /// Overrides for the short date pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short: ZeroMap<'data, char, TinyAsciiStr<8>>,
+}
+
+impl<'data> DateNumberingOverridesV1<'data> {
+    /// Returns the overrides for the date pattern of the given length.
+    pub(crate) fn for_length(
+        &self,
+        length: length::Date,
+    ) -> &ZeroMap<'data, char, TinyAsciiStr<8>> {
+        match length {
+            length::Date::Full => &self.full,
+            length::Date::Long => &self.long,
+            length::Date::Medium => &self.medium,
+            length::Date::Short => &self.short,
+        }
+    }
+}
+
 pub mod patterns {
     use super::*;
     use crate::pattern::runtime::{self, GenericPattern, PatternPlurals};
@@ -12,7 +12,7 @@ use icu_calendar::types::MonthCode;
 use icu_provider::prelude::*;
 use icu_provider::{yoke, zerofrom};
 use tinystr::{tinystr, TinyStr4};
-use zerovec::ZeroMap;
+use zerovec::{ZeroMap, ZeroVec};
 
 #[icu_provider::data_struct(
     marker(GregorianDateSymbolsV1Marker, "datetime/gregory/datesymbols@1"),
@@ -21,7 +21,10 @@ use zerovec::ZeroMap;
     marker(JapaneseExtendedDateSymbolsV1Marker, "datetime/japanext/datesymbols@1"),
     marker(CopticDateSymbolsV1Marker, "datetime/coptic/datesymbols@1"),
     marker(IndianDateSymbolsV1Marker, "datetime/indian/datesymbols@1"),
-    marker(EthiopicDateSymbolsV1Marker, "datetime/ethiopic/datesymbols@1")
+    marker(EthiopicDateSymbolsV1Marker, "datetime/ethiopic/datesymbols@1"),
+    marker(HebrewDateSymbolsV1Marker, "datetime/hebrew/datesymbols@1"),
+    marker(PersianDateSymbolsV1Marker, "datetime/persian/datesymbols@1"),
+    marker(RocDateSymbolsV1Marker, "datetime/roc/datesymbols@1")
 )]
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(
@@ -60,6 +63,73 @@ pub struct TimeSymbolsV1<'data> {
     pub day_periods: day_periods::ContextsV1<'data>,
 }
 
+/// Names of the quarters of the year, used by the `Q` and `q` pattern fields.
+#[icu_provider::data_struct(marker(QuarterSymbolsV1Marker, "datetime/quarters@1",))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct QuarterSymbolsV1<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quarters: quarters::ContextsV1<'data>,
+}
+
+/// The flexible day periods of a locale, such as "in the morning", used by the `B`
+/// pattern field.
+///
+/// The periods are derived from the CLDR day period rules of the locale's language.
+#[icu_provider::data_struct(marker(DayPeriodsV1Marker, "datetime/dayperiods@1",))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct DayPeriodsV1<'data> {
+    /// The day period of each hour of the day, starting at midnight.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hours: ZeroVec<'data, FlexibleDayPeriod>,
+    /// Whether exactly noon has its own day period.
+    pub noon: bool,
+    /// Whether exactly midnight has its own day period.
+    pub midnight: bool,
+    /// The names of the day periods.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: flexible_day_periods::ContextsV1<'data>,
+}
+
+/// A day period from the CLDR day period rules.
+///
+/// Locales without flexible day periods only use [`Am`](FlexibleDayPeriod::Am)
+/// and [`Pm`](FlexibleDayPeriod::Pm), whose names come from [`TimeSymbolsV1`].
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Copy)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+#[zerovec::make_ule(FlexibleDayPeriodULE)]
+pub enum FlexibleDayPeriod {
+    Am = 0,
+    Pm = 1,
+    Morning1 = 2,
+    Morning2 = 3,
+    Afternoon1 = 4,
+    Afternoon2 = 5,
+    Evening1 = 6,
+    Evening2 = 7,
+    Night1 = 8,
+    Night2 = 9,
+}
+
 #[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
 #[cfg_attr(
     feature = "datagen",
@@ -171,6 +241,16 @@ symbols!(
         /// A calendar with an arbitrary number of months, potentially including leap months
         #[cfg_attr(feature = "serde", serde(borrow))]
         Other(ZeroMap<'data, MonthCode, str>),
+        /// A calendar with an arbitrary number of months, where some months have a
+        /// different name in leap years, such as Adar II in the Hebrew calendar
+        OtherWithLeapYearVariants {
+            /// The regular month symbols
+            #[cfg_attr(feature = "serde", serde(borrow))]
+            symbols: ZeroMap<'data, MonthCode, str>,
+            /// The symbols used for months that come after a leap month
+            #[cfg_attr(feature = "serde", serde(borrow))]
+            leap_year_variants: ZeroMap<'data, MonthCode, str>,
+        },
     }
 );
 
@@ -211,6 +291,19 @@ impl<'data> months::SymbolsV1<'data> {
                 arr.get(idx).map(|x| &**x)
             }
             Self::Other(ref map) => map.get(&code),
+            Self::OtherWithLeapYearVariants { ref symbols, .. } => symbols.get(&code),
+        }
+    }
+
+    /// Get the symbol for the given month code when it comes after a leap month
+    /// in the year, falling back to [`Self::get()`] if the month has no leap year variant
+    pub fn get_after_leap_month(&self, code: MonthCode) -> Option<&str> {
+        match *self {
+            Self::OtherWithLeapYearVariants {
+                ref leap_year_variants,
+                ..
+            } => leap_year_variants.get(&code).or_else(|| self.get(code)),
+            _ => self.get(code),
         }
     }
 }
@@ -236,6 +329,21 @@ symbols!(
     );
 );
 
+symbols!(
+    quarters,
+    #[derive(Default)]
+    pub struct SymbolsV1<'data>(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::array_of_cow"
+            )
+        )]
+        pub [Cow<'data, str>; 4],
+    );
+);
+
 symbols!(
     day_periods,
     #[derive(Default)]
@@ -263,6 +371,94 @@ symbols!(
     }
 );
 
+symbols!(
+    flexible_day_periods,
+    #[derive(Default)]
+    pub struct SymbolsV1<'data> {
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub morning1: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub morning2: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub afternoon1: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub afternoon2: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub evening1: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub evening2: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub night1: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        pub night2: Option<Cow<'data, str>>,
+    }
+);
+
+impl<'data> flexible_day_periods::SymbolsV1<'data> {
+    /// Get the symbol for the given day period, if the locale has one
+    pub fn get(&self, day_period: FlexibleDayPeriod) -> Option<&str> {
+        match day_period {
+            FlexibleDayPeriod::Am | FlexibleDayPeriod::Pm => None,
+            FlexibleDayPeriod::Morning1 => self.morning1.as_deref(),
+            FlexibleDayPeriod::Morning2 => self.morning2.as_deref(),
+            FlexibleDayPeriod::Afternoon1 => self.afternoon1.as_deref(),
+            FlexibleDayPeriod::Afternoon2 => self.afternoon2.as_deref(),
+            FlexibleDayPeriod::Evening1 => self.evening1.as_deref(),
+            FlexibleDayPeriod::Evening2 => self.evening2.as_deref(),
+            FlexibleDayPeriod::Night1 => self.night1.as_deref(),
+            FlexibleDayPeriod::Night2 => self.night2.as_deref(),
+        }
+    }
+}
+
 #[cfg(all(test, feature = "datagen"))]
 mod test {
     use super::*;
@@ -392,4 +588,34 @@ mod test {
             Cow::Borrowed(_)
         ));
     }
+
+    #[test]
+    fn months_leap_year_variants() {
+        let symbols = [
+            (&MonthCode(tinystr!(4, "M05")), "Shevat"),
+            (&MonthCode(tinystr!(4, "M05L")), "Adar I"),
+            (&MonthCode(tinystr!(4, "M06")), "Adar"),
+            (&MonthCode(tinystr!(4, "M07")), "Nisan"),
+        ];
+        let leap_year_variants = [(&MonthCode(tinystr!(4, "M06")), "Adar II")];
+        let months = months::SymbolsV1::OtherWithLeapYearVariants {
+            symbols: symbols.iter().copied().collect(),
+            leap_year_variants: leap_year_variants.iter().copied().collect(),
+        };
+
+        let bytes = bincode::serialize(&months).unwrap();
+        let de = bincode::deserialize::<months::SymbolsV1>(&bytes).unwrap();
+        assert_eq!(de, months);
+
+        assert_eq!(de.get(MonthCode(tinystr!(4, "M06"))), Some("Adar"));
+        assert_eq!(
+            de.get_after_leap_month(MonthCode(tinystr!(4, "M06"))),
+            Some("Adar II")
+        );
+        assert_eq!(
+            de.get_after_leap_month(MonthCode(tinystr!(4, "M07"))),
+            Some("Nisan")
+        );
+        assert_eq!(de.get_after_leap_month(MonthCode(tinystr!(4, "M08"))), None);
+    }
 }
@@ -5,17 +5,23 @@
 use crate::error::DateTimeFormatterError;
 use crate::fields;
 use crate::input;
-use crate::options::{components, length, preferences, DateTimeFormatterOptions};
-use crate::pattern::{hour_cycle, runtime::PatternPlurals};
+use crate::options::{
+    components, length, preferences, skeleton as skeleton_options, DateTimeFormatterOptions,
+};
+use crate::pattern::{hour_cycle, runtime, runtime::PatternPlurals};
 use crate::provider;
 use crate::provider::calendar::patterns::PatternPluralsV1;
+use crate::provider::calendar::{months, DateLengthsV1, TimeLengthsV1};
 use crate::provider::calendar::{
     patterns::GenericPatternV1Marker, patterns::PatternPluralsFromPatternsV1Marker,
-    DateSkeletonPatternsV1Marker, ErasedDateLengthsV1Marker, TimeLengthsV1Marker,
+    DateNumberingOverridesV1Marker, DateSkeletonPatternsV1Marker, ErasedDateLengthsV1Marker,
+    TimeLengthsV1Marker,
 };
-use crate::provider::calendar::{DateLengthsV1, TimeLengthsV1};
+use crate::provider::week_data::WeekDataV1Marker;
 use crate::skeleton;
-use icu_calendar::types::{Era, MonthCode};
+use icu_calendar::types::{Era, FormattableMonth, IsoWeekday};
+use icu_locid::extensions::unicode::Value;
+use icu_locid::subtags::Region;
 use icu_provider::prelude::*;
 
 type Result<T> = core::result::Result<T, DateTimeFormatterError>;
@@ -126,6 +132,98 @@ pub(crate) fn generic_pattern_for_date_length(
     })
 }
 
+/// Load the numbering system overrides for the date length patterns.
+///
+/// The overrides are optional, so this returns `None` if the data provider doesn't have
+/// them for the locale.
+pub(crate) fn load_numbering_overrides<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<Option<DataPayload<DateNumberingOverridesV1Marker>>>
+where
+    D: DataProvider<DateNumberingOverridesV1Marker> + ?Sized,
+{
+    // An explicit numbering system for the locale takes precedence over the overrides.
+    if locale
+        .get_unicode_ext(&icu_locid::extensions_unicode_key!("nu"))
+        .is_some()
+    {
+        return Ok(None);
+    }
+    match data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })
+        .and_then(DataResponse::take_payload)
+    {
+        Ok(payload) => Ok(Some(payload)),
+        Err(DataError {
+            kind: DataErrorKind::MissingDataKey | DataErrorKind::MissingLocale,
+            ..
+        }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Load the week data for the locale.
+///
+/// The region of the week data can be overridden with the `-u-rg` extension, and the first day
+/// of the week with the `-u-fw` extension.
+pub(crate) fn load_week_data<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<WeekDataV1Marker>>
+where
+    D: DataProvider<WeekDataV1Marker> + ?Sized,
+{
+    let mut week_data_locale = locale.clone();
+    if let Some(region) = locale
+        .get_unicode_ext(&icu_locid::extensions_unicode_key!("rg"))
+        .and_then(|rg| region_override(&rg))
+    {
+        week_data_locale.set_region(Some(region));
+    }
+    let mut week_data = data_provider
+        .load(DataRequest {
+            locale: &week_data_locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?;
+    if let Some(first_weekday) = locale
+        .get_unicode_ext(&icu_locid::extensions_unicode_key!("fw"))
+        .and_then(|fw| first_weekday_override(&fw))
+    {
+        week_data.with_mut(move |data| data.0.first_weekday = first_weekday);
+    }
+    Ok(week_data)
+}
+
+/// Returns the region of a `-u-rg` value, such as `GB` for `gbzzzz`.
+fn region_override(rg: &Value) -> Option<Region> {
+    let rg = rg.as_single_subtag()?.as_bytes();
+    // The region is followed by a subdivision suffix, which is ignored.
+    let region_len = match rg.first() {
+        Some(b) if b.is_ascii_alphabetic() => 2,
+        _ => 3,
+    };
+    Region::from_bytes(rg.get(..region_len)?).ok()
+}
+
+/// Returns the weekday of a `-u-fw` value, such as [`IsoWeekday::Monday`] for `mon`.
+fn first_weekday_override(fw: &Value) -> Option<IsoWeekday> {
+    Some(match fw.as_single_subtag()?.as_str() {
+        "mon" => IsoWeekday::Monday,
+        "tue" => IsoWeekday::Tuesday,
+        "wed" => IsoWeekday::Wednesday,
+        "thu" => IsoWeekday::Thursday,
+        "fri" => IsoWeekday::Friday,
+        "sat" => IsoWeekday::Saturday,
+        "sun" => IsoWeekday::Sunday,
+        _ => return None,
+    })
+}
+
 #[derive(Clone)]
 pub struct PatternSelector<'a, D: ?Sized> {
     data_provider: &'a D,
@@ -152,6 +250,10 @@ where
             DateTimeFormatterOptions::Length(bag) => selector
                 .pattern_for_length_bag(bag, Some(preferences::Bag::from_data_locale(locale))),
             DateTimeFormatterOptions::Components(bag) => selector.patterns_for_components_bag(bag),
+            DateTimeFormatterOptions::Skeleton(bag) => selector.patterns_for_skeleton_bag(bag),
+            DateTimeFormatterOptions::RawPattern(pattern) => Ok(DataPayload::from_owned(
+                PatternPluralsV1(PatternPlurals::from(runtime::Pattern::from(pattern))),
+            )),
         }
     }
 
@@ -236,6 +338,56 @@ where
         )))
     }
 
+    /// Determine the appropriate `PatternPlurals` for a given `options::skeleton::Bag`.
+    fn patterns_for_skeleton_bag(
+        self,
+        bag: &skeleton_options::Bag,
+    ) -> Result<DataPayload<PatternPluralsFromPatternsV1Marker>> {
+        let mut components = components::Bag::default();
+        let requested_fields = if bag.has_preferred_hour_cycle() {
+            // Resolve the `j` symbol from the locale's preferences, or the locale's data.
+            let preferences = preferences::Bag::from_data_locale(self.locale);
+            let hour = match preferences.hour_cycle {
+                Some(preferences::HourCycle::H11) | Some(preferences::HourCycle::H12) => {
+                    fields::Hour::H12
+                }
+                Some(preferences::HourCycle::H23) | Some(preferences::HourCycle::H24) => {
+                    fields::Hour::H23
+                }
+                None => {
+                    match time_patterns_data_payload(self.data_provider, self.locale)?
+                        .get()
+                        .preferred_hour_cycle
+                    {
+                        crate::pattern::CoarseHourCycle::H11H12 => fields::Hour::H12,
+                        crate::pattern::CoarseHourCycle::H23H24 => fields::Hour::H23,
+                    }
+                }
+            };
+            components.preferences = Some(preferences);
+            bag.fields_with_hour(hour)
+        } else {
+            bag.fields_with_hour(fields::Hour::H23)
+        };
+
+        let skeletons_data = self.skeleton_data_payload()?;
+        let patterns = match skeleton::create_best_pattern_for_fields(
+            skeletons_data.get(),
+            &self.date_patterns_data.get().length_combinations,
+            requested_fields.as_slice(),
+            &components,
+            false, // Prefer the requested fields over the matched pattern.
+        ) {
+            skeleton::BestSkeleton::AllFieldsMatch(pattern)
+            | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(pattern),
+            skeleton::BestSkeleton::NoMatch => None,
+        }
+        .ok_or(DateTimeFormatterError::UnsupportedOptions)?;
+        Ok(DataPayload::from_owned(PatternPluralsV1(
+            patterns.into_owned(),
+        )))
+    }
+
     fn skeleton_data_payload(&self) -> Result<DataPayload<DateSkeletonPatternsV1Marker>> {
         let data = self
             .data_provider
@@ -253,7 +405,7 @@ pub trait DateSymbols {
         &self,
         month: fields::Month,
         length: fields::FieldLength,
-        code: MonthCode,
+        formattable_month: FormattableMonth,
     ) -> Result<&str>;
     fn get_symbol_for_weekday(
         &self,
@@ -264,6 +416,27 @@ pub trait DateSymbols {
     fn get_symbol_for_era<'a>(&'a self, length: fields::FieldLength, era_code: &'a Era) -> &str;
 }
 
+/// Looks up the symbol for a month, using the leap year variant of the name
+/// for months that come after a leap month (e.g. Adar II in Hebrew leap years).
+fn get_month_symbol<'a>(
+    symbols: &'a months::SymbolsV1,
+    formattable_month: FormattableMonth,
+) -> Result<&'a str> {
+    let code = formattable_month.code;
+    let follows_leap_month = code
+        .0
+        .as_str()
+        .strip_prefix('M')
+        .and_then(|number| number.parse::<u32>().ok())
+        .map_or(false, |number| formattable_month.ordinal > number);
+    if follows_leap_month {
+        symbols.get_after_leap_month(code)
+    } else {
+        symbols.get(code)
+    }
+    .ok_or(DateTimeFormatterError::MissingMonthSymbol(code))
+}
+
 impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
     fn get_symbol_for_weekday(
         &self,
@@ -317,7 +490,7 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
         &self,
         month: fields::Month,
         length: fields::FieldLength,
-        code: MonthCode,
+        formattable_month: FormattableMonth,
     ) -> Result<&str> {
         let widths = match month {
             fields::Month::Format => &self.months.format,
@@ -329,14 +502,20 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
                         _ => widths.abbreviated.as_ref(),
                     };
                     if let Some(symbols) = symbols {
-                        return symbols
-                            .get(code)
-                            .ok_or(DateTimeFormatterError::MissingMonthSymbol(code));
+                        return get_month_symbol(symbols, formattable_month);
                     } else {
-                        return self.get_symbol_for_month(fields::Month::Format, length, code);
+                        return self.get_symbol_for_month(
+                            fields::Month::Format,
+                            length,
+                            formattable_month,
+                        );
                     }
                 } else {
-                    return self.get_symbol_for_month(fields::Month::Format, length, code);
+                    return self.get_symbol_for_month(
+                        fields::Month::Format,
+                        length,
+                        formattable_month,
+                    );
                 }
             }
         };
@@ -345,9 +524,7 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
             fields::FieldLength::Narrow => &widths.narrow,
             _ => &widths.abbreviated,
         };
-        symbols
-            .get(code)
-            .ok_or(DateTimeFormatterError::MissingMonthSymbol(code))
+        get_month_symbol(symbols, formattable_month)
     }
 
     /// Get the era symbol
@@ -364,6 +541,50 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
     }
 }
 
+pub trait QuarterSymbols {
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        number: u8,
+    ) -> Option<&str>;
+}
+
+impl<'data> QuarterSymbols for provider::calendar::QuarterSymbolsV1<'data> {
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        number: u8,
+    ) -> Option<&str> {
+        let widths = &self.quarters;
+        if quarter == fields::Quarter::StandAlone {
+            if let Some(ref widths) = widths.stand_alone {
+                let symbols = match length {
+                    fields::FieldLength::Wide => widths.wide.as_ref(),
+                    fields::FieldLength::Narrow => widths.narrow.as_ref(),
+                    _ => widths.abbreviated.as_ref(),
+                };
+                if let Some(symbols) = symbols {
+                    return symbols
+                        .0
+                        .get(usize::from(number).wrapping_sub(1))
+                        .map(|x| &**x);
+                }
+            }
+        }
+        let symbols = match length {
+            fields::FieldLength::Wide => &widths.format.wide,
+            fields::FieldLength::Narrow => &widths.format.narrow,
+            _ => &widths.format.abbreviated,
+        };
+        symbols
+            .0
+            .get(usize::from(number).wrapping_sub(1))
+            .map(|x| &**x)
+    }
+}
+
 pub trait TimeSymbols {
     fn get_symbol_for_day_period(
         &self,
@@ -382,18 +603,80 @@ impl<'data> TimeSymbols for provider::calendar::TimeSymbolsV1<'data> {
         hour: input::IsoHour,
         is_top_of_hour: bool,
     ) -> Result<&str> {
-        use fields::{DayPeriod::NoonMidnight, FieldLength};
+        use fields::{
+            DayPeriod::{Flexible, NoonMidnight},
+            FieldLength,
+        };
         let widths = &self.day_periods.format;
         let symbols = match length {
             FieldLength::Wide => &widths.wide,
             FieldLength::Narrow => &widths.narrow,
             _ => &widths.abbreviated,
         };
+        // Flexible day periods fall back to noon and midnight, then AM and PM.
         Ok(match (day_period, u8::from(hour), is_top_of_hour) {
-            (NoonMidnight, 00, true) => symbols.midnight.as_ref().unwrap_or(&symbols.am),
-            (NoonMidnight, 12, true) => symbols.noon.as_ref().unwrap_or(&symbols.pm),
+            (NoonMidnight | Flexible, 00, true) => symbols.midnight.as_ref().unwrap_or(&symbols.am),
+            (NoonMidnight | Flexible, 12, true) => symbols.noon.as_ref().unwrap_or(&symbols.pm),
             (_, hour, _) if hour < 12 => &symbols.am,
             _ => &symbols.pm,
         })
     }
 }
+
+pub trait DayPeriodSymbols {
+    fn get_symbol_for_flexible_day_period<'a>(
+        &'a self,
+        time_symbols: &'a provider::calendar::TimeSymbolsV1,
+        length: fields::FieldLength,
+        hour: input::IsoHour,
+        is_top_of_hour: bool,
+    ) -> Result<&'a str>;
+}
+
+impl<'data> DayPeriodSymbols for provider::calendar::DayPeriodsV1<'data> {
+    fn get_symbol_for_flexible_day_period<'a>(
+        &'a self,
+        time_symbols: &'a provider::calendar::TimeSymbolsV1,
+        length: fields::FieldLength,
+        hour: input::IsoHour,
+        is_top_of_hour: bool,
+    ) -> Result<&'a str> {
+        use fields::{DayPeriod, FieldLength};
+        use provider::calendar::FlexibleDayPeriod;
+        let hour_number = u8::from(hour);
+        // Noon and midnight are only used if the locale has a rule for them.
+        if is_top_of_hour
+            && ((hour_number == 0 && self.midnight) || (hour_number == 12 && self.noon))
+        {
+            return time_symbols.get_symbol_for_day_period(
+                DayPeriod::NoonMidnight,
+                length,
+                hour,
+                is_top_of_hour,
+            );
+        }
+        let day_period = self
+            .hours
+            .get(usize::from(hour_number))
+            .unwrap_or(if hour_number < 12 {
+                FlexibleDayPeriod::Am
+            } else {
+                FlexibleDayPeriod::Pm
+            });
+        let widths = &self.symbols.format;
+        let symbols = match length {
+            FieldLength::Wide => &widths.wide,
+            FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        match symbols.get(day_period) {
+            Some(symbol) => Ok(symbol),
+            None => time_symbols.get_symbol_for_day_period(
+                DayPeriod::AmPm,
+                length,
+                hour,
+                is_top_of_hour,
+            ),
+        }
+    }
+}
@@ -6,7 +6,7 @@
 //! Central to this is the [`DateTimeFormatter`].
 
 use crate::{
-    format::datetime,
+    format::{datetime, numeric::NumericFormatter},
     input::{DateInput, DateTimeInput, ExtractedDateTimeInput, IsoTimeInput},
     options::components,
     options::{length, preferences, DateTimeFormatterOptions},
@@ -14,19 +14,17 @@ use crate::{
     provider,
     provider::calendar::{
         patterns::GenericPatternV1Marker, patterns::PatternPluralsFromPatternsV1Marker,
-        DateSkeletonPatternsV1Marker, ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker,
+        DateNumberingOverridesV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+        ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, QuarterSymbolsV1Marker,
         TimeLengthsV1Marker, TimeSymbolsV1Marker,
     },
     provider::week_data::WeekDataV1Marker,
     DateTimeFormatterError, FormattedDateTime,
 };
 use alloc::string::String;
+use writeable::Writeable;
 
-use icu_decimal::{
-    options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    provider::DecimalSymbolsV1Marker,
-    FixedDecimalFormatter,
-};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
 use icu_provider::prelude::*;
@@ -35,7 +33,8 @@ pub(crate) struct TimeFormatter {
     pub locale: DataLocale,
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
-    pub fixed_decimal_format: FixedDecimalFormatter,
+    pub day_periods: Option<DataPayload<DayPeriodsV1Marker>>,
+    pub numeric_format: NumericFormatter,
 }
 
 impl TimeFormatter {
@@ -52,6 +51,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
@@ -65,34 +65,31 @@ impl TimeFormatter {
         let required = datetime::analyze_patterns(&patterns.get().0, false)
             .map_err(|field| DateTimeFormatterError::UnsupportedField(field.symbol))?;
 
+        let req = DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        };
+
         let symbols_data = if required.time_symbols_data {
-            Some(
-                data_provider
-                    .load(DataRequest {
-                        locale: &locale,
-                        metadata: Default::default(),
-                    })?
-                    .take_payload()?,
-            )
+            Some(data_provider.load(req)?.take_payload()?)
         } else {
             None
         };
 
-        let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
-        fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+        let day_periods_data = if required.day_periods_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
 
-        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
-            data_provider,
-            &locale,
-            fixed_decimal_format_options,
-        )
-        .map_err(DateTimeFormatterError::FixedDecimalFormatter)?;
+        let numeric_format = NumericFormatter::try_new(data_provider, &locale, None)?;
 
         Ok(Self::new(
             locale,
             patterns,
             symbols_data,
-            fixed_decimal_format,
+            day_periods_data,
+            numeric_format,
         ))
     }
 
@@ -101,13 +98,15 @@ impl TimeFormatter {
         locale: DataLocale,
         patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
         symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
-        fixed_decimal_format: FixedDecimalFormatter,
+        day_periods: Option<DataPayload<DayPeriodsV1Marker>>,
+        numeric_format: NumericFormatter,
     ) -> Self {
         Self {
             locale,
             patterns,
             symbols,
-            fixed_decimal_format,
+            day_periods,
+            numeric_format,
         }
     }
 
@@ -121,12 +120,14 @@ impl TimeFormatter {
         FormattedDateTime {
             patterns: &self.patterns,
             date_symbols: None,
+            quarter_symbols: None,
             time_symbols: self.symbols.as_ref().map(|s| s.get()),
+            day_periods: self.day_periods.as_ref().map(|s| s.get()),
             datetime: ExtractedDateTimeInput::extract_from_time(value),
             week_data: None,
             locale: &self.locale,
             ordinal_rules: None,
-            fixed_decimal_format: &self.fixed_decimal_format,
+            numeric_format: &self.numeric_format,
         }
     }
 
@@ -138,19 +139,7 @@ impl TimeFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl IsoTimeInput,
     ) -> core::fmt::Result {
-        let extracted = ExtractedDateTimeInput::extract_from_time(value);
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            None,
-            self.symbols.as_ref().map(|s| s.get()),
-            &extracted,
-            None,
-            None,
-            &self.fixed_decimal_format,
-            &self.locale,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`IsoTimeInput`] implementer and returns it formatted as a string.
@@ -169,9 +158,10 @@ pub(crate) struct DateFormatter {
     pub generic_pattern: DataPayload<GenericPatternV1Marker>,
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
+    pub quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
     pub week_data: Option<DataPayload<WeekDataV1Marker>>,
     pub ordinal_rules: Option<PluralRules>,
-    pub fixed_decimal_format: FixedDecimalFormatter,
+    pub numeric_format: NumericFormatter,
 }
 
 impl DateFormatter {
@@ -189,6 +179,8 @@ impl DateFormatter {
         D: DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + ?Sized,
     {
         if locale.get_unicode_ext(&key!("ca")) == Some(value!("ethioaa")) {
@@ -208,7 +200,7 @@ impl DateFormatter {
         };
 
         let week_data = if required.week_data {
-            Some(data_provider.load(req)?.take_payload()?)
+            Some(provider::date_time::load_week_data(data_provider, &locale)?)
         } else {
             None
         };
@@ -228,45 +220,55 @@ impl DateFormatter {
             None
         };
 
-        let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
-        fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
 
-        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+        let numbering_overrides =
+            provider::date_time::load_numbering_overrides(data_provider, &locale)?;
+        let numeric_format = NumericFormatter::try_new(
             data_provider,
             &locale,
-            fixed_decimal_format_options,
-        )
-        .map_err(DateTimeFormatterError::FixedDecimalFormatter)?;
+            numbering_overrides
+                .as_ref()
+                .map(|overrides| overrides.get().for_length(length)),
+        )?;
 
         Ok(Self::new(
             locale,
             generic_pattern,
             patterns,
             symbols_data,
+            quarter_symbols_data,
             week_data,
             ordinal_rules,
-            fixed_decimal_format,
+            numeric_format,
         ))
     }
 
     /// Creates a new [`DateTimeFormatter`] regardless of whether there are time-zone symbols in the pattern.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         locale: DataLocale,
         generic_pattern: DataPayload<GenericPatternV1Marker>,
         patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
         symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
+        quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
         week_data: Option<DataPayload<WeekDataV1Marker>>,
         ordinal_rules: Option<PluralRules>,
-        fixed_decimal_format: FixedDecimalFormatter,
+        numeric_format: NumericFormatter,
     ) -> Self {
         Self {
             locale,
             generic_pattern,
             patterns,
             symbols,
+            quarter_symbols,
             week_data,
             ordinal_rules,
-            fixed_decimal_format,
+            numeric_format,
         }
     }
 
@@ -280,12 +282,14 @@ impl DateFormatter {
         FormattedDateTime {
             patterns: &self.patterns,
             date_symbols: self.symbols.as_ref().map(|s| s.get()),
+            quarter_symbols: self.quarter_symbols.as_ref().map(|s| s.get()),
             time_symbols: None,
+            day_periods: None,
             datetime: ExtractedDateTimeInput::extract_from_date(value),
             week_data: None,
             locale: &self.locale,
             ordinal_rules: None,
-            fixed_decimal_format: &self.fixed_decimal_format,
+            numeric_format: &self.numeric_format,
         }
     }
 
@@ -297,19 +301,7 @@ impl DateFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl DateInput,
     ) -> core::fmt::Result {
-        let extracted = ExtractedDateTimeInput::extract_from_date(value);
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            self.symbols.as_ref().map(|s| s.get()),
-            None,
-            &extracted,
-            None,
-            None,
-            &self.fixed_decimal_format,
-            &self.locale,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`DateInput`] implementer and returns it formatted as a string.
@@ -329,10 +321,12 @@ pub(crate) struct DateTimeFormatter {
     pub locale: DataLocale,
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub date_symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
+    pub quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
     pub time_symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+    pub day_periods: Option<DataPayload<DayPeriodsV1Marker>>,
     pub week_data: Option<DataPayload<WeekDataV1Marker>>,
     pub ordinal_rules: Option<PluralRules>,
-    pub fixed_decimal_format: FixedDecimalFormatter,
+    pub numeric_format: NumericFormatter,
 }
 
 impl DateTimeFormatter {
@@ -371,10 +365,12 @@ impl DateTimeFormatter {
             locale: date.locale,
             patterns,
             date_symbols: date.symbols,
+            quarter_symbols: date.quarter_symbols,
             time_symbols: time.symbols,
+            day_periods: time.day_periods,
             week_data: date.week_data,
             ordinal_rules: date.ordinal_rules,
-            fixed_decimal_format: date.fixed_decimal_format,
+            numeric_format: date.numeric_format,
         })
     }
 
@@ -395,6 +391,9 @@ impl DateTimeFormatter {
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + ?Sized,
     {
         let cal = locale.get_unicode_ext(&key!("ca"));
@@ -417,7 +416,7 @@ impl DateTimeFormatter {
         };
 
         let week_data = if required.week_data {
-            Some(data_provider.load(req)?.take_payload()?)
+            Some(provider::date_time::load_week_data(data_provider, &locale)?)
         } else {
             None
         };
@@ -437,51 +436,76 @@ impl DateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let time_symbols_data = if required.time_symbols_data {
             Some(data_provider.load(req)?.take_payload()?)
         } else {
             None
         };
 
-        let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
-        fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+        let day_periods_data = if required.day_periods_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
 
-        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+        let numbering_overrides = match options {
+            DateTimeFormatterOptions::Length(length::Bag {
+                date: Some(date_length),
+                ..
+            }) => provider::date_time::load_numbering_overrides(data_provider, &locale)?
+                .map(|overrides| (date_length, overrides)),
+            _ => None,
+        };
+        let numeric_format = NumericFormatter::try_new(
             data_provider,
             &locale,
-            fixed_decimal_format_options,
-        )
-        .map_err(DateTimeFormatterError::FixedDecimalFormatter)?;
+            numbering_overrides
+                .as_ref()
+                .map(|(date_length, overrides)| overrides.get().for_length(*date_length)),
+        )?;
 
         Ok(Self::new(
             locale,
             patterns,
             date_symbols_data,
+            quarter_symbols_data,
             time_symbols_data,
+            day_periods_data,
             week_data,
             ordinal_rules,
-            fixed_decimal_format,
+            numeric_format,
         ))
     }
 
     /// Creates a new [`DateTimeFormatter`] regardless of whether there are time-zone symbols in the pattern.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         locale: DataLocale,
         patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
         date_symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
+        quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
         time_symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+        day_periods: Option<DataPayload<DayPeriodsV1Marker>>,
         week_data: Option<DataPayload<WeekDataV1Marker>>,
         ordinal_rules: Option<PluralRules>,
-        fixed_decimal_format: FixedDecimalFormatter,
+        numeric_format: NumericFormatter,
     ) -> Self {
         Self {
             locale,
             patterns,
             date_symbols,
+            quarter_symbols,
             time_symbols,
+            day_periods,
             week_data,
             ordinal_rules,
-            fixed_decimal_format,
+            numeric_format,
         }
     }
 
@@ -496,12 +520,14 @@ impl DateTimeFormatter {
         FormattedDateTime {
             patterns: &self.patterns,
             date_symbols: self.date_symbols.as_ref().map(|s| s.get()),
+            quarter_symbols: self.quarter_symbols.as_ref().map(|s| s.get()),
             time_symbols: self.time_symbols.as_ref().map(|s| s.get()),
+            day_periods: self.day_periods.as_ref().map(|s| s.get()),
             datetime: ExtractedDateTimeInput::extract_from(value),
             week_data: self.week_data.as_ref().map(|s| s.get()),
             locale: &self.locale,
             ordinal_rules: self.ordinal_rules.as_ref(),
-            fixed_decimal_format: &self.fixed_decimal_format,
+            numeric_format: &self.numeric_format,
         }
     }
 
@@ -513,18 +539,7 @@ impl DateTimeFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl DateTimeInput,
     ) -> core::fmt::Result {
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            self.date_symbols.as_ref().map(|s| s.get()),
-            self.time_symbols.as_ref().map(|s| s.get()),
-            value,
-            self.week_data.as_ref().map(|s| s.get()),
-            self.ordinal_rules.as_ref(),
-            &self.fixed_decimal_format,
-            &self.locale,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`DateTimeInput`] implementer and returns it formatted as a string.
@@ -0,0 +1,388 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
+use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+
+use crate::{
+    fields::{Field, FieldSymbol},
+    format::{
+        datetime,
+        interval::{FormattedDateTimeInterval, IntervalPattern},
+        numeric::NumericFormatter,
+    },
+    input::DateTimeInput,
+    options::{components, DateTimeFormatterOptions},
+    pattern::{
+        runtime::{GenericPattern, Pattern, PatternPlurals},
+        PatternItem,
+    },
+    provider::{
+        self,
+        calendar::{
+            DateIntervalFormatsV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+            ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, QuarterSymbolsV1Marker,
+            TimeLengthsV1Marker, TimeSymbolsV1Marker,
+        },
+        week_data::WeekDataV1Marker,
+    },
+    raw, skeleton, DateTimeFormatterError,
+};
+
+/// The fields in which two datetimes can differ, from the most to the least significant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DifferingField {
+    Era,
+    Year,
+    Month,
+    Day,
+    DayPeriod,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DifferingField {
+    /// Returns the field that is displayed by a pattern field with the given symbol.
+    fn for_symbol(symbol: FieldSymbol) -> Option<Self> {
+        match symbol {
+            FieldSymbol::Era => Some(Self::Era),
+            FieldSymbol::Year(_) => Some(Self::Year),
+            // CLDR does not have interval patterns for quarters, so they use those for months.
+            FieldSymbol::Quarter(_) | FieldSymbol::Month(_) => Some(Self::Month),
+            FieldSymbol::Week(_) | FieldSymbol::Day(_) | FieldSymbol::Weekday(_) => Some(Self::Day),
+            FieldSymbol::DayPeriod(_) => Some(Self::DayPeriod),
+            FieldSymbol::Hour(_) => Some(Self::Hour),
+            FieldSymbol::Minute => Some(Self::Minute),
+            FieldSymbol::Second(_) => Some(Self::Second),
+            FieldSymbol::TimeZone(_) => None,
+        }
+    }
+
+    /// Returns the most significant field in which `first` and `second` differ, or `None`
+    /// if they are equal.
+    fn greatest(first: &impl DateTimeInput, second: &impl DateTimeInput) -> Option<Self> {
+        let (first_year, second_year) = (first.year(), second.year());
+        let (first_hour, second_hour) = (first.hour(), second.hour());
+        if first_year.map(|y| y.era) != second_year.map(|y| y.era) {
+            Some(Self::Era)
+        } else if first_year.map(|y| y.number) != second_year.map(|y| y.number) {
+            Some(Self::Year)
+        } else if first.month().map(|m| m.code) != second.month().map(|m| m.code) {
+            Some(Self::Month)
+        } else if first.day_of_month() != second.day_of_month() {
+            Some(Self::Day)
+        } else if first_hour.map(|h| h.number() < 12) != second_hour.map(|h| h.number() < 12) {
+            Some(Self::DayPeriod)
+        } else if first_hour != second_hour {
+            Some(Self::Hour)
+        } else if first.minute() != second.minute() {
+            Some(Self::Minute)
+        } else if first.second() != second.second() || first.nanosecond() != second.nanosecond() {
+            Some(Self::Second)
+        } else {
+            None
+        }
+    }
+
+    /// The letters that key the interval patterns for this field in the CLDR data.
+    fn pattern_letters(self) -> &'static [char] {
+        match self {
+            Self::Era => &['G'],
+            Self::Year => &['y'],
+            Self::Month => &['M'],
+            Self::Day => &['d'],
+            Self::DayPeriod => &['a'],
+            Self::Hour => &['h', 'H'],
+            Self::Minute => &['m'],
+            // CLDR does not have interval patterns for seconds.
+            Self::Second => &[],
+        }
+    }
+}
+
+/// Splits an interval pattern into the part for the first value and the part for the second
+/// value, at the first field that repeats the type of an earlier field.
+///
+/// For example, `"MMM d – d, y"` is split into `"MMM d – "` and `"d, y"`.
+fn split_interval_pattern(pattern: &Pattern) -> (Pattern<'static>, Pattern<'static>) {
+    let items: Vec<PatternItem> = pattern.items.iter().collect();
+    let mut seen: Vec<FieldSymbol> = Vec::new();
+    let split = items
+        .iter()
+        .position(|item| match item {
+            PatternItem::Field(field) => {
+                if seen
+                    .iter()
+                    .any(|symbol| symbol.discriminant_cmp(&field.symbol).is_eq())
+                {
+                    true
+                } else {
+                    seen.push(field.symbol);
+                    false
+                }
+            }
+            PatternItem::Literal(_) => false,
+        })
+        .unwrap_or(items.len());
+    let (first, second) = items.split_at(split);
+    (
+        Pattern::from(first.to_vec()),
+        Pattern::from(second.to_vec()),
+    )
+}
+
+/// This is the internal "raw" version of [crate::TypedDateTimeIntervalFormatter], i.e. a version of
+/// TypedDateTimeIntervalFormatter without the generic parameter. The actual implementation of
+/// [crate::TypedDateTimeIntervalFormatter] should live here.
+pub(crate) struct DateTimeIntervalFormatter {
+    pub datetime_format: raw::DateTimeFormatter,
+    /// The split interval patterns, keyed by the pattern letter of the greatest differing field.
+    pub interval_patterns: LiteMap<char, (Pattern<'static>, Pattern<'static>)>,
+    pub fallback: GenericPattern<'static>,
+    /// The least significant field displayed by the patterns.
+    pub smallest_field: Option<DifferingField>,
+    pub has_day_period: bool,
+}
+
+impl DateTimeIntervalFormatter {
+    /// Constructor that takes a selected [`DataLocale`], reference to a [`DataProvider`] and
+    /// a list of options, then collects all data necessary to format intervals between date and
+    /// time values into the given locale.
+    #[inline(never)]
+    pub fn try_new<D>(
+        data_provider: &D,
+        patterns_data: DataPayload<ErasedDateLengthsV1Marker>,
+        symbols_data_fn: impl FnOnce() -> Result<DataPayload<ErasedDateSymbolsV1Marker>, DataError>,
+        mut locale: DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<DateSkeletonPatternsV1Marker>
+            + DataProvider<DateIntervalFormatsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + ?Sized,
+    {
+        if locale.get_unicode_ext(&key!("ca")) == Some(value!("ethioaa")) {
+            locale.set_unicode_ext(key!("ca"), value!("ethiopic"));
+        }
+
+        let patterns = provider::date_time::PatternSelector::for_options(
+            data_provider,
+            patterns_data,
+            &locale,
+            &options,
+        )?;
+
+        let req = DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        };
+
+        let intervals: DataPayload<DateIntervalFormatsV1Marker> =
+            data_provider.load(req)?.take_payload()?;
+        // The interval patterns are matched against the requested fields where possible, as the
+        // resolved pattern may use other lengths, such as a numeric month for "MMM" in Japanese.
+        // The hour symbol is taken from the resolved pattern, which follows the locale's hour cycle.
+        let pattern_fields = components::Bag::from(&patterns.get().0).to_vec_fields();
+        let hour = pattern_fields
+            .iter()
+            .find(|field| matches!(field.symbol, FieldSymbol::Hour(_)))
+            .map(|field| field.symbol);
+        let requested_fields = match &options {
+            DateTimeFormatterOptions::Components(bag) => Some(bag.to_vec_fields()),
+            DateTimeFormatterOptions::Skeleton(bag) => Some(bag.fields().to_vec()),
+            _ => None,
+        };
+        let fields: Vec<Field> = match requested_fields {
+            Some(fields) => fields
+                .into_iter()
+                .map(|field| match (field.symbol, hour) {
+                    (FieldSymbol::Hour(_), Some(symbol)) => Field { symbol, ..field },
+                    _ => field,
+                })
+                .collect(),
+            None => pattern_fields,
+        };
+        let interval_patterns: LiteMap<char, (Pattern<'static>, Pattern<'static>)> =
+            skeleton::get_best_interval_patterns(intervals.get(), &fields)
+                .unwrap_or_default()
+                .iter()
+                .map(|(letter, pattern)| (*letter, split_interval_pattern(pattern)))
+                .collect();
+        let fallback = GenericPattern {
+            items: intervals.get().fallback.items.clone().into_owned(),
+        };
+
+        let mut smallest_field = None;
+        let mut has_day_period = false;
+        for pattern in patterns.get().0.patterns_iter() {
+            for item in pattern.items.iter() {
+                if let PatternItem::Field(field) = item {
+                    has_day_period |= matches!(field.symbol, FieldSymbol::DayPeriod(_));
+                    smallest_field = smallest_field.max(DifferingField::for_symbol(field.symbol));
+                }
+            }
+        }
+
+        let mut required = datetime::analyze_patterns(&patterns.get().0, false)
+            .map_err(|field| DateTimeFormatterError::UnsupportedField(field.symbol))?;
+        for (_, (first, second)) in interval_patterns.iter() {
+            let items: Vec<PatternItem> = first.items.iter().chain(second.items.iter()).collect();
+            let interval_required =
+                datetime::analyze_patterns(&PatternPlurals::from(Pattern::from(items)), false)
+                    .map_err(|field| DateTimeFormatterError::UnsupportedField(field.symbol))?;
+            required.date_symbols_data |= interval_required.date_symbols_data;
+            required.quarter_symbols_data |= interval_required.quarter_symbols_data;
+            required.time_symbols_data |= interval_required.time_symbols_data;
+            required.day_periods_data |= interval_required.day_periods_data;
+            required.week_data |= interval_required.week_data;
+        }
+
+        let week_data = if required.week_data {
+            Some(provider::date_time::load_week_data(data_provider, &locale)?)
+        } else {
+            None
+        };
+
+        let ordinal_rules = if let PatternPlurals::MultipleVariants(_) = &patterns.get().0 {
+            Some(PluralRules::try_new_ordinal_unstable(
+                data_provider,
+                &locale,
+            )?)
+        } else {
+            None
+        };
+
+        let date_symbols_data = if required.date_symbols_data {
+            Some(symbols_data_fn()?)
+        } else {
+            None
+        };
+
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let time_symbols_data = if required.time_symbols_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let day_periods_data = if required.day_periods_data {
+            Some(data_provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let numeric_format = NumericFormatter::try_new(data_provider, &locale, None)?;
+
+        Ok(Self {
+            datetime_format: raw::DateTimeFormatter::new(
+                locale,
+                patterns,
+                date_symbols_data,
+                quarter_symbols_data,
+                time_symbols_data,
+                day_periods_data,
+                week_data,
+                ordinal_rules,
+                numeric_format,
+            ),
+            interval_patterns,
+            fallback,
+            smallest_field,
+            has_day_period,
+        })
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns an instance of a
+    /// [`FormattedDateTimeInterval`] that contains all information necessary to display the
+    /// formatted interval between them.
+    #[inline]
+    pub fn format<'l>(
+        &'l self,
+        first: &impl DateTimeInput,
+        second: &impl DateTimeInput,
+    ) -> FormattedDateTimeInterval<'l> {
+        let interval_pattern = match DifferingField::greatest(first, second) {
+            Some(field)
+                if self
+                    .smallest_field
+                    .map_or(false, |smallest| field <= smallest) =>
+            {
+                // Patterns without a day period key a change of AM/PM by the hour.
+                let field = if field == DifferingField::DayPeriod && !self.has_day_period {
+                    DifferingField::Hour
+                } else {
+                    field
+                };
+                match field
+                    .pattern_letters()
+                    .iter()
+                    .find_map(|letter| self.interval_patterns.get(letter))
+                {
+                    Some((first, second)) => IntervalPattern::Split(first, second),
+                    None => IntervalPattern::Fallback(&self.fallback),
+                }
+            }
+            // The values are the same in all displayed fields.
+            _ => IntervalPattern::Single,
+        };
+
+        FormattedDateTimeInterval {
+            interval_pattern,
+            first: self.datetime_format.format(first),
+            second: self.datetime_format.format(second),
+        }
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and two [`DateTimeInput`] implementers and populates the buffer with the formatted interval.
+    #[inline(never)]
+    pub fn format_to_write(
+        &self,
+        w: &mut impl core::fmt::Write,
+        first: &impl DateTimeInput,
+        second: &impl DateTimeInput,
+    ) -> core::fmt::Result {
+        use writeable::Writeable;
+        self.format(first, second).write_to(w)
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns the interval between them formatted
+    /// as a string.
+    #[inline]
+    pub fn format_to_string(
+        &self,
+        first: &impl DateTimeInput,
+        second: &impl DateTimeInput,
+    ) -> String {
+        let mut s = String::new();
+        #[allow(clippy::expect_used)] // TODO(#1668) Clippy exceptions need docs or fixing.
+        self.format_to_write(&mut s, first, second)
+            .expect("Failed to write to a String.");
+        s
+    }
+
+    /// Returns a [`components::Bag`] that represents the resolved components for the
+    /// options that were provided to the [`DateTimeIntervalFormatter`].
+    pub fn resolve_components(&self) -> components::Bag {
+        self.datetime_format.resolve_components()
+    }
+}
@@ -4,7 +4,9 @@
 
 /// Untyped versions of TypedDateTimeFormatter and TypedZonedDateTimeFormatter
 mod datetime;
+mod interval;
 mod zoned_datetime;
 
 pub(crate) use datetime::{DateFormatter, DateTimeFormatter, TimeFormatter};
+pub(crate) use interval::DateTimeIntervalFormatter;
 pub(crate) use zoned_datetime::ZonedDateTimeFormatter;
@@ -3,28 +3,23 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::string::String;
-use icu_decimal::{
-    options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    provider::DecimalSymbolsV1Marker,
-    FixedDecimalFormatter,
-};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
 use icu_provider::prelude::*;
+use writeable::Writeable;
 
 use crate::{
-    format::{
-        datetime,
-        zoned_datetime::{self, FormattedZonedDateTime},
-    },
+    format::{datetime, numeric::NumericFormatter, zoned_datetime::FormattedZonedDateTime},
     input::{DateTimeInput, TimeZoneInput},
     input::{ExtractedDateTimeInput, ExtractedTimeZoneInput},
-    options::DateTimeFormatterOptions,
+    options::{length, DateTimeFormatterOptions},
     pattern::runtime::PatternPlurals,
     provider::{
         self,
         calendar::{
-            DateSkeletonPatternsV1Marker, ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker,
+            DateNumberingOverridesV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+            ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, QuarterSymbolsV1Marker,
             TimeLengthsV1Marker, TimeSymbolsV1Marker,
         },
         week_data::WeekDataV1Marker,
@@ -61,6 +56,9 @@ impl ZonedDateTimeFormatter {
             + DataProvider<TimeLengthsV1Marker>
             + DataProvider<DateSkeletonPatternsV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
@@ -90,7 +88,7 @@ impl ZonedDateTimeFormatter {
         };
 
         let week_data = if required.week_data {
-            Some(provider.load(req)?.take_payload()?)
+            Some(provider::date_time::load_week_data(provider, &locale)?)
         } else {
             None
         };
@@ -107,30 +105,50 @@ impl ZonedDateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let time_symbols_data = if required.time_symbols_data {
             Some(provider.load(req)?.take_payload()?)
         } else {
             None
         };
 
-        let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
-        fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+        let day_periods_data = if required.day_periods_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
 
-        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+        let numbering_overrides = match date_time_format_options {
+            DateTimeFormatterOptions::Length(length::Bag {
+                date: Some(date_length),
+                ..
+            }) => provider::date_time::load_numbering_overrides(provider, &locale)?
+                .map(|overrides| (date_length, overrides)),
+            _ => None,
+        };
+        let numeric_format = NumericFormatter::try_new(
             provider,
             &locale,
-            fixed_decimal_format_options,
-        )
-        .map_err(DateTimeFormatterError::FixedDecimalFormatter)?;
+            numbering_overrides
+                .as_ref()
+                .map(|(date_length, overrides)| overrides.get().for_length(*date_length)),
+        )?;
 
         let datetime_format = raw::DateTimeFormatter::new(
             locale,
             patterns,
             date_symbols_data,
+            quarter_symbols_data,
             time_symbols_data,
+            day_periods_data,
             week_data,
             ordinal_rules,
-            fixed_decimal_format,
+            numeric_format,
         );
 
         let time_zone_format = TimeZoneFormatter::try_new(
@@ -174,7 +192,7 @@ impl ZonedDateTimeFormatter {
         date: &impl DateTimeInput,
         time_zone: &impl TimeZoneInput,
     ) -> core::fmt::Result {
-        zoned_datetime::write_pattern(self, date, time_zone, w).map_err(|_| core::fmt::Error)
+        self.format(date, time_zone).write_to(w)
     }
 
     /// Takes a [`ZonedDateTimeInput`] implementer and returns it formatted as a string.
@@ -54,19 +54,7 @@ impl From<fields::SymbolError> for SkeletonError {
                 _ => Self::SymbolInvalid(ch),
             },
             fields::SymbolError::InvalidIndex(_) => unimplemented!(),
-            fields::SymbolError::Unknown(ch) => {
-                // NOTE: If you remove a symbol due to it now being supported,
-                //       make sure to regenerate the test data.
-                //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
-                match ch {
-                    // TODO(#487) - Flexible day periods
-                    'B'
-                    // TODO(#501) - Quarters
-                    | 'Q'
-                    => Self::SymbolUnimplemented(ch),
-                    _ => Self::SymbolUnknown(ch),
-                }
-            }
+            fields::SymbolError::Unknown(ch) => Self::SymbolUnknown(ch),
         }
     }
 }
@@ -5,7 +5,11 @@
 use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::convert::TryFrom;
+use litemap::LiteMap;
+use zerovec::ule::AsULE;
 
+use super::reference::Skeleton;
 use crate::{
     fields::{self, Field, FieldLength, FieldSymbol},
     options::{components, length},
@@ -14,7 +18,9 @@ use crate::{
         runtime::{self, PatternPlurals},
         PatternItem, TimeGranularity,
     },
-    provider::calendar::{patterns::GenericLengthPatternsV1, DateSkeletonPatternsV1},
+    provider::calendar::{
+        patterns::GenericLengthPatternsV1, DateIntervalFormatsV1, DateSkeletonPatternsV1,
+    },
 };
 
 // The following scalar values are for testing the suitability of a skeleton's field for the
@@ -76,6 +82,103 @@ const SKELETON_EXTRA_SYMBOL: u32 = 1000;
 // the stored skeletons. There cannot be any cases higher than this one.
 const REQUESTED_SYMBOL_MISSING: u32 = 10000;
 
+/// Computes the distance between the requested `fields` and the fields of a `skeleton`, as
+/// described in [`get_best_available_format_pattern`], along with the number of requested fields
+/// that are missing from the skeleton.
+fn skeleton_distance(skeleton: &Skeleton, fields: &[Field]) -> (u32, usize) {
+    debug_assert!(
+        skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
+        "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
+    );
+    let mut missing_fields = 0;
+    let mut distance: u32 = 0;
+    // The distance should fit into a u32.
+
+    let mut requested_fields = fields.iter().peekable();
+    let mut skeleton_fields = skeleton.fields_iter().peekable();
+
+    let mut matched_seconds = false;
+    loop {
+        let next = (requested_fields.peek(), skeleton_fields.peek());
+
+        // Try to find matching symbols.
+        match next {
+            (Some(requested_field), Some(skeleton_field)) => {
+                debug_assert!(
+                    // As of the time of this writing, stand-alone months are not in the CLDR
+                    // skeleton data. The components::Bag could produce stand-alone month fields,
+                    // but since the CLDR does not have them, only Month::Format symbols are
+                    // used for matching.
+                    skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
+                );
+
+                match skeleton_field
+                    .symbol
+                    .discriminant_cmp(&requested_field.symbol)
+                {
+                    Ordering::Less => {
+                        // Keep searching for a matching skeleton field.
+                        skeleton_fields.next();
+                        distance += SKELETON_EXTRA_SYMBOL;
+                        continue;
+                    }
+                    Ordering::Greater => {
+                        // https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons
+                        // A requested skeleton that includes both seconds and fractional seconds (e.g. “mmssSSS”) is allowed
+                        // to match a dateFormatItem skeleton that includes seconds but not fractional seconds (e.g. “ms”).
+                        if !(matched_seconds
+                            && requested_field.symbol
+                                == FieldSymbol::Second(fields::Second::FractionalSecond))
+                        {
+                            // The requested field symbol is missing from the skeleton.
+                            distance += REQUESTED_SYMBOL_MISSING;
+                            missing_fields += 1;
+                            requested_fields.next();
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+
+                if requested_field.symbol == FieldSymbol::Second(fields::Second::FractionalSecond)
+                    && skeleton_field.symbol
+                        == FieldSymbol::Second(fields::Second::FractionalSecond)
+                {
+                    matched_seconds = true;
+                }
+
+                distance += if requested_field == skeleton_field {
+                    NO_DISTANCE
+                } else if requested_field.symbol != skeleton_field.symbol {
+                    SUBSTANTIAL_DIFFERENCES_DISTANCE
+                } else if requested_field.get_length_type() != skeleton_field.get_length_type() {
+                    TEXT_VS_NUMERIC_DISTANCE
+                } else {
+                    WIDTH_MISMATCH_DISTANCE
+                };
+
+                requested_fields.next();
+                skeleton_fields.next();
+            }
+            (None, Some(_)) => {
+                // The skeleton has additional fields that we are not matching.
+                distance += SKELETON_EXTRA_SYMBOL;
+                skeleton_fields.next();
+            }
+            (Some(_), None) => {
+                // The skeleton is missing requested fields.
+                distance += REQUESTED_SYMBOL_MISSING;
+                requested_fields.next();
+                missing_fields += 1;
+            }
+            (None, None) => {
+                break;
+            }
+        }
+    }
+    (distance, missing_fields)
+}
+
 /// According to the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons)
 /// there will be a guaranteed match for a skeleton. However, with this initial implementation,
 /// there is no attempt to add on missing fields. This enum encodes the variants for the current
@@ -277,6 +380,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Solo example: "E"
             FieldSymbol::Era
             | FieldSymbol::Year(_)
+            | FieldSymbol::Quarter(_)
             | FieldSymbol::Month(_)
             | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
@@ -375,98 +479,7 @@ pub fn get_best_available_format_pattern<'data>(
     let mut closest_missing_fields = 0;
 
     for (skeleton, pattern) in skeletons.0.iter() {
-        debug_assert!(
-            skeleton.0.fields_len() <= MAX_SKELETON_FIELDS as usize,
-            "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
-        );
-        let mut missing_fields = 0;
-        let mut distance: u32 = 0;
-        // The distance should fit into a u32.
-
-        let mut requested_fields = fields.iter().peekable();
-        let mut skeleton_fields = skeleton.0.fields_iter().peekable();
-
-        let mut matched_seconds = false;
-        loop {
-            let next = (requested_fields.peek(), skeleton_fields.peek());
-
-            // Try to find matching symbols.
-            match next {
-                (Some(requested_field), Some(skeleton_field)) => {
-                    debug_assert!(
-                        // As of the time of this writing, stand-alone months are not in the CLDR
-                        // skeleton data. The components::Bag could produce stand-alone month fields,
-                        // but since the CLDR does not have them, only Month::Format symbols are
-                        // used for matching.
-                        skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
-                    );
-
-                    match skeleton_field
-                        .symbol
-                        .discriminant_cmp(&requested_field.symbol)
-                    {
-                        Ordering::Less => {
-                            // Keep searching for a matching skeleton field.
-                            skeleton_fields.next();
-                            distance += SKELETON_EXTRA_SYMBOL;
-                            continue;
-                        }
-                        Ordering::Greater => {
-                            // https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons
-                            // A requested skeleton that includes both seconds and fractional seconds (e.g. “mmssSSS”) is allowed
-                            // to match a dateFormatItem skeleton that includes seconds but not fractional seconds (e.g. “ms”).
-                            if !(matched_seconds
-                                && requested_field.symbol
-                                    == FieldSymbol::Second(fields::Second::FractionalSecond))
-                            {
-                                // The requested field symbol is missing from the skeleton.
-                                distance += REQUESTED_SYMBOL_MISSING;
-                                missing_fields += 1;
-                                requested_fields.next();
-                                continue;
-                            }
-                        }
-                        _ => (),
-                    }
-
-                    if requested_field.symbol
-                        == FieldSymbol::Second(fields::Second::FractionalSecond)
-                        && skeleton_field.symbol
-                            == FieldSymbol::Second(fields::Second::FractionalSecond)
-                    {
-                        matched_seconds = true;
-                    }
-
-                    distance += if requested_field == skeleton_field {
-                        NO_DISTANCE
-                    } else if requested_field.symbol != skeleton_field.symbol {
-                        SUBSTANTIAL_DIFFERENCES_DISTANCE
-                    } else if requested_field.get_length_type() != skeleton_field.get_length_type()
-                    {
-                        TEXT_VS_NUMERIC_DISTANCE
-                    } else {
-                        WIDTH_MISMATCH_DISTANCE
-                    };
-
-                    requested_fields.next();
-                    skeleton_fields.next();
-                }
-                (None, Some(_)) => {
-                    // The skeleton has additional fields that we are not matching.
-                    distance += SKELETON_EXTRA_SYMBOL;
-                    skeleton_fields.next();
-                }
-                (Some(_), None) => {
-                    // The skeleton is missing requested fields.
-                    distance += REQUESTED_SYMBOL_MISSING;
-                    requested_fields.next();
-                    missing_fields += 1;
-                }
-                (None, None) => {
-                    break;
-                }
-            }
-        }
+        let (distance, missing_fields) = skeleton_distance(&skeleton.0, fields);
 
         if distance < closest_distance {
             closest_format_pattern = Some(pattern);
@@ -515,3 +528,40 @@ pub fn get_best_available_format_pattern<'data>(
 
     BestSkeleton::AllFieldsMatch(closest_format_pattern)
 }
+
+/// Given a set of fields, find the interval patterns of the closest skeleton in the CLDR
+/// `intervalFormats` data, keyed by the pattern letter of the greatest differing field.
+///
+/// Unlike [`get_best_available_format_pattern`], only skeletons that differ from the requested
+/// fields in their widths are considered, as interval patterns cannot be extended with missing
+/// fields. The field lengths of the returned patterns are adjusted to the requested fields.
+/// Returns `None` if no skeleton is close enough.
+pub(crate) fn get_best_interval_patterns(
+    intervals: &DateIntervalFormatsV1,
+    fields: &[Field],
+) -> Option<LiteMap<char, runtime::Pattern<'static>>> {
+    let mut closest_skeleton = None;
+    let mut closest_distance = TEXT_VS_NUMERIC_DISTANCE;
+
+    for cursor in intervals.patterns.iter0() {
+        let skeleton = match Skeleton::try_from(cursor.key0()) {
+            Ok(skeleton) => skeleton,
+            Err(_) => continue,
+        };
+        let (distance, _) = skeleton_distance(&skeleton, fields);
+        if distance < closest_distance {
+            closest_skeleton = Some(cursor);
+            closest_distance = distance;
+        }
+    }
+
+    let patterns = closest_skeleton?
+        .iter1()
+        .map(|(letter, items)| {
+            let mut pattern = runtime::Pattern::from(items.iter().collect::<Vec<_>>());
+            adjust_pattern_field_lengths(fields, &mut pattern);
+            (char::from_unaligned(*letter), pattern)
+        })
+        .collect();
+    Some(patterns)
+}
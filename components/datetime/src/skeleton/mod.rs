@@ -206,17 +206,9 @@ mod test {
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // Timezones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
-    ];
-
-    // NOTE: If you are moving this to the SUPPORTED section, make sure to remove the match
-    //       on your symbol from impl From<fields::SymbolError> for SkeletonError
-    //       and then regenerate the test data.
-    //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
-    #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: &[&str] = &[
-        // TODO(#487) - Flexible day periods
+        // Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
-        // TODO(#501) - Quarters
+        // Quarters
         "yQ", "yQQQ", "yQQQQ",
     ];
 
@@ -235,27 +227,6 @@ mod test {
         }
     }
 
-    #[test]
-    fn test_unsupported_skeletons_skeletons_err() {
-        for string_skeleton in UNSUPPORTED_STRING_SKELETONS {
-            match Skeleton::try_from(*string_skeleton) {
-                Ok(_) => {
-                    panic!(
-                        "An unsupported field is now supported, consider moving {:?} to the \
-                         supported skeletons, and ensure the skeleton is properly implemented.",
-                        string_skeleton
-                    )
-                }
-                Err(err) => match err {
-                    SkeletonError::SymbolUnimplemented(_) => {
-                        // Every skeleton should return this error.
-                    }
-                    _ => panic!("{}", err),
-                },
-            }
-        }
-    }
-
     #[test]
     fn test_skeleton_deserialization() {
         assert_eq!(
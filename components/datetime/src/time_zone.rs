@@ -7,13 +7,15 @@ use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::String;
 use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use smallvec::SmallVec;
 use tinystr::tinystr;
 
 use crate::{
     error::DateTimeFormatterError,
     fields::{FieldSymbol, TimeZone},
-    format::time_zone::FormattedTimeZone,
+    format::{numeric::NumericFormatter, time_zone::FormattedTimeZone},
     input::TimeZoneInput,
     pattern::{PatternError, PatternItem},
     provider::{self, calendar::patterns::PatternPluralsFromPatternsV1Marker},
@@ -103,6 +105,8 @@ pub(super) struct TimeZoneDataPayloads {
     /// The specific short metazone names, e.g. Pacific Daylight Time
     pub(super) mz_specific_short:
         Option<DataPayload<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>>,
+    /// The formatter for the digits of localized GMT offsets, e.g. GMT-7
+    pub(super) numeric_format: NumericFormatter,
 }
 
 impl TimeZoneFormatter {
@@ -121,6 +125,7 @@ impl TimeZoneFormatter {
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
         let format_units = SmallVec::<[TimeZoneFormatterUnit; 3]>::new();
@@ -136,6 +141,7 @@ impl TimeZoneFormatter {
             mz_generic_short: None,
             mz_specific_long: None,
             mz_specific_short: None,
+            numeric_format: NumericFormatter::try_new(zone_provider, locale, None)?,
         };
 
         let zone_symbols = patterns
@@ -371,6 +377,7 @@ impl TimeZoneFormatter {
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
         let format_units = SmallVec::<[TimeZoneFormatterUnit; 3]>::new();
@@ -386,6 +393,7 @@ impl TimeZoneFormatter {
             mz_generic_short: None,
             mz_specific_long: None,
             mz_specific_short: None,
+            numeric_format: NumericFormatter::try_new(zone_provider, locale, None)?,
         };
 
         let mut tz_format: TimeZoneFormatter = Self {
@@ -1119,6 +1127,20 @@ impl FormatTimeZone for LocalizedGmtFormat {
             return if gmt_offset.is_zero() {
                 Ok(sink.write_str(&data_payloads.zone_formats.get().gmt_zero_format.clone()))
             } else {
+                // The digits are written in the numbering system of the locale.
+                let format_segment = |n: i32, padding: ZeroPadding| {
+                    let mut n = FixedDecimal::from(n.abs());
+                    if padding == ZeroPadding::On {
+                        n.pad_start(2);
+                    }
+                    data_payloads
+                        .numeric_format
+                        .locale_default()
+                        .format(&n)
+                        .write_to_string()
+                        .into_owned()
+                };
+                let offset_seconds = gmt_offset.raw_offset_seconds();
                 // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
                 Ok(sink.write_str(
                     &data_payloads
@@ -1136,39 +1158,15 @@ impl FormatTimeZone for LocalizedGmtFormat {
                         // support all combos of "(HH|H):mm" by replacing longest patterns first.
                         .replace(
                             "HH",
-                            if let Ok(offset_hours) =
-                                &TimeZoneFormatter::format_offset_hours(time_zone, ZeroPadding::On)
-                            {
-                                offset_hours
-                            } else {
-                                return Err(DateTimeFormatterError::MissingInputField(Some(
-                                    "gmt_offset",
-                                )));
-                            },
+                            &format_segment(offset_seconds / 3600, ZeroPadding::On),
                         )
                         .replace(
                             "mm",
-                            if let Ok(offset_minutes) =
-                                &TimeZoneFormatter::format_offset_minutes(time_zone)
-                            {
-                                offset_minutes
-                            } else {
-                                return Err(DateTimeFormatterError::MissingInputField(Some(
-                                    "gmt_offset",
-                                )));
-                            },
+                            &format_segment(offset_seconds % 3600 / 60, ZeroPadding::On),
                         )
                         .replace(
                             'H',
-                            if let Ok(offset_hours) =
-                                &TimeZoneFormatter::format_offset_hours(time_zone, ZeroPadding::Off)
-                            {
-                                offset_hours
-                            } else {
-                                return Err(DateTimeFormatterError::MissingInputField(Some(
-                                    "gmt_offset",
-                                )));
-                            },
+                            &format_segment(offset_seconds / 3600, ZeroPadding::Off),
                         ),
                 ))
             };
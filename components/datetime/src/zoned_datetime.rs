@@ -16,7 +16,10 @@ use crate::{
     options::DateTimeFormatterOptions,
     provider::{
         self,
-        calendar::{DateSkeletonPatternsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker},
+        calendar::{
+            DateNumberingOverridesV1Marker, DateSkeletonPatternsV1Marker, DayPeriodsV1Marker,
+            QuarterSymbolsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker,
+        },
         week_data::WeekDataV1Marker,
     },
     raw,
@@ -110,6 +113,9 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
             + DataProvider<TimeLengthsV1Marker>
             + DataProvider<DateSkeletonPatternsV1Marker>
             + DataProvider<WeekDataV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<DayPeriodsV1Marker>
+            + DataProvider<DateNumberingOverridesV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
@@ -12,10 +12,13 @@ use icu_calendar::{
     buddhist::Buddhist,
     coptic::Coptic,
     ethiopic::{Ethiopic, EthiopicEraStyle},
+    hebrew::Hebrew,
     indian::Indian,
     japanese::{Japanese, JapaneseExtended},
+    persian::Persian,
     provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker},
-    AsCalendar, DateTime, Gregorian, Iso,
+    roc::Roc,
+    AsCalendar, Date, DateTime, Gregorian, Iso,
 };
 use icu_datetime::provider::time_zones::{
     ExemplarCitiesV1Marker, MetaZoneGenericNamesLongV1Marker, MetaZoneGenericNamesShortV1Marker,
@@ -39,7 +42,7 @@ use icu_plurals::provider::OrdinalV1Marker;
 use icu_provider::prelude::*;
 use icu_provider_adapters::any_payload::AnyPayloadProvider;
 use icu_provider_adapters::fork::MultiForkByKeyProvider;
-use icu_timezone::{CustomTimeZone, TimeVariant};
+use icu_timezone::{CustomTimeZone, GmtOffset, TimeVariant};
 use patterns::{
     get_dayperiod_tests, get_time_zone_tests,
     structs::{
@@ -70,6 +73,9 @@ fn test_fixture(fixture_name: &str) {
         let input_coptic = input_value.to_calendar(Coptic);
         let input_indian = input_value.to_calendar(Indian);
         let input_ethiopic = input_value.to_calendar(Ethiopic::new());
+        let input_hebrew = input_value.to_calendar(Hebrew);
+        let input_persian = input_value.to_calendar(Persian);
+        let input_roc = input_value.to_calendar(Roc);
 
         let input_ethioaa =
             input_value.to_calendar(Ethiopic::new_with_era_style(EthiopicEraStyle::AmeteAlem));
@@ -150,6 +156,33 @@ fn test_fixture(fixture_name: &str) {
                         options,
                         &description,
                     ),
+                    AnyCalendarKind::Hebrew => assert_fixture_element(
+                        &locale,
+                        &input_hebrew,
+                        &input_iso,
+                        &output_value,
+                        &provider,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Persian => assert_fixture_element(
+                        &locale,
+                        &input_persian,
+                        &input_iso,
+                        &output_value,
+                        &provider,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Roc => assert_fixture_element(
+                        &locale,
+                        &input_roc,
+                        &input_iso,
+                        &output_value,
+                        &provider,
+                        options,
+                        &description,
+                    ),
                     _ => panic!("datetime test does not support locale {:?}", locale),
                 }
             } else {
@@ -190,6 +223,9 @@ fn assert_fixture_element<A, D>(
         + DataProvider<CopticDateLengthsV1Marker>
         + DataProvider<IndianDateLengthsV1Marker>
         + DataProvider<EthiopicDateLengthsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
         + DataProvider<GregorianDateSymbolsV1Marker>
         + DataProvider<BuddhistDateSymbolsV1Marker>
         + DataProvider<JapaneseDateSymbolsV1Marker>
@@ -197,11 +233,17 @@ fn assert_fixture_element<A, D>(
         + DataProvider<CopticDateSymbolsV1Marker>
         + DataProvider<IndianDateSymbolsV1Marker>
         + DataProvider<EthiopicDateSymbolsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
         // Note: all CldrCalendar markers are covered above, but the compiler doesn't know that
         + DataProvider<<<A as AsCalendar>::Calendar as CldrCalendar>::DateLengthsV1Marker>
         + DataProvider<<<A as AsCalendar>::Calendar as CldrCalendar>::DateSymbolsV1Marker>
         + DataProvider<OrdinalV1Marker>
         + DataProvider<WeekDataV1Marker>
+        + DataProvider<QuarterSymbolsV1Marker>
+        + DataProvider<DayPeriodsV1Marker>
+        + DataProvider<DateNumberingOverridesV1Marker>
         + DataProvider<JapaneseErasV1Marker>
         + DataProvider<JapaneseExtendedErasV1Marker>,
 {
@@ -401,6 +443,8 @@ fn test_dayperiod_patterns() {
             provider.load(req).unwrap().take_payload().unwrap();
         let week_data: DataPayload<WeekDataV1Marker> =
             provider.load(req).unwrap().take_payload().unwrap();
+        let day_periods_data: DataPayload<DayPeriodsV1Marker> =
+            provider.load(req).unwrap().take_payload().unwrap();
         data_locale.retain_unicode_ext(|_| false);
         let decimal_data: DataPayload<DecimalSymbolsV1Marker> = provider
             .load(DataRequest {
@@ -442,6 +486,10 @@ fn test_dayperiod_patterns() {
                                 key: TimeLengthsV1Marker::KEY,
                                 data: time_patterns_data.clone().wrap_into_any_payload(),
                             },
+                            AnyPayloadProvider {
+                                key: DayPeriodsV1Marker::KEY,
+                                data: day_periods_data.clone().wrap_into_any_payload(),
+                            },
                             AnyPayloadProvider {
                                 key: WeekDataV1Marker::KEY,
                                 data: week_data.clone().wrap_into_any_payload(),
@@ -475,6 +523,251 @@ fn test_dayperiod_patterns() {
     }
 }
 
+#[test]
+fn test_quarter_year_and_flexible_day_period_patterns() {
+    use std::borrow::Cow;
+
+    let provider = icu_testdata::get_provider();
+    let locale: Locale = "en-u-ca-gregory".parse().unwrap();
+    let mut data_locale = DataLocale::from(&locale);
+    let req = DataRequest {
+        locale: &data_locale,
+        metadata: Default::default(),
+    };
+    let mut date_patterns_data: DataPayload<GregorianDateLengthsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    date_patterns_data.with_mut(|data| {
+        data.length_combinations.long = "{0}".parse().unwrap();
+    });
+    let mut time_patterns_data: DataPayload<TimeLengthsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    let date_symbols_data: DataPayload<GregorianDateSymbolsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    let time_symbols_data: DataPayload<TimeSymbolsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    let skeleton_data: DataPayload<DateSkeletonPatternsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    let week_data: DataPayload<WeekDataV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    let en_day_periods_data: DataPayload<DayPeriodsV1Marker> =
+        provider.load(req).unwrap().take_payload().unwrap();
+    data_locale.retain_unicode_ext(|_| false);
+    let decimal_data: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &data_locale,
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let symbols = |names: [&'static str; 4]| quarters::SymbolsV1(names.map(Cow::Borrowed));
+    let quarter_symbols_data =
+        DataPayload::<QuarterSymbolsV1Marker>::from_owned(QuarterSymbolsV1 {
+            quarters: quarters::ContextsV1 {
+                format: quarters::FormatWidthsV1 {
+                    abbreviated: symbols(["Q1", "Q2", "Q3", "Q4"]),
+                    narrow: symbols(["1", "2", "3", "4"]),
+                    short: None,
+                    wide: symbols(["1st quarter", "2nd quarter", "3rd quarter", "4th quarter"]),
+                },
+                stand_alone: Some(quarters::StandAloneWidthsV1 {
+                    abbreviated: None,
+                    narrow: None,
+                    short: None,
+                    wide: Some(symbols(["first", "second", "third", "fourth"])),
+                }),
+            },
+        });
+
+    use FlexibleDayPeriod::*;
+    let mut hours = vec![Night1; 6];
+    hours.extend([Morning1; 6]);
+    hours.extend([Afternoon1; 6]);
+    hours.extend([Evening1; 3]);
+    hours.extend([Night1; 3]);
+    let day_period_symbols = flexible_day_periods::SymbolsV1 {
+        morning1: Some(Cow::Borrowed("in the morning")),
+        afternoon1: Some(Cow::Borrowed("in the afternoon")),
+        evening1: Some(Cow::Borrowed("in the evening")),
+        night1: Some(Cow::Borrowed("at night")),
+        ..Default::default()
+    };
+    let hand_built_day_periods_data = DataPayload::<DayPeriodsV1Marker>::from_owned(DayPeriodsV1 {
+        hours: hours.into_iter().collect(),
+        noon: true,
+        midnight: true,
+        symbols: flexible_day_periods::ContextsV1 {
+            format: flexible_day_periods::FormatWidthsV1 {
+                abbreviated: day_period_symbols.clone(),
+                narrow: day_period_symbols.clone(),
+                short: None,
+                wide: day_period_symbols,
+            },
+            stand_alone: None,
+        },
+    });
+
+    let cases = [
+        ("QQQQ y", (2020, 9, 1, 12, 34), "3rd quarter 2020"),
+        ("QQQ y", (2020, 12, 31, 12, 34), "Q4 2020"),
+        ("Q/QQ/QQQQQ", (2020, 1, 1, 12, 34), "1/01/1"),
+        ("qqqq", (2020, 5, 1, 12, 34), "second"),
+        ("qqq", (2020, 5, 1, 12, 34), "Q2"),
+        ("u", (2020, 9, 1, 12, 34), "2020"),
+        ("u G", (-43, 3, 15, 12, 34), "-43 BC"),
+        ("y G", (-43, 3, 15, 12, 34), "44 BC"),
+        ("r", (2020, 9, 1, 12, 34), "2020"),
+        ("h B", (2020, 9, 1, 12, 0), "12 noon"),
+        ("h B", (2020, 9, 1, 0, 0), "12 midnight"),
+        ("h:mm B", (2020, 9, 1, 12, 34), "12:34 in the afternoon"),
+        ("h:mm BBBB", (2020, 9, 1, 9, 15), "9:15 in the morning"),
+        ("h:mm BBBBB", (2020, 9, 1, 20, 59), "8:59 in the evening"),
+        ("h:mm B", (2020, 9, 1, 23, 5), "11:05 at night"),
+        ("h:mm B", (2020, 9, 1, 3, 30), "3:30 at night"),
+    ];
+    // The same patterns with the generated day periods for en.
+    let en_cases = [
+        ("h B", (2020, 9, 1, 12, 0), "12 noon"),
+        ("h B", (2020, 9, 1, 0, 0), "12 midnight"),
+        ("h:mm B", (2020, 9, 1, 12, 34), "12:34 in the afternoon"),
+        ("h:mm BBBB", (2020, 9, 1, 9, 15), "9:15 in the morning"),
+        ("h:mm BBBBB", (2020, 9, 1, 20, 59), "8:59 in the evening"),
+        ("h:mm BBBBB", (2020, 9, 1, 12, 0), "12:00 n"),
+        ("h:mm B", (2020, 9, 1, 23, 5), "11:05 at night"),
+        ("h:mm B", (2020, 9, 1, 3, 30), "3:30 at night"),
+    ];
+    let cases = cases
+        .iter()
+        .map(|case| (case, &hand_built_day_periods_data))
+        .chain(en_cases.iter().map(|case| (case, &en_day_periods_data)));
+    for (&(pattern, (year, month, day, hour, minute), expected), day_periods_data) in cases {
+        let new_pattern1: runtime::Pattern = pattern.parse().unwrap();
+        let new_pattern2: runtime::Pattern = pattern.parse().unwrap();
+        time_patterns_data.with_mut(move |data| {
+            data.time_h11_h12.long = new_pattern1;
+            data.time_h23_h24.long = new_pattern2;
+        });
+        let local_provider = MultiForkByKeyProvider::new(vec![
+            AnyPayloadProvider {
+                key: GregorianDateSymbolsV1Marker::KEY,
+                data: date_symbols_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: QuarterSymbolsV1Marker::KEY,
+                data: quarter_symbols_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: DayPeriodsV1Marker::KEY,
+                data: (*day_periods_data).clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: TimeSymbolsV1Marker::KEY,
+                data: time_symbols_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: DateSkeletonPatternsV1Marker::KEY,
+                data: skeleton_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: GregorianDateLengthsV1Marker::KEY,
+                data: date_patterns_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: TimeLengthsV1Marker::KEY,
+                data: time_patterns_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: WeekDataV1Marker::KEY,
+                data: week_data.clone().wrap_into_any_payload(),
+            },
+            AnyPayloadProvider {
+                key: DecimalSymbolsV1Marker::KEY,
+                data: decimal_data.clone().wrap_into_any_payload(),
+            },
+        ]);
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+            &local_provider.as_downcasting(),
+            &data_locale,
+            DateTimeFormatterOptions::default(),
+        )
+        .unwrap();
+        let datetime = DateTime::new_gregorian_datetime(year, month, day, hour, minute, 0).unwrap();
+        assert_eq!(dtf.format(&datetime).to_string(), expected, "{}", pattern);
+    }
+}
+
+/// Serves the given numbering system overrides on top of the test data.
+struct NumberingOverridesProvider<P> {
+    provider: P,
+    overrides: DataPayload<DateNumberingOverridesV1Marker>,
+}
+
+impl<P> DataProvider<DateNumberingOverridesV1Marker> for NumberingOverridesProvider<P> {
+    fn load(
+        &self,
+        _: DataRequest,
+    ) -> Result<DataResponse<DateNumberingOverridesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(self.overrides.clone()),
+        })
+    }
+}
+
+macro_rules! forward_to_provider {
+    ($($marker:ty),+) => {
+        $(
+            impl<P: DataProvider<$marker>> DataProvider<$marker> for NumberingOverridesProvider<P> {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    self.provider.load(req)
+                }
+            }
+        )+
+    };
+}
+
+forward_to_provider!(
+    GregorianDateSymbolsV1Marker,
+    GregorianDateLengthsV1Marker,
+    DecimalSymbolsV1Marker,
+    OrdinalV1Marker,
+    WeekDataV1Marker,
+    QuarterSymbolsV1Marker
+);
+
+#[test]
+fn test_numbering_system_overrides() {
+    use icu_datetime::options::length;
+
+    let mut medium = zerovec::ZeroMap::new();
+    medium.insert(&'d', &tinystr!(8, "thai"));
+    let provider = NumberingOverridesProvider {
+        provider: icu_testdata::get_provider(),
+        overrides: DataPayload::from_owned(DateNumberingOverridesV1 {
+            medium,
+            ..Default::default()
+        }),
+    };
+    let date = Date::new_gregorian_date(2020, 9, 1).unwrap();
+
+    for (locale, date_length, expected) in [
+        ("th", length::Date::Medium, "๑ ก.ย. 2020"),
+        ("th", length::Date::Short, "1/9/20"),
+        // An explicit numbering system takes precedence over the overrides.
+        ("th-u-nu-latn", length::Date::Medium, "1 ก.ย. 2020"),
+        ("th-u-nu-thai", length::Date::Medium, "๑ ก.ย. ๒๐๒๐"),
+    ] {
+        let df = TypedDateFormatter::<Gregorian>::try_new_unstable(
+            &provider,
+            &locale.parse::<Locale>().unwrap().into(),
+            date_length,
+        )
+        .unwrap();
+        assert_eq!(df.format_to_string(&date), expected, "{}", locale);
+    }
+}
+
 #[test]
 fn test_time_zone_format_configs() {
     let zone_provider = icu_testdata::get_provider();
@@ -524,6 +817,47 @@ fn test_time_zone_format_configs() {
     }
 }
 
+#[test]
+fn test_time_zone_format_gmt_offset_numbering_system() {
+    use icu_datetime::time_zone::{IsoFormat, IsoMinutes, IsoSeconds};
+
+    let zone_provider = icu_testdata::get_provider();
+    let time_zone =
+        CustomTimeZone::new(Some(GmtOffset::try_new(-27000).unwrap()), None, None, None);
+    for (locale, config, expected) in [
+        ("th", TimeZoneFormatterConfig::LocalizedGMT, "GMT-07:30"),
+        (
+            "th-u-nu-thai",
+            TimeZoneFormatterConfig::LocalizedGMT,
+            "GMT-๐๗:๓๐",
+        ),
+        (
+            "ar-EG",
+            TimeZoneFormatterConfig::LocalizedGMT,
+            "غرينتش-٠٧:٣٠",
+        ),
+        // ISO-8601 offsets are always written with ASCII digits.
+        (
+            "th-u-nu-thai",
+            TimeZoneFormatterConfig::Iso8601(
+                IsoFormat::Extended,
+                IsoMinutes::Required,
+                IsoSeconds::Never,
+            ),
+            "-07:30",
+        ),
+    ] {
+        let tzf = TimeZoneFormatter::try_from_config_unstable(
+            &zone_provider,
+            &locale.parse::<Locale>().unwrap().into(),
+            config,
+            TimeZoneFormatterOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(tzf.format_to_string(&time_zone), expected, "{}", locale);
+    }
+}
+
 #[test]
 #[cfg(debug_assertions)]
 #[should_panic(expected = "using last-resort time zone fallback")]
@@ -815,3 +1149,165 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_skeleton_string_options() {
+    let provider = icu_testdata::get_provider();
+    let datetime = parse_gregorian_from_str("2020-09-01T12:34:28.000").unwrap();
+
+    for (locale, skeleton, expected) in [
+        ("en", "yMMMdEjm", "Tue, Sep 1, 2020, 12:34 PM"),
+        ("en-u-hc-h23", "yMMMdEjm", "Tue, Sep 1, 2020, 12:34"),
+        ("fr", "yMMMdEjm", "mar. 1 sept. 2020, 12:34"),
+        ("en", "MMMMd", "September 1"),
+        ("en", "Hms", "12:34:28"),
+    ] {
+        let options = DateTimeFormatterOptions::try_from_skeleton(skeleton).unwrap();
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+            &provider,
+            &locale.parse::<Locale>().unwrap().into(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(
+            dtf.format_to_string(&datetime),
+            expected,
+            "{} {}",
+            locale,
+            skeleton
+        );
+    }
+
+    for skeleton in ["yMMMdy", "yMMMjH", "yMMMp", "hmmmmmmm"] {
+        assert!(
+            DateTimeFormatterOptions::try_from_skeleton(skeleton).is_err(),
+            "{}",
+            skeleton
+        );
+    }
+}
+
+#[test]
+fn test_week_of_year_locale_extensions() {
+    let provider = icu_testdata::get_provider();
+    let datetime = parse_gregorian_from_str("2021-01-03T12:00:00.000").unwrap();
+
+    for (locale, expected) in [
+        ("en-US", "2021-W02"),
+        ("en-GB", "2020-W53"),
+        ("en-GB-u-fw-sun", "2021-W01"),
+        ("en-US-u-rg-gbzzzz", "2020-W53"),
+    ] {
+        let options = DateTimeFormatterOptions::try_from_raw_pattern("Y-'W'ww").unwrap();
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+            &provider,
+            &locale.parse::<Locale>().unwrap().into(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(dtf.format_to_string(&datetime), expected, "{}", locale);
+    }
+}
+
+#[test]
+fn test_raw_pattern_options() {
+    use icu_datetime::DateTimeFormatterError;
+
+    let provider = icu_testdata::get_provider();
+    let datetime = parse_gregorian_from_str("2020-09-01T12:34:28.000").unwrap();
+
+    let options = DateTimeFormatterOptions::try_from_raw_pattern("y-MM-dd 'at' HH:mm").unwrap();
+    let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+        &provider,
+        &"fr".parse::<Locale>().unwrap().into(),
+        options,
+    )
+    .unwrap();
+    assert_eq!(dtf.format_to_string(&datetime), "2020-09-01 at 12:34");
+
+    assert!(DateTimeFormatterOptions::try_from_raw_pattern("y-MM-dd 'at HH:mm").is_err());
+    assert!(DateTimeFormatterOptions::try_from_raw_pattern("yyyyyyyyyyyyyyyyy").is_err());
+
+    let options = DateTimeFormatterOptions::try_from_raw_pattern("y-MM-dd zzzz").unwrap();
+    assert!(matches!(
+        TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+            &provider,
+            &"fr".parse::<Locale>().unwrap().into(),
+            options,
+        ),
+        Err(DateTimeFormatterError::UnsupportedField(_))
+    ));
+}
+
+#[test]
+fn test_datetime_parts() {
+    use icu_datetime::{options::length, parts};
+    use writeable::assert_writeable_parts_eq;
+
+    let provider = icu_testdata::get_provider();
+    let options =
+        length::Bag::from_date_time_style(length::Date::Medium, length::Time::Medium).into();
+    let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+        &provider,
+        &"en".parse::<Locale>().unwrap().into(),
+        options,
+    )
+    .unwrap();
+    let datetime = parse_gregorian_from_str("2020-09-01T12:34:28.000").unwrap();
+
+    assert_writeable_parts_eq!(
+        dtf.format(&datetime),
+        "Sep 1, 2020, 12:34:28 PM",
+        [
+            (0, 3, parts::MONTH),
+            (3, 4, parts::LITERAL),
+            (4, 5, parts::DAY),
+            (5, 7, parts::LITERAL),
+            (7, 11, parts::YEAR),
+            (11, 13, parts::LITERAL),
+            (13, 15, parts::HOUR),
+            (15, 16, parts::LITERAL),
+            (16, 18, parts::MINUTE),
+            (18, 19, parts::LITERAL),
+            (19, 21, parts::SECOND),
+            (21, 22, parts::LITERAL),
+            (22, 24, parts::DAY_PERIOD),
+        ]
+    );
+}
+
+#[test]
+fn test_zoned_datetime_parts() {
+    use icu_datetime::{options::components, parts};
+    use writeable::assert_writeable_parts_eq;
+
+    let provider = icu_testdata::get_provider();
+    let mut bag = components::Bag::default();
+    bag.weekday = Some(components::Text::Short);
+    bag.hour = Some(components::Numeric::Numeric);
+    bag.minute = Some(components::Numeric::TwoDigit);
+    bag.time_zone_name = Some(components::TimeZoneName::GmtOffset);
+    let dtf = TypedZonedDateTimeFormatter::<Gregorian>::try_new_unstable(
+        &provider,
+        &"en".parse::<Locale>().unwrap().into(),
+        bag.into(),
+        TimeZoneFormatterOptions::default(),
+    )
+    .unwrap();
+    let (datetime, time_zone) =
+        parse_zoned_gregorian_from_str("2021-04-08T16:12:37.000-07:00").unwrap();
+
+    assert_writeable_parts_eq!(
+        dtf.format(&datetime, &time_zone),
+        "Thu, 16:12 GMT-07:00",
+        [
+            (0, 3, parts::WEEKDAY),
+            (3, 5, parts::LITERAL),
+            (5, 7, parts::HOUR),
+            (7, 8, parts::LITERAL),
+            (8, 10, parts::MINUTE),
+            (10, 11, parts::LITERAL),
+            (11, 20, parts::TIME_ZONE_NAME),
+        ]
+    );
+}
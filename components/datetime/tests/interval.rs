@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::{DateTime, Gregorian};
+use icu_datetime::{
+    options::{components, length},
+    DateTimeIntervalFormatter, TypedDateTimeIntervalFormatter,
+};
+use icu_locid::{locale, Locale};
+
+fn date_bag() -> components::Bag {
+    let mut bag = components::Bag::default();
+    bag.year = Some(components::Year::Numeric);
+    bag.month = Some(components::Month::Short);
+    bag.day = Some(components::Day::NumericDayOfMonth);
+    bag
+}
+
+#[test]
+fn test_date_interval() {
+    let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &locale!("en").into(),
+        date_bag().into(),
+    )
+    .unwrap();
+
+    let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0).unwrap();
+    for ((year, month, day, hour), expected) in [
+        ((2023, 1, 7, 11), "Jan 3 – 7, 2023"),
+        ((2023, 2, 7, 11), "Jan 3 – Feb 7, 2023"),
+        ((2024, 1, 7, 11), "Jan 3, 2023 – Jan 7, 2024"),
+        ((2023, 1, 3, 11), "Jan 3, 2023"),
+    ] {
+        let second = DateTime::new_gregorian_datetime(year, month, day, hour, 0, 0).unwrap();
+        assert_eq!(dtif.format_to_string(&first, &second), expected);
+    }
+}
+
+#[test]
+fn test_time_interval() {
+    let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &locale!("en").into(),
+        length::Bag::from_time_style(length::Time::Short).into(),
+    )
+    .unwrap();
+
+    let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0).unwrap();
+    for ((hour, minute, second), expected) in [
+        ((11, 30, 0), "10:00 – 11:30 AM"),
+        ((10, 30, 0), "10:00 – 10:30 AM"),
+        ((14, 0, 0), "10:00 AM – 2:00 PM"),
+        ((10, 0, 30), "10:00 AM"),
+    ] {
+        let second = DateTime::new_gregorian_datetime(2023, 1, 3, hour, minute, second).unwrap();
+        assert_eq!(dtif.format_to_string(&first, &second), expected);
+    }
+}
+
+#[test]
+fn test_any_calendar_interval() {
+    let dtif = DateTimeIntervalFormatter::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &"en-u-ca-gregory".parse::<Locale>().unwrap().into(),
+        date_bag().into(),
+    )
+    .unwrap();
+
+    let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0)
+        .unwrap()
+        .to_any();
+    let second = DateTime::new_gregorian_datetime(2023, 1, 7, 11, 0, 0)
+        .unwrap()
+        .to_any();
+
+    assert_eq!(
+        dtif.format_to_string(&first, &second).unwrap(),
+        "Jan 3 – 7, 2023"
+    );
+}
+
+#[test]
+fn test_date_time_interval_fallback() {
+    // There are no interval patterns for dates with times, so the fallback pattern is used.
+    let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &locale!("en").into(),
+        length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short).into(),
+    )
+    .unwrap();
+
+    let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0).unwrap();
+    let second = DateTime::new_gregorian_datetime(2023, 1, 7, 11, 0, 0).unwrap();
+    assert_eq!(
+        dtif.format_to_string(&first, &second),
+        "Jan 3, 2023, 10:00 AM – Jan 7, 2023, 11:00 AM"
+    );
+}
+
+#[test]
+fn test_localized_date_interval() {
+    let first = DateTime::new_gregorian_datetime(2023, 1, 3, 10, 0, 0).unwrap();
+    let second = DateTime::new_gregorian_datetime(2023, 2, 7, 11, 0, 0).unwrap();
+    for (locale, expected) in [
+        ("fr", "3 janv. – 7 févr. 2023"),
+        ("ja", "2023年1月3日～2月7日"),
+    ] {
+        let dtif = TypedDateTimeIntervalFormatter::<Gregorian>::try_new_with_buffer_provider(
+            &icu_testdata::get_provider(),
+            &locale.parse::<Locale>().unwrap().into(),
+            date_bag().into(),
+        )
+        .unwrap();
+        assert_eq!(
+            dtif.format_to_string(&first, &second),
+            expected,
+            "{}",
+            locale
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "serde")]
+
+use icu_calendar::{AnyCalendar, AnyCalendarKind, DateTime};
+use icu_datetime::fields::{FieldSymbol, Month};
+use icu_datetime::{options::length, DateTimeFormatter, DateTimeParseError, DateTimeParser};
+use icu_locid::Locale;
+
+fn new_parser(locale: &str, date: length::Date, time: length::Time) -> DateTimeParser {
+    let locale: Locale = locale.parse().unwrap();
+    let options = length::Bag::from_date_time_style(date, time);
+    DateTimeParser::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &locale.into(),
+        options.into(),
+    )
+    .unwrap()
+}
+
+fn assert_parses_to(
+    result: Result<DateTime<AnyCalendar>, DateTimeParseError>,
+    expected: &DateTime<AnyCalendar>,
+    input: &str,
+) {
+    let actual = result.unwrap_or_else(|e| panic!("Failed to parse {:?}: {}", input, e));
+    assert_eq!(actual.date, expected.date, "{}", input);
+    assert_eq!(actual.time, expected.time, "{}", input);
+}
+
+#[test]
+fn test_round_trip() {
+    let lengths = [
+        (length::Date::Full, length::Time::Medium),
+        (length::Date::Long, length::Time::Medium),
+        (length::Date::Medium, length::Time::Short),
+        (length::Date::Short, length::Time::Short),
+    ];
+    let locales = [
+        "en",
+        "fr",
+        "ru",
+        "bn",
+        "ar-EG",
+        "th",
+        "en-u-ca-buddhist",
+        "en-u-ca-japanese",
+        "ja-u-ca-japanese",
+        "en-u-ca-coptic",
+    ];
+    for locale_str in locales {
+        let locale: Locale = locale_str.parse().unwrap();
+        let kind = AnyCalendarKind::from_locale(&locale).unwrap_or(AnyCalendarKind::Gregorian);
+        for (date, time) in lengths {
+            let options = length::Bag::from_date_time_style(date, time);
+            let formatter = DateTimeFormatter::try_new_with_buffer_provider(
+                &icu_testdata::get_provider(),
+                &locale.clone().into(),
+                options.into(),
+            )
+            .unwrap();
+            let parser = new_parser(locale_str, date, time);
+
+            let calendar =
+                AnyCalendar::try_new_with_buffer_provider(&icu_testdata::get_provider(), kind)
+                    .unwrap();
+            let seconds = if time == length::Time::Short { 0 } else { 28 };
+            let datetime = DateTime::new_iso_datetime(2020, 9, 1, 17, 34, seconds)
+                .unwrap()
+                .to_calendar(calendar);
+
+            let formatted = formatter.format_to_string(&datetime).unwrap();
+            assert_parses_to(parser.parse_strict(&formatted), &datetime, &formatted);
+            assert_parses_to(parser.parse_lenient(&formatted), &datetime, &formatted);
+        }
+    }
+}
+
+#[test]
+fn test_ordinal_months() {
+    // Numeric months are formatted as the ordinal month, which in a Hebrew leap year
+    // differs from the month code from Adar I onwards.
+    let locale: Locale = "fr-u-ca-hebrew".parse().unwrap();
+    let options = length::Bag::from_date_time_style(length::Date::Short, length::Time::Short);
+    let formatter = DateTimeFormatter::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        &locale.into(),
+        options.into(),
+    )
+    .unwrap();
+    let parser = new_parser("fr-u-ca-hebrew", length::Date::Short, length::Time::Short);
+    let calendar = AnyCalendar::try_new_with_buffer_provider(
+        &icu_testdata::get_provider(),
+        AnyCalendarKind::Hebrew,
+    )
+    .unwrap();
+    // (ISO date, ordinal month, month code)
+    for ((year, month, day), ordinal, code) in [
+        // 5784 is a leap year
+        ((2024, 2, 20), 6, "M05L"),
+        ((2024, 3, 20), 7, "M06"),
+        ((2024, 4, 20), 8, "M07"),
+        // 5783 is not
+        ((2023, 4, 20), 7, "M07"),
+    ] {
+        let datetime = DateTime::new_iso_datetime(year, month, day, 17, 34, 0)
+            .unwrap()
+            .to_calendar(calendar.clone());
+        assert_eq!(datetime.date.month().ordinal, ordinal);
+        assert_eq!(datetime.date.month().code.0, code);
+
+        let formatted = formatter.format_to_string(&datetime).unwrap();
+        assert_parses_to(parser.parse_strict(&formatted), &datetime, &formatted);
+        assert_parses_to(parser.parse_lenient(&formatted), &datetime, &formatted);
+    }
+}
+
+#[test]
+fn test_lenient() {
+    let parser = new_parser("en", length::Date::Medium, length::Time::Medium);
+    let expected = DateTime::new_gregorian_datetime(2020, 9, 1, 17, 34, 28)
+        .unwrap()
+        .to_any();
+
+    let input = "Sep 1, 2020, 5:34:28 PM";
+    assert_parses_to(parser.parse_strict(input), &expected, input);
+    assert_parses_to(parser.parse_lenient(input), &expected, input);
+
+    for input in [
+        "sep 1 2020 5:34:28 pm",
+        "  SEPTEMBER 1, 2020 , 5:34:28PM  ",
+        "9/1/2020 5:34:28 PM",
+        "Sep 01 2020 05:34:28 p",
+    ] {
+        assert_parses_to(parser.parse_lenient(input), &expected, input);
+        assert!(parser.parse_strict(input).is_err(), "{}", input);
+    }
+
+    let parser = new_parser("en", length::Date::Full, length::Time::Short);
+    // The weekday does not need to match the date.
+    let input = "Monday, September 1, 2020 at 5:34 PM";
+    let expected = DateTime::new_gregorian_datetime(2020, 9, 1, 17, 34, 0)
+        .unwrap()
+        .to_any();
+    assert_parses_to(parser.parse_lenient(input), &expected, input);
+    assert_eq!(
+        parser.parse_strict(input).map(|_| ()),
+        Err(DateTimeParseError::MismatchedWeekday)
+    );
+}
+
+#[test]
+fn test_two_digit_years() {
+    let parser = new_parser("en", length::Date::Short, length::Time::Short);
+    for (input, year) in [
+        ("9/1/20, 5:34 PM", 2020),
+        ("9/1/69, 5:34 PM", 2069),
+        ("9/1/70, 5:34 PM", 1970),
+    ] {
+        let expected = DateTime::new_gregorian_datetime(year, 9, 1, 17, 34, 0)
+            .unwrap()
+            .to_any();
+        assert_parses_to(parser.parse_strict(input), &expected, input);
+    }
+
+    // Lenient parsing takes years with more than two digits literally.
+    let input = "9/1/1820, 5:34 PM";
+    let expected = DateTime::new_gregorian_datetime(1820, 9, 1, 17, 34, 0)
+        .unwrap()
+        .to_any();
+    assert_parses_to(parser.parse_lenient(input), &expected, input);
+}
+
+#[test]
+fn test_errors() {
+    let parser = new_parser("en", length::Date::Medium, length::Time::Short);
+
+    assert_eq!(
+        parser.parse_strict("Sep 1 2020, 5:34 PM").map(|_| ()),
+        Err(DateTimeParseError::ExpectedLiteral {
+            position: 5,
+            expected: ','
+        })
+    );
+    assert_eq!(
+        parser.parse_strict("Foo 1, 2020, 5:34 PM").map(|_| ()),
+        Err(DateTimeParseError::InvalidField {
+            position: 0,
+            field: FieldSymbol::Month(Month::Format)
+        })
+    );
+    assert_eq!(
+        parser
+            .parse_lenient("Sep 1, 2020, 5:34 PM and more")
+            .map(|_| ()),
+        Err(DateTimeParseError::TrailingInput(21))
+    );
+    assert!(matches!(
+        parser.parse_strict("Feb 30, 2020, 5:34 PM"),
+        Err(DateTimeParseError::DateTimeInput(_))
+    ));
+}
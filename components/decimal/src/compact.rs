@@ -0,0 +1,206 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedCompactDecimal;
+use crate::options::{
+    CompactDecimalFormatterOptions, CompactDisplay, FixedDecimalFormatterOptions,
+};
+use crate::provider::*;
+use crate::{FixedDecimalFormatter, FixedDecimalFormatterError};
+use fixed_decimal::FixedDecimal;
+use icu_plurals::{provider::CardinalV1Marker, PluralOperands, PluralRules};
+use icu_provider::prelude::*;
+
+/// A formatter for numbers in compact notation, rendering a [`FixedDecimal`] as a rounded
+/// significand with a localized power of ten, such as "1.2K" or "3.4 million".
+///
+/// The significand is rounded half-even to an integer, keeping at least two significant
+/// digits, such as "1.2K" for 1234 and "123K" for 123456.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{CompactDecimalFormatterOptions, CompactDisplay};
+/// use icu::decimal::CompactDecimalFormatter;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options = CompactDecimalFormatterOptions::default();
+/// options.display = CompactDisplay::Long;
+/// let cdf = CompactDecimalFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_writeable_eq!(cdf.format(&3_456_789.into()), "3.5 million");
+/// ```
+pub struct CompactDecimalFormatter {
+    pub(crate) fixed_decimal_formatter: FixedDecimalFormatter,
+    pub(crate) compact_data: DataPayload<ShortCompactDecimalFormatDataV1Marker>,
+    pub(crate) plural_rules: PluralRules,
+}
+
+impl CompactDecimalFormatter {
+    /// Creates a new [`CompactDecimalFormatter`] from locale data and an options bag.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, FixedDecimalFormatterError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<ShortCompactDecimalFormatDataV1Marker>
+            + DataProvider<LongCompactDecimalFormatDataV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            FixedDecimalFormatterOptions {
+                grouping_strategy: options.grouping_strategy,
+            },
+        )?;
+        let request = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let compact_data = match options.display {
+            CompactDisplay::Short => {
+                DataProvider::<ShortCompactDecimalFormatDataV1Marker>::load(data_provider, request)?
+                    .take_payload()?
+            }
+            CompactDisplay::Long => {
+                DataProvider::<LongCompactDecimalFormatDataV1Marker>::load(data_provider, request)?
+                    .take_payload()?
+                    .cast()
+            }
+        };
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        Ok(Self {
+            fixed_decimal_formatter,
+            compact_data,
+            plural_rules,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CompactDecimalFormatterOptions,
+        error: FixedDecimalFormatterError
+    );
+
+    /// Formats a [`FixedDecimal`] in compact notation, returning a [`FormattedCompactDecimal`].
+    pub fn format<'l>(&'l self, value: &FixedDecimal) -> FormattedCompactDecimal<'l> {
+        let data = self.compact_data.get();
+        let magnitude = value.nonzero_magnitude_left();
+        let (mut key, mut exponent) = data.exponent_for(magnitude);
+        let mut significand = value.clone().multiplied_pow10(-exponent);
+        round_significand(&mut significand);
+
+        // Rounding can carry into a magnitude with another pattern, such as 999,999 to 1M.
+        let rounded_magnitude = significand.nonzero_magnitude_left() + exponent;
+        if rounded_magnitude > magnitude {
+            let (rounded_key, rounded_exponent) = data.exponent_for(rounded_magnitude);
+            if rounded_exponent != exponent {
+                significand = significand.multiplied_pow10(exponent - rounded_exponent);
+                significand.trim_end();
+            }
+            key = rounded_key;
+            exponent = rounded_exponent;
+        }
+
+        let pattern = if exponent == 0 {
+            None
+        } else {
+            let operands = compact_plural_operands(&significand, exponent);
+            data.get(key, self.plural_rules.category_for(operands))
+        };
+
+        FormattedCompactDecimal {
+            formatter: &self.fixed_decimal_formatter,
+            significand,
+            pattern,
+        }
+    }
+}
+
+/// Rounds `significand` half-even to an integer, keeping at least two significant digits, and
+/// removes trailing fraction zeros.
+fn round_significand(significand: &mut FixedDecimal) {
+    let magnitude = significand.nonzero_magnitude_left();
+    significand.half_even(core::cmp::min(0, magnitude - 1));
+    significand.trim_end();
+}
+
+/// Returns the plural operands of a number in compact notation, which are those of the full
+/// number with the exponent as `c`, such as i = 1200 and c = 3 for "1.2K".
+fn compact_plural_operands(significand: &FixedDecimal, exponent: i16) -> PluralOperands {
+    let mut operands = PluralOperands::from(&significand.clone().multiplied_pow10(exponent));
+    operands.c = exponent as usize;
+    operands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_significand() {
+        for (input, expected) in [
+            ("1.234", "1.2"),
+            ("1.25", "1.2"),
+            ("1.35", "1.4"),
+            ("12.5", "12"),
+            ("123.456", "123"),
+            ("1.04", "1"),
+            ("9.96", "10"),
+            ("0.0123", "0.012"),
+            ("-3.456", "-3.5"),
+            ("0", "0"),
+        ] {
+            let mut value: FixedDecimal = input.parse().unwrap();
+            round_significand(&mut value);
+            assert_eq!(value.to_string(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_compact_plural_operands() {
+        use icu_locid::locale;
+        use icu_plurals::PluralCategory;
+
+        let operands = compact_plural_operands(&"1.2".parse().unwrap(), 3);
+        assert_eq!(operands.i, 1200);
+        assert_eq!(operands.v, 0);
+        assert_eq!(operands.c, 3);
+
+        // French uses "many" for millions in compact notation, but not for thousands.
+        let rules = PluralRules::try_new_cardinal_unstable(
+            &icu_testdata::get_baked_provider(),
+            &locale!("fr").into(),
+        )
+        .unwrap();
+        for (significand, exponent, expected) in [
+            ("1.2", 6, PluralCategory::Many),
+            ("2", 6, PluralCategory::Many),
+            ("1.2", 3, PluralCategory::Other),
+            ("1.5", 0, PluralCategory::One),
+        ] {
+            let operands = compact_plural_operands(&significand.parse().unwrap(), exponent);
+            assert_eq!(
+                rules.category_for(operands),
+                expected,
+                "{}c{}",
+                significand,
+                exponent
+            );
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedCurrency;
+use crate::options::{CurrencyFormatterOptions, CurrencyUsage};
+use crate::provider::*;
+use crate::FixedDecimalFormatterError;
+use core::cmp::Ordering;
+use fixed_decimal::FixedDecimal;
+use icu_plurals::{provider::CardinalV1Marker, PluralRules};
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+
+/// A formatter for amounts of money, rendering a [`FixedDecimal`] with a currency symbol, ISO
+/// 4217 code, or long name, such as "$1,234.50", "1.234,50 €" or "(€12.00)".
+///
+/// Amounts are rounded half-even to the minor unit of the currency, such as cents for US
+/// dollars, or to its cash rounding increment with [`CurrencyUsage::Cash`].
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{CurrencyFormatterOptions, CurrencySign};
+/// use icu::decimal::CurrencyFormatter;
+/// use icu::locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options = CurrencyFormatterOptions::default();
+/// options.sign = CurrencySign::Accounting;
+/// let cf = CurrencyFormatter::try_new_with_buffer_provider(&provider, &locale!("en").into(), options)
+///     .expect("Data should load successfully");
+///
+/// let amount = "-12".parse().unwrap();
+/// assert_writeable_eq!(cf.format(&amount, tinystr!(3, "EUR")), "(€12.00)");
+/// ```
+pub struct CurrencyFormatter {
+    pub(crate) options: CurrencyFormatterOptions,
+    pub(crate) symbols: DataPayload<DecimalSymbolsV1Marker>,
+    pub(crate) patterns: DataPayload<CurrencyPatternsV1Marker>,
+    pub(crate) currencies: DataPayload<CurrencySymbolsV1Marker>,
+    pub(crate) fractions: DataPayload<CurrencyFractionsV1Marker>,
+    pub(crate) plural_rules: PluralRules,
+}
+
+impl CurrencyFormatter {
+    /// Creates a new [`CurrencyFormatter`] from locale data and an options bag.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, FixedDecimalFormatterError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CurrencyPatternsV1Marker>
+            + DataProvider<CurrencySymbolsV1Marker>
+            + DataProvider<CurrencyFractionsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let patterns = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let currencies = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let fractions = data_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        Ok(Self {
+            options,
+            symbols,
+            patterns,
+            currencies,
+            fractions,
+            plural_rules,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CurrencyFormatterOptions,
+        error: FixedDecimalFormatterError
+    );
+
+    /// Formats an amount of the currency with the given ISO 4217 code, such as `USD`, returning
+    /// a [`FormattedCurrency`].
+    ///
+    /// Currencies without locale data are displayed with their code.
+    pub fn format<'l>(
+        &'l self,
+        value: &FixedDecimal,
+        currency: TinyAsciiStr<3>,
+    ) -> FormattedCurrency<'l> {
+        let currency = currency.to_ascii_uppercase();
+        let fraction = self.fractions.get().get(&currency);
+        let (digits, increment) = match self.options.usage {
+            CurrencyUsage::Standard => (fraction.digits, fraction.rounding),
+            CurrencyUsage::Cash => (fraction.cash_digits, fraction.cash_rounding),
+        };
+        let position = -(digits as i16);
+        let mut value = value.clone();
+        round_to_increment(&mut value, position, increment);
+        value.pad_end(position);
+        FormattedCurrency {
+            formatter: self,
+            value,
+            currency,
+        }
+    }
+}
+
+/// Rounds `value` half-even to a multiple of `increment` units of the digit at `position`, such
+/// as to multiples of 0.05 for an increment of 5 at position -2. Increments that don't divide
+/// 100 are treated as 1.
+fn round_to_increment(value: &mut FixedDecimal, position: i16, increment: u8) {
+    if increment <= 1 || 100 % increment != 0 || value.is_zero() {
+        value.half_even(position);
+        return;
+    }
+    let increment = increment as u16;
+
+    // Since the increment divides 100, the remainder only depends on the last two digits.
+    let last_digits = value.digit_at(position + 1) as u16 * 10 + value.digit_at(position) as u16;
+    let remainder = last_digits % increment;
+    let lower = last_digits - remainder;
+
+    // Compare twice the distance to the lower multiple with the increment. The digits after
+    // `position` add at most 1 to the doubled distance, and leave a fraction unless they are
+    // exactly 0 or 0.5.
+    let next_digit = value.digit_at(position - 1);
+    let doubled = 2 * remainder + u16::from(next_digit >= 5);
+    let has_fraction =
+        (next_digit != 0 && next_digit != 5) || value.nonzero_magnitude_right() < position - 1;
+    let round_up = match doubled.cmp(&increment) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal if has_fraction => true,
+        Ordering::Equal => {
+            // A tie: round to the even multiple of the increment.
+            let hundreds_parity = u16::from(value.digit_at(position + 2) % 2 == 1);
+            (hundreds_parity * (100 / increment) + lower / increment) % 2 == 1
+        }
+    };
+
+    let target = if round_up { lower + increment } else { lower };
+    if target == 100 {
+        value.expand(position + 2);
+    } else {
+        value.trunc(position + 2);
+        // The truncated value has no nonzero digits below `position + 2`, so the two can't
+        // overlap.
+        let _ = value.concatenate_right(FixedDecimal::from(target).multiplied_pow10(position));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_increment() {
+        for (input, position, increment, expected) in [
+            ("1.234", -2, 0, "1.23"),
+            ("1.235", -2, 1, "1.24"),
+            ("1.225", -2, 1, "1.22"),
+            ("1.02", -2, 5, "1.00"),
+            ("1.025", -2, 5, "1.00"),
+            ("1.0251", -2, 5, "1.05"),
+            ("1.03", -2, 5, "1.05"),
+            ("1.075", -2, 5, "1.10"),
+            ("1.97", -2, 5, "1.95"),
+            ("1.98", -2, 5, "2.00"),
+            ("9.99", -2, 5, "10.00"),
+            ("-1.98", -2, 5, "-2.00"),
+            ("0.02", -2, 5, "0.00"),
+            ("0.03", -2, 5, "0.05"),
+            ("-0.03", -2, 5, "-0.05"),
+            ("1.25", -2, 50, "1.00"),
+            ("1.75", -2, 50, "2.00"),
+            ("1.76", -2, 50, "2.00"),
+            ("12", 0, 5, "10"),
+            ("13", 0, 5, "15"),
+            ("127.5", 0, 5, "130"),
+            ("1.30", -2, 20, "1.20"),
+            ("1.10", -2, 20, "1.20"),
+            ("1.1", -2, 3, "1.10"),
+        ] {
+            let mut value: FixedDecimal = input.parse().unwrap();
+            round_to_increment(&mut value, position, increment);
+            value.pad_end(position);
+            assert_eq!(
+                value.to_string(),
+                expected,
+                "{} to {} at {}",
+                input,
+                increment,
+                position
+            );
+        }
+    }
+}
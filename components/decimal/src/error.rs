@@ -13,6 +13,9 @@ pub enum Error {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(icu_plurals::PluralRulesError),
 }
 
 #[cfg(feature = "std")]
@@ -23,3 +26,9 @@ impl From<icu_provider::DataError> for Error {
         Error::Data(e)
     }
 }
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
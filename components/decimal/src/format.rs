@@ -7,8 +7,11 @@
 use crate::grouper;
 use crate::options::*;
 use crate::provider::*;
+use crate::{CurrencyFormatter, FixedDecimalFormatter, PercentFormatter};
 use fixed_decimal::FixedDecimal;
+use fixed_decimal::ScientificDecimal;
 use fixed_decimal::Sign;
+use tinystr::TinyAsciiStr;
 use writeable::Writeable;
 
 /// An intermediate structure returned by [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
@@ -39,28 +42,329 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
         if let Some(affixes) = affixes {
             sink.write_str(&affixes.prefix)?;
         }
-        let range = self.value.magnitude_range();
-        let upper_magnitude = *range.end();
-        for m in range.rev() {
-            if m == -1 {
-                sink.write_str(&self.symbols.decimal_separator)?;
+        write_digits(
+            self.value,
+            self.options.grouping_strategy,
+            self.symbols,
+            sink,
+        )?;
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+/// An intermediate structure returned by
+/// [`CompactDecimalFormatter`](crate::CompactDecimalFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+pub struct FormattedCompactDecimal<'l> {
+    pub(crate) formatter: &'l FixedDecimalFormatter,
+    /// The rounded significand, such as 1.2 for "1.2K".
+    pub(crate) significand: FixedDecimal,
+    /// The pattern with the significand at `{0}`, or `None` if the number is not compacted.
+    pub(crate) pattern: Option<&'l str>,
+}
+
+impl<'l> Writeable for FormattedCompactDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let significand = self.formatter.format(&self.significand);
+        match self.pattern.and_then(|pattern| pattern.split_once("{0}")) {
+            Some((prefix, suffix)) => {
+                sink.write_str(prefix)?;
+                significand.write_to(sink)?;
+                sink.write_str(suffix)
             }
-            let d = self.value.digit_at(m);
-            #[allow(clippy::indexing_slicing)]
-            // TODO(#1668) Clippy exceptions need docs or fixing.
-            sink.write_char(self.symbols.digits[d as usize])?;
-            if grouper::check(
-                upper_magnitude,
-                m,
-                self.options.grouping_strategy,
-                &self.symbols.grouping_sizes,
-            ) {
-                sink.write_str(&self.symbols.grouping_separator)?;
+            None => significand.write_to(sink),
+        }
+    }
+}
+
+/// An intermediate structure returned by [`CurrencyFormatter`](crate::CurrencyFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted amount to a string or buffer.
+pub struct FormattedCurrency<'l> {
+    pub(crate) formatter: &'l CurrencyFormatter,
+    /// The amount, rounded for the currency.
+    pub(crate) value: FixedDecimal,
+    pub(crate) currency: TinyAsciiStr<3>,
+}
+
+impl<'l> FormattedCurrency<'l> {
+    /// Writes the amount with the decimal sign affixes, as used with long names.
+    fn write_number<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        write_signed(
+            &self.value,
+            self.formatter.options.grouping_strategy,
+            self.formatter.symbols.get(),
+            sink,
+        )
+    }
+
+    /// Writes the amount with the long name of the currency, such as "1.00 US dollars".
+    fn write_long_name<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let category = self.formatter.plural_rules.category_for(&self.value);
+        let name = self
+            .formatter
+            .currencies
+            .get()
+            .long_names
+            .get(&self.currency, category)
+            .unwrap_or_else(|| self.currency.as_str());
+        let mut pattern = self.formatter.patterns.get().unit_patterns.get(category);
+        while let Some(index) = pattern.find('{') {
+            let (literal, placeholder) = pattern.split_at(index);
+            sink.write_str(literal)?;
+            pattern = if let Some(rest) = placeholder.strip_prefix("{0}") {
+                self.write_number(sink)?;
+                rest
+            } else if let Some(rest) = placeholder.strip_prefix("{1}") {
+                sink.write_str(name)?;
+                rest
+            } else {
+                sink.write_char('{')?;
+                placeholder.get(1..).unwrap_or_default()
+            };
+        }
+        sink.write_str(pattern)
+    }
+}
+
+/// Whether the currency spacing is needed next to a symbol character, which is the case for
+/// letters and digits, such as in "USD 1.00", but not for symbols, such as in "$1.00".
+fn needs_currency_spacing(symbol_char: Option<char>) -> bool {
+    symbol_char.map_or(false, char::is_alphanumeric)
+}
+
+impl<'l> Writeable for FormattedCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let options = &self.formatter.options;
+        if options.display == CurrencyDisplay::Name {
+            return self.write_long_name(sink);
+        }
+
+        let patterns = self.formatter.patterns.get();
+        let signed_affixes = match options.sign {
+            CurrencySign::Standard => &patterns.standard,
+            CurrencySign::Accounting => &patterns.accounting,
+        };
+        let affixes = if self.value.sign() == Sign::Negative {
+            &signed_affixes.negative
+        } else {
+            &signed_affixes.positive
+        };
+        let currencies = self.formatter.currencies.get();
+        let symbol = match options.display {
+            CurrencyDisplay::Symbol => currencies.symbols.get(&self.currency),
+            CurrencyDisplay::NarrowSymbol => currencies
+                .narrow_symbols
+                .get(&self.currency)
+                .or_else(|| currencies.symbols.get(&self.currency)),
+            CurrencyDisplay::Code | CurrencyDisplay::Name => None,
+        }
+        .unwrap_or_else(|| self.currency.as_str());
+
+        match affixes.prefix.split_once('¤') {
+            Some((before, after)) => {
+                sink.write_str(before)?;
+                sink.write_str(symbol)?;
+                if after.is_empty() && needs_currency_spacing(symbol.chars().next_back()) {
+                    sink.write_str(&patterns.spacing)?;
+                }
+                sink.write_str(after)?;
             }
+            None => sink.write_str(&affixes.prefix)?,
         }
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.suffix)?;
+        write_digits(
+            &self.value,
+            options.grouping_strategy,
+            self.formatter.symbols.get(),
+            sink,
+        )?;
+        match affixes.suffix.split_once('¤') {
+            Some((before, after)) => {
+                sink.write_str(before)?;
+                if before.is_empty() && needs_currency_spacing(symbol.chars().next()) {
+                    sink.write_str(&patterns.spacing)?;
+                }
+                sink.write_str(symbol)?;
+                sink.write_str(after)?;
+            }
+            None => sink.write_str(&affixes.suffix)?,
         }
         Ok(())
     }
 }
+
+/// An intermediate structure returned by [`PercentFormatter`](crate::PercentFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted percentage to a string or buffer.
+pub struct FormattedPercent<'l> {
+    pub(crate) formatter: &'l PercentFormatter,
+    /// The value multiplied by 100 or 1000.
+    pub(crate) value: FixedDecimal,
+}
+
+impl<'l> Writeable for FormattedPercent<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let patterns = self.formatter.patterns.get();
+        let affixes = if self.value.sign() == Sign::Negative {
+            &patterns.affixes.negative
+        } else {
+            &patterns.affixes.positive
+        };
+        let sign = match self.formatter.options.style {
+            PercentStyle::Percent => &patterns.percent_sign,
+            PercentStyle::Permille => &patterns.permille_sign,
+        };
+        write_percent_affix(&affixes.prefix, sign, sink)?;
+        write_digits(
+            &self.value,
+            self.formatter.options.grouping_strategy,
+            self.formatter.symbols.get(),
+            sink,
+        )?;
+        write_percent_affix(&affixes.suffix, sign, sink)
+    }
+}
+
+/// Writes an affix of a percent pattern, replacing the `%` placeholder with `sign`.
+fn write_percent_affix<W>(affix: &str, sign: &str, sink: &mut W) -> core::fmt::Result
+where
+    W: core::fmt::Write + ?Sized,
+{
+    match affix.split_once('%') {
+        Some((before, after)) => {
+            sink.write_str(before)?;
+            sink.write_str(sign)?;
+            sink.write_str(after)
+        }
+        None => sink.write_str(affix),
+    }
+}
+
+/// An intermediate structure returned by
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+pub struct FormattedScientificDecimal<'l> {
+    pub(crate) value: &'l ScientificDecimal,
+    pub(crate) options: &'l ScientificDecimalFormatterOptions,
+    pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+    pub(crate) scientific_symbols: &'l ScientificSymbolsV1<'l>,
+}
+
+/// The superscript digits, used for exponents in numbering systems with ASCII digits.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl<'l> Writeable for FormattedScientificDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        write_signed(
+            self.value.significand(),
+            self.options.grouping_strategy,
+            self.symbols,
+            sink,
+        )?;
+        let exponent = FixedDecimal::from(self.value.exponent());
+        match self.options.exponent_style {
+            ExponentStyle::Exponential => {
+                sink.write_str(&self.scientific_symbols.exponential)?;
+                write_signed(&exponent, GroupingStrategy::Never, self.symbols, sink)
+            }
+            ExponentStyle::Superscript => {
+                sink.write_str(&self.scientific_symbols.superscripting_exponent)?;
+                write_digits(
+                    &FixedDecimal::from(10),
+                    GroupingStrategy::Never,
+                    self.symbols,
+                    sink,
+                )?;
+                if self.symbols.digits[0] != '0' {
+                    return write_signed(&exponent, GroupingStrategy::Never, self.symbols, sink);
+                }
+                if exponent.sign() == Sign::Negative {
+                    sink.write_char('⁻')?;
+                }
+                for m in exponent.magnitude_range().rev() {
+                    let d = exponent.digit_at(m);
+                    #[allow(clippy::indexing_slicing)] // digits are less than 10
+                    sink.write_char(SUPERSCRIPT_DIGITS[d as usize])?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes `value` in the numbering system of `symbols`, with the sign affixes of `symbols`.
+pub(crate) fn write_signed<W>(
+    value: &FixedDecimal,
+    grouping_strategy: GroupingStrategy,
+    symbols: &DecimalSymbolsV1,
+    sink: &mut W,
+) -> core::fmt::Result
+where
+    W: core::fmt::Write + ?Sized,
+{
+    let affixes = match value.sign() {
+        Sign::Negative => Some(&symbols.minus_sign_affixes),
+        Sign::None => None,
+        Sign::Positive => Some(&symbols.plus_sign_affixes),
+    };
+    if let Some(affixes) = affixes {
+        sink.write_str(&affixes.prefix)?;
+    }
+    write_digits(value, grouping_strategy, symbols, sink)?;
+    if let Some(affixes) = affixes {
+        sink.write_str(&affixes.suffix)?;
+    }
+    Ok(())
+}
+
+/// Writes the digits of `value` in the numbering system of `symbols`, with decimal and grouping
+/// separators but without any sign.
+pub(crate) fn write_digits<W>(
+    value: &FixedDecimal,
+    grouping_strategy: GroupingStrategy,
+    symbols: &DecimalSymbolsV1,
+    sink: &mut W,
+) -> core::fmt::Result
+where
+    W: core::fmt::Write + ?Sized,
+{
+    let range = value.magnitude_range();
+    let upper_magnitude = *range.end();
+    for m in range.rev() {
+        if m == -1 {
+            sink.write_str(&symbols.decimal_separator)?;
+        }
+        let d = value.digit_at(m);
+        #[allow(clippy::indexing_slicing)]
+        // TODO(#1668) Clippy exceptions need docs or fixing.
+        sink.write_char(symbols.digits[d as usize])?;
+        if grouper::check(
+            upper_magnitude,
+            m,
+            grouping_strategy,
+            &symbols.grouping_sizes,
+        ) {
+            sink.write_str(&symbols.grouping_separator)?;
+        }
+    }
+    Ok(())
+}
@@ -9,8 +9,11 @@
 //! This module is published as its own crate ([`icu_decimal`](https://docs.rs/icu_decimal/latest/icu_decimal/))
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //!
-//! Support for currencies, measurement units, and compact notation is planned. To track progress,
-//! follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
+//! Amounts of money are formatted with [`CurrencyFormatter`], percentages with
+//! [`PercentFormatter`], numbers in compact notation, such as "1.2K", with
+//! [`CompactDecimalFormatter`], and numbers in scientific notation, such as "1.23E4", with
+//! [`ScientificDecimalFormatter`]. Support for measurement units is planned.
+//! To track progress, follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
 //!
 //! # Examples
 //!
@@ -92,14 +95,25 @@
 
 extern crate alloc;
 
+mod compact;
+mod currency;
 mod error;
 pub mod format;
 mod grouper;
 pub mod options;
+mod percent;
 pub mod provider;
+mod scientific;
 
+pub use compact::CompactDecimalFormatter;
+pub use currency::CurrencyFormatter;
 pub use error::Error as FixedDecimalFormatterError;
-pub use format::FormattedFixedDecimal;
+pub use format::{
+    FormattedCompactDecimal, FormattedCurrency, FormattedFixedDecimal, FormattedPercent,
+    FormattedScientificDecimal,
+};
+pub use percent::PercentFormatter;
+pub use scientific::ScientificDecimalFormatter;
 
 use fixed_decimal::FixedDecimal;
 use icu_provider::prelude::*;
@@ -2,7 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+//! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter),
+//! [`CompactDecimalFormatter`](crate::CompactDecimalFormatter),
+//! [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter),
+//! [`PercentFormatter`](crate::PercentFormatter) and
+//! [`CurrencyFormatter`](crate::CurrencyFormatter).
 
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
@@ -63,3 +67,173 @@ impl Default for GroupingStrategy {
         Self::Auto
     }
 }
+
+/// A bag of options defining how amounts of money will be formatted by
+/// [`CurrencyFormatter`](crate::CurrencyFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct CurrencyFormatterOptions {
+    /// How to display the currency.
+    pub display: CurrencyDisplay,
+
+    /// How to display negative amounts.
+    pub sign: CurrencySign,
+
+    /// Which fraction digits and rounding increment to use.
+    pub usage: CurrencyUsage,
+
+    /// When to render grouping separators.
+    pub grouping_strategy: GroupingStrategy,
+}
+
+/// Configuration for how to display the currency of a formatted amount.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyDisplay {
+    /// The standard symbol, such as "$1.00" or "CA$1.00".
+    Symbol,
+
+    /// The narrow symbol, such as "$1.00" for both US and Canadian dollars.
+    NarrowSymbol,
+
+    /// The ISO 4217 code, such as "USD 1.00".
+    Code,
+
+    /// The long name, inflected by the plural form of the amount, such as "1.00 US dollars".
+    Name,
+}
+
+impl Default for CurrencyDisplay {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// Configuration for how to display negative amounts of money.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencySign {
+    /// Use the locale's minus sign, such as "-$12.00".
+    Standard,
+
+    /// Use the locale's accounting format, such as "($12.00)" in English.
+    ///
+    /// Locales without an accounting format use the standard format.
+    Accounting,
+}
+
+impl Default for CurrencySign {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Configuration for how to round amounts of money.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyUsage {
+    /// Round to the currency's minor unit, such as 2 fraction digits for US dollars and 0 for
+    /// Japanese yen.
+    Standard,
+
+    /// Round to the smallest amount that can be paid in cash, such as multiples of 0.05 for
+    /// Swiss francs.
+    Cash,
+}
+
+impl Default for CurrencyUsage {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`CompactDecimalFormatter`](crate::CompactDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct CompactDecimalFormatterOptions {
+    /// How to display the power of ten.
+    pub display: CompactDisplay,
+
+    /// When to render grouping separators in the significand.
+    pub grouping_strategy: GroupingStrategy,
+}
+
+/// Configuration for how to display the power of ten of a number in compact notation.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CompactDisplay {
+    /// An abbreviation, such as "1.2K".
+    Short,
+
+    /// A word, inflected by the plural form of the number, such as "1.2 thousand".
+    Long,
+}
+
+impl Default for CompactDisplay {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatterOptions {
+    /// How to display the exponent.
+    pub exponent_style: ExponentStyle,
+
+    /// When to render grouping separators in the significand.
+    pub grouping_strategy: GroupingStrategy,
+}
+
+/// Configuration for how to display the exponent of a number in scientific notation.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ExponentStyle {
+    /// The exponent after the locale's exponential symbol, such as "1.23E4".
+    Exponential,
+
+    /// A power of ten with a superscript exponent, such as "1.23×10⁴".
+    ///
+    /// Numbering systems other than `latn` have no superscript digits, and use their own digits
+    /// after the power of ten.
+    Superscript,
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::Exponential
+    }
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`PercentFormatter`](crate::PercentFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct PercentFormatterOptions {
+    /// Whether to format the number as a percentage or as a permille value.
+    pub style: PercentStyle,
+
+    /// When to render grouping separators.
+    pub grouping_strategy: GroupingStrategy,
+}
+
+/// Configuration for the unit of a number formatted by
+/// [`PercentFormatter`](crate::PercentFormatter).
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum PercentStyle {
+    /// Multiply the number by 100 and display the percent sign, such as "45%" for 0.45.
+    Percent,
+
+    /// Multiply the number by 1000 and display the permille sign, such as "450‰" for 0.45.
+    Permille,
+}
+
+impl Default for PercentStyle {
+    fn default() -> Self {
+        Self::Percent
+    }
+}
@@ -0,0 +1,94 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedPercent;
+use crate::options::{PercentFormatterOptions, PercentStyle};
+use crate::provider::*;
+use crate::FixedDecimalFormatterError;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+
+/// A formatter for percentages and permille values, rendering a [`FixedDecimal`] multiplied by
+/// 100 or 1000 with the locale's pattern, such as "45%" in English or "45 %" in French for 0.45.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::PercentFormatter;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let pf = PercentFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("fr").into(),
+///     Default::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// let value = "0.456".parse().unwrap();
+/// assert_writeable_eq!(pf.format(&value), "45,6\u{a0}%");
+/// ```
+pub struct PercentFormatter {
+    pub(crate) options: PercentFormatterOptions,
+    pub(crate) symbols: DataPayload<DecimalSymbolsV1Marker>,
+    pub(crate) patterns: DataPayload<PercentPatternsV1Marker>,
+}
+
+impl PercentFormatter {
+    /// Creates a new [`PercentFormatter`] from locale data and an options bag.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: PercentFormatterOptions,
+    ) -> Result<Self, FixedDecimalFormatterError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<PercentPatternsV1Marker> + ?Sized,
+    {
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let patterns = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            options,
+            symbols,
+            patterns,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: PercentFormatterOptions,
+        error: FixedDecimalFormatterError
+    );
+
+    /// Formats a ratio, such as 0.45, as a percentage or permille value, returning a
+    /// [`FormattedPercent`].
+    pub fn format<'l>(&'l self, value: &FixedDecimal) -> FormattedPercent<'l> {
+        let delta = match self.options.style {
+            PercentStyle::Percent => 2,
+            PercentStyle::Permille => 3,
+        };
+        // Shifting keeps the integer digits of the ratio, such as the zero of 0.45, so those
+        // are trimmed; the number of integer digits the caller padded the value to is kept.
+        let integer_digits = value.magnitude_range().end() + 1;
+        let mut value = value.clone().multiplied_pow10(delta);
+        value.trim_start();
+        value.pad_start(integer_digits);
+        FormattedPercent {
+            formatter: self,
+            value,
+        }
+    }
+}
@@ -10,7 +10,10 @@
 #![allow(clippy::exhaustive_structs)]
 
 use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
 use icu_provider::{yoke, zerofrom};
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
 
 /// A collection of strings to affix to a decimal number.
 #[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
@@ -115,3 +118,371 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// The affixes of a number pattern for positive and negative numbers.
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct SignedAffixesV1<'data> {
+    /// Affixes for zero and positive numbers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub positive: AffixesV1<'data>,
+
+    /// Affixes for negative numbers, including the localized minus sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative: AffixesV1<'data>,
+}
+
+/// A pattern for each plural category, such as `{0} {1}`. Categories without a pattern use the
+/// `other` pattern.
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PluralPatternsV1<'data> {
+    /// The pattern for [`PluralCategory::Zero`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::One`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Two`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Few`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Many`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: Cow<'data, str>,
+}
+
+impl<'data> PluralPatternsV1<'data> {
+    pub(crate) fn get(&self, category: PluralCategory) -> &str {
+        match category {
+            PluralCategory::Zero => self.zero.as_deref(),
+            PluralCategory::One => self.one.as_deref(),
+            PluralCategory::Two => self.two.as_deref(),
+            PluralCategory::Few => self.few.as_deref(),
+            PluralCategory::Many => self.many.as_deref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
+
+/// Patterns for formatting amounts of money in a numbering system.
+#[icu_provider::data_struct(marker(
+    CurrencyPatternsV1Marker,
+    "currency/patterns@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CurrencyPatternsV1<'data> {
+    /// Affixes of the standard pattern, such as `¤#,##0.00`. The currency symbol is
+    /// represented by `¤`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard: SignedAffixesV1<'data>,
+
+    /// Affixes of the accounting pattern, such as `¤#,##0.00;(¤#,##0.00)`. The currency symbol
+    /// is represented by `¤`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub accounting: SignedAffixesV1<'data>,
+
+    /// String to insert between the number and an adjacent currency symbol that starts or ends
+    /// with a letter or digit, such as the space in "USD 1.00".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub spacing: Cow<'data, str>,
+
+    /// Patterns for combining a formatted number `{0}` with the long name of a currency `{1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unit_patterns: PluralPatternsV1<'data>,
+}
+
+/// Symbols and display names of currencies, keyed by ISO 4217 code.
+#[icu_provider::data_struct(CurrencySymbolsV1Marker = "currency/symbols@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencySymbolsV1<'data> {
+    /// The standard symbols, such as "$" for `USD`. Currencies without a symbol are displayed
+    /// with their ISO code.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+
+    /// The narrow symbols, such as "$" for `CAD`, if they differ from the standard symbols.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow_symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+
+    /// The long names, such as "US dollars".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long_names: CurrencyLongNamesV1<'data>,
+}
+
+/// The long names of currencies for each plural category. Categories other than `other` only
+/// contain the names that differ from the `other` name.
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyLongNamesV1<'data> {
+    /// The names for [`PluralCategory::Zero`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The names for [`PluralCategory::One`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The names for [`PluralCategory::Two`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The names for [`PluralCategory::Few`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The names for [`PluralCategory::Many`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The names for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap<'data, TinyAsciiStr<3>, str>,
+}
+
+impl<'data> CurrencyLongNamesV1<'data> {
+    pub(crate) fn get(&self, currency: &TinyAsciiStr<3>, category: PluralCategory) -> Option<&str> {
+        match category {
+            PluralCategory::Zero => self.zero.get(currency),
+            PluralCategory::One => self.one.get(currency),
+            PluralCategory::Two => self.two.get(currency),
+            PluralCategory::Few => self.few.get(currency),
+            PluralCategory::Many => self.many.get(currency),
+            PluralCategory::Other => None,
+        }
+        .or_else(|| self.other.get(currency))
+    }
+}
+
+/// The number of fraction digits and the rounding increments of currencies, keyed by ISO 4217
+/// code.
+#[icu_provider::data_struct(CurrencyFractionsV1Marker = "currency/fractions@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyFractionsV1<'data> {
+    /// The fraction settings of currencies that are not in `fractions`.
+    pub default: CurrencyFractionV1,
+
+    /// The fraction settings of currencies that differ from the default.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fractions: ZeroMap<'data, TinyAsciiStr<3>, CurrencyFractionV1>,
+}
+
+impl<'data> CurrencyFractionsV1<'data> {
+    pub(crate) fn get(&self, currency: &TinyAsciiStr<3>) -> CurrencyFractionV1 {
+        self.fractions.get_copied(currency).unwrap_or(self.default)
+    }
+}
+
+/// The number of fraction digits and the rounding increments of a currency.
+///
+/// A rounding increment is expressed in units of the last fraction digit; for example, an
+/// increment of 5 with 2 digits rounds to multiples of 0.05. An increment of 0 means no
+/// rounding beyond the fraction digits.
+#[zerovec::make_ule(CurrencyFractionULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CurrencyFractionV1 {
+    /// The number of fraction digits for standard usage.
+    pub digits: u8,
+    /// The rounding increment for standard usage.
+    pub rounding: u8,
+    /// The number of fraction digits for cash transactions.
+    pub cash_digits: u8,
+    /// The rounding increment for cash transactions.
+    pub cash_rounding: u8,
+}
+
+impl Default for CurrencyFractionV1 {
+    fn default() -> Self {
+        Self {
+            digits: 2,
+            rounding: 0,
+            cash_digits: 2,
+            cash_rounding: 0,
+        }
+    }
+}
+
+/// Patterns for formatting numbers in compact notation, such as "1.2K" or "3.4 million", in a
+/// numbering system.
+///
+/// The patterns are keyed by the magnitude of the number, such as 3 for numbers from 1000 to
+/// 9999, and combine the significand `{0}` with the abbreviation of the power of ten. Numbers
+/// with a magnitude above the largest key use the pattern of that key.
+#[icu_provider::data_struct(
+    marker(
+        ShortCompactDecimalFormatDataV1Marker,
+        "compactdecimal/short@1",
+        extension_key = "nu"
+    ),
+    marker(
+        LongCompactDecimalFormatDataV1Marker,
+        "compactdecimal/long@1",
+        extension_key = "nu"
+    )
+)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CompactDecimalPatternDataV1<'data> {
+    /// The power of ten by which numbers of each magnitude are divided, such as 3 for both 1000
+    /// ("1K") and 10000 ("10K") in English. An exponent of 0 means that numbers of the magnitude
+    /// are not compacted, and have no patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponents: ZeroMap<'data, i16, i16>,
+
+    /// The patterns for [`PluralCategory::Zero`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap<'data, i16, str>,
+    /// The patterns for [`PluralCategory::One`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap<'data, i16, str>,
+    /// The patterns for [`PluralCategory::Two`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap<'data, i16, str>,
+    /// The patterns for [`PluralCategory::Few`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap<'data, i16, str>,
+    /// The patterns for [`PluralCategory::Many`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap<'data, i16, str>,
+    /// The patterns for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap<'data, i16, str>,
+}
+
+impl<'data> CompactDecimalPatternDataV1<'data> {
+    /// Returns the key of the patterns for numbers of the given magnitude, and the exponent of
+    /// their significand.
+    pub(crate) fn exponent_for(&self, magnitude: i16) -> (i16, i16) {
+        let mut result = (0, 0);
+        for (key, exponent) in self.exponents.iter_copied() {
+            if key > magnitude {
+                break;
+            }
+            result = (key, exponent);
+        }
+        result
+    }
+
+    pub(crate) fn get(&self, key: i16, category: PluralCategory) -> Option<&str> {
+        match category {
+            PluralCategory::Zero => self.zero.get(&key),
+            PluralCategory::One => self.one.get(&key),
+            PluralCategory::Two => self.two.get(&key),
+            PluralCategory::Few => self.few.get(&key),
+            PluralCategory::Many => self.many.get(&key),
+            PluralCategory::Other => None,
+        }
+        .or_else(|| self.other.get(&key))
+    }
+}
+
+/// Symbols for formatting numbers in scientific notation in a numbering system.
+#[icu_provider::data_struct(marker(
+    ScientificSymbolsV1Marker,
+    "decimal/scientific@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ScientificSymbolsV1<'data> {
+    /// String to separate the significand from the exponent, such as "E" in "1.2E3".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: Cow<'data, str>,
+
+    /// String to multiply the significand with a power of ten with a superscript exponent,
+    /// such as "×" in "1.2×10³".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+}
+
+impl Default for ScientificSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            exponential: Cow::Borrowed("E"),
+            superscripting_exponent: Cow::Borrowed("×"),
+        }
+    }
+}
+
+/// Patterns and symbols for formatting percentages and permille values in a numbering system.
+#[icu_provider::data_struct(marker(
+    PercentPatternsV1Marker,
+    "decimal/percent@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PercentPatternsV1<'data> {
+    /// Affixes of the percent pattern, such as `#,##0 %`. The percent or permille sign is
+    /// represented by `%`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub affixes: SignedAffixesV1<'data>,
+
+    /// The percent sign, such as "%".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub percent_sign: Cow<'data, str>,
+
+    /// The permille sign, such as "‰".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub permille_sign: Cow<'data, str>,
+}
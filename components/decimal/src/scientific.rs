@@ -0,0 +1,93 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedScientificDecimal;
+use crate::options::ScientificDecimalFormatterOptions;
+use crate::provider::*;
+use crate::FixedDecimalFormatterError;
+use fixed_decimal::ScientificDecimal;
+use icu_provider::prelude::*;
+
+/// A formatter for numbers in scientific notation, rendering a [`ScientificDecimal`] as a
+/// significand and an exponent, such as "1.23E4" or "1.23×10⁴".
+///
+/// The significand is formatted as is, so it should be normalized and rounded beforehand, such
+/// as with [`ScientificDecimal::from`] and [`ScientificDecimal::significand_mut`].
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::{FixedDecimal, ScientificDecimal};
+/// use icu::decimal::options::{ExponentStyle, ScientificDecimalFormatterOptions};
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let provider = icu_testdata::get_provider();
+/// let mut options = ScientificDecimalFormatterOptions::default();
+/// options.exponent_style = ExponentStyle::Superscript;
+/// let sdf = ScientificDecimalFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let mut value = ScientificDecimal::from(FixedDecimal::from(12345));
+/// value.significand_mut().half_even(-2);
+/// assert_writeable_eq!(sdf.format(&value), "1.23×10⁴");
+/// ```
+pub struct ScientificDecimalFormatter {
+    pub(crate) options: ScientificDecimalFormatterOptions,
+    pub(crate) symbols: DataPayload<DecimalSymbolsV1Marker>,
+    pub(crate) scientific_symbols: DataPayload<ScientificSymbolsV1Marker>,
+}
+
+impl ScientificDecimalFormatter {
+    /// Creates a new [`ScientificDecimalFormatter`] from locale data and an options bag.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, FixedDecimalFormatterError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<ScientificSymbolsV1Marker> + ?Sized,
+    {
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let scientific_symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            options,
+            symbols,
+            scientific_symbols,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: ScientificDecimalFormatterOptions,
+        error: FixedDecimalFormatterError
+    );
+
+    /// Formats a [`ScientificDecimal`], returning a [`FormattedScientificDecimal`].
+    pub fn format<'l>(&'l self, value: &'l ScientificDecimal) -> FormattedScientificDecimal<'l> {
+        FormattedScientificDecimal {
+            value,
+            options: &self.options,
+            symbols: self.symbols.get(),
+            scientific_symbols: self.scientific_symbols.get(),
+        }
+    }
+}
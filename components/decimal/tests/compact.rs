@@ -0,0 +1,92 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::options::*;
+use icu_decimal::CompactDecimalFormatter;
+use icu_locid::{locale, Locale};
+use writeable::assert_writeable_eq;
+
+fn formatter(locale: &Locale, display: CompactDisplay) -> CompactDecimalFormatter {
+    let mut options = CompactDecimalFormatterOptions::default();
+    options.display = display;
+    CompactDecimalFormatter::try_new_unstable(
+        &icu_testdata::get_baked_provider(),
+        &locale.into(),
+        options,
+    )
+    .unwrap()
+}
+
+fn assert_formats(formatter: &CompactDecimalFormatter, cases: &[(&str, &str)]) {
+    for (value, expected) in cases {
+        assert_writeable_eq!(
+            formatter.format(&value.parse().unwrap()),
+            *expected,
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_short() {
+    assert_formats(
+        &formatter(&locale!("en"), CompactDisplay::Short),
+        &[
+            ("0", "0"),
+            ("1.234", "1.2"),
+            ("999", "999"),
+            ("1000", "1K"),
+            ("1234", "1.2K"),
+            ("-1234", "-1.2K"),
+            ("1250", "1.2K"),
+            ("12345", "12K"),
+            ("123456", "123K"),
+            ("999499", "999K"),
+            ("999999", "1M"),
+            ("3456789", "3.5M"),
+            ("1000000000", "1B"),
+            ("1234567890123", "1.2T"),
+            // Magnitudes beyond the largest pattern use it with a larger significand.
+            ("1234567890123456", "1,235T"),
+        ],
+    );
+}
+
+#[test]
+fn test_long() {
+    assert_formats(
+        &formatter(&locale!("en"), CompactDisplay::Long),
+        &[
+            ("1000", "1 thousand"),
+            ("3456789", "3.5 million"),
+            ("1000000000", "1 billion"),
+        ],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), CompactDisplay::Long),
+        &[("2000", "2 mille"), ("2500000", "2,5 millions")],
+    );
+    assert_formats(
+        &formatter(&locale!("es"), CompactDisplay::Long),
+        &[("1200000", "1,2 millones")],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), CompactDisplay::Short),
+        &[("1200000", "1,2\u{a0}M")],
+    );
+}
+
+#[test]
+fn test_uncompacted_magnitudes() {
+    assert_formats(
+        &formatter(&locale!("ja"), CompactDisplay::Short),
+        &[
+            ("1234", "1,234"),
+            ("9999", "9,999"),
+            ("12345", "1.2万"),
+            ("123456789", "1.2億"),
+        ],
+    );
+}
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::options::*;
+use icu_decimal::CurrencyFormatter;
+use icu_locid::{locale, Locale};
+use writeable::assert_writeable_eq;
+
+fn formatter(locale: &Locale, options: CurrencyFormatterOptions) -> CurrencyFormatter {
+    CurrencyFormatter::try_new_unstable(
+        &icu_testdata::get_baked_provider(),
+        &locale.into(),
+        options,
+    )
+    .unwrap()
+}
+
+fn assert_formats(formatter: &CurrencyFormatter, cases: &[(&str, &str, &str)]) {
+    for (value, currency, expected) in cases {
+        assert_writeable_eq!(
+            formatter.format(&value.parse().unwrap(), currency.parse().unwrap()),
+            *expected,
+            "{} {}",
+            value,
+            currency
+        );
+    }
+}
+
+#[test]
+fn test_standard() {
+    let options = CurrencyFormatterOptions::default();
+    assert_formats(
+        &formatter(&locale!("en"), options.clone()),
+        &[
+            ("1234.5", "USD", "$1,234.50"),
+            ("-12", "EUR", "-€12.00"),
+            ("0.125", "usd", "$0.12"),
+            // The yen has no minor unit.
+            ("1234.5", "JPY", "¥1,234"),
+            // Currencies without a symbol use their code, separated from the number.
+            ("1234.5", "XTS", "XTS\u{a0}1,234.50"),
+        ],
+    );
+    assert_formats(
+        &formatter(&locale!("ja"), options.clone()),
+        &[("1234.5", "JPY", "￥1,234")],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), options),
+        &[
+            ("1234.5", "EUR", "1\u{202f}234,50\u{a0}€"),
+            ("-1234.5", "USD", "-1\u{202f}234,50\u{a0}$US"),
+        ],
+    );
+}
+
+#[test]
+fn test_accounting() {
+    let mut options = CurrencyFormatterOptions::default();
+    options.sign = CurrencySign::Accounting;
+    assert_formats(
+        &formatter(&locale!("en"), options.clone()),
+        &[("-12", "EUR", "(€12.00)"), ("12", "EUR", "€12.00")],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), options),
+        &[("-12", "EUR", "(12,00\u{a0}€)")],
+    );
+}
+
+#[test]
+fn test_display() {
+    let mut narrow = CurrencyFormatterOptions::default();
+    narrow.display = CurrencyDisplay::NarrowSymbol;
+    assert_formats(
+        &formatter(&locale!("en"), narrow),
+        &[("5", "CAD", "$5.00"), ("5", "EUR", "€5.00")],
+    );
+
+    let mut code = CurrencyFormatterOptions::default();
+    code.display = CurrencyDisplay::Code;
+    assert_formats(
+        &formatter(&locale!("en"), code.clone()),
+        &[
+            ("5", "CAD", "CAD\u{a0}5.00"),
+            ("-5", "CAD", "-CAD\u{a0}5.00"),
+        ],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), code),
+        &[("5", "CAD", "5,00\u{a0}CAD")],
+    );
+
+    let mut name = CurrencyFormatterOptions::default();
+    name.display = CurrencyDisplay::Name;
+    assert_formats(
+        &formatter(&locale!("en"), name.clone()),
+        &[
+            ("1", "USD", "1.00 US dollars"),
+            ("1234.5", "USD", "1,234.50 US dollars"),
+            ("-2", "USD", "-2.00 US dollars"),
+            ("1", "JPY", "1 Japanese yen"),
+            ("1", "ZZZ", "1.00 ZZZ"),
+        ],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), name.clone()),
+        &[("1.5", "EUR", "1,50 euro"), ("2", "EUR", "2,00 euros")],
+    );
+    // Russian amounts with visible fraction digits take the "other" form.
+    assert_formats(
+        &formatter(&locale!("ru"), name),
+        &[("5", "RUB", "5,00 российского рубля")],
+    );
+}
+
+#[test]
+fn test_cash_rounding() {
+    let standard = CurrencyFormatterOptions::default();
+    let mut cash = CurrencyFormatterOptions::default();
+    cash.usage = CurrencyUsage::Cash;
+    assert_formats(
+        &formatter(&locale!("en"), standard),
+        &[("1.03", "CHF", "CHF\u{a0}1.03")],
+    );
+    assert_formats(
+        &formatter(&locale!("en"), cash),
+        &[
+            ("1.03", "CHF", "CHF\u{a0}1.05"),
+            ("1.02", "CHF", "CHF\u{a0}1.00"),
+            ("1.03", "USD", "$1.03"),
+        ],
+    );
+}
@@ -0,0 +1,73 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::options::*;
+use icu_decimal::PercentFormatter;
+use icu_locid::{locale, Locale};
+use writeable::assert_writeable_eq;
+
+fn formatter(locale: &Locale, style: PercentStyle) -> PercentFormatter {
+    let mut options = PercentFormatterOptions::default();
+    options.style = style;
+    PercentFormatter::try_new_unstable(&icu_testdata::get_baked_provider(), &locale.into(), options)
+        .unwrap()
+}
+
+fn assert_formats(formatter: &PercentFormatter, cases: &[(&str, &str)]) {
+    for (value, expected) in cases {
+        assert_writeable_eq!(
+            formatter.format(&value.parse().unwrap()),
+            *expected,
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_percent() {
+    assert_formats(
+        &formatter(&locale!("en"), PercentStyle::Percent),
+        &[
+            ("0", "0%"),
+            ("0.45", "45%"),
+            ("0.456", "45.6%"),
+            ("1", "100%"),
+            ("-0.05", "-5%"),
+            ("12.5", "1,250%"),
+            // Padding set by the caller is kept
+            ("00.05", "05%"),
+            ("000.456", "045.6%"),
+            ("0012", "1,200%"),
+        ],
+    );
+}
+
+#[test]
+fn test_permille() {
+    assert_formats(
+        &formatter(&locale!("en"), PercentStyle::Permille),
+        &[("0.45", "450‰"), ("-0.0025", "-2.5‰")],
+    );
+    assert_formats(
+        &formatter(&locale!("fr"), PercentStyle::Permille),
+        &[("0.012", "12\u{a0}‰")],
+    );
+}
+
+#[test]
+fn test_locale_patterns() {
+    assert_formats(
+        &formatter(&locale!("fr"), PercentStyle::Percent),
+        &[("0.456", "45,6\u{a0}%"), ("-0.5", "-50\u{a0}%")],
+    );
+    assert_formats(
+        &formatter(&locale!("tr"), PercentStyle::Percent),
+        &[("0.45", "%45"), ("-0.45", "-%45")],
+    );
+    assert_formats(
+        &formatter(&locale!("ar"), PercentStyle::Percent),
+        &[("0.45", "٤٥٪\u{61c}")],
+    );
+}
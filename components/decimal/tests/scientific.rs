@@ -0,0 +1,73 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, ScientificDecimal};
+use icu_decimal::options::*;
+use icu_decimal::ScientificDecimalFormatter;
+use icu_locid::{locale, Locale};
+use writeable::assert_writeable_eq;
+
+fn formatter(locale: &Locale, exponent_style: ExponentStyle) -> ScientificDecimalFormatter {
+    let mut options = ScientificDecimalFormatterOptions::default();
+    options.exponent_style = exponent_style;
+    ScientificDecimalFormatter::try_new_unstable(
+        &icu_testdata::get_baked_provider(),
+        &locale.into(),
+        options,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_exponential() {
+    let formatter_en = formatter(&locale!("en"), ExponentStyle::Exponential);
+    for (value, expected) in [
+        ("12345", "1.2345E4"),
+        ("-0.00123", "-1.23E-3"),
+        ("7", "7E0"),
+        ("0", "0E0"),
+    ] {
+        let value = ScientificDecimal::from(value.parse::<FixedDecimal>().unwrap());
+        assert_writeable_eq!(formatter_en.format(&value), expected, "{}", value);
+    }
+
+    let formatter_fr = formatter(&locale!("fr"), ExponentStyle::Exponential);
+    let value = "1.5e-10".parse().unwrap();
+    assert_writeable_eq!(formatter_fr.format(&value), "1,5E-10");
+}
+
+#[test]
+fn test_superscript() {
+    let formatter = formatter(&locale!("en"), ExponentStyle::Superscript);
+    for (value, expected) in [
+        ("1.23e4", "1.23×10⁴"),
+        ("-5e-12", "-5×10⁻¹²"),
+        ("9.9e0", "9.9×10⁰"),
+    ] {
+        let value: ScientificDecimal = value.parse().unwrap();
+        assert_writeable_eq!(formatter.format(&value), expected, "{}", value);
+    }
+}
+
+#[test]
+fn test_significand_grouping() {
+    let formatter = formatter(&locale!("en"), ExponentStyle::Exponential);
+    let value = ScientificDecimal::from_fixed_decimal_engineering(FixedDecimal::from(1_234_567));
+    assert_writeable_eq!(formatter.format(&value), "1.234567E6");
+    let value = ScientificDecimal::new(FixedDecimal::from(12345), 3);
+    assert_writeable_eq!(formatter.format(&value), "12,345E3");
+}
+
+#[test]
+fn test_numbering_system() {
+    let value = "1.23e-4".parse().unwrap();
+    let formatter_thai = formatter(&"th-u-nu-thai".parse().unwrap(), ExponentStyle::Exponential);
+    assert_writeable_eq!(formatter_thai.format(&value), "๑.๒๓E-๔");
+    // There are no superscript Thai digits.
+    let formatter_thai = formatter(&"th-u-nu-thai".parse().unwrap(), ExponentStyle::Superscript);
+    assert_writeable_eq!(formatter_thai.format(&value), "๑.๒๓×๑๐-๔");
+    // The arab numbering system has its own exponent symbol.
+    let formatter_ar = formatter(&locale!("ar"), ExponentStyle::Exponential);
+    assert_writeable_eq!(formatter_ar.format(&value), "١٫٢٣اس\u{61c}-٤");
+}
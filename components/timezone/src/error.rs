@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use displaydoc::Display;
+use icu_calendar::DateTimeError;
 use icu_provider::prelude::DataError;
 
 #[cfg(feature = "std")]
@@ -22,6 +23,19 @@ pub enum TimeZoneError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("{0}")]
     DataProvider(DataError),
+    /// The time zone is not known.
+    #[displaydoc("Unknown time zone")]
+    UnknownTimeZone,
+    /// The time zone data was invalid.
+    #[displaydoc("Invalid TZif data")]
+    InvalidTzif,
+    /// An error occurred while reading time zone data.
+    #[displaydoc("I/O error: {0:?}")]
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// An error originating from a date or time calculation.
+    #[displaydoc("{0}")]
+    DateTime(DateTimeError),
 }
 
 impl From<DataError> for TimeZoneError {
@@ -29,3 +43,19 @@ impl From<DataError> for TimeZoneError {
         TimeZoneError::DataProvider(e)
     }
 }
+
+impl From<DateTimeError> for TimeZoneError {
+    fn from(e: DateTimeError) -> Self {
+        TimeZoneError::DateTime(e)
+    }
+}
+
+#[cfg(feature = "tzif")]
+impl From<tzif::error::Error> for TimeZoneError {
+    fn from(e: tzif::error::Error) -> Self {
+        match e {
+            tzif::error::Error::Io(e) => TimeZoneError::Io(e.kind()),
+            _ => TimeZoneError::InvalidTzif,
+        }
+    }
+}
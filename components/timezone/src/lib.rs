@@ -25,8 +25,12 @@ mod metazone;
 pub mod provider;
 mod time_zone;
 mod types;
+#[cfg(feature = "tzif")]
+mod tzif_resolver;
 
 pub use error::TimeZoneError;
 pub use metazone::MetaZoneCalculator;
 pub use time_zone::CustomTimeZone;
 pub use types::{GmtOffset, TimeVariant};
+#[cfg(feature = "tzif")]
+pub use tzif_resolver::TzifTimeZoneResolver;
@@ -0,0 +1,184 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::TimeZoneBcp47Id;
+use crate::{CustomTimeZone, GmtOffset, TimeVariant, TimeZoneError};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::convert::TryFrom;
+use icu_calendar::{DateTime, Iso};
+use std::path::Path;
+use tzif::data::time::Seconds;
+use tzif::data::tzif::TzifData;
+
+/// [`TzifTimeZoneResolver`] resolves the GMT offset and time variant of a time zone at
+/// a given instant, using data from [`TZif`](tzif) files.
+///
+/// Transitions in the TZif data are used where available, and the POSIX time-zone
+/// string in the footer of version 2+ files is used for instants after the last transition.
+///
+/// The resolver does not know about metazones, so the returned [`CustomTimeZone`] has no
+/// metazone; compute it from the local datetime with a [`MetaZoneCalculator`] before
+/// formatting names such as "Pacific Daylight Time".
+///
+/// # Examples
+///
+/// ```
+/// use icu_timezone::provider::{MetaZoneId, TimeZoneBcp47Id};
+/// use icu_timezone::{MetaZoneCalculator, TimeVariant, TzifTimeZoneResolver};
+/// use icu_calendar::DateTime;
+/// use tinystr::tinystr;
+///
+/// let data = tzif::parse_tzif(include_bytes!(
+///     "../../../utils/tzif/testdata/America/Los_Angeles"
+/// ))
+/// .expect("Failed to parse TZif data");
+/// let mut resolver = TzifTimeZoneResolver::new();
+/// resolver.insert(
+///     "America/Los_Angeles",
+///     Some(TimeZoneBcp47Id(tinystr!(8, "uslax"))),
+///     data,
+/// );
+///
+/// let utc_datetime = DateTime::from_seconds_since_unix_epoch(1656633600).unwrap();
+/// let (local_datetime, mut time_zone) = resolver
+///     .resolve_iana("America/Los_Angeles", &utc_datetime)
+///     .expect("Time zone is known");
+///
+/// assert_eq!(
+///     local_datetime,
+///     DateTime::new_iso_datetime(2022, 6, 30, 17, 0, 0).unwrap()
+/// );
+/// assert_eq!(time_zone.gmt_offset.unwrap().raw_offset_seconds(), -25200);
+/// assert_eq!(time_zone.time_zone_id, Some(TimeZoneBcp47Id(tinystr!(8, "uslax"))));
+/// assert_eq!(time_zone.time_variant, Some(TimeVariant::daylight()));
+/// assert_eq!(time_zone.metazone_id, None);
+///
+/// let provider = icu_testdata::get_provider();
+/// let mzc = MetaZoneCalculator::try_new_unstable(&provider).expect("data exists");
+/// time_zone.maybe_set_metazone(&local_datetime, &mzc);
+/// assert_eq!(time_zone.metazone_id, Some(MetaZoneId(tinystr!(4, "ampa"))));
+/// ```
+///
+/// [`MetaZoneCalculator`]: crate::MetaZoneCalculator
+#[derive(Debug, Default)]
+pub struct TzifTimeZoneResolver {
+    zones: BTreeMap<String, (TzifData, Option<TimeZoneBcp47Id>)>,
+    bcp47_ids: BTreeMap<TimeZoneBcp47Id, String>,
+}
+
+impl TzifTimeZoneResolver {
+    /// Creates a new [`TzifTimeZoneResolver`] without any time zones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the parsed TZif `data` for the time zone with the IANA name `iana_name`, and
+    /// optionally the BCP-47 identifier `bcp47_id`.
+    ///
+    /// This replaces any data previously added for the same time zone.
+    pub fn insert(&mut self, iana_name: &str, bcp47_id: Option<TimeZoneBcp47Id>, data: TzifData) {
+        if let Some(bcp47_id) = bcp47_id {
+            self.bcp47_ids.insert(bcp47_id, iana_name.into());
+        }
+        self.zones.insert(iana_name.into(), (data, bcp47_id));
+    }
+
+    /// Reads the TZif file at `path` and adds it for the time zone with the IANA name
+    /// `iana_name`, and optionally the BCP-47 identifier `bcp47_id`.
+    ///
+    /// On Unix systems, TZif files are usually found in `/usr/share/zoneinfo`.
+    ///
+    /// Returns [`TimeZoneError::Io`] if the file could not be read, and
+    /// [`TimeZoneError::InvalidTzif`] if it is not valid TZif data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_timezone::{TimeZoneError, TzifTimeZoneResolver};
+    ///
+    /// let mut resolver = TzifTimeZoneResolver::new();
+    /// assert_eq!(
+    ///     resolver.try_insert_file("Etc/Unknown", None, "does/not/exist"),
+    ///     Err(TimeZoneError::Io(std::io::ErrorKind::NotFound))
+    /// );
+    /// assert_eq!(
+    ///     resolver.try_insert_file("Etc/Unknown", None, "Cargo.toml"),
+    ///     Err(TimeZoneError::InvalidTzif)
+    /// );
+    /// ```
+    pub fn try_insert_file<P: AsRef<Path>>(
+        &mut self,
+        iana_name: &str,
+        bcp47_id: Option<TimeZoneBcp47Id>,
+        path: P,
+    ) -> Result<(), TimeZoneError> {
+        let data = tzif::parse_tzif_file(path)?;
+        self.insert(iana_name, bcp47_id, data);
+        Ok(())
+    }
+
+    /// Resolves the time zone with the IANA name `iana_name` at the instant `utc_datetime`.
+    ///
+    /// Returns the local datetime at that instant and a [`CustomTimeZone`] with the GMT
+    /// offset, time variant, and (if known) BCP-47 identifier filled in. The metazone is
+    /// not filled in; see [`CustomTimeZone::maybe_set_metazone()`].
+    pub fn resolve_iana(
+        &self,
+        iana_name: &str,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Result<(DateTime<Iso>, CustomTimeZone), TimeZoneError> {
+        let (data, bcp47_id) = self
+            .zones
+            .get(iana_name)
+            .ok_or(TimeZoneError::UnknownTimeZone)?;
+        Self::resolve(data, *bcp47_id, utc_datetime)
+    }
+
+    /// Resolves the time zone with the BCP-47 identifier `bcp47_id` at the instant
+    /// `utc_datetime`.
+    ///
+    /// See [`TzifTimeZoneResolver::resolve_iana()`] for more information.
+    pub fn resolve_bcp47(
+        &self,
+        bcp47_id: TimeZoneBcp47Id,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Result<(DateTime<Iso>, CustomTimeZone), TimeZoneError> {
+        let iana_name = self
+            .bcp47_ids
+            .get(&bcp47_id)
+            .ok_or(TimeZoneError::UnknownTimeZone)?;
+        self.resolve_iana(iana_name, utc_datetime)
+    }
+
+    fn resolve(
+        data: &TzifData,
+        bcp47_id: Option<TimeZoneBcp47Id>,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Result<(DateTime<Iso>, CustomTimeZone), TimeZoneError> {
+        let local_time_type = data
+            .local_time_type(Seconds(utc_datetime.seconds_since_unix_epoch()))
+            .ok_or(TimeZoneError::InvalidTzif)?;
+        let offset_seconds =
+            i32::try_from(local_time_type.utoff.0).map_err(|_| TimeZoneError::OffsetOutOfBounds)?;
+        let local_datetime = DateTime::from_nanoseconds_since_unix_epoch(
+            utc_datetime.nanoseconds_since_unix_epoch()
+                + i128::from(offset_seconds) * 1_000_000_000,
+        )?;
+        let time_variant = if local_time_type.is_dst {
+            TimeVariant::daylight()
+        } else {
+            TimeVariant::standard()
+        };
+        Ok((
+            local_datetime,
+            CustomTimeZone::new(
+                Some(GmtOffset::try_new(offset_seconds)?),
+                bcp47_id,
+                None,
+                Some(time_variant),
+            ),
+        ))
+    }
+}
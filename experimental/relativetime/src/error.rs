@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::FixedDecimalFormatterError;
+use icu_plurals::PluralRulesError;
+use icu_provider::DataError;
+
+/// A list of possible error outcomes for the [`RelativeTimeFormatter`](crate::RelativeTimeFormatter) struct.
+#[derive(Display, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum RelativeTimeError {
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(PluralRulesError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("{0}")]
+    FixedDecimalFormatter(FixedDecimalFormatterError),
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("error loading data: {0}")]
+    Data(DataError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RelativeTimeError {}
+
+impl From<PluralRulesError> for RelativeTimeError {
+    fn from(e: PluralRulesError) -> Self {
+        RelativeTimeError::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatterError> for RelativeTimeError {
+    fn from(e: FixedDecimalFormatterError) -> Self {
+        RelativeTimeError::FixedDecimalFormatter(e)
+    }
+}
+
+impl From<DataError> for RelativeTimeError {
+    fn from(e: DataError) -> Self {
+        RelativeTimeError::Data(e)
+    }
+}
@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::Numeric;
+use crate::RelativeTimeFormatter;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use writeable::Writeable;
+
+/// The [`Writeable`] implementation that is returned by [`RelativeTimeFormatter::format`]. See
+/// the [`writeable`] crate for how to consume this.
+pub struct FormattedRelativeTime<'a> {
+    pub(crate) formatter: &'a RelativeTimeFormatter,
+    /// The absolute value of the amount.
+    pub(crate) value: FixedDecimal,
+    pub(crate) is_negative: bool,
+}
+
+impl<'a> FormattedRelativeTime<'a> {
+    /// Returns the amount as an integer if it is one with at most three digits, which covers
+    /// the amounts that can have their own name.
+    fn small_integer(&self) -> Option<i16> {
+        if self.value.nonzero_magnitude_right() < 0 || self.value.nonzero_magnitude_left() > 2 {
+            return None;
+        }
+        let value = (0..=2).rev().fold(0, |acc, magnitude| {
+            acc * 10 + self.value.digit_at(magnitude) as i16
+        });
+        Some(if self.is_negative { -value } else { value })
+    }
+}
+
+impl<'a> Writeable for FormattedRelativeTime<'a> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let patterns = self.formatter.rt.get().get(self.formatter.options.width);
+
+        if self.formatter.options.numeric == Numeric::Auto {
+            if let Some(relative) = self
+                .small_integer()
+                .and_then(|value| patterns.relatives.get(&value))
+            {
+                return sink.write_str(relative);
+            }
+        }
+
+        let plural_patterns = if self.is_negative {
+            &patterns.past
+        } else {
+            &patterns.future
+        };
+        let category = self.formatter.plural_rules.category_for(&self.value);
+        let pattern = plural_patterns.get(category);
+        let (before, after) = match pattern.parts() {
+            Some(parts) => parts,
+            None => return sink.write_str(&pattern.pattern),
+        };
+        sink.write_str(before)?;
+        self.formatter
+            .fixed_decimal_format
+            .format(&self.value)
+            .write_to(sink)?;
+        sink.write_str(after)
+    }
+}
+
+impl<'a> fmt::Display for FormattedRelativeTime<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
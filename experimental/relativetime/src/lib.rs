@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting relative times in a locale-sensitive way, such as "in 3 days" or "yesterday".
+//!
+//! This module is published as its own crate ([`icu_relativetime`](https://docs.rs/icu_relativetime/latest/icu_relativetime/)).
+//! See the [`icu`](https://docs.rs/icu/latest/icu/) crate for more details on the ICU4X project.
+//!
+//! [`RelativeTimeFormatter`] is the equivalent of ECMA-402's `Intl.RelativeTimeFormat`. It is
+//! created for a single time unit, such as days, and formats a [`FixedDecimal`] amount of that
+//! unit, using the past patterns for negative amounts and the future patterns otherwise. With
+//! [`Numeric::Auto`](options::Numeric::Auto), amounts that have their own name in the locale,
+//! such as -1 day, are displayed with that name ("yesterday") instead of a number.
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_relativetime::options::{Numeric, RelativeTimeFormatterOptions};
+//! use icu_relativetime::RelativeTimeFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut options = RelativeTimeFormatterOptions::default();
+//! options.numeric = Numeric::Auto;
+//!
+//! let relative_time_formatter = RelativeTimeFormatter::try_new_day_unstable(
+//!     &icu_testdata::get_baked_provider(),
+//!     &locale!("en").into(),
+//!     options,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(
+//!     relative_time_formatter.format(FixedDecimal::from(3)),
+//!     "in 3 days"
+//! );
+//! assert_writeable_eq!(
+//!     relative_time_formatter.format(FixedDecimal::from(-1)),
+//!     "yesterday"
+//! );
+//! ```
+//!
+//! [`FixedDecimal`]: fixed_decimal::FixedDecimal
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+mod relativetime;
+
+pub use error::RelativeTimeError;
+pub use format::FormattedRelativeTime;
+pub use relativetime::RelativeTimeFormatter;
@@ -0,0 +1,52 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RelativeTimeFormatter`](crate::RelativeTimeFormatter).
+
+/// A bag of options defining how relative times will be formatted by
+/// [`RelativeTimeFormatter`](crate::RelativeTimeFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct RelativeTimeFormatterOptions {
+    /// The length of the formatted output.
+    pub width: Width,
+    /// Whether to always display a number, or to use names such as "yesterday" where available.
+    pub numeric: Numeric,
+}
+
+/// The length of a formatted relative time, such as "in 3 months", "in 3 mo." or "in 3m".
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Width {
+    /// The long form, such as "in 3 months".
+    Long,
+    /// The short form, such as "in 3 mo.".
+    Short,
+    /// The narrowest form, such as "in 3m".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+/// Configuration for whether a formatted relative time always contains a number.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Numeric {
+    /// Always display a number, such as "1 day ago".
+    Always,
+
+    /// Use the locale's name for the amount if it has one, such as "yesterday", and a number
+    /// otherwise.
+    Auto,
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Self::Always
+    }
+}
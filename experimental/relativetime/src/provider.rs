@@ -0,0 +1,168 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::options::Width;
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::DataMarker;
+use icu_provider::{yoke, zerofrom};
+use zerovec::ZeroMap;
+
+/// Patterns for formatting relative times of a single unit, such as days, in all widths.
+#[icu_provider::data_struct(
+    SecondRelativeTimeFormatDataV1Marker = "relativetime/second@1",
+    MinuteRelativeTimeFormatDataV1Marker = "relativetime/minute@1",
+    HourRelativeTimeFormatDataV1Marker = "relativetime/hour@1",
+    DayRelativeTimeFormatDataV1Marker = "relativetime/day@1",
+    WeekRelativeTimeFormatDataV1Marker = "relativetime/week@1",
+    MonthRelativeTimeFormatDataV1Marker = "relativetime/month@1",
+    QuarterRelativeTimeFormatDataV1Marker = "relativetime/quarter@1",
+    YearRelativeTimeFormatDataV1Marker = "relativetime/year@1"
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RelativeTimePatternDataV1<'data> {
+    /// The patterns for [`Width::Long`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long: RelativeTimePatterns<'data>,
+    /// The patterns for [`Width::Short`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short: RelativeTimePatterns<'data>,
+    /// The patterns for [`Width::Narrow`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: RelativeTimePatterns<'data>,
+}
+
+pub(crate) struct ErasedRelativeTimeFormatV1Marker;
+
+impl DataMarker for ErasedRelativeTimeFormatV1Marker {
+    type Yokeable = RelativeTimePatternDataV1<'static>;
+}
+
+impl<'data> RelativeTimePatternDataV1<'data> {
+    pub(crate) fn get(&self, width: Width) -> &RelativeTimePatterns<'data> {
+        match width {
+            Width::Long => &self.long,
+            Width::Short => &self.short,
+            Width::Narrow => &self.narrow,
+        }
+    }
+}
+
+/// The patterns for a single unit in a single width.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RelativeTimePatterns<'data> {
+    /// The names of amounts that have their own name, such as -1 for "yesterday" or 0 for
+    /// "today".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub relatives: ZeroMap<'data, i16, str>,
+    /// The patterns for amounts in the past, such as "{0} days ago".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub past: PluralPatterns<'data>,
+    /// The patterns for amounts in the future, such as "in {0} days".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub future: PluralPatterns<'data>,
+}
+
+/// A pattern for each plural category. Categories without a pattern use the `other` pattern.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PluralPatterns<'data> {
+    /// The pattern for [`PluralCategory::Zero`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::One`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Two`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Few`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Many`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: SingularSubPattern<'data>,
+}
+
+impl<'data> PluralPatterns<'data> {
+    pub(crate) fn get(&self, category: PluralCategory) -> &SingularSubPattern<'data> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
+
+/// A pattern containing at most one placeholder, such as "in {0} days".
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct SingularSubPattern<'data> {
+    /// The pattern string without the placeholder, such as "in  days".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: Cow<'data, str>,
+    /// The byte index in `pattern` at which the placeholder is to be inserted, or
+    /// [`Self::NO_PLACEHOLDER`].
+    pub index: u8,
+}
+
+impl<'data> SingularSubPattern<'data> {
+    /// The index of patterns that do not contain the amount, such as "قبل يوم واحد"
+    /// ("one day ago") in Arabic.
+    pub const NO_PLACEHOLDER: u8 = u8::MAX;
+
+    /// The parts of the pattern before and after the placeholder, or `None` if the pattern
+    /// has no placeholder. If the index is otherwise invalid, the placeholder is placed at
+    /// the end.
+    pub(crate) fn parts(&self) -> Option<(&str, &str)> {
+        if self.index == Self::NO_PLACEHOLDER {
+            return None;
+        }
+        let index = self.index as usize;
+        Some(
+            match (self.pattern.get(..index), self.pattern.get(index..)) {
+                (Some(before), Some(after)) => (before, after),
+                _ => (&self.pattern, ""),
+            },
+        )
+    }
+}
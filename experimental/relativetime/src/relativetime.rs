@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedRelativeTime;
+use crate::options::RelativeTimeFormatterOptions;
+use crate::provider::*;
+use crate::RelativeTimeError;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_plurals::{provider::CardinalV1Marker, PluralRules};
+use icu_provider::prelude::*;
+
+/// A formatter that renders an amount of a time unit relative to the present, such as
+/// "in 3 days" or "yesterday". See the [crate-level documentation](crate) for more details.
+pub struct RelativeTimeFormatter {
+    pub(crate) plural_rules: PluralRules,
+    pub(crate) rt: DataPayload<ErasedRelativeTimeFormatV1Marker>,
+    pub(crate) options: RelativeTimeFormatterOptions,
+    pub(crate) fixed_decimal_format: FixedDecimalFormatter,
+}
+
+macro_rules! constructor {
+    ($name: ident, $name_any: ident, $name_buffer: ident, $marker: ty, $doc: literal) => {
+        #[doc = concat!("Creates a new [`RelativeTimeFormatter`] that formats amounts of ", $doc, ".")]
+        pub fn $name<D>(
+            data_provider: &D,
+            locale: &DataLocale,
+            options: RelativeTimeFormatterOptions,
+        ) -> Result<Self, RelativeTimeError>
+        where
+            D: DataProvider<CardinalV1Marker>
+                + DataProvider<DecimalSymbolsV1Marker>
+                + DataProvider<$marker>
+                + ?Sized,
+        {
+            let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+            let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                FixedDecimalFormatterOptions::default(),
+            )?;
+            let rt: DataPayload<$marker> = data_provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?;
+            Ok(Self {
+                plural_rules,
+                rt: rt.cast(),
+                options,
+                fixed_decimal_format,
+            })
+        }
+        icu_provider::gen_any_buffer_constructors!(
+            locale: include,
+            options: RelativeTimeFormatterOptions,
+            error: RelativeTimeError,
+            functions: [
+                Self::$name,
+                $name_any,
+                $name_buffer
+            ]
+        );
+    };
+}
+
+impl RelativeTimeFormatter {
+    constructor!(
+        try_new_second_unstable,
+        try_new_second_with_any_provider,
+        try_new_second_with_buffer_provider,
+        SecondRelativeTimeFormatDataV1Marker,
+        "seconds"
+    );
+    constructor!(
+        try_new_minute_unstable,
+        try_new_minute_with_any_provider,
+        try_new_minute_with_buffer_provider,
+        MinuteRelativeTimeFormatDataV1Marker,
+        "minutes"
+    );
+    constructor!(
+        try_new_hour_unstable,
+        try_new_hour_with_any_provider,
+        try_new_hour_with_buffer_provider,
+        HourRelativeTimeFormatDataV1Marker,
+        "hours"
+    );
+    constructor!(
+        try_new_day_unstable,
+        try_new_day_with_any_provider,
+        try_new_day_with_buffer_provider,
+        DayRelativeTimeFormatDataV1Marker,
+        "days"
+    );
+    constructor!(
+        try_new_week_unstable,
+        try_new_week_with_any_provider,
+        try_new_week_with_buffer_provider,
+        WeekRelativeTimeFormatDataV1Marker,
+        "weeks"
+    );
+    constructor!(
+        try_new_month_unstable,
+        try_new_month_with_any_provider,
+        try_new_month_with_buffer_provider,
+        MonthRelativeTimeFormatDataV1Marker,
+        "months"
+    );
+    constructor!(
+        try_new_quarter_unstable,
+        try_new_quarter_with_any_provider,
+        try_new_quarter_with_buffer_provider,
+        QuarterRelativeTimeFormatDataV1Marker,
+        "quarters"
+    );
+    constructor!(
+        try_new_year_unstable,
+        try_new_year_with_any_provider,
+        try_new_year_with_buffer_provider,
+        YearRelativeTimeFormatDataV1Marker,
+        "years"
+    );
+
+    /// Formats an amount of the formatter's time unit relative to the present. Negative
+    /// amounts, including negative zero, are in the past; all other amounts are in the future.
+    pub fn format(&self, value: FixedDecimal) -> FormattedRelativeTime<'_> {
+        let is_negative = value.sign() == Sign::Negative;
+        FormattedRelativeTime {
+            formatter: self,
+            value: value.with_sign(Sign::None),
+            is_negative,
+        }
+    }
+}
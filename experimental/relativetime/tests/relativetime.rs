@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_locid::{locale, Locale};
+use icu_relativetime::options::{Numeric, RelativeTimeFormatterOptions, Width};
+use icu_relativetime::RelativeTimeFormatter;
+use writeable::assert_writeable_eq;
+
+fn formatter(locale: &Locale, width: Width, numeric: Numeric) -> RelativeTimeFormatter {
+    let mut options = RelativeTimeFormatterOptions::default();
+    options.width = width;
+    options.numeric = numeric;
+    RelativeTimeFormatter::try_new_day_unstable(
+        &icu_testdata::get_baked_provider(),
+        &locale.into(),
+        options,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_numeric_always() {
+    let formatter = formatter(&locale!("en"), Width::Long, Numeric::Always);
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(3)), "in 3 days");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(1)), "in 1 day");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(0)), "in 0 days");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(-1)), "1 day ago");
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(-1234)),
+        "1,234 days ago"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(0).with_sign(Sign::Negative)),
+        "0 days ago"
+    );
+    assert_writeable_eq!(formatter.format("-1.5".parse().unwrap()), "1.5 days ago");
+}
+
+#[test]
+fn test_numeric_auto() {
+    let formatter = formatter(&locale!("en"), Width::Long, Numeric::Auto);
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(-1)), "yesterday");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(0)), "today");
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(0).with_sign(Sign::Negative)),
+        "today"
+    );
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(1)), "tomorrow");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(2)), "in 2 days");
+    assert_writeable_eq!(formatter.format("1.0".parse().unwrap()), "tomorrow");
+    assert_writeable_eq!(formatter.format("1.5".parse().unwrap()), "in 1.5 days");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(1001)), "in 1,001 days");
+}
+
+#[test]
+fn test_width() {
+    let formatter = formatter(&locale!("en"), Width::Narrow, Numeric::Always);
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(-2)), "2d ago");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(2)), "in 2d");
+}
+
+#[test]
+fn test_patterns_without_placeholder() {
+    // Arabic spells out one and two days
+    let formatter = formatter(&locale!("ar"), Width::Long, Numeric::Always);
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(-1)), "قبل يوم واحد");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(2)), "خلال يومين");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(-3)), "قبل ٣ أيام");
+}
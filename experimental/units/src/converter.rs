@@ -0,0 +1,452 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use crate::{Ratio, UnitsError};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+
+/// The SI and binary prefixes, with their base and exponent.
+const PREFIXES: &[(&str, i128, i8)] = &[
+    ("yotta", 10, 24),
+    ("zetta", 10, 21),
+    ("exa", 10, 18),
+    ("peta", 10, 15),
+    ("tera", 10, 12),
+    ("giga", 10, 9),
+    ("mega", 10, 6),
+    ("kilo", 10, 3),
+    ("hecto", 10, 2),
+    ("deka", 10, 1),
+    ("deci", 10, -1),
+    ("centi", 10, -2),
+    ("milli", 10, -3),
+    ("micro", 10, -6),
+    ("nano", 10, -9),
+    ("pico", 10, -12),
+    ("femto", 10, -15),
+    ("atto", 10, -18),
+    ("zepto", 10, -21),
+    ("yocto", 10, -24),
+    ("kibi", 2, 10),
+    ("mebi", 2, 20),
+    ("gibi", 2, 30),
+    ("tebi", 2, 40),
+    ("pebi", 2, 50),
+    ("exbi", 2, 60),
+    ("zebi", 2, 70),
+    ("yobi", 2, 80),
+];
+
+/// Returns the power denoted by a power prefix such as `square`, if it is one.
+fn parse_power(token: &str) -> Option<i8> {
+    match token {
+        "square" => Some(2),
+        "cubic" => Some(3),
+        _ => match token.strip_prefix("pow")?.parse() {
+            Ok(power) if (2..=9).contains(&power) => Some(power),
+            _ => None,
+        },
+    }
+}
+
+/// A unit in a compound unit, such as `kilometer` in `kilometer-per-hour`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct SingleUnit<'a> {
+    /// The factor of the prefix, such as 1000 for `kilo`.
+    pub(crate) prefix: Ratio,
+    /// The name of the unit without the prefix, which is a key of
+    /// [`UnitsInfoV1::base_units`].
+    pub(crate) name: &'a str,
+    /// The power of the unit, which is negative in the denominator.
+    pub(crate) power: i8,
+}
+
+/// Finds the unit in [`UnitsInfoV1::base_units`] that a (possibly prefixed) identifier
+/// denotes, preferring the unprefixed unit, such as `kilogram` over `kilo` `gram`.
+fn find_unit<'a>(info: &UnitsInfoV1, id: &'a str) -> Option<(Ratio, &'a str)> {
+    if info.base_units.get(id).is_some() {
+        return Some((Ratio::ONE, id));
+    }
+    PREFIXES.iter().find_map(|&(prefix, base, exponent)| {
+        let name = id.strip_prefix(prefix)?;
+        info.base_units.get(name)?;
+        Some((Ratio::from_integer(base).checked_powi(exponent)?, name))
+    })
+}
+
+/// Splits a CLDR unit identifier, such as `kilometer-per-square-second`, into its units.
+///
+/// Units may themselves contain hyphens, such as `light-year`, so the longest run of tokens
+/// that is a known unit is taken.
+pub(crate) fn parse_unit<'a>(info: &UnitsInfoV1, id: &'a str) -> Option<Vec<SingleUnit<'a>>> {
+    let mut units = Vec::new();
+    let mut in_denominator = false;
+    let mut numerator_len = 0;
+    let mut rest = id;
+    while !rest.is_empty() {
+        let (token, after) = rest.split_once('-').unwrap_or((rest, ""));
+        if token == "per" && !in_denominator {
+            in_denominator = true;
+            numerator_len = units.len();
+            rest = after;
+            continue;
+        }
+        let (power, after_power) = match parse_power(token) {
+            Some(power) => (power, after),
+            None => (1, rest),
+        };
+        // The ends of the runs of tokens, from longest to shortest.
+        let ends = after_power
+            .match_indices('-')
+            .map(|(index, _)| index)
+            .chain(core::iter::once(after_power.len()))
+            .rev();
+        let (end, (prefix, name)) = ends
+            .filter_map(|end| {
+                let candidate = after_power.get(..end)?;
+                if candidate.split('-').any(|token| token == "per") {
+                    return None;
+                }
+                Some((end, find_unit(info, candidate)?))
+            })
+            .next()?;
+        units.push(SingleUnit {
+            prefix,
+            name,
+            power: if in_denominator { -power } else { power },
+        });
+        rest = after_power.get(end..)?.strip_prefix('-').unwrap_or("");
+    }
+    if units.is_empty() || (in_denominator && units.len() == numerator_len) {
+        None
+    } else {
+        Some(units)
+    }
+}
+
+/// A unit expressed in base units: the factor that converts it to the base units, and the power
+/// of each base unit.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ResolvedUnit {
+    pub(crate) factor: Ratio,
+    pub(crate) offset: Ratio,
+    pub(crate) base_units: BTreeMap<String, i16>,
+}
+
+/// Expresses a CLDR unit identifier in base units, or returns `None` if it is unknown or its
+/// factor overflows.
+pub(crate) fn resolve_unit(info: &UnitsInfoV1, id: &str) -> Option<ResolvedUnit> {
+    let units = parse_unit(info, id)?;
+    let mut factor = Ratio::ONE;
+    let mut base_units = BTreeMap::new();
+    for unit in &units {
+        let unit_factor = match info.factors.get_copied(unit.name) {
+            Some(factor) => factor.to_ratio()?,
+            None => Ratio::ONE,
+        };
+        factor = factor.checked_mul(
+            unit.prefix
+                .checked_mul(unit_factor)?
+                .checked_powi(unit.power)?,
+        )?;
+        let base = info.base_units.get(unit.name)?;
+        if base == unit.name {
+            *base_units.entry(base.to_string()).or_insert(0) += unit.power as i16;
+        } else {
+            // Base units are their own base units, so this does not recurse further.
+            for base_unit in parse_unit(info, base)? {
+                if info.base_units.get(base_unit.name)? != base_unit.name {
+                    return None;
+                }
+                *base_units.entry(base_unit.name.to_string()).or_insert(0) +=
+                    base_unit.power as i16 * unit.power as i16;
+            }
+        }
+    }
+    base_units.retain(|_, power| *power != 0);
+    // Only simple units, such as `celsius`, have an offset.
+    let offset = match units.as_slice() {
+        [unit] if unit.name == id => match info.offsets.get_copied(unit.name) {
+            Some(offset) => offset.to_ratio()?,
+            None => Ratio::ZERO,
+        },
+        _ => Ratio::ZERO,
+    };
+    Some(ResolvedUnit {
+        factor,
+        offset,
+        base_units,
+    })
+}
+
+/// A converter between two units that measure the same quantity, such as feet and meters or
+/// degrees Celsius and Fahrenheit.
+///
+/// The conversion factors come from CLDR and are exact rational numbers, so conversions are
+/// done without loss of precision and return a [`Ratio`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_units::{Ratio, UnitsConverter};
+///
+/// let converter = UnitsConverter::try_new_unstable(
+///     &icu_testdata::get_baked_provider(),
+///     "fahrenheit",
+///     "celsius",
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     converter.convert(&"212".parse().unwrap()),
+///     Some(Ratio::from_integer(100))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitsConverter {
+    factor: Ratio,
+    offset: Ratio,
+}
+
+impl UnitsConverter {
+    /// Creates a new [`UnitsConverter`] from the `input` unit to the `output` unit, which are
+    /// CLDR unit identifiers such as `kilometer-per-hour`.
+    ///
+    /// Returns [`UnitsError::UnknownUnit`] if a unit is unknown, and
+    /// [`UnitsError::IncompatibleUnits`] if the units measure different quantities.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        input: &str,
+        output: &str,
+    ) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<UnitsInfoV1Marker> + ?Sized,
+    {
+        let info: DataPayload<UnitsInfoV1Marker> = data_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Self::try_new_with_info(info.get(), input, output)
+    }
+
+    /// Creates a new [`UnitsConverter`] using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        input: &str,
+        output: &str,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_downcasting(), input, output)
+    }
+
+    /// Creates a new [`UnitsConverter`] using a [`BufferProvider`](icu_provider::BufferProvider).
+    /// Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        input: &str,
+        output: &str,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_deserializing(), input, output)
+    }
+
+    pub(crate) fn try_new_with_info(
+        info: &UnitsInfoV1,
+        input: &str,
+        output: &str,
+    ) -> Result<Self, UnitsError> {
+        let input = resolve_unit(info, input).ok_or(UnitsError::UnknownUnit)?;
+        let output = resolve_unit(info, output).ok_or(UnitsError::UnknownUnit)?;
+        if input.base_units != output.base_units {
+            return Err(UnitsError::IncompatibleUnits);
+        }
+        // v ↦ ((v × fi + oi) - oo) / fo = v × (fi / fo) + (oi - oo) / fo
+        let factor = input.factor.checked_div(output.factor);
+        let offset = input
+            .offset
+            .checked_sub(output.offset)
+            .and_then(|offset| offset.checked_div(output.factor));
+        match (factor, offset) {
+            (Some(factor), Some(offset)) => Ok(Self { factor, offset }),
+            _ => Err(UnitsError::IncompatibleUnits),
+        }
+    }
+
+    /// Converts a value in the input unit to the output unit, or returns `None` on overflow.
+    pub fn convert(&self, value: &FixedDecimal) -> Option<Ratio> {
+        self.convert_ratio(Ratio::try_from_fixed_decimal(value)?)
+    }
+
+    /// Converts a [`Ratio`] in the input unit to the output unit, or returns `None` on overflow.
+    pub fn convert_ratio(&self, value: Ratio) -> Option<Ratio> {
+        value.checked_mul(self.factor)?.checked_add(self.offset)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn rational(numerator: i128, denominator: i128) -> RationalV1 {
+        Ratio::new(numerator, denominator).unwrap().into()
+    }
+
+    /// A subset of the conversion data in CLDR.
+    pub(crate) fn units_info() -> UnitsInfoV1<'static> {
+        let base_units = [
+            ("celsius", "kelvin"),
+            ("fahrenheit", "kelvin"),
+            ("foot", "meter"),
+            ("gram", "kilogram"),
+            ("hour", "second"),
+            ("kelvin", "kelvin"),
+            ("kilogram", "kilogram"),
+            ("light-year", "meter"),
+            ("liter", "cubic-meter"),
+            ("meter", "meter"),
+            ("mile", "meter"),
+            ("minute", "second"),
+            ("second", "second"),
+            ("watt", "kilogram-square-meter-per-cubic-second"),
+        ];
+        let factors = [
+            ("fahrenheit", rational(5, 9)),
+            ("foot", rational(381, 1250)),
+            ("gram", rational(1, 1000)),
+            ("hour", rational(3600, 1)),
+            ("light-year", rational(9_460_730_472_580_800, 1)),
+            ("liter", rational(1, 1000)),
+            ("mile", rational(201_168, 125)),
+            ("minute", rational(60, 1)),
+        ];
+        let offsets = [
+            ("celsius", rational(5463, 20)),
+            ("fahrenheit", rational(45967, 180)),
+        ];
+        let quantities = [
+            ("cubic-meter", "volume"),
+            ("kelvin", "temperature"),
+            ("kilogram", "mass"),
+            ("kilogram-square-meter-per-cubic-second", "power"),
+            ("meter", "length"),
+            ("meter-per-second", "speed"),
+            ("second", "duration"),
+        ];
+        UnitsInfoV1 {
+            base_units: base_units.iter().copied().collect(),
+            factors: factors.iter().copied().collect(),
+            offsets: offsets.iter().copied().collect(),
+            quantities: quantities.iter().copied().collect(),
+        }
+    }
+
+    fn convert(input: &str, output: &str, value: &str) -> Option<Ratio> {
+        UnitsConverter::try_new_with_info(&units_info(), input, output)
+            .unwrap()
+            .convert(&value.parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse_unit() {
+        let info = units_info();
+        let units = parse_unit(&info, "kilometer-per-square-second").unwrap();
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].prefix, Ratio::from_integer(1000));
+        assert_eq!(units[0].name, "meter");
+        assert_eq!(units[0].power, 1);
+        assert_eq!(units[1].name, "second");
+        assert_eq!(units[1].power, -2);
+
+        let units = parse_unit(&info, "kilogram-light-year").unwrap();
+        assert_eq!(units[0].prefix, Ratio::ONE);
+        assert_eq!(units[0].name, "kilogram");
+        assert_eq!(units[1].name, "light-year");
+
+        assert_eq!(parse_unit(&info, "furlong"), None);
+        assert_eq!(parse_unit(&info, "meter-per"), None);
+        assert_eq!(parse_unit(&info, "per-per-second"), None);
+        assert_eq!(parse_unit(&info, ""), None);
+    }
+
+    #[test]
+    fn test_resolve_unit() {
+        let info = units_info();
+        let watt = resolve_unit(&info, "kilowatt").unwrap();
+        assert_eq!(watt.factor, Ratio::from_integer(1000));
+        assert_eq!(
+            watt.base_units,
+            [("kilogram", 1), ("meter", 2), ("second", -3)]
+                .iter()
+                .map(|&(unit, power)| (unit.to_string(), power))
+                .collect()
+        );
+        let speed = resolve_unit(&info, "kilometer-per-hour").unwrap();
+        assert_eq!(speed.factor, Ratio::new(5, 18).unwrap());
+        assert_eq!(
+            resolve_unit(&info, "cubic-decimeter").unwrap(),
+            resolve_unit(&info, "liter").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(
+            convert("mile", "foot", "1"),
+            Some(Ratio::from_integer(5280))
+        );
+        assert_eq!(convert("foot", "meter", "1"), Ratio::new(381, 1250));
+        assert_eq!(
+            convert("kilometer-per-hour", "meter-per-second", "36"),
+            Some(Ratio::from_integer(10))
+        );
+        assert_eq!(
+            convert("mile-per-hour", "kilometer-per-hour", "1"),
+            Ratio::new(201_168, 125_000)
+        );
+        assert_eq!(
+            convert("square-kilometer", "square-meter", "1.5"),
+            Some(Ratio::from_integer(1_500_000))
+        );
+        assert_eq!(
+            convert("megagram", "kilogram", "2"),
+            Some(Ratio::from_integer(2000))
+        );
+    }
+
+    #[test]
+    fn test_convert_offset() {
+        assert_eq!(
+            convert("celsius", "fahrenheit", "100"),
+            Some(Ratio::from_integer(212))
+        );
+        assert_eq!(
+            convert("fahrenheit", "celsius", "-40"),
+            Some(Ratio::from_integer(-40))
+        );
+        assert_eq!(convert("celsius", "kelvin", "0"), Ratio::new(5463, 20));
+        assert_eq!(convert("kelvin", "celsius", "0"), Ratio::new(-5463, 20));
+    }
+
+    #[test]
+    fn test_errors() {
+        let info = units_info();
+        assert!(matches!(
+            UnitsConverter::try_new_with_info(&info, "meter", "second"),
+            Err(UnitsError::IncompatibleUnits)
+        ));
+        assert!(matches!(
+            UnitsConverter::try_new_with_info(&info, "meter", "furlong"),
+            Err(UnitsError::UnknownUnit)
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::FixedDecimalFormatterError;
+use icu_plurals::PluralRulesError;
+use icu_provider::DataError;
+
+/// A list of possible error outcomes for the [`UnitsFormatter`](crate::UnitsFormatter),
+/// [`UnitsConverter`](crate::UnitsConverter) and [`UnitPreferences`](crate::UnitPreferences)
+/// structs.
+#[derive(Display, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum UnitsError {
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(PluralRulesError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("{0}")]
+    FixedDecimalFormatter(FixedDecimalFormatterError),
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("error loading data: {0}")]
+    Data(DataError),
+    /// The unit identifier is not valid or has no data.
+    #[displaydoc("unknown unit")]
+    UnknownUnit,
+    /// The units measure different quantities, such as meters and seconds, or cannot be
+    /// converted exactly.
+    #[displaydoc("units cannot be converted")]
+    IncompatibleUnits,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+impl From<PluralRulesError> for UnitsError {
+    fn from(e: PluralRulesError) -> Self {
+        UnitsError::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatterError> for UnitsError {
+    fn from(e: FixedDecimalFormatterError) -> Self {
+        UnitsError::FixedDecimalFormatter(e)
+    }
+}
+
+impl From<DataError> for UnitsError {
+    fn from(e: DataError) -> Self {
+        UnitsError::Data(e)
+    }
+}
@@ -0,0 +1,84 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::UnitsFormatter;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_plurals::PluralCategory;
+use writeable::Writeable;
+
+/// The [`Writeable`] implementation that is returned by [`UnitsFormatter::format`]. See
+/// the [`writeable`] crate for how to consume this.
+pub struct FormattedUnit<'a> {
+    pub(crate) formatter: &'a UnitsFormatter,
+    pub(crate) value: FixedDecimal,
+}
+
+/// Writes a pattern such as "{0} km", calling `write_arg` with the index of each placeholder.
+fn write_pattern<W: fmt::Write + ?Sized>(
+    pattern: &str,
+    sink: &mut W,
+    mut write_arg: impl FnMut(&mut W, u8) -> fmt::Result,
+) -> fmt::Result {
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let (before, after) = rest.split_at(start);
+        sink.write_str(before)?;
+        if let Some(after_arg) = after.strip_prefix("{0}") {
+            write_arg(sink, 0)?;
+            rest = after_arg;
+        } else if let Some(after_arg) = after.strip_prefix("{1}") {
+            write_arg(sink, 1)?;
+            rest = after_arg;
+        } else {
+            sink.write_char('{')?;
+            rest = after.get(1..).unwrap_or_default();
+        }
+    }
+    sink.write_str(rest)
+}
+
+impl<'a> Writeable for FormattedUnit<'a> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let display = self.formatter.display.get();
+        let unit = self.formatter.unit.as_str();
+        let category = self.formatter.plural_rules.category_for(&self.value);
+        let write_number = |sink: &mut W, _| {
+            self.formatter
+                .fixed_decimal_format
+                .format(&self.value)
+                .write_to(sink)
+        };
+
+        if let Some(pattern) = display.get(unit, category) {
+            return write_pattern(pattern, sink, write_number);
+        }
+
+        // Compound units without their own patterns are formatted by combining the patterns of
+        // the numerator and the denominator. The constructor checks that these exist.
+        let (numerator, denominator) = unit.split_once("-per-").unwrap_or((unit, ""));
+        let numerator_pattern = display.get(numerator, category).unwrap_or("{0}");
+        if let Some(per_unit_pattern) = display.per_unit_patterns.get(denominator) {
+            write_pattern(per_unit_pattern, sink, |sink, _| {
+                write_pattern(numerator_pattern, sink, write_number)
+            })
+        } else {
+            // The name of the denominator is its singular pattern without the number.
+            let denominator_name = display
+                .get(denominator, PluralCategory::One)
+                .unwrap_or_default();
+            let denominator_name = denominator_name.replacen("{0}", "", 1);
+            write_pattern(&display.per_pattern, sink, |sink, index| match index {
+                0 => write_pattern(numerator_pattern, sink, write_number),
+                _ => sink.write_str(denominator_name.trim()),
+            })
+        }
+    }
+}
+
+impl<'a> fmt::Display for FormattedUnit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
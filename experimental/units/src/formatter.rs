@@ -0,0 +1,138 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedUnit;
+use crate::options::{UnitsFormatterOptions, Width};
+use crate::provider::*;
+use crate::UnitsError;
+use alloc::string::{String, ToString};
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_plurals::{provider::CardinalV1Marker, PluralCategory, PluralRules};
+use icu_provider::prelude::*;
+
+/// A formatter that renders an amount of a measurement unit, such as "3 km" or
+/// "5 kilometers per hour". See the [crate-level documentation](crate) for more details.
+pub struct UnitsFormatter {
+    pub(crate) plural_rules: PluralRules,
+    pub(crate) display: DataPayload<ErasedUnitsDisplayV1Marker>,
+    pub(crate) fixed_decimal_format: FixedDecimalFormatter,
+    pub(crate) unit: String,
+}
+
+fn load_display<D, M>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<ErasedUnitsDisplayV1Marker>, DataError>
+where
+    D: DataProvider<M> + ?Sized,
+    M: KeyedDataMarker<Yokeable = UnitsDisplayV1<'static>>,
+{
+    Ok(data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?
+        .cast())
+}
+
+/// Returns whether a unit has patterns, either its own or, for a compound unit such as
+/// `kilometer-per-minute`, those of its numerator and denominator.
+fn has_patterns(display: &UnitsDisplayV1, unit: &str) -> bool {
+    if display.get(unit, PluralCategory::Other).is_some() {
+        return true;
+    }
+    match unit.split_once("-per-") {
+        Some((numerator, denominator)) => {
+            display.get(numerator, PluralCategory::Other).is_some()
+                && (display.per_unit_patterns.get(denominator).is_some()
+                    || display.get(denominator, PluralCategory::Other).is_some())
+        }
+        None => false,
+    }
+}
+
+impl UnitsFormatter {
+    /// Creates a new [`UnitsFormatter`] that formats amounts of a unit, which is a CLDR unit
+    /// identifier such as `kilometer` or `kilometer-per-hour`.
+    ///
+    /// Returns [`UnitsError::UnknownUnit`] if the locale has no patterns for the unit.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: &str,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<CardinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<LongUnitsDisplayV1Marker>
+            + DataProvider<ShortUnitsDisplayV1Marker>
+            + DataProvider<NarrowUnitsDisplayV1Marker>
+            + ?Sized,
+    {
+        let display = match options.width {
+            Width::Long => load_display::<D, LongUnitsDisplayV1Marker>(data_provider, locale)?,
+            Width::Short => load_display::<D, ShortUnitsDisplayV1Marker>(data_provider, locale)?,
+            Width::Narrow => load_display::<D, NarrowUnitsDisplayV1Marker>(data_provider, locale)?,
+        };
+        if !has_patterns(display.get(), unit) {
+            return Err(UnitsError::UnknownUnit);
+        }
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            FixedDecimalFormatterOptions::default(),
+        )?;
+        Ok(Self {
+            plural_rules,
+            display,
+            fixed_decimal_format,
+            unit: unit.to_string(),
+        })
+    }
+
+    /// Creates a new [`UnitsFormatter`] using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        unit: &str,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale, unit, options)
+    }
+
+    /// Creates a new [`UnitsFormatter`] using a [`BufferProvider`](icu_provider::BufferProvider).
+    /// Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        unit: &str,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale, unit, options)
+    }
+
+    /// Formats an amount of the formatter's unit, returning a [`FormattedUnit`].
+    pub fn format(&self, value: &FixedDecimal) -> FormattedUnit<'_> {
+        FormattedUnit {
+            formatter: self,
+            value: value.clone(),
+        }
+    }
+
+    /// Returns the CLDR identifier of the formatter's unit.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting and converting measurement units in a locale-sensitive way, such as "3 km" or
+//! "5 miles per hour".
+//!
+//! This module is published as its own crate ([`icu_units`](https://docs.rs/icu_units/latest/icu_units/)).
+//! See the [`icu`](https://docs.rs/icu/latest/icu/) crate for more details on the ICU4X project.
+//!
+//! Units are identified by their CLDR unit identifiers, such as `kilometer`, which may be
+//! compound, such as `kilometer-per-hour` or `square-meter`.
+//!
+//! - [`UnitsFormatter`] formats a [`FixedDecimal`] amount of a unit in a
+//!   [`Width`](options::Width), using the plural form of the unit for the amount. Compound units
+//!   without their own patterns are formatted from the patterns of their parts.
+//! - [`UnitsConverter`] converts amounts between units that measure the same quantity, such as
+//!   feet and meters, using the exact rational factors in CLDR.
+//! - [`UnitPreferences`] converts amounts to the units preferred in the region of a locale for a
+//!   usage, such as feet and miles for roads in the United States.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_units::options::UnitsFormatterOptions;
+//! use icu_units::{UnitPreferences, UnitsFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let provider = icu_testdata::get_baked_provider();
+//!
+//! // The preferences depend on the region of the locale.
+//! let preferences =
+//!     UnitPreferences::try_new_unstable(&provider, &locale!("en-US").into(), "meter", "road")
+//!         .expect("Data should load successfully");
+//! let (unit, value) = preferences
+//!     .convert(&"1207.008".parse().unwrap())
+//!     .expect("Conversion should not overflow");
+//! assert_eq!(unit, "mile");
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &provider,
+//!     &locale!("en").into(),
+//!     unit,
+//!     UnitsFormatterOptions::default(),
+//! )
+//! .expect("Data should load successfully");
+//! assert_writeable_eq!(
+//!     formatter.format(&value.to_fixed_decimal(-1).unwrap()),
+//!     "0.8 mi"
+//! );
+//! ```
+//!
+//! [`FixedDecimal`]: fixed_decimal::FixedDecimal
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod converter;
+mod error;
+mod format;
+mod formatter;
+pub mod options;
+mod preferences;
+pub mod provider;
+mod ratio;
+
+pub use converter::UnitsConverter;
+pub use error::UnitsError;
+pub use format::FormattedUnit;
+pub use formatter::UnitsFormatter;
+pub use preferences::UnitPreferences;
+pub use ratio::Ratio;
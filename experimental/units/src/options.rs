@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`UnitsFormatter`](crate::UnitsFormatter).
+
+/// A bag of options defining how measurement units will be formatted by
+/// [`UnitsFormatter`](crate::UnitsFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct UnitsFormatterOptions {
+    /// The length of the formatted output.
+    pub width: Width,
+}
+
+/// The length of a formatted unit, such as "3 kilometers", "3 km" or "3km".
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Width {
+    /// The long form, such as "3 kilometers".
+    Long,
+    /// The short form, such as "3 km".
+    Short,
+    /// The narrowest form, such as "3km".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Short
+    }
+}
@@ -0,0 +1,297 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::converter::resolve_unit;
+use crate::provider::*;
+use crate::{Ratio, UnitsConverter, UnitsError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+
+/// A preferred unit, with the threshold at which it is used.
+#[derive(Debug)]
+struct Preference {
+    unit: String,
+    threshold: Ratio,
+    converter: UnitsConverter,
+}
+
+/// The units preferred in a region for a quantity and usage, such as miles and feet for roads
+/// in the United States, with converters from an input unit to them.
+///
+/// The preferences come from CLDR. If there are no preferences for the region of the locale,
+/// those of the world (`001`) are used, and if there are none for the usage, those of the
+/// `default` usage are used.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_units::{Ratio, UnitPreferences};
+///
+/// let preferences = UnitPreferences::try_new_unstable(
+///     &icu_testdata::get_baked_provider(),
+///     &locale!("en-US").into(),
+///     "meter",
+///     "road",
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     preferences.convert(&"30".parse().unwrap()),
+///     Some(("foot", Ratio::new(12500, 127).unwrap()))
+/// );
+/// assert_eq!(
+///     preferences.convert(&"8046.72".parse().unwrap()),
+///     Some(("mile", Ratio::from_integer(5)))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct UnitPreferences {
+    preferences: Vec<Preference>,
+}
+
+impl UnitPreferences {
+    /// Creates a new [`UnitPreferences`] for values in the `input` unit, which is a CLDR unit
+    /// identifier such as `meter`, and a CLDR usage such as `road` or `person-height`.
+    ///
+    /// Returns [`UnitsError::UnknownUnit`] if the unit is unknown or there are no preferences
+    /// for the quantity it measures.
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        input: &str,
+        usage: &str,
+    ) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<UnitsInfoV1Marker> + DataProvider<UnitPreferencesV1Marker> + ?Sized,
+    {
+        let info: DataPayload<UnitsInfoV1Marker> = data_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let preferences: DataPayload<UnitPreferencesV1Marker> = data_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Self::try_new_with_data(info.get(), preferences.get(), locale, input, usage)
+    }
+
+    /// Creates a new [`UnitPreferences`] using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        input: &str,
+        usage: &str,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale, input, usage)
+    }
+
+    /// Creates a new [`UnitPreferences`] using a [`BufferProvider`](icu_provider::BufferProvider).
+    /// Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        input: &str,
+        usage: &str,
+    ) -> Result<Self, UnitsError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale, input, usage)
+    }
+
+    fn try_new_with_data(
+        info: &UnitsInfoV1,
+        data: &UnitPreferencesV1,
+        locale: &DataLocale,
+        input: &str,
+        usage: &str,
+    ) -> Result<Self, UnitsError> {
+        let base_units = resolve_unit(info, input)
+            .ok_or(UnitsError::UnknownUnit)?
+            .base_units;
+        let quantity = info
+            .quantities
+            .iter()
+            .find(|(base, _)| {
+                resolve_unit(info, base).map_or(false, |resolved| resolved.base_units == base_units)
+            })
+            .map(|(_, quantity)| quantity)
+            .ok_or(UnitsError::UnknownUnit)?;
+
+        let region = locale.region();
+        let region = region.as_ref().map_or("001", |region| region.as_str());
+        let (key, cursor) = [
+            (usage, region),
+            (usage, "001"),
+            ("default", region),
+            ("default", "001"),
+        ]
+        .iter()
+        .find_map(|(usage, region)| {
+            let key = format!("{}/{}/{}", quantity, usage, region);
+            let cursor = data.units.get0(key.as_str())?;
+            Some((key, cursor))
+        })
+        .ok_or(UnitsError::UnknownUnit)?;
+
+        let preferences = cursor
+            .iter1()
+            .map(|(index, unit)| {
+                let index = u16::from_unaligned(*index);
+                let threshold = match data.thresholds.get_copied_2d(key.as_str(), &index) {
+                    Some(threshold) => threshold.to_ratio().ok_or(UnitsError::UnknownUnit)?,
+                    None => Ratio::ONE,
+                };
+                Ok(Preference {
+                    unit: unit.to_string(),
+                    threshold,
+                    converter: UnitsConverter::try_new_with_info(info, input, unit)?,
+                })
+            })
+            .collect::<Result<Vec<_>, UnitsError>>()?;
+        Ok(Self { preferences })
+    }
+
+    /// Converts a value in the input unit to the preferred unit for its magnitude, returning the
+    /// CLDR identifier of that unit and the converted value, or `None` on overflow.
+    pub fn convert(&self, value: &FixedDecimal) -> Option<(&str, Ratio)> {
+        let value = Ratio::try_from_fixed_decimal(value)?;
+        let (last, rest) = self.preferences.split_last()?;
+        for preference in rest {
+            let converted = preference.converter.convert_ratio(value)?;
+            if converted.checked_abs()? >= preference.threshold {
+                return Some((&preference.unit, converted));
+            }
+        }
+        Some((&last.unit, last.converter.convert_ratio(value)?))
+    }
+
+    /// Returns the CLDR identifiers of the preferred units, from largest to smallest.
+    pub fn units(&self) -> impl Iterator<Item = &str> + '_ {
+        self.preferences
+            .iter()
+            .map(|preference| preference.unit.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::tests::units_info;
+    use icu_locid::locale;
+
+    /// A subset of the preference data in CLDR.
+    fn unit_preferences() -> UnitPreferencesV1<'static> {
+        let units = [
+            ("length/default/001", 0, "kilometer"),
+            ("length/default/001", 1, "meter"),
+            ("length/default/001", 2, "centimeter"),
+            ("length/road/001", 0, "kilometer"),
+            ("length/road/001", 1, "meter"),
+            ("length/road/US", 0, "mile"),
+            ("length/road/US", 1, "foot"),
+            ("length/road/US", 2, "foot"),
+            ("temperature/default/001", 0, "celsius"),
+            ("temperature/default/US", 0, "fahrenheit"),
+        ];
+        let thresholds = [
+            (
+                "length/road/US",
+                0,
+                RationalV1::from(Ratio::new(1, 2).unwrap()),
+            ),
+            (
+                "length/road/US",
+                1,
+                RationalV1::from(Ratio::from_integer(100)),
+            ),
+        ];
+        UnitPreferencesV1 {
+            units: units.iter().map(|&(a, b, c)| (a, b as u16, c)).collect(),
+            thresholds: thresholds
+                .iter()
+                .map(|&(a, b, c)| (a, b as u16, c))
+                .collect(),
+        }
+    }
+
+    fn new_preferences(locale: DataLocale, input: &str, usage: &str) -> UnitPreferences {
+        UnitPreferences::try_new_with_data(
+            &units_info(),
+            &unit_preferences(),
+            &locale,
+            input,
+            usage,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_road() {
+        let preferences = new_preferences(locale!("en-US").into(), "meter", "road");
+        assert_eq!(
+            preferences.units().collect::<Vec<_>>(),
+            ["mile", "foot", "foot"]
+        );
+        assert_eq!(
+            preferences.convert(&"1609.344".parse().unwrap()),
+            Some(("mile", Ratio::ONE))
+        );
+        assert_eq!(
+            preferences.convert(&"30.48".parse().unwrap()),
+            Some(("foot", Ratio::from_integer(100)))
+        );
+        assert_eq!(
+            preferences.convert(&"-3.048".parse().unwrap()),
+            Some(("foot", Ratio::from_integer(-10)))
+        );
+
+        let preferences = new_preferences(locale!("fr-FR").into(), "mile", "road");
+        assert_eq!(
+            preferences.convert(&"2".parse().unwrap()),
+            Some(("kilometer", Ratio::new(402_336, 125_000).unwrap()))
+        );
+        assert_eq!(
+            preferences.convert(&"0.25".parse().unwrap()),
+            Some(("meter", Ratio::new(50_292, 125).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        // There are no preferences for the usage, so the default ones are used.
+        let preferences = new_preferences(locale!("fr").into(), "foot", "person-height");
+        assert_eq!(
+            preferences.convert(&"1".parse().unwrap()),
+            Some(("centimeter", Ratio::new(762, 25).unwrap()))
+        );
+        let preferences = new_preferences(locale!("en-US").into(), "kelvin", "weather");
+        assert_eq!(
+            preferences.convert(&"0".parse().unwrap()),
+            Some(("fahrenheit", Ratio::new(-45967, 100).unwrap()))
+        );
+        // There are no preferences for mass.
+        assert!(matches!(
+            UnitPreferences::try_new_with_data(
+                &units_info(),
+                &unit_preferences(),
+                &locale!("en").into(),
+                "kilogram",
+                "road"
+            ),
+            Err(UnitsError::UnknownUnit)
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::ratio::Ratio;
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::{yoke, zerofrom, DataMarker};
+use zerovec::{ZeroMap, ZeroMap2d};
+
+/// Patterns for formatting measurement units in a single width.
+///
+/// Units are identified by their CLDR unit identifier without the category, such as
+/// `kilometer` or `kilometer-per-hour`. The patterns combine the formatted number `{0}` with
+/// the unit, such as "{0} km".
+#[icu_provider::data_struct(
+    LongUnitsDisplayV1Marker = "units/long@1",
+    ShortUnitsDisplayV1Marker = "units/short@1",
+    NarrowUnitsDisplayV1Marker = "units/narrow@1"
+)]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsDisplayV1<'data> {
+    /// The patterns for [`PluralCategory::Zero`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::One`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Two`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Few`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Many`], if they differ from the `other` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Other`], which contain every unit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap<'data, str, str>,
+
+    /// The patterns for dividing a formatted unit `{0}` by a unit, such as "{0}/h" for `hour`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+
+    /// The pattern for dividing a formatted unit `{0}` by a unit `{1}` without a per-unit
+    /// pattern, such as "{0}/{1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_pattern: Cow<'data, str>,
+}
+
+pub(crate) struct ErasedUnitsDisplayV1Marker;
+
+impl DataMarker for ErasedUnitsDisplayV1Marker {
+    type Yokeable = UnitsDisplayV1<'static>;
+}
+
+impl<'data> UnitsDisplayV1<'data> {
+    pub(crate) fn get(&self, unit: &str, category: PluralCategory) -> Option<&str> {
+        match category {
+            PluralCategory::Zero => self.zero.get(unit),
+            PluralCategory::One => self.one.get(unit),
+            PluralCategory::Two => self.two.get(unit),
+            PluralCategory::Few => self.few.get(unit),
+            PluralCategory::Many => self.many.get(unit),
+            PluralCategory::Other => None,
+        }
+        .or_else(|| self.other.get(unit))
+    }
+}
+
+/// An exact rational number, such as a conversion factor.
+#[zerovec::make_ule(RationalULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RationalV1 {
+    /// The numerator.
+    pub numerator: i128,
+    /// The denominator, which is positive.
+    pub denominator: i128,
+}
+
+impl RationalV1 {
+    /// Returns the [`Ratio`] equal to this number, or `None` if the denominator is zero.
+    pub fn to_ratio(self) -> Option<Ratio> {
+        Ratio::new(self.numerator, self.denominator)
+    }
+}
+
+impl From<Ratio> for RationalV1 {
+    fn from(other: Ratio) -> Self {
+        Self {
+            numerator: other.numerator(),
+            denominator: other.denominator(),
+        }
+    }
+}
+
+/// The conversions of units to their base units, from CLDR `units.xml`.
+///
+/// A value in a unit is converted to its base unit by multiplying it by the factor and adding
+/// the offset, such as 1.8 °F ↦ (1.8 × 5/9) + 2298.35/9 K.
+#[icu_provider::data_struct(UnitsInfoV1Marker = "units/info@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsInfoV1<'data> {
+    /// The base unit of each convertible unit, such as `meter` for `foot` or
+    /// `kilogram-square-meter-per-cubic-second` for `watt`. Base units map to themselves.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_units: ZeroMap<'data, str, str>,
+
+    /// The factors of units whose factor is not 1.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub factors: ZeroMap<'data, str, RationalV1>,
+
+    /// The offsets of units whose offset is not 0, such as `celsius`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub offsets: ZeroMap<'data, str, RationalV1>,
+
+    /// The quantity measured by base units, such as `length` for `meter` or `speed` for
+    /// `meter-per-second`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quantities: ZeroMap<'data, str, str>,
+}
+
+/// The units preferred in each region for each quantity and usage, from CLDR
+/// `unitPreferenceData`.
+///
+/// The preferences are keyed by quantity, usage and region, separated by slashes, such as
+/// `length/road/US`, and by their position in the list, such as mile, foot. The first unit in
+/// which the absolute value is at least its threshold is used, and the last unit otherwise.
+#[icu_provider::data_struct(UnitPreferencesV1Marker = "units/preferences@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPreferencesV1<'data> {
+    /// The preferred units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub units: ZeroMap2d<'data, str, u16, str>,
+
+    /// The thresholds of the preferred units, in the preferred unit, such as 0.5 for mile.
+    /// Thresholds of 1, the default in CLDR, are absent.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thresholds: ZeroMap2d<'data, str, u16, RationalV1>,
+}
@@ -0,0 +1,365 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use fixed_decimal::{FixedDecimal, Sign};
+
+/// An exact rational number, in lowest terms and with a positive denominator.
+///
+/// Unit conversions are done with rational numbers so that converting between units whose
+/// factors are exact in CLDR, such as feet and meters, does not lose precision. All arithmetic
+/// is checked, and returns `None` on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use icu_units::Ratio;
+///
+/// let foot = Ratio::new(3048, 10000).expect("nonzero denominator");
+/// assert_eq!(foot.numerator(), 381);
+/// assert_eq!(foot.denominator(), 1250);
+///
+/// let yard = foot.checked_mul(Ratio::from_integer(3)).expect("no overflow");
+/// assert_eq!(yard, Ratio::new(1143, 1250).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numerator: i128,
+    denominator: i128,
+}
+
+/// Returns the greatest common divisor of `a` and `b`, or `None` if it does not fit in an
+/// `i128`, which only happens if both are `i128::MIN`.
+fn gcd(a: i128, b: i128) -> Option<i128> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    i128::try_from(a).ok()
+}
+
+impl Ratio {
+    /// The number zero.
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// The number one.
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a new [`Ratio`] from a numerator and a denominator, reducing it to lowest terms.
+    ///
+    /// Returns `None` if the denominator is zero or the reduced ratio cannot be represented.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let gcd = gcd(numerator, denominator)?;
+        let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+        if denominator < 0 {
+            Some(Self {
+                numerator: numerator.checked_neg()?,
+                denominator: denominator.checked_neg()?,
+            })
+        } else {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    /// Creates a new [`Ratio`] equal to an integer.
+    pub fn from_integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Returns the numerator, which has the sign of the ratio.
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Returns whether the ratio is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Returns the negation of the ratio.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns the absolute value of the ratio.
+    pub fn checked_abs(self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_abs()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns the reciprocal of the ratio, or `None` if it is zero.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// Returns the sum of two ratios.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let gcd = gcd(self.denominator, other.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / gcd)?
+            .checked_add(other.numerator.checked_mul(self.denominator / gcd)?)?;
+        let denominator = (self.denominator / gcd).checked_mul(other.denominator)?;
+        Self::new(numerator, denominator)
+    }
+
+    /// Returns the difference of two ratios.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Returns the product of two ratios.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::ZERO);
+        }
+        // Reduce crosswise first to keep the intermediate values small.
+        let gcd1 = gcd(self.numerator, other.denominator)?;
+        let gcd2 = gcd(other.numerator, self.denominator)?;
+        let numerator = (self.numerator / gcd1).checked_mul(other.numerator / gcd2)?;
+        let denominator = (self.denominator / gcd2).checked_mul(other.denominator / gcd1)?;
+        Self::new(numerator, denominator)
+    }
+
+    /// Returns the quotient of two ratios, or `None` if `other` is zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.checked_recip()?)
+    }
+
+    /// Returns the ratio raised to an integer power. Negative powers are the powers of the
+    /// reciprocal.
+    pub fn checked_powi(self, exp: i8) -> Option<Self> {
+        let base = if exp < 0 { self.checked_recip()? } else { self };
+        (0..exp.unsigned_abs()).try_fold(Self::ONE, |acc, _| acc.checked_mul(base))
+    }
+
+    /// Creates a new [`Ratio`] equal to a [`FixedDecimal`], or `None` if it has too many digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_units::Ratio;
+    ///
+    /// let value: FixedDecimal = "-0.125".parse().unwrap();
+    /// assert_eq!(
+    ///     Ratio::try_from_fixed_decimal(&value),
+    ///     Ratio::new(-1, 8)
+    /// );
+    /// ```
+    pub fn try_from_fixed_decimal(value: &FixedDecimal) -> Option<Self> {
+        let lowest = value.nonzero_magnitude_right().min(0);
+        let digits = (lowest..=value.nonzero_magnitude_left().max(0))
+            .rev()
+            .try_fold(0i128, |acc, magnitude| {
+                acc.checked_mul(10)?
+                    .checked_add(value.digit_at(magnitude) as i128)
+            })?;
+        let numerator = if value.sign() == Sign::Negative {
+            -digits
+        } else {
+            digits
+        };
+        Self::new(numerator, 10i128.checked_pow(lowest.unsigned_abs() as u32)?)
+    }
+
+    /// Converts the ratio to a [`FixedDecimal`] rounded half-to-even at the given magnitude,
+    /// such as -2 for hundredths, with trailing zeros removed. Returns `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_units::Ratio;
+    ///
+    /// let third = Ratio::new(1, 3).unwrap();
+    /// assert_eq!(third.to_fixed_decimal(-3).unwrap().to_string(), "0.333");
+    ///
+    /// let eighth = Ratio::new(-1, 8).unwrap();
+    /// assert_eq!(eighth.to_fixed_decimal(-2).unwrap().to_string(), "-0.12");
+    /// assert_eq!(eighth.to_fixed_decimal(0).unwrap().to_string(), "0");
+    /// ```
+    pub fn to_fixed_decimal(&self, magnitude: i16) -> Option<FixedDecimal> {
+        let scaled = self.checked_mul(
+            Ratio::from_integer(10).checked_powi(i8::try_from(magnitude.checked_neg()?).ok()?)?,
+        )?;
+        let numerator = scaled.numerator.unsigned_abs();
+        let denominator = scaled.denominator.unsigned_abs();
+        let (mut quotient, remainder) = (numerator / denominator, numerator % denominator);
+        let rest = denominator - remainder;
+        if remainder > rest || (remainder == rest && quotient % 2 == 1) {
+            quotient += 1;
+        }
+        let mut result = FixedDecimal::from(quotient).multiplied_pow10(magnitude);
+        result.trim_start();
+        result.trim_end();
+        if scaled.numerator < 0 && quotient != 0 {
+            result.set_sign(Sign::Negative);
+        }
+        Some(result)
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i128> for Ratio {
+    fn from(value: i128) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+/// Compares `a / b` with `c / d`, where `b` and `d` are positive, without overflowing, by
+/// comparing their continued fractions.
+fn compare(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+    let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+    match (q1.cmp(&q2), r1, r2) {
+        (Ordering::Equal, 0, 0) => Ordering::Equal,
+        (Ordering::Equal, 0, _) => Ordering::Less,
+        (Ordering::Equal, _, 0) => Ordering::Greater,
+        // r1 / b < r2 / d if and only if d / r2 < b / r1.
+        (Ordering::Equal, _, _) => compare(d, r2, b, r1),
+        (ordering, _, _) => ordering,
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(
+            self.numerator,
+            self.denominator,
+            other.numerator,
+            other.denominator,
+        )
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn ratio(numerator: i128, denominator: i128) -> Ratio {
+        Ratio::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(ratio(6, -4), ratio(-3, 2));
+        assert_eq!(ratio(-6, -4).numerator(), 3);
+        assert_eq!(ratio(0, -5), Ratio::ZERO);
+        assert_eq!(Ratio::new(1, 0), None);
+        assert_eq!(Ratio::new(i128::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = ratio(1, 6);
+        let b = ratio(3, 4);
+        assert_eq!(a.checked_add(b), Some(ratio(11, 12)));
+        assert_eq!(a.checked_sub(b), Some(ratio(-7, 12)));
+        assert_eq!(a.checked_mul(b), Some(ratio(1, 8)));
+        assert_eq!(a.checked_div(b), Some(ratio(2, 9)));
+        assert_eq!(a.checked_div(Ratio::ZERO), None);
+        assert_eq!(b.checked_powi(2), Some(ratio(9, 16)));
+        assert_eq!(b.checked_powi(-3), Some(ratio(64, 27)));
+        assert_eq!(b.checked_powi(0), Some(Ratio::ONE));
+        assert_eq!(Ratio::from_integer(10).checked_powi(40), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(ratio(1, 3) < ratio(1, 2));
+        assert!(ratio(-1, 2) < ratio(-1, 3));
+        assert!(ratio(7, 5) > ratio(4, 3));
+        assert!(ratio(i128::MAX, 3) > ratio(i128::MAX - 1, 3));
+        assert!(ratio(i128::MAX - 1, i128::MAX) > ratio(i128::MAX - 2, i128::MAX - 1));
+        assert_eq!(ratio(2, 4).cmp(&ratio(1, 2)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_fixed_decimal() {
+        for (input, expected) in [
+            ("0", Ratio::ZERO),
+            ("12.5", ratio(25, 2)),
+            ("-0.004", ratio(-1, 250)),
+            ("3000", Ratio::from_integer(3000)),
+        ] {
+            let value: FixedDecimal = input.parse().unwrap();
+            assert_eq!(
+                Ratio::try_from_fixed_decimal(&value),
+                Some(expected),
+                "{}",
+                input
+            );
+        }
+
+        for (value, magnitude, expected) in [
+            (ratio(2, 3), -2, "0.67"),
+            (ratio(5, 2), 0, "2"),
+            (ratio(7, 2), 0, "4"),
+            (ratio(-5, 2), 0, "-2"),
+            (ratio(-1, 3), 0, "0"),
+            (ratio(1, 2), -3, "0.5"),
+            (ratio(12345, 1), 2, "12300"),
+            (ratio(15, 1), 1, "20"),
+        ] {
+            assert_eq!(
+                value.to_fixed_decimal(magnitude).unwrap().to_string(),
+                expected,
+                "{} {}",
+                value,
+                magnitude
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::{locale, Locale};
+use icu_units::options::{UnitsFormatterOptions, Width};
+use icu_units::{UnitsError, UnitsFormatter};
+use writeable::assert_writeable_eq;
+
+fn units_formatter(locale: &Locale, unit: &str, width: Width) -> UnitsFormatter {
+    let mut options = UnitsFormatterOptions::default();
+    options.width = width;
+    UnitsFormatter::try_new_unstable(
+        &icu_testdata::get_baked_provider(),
+        &locale.into(),
+        unit,
+        options,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_simple_unit() {
+    let formatter = units_formatter(&locale!("en"), "kilometer", Width::Long);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(1)), "1 kilometer");
+    assert_writeable_eq!(
+        formatter.format(&FixedDecimal::from(1234)),
+        "1,234 kilometers"
+    );
+    assert_writeable_eq!(formatter.format(&"1.5".parse().unwrap()), "1.5 kilometers");
+
+    let formatter = units_formatter(&locale!("en"), "kilometer", Width::Short);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(3)), "3 km");
+
+    let formatter = units_formatter(&locale!("en"), "kilometer", Width::Narrow);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(3)), "3km");
+}
+
+#[test]
+fn test_compound_unit() {
+    let formatter = units_formatter(&locale!("en"), "kilometer-per-hour", Width::Short);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(50)), "50 km/h");
+
+    let formatter = units_formatter(&locale!("en"), "liter-per-hour", Width::Short);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(2)), "2 L/h");
+
+    let formatter = units_formatter(&locale!("en"), "liter-per-hour", Width::Long);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(1)), "1 liter per hour");
+    assert_writeable_eq!(
+        formatter.format(&FixedDecimal::from(2)),
+        "2 liters per hour"
+    );
+
+    let formatter = units_formatter(&locale!("en"), "kilometer-per-minute", Width::Narrow);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(2)), "2km/min");
+}
+
+#[test]
+fn test_localized_unit() {
+    let formatter = units_formatter(&locale!("fr"), "kilometer", Width::Long);
+    assert_writeable_eq!(
+        formatter.format(&"1.5".parse().unwrap()),
+        "1,5\u{a0}kilomètre"
+    );
+    assert_writeable_eq!(
+        formatter.format(&FixedDecimal::from(2)),
+        "2\u{a0}kilomètres"
+    );
+
+    let formatter = units_formatter(&locale!("ru"), "hour", Width::Long);
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(2)), "2 часа");
+    assert_writeable_eq!(formatter.format(&FixedDecimal::from(5)), "5 часов");
+}
+
+#[test]
+fn test_unknown_unit() {
+    for unit in ["smoot", "kilometer-per-smoot", "smoot-per-hour"] {
+        assert!(matches!(
+            UnitsFormatter::try_new_unstable(
+                &icu_testdata::get_baked_provider(),
+                &locale!("en").into(),
+                unit,
+                Default::default()
+            ),
+            Err(UnitsError::UnknownUnit)
+        ));
+    }
+}
@@ -22,7 +22,11 @@ use icu_timezone::provider::*;
 #[cfg(feature = "experimental")]
 use icu_casemapping::provider::*;
 #[cfg(feature = "experimental")]
+use icu_relativetime::provider::*;
+#[cfg(feature = "experimental")]
 use icu_segmenter::provider::*;
+#[cfg(feature = "experimental")]
+use icu_units::provider::*;
 
 macro_rules! registry {
     ($($marker:ident,)+ #[cfg(feature = "experimental")] { $($exp_marker:ident,)+ }) => {
@@ -98,8 +102,14 @@ registry!(
     CompatibilityDecompositionTablesV1Marker,
     CopticDateLengthsV1Marker,
     CopticDateSymbolsV1Marker,
+    CurrencyFractionsV1Marker,
+    CurrencyPatternsV1Marker,
+    CurrencySymbolsV1Marker,
     DashV1Marker,
+    DateIntervalFormatsV1Marker,
+    DateNumberingOverridesV1Marker,
     DateSkeletonPatternsV1Marker,
+    DayPeriodsV1Marker,
     DecimalSymbolsV1Marker,
     DefaultIgnorableCodePointV1Marker,
     DeprecatedV1Marker,
@@ -124,6 +134,8 @@ registry!(
     GraphV1Marker,
     GregorianDateLengthsV1Marker,
     GregorianDateSymbolsV1Marker,
+    HebrewDateLengthsV1Marker,
+    HebrewDateSymbolsV1Marker,
     HexDigitV1Marker,
     HyphenV1Marker,
     IdContinueV1Marker,
@@ -145,6 +157,7 @@ registry!(
     LocaleFallbackLikelySubtagsV1Marker,
     LocaleFallbackParentsV1Marker,
     LogicalOrderExceptionV1Marker,
+    LongCompactDecimalFormatDataV1Marker,
     LowercaseV1Marker,
     MathV1Marker,
     MetaZoneGenericNamesLongV1Marker,
@@ -162,16 +175,24 @@ registry!(
     OrListV1Marker,
     PatternSyntaxV1Marker,
     PatternWhiteSpaceV1Marker,
+    PercentPatternsV1Marker,
+    PersianDateLengthsV1Marker,
+    PersianDateSymbolsV1Marker,
     PrependedConcatenationMarkV1Marker,
     PrintV1Marker,
+    QuarterSymbolsV1Marker,
     QuotationMarkV1Marker,
     RadicalV1Marker,
     RegionalIndicatorV1Marker,
+    RocDateLengthsV1Marker,
+    RocDateSymbolsV1Marker,
+    ScientificSymbolsV1Marker,
     ScriptV1Marker,
     ScriptWithExtensionsPropertyV1Marker,
     SegmentStarterV1Marker,
     SentenceBreakV1Marker,
     SentenceTerminalV1Marker,
+    ShortCompactDecimalFormatDataV1Marker,
     SoftDottedV1Marker,
     TerminalPunctuationV1Marker,
     TimeLengthsV1Marker,
@@ -192,12 +213,25 @@ registry!(
     #[cfg(feature = "experimental")]
     {
         CaseMappingV1Marker,
+        DayRelativeTimeFormatDataV1Marker,
         GraphemeClusterBreakDataV1Marker,
+        HourRelativeTimeFormatDataV1Marker,
         LineBreakDataV1Marker,
+        LongUnitsDisplayV1Marker,
         LstmDataV1Marker,
+        MinuteRelativeTimeFormatDataV1Marker,
+        MonthRelativeTimeFormatDataV1Marker,
+        NarrowUnitsDisplayV1Marker,
+        QuarterRelativeTimeFormatDataV1Marker,
+        SecondRelativeTimeFormatDataV1Marker,
         SentenceBreakDataV1Marker,
+        ShortUnitsDisplayV1Marker,
         UCharDictionaryBreakDataV1Marker,
+        UnitPreferencesV1Marker,
+        UnitsInfoV1Marker,
+        WeekRelativeTimeFormatDataV1Marker,
         WordBreakDataV1Marker,
+        YearRelativeTimeFormatDataV1Marker,
     }
 );
 
@@ -66,6 +66,20 @@ symbols!(
     }
 );
 
+symbols!(
+    quarters,
+    pub struct Symbols {
+        #[serde(rename = "1")]
+        pub q1: String,
+        #[serde(rename = "2")]
+        pub q2: String,
+        #[serde(rename = "3")]
+        pub q3: String,
+        #[serde(rename = "4")]
+        pub q4: String,
+    }
+);
+
 // The day period symbols are Cow<'static, str> instead of String because the Option
 // needs to be retained when converting them into Cow for the data provider.
 symbols!(
@@ -75,6 +89,14 @@ symbols!(
         pub pm: Cow<'static, str>,
         pub noon: Option<Cow<'static, str>>,
         pub midnight: Option<Cow<'static, str>>,
+        pub morning1: Option<Cow<'static, str>>,
+        pub morning2: Option<Cow<'static, str>>,
+        pub afternoon1: Option<Cow<'static, str>>,
+        pub afternoon2: Option<Cow<'static, str>>,
+        pub evening1: Option<Cow<'static, str>>,
+        pub evening2: Option<Cow<'static, str>>,
+        pub night1: Option<Cow<'static, str>>,
+        pub night2: Option<Cow<'static, str>>,
     }
 );
 
@@ -126,11 +148,21 @@ pub struct DateTimeFormats {
     pub short: LengthPattern,
     #[serde(rename = "availableFormats")]
     pub available_formats: AvailableFormats,
+    #[serde(rename = "intervalFormats")]
+    pub interval_formats: IntervalFormats,
 }
 
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub struct AvailableFormats(pub HashMap<String, String>);
 
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+pub struct IntervalFormats {
+    #[serde(rename = "intervalFormatFallback")]
+    pub fallback: String,
+    #[serde(flatten)]
+    pub formats: HashMap<String, HashMap<String, String>>,
+}
+
 /// This struct represents a 1:1 mapping of the CLDR ca-gregorian.json data at the key
 /// "main.LANGID.dates.calendars.gregorian" where "LANGID" is the identifier.
 ///
@@ -140,6 +172,7 @@ pub struct AvailableFormats(pub HashMap<String, String>);
 pub struct Dates {
     pub months: months::Contexts,
     pub days: days::Contexts,
+    pub quarters: quarters::Contexts,
     pub eras: Eras,
     #[serde(rename = "dayPeriods")]
     pub day_periods: day_periods::Contexts,
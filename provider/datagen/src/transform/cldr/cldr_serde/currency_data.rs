@@ -0,0 +1,47 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON currencyData.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/currencyData.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The fraction settings of a currency. Cash settings default to the standard ones.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Fraction {
+    #[serde(rename = "_digits")]
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
+    pub digits: u8,
+    #[serde(rename = "_rounding")]
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
+    pub rounding: u8,
+    #[serde(rename = "_cashDigits")]
+    #[serde(default)]
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_option_number_from_string")]
+    pub cash_digits: Option<u8>,
+    #[serde(rename = "_cashRounding")]
+    #[serde(default)]
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_option_number_from_string")]
+    pub cash_rounding: Option<u8>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyData {
+    /// The fraction settings, keyed by ISO 4217 code or "DEFAULT".
+    pub fractions: HashMap<String, Fraction>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "currencyData")]
+    pub currency_data: CurrencyData,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dateFields.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-dates-full/main/en/dateFields.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PluralRulesPattern {
+    #[serde(rename = "relativeTimePattern-count-zero")]
+    pub zero: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-one")]
+    pub one: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-two")]
+    pub two: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-few")]
+    pub few: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-many")]
+    pub many: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-other")]
+    pub other: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Field {
+    #[serde(rename = "relativeTime-type-past")]
+    pub past: PluralRulesPattern,
+    #[serde(rename = "relativeTime-type-future")]
+    pub future: PluralRulesPattern,
+    /// All other entries, including the names of relative amounts such as
+    /// `"relative-type--1": "yesterday"`.
+    #[serde(flatten)]
+    pub others: HashMap<String, String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Fields {
+    pub second: Field,
+    #[serde(rename = "second-short")]
+    pub second_short: Field,
+    #[serde(rename = "second-narrow")]
+    pub second_narrow: Field,
+    pub minute: Field,
+    #[serde(rename = "minute-short")]
+    pub minute_short: Field,
+    #[serde(rename = "minute-narrow")]
+    pub minute_narrow: Field,
+    pub hour: Field,
+    #[serde(rename = "hour-short")]
+    pub hour_short: Field,
+    #[serde(rename = "hour-narrow")]
+    pub hour_narrow: Field,
+    pub day: Field,
+    #[serde(rename = "day-short")]
+    pub day_short: Field,
+    #[serde(rename = "day-narrow")]
+    pub day_narrow: Field,
+    pub week: Field,
+    #[serde(rename = "week-short")]
+    pub week_short: Field,
+    #[serde(rename = "week-narrow")]
+    pub week_narrow: Field,
+    pub month: Field,
+    #[serde(rename = "month-short")]
+    pub month_short: Field,
+    #[serde(rename = "month-narrow")]
+    pub month_narrow: Field,
+    pub quarter: Field,
+    #[serde(rename = "quarter-short")]
+    pub quarter_short: Field,
+    #[serde(rename = "quarter-narrow")]
+    pub quarter_narrow: Field,
+    pub year: Field,
+    #[serde(rename = "year-short")]
+    pub year_short: Field,
+    #[serde(rename = "year-narrow")]
+    pub year_narrow: Field,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Dates {
+    pub fields: Fields,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangDates {
+    pub dates: Dates,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangDates>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
@@ -0,0 +1,41 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dayPeriods.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/dayPeriods.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The time span of a day period, as times of the form "HH:MM".
+///
+/// A period is either a single point in time ("at"), such as noon,
+/// or a span starting at "from" and ending before "before", which may wrap around midnight.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Rule {
+    #[serde(rename = "_at")]
+    pub at: Option<String>,
+    #[serde(rename = "_from")]
+    pub from: Option<String>,
+    #[serde(rename = "_before")]
+    pub before: Option<String>,
+}
+
+/// The rules of a language, keyed by day period name, such as "morning1".
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct RuleSet(pub HashMap<String, Rule>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// The rule sets, keyed by language, such as "en", "zh_Hant" or "root".
+    #[serde(rename = "dayPeriodRuleSet")]
+    pub day_period_rule_set: HashMap<String, RuleSet>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -9,6 +9,10 @@
 
 pub mod aliases;
 pub mod ca;
+pub mod currencies;
+pub mod currency_data;
+pub mod date_fields;
+pub mod day_periods;
 pub mod japanese;
 pub mod likely_subtags;
 pub mod list_patterns;
@@ -17,4 +21,6 @@ pub mod numbers;
 pub mod parent_locales;
 pub mod plurals;
 pub mod time_zones;
+pub mod units;
+pub mod units_data;
 pub mod week_data;
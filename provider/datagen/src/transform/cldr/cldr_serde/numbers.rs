@@ -23,11 +23,67 @@ pub struct Symbols {
     pub minus_sign: String,
     #[serde(rename = "plusSign")]
     pub plus_sign: String,
+    pub exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub superscripting_exponent: String,
+    #[serde(rename = "percentSign")]
+    pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CompactDecimalFormats {
+    /// Map from pattern key, such as "1000-count-one", to pattern
+    #[serde(rename = "decimalFormat")]
+    pub patterns: HashMap<String, String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct DecimalFormats {
     pub standard: String,
+    pub long: Option<CompactDecimalFormats>,
+    pub short: Option<CompactDecimalFormats>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PercentFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacingPattern {
+    #[serde(rename = "insertBetween")]
+    pub insert_between: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacing {
+    #[serde(rename = "beforeCurrency")]
+    pub before_currency: CurrencySpacingPattern,
+    #[serde(rename = "afterCurrency")]
+    pub after_currency: CurrencySpacingPattern,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyFormats {
+    // This list is not comprehensive; add more fields when needed
+    pub standard: String,
+    pub accounting: String,
+    #[serde(rename = "currencySpacing")]
+    pub currency_spacing: CurrencySpacing,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: String,
 }
 
 #[derive(PartialEq, Debug, Default)]
@@ -36,6 +92,10 @@ pub struct NumberingSystemData {
     pub symbols: HashMap<TinyStr8, Symbols>,
     /// Map from numbering system to decimal formats
     pub formats: HashMap<TinyStr8, DecimalFormats>,
+    /// Map from numbering system to percent formats
+    pub percent_formats: HashMap<TinyStr8, PercentFormats>,
+    /// Map from numbering system to currency formats
+    pub currency_formats: HashMap<TinyStr8, CurrencyFormats>,
 }
 
 pub struct NumberingSystemDataVisitor;
@@ -71,8 +131,16 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: DecimalFormats = access.next_value()?;
                     result.formats.insert(numsys, value);
                 }
+                "percentFormats" => {
+                    let value: PercentFormats = access.next_value()?;
+                    result.percent_formats.insert(numsys, value);
+                }
+                "currencyFormats" => {
+                    let value: CurrencyFormats = access.next_value()?;
+                    result.currency_formats.insert(numsys, value);
+                }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume "scientificFormats", "miscPatterns", ...
                     // For now, ignore them.
                 }
             }
@@ -0,0 +1,39 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/en/units.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The patterns of a unit or of a compound pattern such as `per`, keyed by their CLDR name,
+/// such as "unitPattern-count-one" or "compoundUnitPattern".
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Patterns(pub HashMap<String, String>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    /// The patterns in each width, keyed by unit identifier with its category, such as
+    /// "length-kilometer", or by compound pattern, such as "per".
+    pub long: HashMap<String, Patterns>,
+    pub short: HashMap<String, Patterns>,
+    pub narrow: HashMap<String, Patterns>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangUnits {
+    pub units: Units,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangUnits>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
@@ -0,0 +1,82 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json and unitPreferenceData.json files.
+//!
+//! Sample files:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/units.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/unitPreferenceData.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Constant {
+    /// An expression such as "12*in_to_m".
+    #[serde(rename = "_value")]
+    pub value: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Quantity {
+    #[serde(rename = "_quantity")]
+    pub quantity: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Conversion {
+    #[serde(rename = "_baseUnit")]
+    pub base_unit: String,
+    /// An expression such as "ft2_to_m2*43560". Defaults to 1.
+    #[serde(rename = "_factor")]
+    pub factor: Option<String>,
+    /// An expression such as "2298.35/9". Defaults to 0.
+    #[serde(rename = "_offset")]
+    pub offset: Option<String>,
+    /// The name of a nonlinear conversion, such as "beaufort".
+    #[serde(rename = "_special")]
+    pub special: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitsData {
+    #[serde(rename = "unitConstants")]
+    pub unit_constants: HashMap<String, Constant>,
+    /// The quantities, keyed by base unit.
+    #[serde(rename = "unitQuantities")]
+    pub unit_quantities: HashMap<String, Quantity>,
+    /// The conversions to base units, keyed by unit.
+    #[serde(rename = "convertUnits")]
+    pub convert_units: HashMap<String, Conversion>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: UnitsData,
+}
+
+/// A number, which recent CLDR versions store as a JSON number rather than a string.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Number(
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_string_from_number")] pub String,
+);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Preference {
+    pub unit: String,
+    /// The threshold in the unit, such as 0.5. Defaults to 1.
+    pub geq: Option<Number>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PreferenceSupplemental {
+    /// The preferences, keyed by quantity, usage and region.
+    #[serde(rename = "unitPreferenceData")]
+    pub unit_preference_data: HashMap<String, HashMap<String, HashMap<String, Vec<Preference>>>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PreferenceResource {
+    pub supplemental: PreferenceSupplemental,
+}
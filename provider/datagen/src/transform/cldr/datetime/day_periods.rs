@@ -0,0 +1,207 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_datetime::provider::calendar::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use zerovec::ZeroVec;
+
+impl IterableDataProvider<DayPeriodsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .dates("gregorian")
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl DataProvider<DayPeriodsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DayPeriodsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::ca::Resource = self
+            .source
+            .cldr()?
+            .dates("gregorian")
+            .read_and_parse(&langid, "ca-gregorian.json")?;
+        let symbols = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .dates
+            .calendars
+            .get("gregorian")
+            .expect("CLDR file contains a gregorian calendar")
+            .day_periods;
+
+        let rules: &cldr_serde::day_periods::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/dayPeriods.json")?;
+        let rule_set = find_rule_set(&rules.supplemental.day_period_rule_set, &langid)?;
+
+        let mut hours = [None; 24];
+        let mut noon = false;
+        let mut midnight = false;
+        for (name, rule) in rule_set.0.iter() {
+            let day_period = match name.as_str() {
+                "noon" => {
+                    noon = true;
+                    continue;
+                }
+                "midnight" => {
+                    midnight = true;
+                    continue;
+                }
+                "am" => FlexibleDayPeriod::Am,
+                "pm" => FlexibleDayPeriod::Pm,
+                "morning1" => FlexibleDayPeriod::Morning1,
+                "morning2" => FlexibleDayPeriod::Morning2,
+                "afternoon1" => FlexibleDayPeriod::Afternoon1,
+                "afternoon2" => FlexibleDayPeriod::Afternoon2,
+                "evening1" => FlexibleDayPeriod::Evening1,
+                "evening2" => FlexibleDayPeriod::Evening2,
+                "night1" => FlexibleDayPeriod::Night1,
+                "night2" => FlexibleDayPeriod::Night2,
+                _ => {
+                    return Err(DataError::custom("Unknown day period in dayPeriods.json")
+                        .with_display_context(name))
+                }
+            };
+            let (from, before) = match (&rule.from, &rule.before) {
+                (Some(from), Some(before)) => (parse_hour(from)?, parse_hour(before)? % 24),
+                _ => {
+                    return Err(DataError::custom("Day period without a time span")
+                        .with_display_context(name))
+                }
+            };
+            // Spans may wrap around midnight, such as night1 from 21:00 before 06:00.
+            let mut hour = from;
+            loop {
+                if let Some(slot) = hours.get_mut(hour) {
+                    *slot = Some(day_period);
+                }
+                hour = (hour + 1) % 24;
+                if hour == before {
+                    break;
+                }
+            }
+        }
+
+        let hours = hours
+            .iter()
+            .map(|day_period| {
+                day_period.ok_or_else(|| {
+                    DataError::custom("Day period rules do not cover the whole day")
+                        .with_display_context(&langid)
+                })
+            })
+            .collect::<Result<ZeroVec<_>, _>>()?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(DayPeriodsV1 {
+                hours,
+                noon,
+                midnight,
+                // The `B` field has no stand-alone form, so only format names are needed.
+                symbols: flexible_day_periods::ContextsV1 {
+                    format: flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: convert_symbols(&symbols.format.abbreviated),
+                        narrow: convert_symbols(&symbols.format.narrow),
+                        short: symbols.format.short.as_ref().map(convert_symbols),
+                        wide: convert_symbols(&symbols.format.wide),
+                    },
+                    stand_alone: None,
+                },
+            })),
+        })
+    }
+}
+
+/// Finds the rule set for the language and script of `langid`, falling back to the root rules.
+fn find_rule_set<'a>(
+    rule_sets: &'a std::collections::HashMap<String, cldr_serde::day_periods::RuleSet>,
+    langid: &LanguageIdentifier,
+) -> Result<&'a cldr_serde::day_periods::RuleSet, DataError> {
+    langid
+        .script
+        .and_then(|script| rule_sets.get(&format!("{}_{}", langid.language, script)))
+        .or_else(|| rule_sets.get(langid.language.as_str()))
+        .or_else(|| rule_sets.get("root"))
+        .ok_or_else(|| DataError::custom("Missing root rules in dayPeriods.json"))
+}
+
+/// Parses the hour of a time of the form "HH:MM". Day periods must start on the hour.
+fn parse_hour(time: &str) -> Result<usize, DataError> {
+    match time.split_once(':') {
+        Some((hour, "00")) => hour.parse().ok().filter(|&hour| hour <= 24),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        DataError::custom("Unsupported time in dayPeriods.json").with_display_context(time)
+    })
+}
+
+fn convert_symbols(
+    symbols: &cldr_serde::ca::day_periods::Symbols,
+) -> flexible_day_periods::SymbolsV1<'static> {
+    flexible_day_periods::SymbolsV1 {
+        morning1: symbols.morning1.clone(),
+        morning2: symbols.morning2.clone(),
+        afternoon1: symbols.afternoon1.clone(),
+        afternoon2: symbols.afternoon2.clone(),
+        evening1: symbols.evening1.clone(),
+        evening2: symbols.evening2.clone(),
+        night1: symbols.night1.clone(),
+        night2: symbols.night2.clone(),
+    }
+}
+
+#[test]
+fn basic_cldr_day_periods() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let en: DataPayload<DayPeriodsV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let en = en.get();
+    assert!(en.noon);
+    assert!(en.midnight);
+    assert_eq!(en.hours.get(5), Some(FlexibleDayPeriod::Night1));
+    assert_eq!(en.hours.get(6), Some(FlexibleDayPeriod::Morning1));
+    assert_eq!(en.hours.get(20), Some(FlexibleDayPeriod::Evening1));
+    assert_eq!(
+        en.symbols.format.wide.get(FlexibleDayPeriod::Morning1),
+        Some("in the morning")
+    );
+
+    // Languages without their own rules fall back to AM and PM.
+    let und: DataPayload<DayPeriodsV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("und").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let und = und.get();
+    assert!(!und.noon);
+    assert_eq!(und.hours.get(11), Some(FlexibleDayPeriod::Am));
+    assert_eq!(und.hours.get(12), Some(FlexibleDayPeriod::Pm));
+}
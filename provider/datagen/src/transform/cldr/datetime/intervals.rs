@@ -0,0 +1,52 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_datetime::pattern::runtime::{GenericPattern, Pattern};
+use icu_datetime::provider::calendar::*;
+use icu_datetime::skeleton::reference::Skeleton;
+use icu_datetime::skeleton::SkeletonError;
+use std::convert::TryFrom;
+use zerovec::ZeroMap2d;
+
+impl From<&cldr_serde::ca::Dates> for DateIntervalFormatsV1<'_> {
+    fn from(other: &cldr_serde::ca::Dates) -> Self {
+        let interval_formats = &other.datetime_formats.interval_formats;
+
+        let fallback: GenericPattern = interval_formats
+            .fallback
+            .parse()
+            .expect("Unable to parse the interval fallback pattern");
+
+        let mut patterns = ZeroMap2d::new();
+        for (skeleton_str, letters) in interval_formats.formats.iter() {
+            match Skeleton::try_from(skeleton_str.as_str()) {
+                Ok(_) => {}
+                Err(SkeletonError::SymbolUnimplemented(_)) => continue,
+                Err(SkeletonError::SkeletonHasVariant) => continue,
+                Err(err) => panic!(
+                    "Unexpected skeleton error while parsing skeleton {:?} {}",
+                    skeleton_str, err
+                ),
+            }
+            for (letter_str, pattern_str) in letters.iter() {
+                // The keys are the pattern letter of the greatest differing field, possibly
+                // followed by a variant, such as "-alt-variant", which we do not support.
+                let mut chars = letter_str.chars();
+                let letter = match (chars.next(), chars.next()) {
+                    (Some(letter), None) => letter,
+                    _ => continue,
+                };
+                let pattern: Pattern = match pattern_str.parse() {
+                    Ok(pattern) => pattern,
+                    // Patterns with unimplemented fields, such as "B", are skipped.
+                    Err(_) => continue,
+                };
+                patterns.insert(skeleton_str.as_str(), &letter, &*pattern.items);
+            }
+        }
+
+        Self { fallback, patterns }
+    }
+}
@@ -13,6 +13,9 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+mod day_periods;
+mod intervals;
+mod numbering;
 mod patterns;
 mod skeletons;
 mod symbols;
@@ -28,6 +31,9 @@ lazy_static! {
         (value!("coptic"), "coptic"),
         (value!("indian"), "indian"),
         (value!("ethiopic"), "ethiopic"),
+        (value!("hebrew"), "hebrew"),
+        (value!("persian"), "persian"),
+        (value!("roc"), "roc"),
     ]
     .into_iter()
     .collect();
@@ -245,16 +251,41 @@ impl_data_provider!(
         symbols::convert_dates,
         calendared = "ethiopic"
     ),
+    (
+        HebrewDateSymbolsV1Marker,
+        symbols::convert_dates,
+        calendared = "hebrew"
+    ),
+    (
+        PersianDateSymbolsV1Marker,
+        symbols::convert_dates,
+        calendared = "persian"
+    ),
+    (
+        RocDateSymbolsV1Marker,
+        symbols::convert_dates,
+        calendared = "roc"
+    ),
     (
         TimeSymbolsV1Marker,
         |dates, _| { symbols::convert_times(dates) },
         calendared = "false"
     ),
+    (
+        QuarterSymbolsV1Marker,
+        |dates, _| { symbols::convert_quarters(dates) },
+        calendared = "false"
+    ),
     (
         DateSkeletonPatternsV1Marker,
         |dates, _| { DateSkeletonPatternsV1::from(dates) },
         calendared = "locale"
     ),
+    (
+        DateIntervalFormatsV1Marker,
+        |dates, _| { DateIntervalFormatsV1::from(dates) },
+        calendared = "locale"
+    ),
     (
         GregorianDateLengthsV1Marker,
         |dates, _| DateLengthsV1::from(dates),
@@ -290,6 +321,21 @@ impl_data_provider!(
         |dates, _| DateLengthsV1::from(dates),
         calendared = "ethiopic"
     ),
+    (
+        HebrewDateLengthsV1Marker,
+        |dates, _| DateLengthsV1::from(dates),
+        calendared = "hebrew"
+    ),
+    (
+        PersianDateLengthsV1Marker,
+        |dates, _| DateLengthsV1::from(dates),
+        calendared = "persian"
+    ),
+    (
+        RocDateLengthsV1Marker,
+        |dates, _| DateLengthsV1::from(dates),
+        calendared = "roc"
+    ),
     (
         TimeLengthsV1Marker,
         |dates, _| TimeLengthsV1::from(dates),
@@ -336,7 +382,7 @@ mod test {
             .expect("Failed to retrieve payload");
 
         assert_eq!("d MMM y", cs_dates.get().date.medium.to_string());
-        // TODO(#308): Support numbering system variations. We currently throw them away.
+        // Algorithmic numbering system variations, such as `M=romanlow`, are not supported.
         assert_eq!("d/M/yy", cs_dates.get().date.short.to_string());
     }
 
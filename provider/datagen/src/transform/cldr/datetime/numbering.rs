@@ -0,0 +1,179 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::SUPPORTED_CALS;
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::numbering_systems::NumberingSystemType;
+use icu_datetime::pattern::runtime::Pattern;
+use icu_datetime::pattern::PatternItem;
+use icu_datetime::provider::calendar::*;
+use icu_locid::extensions_unicode_key as key;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+impl DataProvider<DateNumberingOverridesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DateNumberingOverridesV1Marker>, DataError> {
+        if req.locale.is_empty() {
+            return Err(DataErrorKind::NeedsLocale.into_error());
+        }
+
+        let langid = req.locale.get_langid();
+        let calendar = req
+            .locale
+            .get_unicode_ext(&key!("ca"))
+            .ok_or_else(|| DataErrorKind::MissingLocale.into_error())?;
+        let cldr_cal = SUPPORTED_CALS
+            .get(&calendar)
+            .ok_or_else(|| DataErrorKind::MissingLocale.into_error())?;
+
+        let resource: &cldr_serde::ca::Resource = self
+            .source
+            .cldr()?
+            .dates(cldr_cal)
+            .read_and_parse(&langid, &format!("ca-{}.json", cldr_cal))?;
+        let date_formats = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .dates
+            .calendars
+            .get(*cldr_cal)
+            .expect("CLDR file contains the expected calendar")
+            .date_formats;
+
+        let numbering_systems: &cldr_serde::numbering_systems::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/numberingSystems.json")?;
+        // Algorithmic numbering systems, such as `jpanyear`, can't be formatted with decimal data.
+        let is_numeric = |numbering_system: &TinyAsciiStr<8>| {
+            matches!(
+                numbering_system_type(numbering_systems, numbering_system),
+                Some(NumberingSystemType::Numeric)
+            )
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(DateNumberingOverridesV1 {
+                full: convert_overrides(&date_formats.full, is_numeric),
+                long: convert_overrides(&date_formats.long, is_numeric),
+                medium: convert_overrides(&date_formats.medium, is_numeric),
+                short: convert_overrides(&date_formats.short, is_numeric),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<DateNumberingOverridesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        // The overrides are calendared the same way as the skeletons.
+        IterableDataProvider::<DateSkeletonPatternsV1Marker>::supported_locales(self)
+    }
+}
+
+fn numbering_system_type<'a>(
+    resource: &'a cldr_serde::numbering_systems::Resource,
+    numbering_system: &TinyAsciiStr<8>,
+) -> Option<&'a NumberingSystemType> {
+    resource
+        .supplemental
+        .numbering_systems
+        .get(numbering_system)
+        .map(|ns| &ns.nstype)
+}
+
+/// Converts the `_numbers` attribute of a pattern, such as `d=hanidec` or `hanidec`, into a map
+/// from pattern characters to numbering systems. A numbering system without a pattern character
+/// applies to all the fields of the pattern.
+fn convert_overrides(
+    pattern: &cldr_serde::ca::LengthPattern,
+    is_numeric: impl Fn(&TinyAsciiStr<8>) -> bool,
+) -> ZeroMap<'static, char, TinyAsciiStr<8>> {
+    let (pattern, numbering_systems) = match pattern {
+        cldr_serde::ca::LengthPattern::Plain(_) => return ZeroMap::new(),
+        cldr_serde::ca::LengthPattern::WithNumberingSystems {
+            pattern,
+            numbering_systems,
+        } => (pattern, numbering_systems),
+    };
+
+    let mut overrides = BTreeMap::new();
+    for numbering_system in numbering_systems.split(';') {
+        let (chars, numbering_system) = match numbering_system.split_once('=') {
+            Some((ch, numbering_system)) => (ch.chars().collect(), numbering_system),
+            None => (pattern_chars(pattern), numbering_system),
+        };
+        let numbering_system = TinyAsciiStr::<8>::from_str(numbering_system.trim())
+            .expect("CLDR numbering systems fit in 8 bytes");
+        if !is_numeric(&numbering_system) {
+            continue;
+        }
+        for ch in chars {
+            overrides.insert(ch, numbering_system);
+        }
+    }
+    overrides.into_iter().collect()
+}
+
+fn pattern_chars(pattern: &str) -> Vec<char> {
+    let pattern: Pattern = pattern.parse().expect("Failed to parse pattern");
+    pattern
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            PatternItem::Field(field) => Some(char::from(field.symbol)),
+            PatternItem::Literal(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_numbering_overrides() {
+    use icu_locid::Locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    // `y=jpanyear` is algorithmic, so it is not included.
+    let locale: Locale = "ja-u-ca-japanese".parse().unwrap();
+    let overrides: DataPayload<DateNumberingOverridesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale.into(),
+            metadata: Default::default(),
+        })
+        .expect("Failed to load payload")
+        .take_payload()
+        .expect("Failed to retrieve payload");
+    assert_eq!(overrides.get(), &DateNumberingOverridesV1::default());
+
+    let pattern = cldr_serde::ca::LengthPattern::WithNumberingSystems {
+        pattern: "d/M/y".into(),
+        numbering_systems: "d=hanidec;y=jpanyear".into(),
+    };
+    let overrides = convert_overrides(&pattern, |ns| ns.as_str() != "jpanyear");
+    assert_eq!(
+        overrides.get_copied(&'d'),
+        Some(tinystr::tinystr!(8, "hanidec"))
+    );
+    assert_eq!(overrides.get_copied(&'y'), None);
+
+    let pattern = cldr_serde::ca::LengthPattern::WithNumberingSystems {
+        pattern: "d/M/y".into(),
+        numbering_systems: "hanidec".into(),
+    };
+    let overrides = convert_overrides(&pattern, |_| true);
+    assert_eq!(overrides.len(), 3);
+    assert_eq!(
+        overrides.get_copied(&'M'),
+        Some(tinystr::tinystr!(8, "hanidec"))
+    );
+}
@@ -9,7 +9,9 @@ use icu_datetime::provider::calendar::*;
 
 impl From<&cldr_serde::ca::LengthPatterns> for patterns::LengthPatternsV1<'_> {
     fn from(other: &cldr_serde::ca::LengthPatterns) -> Self {
-        // TODO(#308): Support numbering system variations. We currently throw them away.
+        // Numbering system variations of the date patterns are stored separately in
+        // `DateNumberingOverridesV1`.
+        // TODO(#308): Support numbering system variations of the time patterns.
         Self {
             full: other
                 .full
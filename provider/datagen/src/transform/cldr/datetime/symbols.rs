@@ -18,6 +18,12 @@ pub fn convert_dates(other: &cldr_serde::ca::Dates, calendar: &str) -> DateSymbo
     }
 }
 
+pub fn convert_quarters(other: &cldr_serde::ca::Dates) -> QuarterSymbolsV1<'static> {
+    QuarterSymbolsV1 {
+        quarters: other.quarters.get(&()),
+    }
+}
+
 pub fn convert_times(other: &cldr_serde::ca::Dates) -> TimeSymbolsV1<'static> {
     TimeSymbolsV1 {
         day_periods: other.day_periods.get(&()),
@@ -61,9 +67,30 @@ fn get_month_code_map(calendar: &str) -> &'static [TinyStr4] {
         tinystr!(4, "M13"),
     ];
 
+    // CLDR numbers the Hebrew months from Tishri, with Adar I as the 6th month
+    // and Adar as the 7th. Adar II is the "7-yeartype-leap" variant of Adar.
+    static HEBREW_MONTH_CODES: &[TinyStr4] = &[
+        tinystr!(4, "M01"),
+        tinystr!(4, "M02"),
+        tinystr!(4, "M03"),
+        tinystr!(4, "M04"),
+        tinystr!(4, "M05"),
+        tinystr!(4, "M05L"),
+        tinystr!(4, "M06"),
+        tinystr!(4, "M07"),
+        tinystr!(4, "M08"),
+        tinystr!(4, "M09"),
+        tinystr!(4, "M10"),
+        tinystr!(4, "M11"),
+        tinystr!(4, "M12"),
+    ];
+
     match calendar {
-        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" => &SOLAR_MONTH_CODES[0..12],
+        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" | "persian" | "roc" => {
+            &SOLAR_MONTH_CODES[0..12]
+        }
         "coptic" | "ethiopic" => SOLAR_MONTH_CODES,
+        "hebrew" => HEBREW_MONTH_CODES,
         _ => panic!("Month map unknown for {}", calendar),
     }
 }
@@ -98,6 +125,20 @@ fn get_era_code_map(calendar: &str) -> BTreeMap<String, TinyStr16> {
         ]
         .into_iter()
         .collect(),
+        "hebrew" => vec![("0".to_string(), tinystr!(16, "am"))]
+            .into_iter()
+            .collect(),
+        "persian" => vec![("0".to_string(), tinystr!(16, "ap"))]
+            .into_iter()
+            .collect(),
+        "roc" => vec![
+            // Before R.O.C.
+            ("0".to_string(), tinystr!(16, "roc-inverse")),
+            // Minguo
+            ("1".to_string(), tinystr!(16, "roc")),
+        ]
+        .into_iter()
+        .collect(),
         _ => panic!("Era map unknown for {}", calendar),
     }
 }
@@ -129,12 +170,14 @@ macro_rules! symbols_from {
     };
     ([$name: ident, $name2: ident], $ctx:ty) => {
         impl cldr_serde::ca::$name::Symbols {
-            // Helper function which returns None if the two groups of symbols overlap.
-            pub fn get_unaliased(&self, other: &Self) -> Option<Self> {
-                if self == other {
+            // Helper function which returns None if the two groups of symbols convert to the
+            // same data. Fields that are not part of the data do not prevent aliasing.
+            pub fn get_unaliased(&self, other: &Self, ctx: &$ctx) -> Option<$name2::SymbolsV1<'static>> {
+                let symbols = self.get(ctx);
+                if symbols == other.get(ctx) {
                     None
                 } else {
-                    Some(self.clone())
+                    Some(symbols)
                 }
             }
         }
@@ -144,28 +187,27 @@ macro_rules! symbols_from {
                 $name2::ContextsV1 {
                     format: self.format.get(ctx),
                     stand_alone: self.stand_alone.as_ref().and_then(|stand_alone| {
-                        stand_alone.get_unaliased(&self.format)
-                    }).map(|ref stand_alone| stand_alone.get(ctx))
+                        stand_alone.get_unaliased(&self.format, ctx)
+                    }),
                 }
             }
         }
 
         impl cldr_serde::ca::$name::StandAloneWidths {
             // Helper function which returns None if the two groups of symbols overlap.
-            pub fn get_unaliased(&self, other: &cldr_serde::ca::$name::FormatWidths) -> Option<Self> {
-                let abbreviated = self.abbreviated.as_ref().and_then(|v| v.get_unaliased(&other.abbreviated));
-                let narrow = self.narrow.as_ref().and_then(|v| v.get_unaliased(&other.narrow));
-                let short = if self.short == other.short {
-                    None
-                } else {
-                    self.short.clone()
-                };
-                let wide = self.wide.as_ref().and_then(|v| v.get_unaliased(&other.wide));
+            pub fn get_unaliased(&self, other: &cldr_serde::ca::$name::FormatWidths, ctx: &$ctx) -> Option<$name2::StandAloneWidthsV1<'static>> {
+                let abbreviated = self.abbreviated.as_ref().and_then(|v| v.get_unaliased(&other.abbreviated, ctx));
+                let narrow = self.narrow.as_ref().and_then(|v| v.get_unaliased(&other.narrow, ctx));
+                let short = self.short.as_ref().and_then(|v| match other.short {
+                    Some(ref other) => v.get_unaliased(other, ctx),
+                    None => Some(v.get(ctx)),
+                });
+                let wide = self.wide.as_ref().and_then(|v| v.get_unaliased(&other.wide, ctx));
 
                 if abbreviated.is_none() && narrow.is_none() && wide.is_none() && short.is_none() {
                     None
                 } else {
-                    Some(Self {
+                    Some($name2::StandAloneWidthsV1 {
                         abbreviated,
                         narrow,
                         short,
@@ -185,17 +227,6 @@ macro_rules! symbols_from {
                 }
             }
         }
-
-        impl cldr_serde::ca::$name::StandAloneWidths {
-            fn get(&self, ctx: &$ctx) -> $name2::StandAloneWidthsV1<'static> {
-                $name2::StandAloneWidthsV1 {
-                    abbreviated: self.abbreviated.as_ref().map(|width| width.get(ctx)),
-                    narrow: self.narrow.as_ref().map(|width| width.get(ctx)),
-                    short: self.short.as_ref().map(|width| width.get(ctx)),
-                    wide: self.wide.as_ref().map(|width| width.get(ctx)),
-                }
-            }
-        }
     };
 }
 symbols_from!([months, months], &'static [TinyStr4]);
@@ -222,8 +253,15 @@ impl cldr_serde::ca::months::Symbols {
             }
             months::SymbolsV1::SolarTwelve(arr)
         } else {
-            let mut map: ZeroMap<MonthCode, str> = ZeroMap::default();
+            let mut symbols: ZeroMap<MonthCode, str> = ZeroMap::default();
+            let mut leap_year_variants: ZeroMap<MonthCode, str> = ZeroMap::default();
             for (k, v) in self.0.iter() {
+                // Some months have a different name in leap years (e.g. Hebrew Adar II,
+                // "7-yeartype-leap"), which is stored separately under the same month code
+                let (k, map) = match k.strip_suffix("-yeartype-leap") {
+                    Some(k) => (k, &mut leap_year_variants),
+                    None => (k.as_str(), &mut symbols),
+                };
                 let index: usize = k
                     .parse()
                     .expect("CLDR month indices must parse as numbers!");
@@ -236,13 +274,22 @@ impl cldr_serde::ca::months::Symbols {
 
                 map.insert(&MonthCode(*code), v);
             }
-            months::SymbolsV1::Other(map)
+            if leap_year_variants.is_empty() {
+                months::SymbolsV1::Other(symbols)
+            } else {
+                months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols,
+                    leap_year_variants,
+                }
+            }
         }
     }
 }
 
 symbols_from!([days, weekdays], (), [sun, mon, tue, wed, thu, fri, sat]);
 
+symbols_from!([quarters, quarters], (), [q1, q2, q3, q4]);
+
 symbols_from!(
     [
         day_periods,
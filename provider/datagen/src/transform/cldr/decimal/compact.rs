@@ -0,0 +1,247 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+impl crate::DatagenProvider {
+    fn load_compact_decimal_patterns(
+        &self,
+        req: &DataRequest,
+        select: fn(
+            &cldr_serde::numbers::DecimalFormats,
+        ) -> Option<&cldr_serde::numbers::CompactDecimalFormats>,
+    ) -> Result<CompactDecimalPatternDataV1<'static>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::get_numbering_system(req, numbers);
+
+        let formats = numbers.numsys_data.formats.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find formats for numbering system")
+                .with_display_context(&nsname)
+        })?;
+
+        match select(formats) {
+            Some(compact_formats) => convert_compact_formats(compact_formats).map_err(|s| {
+                DataError::custom("Could not create compact decimal patterns")
+                    .with_display_context(&s)
+                    .with_display_context(&nsname)
+            }),
+            // Numbers are not compacted without patterns.
+            None => Ok(Default::default()),
+        }
+    }
+}
+
+impl DataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ShortCompactDecimalFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                self.load_compact_decimal_patterns(&req, |formats| formats.short.as_ref())?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.get_supported_locales_with_numsys()
+    }
+}
+
+impl DataProvider<LongCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LongCompactDecimalFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                self.load_compact_decimal_patterns(&req, |formats| formats.long.as_ref())?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<LongCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.get_supported_locales_with_numsys()
+    }
+}
+
+/// Converts a compact pattern, such as "00K", to a pattern with the significand at `{0}`, such
+/// as "{0}K", and the number of integer digits of the significand. Returns `None` for the
+/// pattern "0", which means that numbers are not compacted.
+fn parse_compact_pattern(pattern: &str) -> Result<Option<(String, i16)>, Cow<'static, str>> {
+    let mut result = String::new();
+    let mut digits = None;
+    let mut in_quote = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                result.push('\'');
+            }
+            '\'' => in_quote = !in_quote,
+            '0' if !in_quote => {
+                if digits.is_some() {
+                    return Err(format!("Multiple numbers in pattern {:?}", pattern).into());
+                }
+                let mut count = 1;
+                while chars.next_if_eq(&'0').is_some() {
+                    count += 1;
+                }
+                digits = Some(count);
+                result.push_str("{0}");
+            }
+            c => result.push(c),
+        }
+    }
+    match digits {
+        None => Err(format!("No number in pattern {:?}", pattern).into()),
+        Some(_) if result == "{0}" => Ok(None),
+        Some(digits) => Ok(Some((result, digits))),
+    }
+}
+
+fn convert_compact_formats(
+    formats: &cldr_serde::numbers::CompactDecimalFormats,
+) -> Result<CompactDecimalPatternDataV1<'static>, Cow<'static, str>> {
+    let mut exponents = BTreeMap::<i16, i16>::new();
+    let mut patterns = BTreeMap::<i16, HashMap<PluralCategory, String>>::new();
+    for (key, pattern) in formats.patterns.iter() {
+        // Keys are of the form "1000-count-one"
+        let (power, count) = match key.split_once("-count-") {
+            Some(v) => v,
+            None => continue,
+        };
+        // Explicit patterns, such as "1000-count-1", are not supported.
+        let category = match PluralCategory::from_tr35_string(count) {
+            Some(category) => category,
+            None => continue,
+        };
+        let magnitude = match power.strip_prefix('1') {
+            Some(zeros) if zeros.bytes().all(|b| b == b'0') => zeros.len() as i16,
+            _ => return Err(format!("Invalid compact pattern key {:?}", key).into()),
+        };
+
+        let parsed = parse_compact_pattern(pattern)?;
+        let exponent = match parsed {
+            Some((_, digits)) => magnitude - digits + 1,
+            None => 0,
+        };
+        if exponents.insert(magnitude, exponent).unwrap_or(exponent) != exponent {
+            return Err(format!("Inconsistent compact patterns for {}", power).into());
+        }
+        if let Some((pattern, _)) = parsed {
+            patterns
+                .entry(magnitude)
+                .or_default()
+                .insert(category, pattern);
+        }
+    }
+
+    let mut result = CompactDecimalPatternDataV1 {
+        exponents: exponents.into_iter().collect(),
+        ..Default::default()
+    };
+    for (magnitude, patterns) in patterns.iter() {
+        let other = patterns
+            .get(&PluralCategory::Other)
+            .ok_or("Missing compact pattern for the other plural category")?;
+        result.other.insert(magnitude, other);
+        for (map, category) in [
+            (&mut result.zero, PluralCategory::Zero),
+            (&mut result.one, PluralCategory::One),
+            (&mut result.two, PluralCategory::Two),
+            (&mut result.few, PluralCategory::Few),
+            (&mut result.many, PluralCategory::Many),
+        ] {
+            if let Some(pattern) = patterns.get(&category).filter(|p| *p != other) {
+                map.insert(magnitude, pattern);
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[test]
+fn test_parse_compact_pattern() {
+    assert_eq!(
+        parse_compact_pattern("00K"),
+        Ok(Some(("{0}K".to_string(), 2)))
+    );
+    assert_eq!(
+        parse_compact_pattern("0 'mil''s'"),
+        Ok(Some(("{0} mil's".to_string(), 1)))
+    );
+    assert_eq!(parse_compact_pattern("0"), Ok(None));
+    assert!(parse_compact_pattern("mille").is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let en_short: DataPayload<ShortCompactDecimalFormatDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en_short.get().exponents.get_copied(&4), Some(3));
+    assert_eq!(en_short.get().other.get(&4), Some("{0}K"));
+    assert_eq!(en_short.get().one.get(&4), None);
+
+    let fr_long: DataPayload<LongCompactDecimalFormatDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fr_long.get().other.get(&6), Some("{0} millions"));
+    assert_eq!(fr_long.get().one.get(&6), Some("{0} million"));
+
+    let ja_short: DataPayload<ShortCompactDecimalFormatDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(ja_short.get().exponents.get_copied(&3), Some(0));
+    assert_eq!(ja_short.get().other.get(&3), None);
+    assert_eq!(ja_short.get().exponents.get_copied(&5), Some(4));
+    assert_eq!(ja_short.get().other.get(&5), Some("{0}万"));
+}
@@ -0,0 +1,258 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::decimal_pattern::DecimalPattern;
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+impl DataProvider<CurrencyPatternsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyPatternsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::get_numbering_system(&req, numbers);
+
+        let symbols = numbers.numsys_data.symbols.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(&nsname)
+        })?;
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                convert_currency_formats(formats, &symbols.minus_sign).map_err(|e| {
+                    DataError::custom("Could not create currency patterns")
+                        .with_display_context(&e)
+                        .with_display_context(&nsname)
+                })?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.get_supported_locales_with_numsys()
+    }
+}
+
+fn convert_currency_formats(
+    formats: &cldr_serde::numbers::CurrencyFormats,
+    minus_sign: &str,
+) -> Result<CurrencyPatternsV1<'static>, super::decimal_pattern::Error> {
+    let standard: DecimalPattern = formats.standard.parse()?;
+    let accounting: DecimalPattern = formats.accounting.parse()?;
+    let owned = |pattern: &Option<String>| pattern.clone().map(Cow::Owned);
+    Ok(CurrencyPatternsV1 {
+        standard: standard.localize_signed_affixes(minus_sign),
+        accounting: accounting.localize_signed_affixes(minus_sign),
+        spacing: Cow::Owned(
+            formats
+                .currency_spacing
+                .before_currency
+                .insert_between
+                .clone(),
+        ),
+        unit_patterns: PluralPatternsV1 {
+            zero: owned(&formats.unit_pattern_zero),
+            one: owned(&formats.unit_pattern_one),
+            two: owned(&formats.unit_pattern_two),
+            few: owned(&formats.unit_pattern_few),
+            many: owned(&formats.unit_pattern_many),
+            other: Cow::Owned(formats.unit_pattern_other.clone()),
+        },
+    })
+}
+
+impl DataProvider<CurrencySymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencySymbolsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::currencies::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "currencies.json")?;
+
+        let currencies = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers
+            .currencies;
+
+        // Sort by code, since the iteration order of the CLDR map is unspecified.
+        let currencies = currencies.iter().collect::<BTreeMap<_, _>>();
+
+        let mut symbols = ZeroMap::new();
+        let mut narrow_symbols = ZeroMap::new();
+        let mut long_names = CurrencyLongNamesV1::default();
+        for (code, currency) in currencies {
+            let symbol = currency.symbol.as_deref();
+            // Symbols that are just the code are redundant with the fallback to the code.
+            if let Some(symbol) = symbol.filter(|s| *s != code.as_str()) {
+                symbols.insert(code, symbol);
+            }
+            if let Some(narrow) = currency.symbol_narrow.as_deref() {
+                if Some(narrow) != symbol {
+                    narrow_symbols.insert(code, narrow);
+                }
+            }
+
+            let other = match currency
+                .display_name_other
+                .as_deref()
+                .or(currency.display_name.as_deref())
+            {
+                Some(other) => other,
+                None => continue,
+            };
+            long_names.other.insert(code, other);
+            for (map, name) in [
+                (&mut long_names.zero, &currency.display_name_zero),
+                (&mut long_names.one, &currency.display_name_one),
+                (&mut long_names.two, &currency.display_name_two),
+                (&mut long_names.few, &currency.display_name_few),
+                (&mut long_names.many, &currency.display_name_many),
+            ] {
+                if let Some(name) = name.as_deref().filter(|name| *name != other) {
+                    map.insert(code, name);
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencySymbolsV1 {
+                symbols,
+                narrow_symbols,
+                long_names,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencySymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl DataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<CurrencyFractionsV1Marker>, DataError> {
+        let resource: &cldr_serde::currency_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        let fractions = &resource.supplemental.currency_data.fractions;
+
+        let default = fractions
+            .get("DEFAULT")
+            .map(convert_fraction)
+            .unwrap_or_default();
+
+        let mut result = ZeroMap::new();
+        for (code, fraction) in fractions.iter() {
+            let fraction = convert_fraction(fraction);
+            if code == "DEFAULT" || fraction == default {
+                continue;
+            }
+            let code = code.parse::<TinyAsciiStr<3>>().map_err(|_| {
+                DataError::custom("Invalid currency code").with_display_context(code)
+            })?;
+            result.insert(&code, &fraction);
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyFractionsV1 {
+                default,
+                fractions: result,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn convert_fraction(fraction: &cldr_serde::currency_data::Fraction) -> CurrencyFractionV1 {
+    CurrencyFractionV1 {
+        digits: fraction.digits,
+        rounding: fraction.rounding,
+        cash_digits: fraction.cash_digits.unwrap_or(fraction.digits),
+        cash_rounding: fraction.cash_rounding.unwrap_or(fraction.rounding),
+    }
+}
+
+#[test]
+fn test_currency_formats() {
+    use cldr_serde::numbers::{CurrencyFormats, CurrencySpacing, CurrencySpacingPattern};
+
+    let spacing = || CurrencySpacingPattern {
+        insert_between: "\u{a0}".into(),
+    };
+    let formats = CurrencyFormats {
+        standard: "#,##0.00\u{a0}¤".into(),
+        accounting: "#,##0.00\u{a0}¤;(#,##0.00\u{a0}¤)".into(),
+        currency_spacing: CurrencySpacing {
+            before_currency: spacing(),
+            after_currency: spacing(),
+        },
+        unit_pattern_zero: None,
+        unit_pattern_one: Some("{0} {1}".into()),
+        unit_pattern_two: None,
+        unit_pattern_few: None,
+        unit_pattern_many: None,
+        unit_pattern_other: "{0} {1}".into(),
+    };
+
+    let patterns = convert_currency_formats(&formats, "\u{2212}").unwrap();
+    assert_eq!(patterns.standard.positive.prefix, "");
+    assert_eq!(patterns.standard.positive.suffix, "\u{a0}¤");
+    assert_eq!(patterns.standard.negative.prefix, "\u{2212}");
+    assert_eq!(patterns.standard.negative.suffix, "\u{a0}¤");
+    assert_eq!(patterns.accounting.negative.prefix, "(");
+    assert_eq!(patterns.accounting.negative.suffix, "\u{a0}¤)");
+    assert_eq!(patterns.spacing, "\u{a0}");
+    assert_eq!(patterns.unit_patterns.one.as_deref(), Some("{0} {1}"));
+}
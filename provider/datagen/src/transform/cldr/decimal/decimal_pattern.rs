@@ -7,7 +7,7 @@
 //! Spec reference: <https://unicode.org/reports/tr35/tr35-numbers.html#Number_Format_Patterns>
 
 use displaydoc::Display;
-use icu_decimal::provider::AffixesV1;
+use icu_decimal::provider::{AffixesV1, SignedAffixesV1};
 use itertools::Itertools;
 use std::borrow::Cow;
 use std::str::FromStr;
@@ -58,9 +58,13 @@ impl FromStr for DecimalSubPattern {
         // For now, we expect one of a handful of pattern bodies.
         // TODO(#567): Generalize this to support all of UTS 35.
         let (a, b, c, d) = match body {
+            "#,##0" => (3, 3, 0, 0),
+            "#,##,##0" => (3, 2, 0, 0),
             "#,##0.###" => (3, 3, 0, 3),
             "#,##,##0.###" => (3, 2, 0, 3),
             "0.######" => (0, 0, 0, 6),
+            "#,##0.00" => (3, 3, 2, 2),
+            "#,##,##0.00" => (3, 2, 2, 2),
             _ => return Err(Error::UnknownPatternBody(body.to_string())),
         };
         Ok(Self {
@@ -112,6 +116,29 @@ impl DecimalPattern {
             suffix: Cow::Owned(signed_affixes.1.replace('-', sign_str)),
         }
     }
+
+    /// Returns the affixes of positive and negative numbers, keeping placeholders such as `¤`
+    /// and localizing the minus sign of the negative affixes.
+    pub fn localize_signed_affixes(&self, minus_sign_str: &str) -> SignedAffixesV1<'static> {
+        // UTS 35: the absence of a negative pattern means the positive pattern with a prefixed sign
+        let (negative_prefix, negative_suffix) = match self.negative {
+            Some(ref subpattern) => (subpattern.prefix.clone(), subpattern.suffix.clone()),
+            None => (
+                format!("-{}", self.positive.prefix),
+                self.positive.suffix.clone(),
+            ),
+        };
+        SignedAffixesV1 {
+            positive: AffixesV1 {
+                prefix: Cow::Owned(self.positive.prefix.clone()),
+                suffix: Cow::Owned(self.positive.suffix.clone()),
+            },
+            negative: AffixesV1 {
+                prefix: Cow::Owned(negative_prefix.replace('-', minus_sign_str)),
+                suffix: Cow::Owned(negative_suffix.replace('-', minus_sign_str)),
+            },
+        }
+    }
 }
 
 #[test]
@@ -199,6 +226,41 @@ fn test_basic() {
                 }),
             }),
         },
+        TestCase {
+            pattern: "¤#,##0.00;(¤#,##0.00)",
+            expected: Ok(DecimalPattern {
+                positive: DecimalSubPattern {
+                    prefix: "¤".into(),
+                    suffix: "".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 3,
+                    min_fraction_digits: 2,
+                    max_fraction_digits: 2,
+                },
+                negative: Some(DecimalSubPattern {
+                    prefix: "(¤".into(),
+                    suffix: ")".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 3,
+                    min_fraction_digits: 2,
+                    max_fraction_digits: 2,
+                }),
+            }),
+        },
+        TestCase {
+            pattern: "#,##,##0%",
+            expected: Ok(DecimalPattern {
+                positive: DecimalSubPattern {
+                    prefix: "".into(),
+                    suffix: "%".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 2,
+                    min_fraction_digits: 0,
+                    max_fraction_digits: 0,
+                },
+                negative: None,
+            }),
+        },
         TestCase {
             pattern: "xyz",
             expected: Err(Error::NoBodyInSubpattern),
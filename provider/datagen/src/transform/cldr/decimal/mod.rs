@@ -13,7 +13,11 @@ use std::borrow::Cow;
 use std::convert::TryFrom;
 use tinystr::TinyAsciiStr;
 
+mod compact;
+mod currency;
 mod decimal_pattern;
+mod percent;
+mod scientific;
 
 impl crate::DatagenProvider {
     /// Returns the digits for the given numbering system name.
@@ -77,6 +81,47 @@ impl crate::DatagenProvider {
             .copied()
             .collect())
     }
+
+    /// Returns the supported locales of numbering-system-specific keys, with a `-u-nu`
+    /// extension for each numbering system other than the default one.
+    fn get_supported_locales_with_numsys(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .flat_map(|langid| {
+                let last = DataLocale::from(&langid);
+                self.get_supported_numsys_for_langid_without_default(&langid)
+                    .expect("All languages from list_langs should be present")
+                    .into_iter()
+                    .map(move |nsname| {
+                        let mut data_locale = DataLocale::from(&langid);
+                        data_locale.set_unicode_ext(
+                            key!("nu"),
+                            Value::try_from_single_subtag(nsname.as_bytes())
+                                .expect("CLDR should have valid numbering system names"),
+                        );
+                        data_locale
+                    })
+                    .chain(core::iter::once(last))
+            })
+            .collect())
+    }
+}
+
+/// Returns the numbering system requested with `-u-nu`, or the default one of the locale.
+fn get_numbering_system(
+    req: &DataRequest,
+    numbers: &cldr_serde::numbers::Numbers,
+) -> TinyAsciiStr<8> {
+    match req.locale.get_unicode_ext(&key!("nu")) {
+        Some(v) => *v
+            .as_tinystr_slice()
+            .first()
+            .expect("expecting subtag if key is present"),
+        None => numbers.default_numbering_system,
+    }
 }
 
 impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
@@ -96,13 +141,7 @@ impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
             .expect("CLDR file contains the expected language")
             .numbers;
 
-        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
-            Some(v) => *v
-                .as_tinystr_slice()
-                .first()
-                .expect("expecting subtag if key is present"),
-            None => numbers.default_numbering_system,
-        };
+        let nsname = get_numbering_system(&req, numbers);
 
         let mut result =
             DecimalSymbolsV1::try_from(NumbersWithNumsys(numbers, nsname)).map_err(|s| {
@@ -122,28 +161,7 @@ impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
 
 impl IterableDataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-        Ok(self
-            .source
-            .cldr()?
-            .numbers()
-            .list_langs()?
-            .flat_map(|langid| {
-                let last = DataLocale::from(&langid);
-                self.get_supported_numsys_for_langid_without_default(&langid)
-                    .expect("All languages from list_langs should be present")
-                    .into_iter()
-                    .map(move |nsname| {
-                        let mut data_locale = DataLocale::from(&langid);
-                        data_locale.set_unicode_ext(
-                            key!("nu"),
-                            Value::try_from_single_subtag(nsname.as_bytes())
-                                .expect("CLDR should have valid numbering system names"),
-                        );
-                        data_locale
-                    })
-                    .chain(core::iter::once(last))
-            })
-            .collect())
+        self.get_supported_locales_with_numsys()
     }
 }
 
@@ -0,0 +1,115 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::decimal_pattern::DecimalPattern;
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+impl DataProvider<PercentPatternsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PercentPatternsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::get_numbering_system(&req, numbers);
+
+        let symbols = numbers.numsys_data.symbols.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(&nsname)
+        })?;
+        let formats = numbers
+            .numsys_data
+            .percent_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find percent formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                convert_percent_formats(formats, symbols).map_err(|e| {
+                    DataError::custom("Could not create percent patterns")
+                        .with_display_context(&e)
+                        .with_display_context(&nsname)
+                })?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<PercentPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.get_supported_locales_with_numsys()
+    }
+}
+
+fn convert_percent_formats(
+    formats: &cldr_serde::numbers::PercentFormats,
+    symbols: &cldr_serde::numbers::Symbols,
+) -> Result<PercentPatternsV1<'static>, super::decimal_pattern::Error> {
+    let standard: DecimalPattern = formats.standard.parse()?;
+    Ok(PercentPatternsV1 {
+        affixes: standard.localize_signed_affixes(&symbols.minus_sign),
+        percent_sign: Cow::Owned(symbols.percent_sign.clone()),
+        permille_sign: Cow::Owned(symbols.per_mille.clone()),
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let en: DataPayload<PercentPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().affixes.positive.prefix, "");
+    assert_eq!(en.get().affixes.positive.suffix, "%");
+    assert_eq!(en.get().affixes.negative.prefix, "-");
+    assert_eq!(en.get().percent_sign, "%");
+    assert_eq!(en.get().permille_sign, "‰");
+
+    let fr: DataPayload<PercentPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fr.get().affixes.positive.suffix, "\u{a0}%");
+
+    let tr: DataPayload<PercentPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("tr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(tr.get().affixes.positive.prefix, "%");
+    assert_eq!(tr.get().affixes.negative.prefix, "-%");
+}
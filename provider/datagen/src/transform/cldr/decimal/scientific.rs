@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+impl DataProvider<ScientificSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ScientificSymbolsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::get_numbering_system(&req, numbers);
+
+        let symbols = numbers.numsys_data.symbols.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(&nsname)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ScientificSymbolsV1 {
+                exponential: Cow::Owned(symbols.exponential.clone()),
+                superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<ScientificSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.get_supported_locales_with_numsys()
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let en: DataPayload<ScientificSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().exponential, "E");
+    assert_eq!(en.get().superscripting_exponent, "×");
+
+    let ar: DataPayload<ScientificSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ar").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(ar.get().exponential, "اس");
+}
@@ -12,5 +12,9 @@ pub mod fallback;
 pub mod list;
 pub mod locale_canonicalizer;
 pub mod plurals;
+#[cfg(feature = "experimental")]
+pub mod relativetime;
 pub mod source;
 pub mod time_zones;
+#[cfg(feature = "experimental")]
+pub mod units;
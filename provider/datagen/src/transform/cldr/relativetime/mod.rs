@@ -0,0 +1,153 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_relativetime::provider::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use zerovec::ZeroMap;
+
+fn parse_sub_pattern(pattern: &str) -> Result<SingularSubPattern<'static>, DataError> {
+    // Some patterns spell out the amount, such as "قبل يوم واحد" ("one day ago") in Arabic.
+    let index = match pattern.find("{0}") {
+        Some(index) => index,
+        None => {
+            return Ok(SingularSubPattern {
+                pattern: Cow::Owned(pattern.to_string()),
+                index: SingularSubPattern::NO_PLACEHOLDER,
+            })
+        }
+    };
+    Ok(SingularSubPattern {
+        pattern: Cow::Owned(pattern.replacen("{0}", "", 1)),
+        index: u8::try_from(index)
+            .ok()
+            .filter(|&index| index != SingularSubPattern::NO_PLACEHOLDER)
+            .ok_or_else(|| DataError::custom("Pattern too long").with_display_context(pattern))?,
+    })
+}
+
+fn convert_plural_patterns(
+    patterns: &cldr_serde::date_fields::PluralRulesPattern,
+) -> Result<PluralPatterns<'static>, DataError> {
+    let parse_optional =
+        |pattern: &Option<String>| pattern.as_deref().map(parse_sub_pattern).transpose();
+    Ok(PluralPatterns {
+        zero: parse_optional(&patterns.zero)?,
+        one: parse_optional(&patterns.one)?,
+        two: parse_optional(&patterns.two)?,
+        few: parse_optional(&patterns.few)?,
+        many: parse_optional(&patterns.many)?,
+        other: parse_sub_pattern(&patterns.other)?,
+    })
+}
+
+fn convert_field(
+    field: &cldr_serde::date_fields::Field,
+) -> Result<RelativeTimePatterns<'static>, DataError> {
+    let relatives = field
+        .others
+        .iter()
+        .filter_map(|(key, name)| {
+            key.strip_prefix("relative-type-")
+                .and_then(|offset| offset.parse::<i16>().ok())
+                .map(|offset| (offset, name.as_str()))
+        })
+        .collect::<ZeroMap<i16, str>>();
+    Ok(RelativeTimePatterns {
+        relatives,
+        past: convert_plural_patterns(&field.past)?,
+        future: convert_plural_patterns(&field.future)?,
+    })
+}
+
+fn load<M: KeyedDataMarker<Yokeable = RelativeTimePatternDataV1<'static>>>(
+    selff: &crate::DatagenProvider,
+    req: DataRequest,
+) -> Result<DataResponse<M>, DataError> {
+    let langid = req.locale.get_langid();
+
+    let resource: &cldr_serde::date_fields::Resource = selff
+        .source
+        .cldr()?
+        .dates("gregorian")
+        .read_and_parse(&langid, "dateFields.json")?;
+
+    let fields = &resource
+        .main
+        .0
+        .get(&langid)
+        .expect("CLDR file contains the expected language")
+        .dates
+        .fields;
+
+    let (long, short, narrow) = if M::KEY == SecondRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.second, &fields.second_short, &fields.second_narrow)
+    } else if M::KEY == MinuteRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.minute, &fields.minute_short, &fields.minute_narrow)
+    } else if M::KEY == HourRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.hour, &fields.hour_short, &fields.hour_narrow)
+    } else if M::KEY == DayRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.day, &fields.day_short, &fields.day_narrow)
+    } else if M::KEY == WeekRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.week, &fields.week_short, &fields.week_narrow)
+    } else if M::KEY == MonthRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.month, &fields.month_short, &fields.month_narrow)
+    } else if M::KEY == QuarterRelativeTimeFormatDataV1Marker::KEY {
+        (
+            &fields.quarter,
+            &fields.quarter_short,
+            &fields.quarter_narrow,
+        )
+    } else if M::KEY == YearRelativeTimeFormatDataV1Marker::KEY {
+        (&fields.year, &fields.year_short, &fields.year_narrow)
+    } else {
+        return Err(DataError::custom(
+            "Unknown key for RelativeTimePatternDataV1",
+        ));
+    };
+
+    let metadata = DataResponseMetadata::default();
+    Ok(DataResponse {
+        metadata,
+        payload: Some(DataPayload::from_owned(RelativeTimePatternDataV1 {
+            long: convert_field(long)?,
+            short: convert_field(short)?,
+            narrow: convert_field(narrow)?,
+        })),
+    })
+}
+
+macro_rules! implement {
+    ($marker:ident) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                load(self, req)
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                Ok(self
+                    .source
+                    .cldr()?
+                    .dates("gregorian")
+                    .list_langs()?
+                    .map(DataLocale::from)
+                    .collect())
+            }
+        }
+    };
+}
+
+implement!(SecondRelativeTimeFormatDataV1Marker);
+implement!(MinuteRelativeTimeFormatDataV1Marker);
+implement!(HourRelativeTimeFormatDataV1Marker);
+implement!(DayRelativeTimeFormatDataV1Marker);
+implement!(WeekRelativeTimeFormatDataV1Marker);
+implement!(MonthRelativeTimeFormatDataV1Marker);
+implement!(QuarterRelativeTimeFormatDataV1Marker);
+implement!(YearRelativeTimeFormatDataV1Marker);
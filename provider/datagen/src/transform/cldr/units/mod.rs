@@ -0,0 +1,343 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use icu_units::Ratio;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+fn convert_display(
+    width: &HashMap<String, cldr_serde::units::Patterns>,
+) -> Result<UnitsDisplayV1<'static>, DataError> {
+    let mut display = UnitsDisplayV1::default();
+    // Sort by identifier, since the iteration order of the CLDR map is unspecified.
+    for (id, patterns) in width.iter().collect::<BTreeMap<_, _>>() {
+        // Identifiers are prefixed with their category, such as "length-kilometer".
+        let unit = match id.split_once('-') {
+            Some((_, unit)) => unit,
+            None => continue,
+        };
+        let patterns = &patterns.0;
+        if let Some(per_unit) = patterns.get("perUnitPattern") {
+            display.per_unit_patterns.insert(unit, per_unit.as_str());
+        }
+        let other = match patterns.get("unitPattern-count-other") {
+            Some(other) => other.as_str(),
+            None => continue,
+        };
+        display.other.insert(unit, other);
+        for (map, category) in [
+            (&mut display.zero, "zero"),
+            (&mut display.one, "one"),
+            (&mut display.two, "two"),
+            (&mut display.few, "few"),
+            (&mut display.many, "many"),
+        ] {
+            if let Some(pattern) = patterns
+                .get(&format!("unitPattern-count-{}", category))
+                .filter(|pattern| *pattern != other)
+            {
+                map.insert(unit, pattern.as_str());
+            }
+        }
+    }
+    display.per_pattern = Cow::Owned(
+        width
+            .get("per")
+            .and_then(|per| per.0.get("compoundUnitPattern"))
+            .ok_or_else(|| DataError::custom("Missing per pattern"))?
+            .clone(),
+    );
+    Ok(display)
+}
+
+fn load_display<M: KeyedDataMarker<Yokeable = UnitsDisplayV1<'static>>>(
+    selff: &crate::DatagenProvider,
+    req: DataRequest,
+) -> Result<DataResponse<M>, DataError> {
+    let langid = req.locale.get_langid();
+
+    let resource: &cldr_serde::units::Resource = selff
+        .source
+        .cldr()?
+        .units()
+        .read_and_parse(&langid, "units.json")?;
+
+    let units = &resource
+        .main
+        .0
+        .get(&langid)
+        .expect("CLDR file contains the expected language")
+        .units;
+
+    let width = if M::KEY == LongUnitsDisplayV1Marker::KEY {
+        &units.long
+    } else if M::KEY == ShortUnitsDisplayV1Marker::KEY {
+        &units.short
+    } else if M::KEY == NarrowUnitsDisplayV1Marker::KEY {
+        &units.narrow
+    } else {
+        return Err(DataError::custom("Unknown key for UnitsDisplayV1"));
+    };
+
+    Ok(DataResponse {
+        metadata: Default::default(),
+        payload: Some(DataPayload::from_owned(convert_display(width)?)),
+    })
+}
+
+macro_rules! implement {
+    ($marker:ident) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                load_display(self, req)
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                Ok(self
+                    .source
+                    .cldr()?
+                    .units()
+                    .list_langs()?
+                    .map(DataLocale::from)
+                    .collect())
+            }
+        }
+    };
+}
+
+implement!(LongUnitsDisplayV1Marker);
+implement!(ShortUnitsDisplayV1Marker);
+implement!(NarrowUnitsDisplayV1Marker);
+
+/// Parses a decimal number with an optional exponent, such as "0.3048" or "6.67408E-11",
+/// returning `None` if it overflows.
+fn parse_number(number: &str) -> Result<Option<Ratio>, DataError> {
+    let invalid = || DataError::custom("Invalid number").with_display_context(number);
+    let (significand, exponent) = match number.split_once(|c| c == 'E' || c == 'e') {
+        Some((significand, exponent)) => {
+            (significand, exponent.parse::<i8>().map_err(|_| invalid())?)
+        }
+        None => (number, 0),
+    };
+    let (integer, fraction) = significand.split_once('.').unwrap_or((significand, ""));
+    let unsigned = integer.strip_prefix('-').unwrap_or(integer);
+    if unsigned.is_empty()
+        || !unsigned.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    // The digits are valid, so parsing only fails on overflow.
+    let digits = match format!("{}{}", integer, fraction).parse::<i128>() {
+        Ok(digits) => digits,
+        Err(_) => return Ok(None),
+    };
+    let scale = match i8::try_from(fraction.len()) {
+        Ok(len) => exponent.checked_sub(len),
+        Err(_) => None,
+    };
+    Ok(scale.and_then(|scale| {
+        Ratio::from_integer(digits).checked_mul(Ratio::from_integer(10).checked_powi(scale)?)
+    }))
+}
+
+/// Evaluates a CLDR conversion expression, which is a product of numbers and constants
+/// optionally divided by another, such as "ft2_to_m2*43560" or "2298.35/9". Returns `None`
+/// if it overflows.
+fn evaluate(
+    expression: &str,
+    constants: &HashMap<String, cldr_serde::units_data::Constant>,
+) -> Result<Option<Ratio>, DataError> {
+    let product = |terms: &str| -> Result<Option<Ratio>, DataError> {
+        let mut result = Ratio::ONE;
+        for term in terms.split('*').map(str::trim) {
+            let value = if term.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+                parse_number(term)?
+            } else {
+                let constant = constants.get(term).ok_or_else(|| {
+                    DataError::custom("Unknown constant").with_display_context(term)
+                })?;
+                evaluate(&constant.value, constants)?
+            };
+            result = match value.and_then(|value| result.checked_mul(value)) {
+                Some(result) => result,
+                None => return Ok(None),
+            };
+        }
+        Ok(Some(result))
+    };
+    Ok(match expression.split_once('/') {
+        Some((numerator, denominator)) => match (product(numerator)?, product(denominator)?) {
+            (Some(numerator), Some(denominator)) => numerator.checked_div(denominator),
+            _ => None,
+        },
+        None => product(expression)?,
+    })
+}
+
+impl DataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<UnitsInfoV1Marker>, DataError> {
+        let resource: &cldr_serde::units_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+
+        let data = &resource.supplemental;
+
+        let mut base_units = ZeroMap::new();
+        let mut factors = ZeroMap::new();
+        let mut offsets = ZeroMap::new();
+        // Sort by identifier, since the iteration order of the CLDR map is unspecified.
+        for (unit, conversion) in data.convert_units.iter().collect::<BTreeMap<_, _>>() {
+            // Units with nonlinear conversions, such as `beaufort`, are not supported.
+            if conversion.special.is_some() {
+                continue;
+            }
+            let factor = match &conversion.factor {
+                Some(factor) => evaluate(factor, &data.unit_constants)?,
+                None => Some(Ratio::ONE),
+            };
+            let offset = match &conversion.offset {
+                Some(offset) => evaluate(offset, &data.unit_constants)?,
+                None => Some(Ratio::ZERO),
+            };
+            // A few units, such as those defined using the gravitational constant, have
+            // factors that do not fit; they are left out.
+            let (factor, offset) = match (factor, offset) {
+                (Some(factor), Some(offset)) => (factor, offset),
+                _ => {
+                    log::warn!("Skipping unit {}: its conversion overflows", unit);
+                    continue;
+                }
+            };
+            base_units.insert(unit.as_str(), conversion.base_unit.as_str());
+            if factor != Ratio::ONE {
+                factors.insert(unit.as_str(), &RationalV1::from(factor));
+            }
+            if !offset.is_zero() {
+                offsets.insert(unit.as_str(), &RationalV1::from(offset));
+            }
+            // Base units, such as `kilogram` in `kilogram-meter-per-square-second`, are their
+            // own base units.
+            for token in conversion.base_unit.split('-') {
+                if !matches!(token, "per" | "square" | "cubic") && !token.starts_with("pow") {
+                    base_units.insert(token, token);
+                }
+            }
+        }
+
+        let quantities = data
+            .unit_quantities
+            .iter()
+            .map(|(base_unit, quantity)| (base_unit.as_str(), quantity.quantity.as_str()))
+            .collect::<ZeroMap<_, _>>();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitsInfoV1 {
+                base_units,
+                factors,
+                offsets,
+                quantities,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<UnitPreferencesV1Marker>, DataError> {
+        let resource: &cldr_serde::units_data::PreferenceResource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/unitPreferenceData.json")?;
+
+        let mut units = ZeroMap2d::new();
+        let mut thresholds = ZeroMap2d::new();
+        for (quantity, usages) in &resource.supplemental.unit_preference_data {
+            for (usage, regions) in usages {
+                for (region, preferences) in regions {
+                    let key = format!("{}/{}/{}", quantity, usage, region);
+                    // Mixed units, such as `foot-and-inch`, are not supported.
+                    let preferences = preferences
+                        .iter()
+                        .filter(|preference| !preference.unit.contains("-and-"));
+                    for (index, preference) in preferences.enumerate() {
+                        let index = u16::try_from(index)
+                            .map_err(|_| DataError::custom("Too many preferences"))?;
+                        units.insert(key.as_str(), &index, preference.unit.as_str());
+                        let threshold = match &preference.geq {
+                            Some(geq) => parse_number(&geq.0)?.ok_or_else(|| {
+                                DataError::custom("Threshold overflow").with_display_context(&geq.0)
+                            })?,
+                            None => continue,
+                        };
+                        if threshold != Ratio::ONE {
+                            thresholds.insert(key.as_str(), &index, &RationalV1::from(threshold));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitPreferencesV1 {
+                units,
+                thresholds,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_evaluate() {
+    use cldr_serde::units_data::Constant;
+
+    let constants = [
+        ("ft_to_m", "0.3048"),
+        ("ft2_to_m2", "ft_to_m*ft_to_m"),
+        ("G", "6.67408E-11"),
+    ]
+    .into_iter()
+    .map(|(name, value)| {
+        (
+            name.to_string(),
+            Constant {
+                value: value.to_string(),
+            },
+        )
+    })
+    .collect::<HashMap<_, _>>();
+
+    let eval = |expression| evaluate(expression, &constants).unwrap();
+    assert_eq!(eval("5/9"), Ratio::new(5, 9));
+    assert_eq!(eval("2298.35/9"), Ratio::new(45967, 180));
+    assert_eq!(eval("ft2_to_m2*43560"), Ratio::new(316_160_658, 78_125));
+    assert_eq!(eval("1/ft_to_m"), Ratio::new(1250, 381));
+    assert_eq!(eval("G"), Ratio::new(41713, 625_000_000_000_000));
+    assert_eq!(eval("G*G*G*G"), None);
+    assert!(evaluate("unknown_constant", &constants).is_err());
+}
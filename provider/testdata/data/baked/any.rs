@@ -27,8 +27,14 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::CopticDateLengthsV1Marker::KEY.get_hash();
         const COPTICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::CopticDateSymbolsV1Marker::KEY.get_hash();
+        const DATEINTERVALFORMATSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DateIntervalFormatsV1Marker::KEY.get_hash();
+        const DATENUMBERINGOVERRIDESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DateNumberingOverridesV1Marker::KEY.get_hash();
         const DATESKELETONPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker::KEY.get_hash();
+        const DAYPERIODSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DayPeriodsV1Marker::KEY.get_hash();
         const ETHIOPICDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::EthiopicDateLengthsV1Marker::KEY.get_hash();
         const ETHIOPICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
@@ -37,6 +43,10 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::GregorianDateLengthsV1Marker::KEY.get_hash();
         const GREGORIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::GregorianDateSymbolsV1Marker::KEY.get_hash();
+        const HEBREWDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker::KEY.get_hash();
+        const HEBREWDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker::KEY.get_hash();
         const INDIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::IndianDateLengthsV1Marker::KEY.get_hash();
         const INDIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
@@ -49,6 +59,16 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::JapaneseExtendedDateLengthsV1Marker::KEY.get_hash();
         const JAPANESEEXTENDEDDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::JapaneseExtendedDateSymbolsV1Marker::KEY.get_hash();
+        const PERSIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateLengthsV1Marker::KEY.get_hash();
+        const PERSIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker::KEY.get_hash();
+        const QUARTERSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::QuarterSymbolsV1Marker::KEY.get_hash();
+        const ROCDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateLengthsV1Marker::KEY.get_hash();
+        const ROCDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateSymbolsV1Marker::KEY.get_hash();
         const TIMELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::TimeLengthsV1Marker::KEY.get_hash();
         const TIMESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
@@ -68,8 +88,22 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker::KEY.get_hash();
         const WEEKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::week_data::WeekDataV1Marker::KEY.get_hash();
+        const CURRENCYFRACTIONSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::CurrencyFractionsV1Marker::KEY.get_hash();
+        const CURRENCYPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::CurrencyPatternsV1Marker::KEY.get_hash();
+        const CURRENCYSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::CurrencySymbolsV1Marker::KEY.get_hash();
         const DECIMALSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_decimal::provider::DecimalSymbolsV1Marker::KEY.get_hash();
+        const LONGCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::LongCompactDecimalFormatDataV1Marker::KEY.get_hash();
+        const PERCENTPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::PercentPatternsV1Marker::KEY.get_hash();
+        const SCIENTIFICSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::ScientificSymbolsV1Marker::KEY.get_hash();
+        const SHORTCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY.get_hash();
         const ANDLISTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_list::provider::AndListV1Marker::KEY.get_hash();
         const ORLISTV1MARKER: ::icu_provider::DataKeyHash =
@@ -231,6 +265,22 @@ impl AnyProvider for BakedDataProvider {
         const LOCALEFALLBACKPARENTSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker::KEY
                 .get_hash();
+        const DAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::DayRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const HOURRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::HourRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const MINUTERELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::MinuteRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const MONTHRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::MonthRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const QUARTERRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::QuarterRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const SECONDRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::SecondRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const WEEKRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::WeekRelativeTimeFormatDataV1Marker::KEY.get_hash();
+        const YEARRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::YearRelativeTimeFormatDataV1Marker::KEY.get_hash();
         const GRAPHEMECLUSTERBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker::KEY.get_hash();
         const LINEBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
@@ -245,6 +295,16 @@ impl AnyProvider for BakedDataProvider {
             ::icu_segmenter::provider::WordBreakDataV1Marker::KEY.get_hash();
         const METAZONEPERIODV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_timezone::provider::MetaZonePeriodV1Marker::KEY.get_hash();
+        const LONGUNITSDISPLAYV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::LongUnitsDisplayV1Marker::KEY.get_hash();
+        const NARROWUNITSDISPLAYV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::NarrowUnitsDisplayV1Marker::KEY.get_hash();
+        const SHORTUNITSDISPLAYV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::ShortUnitsDisplayV1Marker::KEY.get_hash();
+        const UNITPREFERENCESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::UnitPreferencesV1Marker::KEY.get_hash();
+        const UNITSINFOV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::UnitsInfoV1Marker::KEY.get_hash();
         Ok(AnyResponse {
             payload: Some(match key.get_hash() {
                 JAPANESEERASV1MARKER => calendar::japanese_v1::DATA
@@ -286,6 +346,12 @@ impl AnyProvider for BakedDataProvider {
                 COPTICDATESYMBOLSV1MARKER => datetime::coptic::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                DATEINTERVALFORMATSV1MARKER => datetime::intervalformats_v1_u_ca::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                DATENUMBERINGOVERRIDESV1MARKER => datetime::numbering_v1_u_ca::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 DATESKELETONPATTERNSV1MARKER => datetime::skeletons_v1_u_ca::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(|&data| {
@@ -295,6 +361,9 @@ impl AnyProvider for BakedDataProvider {
                             zerofrom::ZeroFrom::zero_from(data),
                         )))
                     }),
+                DAYPERIODSV1MARKER => datetime::dayperiods_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 ETHIOPICDATELENGTHSV1MARKER => datetime::ethiopic::datelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -307,6 +376,12 @@ impl AnyProvider for BakedDataProvider {
                 GREGORIANDATESYMBOLSV1MARKER => datetime::gregory::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                HEBREWDATELENGTHSV1MARKER => datetime::hebrew::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                HEBREWDATESYMBOLSV1MARKER => datetime::hebrew::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 INDIANDATELENGTHSV1MARKER => datetime::indian::datelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -325,6 +400,21 @@ impl AnyProvider for BakedDataProvider {
                 JAPANESEEXTENDEDDATESYMBOLSV1MARKER => datetime::japanext::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                PERSIANDATELENGTHSV1MARKER => datetime::persian::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                PERSIANDATESYMBOLSV1MARKER => datetime::persian::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                QUARTERSYMBOLSV1MARKER => datetime::quarters_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                ROCDATELENGTHSV1MARKER => datetime::roc::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                ROCDATESYMBOLSV1MARKER => datetime::roc::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 TIMELENGTHSV1MARKER => datetime::timelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -352,9 +442,30 @@ impl AnyProvider for BakedDataProvider {
                 WEEKDATAV1MARKER => datetime::week_data_v1_r::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                CURRENCYFRACTIONSV1MARKER => currency::fractions_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                CURRENCYPATTERNSV1MARKER => currency::patterns_v1_u_nu::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                CURRENCYSYMBOLSV1MARKER => currency::symbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 DECIMALSYMBOLSV1MARKER => decimal::symbols_v1_u_nu::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                LONGCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::long_v1_u_nu::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                PERCENTPATTERNSV1MARKER => decimal::percent_v1_u_nu::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                SCIENTIFICSYMBOLSV1MARKER => decimal::scientific_v1_u_nu::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                SHORTCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::short_v1_u_nu::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 ANDLISTV1MARKER => list::and_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -592,6 +703,30 @@ impl AnyProvider for BakedDataProvider {
                 LOCALEFALLBACKPARENTSV1MARKER => fallback::parents_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                DAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::day_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                HOURRELATIVETIMEFORMATDATAV1MARKER => relativetime::hour_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                MINUTERELATIVETIMEFORMATDATAV1MARKER => relativetime::minute_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                MONTHRELATIVETIMEFORMATDATAV1MARKER => relativetime::month_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                QUARTERRELATIVETIMEFORMATDATAV1MARKER => relativetime::quarter_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                SECONDRELATIVETIMEFORMATDATAV1MARKER => relativetime::second_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                WEEKRELATIVETIMEFORMATDATAV1MARKER => relativetime::week_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                YEARRELATIVETIMEFORMATDATAV1MARKER => relativetime::year_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 GRAPHEMECLUSTERBREAKDATAV1MARKER => segmenter::grapheme_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -613,6 +748,21 @@ impl AnyProvider for BakedDataProvider {
                 METAZONEPERIODV1MARKER => time_zone::metazone_period_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                LONGUNITSDISPLAYV1MARKER => units::long_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                NARROWUNITSDISPLAYV1MARKER => units::narrow_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                SHORTUNITSDISPLAYV1MARKER => units::short_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                UNITPREFERENCESV1MARKER => units::preferences_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                UNITSINFOV1MARKER => units::info_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 _ => return Err(DataErrorKind::MissingDataKey.with_req(key, req)),
             })
             .ok_or_else(|| DataErrorKind::MissingLocale.with_req(key, req))?,
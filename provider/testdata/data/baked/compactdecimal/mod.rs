@@ -0,0 +1,3 @@
+// @generated
+pub mod long_v1_u_nu;
+pub mod short_v1_u_nu;
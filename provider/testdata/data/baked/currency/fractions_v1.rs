@@ -0,0 +1,61 @@
+// @generated
+type DataStruct =
+    <::icu_decimal::provider::CurrencyFractionsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_decimal::provider::CurrencyFractionsV1 {
+    default: ::icu_decimal::provider::CurrencyFractionV1 {
+        digits: 2u8,
+        rounding: 0u8,
+        cash_digits: 2u8,
+        cash_rounding: 0u8,
+    },
+    fractions: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 68u8, 80u8, 65u8, 70u8, 78u8, 65u8, 76u8, 76u8, 65u8, 77u8, 68u8, 66u8,
+                    72u8, 68u8, 66u8, 73u8, 70u8, 66u8, 89u8, 82u8, 67u8, 65u8, 68u8, 67u8, 72u8,
+                    70u8, 67u8, 76u8, 70u8, 67u8, 76u8, 80u8, 67u8, 79u8, 80u8, 67u8, 82u8, 67u8,
+                    67u8, 90u8, 75u8, 68u8, 74u8, 70u8, 68u8, 75u8, 75u8, 69u8, 83u8, 80u8, 71u8,
+                    78u8, 70u8, 71u8, 89u8, 68u8, 72u8, 85u8, 70u8, 73u8, 68u8, 82u8, 73u8, 81u8,
+                    68u8, 73u8, 82u8, 82u8, 73u8, 83u8, 75u8, 73u8, 84u8, 76u8, 74u8, 79u8, 68u8,
+                    74u8, 80u8, 89u8, 75u8, 77u8, 70u8, 75u8, 80u8, 87u8, 75u8, 82u8, 87u8, 75u8,
+                    87u8, 68u8, 76u8, 65u8, 75u8, 76u8, 66u8, 80u8, 76u8, 85u8, 70u8, 76u8, 89u8,
+                    68u8, 77u8, 71u8, 65u8, 77u8, 71u8, 70u8, 77u8, 77u8, 75u8, 77u8, 78u8, 84u8,
+                    77u8, 82u8, 79u8, 77u8, 85u8, 82u8, 78u8, 79u8, 75u8, 79u8, 77u8, 82u8, 80u8,
+                    75u8, 82u8, 80u8, 89u8, 71u8, 82u8, 83u8, 68u8, 82u8, 87u8, 70u8, 83u8, 69u8,
+                    75u8, 83u8, 76u8, 76u8, 83u8, 79u8, 83u8, 83u8, 84u8, 68u8, 83u8, 89u8, 80u8,
+                    84u8, 77u8, 77u8, 84u8, 78u8, 68u8, 84u8, 82u8, 76u8, 84u8, 87u8, 68u8, 84u8,
+                    90u8, 83u8, 85u8, 71u8, 88u8, 85u8, 89u8, 73u8, 85u8, 89u8, 87u8, 85u8, 90u8,
+                    83u8, 86u8, 69u8, 70u8, 86u8, 78u8, 68u8, 86u8, 85u8, 86u8, 88u8, 65u8, 70u8,
+                    88u8, 79u8, 70u8, 88u8, 80u8, 70u8, 89u8, 69u8, 82u8, 90u8, 77u8, 75u8, 90u8,
+                    87u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 2u8, 5u8,
+                    2u8, 0u8, 2u8, 5u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 2u8,
+                    50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+                    4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
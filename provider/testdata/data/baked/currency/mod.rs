@@ -0,0 +1,4 @@
+// @generated
+pub mod fractions_v1;
+pub mod patterns_v1_u_nu;
+pub mod symbols_v1;
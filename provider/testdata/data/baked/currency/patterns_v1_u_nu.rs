@@ -0,0 +1,403 @@
+// @generated
+type DataStruct =
+    <::icu_decimal::provider::CurrencyPatternsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("ar-EG-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("en", EN_EN_001_EN_ZA_FIL_TR),
+        ("en-001", EN_EN_001_EN_ZA_FIL_TR),
+        ("en-ZA", EN_EN_001_EN_ZA_FIL_TR),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", EN_EN_001_EN_ZA_FIL_TR),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL_SR_LATN),
+        ("sr-Cyrl", SR_SR_CYRL_SR_LATN),
+        ("sr-Latn", SR_SR_CYRL_SR_LATN),
+        ("th", TH_TH_U_NU_THAI),
+        ("th-u-nu-thai", TH_TH_U_NU_THAI),
+        ("tr", EN_EN_001_EN_ZA_FIL_TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        few: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        many: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static AR_EG_U_NU_LATN_AR_U_NU_LATN: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("\u{200e}-¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        few: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        many: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN: &DataStruct =
+    &::icu_decimal::provider::CurrencyPatternsV1 {
+        standard: ::icu_decimal::provider::SignedAffixesV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed("¤"),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: alloc::borrow::Cow::Borrowed("-"),
+                suffix: alloc::borrow::Cow::Borrowed("¤"),
+            },
+        },
+        accounting: ::icu_decimal::provider::SignedAffixesV1 {
+            positive: ::icu_decimal::provider::AffixesV1 {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed("¤"),
+            },
+            negative: ::icu_decimal::provider::AffixesV1 {
+                prefix: alloc::borrow::Cow::Borrowed("("),
+                suffix: alloc::borrow::Cow::Borrowed("¤)"),
+            },
+        },
+        spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+        unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+            zero: None,
+            one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+            two: None,
+            few: None,
+            many: None,
+            other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+        },
+    };
+static EN_EN_001_EN_ZA_FIL_TR: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static ES: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static ES_AR: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("(¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static FR: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤)"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static JA: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: None,
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0}{1}"),
+    },
+};
+static RU: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        many: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static SR_SR_CYRL_SR_LATN: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤"),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            suffix: alloc::borrow::Cow::Borrowed("\u{a0}¤)"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        two: None,
+        few: Some(alloc::borrow::Cow::Borrowed("{0} {1}")),
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static TH_TH_U_NU_THAI: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("(¤"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: None,
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
+static UND: &DataStruct = &::icu_decimal::provider::CurrencyPatternsV1 {
+    standard: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    accounting: ::icu_decimal::provider::SignedAffixesV1 {
+        positive: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        negative: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed("-¤\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+    },
+    spacing: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    unit_patterns: ::icu_decimal::provider::PluralPatternsV1 {
+        zero: None,
+        one: None,
+        two: None,
+        few: None,
+        many: None,
+        other: alloc::borrow::Cow::Borrowed("{0} {1}"),
+    },
+};
@@ -0,0 +1,726 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: DayPeriodsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+            1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+        ])
+    },
+    noon: false,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("فجر\u{64b}ا")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("ص")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ظهر\u{64b}ا")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("بعد الظهر")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("مساء\u{64b}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("في المساء")),
+                night2: Some(alloc::borrow::Cow::Borrowed("ليلا\u{64b}")),
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("فجر\u{64b}ا")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("صباح\u{64b}ا")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ظهر\u{64b}ا")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("بعد الظهر")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("مساء\u{64b}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("منتصف الليل")),
+                night2: Some(alloc::borrow::Cow::Borrowed("ليلا\u{64b}")),
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("في الصباح")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("صباح\u{64b}ا")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ظهر\u{64b}ا")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("بعد الظهر")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("مساء\u{64b}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("في المساء")),
+                night2: Some(alloc::borrow::Cow::Borrowed("ليلا\u{64b}")),
+            },
+        },
+        stand_alone: None,
+    },
+};
+static BN: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 4u8, 4u8, 4u8, 4u8, 6u8,
+            6u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: false,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ভোর")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("সক\u{9be}ল")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("দ\u{9c1}প\u{9c1}র")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("বিক\u{9be}ল")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("সন\u{9cd}ধ\u{9cd}য\u{9be}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("র\u{9be}ত\u{9cd}রি")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ভোর")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("সক\u{9be}ল")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("দ\u{9c1}প\u{9c1}র")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("বিক\u{9be}ল")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("সন\u{9cd}ধ\u{9cd}য\u{9be}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("র\u{9be}ত\u{9cd}রি")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ভোর")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("সক\u{9be}ল")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("দ\u{9c1}প\u{9c1}র")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("বিক\u{9be}ল")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("সন\u{9cd}ধ\u{9cd}য\u{9be}")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("র\u{9be}ত\u{9cd}রিতে")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static CCP: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+            1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+        ])
+    },
+    noon: false,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄛\u{11127}𑄖\u{11133}𑄠𑄃𑄟\u{11127}𑄣\u{11133}𑄠𑄬",
+                )),
+                morning2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄚\u{11133}𑄠𑄬")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄘\u{11128}𑄝\u{1112a}𑄎\u{11133}𑄠",
+                )),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄣\u{11133}𑄠𑄬")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("𑄥𑄎\u{11127}𑄚\u{11133}𑄠")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("𑄢𑄬𑄖\u{11134}")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄛\u{11127}𑄖\u{11133}𑄠𑄃𑄟\u{11127}𑄣\u{11133}𑄠𑄬",
+                )),
+                morning2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄚\u{11133}𑄠𑄬")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄘\u{11128}𑄝\u{1112a}𑄎\u{11133}𑄠",
+                )),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄣\u{11133}𑄠𑄬")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("𑄥𑄎\u{11127}𑄚\u{11133}𑄠")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("𑄢𑄬𑄖\u{11134}")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄛\u{11127}𑄖\u{11133}𑄠𑄃𑄟\u{11127}𑄣\u{11133}𑄠𑄬",
+                )),
+                morning2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄚\u{11133}𑄠𑄬")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed(
+                    "𑄘\u{11128}𑄝\u{1112a}𑄎\u{11133}𑄠",
+                )),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("𑄝𑄬𑄣\u{11133}𑄠𑄬")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("𑄥𑄎\u{11127}𑄚\u{11133}𑄠")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("𑄢𑄬𑄖\u{11134}")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 6u8, 6u8, 6u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("in the morning")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("in the afternoon")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("in the evening")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("at night")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("in the morning")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("in the afternoon")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("in the evening")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("at night")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("in the morning")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("in the afternoon")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("in the evening")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("at night")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static ES: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 6u8, 6u8, 6u8, 6u8, 6u8,
+            6u8, 6u8, 6u8, 8u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("de la madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("de la mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("de la tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("de la noche")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("de la madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("de la mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("de la tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("de la noche")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("de la madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("de la mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("de la tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("de la noche")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 6u8, 6u8, 6u8, 6u8, 6u8,
+            6u8, 6u8, 6u8, 8u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("noche")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("de la madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("de la mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("de la tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("de la noche")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("madrugada")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("mañana")),
+                afternoon1: None,
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("tarde")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("noche")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static FIL: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 4u8, 4u8, 4u8, 4u8, 6u8,
+            6u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("nang umaga")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("madaling-araw")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("tanghali")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("ng hapon")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("gabi")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("umaga")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("madaling-araw")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("sa hapon")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("sa gabi")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("gabi")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("nang umaga")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("madaling-araw")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("tanghali")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("ng hapon")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ng gabi")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static FR: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 6u8, 6u8, 6u8, 6u8, 6u8, 6u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("mat.")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ap.m.")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("soir")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("nuit")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("mat.")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ap.m.")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("soir")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("nuit")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("du matin")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("de l’après-midi")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("du soir")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("du matin")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static JA: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            9u8, 9u8, 9u8, 9u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 6u8,
+            6u8, 6u8, 8u8, 8u8, 8u8, 8u8, 9u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("朝")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("昼")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("夕方")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("夜")),
+                night2: Some(alloc::borrow::Cow::Borrowed("夜中")),
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("朝")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("昼")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("夕方")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("夜")),
+                night2: Some(alloc::borrow::Cow::Borrowed("夜中")),
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("朝")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("昼")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("夕方")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("夜")),
+                night2: Some(alloc::borrow::Cow::Borrowed("夜中")),
+            },
+        },
+        stand_alone: None,
+    },
+};
+static RU: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 6u8, 6u8, 6u8, 6u8, 6u8, 6u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("утра")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("дня")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("вечера")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ночи")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("утра")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("дня")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("веч.")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ночи")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("утра")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("дня")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("вечера")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ночи")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 6u8, 6u8, 6u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ujutro")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("po podne")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("uveče")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("noću")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ujutru")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("po podne")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("uveče")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("noću")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ujutro")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("po podne")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("uveče")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("noću")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 6u8, 6u8, 6u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ујутро")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("по подне")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("увече")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ноћу")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ујутру")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("по подне")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("увече")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ноћу")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ујутро")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("по подне")),
+                afternoon2: None,
+                evening1: Some(alloc::borrow::Cow::Borrowed("увече")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("ноћу")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static TH: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 5u8, 5u8, 5u8, 6u8,
+            6u8, 7u8, 7u8, 7u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ในตอนเช\u{e49}า")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ในตอนบ\u{e48}าย")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("บ\u{e48}าย")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("ในตอนเย\u{e47}น")),
+                evening2: Some(alloc::borrow::Cow::Borrowed("ค\u{e48}ำ")),
+                night1: Some(alloc::borrow::Cow::Borrowed("กลางค\u{e37}น")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("เช\u{e49}า")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยง")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("บ\u{e48}าย")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("เย\u{e47}น")),
+                evening2: Some(alloc::borrow::Cow::Borrowed("ค\u{e48}ำ")),
+                night1: Some(alloc::borrow::Cow::Borrowed("กลางค\u{e37}น")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("ในตอนเช\u{e49}า")),
+                morning2: None,
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("ในตอนบ\u{e48}าย")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("บ\u{e48}าย")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("ในตอนเย\u{e47}น")),
+                evening2: Some(alloc::borrow::Cow::Borrowed("ค\u{e48}ำ")),
+                night1: Some(alloc::borrow::Cow::Borrowed("กลางค\u{e37}น")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static TR: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+            4u8, 5u8, 6u8, 6u8, 8u8, 8u8, 8u8,
+        ])
+    },
+    noon: true,
+    midnight: true,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("sabah")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("öğleden önce")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("öğleden sonra")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("akşamüstü")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("akşam")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("gece")),
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("sabah")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("öğleden önce")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("öğleden sonra")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("akşamüstü")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("akşam")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("gece")),
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: Some(alloc::borrow::Cow::Borrowed("sabah")),
+                morning2: Some(alloc::borrow::Cow::Borrowed("öğleden önce")),
+                afternoon1: Some(alloc::borrow::Cow::Borrowed("öğleden sonra")),
+                afternoon2: Some(alloc::borrow::Cow::Borrowed("akşamüstü")),
+                evening1: Some(alloc::borrow::Cow::Borrowed("akşam")),
+                evening2: None,
+                night1: Some(alloc::borrow::Cow::Borrowed("gece")),
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DayPeriodsV1 {
+    hours: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+            1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+        ])
+    },
+    noon: false,
+    midnight: false,
+    symbols: ::icu_datetime::provider::calendar::flexible_day_periods::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: None,
+                morning2: None,
+                afternoon1: None,
+                afternoon2: None,
+                evening1: None,
+                evening2: None,
+                night1: None,
+                night2: None,
+            },
+            narrow: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: None,
+                morning2: None,
+                afternoon1: None,
+                afternoon2: None,
+                evening1: None,
+                evening2: None,
+                night1: None,
+                night2: None,
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                morning1: None,
+                morning2: None,
+                afternoon1: None,
+                afternoon2: None,
+                evening1: None,
+                evening2: None,
+                night1: None,
+                night2: None,
+            },
+        },
+        stand_alone: None,
+    },
+};
@@ -0,0 +1,3311 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: HebrewDateSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 22u8, 0u8, 32u8, 0u8,
+                                    40u8, 0u8, 48u8, 0u8, 67u8, 0u8, 75u8, 0u8, 85u8, 0u8, 93u8,
+                                    0u8, 103u8, 0u8, 111u8, 0u8, 115u8, 0u8, 216u8, 170u8, 216u8,
+                                    180u8, 216u8, 177u8, 217u8, 138u8, 217u8, 133u8, 216u8, 177u8,
+                                    216u8, 173u8, 216u8, 180u8, 217u8, 136u8, 216u8, 167u8, 217u8,
+                                    134u8, 217u8, 131u8, 217u8, 138u8, 216u8, 179u8, 217u8, 132u8,
+                                    217u8, 136u8, 216u8, 183u8, 217u8, 138u8, 217u8, 129u8, 216u8,
+                                    170u8, 216u8, 180u8, 216u8, 168u8, 216u8, 167u8, 216u8, 183u8,
+                                    216u8, 162u8, 216u8, 176u8, 216u8, 167u8, 216u8, 177u8, 32u8,
+                                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 136u8, 217u8,
+                                    132u8, 216u8, 162u8, 216u8, 176u8, 216u8, 167u8, 216u8, 177u8,
+                                    217u8, 134u8, 217u8, 138u8, 216u8, 179u8, 216u8, 167u8, 217u8,
+                                    134u8, 216u8, 163u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8,
+                                    216u8, 179u8, 217u8, 138u8, 217u8, 129u8, 216u8, 167u8, 217u8,
+                                    134u8, 216u8, 170u8, 217u8, 133u8, 217u8, 136u8, 216u8, 178u8,
+                                    216u8, 162u8, 216u8, 168u8, 216u8, 163u8, 217u8, 138u8, 217u8,
+                                    132u8, 217u8, 136u8, 217u8, 132u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 162u8, 216u8, 176u8,
+                                    216u8, 167u8, 216u8, 177u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                                    216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 22u8, 0u8, 32u8, 0u8,
+                                    40u8, 0u8, 48u8, 0u8, 67u8, 0u8, 75u8, 0u8, 85u8, 0u8, 93u8,
+                                    0u8, 103u8, 0u8, 111u8, 0u8, 115u8, 0u8, 216u8, 170u8, 216u8,
+                                    180u8, 216u8, 177u8, 217u8, 138u8, 217u8, 133u8, 216u8, 177u8,
+                                    216u8, 173u8, 216u8, 180u8, 217u8, 136u8, 216u8, 167u8, 217u8,
+                                    134u8, 217u8, 131u8, 217u8, 138u8, 216u8, 179u8, 217u8, 132u8,
+                                    217u8, 136u8, 216u8, 183u8, 217u8, 138u8, 217u8, 129u8, 216u8,
+                                    170u8, 216u8, 180u8, 216u8, 168u8, 216u8, 167u8, 216u8, 183u8,
+                                    216u8, 162u8, 216u8, 176u8, 216u8, 167u8, 216u8, 177u8, 32u8,
+                                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 136u8, 217u8,
+                                    132u8, 216u8, 162u8, 216u8, 176u8, 216u8, 167u8, 216u8, 177u8,
+                                    217u8, 134u8, 217u8, 138u8, 216u8, 179u8, 216u8, 167u8, 217u8,
+                                    134u8, 216u8, 163u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8,
+                                    216u8, 179u8, 217u8, 138u8, 217u8, 129u8, 216u8, 167u8, 217u8,
+                                    134u8, 216u8, 170u8, 217u8, 133u8, 217u8, 136u8, 216u8, 178u8,
+                                    216u8, 162u8, 216u8, 168u8, 216u8, 163u8, 217u8, 138u8, 217u8,
+                                    132u8, 217u8, 136u8, 217u8, 132u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 162u8, 216u8, 176u8,
+                                    216u8, 167u8, 216u8, 177u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                                    216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الأحد"),
+                alloc::borrow::Cow::Borrowed("الاثنين"),
+                alloc::borrow::Cow::Borrowed("الثلاثاء"),
+                alloc::borrow::Cow::Borrowed("الأربعاء"),
+                alloc::borrow::Cow::Borrowed("الخميس"),
+                alloc::borrow::Cow::Borrowed("الجمعة"),
+                alloc::borrow::Cow::Borrowed("السبت"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ح"),
+                alloc::borrow::Cow::Borrowed("ن"),
+                alloc::borrow::Cow::Borrowed("ث"),
+                alloc::borrow::Cow::Borrowed("ر"),
+                alloc::borrow::Cow::Borrowed("خ"),
+                alloc::borrow::Cow::Borrowed("ج"),
+                alloc::borrow::Cow::Borrowed("س"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("أحد"),
+                alloc::borrow::Cow::Borrowed("إثنين"),
+                alloc::borrow::Cow::Borrowed("ثلاثاء"),
+                alloc::borrow::Cow::Borrowed("أربعاء"),
+                alloc::borrow::Cow::Borrowed("خميس"),
+                alloc::borrow::Cow::Borrowed("جمعة"),
+                alloc::borrow::Cow::Borrowed("سبت"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الأحد"),
+                alloc::borrow::Cow::Borrowed("الاثنين"),
+                alloc::borrow::Cow::Borrowed("الثلاثاء"),
+                alloc::borrow::Cow::Borrowed("الأربعاء"),
+                alloc::borrow::Cow::Borrowed("الخميس"),
+                alloc::borrow::Cow::Borrowed("الجمعة"),
+                alloc::borrow::Cow::Borrowed("السبت"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 181u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 181u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 181u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 33u8, 0u8, 51u8, 0u8,
+                                    66u8, 0u8, 81u8, 0u8, 95u8, 0u8, 107u8, 0u8, 122u8, 0u8, 137u8,
+                                    0u8, 152u8, 0u8, 167u8, 0u8, 173u8, 0u8, 224u8, 166u8, 164u8,
+                                    224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 176u8,
+                                    224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 182u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8,
+                                    224u8, 166u8, 168u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                                    224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 173u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 173u8, 224u8, 167u8, 135u8, 224u8, 166u8, 164u8,
+                                    224u8, 166u8, 182u8, 224u8, 167u8, 135u8, 224u8, 166u8, 173u8,
+                                    224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 166u8, 134u8,
+                                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                                    32u8, 73u8, 224u8, 166u8, 134u8, 224u8, 166u8, 161u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 168u8, 224u8,
+                                    166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 190u8, 224u8,
+                                    166u8, 168u8, 224u8, 166u8, 134u8, 224u8, 166u8, 175u8, 224u8,
+                                    166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                                    166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 173u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 164u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8,
+                                    166u8, 156u8, 224u8, 166u8, 133u8, 224u8, 166u8, 173u8, 224u8,
+                                    166u8, 143u8, 224u8, 166u8, 178u8, 224u8, 167u8, 129u8, 224u8,
+                                    166u8, 178u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 134u8, 224u8,
+                                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 9u8, 0u8,
+                                    12u8, 0u8, 15u8, 0u8, 18u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8,
+                                    0u8, 33u8, 0u8, 39u8, 0u8, 45u8, 0u8, 224u8, 167u8, 167u8,
+                                    224u8, 167u8, 168u8, 224u8, 167u8, 169u8, 224u8, 167u8, 170u8,
+                                    224u8, 167u8, 171u8, 224u8, 167u8, 172u8, 224u8, 167u8, 173u8,
+                                    224u8, 167u8, 174u8, 224u8, 167u8, 175u8, 224u8, 167u8, 167u8,
+                                    224u8, 167u8, 166u8, 224u8, 167u8, 167u8, 224u8, 167u8, 167u8,
+                                    224u8, 167u8, 167u8, 224u8, 167u8, 168u8, 224u8, 167u8, 167u8,
+                                    224u8, 167u8, 169u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 167u8, 173u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 33u8, 0u8, 51u8, 0u8,
+                                    66u8, 0u8, 81u8, 0u8, 95u8, 0u8, 107u8, 0u8, 122u8, 0u8, 137u8,
+                                    0u8, 152u8, 0u8, 167u8, 0u8, 173u8, 0u8, 224u8, 166u8, 164u8,
+                                    224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 176u8,
+                                    224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 182u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8,
+                                    224u8, 166u8, 168u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                                    224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 173u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8,
+                                    224u8, 166u8, 173u8, 224u8, 167u8, 135u8, 224u8, 166u8, 164u8,
+                                    224u8, 166u8, 182u8, 224u8, 167u8, 135u8, 224u8, 166u8, 173u8,
+                                    224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 166u8, 134u8,
+                                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                                    32u8, 73u8, 224u8, 166u8, 134u8, 224u8, 166u8, 161u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 168u8, 224u8,
+                                    166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 190u8, 224u8,
+                                    166u8, 168u8, 224u8, 166u8, 134u8, 224u8, 166u8, 175u8, 224u8,
+                                    166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                                    166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 173u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 164u8, 224u8,
+                                    166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8,
+                                    166u8, 156u8, 224u8, 166u8, 133u8, 224u8, 166u8, 173u8, 224u8,
+                                    166u8, 143u8, 224u8, 166u8, 178u8, 224u8, 167u8, 129u8, 224u8,
+                                    166u8, 178u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 134u8, 224u8,
+                                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রবি"),
+                alloc::borrow::Cow::Borrowed("সোম"),
+                alloc::borrow::Cow::Borrowed("মঙ\u{9cd}গল"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ধ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}হস\u{9cd}পতি"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ক\u{9cd}র"),
+                alloc::borrow::Cow::Borrowed("শনি"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("র"),
+                alloc::borrow::Cow::Borrowed("সো"),
+                alloc::borrow::Cow::Borrowed("ম"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}"),
+                alloc::borrow::Cow::Borrowed("শ"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রঃ"),
+                alloc::borrow::Cow::Borrowed("সোঃ"),
+                alloc::borrow::Cow::Borrowed("মঃ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ঃ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}ঃ"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ঃ"),
+                alloc::borrow::Cow::Borrowed("শনি"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রবিব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("সোমব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("মঙ\u{9cd}গলব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ধব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}হস\u{9cd}পতিব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ক\u{9cd}রব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("শনিব\u{9be}র"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Su"),
+                alloc::borrow::Cow::Borrowed("Mo"),
+                alloc::borrow::Cow::Borrowed("Tu"),
+                alloc::borrow::Cow::Borrowed("We"),
+                alloc::borrow::Cow::Borrowed("Th"),
+                alloc::borrow::Cow::Borrowed("Fr"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sunday"),
+                alloc::borrow::Cow::Borrowed("Monday"),
+                alloc::borrow::Cow::Borrowed("Tuesday"),
+                alloc::borrow::Cow::Borrowed("Wednesday"),
+                alloc::borrow::Cow::Borrowed("Thursday"),
+                alloc::borrow::Cow::Borrowed("Friday"),
+                alloc::borrow::Cow::Borrowed("Saturday"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 116u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 104u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 107u8, 105u8, 115u8, 108u8, 101u8, 118u8, 116u8, 101u8,
+                                    118u8, 101u8, 116u8, 115u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    97u8, 100u8, 97u8, 114u8, 32u8, 73u8, 97u8, 100u8, 97u8, 114u8,
+                                    110u8, 105u8, 115u8, 97u8, 110u8, 105u8, 121u8, 97u8, 114u8,
+                                    115u8, 105u8, 118u8, 97u8, 110u8, 116u8, 97u8, 109u8, 117u8,
+                                    122u8, 97u8, 118u8, 101u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 116u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 104u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 107u8, 105u8, 115u8, 108u8, 101u8, 118u8, 116u8, 101u8,
+                                    118u8, 101u8, 116u8, 115u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    97u8, 100u8, 97u8, 114u8, 32u8, 73u8, 97u8, 100u8, 97u8, 114u8,
+                                    110u8, 105u8, 115u8, 97u8, 110u8, 105u8, 121u8, 97u8, 114u8,
+                                    115u8, 105u8, 118u8, 97u8, 110u8, 116u8, 97u8, 109u8, 117u8,
+                                    122u8, 97u8, 118u8, 101u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dom"),
+                alloc::borrow::Cow::Borrowed("lun"),
+                alloc::borrow::Cow::Borrowed("mar"),
+                alloc::borrow::Cow::Borrowed("mié"),
+                alloc::borrow::Cow::Borrowed("jue"),
+                alloc::borrow::Cow::Borrowed("vie"),
+                alloc::borrow::Cow::Borrowed("sáb"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("D"),
+                alloc::borrow::Cow::Borrowed("L"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("X"),
+                alloc::borrow::Cow::Borrowed("J"),
+                alloc::borrow::Cow::Borrowed("V"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("DO"),
+                alloc::borrow::Cow::Borrowed("LU"),
+                alloc::borrow::Cow::Borrowed("MA"),
+                alloc::borrow::Cow::Borrowed("MI"),
+                alloc::borrow::Cow::Borrowed("JU"),
+                alloc::borrow::Cow::Borrowed("VI"),
+                alloc::borrow::Cow::Borrowed("SA"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("domingo"),
+                alloc::borrow::Cow::Borrowed("lunes"),
+                alloc::borrow::Cow::Borrowed("martes"),
+                alloc::borrow::Cow::Borrowed("miércoles"),
+                alloc::borrow::Cow::Borrowed("jueves"),
+                alloc::borrow::Cow::Borrowed("viernes"),
+                alloc::borrow::Cow::Borrowed("sábado"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static ES_AR : & DataStruct = & :: icu_datetime :: provider :: calendar :: DateSymbolsV1 { months : :: icu_datetime :: provider :: calendar :: months :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: months :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: OtherWithLeapYearVariants { symbols : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 53u8 , 76u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 6u8 , 0u8 , 13u8 , 0u8 , 19u8 , 0u8 , 24u8 , 0u8 , 30u8 , 0u8 , 36u8 , 0u8 , 40u8 , 0u8 , 45u8 , 0u8 , 49u8 , 0u8 , 54u8 , 0u8 , 59u8 , 0u8 , 61u8 , 0u8 , 84u8 , 105u8 , 115u8 , 104u8 , 114u8 , 105u8 , 72u8 , 101u8 , 115u8 , 104u8 , 118u8 , 97u8 , 110u8 , 75u8 , 105u8 , 115u8 , 108u8 , 101u8 , 118u8 , 84u8 , 101u8 , 118u8 , 101u8 , 116u8 , 83u8 , 104u8 , 101u8 , 118u8 , 97u8 , 116u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 65u8 , 100u8 , 97u8 , 114u8 , 78u8 , 105u8 , 115u8 , 97u8 , 110u8 , 73u8 , 121u8 , 97u8 , 114u8 , 83u8 , 105u8 , 118u8 , 97u8 , 110u8 , 84u8 , 97u8 , 109u8 , 117u8 , 122u8 , 65u8 , 118u8 , 69u8 , 108u8 , 117u8 , 108u8]) }) } , leap_year_variants : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 54u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 73u8]) }) } , } , narrow : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: OtherWithLeapYearVariants { symbols : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 53u8 , 76u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 1u8 , 0u8 , 2u8 , 0u8 , 3u8 , 0u8 , 4u8 , 0u8 , 5u8 , 0u8 , 6u8 , 0u8 , 7u8 , 0u8 , 8u8 , 0u8 , 9u8 , 0u8 , 11u8 , 0u8 , 13u8 , 0u8 , 15u8 , 0u8 , 49u8 , 50u8 , 51u8 , 52u8 , 53u8 , 54u8 , 55u8 , 56u8 , 57u8 , 49u8 , 48u8 , 49u8 , 49u8 , 49u8 , 50u8 , 49u8 , 51u8]) }) } , leap_year_variants : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 54u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 55u8]) }) } , } , short : None , wide : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: OtherWithLeapYearVariants { symbols : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 53u8 , 76u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 6u8 , 0u8 , 13u8 , 0u8 , 19u8 , 0u8 , 24u8 , 0u8 , 30u8 , 0u8 , 36u8 , 0u8 , 40u8 , 0u8 , 45u8 , 0u8 , 49u8 , 0u8 , 54u8 , 0u8 , 59u8 , 0u8 , 61u8 , 0u8 , 84u8 , 105u8 , 115u8 , 104u8 , 114u8 , 105u8 , 72u8 , 101u8 , 115u8 , 104u8 , 118u8 , 97u8 , 110u8 , 75u8 , 105u8 , 115u8 , 108u8 , 101u8 , 118u8 , 84u8 , 101u8 , 118u8 , 101u8 , 116u8 , 83u8 , 104u8 , 101u8 , 118u8 , 97u8 , 116u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 65u8 , 100u8 , 97u8 , 114u8 , 78u8 , 105u8 , 115u8 , 97u8 , 110u8 , 73u8 , 121u8 , 97u8 , 114u8 , 83u8 , 105u8 , 118u8 , 97u8 , 110u8 , 84u8 , 97u8 , 109u8 , 117u8 , 122u8 , 65u8 , 118u8 , 69u8 , 108u8 , 117u8 , 108u8]) }) } , leap_year_variants : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 54u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 73u8]) }) } , } , } , stand_alone : Some (:: icu_datetime :: provider :: calendar :: months :: StandAloneWidthsV1 { abbreviated : Some (:: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: OtherWithLeapYearVariants { symbols : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 53u8 , 76u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 6u8 , 0u8 , 13u8 , 0u8 , 19u8 , 0u8 , 24u8 , 0u8 , 30u8 , 0u8 , 36u8 , 0u8 , 40u8 , 0u8 , 45u8 , 0u8 , 49u8 , 0u8 , 54u8 , 0u8 , 59u8 , 0u8 , 61u8 , 0u8 , 84u8 , 105u8 , 115u8 , 104u8 , 114u8 , 105u8 , 72u8 , 101u8 , 115u8 , 104u8 , 118u8 , 97u8 , 110u8 , 75u8 , 105u8 , 115u8 , 108u8 , 101u8 , 118u8 , 84u8 , 101u8 , 118u8 , 101u8 , 116u8 , 83u8 , 104u8 , 101u8 , 118u8 , 97u8 , 116u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 65u8 , 100u8 , 97u8 , 114u8 , 78u8 , 105u8 , 115u8 , 97u8 , 110u8 , 73u8 , 121u8 , 97u8 , 114u8 , 83u8 , 105u8 , 118u8 , 97u8 , 110u8 , 84u8 , 97u8 , 109u8 , 117u8 , 122u8 , 97u8 , 118u8 , 101u8 , 108u8 , 117u8 , 108u8]) }) } , leap_year_variants : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 54u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 100u8 , 97u8 , 114u8 , 32u8 , 73u8 , 73u8]) }) } , }) , narrow : None , short : None , wide : None , }) , } , weekdays : :: icu_datetime :: provider :: calendar :: weekdays :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: weekdays :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("dom") , alloc :: borrow :: Cow :: Borrowed ("lun") , alloc :: borrow :: Cow :: Borrowed ("mar") , alloc :: borrow :: Cow :: Borrowed ("mié") , alloc :: borrow :: Cow :: Borrowed ("jue") , alloc :: borrow :: Cow :: Borrowed ("vie") , alloc :: borrow :: Cow :: Borrowed ("sáb") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("D") , alloc :: borrow :: Cow :: Borrowed ("L") , alloc :: borrow :: Cow :: Borrowed ("M") , alloc :: borrow :: Cow :: Borrowed ("M") , alloc :: borrow :: Cow :: Borrowed ("J") , alloc :: borrow :: Cow :: Borrowed ("V") , alloc :: borrow :: Cow :: Borrowed ("S") ,] ,) , short : Some (:: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("DO") , alloc :: borrow :: Cow :: Borrowed ("LU") , alloc :: borrow :: Cow :: Borrowed ("MA") , alloc :: borrow :: Cow :: Borrowed ("MI") , alloc :: borrow :: Cow :: Borrowed ("JU") , alloc :: borrow :: Cow :: Borrowed ("VI") , alloc :: borrow :: Cow :: Borrowed ("SA") ,] ,)) , wide : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("domingo") , alloc :: borrow :: Cow :: Borrowed ("lunes") , alloc :: borrow :: Cow :: Borrowed ("martes") , alloc :: borrow :: Cow :: Borrowed ("miércoles") , alloc :: borrow :: Cow :: Borrowed ("jueves") , alloc :: borrow :: Cow :: Borrowed ("viernes") , alloc :: borrow :: Cow :: Borrowed ("sábado") ,] ,) , } , stand_alone : None , } , eras : :: icu_datetime :: provider :: calendar :: Eras { names : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 97u8 , 109u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 77u8]) }) } , abbr : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 97u8 , 109u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 77u8]) }) } , narrow : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 97u8 , 109u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 65u8 , 77u8]) }) } , } , } ;
+static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Lin"),
+                alloc::borrow::Cow::Borrowed("Lun"),
+                alloc::borrow::Cow::Borrowed("Mar"),
+                alloc::borrow::Cow::Borrowed("Miy"),
+                alloc::borrow::Cow::Borrowed("Huw"),
+                alloc::borrow::Cow::Borrowed("Biy"),
+                alloc::borrow::Cow::Borrowed("Sab"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Lin"),
+                alloc::borrow::Cow::Borrowed("Lun"),
+                alloc::borrow::Cow::Borrowed("Mar"),
+                alloc::borrow::Cow::Borrowed("Miy"),
+                alloc::borrow::Cow::Borrowed("Huw"),
+                alloc::borrow::Cow::Borrowed("Biy"),
+                alloc::borrow::Cow::Borrowed("Sab"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Li"),
+                alloc::borrow::Cow::Borrowed("Lu"),
+                alloc::borrow::Cow::Borrowed("Ma"),
+                alloc::borrow::Cow::Borrowed("Mi"),
+                alloc::borrow::Cow::Borrowed("Hu"),
+                alloc::borrow::Cow::Borrowed("Bi"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Linggo"),
+                alloc::borrow::Cow::Borrowed("Lunes"),
+                alloc::borrow::Cow::Borrowed("Martes"),
+                alloc::borrow::Cow::Borrowed("Miyerkules"),
+                alloc::borrow::Cow::Borrowed("Huwebes"),
+                alloc::borrow::Cow::Borrowed("Biyernes"),
+                alloc::borrow::Cow::Borrowed("Sabado"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 11u8, 0u8, 15u8, 0u8,
+                                    20u8, 0u8, 25u8, 0u8, 29u8, 0u8, 33u8, 0u8, 37u8, 0u8, 41u8,
+                                    0u8, 45u8, 0u8, 49u8, 0u8, 51u8, 0u8, 116u8, 105u8, 99u8,
+                                    104u8, 46u8, 104u8, 195u8, 168u8, 99u8, 104u8, 46u8, 107u8,
+                                    105u8, 115u8, 46u8, 116u8, 195u8, 169u8, 118u8, 46u8, 99u8,
+                                    104u8, 101u8, 118u8, 46u8, 97u8, 100u8, 46u8, 73u8, 97u8,
+                                    100u8, 97u8, 114u8, 110u8, 105u8, 115u8, 46u8, 105u8, 121u8,
+                                    97u8, 114u8, 115u8, 105u8, 118u8, 46u8, 116u8, 97u8, 109u8,
+                                    46u8, 97u8, 118u8, 195u8, 169u8, 108u8, 46u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 100u8, 46u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 20u8, 0u8,
+                                    27u8, 0u8, 33u8, 0u8, 39u8, 0u8, 43u8, 0u8, 49u8, 0u8, 53u8,
+                                    0u8, 58u8, 0u8, 64u8, 0u8, 66u8, 0u8, 116u8, 105u8, 99u8,
+                                    104u8, 114u8, 105u8, 104u8, 195u8, 168u8, 99u8, 104u8, 118u8,
+                                    97u8, 110u8, 107u8, 105u8, 115u8, 108u8, 101u8, 118u8, 116u8,
+                                    195u8, 169u8, 118u8, 101u8, 116u8, 104u8, 99u8, 104u8, 101u8,
+                                    118u8, 97u8, 116u8, 97u8, 100u8, 97u8, 114u8, 32u8, 73u8, 97u8,
+                                    100u8, 97u8, 114u8, 110u8, 105u8, 115u8, 115u8, 97u8, 110u8,
+                                    105u8, 121u8, 97u8, 114u8, 115u8, 105u8, 118u8, 97u8, 110u8,
+                                    116u8, 97u8, 109u8, 111u8, 117u8, 122u8, 97u8, 118u8, 195u8,
+                                    169u8, 108u8, 111u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dim."),
+                alloc::borrow::Cow::Borrowed("lun."),
+                alloc::borrow::Cow::Borrowed("mar."),
+                alloc::borrow::Cow::Borrowed("mer."),
+                alloc::borrow::Cow::Borrowed("jeu."),
+                alloc::borrow::Cow::Borrowed("ven."),
+                alloc::borrow::Cow::Borrowed("sam."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("D"),
+                alloc::borrow::Cow::Borrowed("L"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("J"),
+                alloc::borrow::Cow::Borrowed("V"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("di"),
+                alloc::borrow::Cow::Borrowed("lu"),
+                alloc::borrow::Cow::Borrowed("ma"),
+                alloc::borrow::Cow::Borrowed("me"),
+                alloc::borrow::Cow::Borrowed("je"),
+                alloc::borrow::Cow::Borrowed("ve"),
+                alloc::borrow::Cow::Borrowed("sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dimanche"),
+                alloc::borrow::Cow::Borrowed("lundi"),
+                alloc::borrow::Cow::Borrowed("mardi"),
+                alloc::borrow::Cow::Borrowed("mercredi"),
+                alloc::borrow::Cow::Borrowed("jeudi"),
+                alloc::borrow::Cow::Borrowed("vendredi"),
+                alloc::borrow::Cow::Borrowed("samedi"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 110u8, 110u8, 111u8, 32u8, 77u8, 117u8,
+                        110u8, 100u8, 105u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 46u8, 32u8, 77u8, 46u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 46u8, 32u8, 77u8, 46u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 24u8, 0u8, 36u8, 0u8,
+                                    48u8, 0u8, 60u8, 0u8, 71u8, 0u8, 80u8, 0u8, 89u8, 0u8, 98u8,
+                                    0u8, 107u8, 0u8, 116u8, 0u8, 122u8, 0u8, 227u8, 131u8, 134u8,
+                                    227u8, 130u8, 163u8, 227u8, 130u8, 185u8, 227u8, 131u8, 172u8,
+                                    227u8, 129u8, 184u8, 227u8, 130u8, 183u8, 227u8, 131u8, 156u8,
+                                    227u8, 131u8, 179u8, 227u8, 130u8, 173u8, 227u8, 130u8, 185u8,
+                                    227u8, 131u8, 172u8, 227u8, 131u8, 150u8, 227u8, 131u8, 134u8,
+                                    227u8, 131u8, 153u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8,
+                                    227u8, 130u8, 183u8, 227u8, 131u8, 144u8, 227u8, 131u8, 131u8,
+                                    227u8, 131u8, 136u8, 227u8, 130u8, 162u8, 227u8, 131u8, 128u8,
+                                    227u8, 131u8, 171u8, 32u8, 73u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 128u8, 227u8, 131u8, 171u8, 227u8, 131u8, 139u8, 227u8,
+                                    130u8, 181u8, 227u8, 131u8, 179u8, 227u8, 130u8, 164u8, 227u8,
+                                    131u8, 164u8, 227u8, 131u8, 171u8, 227u8, 130u8, 183u8, 227u8,
+                                    131u8, 144u8, 227u8, 131u8, 179u8, 227u8, 130u8, 191u8, 227u8,
+                                    131u8, 160u8, 227u8, 130u8, 186u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 180u8, 227u8, 130u8, 168u8, 227u8, 131u8, 171u8, 227u8,
+                                    131u8, 171u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 128u8, 227u8, 131u8, 171u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 24u8, 0u8, 36u8, 0u8,
+                                    48u8, 0u8, 60u8, 0u8, 71u8, 0u8, 80u8, 0u8, 89u8, 0u8, 98u8,
+                                    0u8, 107u8, 0u8, 116u8, 0u8, 122u8, 0u8, 227u8, 131u8, 134u8,
+                                    227u8, 130u8, 163u8, 227u8, 130u8, 185u8, 227u8, 131u8, 172u8,
+                                    227u8, 129u8, 184u8, 227u8, 130u8, 183u8, 227u8, 131u8, 156u8,
+                                    227u8, 131u8, 179u8, 227u8, 130u8, 173u8, 227u8, 130u8, 185u8,
+                                    227u8, 131u8, 172u8, 227u8, 131u8, 150u8, 227u8, 131u8, 134u8,
+                                    227u8, 131u8, 153u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8,
+                                    227u8, 130u8, 183u8, 227u8, 131u8, 144u8, 227u8, 131u8, 131u8,
+                                    227u8, 131u8, 136u8, 227u8, 130u8, 162u8, 227u8, 131u8, 128u8,
+                                    227u8, 131u8, 171u8, 32u8, 73u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 128u8, 227u8, 131u8, 171u8, 227u8, 131u8, 139u8, 227u8,
+                                    130u8, 181u8, 227u8, 131u8, 179u8, 227u8, 130u8, 164u8, 227u8,
+                                    131u8, 164u8, 227u8, 131u8, 171u8, 227u8, 130u8, 183u8, 227u8,
+                                    131u8, 144u8, 227u8, 131u8, 179u8, 227u8, 130u8, 191u8, 227u8,
+                                    131u8, 160u8, 227u8, 130u8, 186u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 180u8, 227u8, 130u8, 168u8, 227u8, 131u8, 171u8, 227u8,
+                                    131u8, 171u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 130u8, 162u8, 227u8,
+                                    131u8, 128u8, 227u8, 131u8, 171u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日曜日"),
+                alloc::borrow::Cow::Borrowed("月曜日"),
+                alloc::borrow::Cow::Borrowed("火曜日"),
+                alloc::borrow::Cow::Borrowed("水曜日"),
+                alloc::borrow::Cow::Borrowed("木曜日"),
+                alloc::borrow::Cow::Borrowed("金曜日"),
+                alloc::borrow::Cow::Borrowed("土曜日"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 24u8, 0u8, 36u8, 0u8,
+                                    46u8, 0u8, 56u8, 0u8, 66u8, 0u8, 74u8, 0u8, 84u8, 0u8, 90u8,
+                                    0u8, 100u8, 0u8, 112u8, 0u8, 116u8, 0u8, 209u8, 130u8, 208u8,
+                                    184u8, 209u8, 136u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8,
+                                    209u8, 133u8, 208u8, 181u8, 209u8, 136u8, 208u8, 178u8, 208u8,
+                                    176u8, 208u8, 189u8, 208u8, 186u8, 208u8, 184u8, 209u8, 129u8,
+                                    208u8, 187u8, 208u8, 181u8, 208u8, 178u8, 209u8, 130u8, 208u8,
+                                    181u8, 208u8, 178u8, 208u8, 181u8, 209u8, 130u8, 209u8, 136u8,
+                                    208u8, 181u8, 208u8, 178u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                                    176u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 32u8, 73u8,
+                                    208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                                    189u8, 208u8, 184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8,
+                                    208u8, 184u8, 209u8, 143u8, 209u8, 128u8, 209u8, 129u8, 208u8,
+                                    184u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 209u8, 130u8,
+                                    208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 209u8, 131u8, 208u8,
+                                    183u8, 208u8, 176u8, 208u8, 178u8, 209u8, 141u8, 208u8, 187u8,
+                                    209u8, 131u8, 208u8, 187u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 176u8, 208u8, 180u8,
+                                    208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 24u8, 0u8, 36u8, 0u8,
+                                    46u8, 0u8, 56u8, 0u8, 66u8, 0u8, 74u8, 0u8, 84u8, 0u8, 90u8,
+                                    0u8, 100u8, 0u8, 112u8, 0u8, 116u8, 0u8, 209u8, 130u8, 208u8,
+                                    184u8, 209u8, 136u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8,
+                                    209u8, 133u8, 208u8, 181u8, 209u8, 136u8, 208u8, 178u8, 208u8,
+                                    176u8, 208u8, 189u8, 208u8, 186u8, 208u8, 184u8, 209u8, 129u8,
+                                    208u8, 187u8, 208u8, 181u8, 208u8, 178u8, 209u8, 130u8, 208u8,
+                                    181u8, 208u8, 178u8, 208u8, 181u8, 209u8, 130u8, 209u8, 136u8,
+                                    208u8, 181u8, 208u8, 178u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                                    176u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 32u8, 73u8,
+                                    208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                                    189u8, 208u8, 184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8,
+                                    208u8, 184u8, 209u8, 143u8, 209u8, 128u8, 209u8, 129u8, 208u8,
+                                    184u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 209u8, 130u8,
+                                    208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 209u8, 131u8, 208u8,
+                                    183u8, 208u8, 176u8, 208u8, 178u8, 209u8, 141u8, 208u8, 187u8,
+                                    209u8, 131u8, 208u8, 187u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 176u8, 208u8, 180u8,
+                                    208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("вс"),
+                alloc::borrow::Cow::Borrowed("пн"),
+                alloc::borrow::Cow::Borrowed("вт"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("чт"),
+                alloc::borrow::Cow::Borrowed("пт"),
+                alloc::borrow::Cow::Borrowed("сб"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("В"),
+                alloc::borrow::Cow::Borrowed("П"),
+                alloc::borrow::Cow::Borrowed("В"),
+                alloc::borrow::Cow::Borrowed("С"),
+                alloc::borrow::Cow::Borrowed("Ч"),
+                alloc::borrow::Cow::Borrowed("П"),
+                alloc::borrow::Cow::Borrowed("С"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("вс"),
+                alloc::borrow::Cow::Borrowed("пн"),
+                alloc::borrow::Cow::Borrowed("вт"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("чт"),
+                alloc::borrow::Cow::Borrowed("пт"),
+                alloc::borrow::Cow::Borrowed("сб"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("воскресенье"),
+                alloc::borrow::Cow::Borrowed("понедельник"),
+                alloc::borrow::Cow::Borrowed("вторник"),
+                alloc::borrow::Cow::Borrowed("среда"),
+                alloc::borrow::Cow::Borrowed("четверг"),
+                alloc::borrow::Cow::Borrowed("пятница"),
+                alloc::borrow::Cow::Borrowed("суббота"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 190u8, 209u8, 130u8, 32u8, 209u8,
+                        129u8, 208u8, 190u8, 209u8, 130u8, 208u8, 178u8, 208u8, 190u8, 209u8,
+                        128u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 209u8, 143u8, 32u8, 208u8,
+                        188u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 197u8,
+                                    161u8, 114u8, 105u8, 72u8, 101u8, 197u8, 161u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 197u8, 160u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 106u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 197u8,
+                                    161u8, 114u8, 105u8, 72u8, 101u8, 197u8, 161u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 197u8, 160u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 106u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ned"),
+                alloc::borrow::Cow::Borrowed("pon"),
+                alloc::borrow::Cow::Borrowed("uto"),
+                alloc::borrow::Cow::Borrowed("sre"),
+                alloc::borrow::Cow::Borrowed("čet"),
+                alloc::borrow::Cow::Borrowed("pet"),
+                alloc::borrow::Cow::Borrowed("sub"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("n"),
+                alloc::borrow::Cow::Borrowed("p"),
+                alloc::borrow::Cow::Borrowed("u"),
+                alloc::borrow::Cow::Borrowed("s"),
+                alloc::borrow::Cow::Borrowed("č"),
+                alloc::borrow::Cow::Borrowed("p"),
+                alloc::borrow::Cow::Borrowed("s"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ne"),
+                alloc::borrow::Cow::Borrowed("po"),
+                alloc::borrow::Cow::Borrowed("ut"),
+                alloc::borrow::Cow::Borrowed("sr"),
+                alloc::borrow::Cow::Borrowed("če"),
+                alloc::borrow::Cow::Borrowed("pe"),
+                alloc::borrow::Cow::Borrowed("su"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("nedelja"),
+                alloc::borrow::Cow::Borrowed("ponedeljak"),
+                alloc::borrow::Cow::Borrowed("utorak"),
+                alloc::borrow::Cow::Borrowed("sreda"),
+                alloc::borrow::Cow::Borrowed("četvrtak"),
+                alloc::borrow::Cow::Borrowed("petak"),
+                alloc::borrow::Cow::Borrowed("subota"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 22u8, 0u8, 34u8, 0u8,
+                                    44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 72u8, 0u8, 82u8, 0u8, 90u8,
+                                    0u8, 100u8, 0u8, 110u8, 0u8, 114u8, 0u8, 208u8, 162u8, 208u8,
+                                    184u8, 209u8, 136u8, 209u8, 128u8, 208u8, 184u8, 208u8, 165u8,
+                                    208u8, 181u8, 209u8, 136u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                                    189u8, 208u8, 154u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8,
+                                    208u8, 181u8, 208u8, 178u8, 208u8, 162u8, 208u8, 181u8, 208u8,
+                                    178u8, 208u8, 181u8, 209u8, 130u8, 208u8, 168u8, 208u8, 181u8,
+                                    208u8, 178u8, 208u8, 176u8, 209u8, 130u8, 208u8, 144u8, 208u8,
+                                    180u8, 208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 208u8, 144u8,
+                                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 157u8, 208u8,
+                                    184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 208u8, 152u8,
+                                    209u8, 152u8, 208u8, 176u8, 209u8, 128u8, 208u8, 161u8, 208u8,
+                                    184u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 162u8,
+                                    208u8, 176u8, 208u8, 188u8, 209u8, 131u8, 208u8, 183u8, 208u8,
+                                    144u8, 208u8, 178u8, 208u8, 149u8, 208u8, 187u8, 209u8, 131u8,
+                                    208u8, 187u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 144u8, 208u8, 180u8,
+                                    208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 22u8, 0u8, 34u8, 0u8,
+                                    44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 72u8, 0u8, 82u8, 0u8, 90u8,
+                                    0u8, 100u8, 0u8, 110u8, 0u8, 114u8, 0u8, 208u8, 162u8, 208u8,
+                                    184u8, 209u8, 136u8, 209u8, 128u8, 208u8, 184u8, 208u8, 165u8,
+                                    208u8, 181u8, 209u8, 136u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                                    189u8, 208u8, 154u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8,
+                                    208u8, 181u8, 208u8, 178u8, 208u8, 162u8, 208u8, 181u8, 208u8,
+                                    178u8, 208u8, 181u8, 209u8, 130u8, 208u8, 168u8, 208u8, 181u8,
+                                    208u8, 178u8, 208u8, 176u8, 209u8, 130u8, 208u8, 144u8, 208u8,
+                                    180u8, 208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 208u8, 144u8,
+                                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 157u8, 208u8,
+                                    184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 208u8, 152u8,
+                                    209u8, 152u8, 208u8, 176u8, 209u8, 128u8, 208u8, 161u8, 208u8,
+                                    184u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 162u8,
+                                    208u8, 176u8, 208u8, 188u8, 209u8, 131u8, 208u8, 183u8, 208u8,
+                                    144u8, 208u8, 178u8, 208u8, 149u8, 208u8, 187u8, 209u8, 131u8,
+                                    208u8, 187u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 144u8, 208u8, 180u8,
+                                    208u8, 176u8, 209u8, 128u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("нед"),
+                alloc::borrow::Cow::Borrowed("пон"),
+                alloc::borrow::Cow::Borrowed("уто"),
+                alloc::borrow::Cow::Borrowed("сре"),
+                alloc::borrow::Cow::Borrowed("чет"),
+                alloc::borrow::Cow::Borrowed("пет"),
+                alloc::borrow::Cow::Borrowed("суб"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("н"),
+                alloc::borrow::Cow::Borrowed("п"),
+                alloc::borrow::Cow::Borrowed("у"),
+                alloc::borrow::Cow::Borrowed("с"),
+                alloc::borrow::Cow::Borrowed("ч"),
+                alloc::borrow::Cow::Borrowed("п"),
+                alloc::borrow::Cow::Borrowed("с"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("не"),
+                alloc::borrow::Cow::Borrowed("по"),
+                alloc::borrow::Cow::Borrowed("ут"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("че"),
+                alloc::borrow::Cow::Borrowed("пе"),
+                alloc::borrow::Cow::Borrowed("су"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("недеља"),
+                alloc::borrow::Cow::Borrowed("понедељак"),
+                alloc::borrow::Cow::Borrowed("уторак"),
+                alloc::borrow::Cow::Borrowed("среда"),
+                alloc::borrow::Cow::Borrowed("четвртак"),
+                alloc::borrow::Cow::Borrowed("петак"),
+                alloc::borrow::Cow::Borrowed("субота"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 30u8, 0u8, 48u8, 0u8,
+                                    63u8, 0u8, 78u8, 0u8, 98u8, 0u8, 116u8, 0u8, 134u8, 0u8, 152u8,
+                                    0u8, 167u8, 0u8, 182u8, 0u8, 191u8, 0u8, 224u8, 184u8, 151u8,
+                                    224u8, 184u8, 180u8, 224u8, 184u8, 138u8, 224u8, 184u8, 163u8,
+                                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 174u8,
+                                    224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                                    224u8, 184u8, 129u8, 224u8, 184u8, 181u8, 224u8, 184u8, 170u8,
+                                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 159u8,
+                                    224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 185u8, 128u8,
+                                    224u8, 184u8, 167u8, 224u8, 184u8, 149u8, 224u8, 185u8, 128u8,
+                                    224u8, 184u8, 138u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8,
+                                    224u8, 184u8, 149u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                                    224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                                    224u8, 185u8, 140u8, 32u8, 73u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 153u8, 224u8,
+                                    184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8, 139u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 180u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 170u8, 224u8,
+                                    184u8, 181u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8,
+                                    184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 161u8, 224u8, 184u8, 185u8, 224u8, 184u8, 139u8, 224u8,
+                                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 159u8, 224u8,
+                                    185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8,
+                                    184u8, 173u8, 224u8, 184u8, 184u8, 224u8, 184u8, 165u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 30u8, 0u8, 48u8, 0u8,
+                                    63u8, 0u8, 78u8, 0u8, 98u8, 0u8, 116u8, 0u8, 134u8, 0u8, 152u8,
+                                    0u8, 167u8, 0u8, 182u8, 0u8, 191u8, 0u8, 224u8, 184u8, 151u8,
+                                    224u8, 184u8, 180u8, 224u8, 184u8, 138u8, 224u8, 184u8, 163u8,
+                                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 174u8,
+                                    224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                                    224u8, 184u8, 129u8, 224u8, 184u8, 181u8, 224u8, 184u8, 170u8,
+                                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 159u8,
+                                    224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 185u8, 128u8,
+                                    224u8, 184u8, 167u8, 224u8, 184u8, 149u8, 224u8, 185u8, 128u8,
+                                    224u8, 184u8, 138u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8,
+                                    224u8, 184u8, 149u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                                    224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                                    224u8, 185u8, 140u8, 32u8, 73u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 153u8, 224u8,
+                                    184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8, 139u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 180u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 170u8, 224u8,
+                                    184u8, 181u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8,
+                                    184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 161u8, 224u8, 184u8, 185u8, 224u8, 184u8, 139u8, 224u8,
+                                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 159u8, 224u8,
+                                    185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8,
+                                    184u8, 173u8, 224u8, 184u8, 184u8, 224u8, 184u8, 165u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 173u8, 224u8,
+                                    184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                                    184u8, 163u8, 224u8, 185u8, 140u8, 32u8, 73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา."),
+                alloc::borrow::Cow::Borrowed("จ."),
+                alloc::borrow::Cow::Borrowed("อ."),
+                alloc::borrow::Cow::Borrowed("พ."),
+                alloc::borrow::Cow::Borrowed("พฤ."),
+                alloc::borrow::Cow::Borrowed("ศ."),
+                alloc::borrow::Cow::Borrowed("ส."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา"),
+                alloc::borrow::Cow::Borrowed("จ"),
+                alloc::borrow::Cow::Borrowed("อ"),
+                alloc::borrow::Cow::Borrowed("พ"),
+                alloc::borrow::Cow::Borrowed("พฤ"),
+                alloc::borrow::Cow::Borrowed("ศ"),
+                alloc::borrow::Cow::Borrowed("ส"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา."),
+                alloc::borrow::Cow::Borrowed("จ."),
+                alloc::borrow::Cow::Borrowed("อ."),
+                alloc::borrow::Cow::Borrowed("พ."),
+                alloc::borrow::Cow::Borrowed("พฤ."),
+                alloc::borrow::Cow::Borrowed("ศ."),
+                alloc::borrow::Cow::Borrowed("ส."),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นอาท\u{e34}ตย\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นจ\u{e31}นทร\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นอ\u{e31}งคาร"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นพ\u{e38}ธ"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นพฤห\u{e31}สบด\u{e35}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นศ\u{e38}กร\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นเสาร\u{e4c}"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 162u8, 46u8, 224u8, 184u8,
+                        168u8, 46u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 162u8, 46u8, 224u8, 184u8,
+                        168u8, 46u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 162u8, 46u8, 224u8, 184u8,
+                        168u8, 46u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 41u8, 0u8, 45u8, 0u8, 50u8, 0u8, 55u8,
+                                    0u8, 60u8, 0u8, 65u8, 0u8, 67u8, 0u8, 84u8, 105u8, 197u8,
+                                    159u8, 114u8, 105u8, 72u8, 101u8, 197u8, 159u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 197u8, 158u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 82u8, 105u8, 197u8, 159u8,
+                                    111u8, 110u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8,
+                                    97u8, 110u8, 196u8, 176u8, 121u8, 97u8, 114u8, 83u8, 105u8,
+                                    118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8, 122u8, 65u8,
+                                    118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 101u8, 97u8, 100u8, 97u8,
+                                    114u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 41u8, 0u8, 45u8, 0u8, 50u8, 0u8, 55u8,
+                                    0u8, 60u8, 0u8, 65u8, 0u8, 67u8, 0u8, 84u8, 105u8, 197u8,
+                                    159u8, 114u8, 105u8, 72u8, 101u8, 197u8, 159u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 197u8, 158u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 82u8, 105u8, 197u8, 159u8,
+                                    111u8, 110u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8,
+                                    97u8, 110u8, 196u8, 176u8, 121u8, 97u8, 114u8, 83u8, 105u8,
+                                    118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8, 122u8, 65u8,
+                                    118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 101u8, 97u8, 100u8, 97u8,
+                                    114u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Paz"),
+                alloc::borrow::Cow::Borrowed("Pzt"),
+                alloc::borrow::Cow::Borrowed("Sal"),
+                alloc::borrow::Cow::Borrowed("Çar"),
+                alloc::borrow::Cow::Borrowed("Per"),
+                alloc::borrow::Cow::Borrowed("Cum"),
+                alloc::borrow::Cow::Borrowed("Cmt"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("Ç"),
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("C"),
+                alloc::borrow::Cow::Borrowed("C"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Pa"),
+                alloc::borrow::Cow::Borrowed("Pt"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+                alloc::borrow::Cow::Borrowed("Ça"),
+                alloc::borrow::Cow::Borrowed("Pe"),
+                alloc::borrow::Cow::Borrowed("Cu"),
+                alloc::borrow::Cow::Borrowed("Ct"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Pazar"),
+                alloc::borrow::Cow::Borrowed("Pazartesi"),
+                alloc::borrow::Cow::Borrowed("Salı"),
+                alloc::borrow::Cow::Borrowed("Çarşamba"),
+                alloc::borrow::Cow::Borrowed("Perşembe"),
+                alloc::borrow::Cow::Borrowed("Cuma"),
+                alloc::borrow::Cow::Borrowed("Cumartesi"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            narrow:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8,
+                                    11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8,
+                                    54u8, 55u8, 56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8,
+                                    49u8, 51u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+            short: None,
+            wide:
+                ::icu_datetime::provider::calendar::months::SymbolsV1::OtherWithLeapYearVariants {
+                    symbols: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                    0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                    53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                    48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                    77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                    24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8,
+                                    104u8, 114u8, 105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8,
+                                    110u8, 75u8, 105u8, 115u8, 108u8, 101u8, 118u8, 84u8, 101u8,
+                                    118u8, 101u8, 116u8, 83u8, 104u8, 101u8, 118u8, 97u8, 116u8,
+                                    65u8, 100u8, 97u8, 114u8, 32u8, 73u8, 65u8, 100u8, 97u8, 114u8,
+                                    78u8, 105u8, 115u8, 97u8, 110u8, 73u8, 121u8, 97u8, 114u8,
+                                    83u8, 105u8, 118u8, 97u8, 110u8, 84u8, 97u8, 109u8, 117u8,
+                                    122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                                ])
+                            },
+                        )
+                    },
+                    leap_year_variants: unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                    73u8, 73u8,
+                                ])
+                            },
+                        )
+                    },
+                },
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+};
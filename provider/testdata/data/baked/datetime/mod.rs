@@ -1,11 +1,18 @@
 // @generated
 pub mod buddhist;
 pub mod coptic;
+pub mod dayperiods_v1;
 pub mod ethiopic;
 pub mod gregory;
+pub mod hebrew;
 pub mod indian;
+pub mod intervalformats_v1_u_ca;
 pub mod japanese;
 pub mod japanext;
+pub mod numbering_v1_u_ca;
+pub mod persian;
+pub mod quarters_v1;
+pub mod roc;
 pub mod skeletons_v1_u_ca;
 pub mod timelengths_v1;
 pub mod timesymbols_v1;
@@ -0,0 +1,274 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: DateNumberingOverridesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar-EG-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("bn-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ccp-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "en-001-u-ca-buddhist",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("en-001-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "en-001-u-ca-ethiopic",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("en-001-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-001-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-001-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "en-001-u-ca-japanese",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "en-001-u-ca-japanext",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("en-001-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-001-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-ZA-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("en-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-AR-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("es-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fil-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("fr-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ja-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ru-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Cyrl-u-ca-buddhist",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Cyrl-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Cyrl-u-ca-ethiopic",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Cyrl-u-ca-gregory",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Cyrl-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-Cyrl-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Cyrl-u-ca-japanese",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Cyrl-u-ca-japanext",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Cyrl-u-ca-persian",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Cyrl-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Latn-u-ca-buddhist",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Latn-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Latn-u-ca-ethiopic",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Latn-u-ca-gregory",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Latn-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-Latn-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        (
+            "sr-Latn-u-ca-japanese",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Latn-u-ca-japanext",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        (
+            "sr-Latn-u-ca-persian",
+            AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC,
+        ),
+        ("sr-Latn-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("sr-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("th-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("tr-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-gregory", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-hebrew", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-persian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("und-u-ca-roc", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+    ]);
+static AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC: &DataStruct =
+    &::icu_datetime::provider::calendar::DateNumberingOverridesV1 {
+        full: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        long: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        medium: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        short: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    };
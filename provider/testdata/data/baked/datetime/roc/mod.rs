@@ -0,0 +1,3 @@
+// @generated
+pub mod datelengths_v1;
+pub mod datesymbols_v1;
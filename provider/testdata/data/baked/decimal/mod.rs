@@ -1,2 +1,4 @@
 // @generated
+pub mod percent_v1_u_nu;
+pub mod scientific_v1_u_nu;
 pub mod symbols_v1_u_nu;
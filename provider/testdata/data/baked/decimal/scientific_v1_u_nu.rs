@@ -0,0 +1,45 @@
+// @generated
+type DataStruct =
+    <::icu_decimal::provider::ScientificSymbolsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        (
+            "ar-EG-u-nu-latn",
+            AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN,
+        ),
+        ("ar-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("bn", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("bn-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("ccp", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        (
+            "ccp-u-nu-latn",
+            AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN,
+        ),
+        ("en", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("en-001", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("en-ZA", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("es", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("es-AR", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("fil", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("fr", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("ja", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("ru", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("sr", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("sr-Cyrl", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("sr-Latn", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("th", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("th-u-nu-thai", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("tr", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+        ("und", AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_decimal::provider::ScientificSymbolsV1 {
+    exponential: alloc::borrow::Cow::Borrowed("اس"),
+    superscripting_exponent: alloc::borrow::Cow::Borrowed("×"),
+};
+static AR_EG_U_NU_LATN_AR_U_NU_LATN_BN_BN_U_NU_LATN: &DataStruct =
+    &::icu_decimal::provider::ScientificSymbolsV1 {
+        exponential: alloc::borrow::Cow::Borrowed("E"),
+        superscripting_exponent: alloc::borrow::Cow::Borrowed("×"),
+    };
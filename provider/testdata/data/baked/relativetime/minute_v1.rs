@@ -0,0 +1,2058 @@
+// @generated
+type DataStruct = < :: icu_relativetime :: provider :: MinuteRelativeTimeFormatDataV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES_ES_AR),
+        ("es-AR", ES_ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 135u8, 216u8, 176u8, 217u8, 135u8,
+                        32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 175u8, 217u8, 130u8, 217u8, 138u8,
+                        217u8, 130u8, 216u8, 169u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقائق"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقائق"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 135u8, 216u8, 176u8, 217u8, 135u8,
+                        32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 175u8, 217u8, 130u8, 217u8, 138u8,
+                        217u8, 130u8, 216u8, 169u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقائق"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقائق"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 135u8, 216u8, 176u8, 217u8, 135u8,
+                        32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 175u8, 217u8, 130u8, 217u8, 138u8,
+                        217u8, 130u8, 216u8, 169u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقائق"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  دقيقة"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقة واحدة"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال دقيقتين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقائق"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  دقيقة"),
+                index: 9u8,
+            },
+        },
+    },
+};
+static BN: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 143u8, 224u8, 166u8, 135u8,
+                        32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 191u8, 224u8, 166u8, 159u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 143u8, 224u8, 166u8, 135u8,
+                        32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 191u8, 224u8, 166u8, 159u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 143u8, 224u8, 166u8, 135u8,
+                        32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 191u8, 224u8, 166u8, 159u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিট আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" মিনিটে"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static CCP: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                        132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 172u8, 32u8,
+                        240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                        132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8,
+                        240u8, 145u8, 132u8, 180u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                        132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 172u8, 32u8,
+                        240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                        132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8,
+                        240u8, 145u8, 132u8, 180u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                        132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 172u8, 32u8,
+                        240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                        132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8,
+                        240u8, 145u8, 132u8, 180u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄖\u{11134} 𑄃𑄉𑄬"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 𑄟\u{11128}𑄚\u{11128}𑄘𑄬"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static EN: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minute ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minutes ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  minute"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  minutes"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min."),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min."),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("m ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("m ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in m"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in m"),
+                index: 3u8,
+            },
+        },
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minute ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minutes ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  minute"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  minutes"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min"),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  min"),
+                index: 3u8,
+            },
+        },
+    },
+};
+static ES_ES_AR: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 115u8, 116u8, 101u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  minuto"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  minutos"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  minuto"),
+                index: 10u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  minutos"),
+                index: 10u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 115u8, 116u8, 101u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  min"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  min"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  min"),
+                index: 10u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  min"),
+                index: 10u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 115u8, 116u8, 101u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  min"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  min"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  min"),
+                index: 10u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  min"),
+                index: 10u8,
+            },
+        },
+    },
+};
+static FIL: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 32u8, 109u8, 105u8, 110u8,
+                        117u8, 116u8, 111u8, 110u8, 103u8, 32u8, 105u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minuto ang nakalipas"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" minuto ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  minuto"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  minuto"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 32u8, 109u8, 105u8, 110u8,
+                        117u8, 116u8, 111u8, 110u8, 103u8, 32u8, 105u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ang nakalipas"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  min."),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  min."),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 32u8, 109u8, 105u8, 110u8,
+                        117u8, 116u8, 111u8, 110u8, 103u8, 32u8, 105u8, 116u8, 111u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ang nakalipas"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" min. ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  min."),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  min."),
+                index: 3u8,
+            },
+        },
+    },
+};
+static FR: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 101u8, 116u8, 116u8, 101u8, 32u8,
+                        109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 45u8, 99u8, 105u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a  minute"),
+                index: 7u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a  minutes"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans  minute"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans  minutes"),
+                index: 5u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 101u8, 116u8, 116u8, 101u8, 32u8,
+                        109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 45u8, 99u8, 105u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a \u{a0}min"),
+                index: 7u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a \u{a0}min"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans \u{a0}min"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans \u{a0}min"),
+                index: 5u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 101u8, 116u8, 116u8, 101u8, 32u8,
+                        109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 45u8, 99u8, 105u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- min"),
+                index: 1u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- min"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ min"),
+                index: 1u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ min"),
+                index: 1u8,
+            },
+        },
+    },
+};
+static JA: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 32u8, 229u8, 136u8, 134u8, 228u8,
+                        187u8, 165u8, 229u8, 134u8, 133u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 分前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 分後"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 32u8, 229u8, 136u8, 134u8, 228u8,
+                        187u8, 165u8, 229u8, 134u8, 133u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 分前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 分後"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 32u8, 229u8, 136u8, 134u8, 228u8,
+                        187u8, 165u8, 229u8, 134u8, 133u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("分前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("分後"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static RU: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 32u8, 209u8, 141u8, 209u8,
+                        130u8, 209u8, 131u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                        131u8, 209u8, 130u8, 209u8, 131u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" минуту назад"),
+                index: 0u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" минуты назад"),
+                index: 0u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" минут назад"),
+                index: 0u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" минуты назад"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  минуту"),
+                index: 11u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  минуты"),
+                index: 11u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  минут"),
+                index: 11u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  минуты"),
+                index: 11u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 32u8, 209u8, 141u8, 209u8,
+                        130u8, 209u8, 131u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                        131u8, 209u8, 130u8, 209u8, 131u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" мин. назад"),
+                index: 0u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" мин. назад"),
+                index: 0u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" мин. назад"),
+                index: 0u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" мин. назад"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  мин."),
+                index: 11u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  мин."),
+                index: 11u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  мин."),
+                index: 11u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  мин."),
+                index: 11u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 32u8, 209u8, 141u8, 209u8,
+                        130u8, 209u8, 131u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                        131u8, 209u8, 130u8, 209u8, 131u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- мин"),
+                index: 1u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- мин"),
+                index: 1u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- мин"),
+                index: 1u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- мин"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ мин"),
+                index: 1u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ мин"),
+                index: 1u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ мин"),
+                index: 1u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ мин"),
+                index: 1u8,
+            },
+        },
+    },
+};
+static SR_LATN: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 118u8, 111u8, 103u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  minuta"),
+                index: 4u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  minuta"),
+                index: 4u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  minuta"),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  minut"),
+                index: 3u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  minuta"),
+                index: 3u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  minuta"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 118u8, 111u8, 103u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 118u8, 111u8, 103u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  min."),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  min."),
+                index: 3u8,
+            },
+        },
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8,
+                        208u8, 179u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8, 131u8,
+                        209u8, 130u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  минута"),
+                index: 7u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  минута"),
+                index: 7u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  минута"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  минут"),
+                index: 5u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  минута"),
+                index: 5u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  минута"),
+                index: 5u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8,
+                        208u8, 179u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8, 131u8,
+                        209u8, 130u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8,
+                        208u8, 179u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8, 131u8,
+                        209u8, 130u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  мин."),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            }),
+            two: None,
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            }),
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  мин."),
+                index: 5u8,
+            },
+        },
+    },
+};
+static TH: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" นาท\u{e35}ท\u{e35}\u{e48}ผ\u{e48}านมา"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ในอ\u{e35}ก  นาท\u{e35}"),
+                index: 16u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" นาท\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ใน  นาท\u{e35}"),
+                index: 7u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" นาท\u{e35}ท\u{e35}\u{e48}แล\u{e49}ว"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ใน  นาท\u{e35}"),
+                index: 7u8,
+            },
+        },
+    },
+};
+static TR: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 117u8, 32u8, 100u8, 97u8, 107u8, 105u8,
+                        107u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dakika önce"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dakika önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dakika sonra"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dakika sonra"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 117u8, 32u8, 100u8, 97u8, 107u8, 105u8,
+                        107u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. önce"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. sonra"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. sonra"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 117u8, 32u8, 100u8, 97u8, 107u8, 105u8,
+                        107u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. önce"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. sonra"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" dk. sonra"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static UND: &DataStruct = &::icu_relativetime::provider::RelativeTimePatternDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- min"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ min"),
+                index: 1u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- min"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ min"),
+                index: 1u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatterns {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 116u8, 104u8, 105u8, 115u8, 32u8, 109u8,
+                        105u8, 110u8, 117u8, 116u8, 101u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- min"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ min"),
+                index: 1u8,
+            },
+        },
+    },
+};
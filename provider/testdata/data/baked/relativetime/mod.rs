@@ -0,0 +1,9 @@
+// @generated
+pub mod day_v1;
+pub mod hour_v1;
+pub mod minute_v1;
+pub mod month_v1;
+pub mod quarter_v1;
+pub mod second_v1;
+pub mod week_v1;
+pub mod year_v1;
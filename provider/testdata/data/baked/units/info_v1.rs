@@ -0,0 +1,923 @@
+// @generated
+type DataStruct =
+    <::icu_units::provider::UnitsInfoV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_units::provider::UnitsInfoV1 {
+    base_units: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    154u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 10u8, 0u8, 20u8, 0u8, 30u8, 0u8,
+                    47u8, 0u8, 57u8, 0u8, 60u8, 0u8, 66u8, 0u8, 75u8, 0u8, 78u8, 0u8, 98u8, 0u8,
+                    121u8, 0u8, 126u8, 0u8, 132u8, 0u8, 136u8, 0u8, 143u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 165u8, 0u8, 172u8, 0u8, 179u8, 0u8, 184u8, 0u8, 187u8, 0u8, 194u8, 0u8,
+                    197u8, 0u8, 209u8, 0u8, 215u8, 0u8, 225u8, 0u8, 231u8, 0u8, 234u8, 0u8, 244u8,
+                    0u8, 250u8, 0u8, 0u8, 1u8, 13u8, 1u8, 35u8, 1u8, 38u8, 1u8, 42u8, 1u8, 46u8,
+                    1u8, 51u8, 1u8, 61u8, 1u8, 73u8, 1u8, 85u8, 1u8, 87u8, 1u8, 97u8, 1u8, 102u8,
+                    1u8, 108u8, 1u8, 119u8, 1u8, 139u8, 1u8, 157u8, 1u8, 168u8, 1u8, 172u8, 1u8,
+                    181u8, 1u8, 184u8, 1u8, 191u8, 1u8, 198u8, 1u8, 204u8, 1u8, 219u8, 1u8, 242u8,
+                    1u8, 247u8, 1u8, 251u8, 1u8, 255u8, 1u8, 6u8, 2u8, 11u8, 2u8, 16u8, 2u8, 26u8,
+                    2u8, 30u8, 2u8, 34u8, 2u8, 38u8, 2u8, 44u8, 2u8, 49u8, 2u8, 54u8, 2u8, 59u8,
+                    2u8, 64u8, 2u8, 70u8, 2u8, 73u8, 2u8, 81u8, 2u8, 95u8, 2u8, 99u8, 2u8, 103u8,
+                    2u8, 109u8, 2u8, 120u8, 2u8, 130u8, 2u8, 135u8, 2u8, 140u8, 2u8, 143u8, 2u8,
+                    148u8, 2u8, 152u8, 2u8, 169u8, 2u8, 175u8, 2u8, 179u8, 2u8, 184u8, 2u8, 196u8,
+                    2u8, 209u8, 2u8, 215u8, 2u8, 220u8, 2u8, 229u8, 2u8, 233u8, 2u8, 236u8, 2u8,
+                    241u8, 2u8, 246u8, 2u8, 0u8, 3u8, 6u8, 3u8, 10u8, 3u8, 16u8, 3u8, 23u8, 3u8,
+                    31u8, 3u8, 40u8, 3u8, 45u8, 3u8, 49u8, 3u8, 62u8, 3u8, 73u8, 3u8, 78u8, 3u8,
+                    83u8, 3u8, 88u8, 3u8, 99u8, 3u8, 104u8, 3u8, 118u8, 3u8, 125u8, 3u8, 131u8,
+                    3u8, 138u8, 3u8, 148u8, 3u8, 153u8, 3u8, 156u8, 3u8, 159u8, 3u8, 162u8, 3u8,
+                    167u8, 3u8, 173u8, 3u8, 178u8, 3u8, 189u8, 3u8, 201u8, 3u8, 208u8, 3u8, 215u8,
+                    3u8, 219u8, 3u8, 235u8, 3u8, 245u8, 3u8, 1u8, 4u8, 10u8, 4u8, 15u8, 4u8, 18u8,
+                    4u8, 28u8, 4u8, 36u8, 4u8, 41u8, 4u8, 49u8, 4u8, 54u8, 4u8, 57u8, 4u8, 62u8,
+                    4u8, 66u8, 4u8, 70u8, 4u8, 75u8, 4u8, 79u8, 4u8, 90u8, 4u8, 94u8, 4u8, 98u8,
+                    4u8, 97u8, 99u8, 114u8, 101u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 97u8,
+                    114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8, 99u8,
+                    45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 97u8, 115u8, 116u8, 114u8,
+                    111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8, 108u8, 45u8, 117u8, 110u8,
+                    105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8, 112u8, 104u8, 101u8, 114u8,
+                    101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 98u8, 101u8,
+                    99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8, 98u8,
+                    114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8,
+                    109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8, 105u8,
+                    116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8, 45u8,
+                    106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8, 116u8,
+                    101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8, 111u8,
+                    114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8, 101u8,
+                    108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+                    117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8,
+                    97u8, 105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8,
+                    109u8, 98u8, 99u8, 117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8,
+                    117u8, 112u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8,
+                    108u8, 116u8, 111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 97u8, 121u8, 45u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 100u8, 101u8, 99u8, 97u8, 100u8,
+                    101u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 100u8, 101u8, 115u8, 115u8,
+                    101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 100u8, 101u8,
+                    115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8,
+                    45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 100u8, 111u8,
+                    116u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8,
+                    110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 109u8, 97u8, 115u8,
+                    115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8, 97u8, 100u8, 105u8,
+                    117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 111u8, 110u8, 118u8,
+                    111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8, 100u8, 102u8, 97u8,
+                    116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8,
+                    97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 102u8, 111u8,
+                    111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 102u8, 111u8,
+                    111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8,
+                    45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8,
+                    110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8, 111u8, 108u8, 105u8,
+                    110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8, 121u8, 45u8, 100u8,
+                    101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 114u8, 97u8, 105u8, 110u8,
+                    103u8, 114u8, 97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8,
+                    116u8, 97u8, 114u8, 101u8, 104u8, 101u8, 110u8, 114u8, 121u8, 104u8, 101u8,
+                    114u8, 116u8, 122u8, 104u8, 111u8, 114u8, 115u8, 101u8, 112u8, 111u8, 119u8,
+                    101u8, 114u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 105u8,
+                    116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8, 111u8,
+                    45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 97u8, 114u8,
+                    97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8, 101u8, 108u8, 118u8,
+                    105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    102u8, 111u8, 114u8, 99u8, 101u8, 107u8, 110u8, 111u8, 116u8, 107u8, 111u8,
+                    107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 108u8, 105u8, 103u8,
+                    104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8, 105u8, 103u8,
+                    104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 108u8, 101u8,
+                    109u8, 111u8, 110u8, 116u8, 104u8, 109u8, 111u8, 110u8, 116u8, 104u8, 45u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 110u8, 97u8, 117u8, 116u8, 105u8,
+                    99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8, 101u8, 119u8,
+                    116u8, 111u8, 110u8, 110u8, 105u8, 103u8, 104u8, 116u8, 111u8, 102u8, 103u8,
+                    108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8, 103u8, 111u8,
+                    104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8, 117u8, 110u8, 99u8,
+                    101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8, 114u8, 111u8, 121u8,
+                    112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8, 114u8, 116u8, 112u8, 97u8,
+                    115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8,
+                    112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8, 112u8, 101u8, 114u8,
+                    109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 105u8, 110u8, 99u8, 104u8,
+                    112u8, 105u8, 110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8,
+                    112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8,
+                    109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    112u8, 111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 113u8,
+                    117u8, 97u8, 114u8, 116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8,
+                    109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 101u8, 114u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8,
+                    110u8, 107u8, 105u8, 110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8,
+                    116u8, 105u8, 111u8, 110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8,
+                    110u8, 114u8, 111u8, 100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8,
+                    112u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 115u8, 104u8, 97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8,
+                    104u8, 115u8, 104u8, 97u8, 107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 115u8, 105u8, 101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8,
+                    101u8, 118u8, 101u8, 114u8, 116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8,
+                    105u8, 116u8, 121u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8,
+                    115u8, 115u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8,
+                    105u8, 117u8, 115u8, 115u8, 116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8,
+                    110u8, 115u8, 116u8, 111u8, 110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8,
+                    98u8, 108u8, 101u8, 115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8,
+                    115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 119u8, 101u8, 101u8, 107u8, 45u8, 112u8, 101u8,
+                    114u8, 115u8, 111u8, 110u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8, 121u8, 101u8, 97u8, 114u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+                    110u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    154u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 18u8, 0u8, 28u8, 0u8, 38u8, 0u8,
+                    43u8, 0u8, 75u8, 0u8, 107u8, 0u8, 118u8, 0u8, 128u8, 0u8, 131u8, 0u8, 170u8,
+                    0u8, 209u8, 0u8, 221u8, 0u8, 232u8, 0u8, 235u8, 0u8, 18u8, 1u8, 57u8, 1u8,
+                    64u8, 1u8, 72u8, 1u8, 78u8, 1u8, 82u8, 1u8, 87u8, 1u8, 99u8, 1u8, 112u8, 1u8,
+                    123u8, 1u8, 134u8, 1u8, 145u8, 1u8, 156u8, 1u8, 164u8, 1u8, 170u8, 1u8, 176u8,
+                    1u8, 180u8, 1u8, 190u8, 1u8, 201u8, 1u8, 212u8, 1u8, 217u8, 1u8, 228u8, 1u8,
+                    239u8, 1u8, 251u8, 1u8, 3u8, 2u8, 8u8, 2u8, 47u8, 2u8, 49u8, 2u8, 55u8, 2u8,
+                    106u8, 2u8, 111u8, 2u8, 122u8, 2u8, 133u8, 2u8, 144u8, 2u8, 183u8, 2u8, 188u8,
+                    2u8, 194u8, 2u8, 202u8, 2u8, 207u8, 2u8, 230u8, 2u8, 241u8, 2u8, 252u8, 2u8,
+                    28u8, 3u8, 36u8, 3u8, 44u8, 3u8, 74u8, 3u8, 86u8, 3u8, 139u8, 3u8, 160u8, 3u8,
+                    198u8, 3u8, 204u8, 3u8, 209u8, 3u8, 213u8, 3u8, 224u8, 3u8, 229u8, 3u8, 12u8,
+                    4u8, 16u8, 4u8, 31u8, 4u8, 37u8, 4u8, 42u8, 4u8, 50u8, 4u8, 82u8, 4u8, 98u8,
+                    4u8, 109u8, 4u8, 120u8, 4u8, 136u8, 4u8, 141u8, 4u8, 152u8, 4u8, 189u8, 4u8,
+                    213u8, 4u8, 218u8, 4u8, 223u8, 4u8, 228u8, 4u8, 234u8, 4u8, 238u8, 4u8, 242u8,
+                    4u8, 246u8, 4u8, 251u8, 4u8, 27u8, 5u8, 32u8, 5u8, 49u8, 5u8, 88u8, 5u8, 140u8,
+                    5u8, 151u8, 5u8, 159u8, 5u8, 167u8, 5u8, 172u8, 5u8, 176u8, 5u8, 208u8, 5u8,
+                    212u8, 5u8, 216u8, 5u8, 220u8, 5u8, 231u8, 5u8, 242u8, 5u8, 253u8, 5u8, 8u8,
+                    6u8, 13u8, 6u8, 18u8, 6u8, 26u8, 6u8, 58u8, 6u8, 69u8, 6u8, 80u8, 6u8, 84u8,
+                    6u8, 94u8, 6u8, 100u8, 6u8, 110u8, 6u8, 115u8, 6u8, 120u8, 6u8, 125u8, 6u8,
+                    136u8, 6u8, 148u8, 6u8, 154u8, 6u8, 165u8, 6u8, 170u8, 6u8, 175u8, 6u8, 227u8,
+                    6u8, 1u8, 7u8, 9u8, 7u8, 47u8, 7u8, 55u8, 7u8, 60u8, 7u8, 77u8, 7u8, 85u8, 7u8,
+                    90u8, 7u8, 101u8, 7u8, 112u8, 7u8, 145u8, 7u8, 184u8, 7u8, 195u8, 7u8, 203u8,
+                    7u8, 211u8, 7u8, 0u8, 8u8, 38u8, 8u8, 84u8, 8u8, 90u8, 8u8, 96u8, 8u8, 101u8,
+                    8u8, 105u8, 8u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 114u8, 101u8,
+                    118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 114u8, 101u8, 118u8,
+                    111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 98u8, 105u8, 116u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 101u8, 108u8, 118u8,
+                    105u8, 110u8, 121u8, 101u8, 97u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 101u8,
+                    109u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 112u8, 111u8, 119u8, 52u8,
+                    45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8,
+                    114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 105u8, 116u8, 101u8, 109u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 112u8, 97u8, 114u8, 116u8, 105u8,
+                    116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 105u8, 116u8, 101u8, 109u8, 121u8, 101u8, 97u8, 114u8,
+                    121u8, 101u8, 97u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 105u8, 103u8,
+                    104u8, 116u8, 105u8, 116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 97u8, 114u8, 116u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    121u8, 101u8, 97u8, 114u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8,
+                    105u8, 111u8, 110u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 114u8, 101u8,
+                    118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 114u8, 101u8, 118u8, 111u8,
+                    108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8,
+                    97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 121u8, 101u8, 97u8, 114u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+        )
+    },
+    factors: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    119u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 14u8, 0u8, 24u8, 0u8, 41u8, 0u8,
+                    51u8, 0u8, 54u8, 0u8, 60u8, 0u8, 80u8, 0u8, 103u8, 0u8, 108u8, 0u8, 114u8, 0u8,
+                    118u8, 0u8, 125u8, 0u8, 135u8, 0u8, 140u8, 0u8, 147u8, 0u8, 152u8, 0u8, 155u8,
+                    0u8, 158u8, 0u8, 170u8, 0u8, 176u8, 0u8, 186u8, 0u8, 192u8, 0u8, 195u8, 0u8,
+                    205u8, 0u8, 211u8, 0u8, 217u8, 0u8, 230u8, 0u8, 252u8, 0u8, 0u8, 1u8, 4u8, 1u8,
+                    9u8, 1u8, 19u8, 1u8, 31u8, 1u8, 43u8, 1u8, 53u8, 1u8, 59u8, 1u8, 70u8, 1u8,
+                    90u8, 1u8, 108u8, 1u8, 119u8, 1u8, 123u8, 1u8, 132u8, 1u8, 135u8, 1u8, 142u8,
+                    1u8, 149u8, 1u8, 155u8, 1u8, 170u8, 1u8, 193u8, 1u8, 198u8, 1u8, 202u8, 1u8,
+                    209u8, 1u8, 219u8, 1u8, 223u8, 1u8, 227u8, 1u8, 233u8, 1u8, 238u8, 1u8, 243u8,
+                    1u8, 248u8, 1u8, 251u8, 1u8, 9u8, 2u8, 13u8, 2u8, 17u8, 2u8, 23u8, 2u8, 34u8,
+                    2u8, 44u8, 2u8, 49u8, 2u8, 53u8, 2u8, 70u8, 2u8, 76u8, 2u8, 80u8, 2u8, 85u8,
+                    2u8, 97u8, 2u8, 110u8, 2u8, 119u8, 2u8, 123u8, 2u8, 128u8, 2u8, 133u8, 2u8,
+                    143u8, 2u8, 149u8, 2u8, 156u8, 2u8, 164u8, 2u8, 173u8, 2u8, 178u8, 2u8, 182u8,
+                    2u8, 195u8, 2u8, 206u8, 2u8, 211u8, 2u8, 216u8, 2u8, 227u8, 2u8, 232u8, 2u8,
+                    246u8, 2u8, 253u8, 2u8, 3u8, 3u8, 10u8, 3u8, 15u8, 3u8, 18u8, 3u8, 21u8, 3u8,
+                    24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 45u8, 3u8, 57u8, 3u8, 61u8, 3u8, 77u8, 3u8,
+                    87u8, 3u8, 99u8, 3u8, 108u8, 3u8, 113u8, 3u8, 116u8, 3u8, 126u8, 3u8, 134u8,
+                    3u8, 142u8, 3u8, 147u8, 3u8, 150u8, 3u8, 155u8, 3u8, 159u8, 3u8, 170u8, 3u8,
+                    97u8, 99u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8,
+                    116u8, 98u8, 117u8, 45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8,
+                    108u8, 98u8, 121u8, 116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8,
+                    97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8,
+                    104u8, 97u8, 105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 117u8, 112u8, 99u8, 117u8,
+                    112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 99u8,
+                    117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8, 111u8, 110u8, 100u8,
+                    97u8, 121u8, 100u8, 97u8, 121u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+                    110u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8,
+                    117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8,
+                    108u8, 111u8, 114u8, 105u8, 101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8,
+                    114u8, 116u8, 110u8, 105u8, 103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8,
+                    117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8,
+                    99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8,
+                    108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 103u8, 97u8, 115u8, 111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8,
+                    110u8, 101u8, 114u8, 103u8, 121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8,
+                    116u8, 121u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8, 97u8, 109u8,
+                    104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 111u8, 114u8, 115u8,
+                    101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8, 114u8, 105u8,
+                    110u8, 99u8, 104u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8, 111u8,
+                    45u8, 106u8, 112u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8,
+                    108u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 107u8, 110u8, 111u8, 116u8,
+                    107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 108u8,
+                    105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8,
+                    105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 108u8, 101u8,
+                    109u8, 111u8, 110u8, 116u8, 104u8, 109u8, 111u8, 110u8, 116u8, 104u8, 45u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 110u8, 97u8, 117u8, 116u8, 105u8,
+                    99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 111u8, 102u8, 103u8,
+                    108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8, 103u8, 111u8,
+                    115u8, 97u8, 106u8, 105u8, 111u8, 117u8, 110u8, 99u8, 101u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 45u8, 116u8, 114u8, 111u8, 121u8, 112u8, 97u8, 114u8,
+                    115u8, 101u8, 99u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 112u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8, 112u8, 101u8, 114u8, 109u8,
+                    121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    105u8, 110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 112u8, 111u8, 105u8, 110u8, 116u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8,
+                    111u8, 114u8, 99u8, 101u8, 113u8, 117u8, 97u8, 114u8, 116u8, 113u8, 117u8,
+                    97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8, 97u8, 100u8,
+                    105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8, 110u8, 101u8, 114u8,
+                    105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8, 100u8, 115u8,
+                    97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 115u8, 104u8, 97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8,
+                    104u8, 115u8, 104u8, 97u8, 107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8,
+                    45u8, 108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8,
+                    115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8,
+                    115u8, 116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8,
+                    111u8, 110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8,
+                    115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8,
+                    111u8, 110u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8,
+                    111u8, 45u8, 106u8, 112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8,
+                    101u8, 119u8, 101u8, 101u8, 107u8, 119u8, 101u8, 101u8, 107u8, 45u8, 112u8,
+                    101u8, 114u8, 115u8, 111u8, 110u8, 121u8, 97u8, 114u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    146u8, 58u8, 216u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 45u8, 49u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 96u8, 84u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 128u8, 198u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 90u8, 186u8, 212u8, 34u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8, 139u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 141u8, 25u8, 70u8, 80u8, 2u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 81u8, 74u8, 141u8, 14u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 183u8, 111u8, 254u8,
+                    133u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 82u8,
+                    87u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                    188u8, 79u8, 72u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    128u8, 240u8, 250u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 127u8, 157u8, 147u8, 71u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 164u8, 7u8, 49u8, 175u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 40u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 196u8, 9u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 19u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 29u8, 49u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8,
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    128u8, 79u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 153u8, 25u8, 52u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 32u8, 74u8, 169u8, 209u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 94u8, 95u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 26u8, 149u8, 169u8, 38u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    64u8, 34u8, 138u8, 9u8, 122u8, 196u8, 134u8, 90u8, 168u8, 76u8, 59u8, 75u8,
+                    128u8, 81u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 128u8, 81u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 104u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 153u8, 25u8, 52u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 175u8, 47u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 25u8, 52u8, 28u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 82u8, 106u8,
+                    116u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 51u8, 179u8, 102u8,
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    192u8, 188u8, 247u8, 233u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 160u8, 50u8, 72u8, 136u8, 88u8, 156u8, 169u8, 240u8, 4u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 116u8, 82u8, 97u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 129u8, 114u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 72u8, 1u8, 20u8, 22u8,
+                    149u8, 69u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 119u8, 4u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 2u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 25u8, 52u8, 28u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 81u8,
+                    74u8, 141u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8,
+                    239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 160u8, 172u8, 185u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 88u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 125u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 58u8, 98u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 254u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 78u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 25u8, 52u8,
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 162u8,
+                    148u8, 26u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    209u8, 239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 160u8, 6u8, 97u8, 140u8, 242u8, 5u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 117u8, 208u8, 87u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 224u8, 98u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 118u8, 72u8, 23u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 92u8, 188u8, 13u8,
+                    129u8, 118u8, 132u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8,
+                    61u8, 136u8, 121u8, 45u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    16u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 203u8, 76u8, 156u8, 84u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 162u8, 148u8, 26u8, 29u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 198u8, 92u8, 20u8,
+                    95u8, 41u8, 23u8, 134u8, 127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    37u8, 254u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 32u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 207u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 97u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 254u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 13u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 120u8, 222u8, 17u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 192u8, 70u8, 34u8, 247u8, 123u8,
+                    156u8, 33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 17u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 125u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 198u8, 92u8, 20u8, 95u8, 41u8, 23u8, 134u8, 127u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 26u8, 142u8,
+                    124u8, 70u8, 46u8, 21u8, 150u8, 2u8, 76u8, 0u8, 0u8, 0u8, 85u8, 125u8, 27u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 43u8, 163u8,
+                    83u8, 53u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8,
+                    13u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    64u8, 13u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 133u8, 32u8, 180u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 16u8, 94u8, 95u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 115u8, 221u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 64u8, 120u8, 125u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 241u8, 225u8, 59u8, 176u8, 215u8, 71u8,
+                    49u8, 129u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 224u8, 135u8, 24u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 25u8,
+                    52u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 128u8, 40u8, 165u8, 70u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 153u8, 25u8, 52u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 209u8, 239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 175u8, 47u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 126u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 133u8, 32u8, 180u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 57u8, 169u8, 86u8, 93u8,
+                    23u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 74u8,
+                    169u8, 209u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8,
+                    25u8, 52u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 136u8, 82u8, 106u8, 116u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 209u8, 239u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 132u8, 215u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 48u8, 121u8, 231u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 224u8, 135u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 202u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 118u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 29u8, 49u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 196u8, 9u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 9u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 192u8, 114u8,
+                    85u8, 79u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8,
+                    46u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 97u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 224u8, 241u8, 238u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 57u8, 169u8, 86u8, 93u8, 23u8, 8u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 253u8, 238u8, 141u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 242u8, 237u8,
+                    212u8, 212u8, 148u8, 13u8, 165u8, 60u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    54u8, 63u8, 21u8, 208u8, 28u8, 40u8, 213u8, 23u8, 25u8, 25u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    32u8, 138u8, 119u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 105u8, 254u8, 13u8, 35u8, 62u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 73u8, 102u8, 23u8, 253u8, 48u8, 194u8, 89u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 163u8, 227u8, 236u8, 18u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 240u8, 250u8, 2u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 93u8, 2u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 25u8, 52u8, 28u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 162u8,
+                    148u8, 26u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 51u8,
+                    179u8, 102u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 162u8, 148u8, 26u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 208u8, 128u8, 73u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 97u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 236u8, 7u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 133u8, 32u8, 180u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 80u8, 195u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 58u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 58u8, 9u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 119u8, 4u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 4u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+    offsets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+                    117u8, 115u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    87u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 143u8, 179u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 180u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+    quantities: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 22u8, 0u8, 45u8, 0u8, 48u8, 0u8, 55u8,
+                    0u8, 79u8, 0u8, 116u8, 0u8, 127u8, 0u8, 151u8, 0u8, 172u8, 0u8, 224u8, 0u8,
+                    226u8, 0u8, 230u8, 0u8, 250u8, 0u8, 11u8, 1u8, 26u8, 1u8, 32u8, 1u8, 40u8, 1u8,
+                    72u8, 1u8, 96u8, 1u8, 117u8, 1u8, 149u8, 1u8, 188u8, 1u8, 221u8, 1u8, 3u8, 2u8,
+                    48u8, 2u8, 100u8, 2u8, 139u8, 2u8, 185u8, 2u8, 238u8, 2u8, 243u8, 2u8, 3u8,
+                    3u8, 26u8, 3u8, 31u8, 3u8, 35u8, 3u8, 45u8, 3u8, 50u8, 3u8, 65u8, 3u8, 116u8,
+                    3u8, 126u8, 3u8, 146u8, 3u8, 167u8, 3u8, 173u8, 3u8, 186u8, 3u8, 198u8, 3u8,
+                    228u8, 3u8, 245u8, 3u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 98u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 101u8, 109u8, 105u8, 116u8, 101u8, 109u8, 105u8, 116u8, 101u8, 109u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 105u8, 116u8, 101u8, 109u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 105u8,
+                    116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 97u8, 114u8, 116u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 112u8, 105u8, 120u8, 101u8, 108u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 111u8, 119u8, 52u8,
+                    45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 114u8,
+                    101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8,
+                    109u8, 112u8, 101u8, 114u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 114u8, 101u8,
+                    118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 39u8, 0u8, 54u8, 0u8, 61u8, 0u8,
+                    79u8, 0u8, 90u8, 0u8, 103u8, 0u8, 109u8, 0u8, 124u8, 0u8, 135u8, 0u8, 155u8,
+                    0u8, 164u8, 0u8, 180u8, 0u8, 193u8, 0u8, 211u8, 0u8, 229u8, 0u8, 240u8, 0u8,
+                    244u8, 0u8, 249u8, 0u8, 5u8, 1u8, 18u8, 1u8, 26u8, 1u8, 45u8, 1u8, 63u8, 1u8,
+                    68u8, 1u8, 75u8, 1u8, 94u8, 1u8, 100u8, 1u8, 113u8, 1u8, 132u8, 1u8, 138u8,
+                    1u8, 143u8, 1u8, 155u8, 1u8, 169u8, 1u8, 176u8, 1u8, 189u8, 1u8, 197u8, 1u8,
+                    207u8, 1u8, 227u8, 1u8, 232u8, 1u8, 243u8, 1u8, 252u8, 1u8, 4u8, 2u8, 19u8,
+                    2u8, 23u8, 2u8, 41u8, 2u8, 52u8, 2u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    105u8, 99u8, 45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 116u8, 109u8, 97u8,
+                    103u8, 110u8, 101u8, 116u8, 105u8, 99u8, 45u8, 102u8, 105u8, 101u8, 108u8,
+                    100u8, 45u8, 115u8, 116u8, 114u8, 101u8, 110u8, 103u8, 116u8, 104u8, 99u8,
+                    117u8, 114u8, 114u8, 101u8, 110u8, 116u8, 45u8, 100u8, 101u8, 110u8, 115u8,
+                    105u8, 116u8, 121u8, 100u8, 105u8, 103u8, 105u8, 116u8, 97u8, 108u8, 108u8,
+                    117u8, 109u8, 105u8, 110u8, 111u8, 117u8, 115u8, 45u8, 105u8, 110u8, 116u8,
+                    101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 105u8, 108u8, 108u8, 117u8, 109u8,
+                    105u8, 110u8, 97u8, 110u8, 99u8, 101u8, 108u8, 117u8, 109u8, 105u8, 110u8,
+                    111u8, 117u8, 115u8, 45u8, 102u8, 108u8, 117u8, 120u8, 118u8, 111u8, 108u8,
+                    117u8, 109u8, 101u8, 115u8, 112u8, 101u8, 99u8, 105u8, 102u8, 105u8, 99u8,
+                    45u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 99u8, 111u8, 110u8, 115u8,
+                    117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 101u8, 108u8, 101u8, 99u8,
+                    116u8, 114u8, 105u8, 99u8, 45u8, 99u8, 111u8, 110u8, 100u8, 117u8, 99u8, 116u8,
+                    97u8, 110u8, 99u8, 101u8, 116u8, 121u8, 112u8, 101u8, 119u8, 105u8, 100u8,
+                    116u8, 104u8, 115u8, 117u8, 98u8, 115u8, 116u8, 97u8, 110u8, 99u8, 101u8, 45u8,
+                    97u8, 109u8, 111u8, 117u8, 110u8, 116u8, 99u8, 111u8, 110u8, 99u8, 101u8,
+                    110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 99u8, 111u8, 110u8,
+                    99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 45u8,
+                    109u8, 97u8, 115u8, 115u8, 99u8, 97u8, 116u8, 97u8, 108u8, 121u8, 116u8, 105u8,
+                    99u8, 45u8, 97u8, 99u8, 116u8, 105u8, 118u8, 105u8, 116u8, 121u8, 116u8, 101u8,
+                    109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 109u8, 97u8,
+                    115u8, 115u8, 102u8, 111u8, 114u8, 99u8, 101u8, 109u8, 97u8, 115u8, 115u8,
+                    45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 109u8, 97u8, 115u8,
+                    115u8, 45u8, 102u8, 114u8, 97u8, 99u8, 116u8, 105u8, 111u8, 110u8, 112u8,
+                    114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 112u8, 114u8, 101u8, 115u8,
+                    115u8, 117u8, 114u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 101u8,
+                    110u8, 103u8, 116u8, 104u8, 109u8, 97u8, 103u8, 110u8, 101u8, 116u8, 105u8,
+                    99u8, 45u8, 105u8, 110u8, 100u8, 117u8, 99u8, 116u8, 105u8, 111u8, 110u8,
+                    112u8, 111u8, 119u8, 101u8, 114u8, 118u8, 111u8, 108u8, 116u8, 97u8, 103u8,
+                    101u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8, 45u8, 114u8,
+                    101u8, 115u8, 105u8, 115u8, 116u8, 97u8, 110u8, 99u8, 101u8, 101u8, 110u8,
+                    101u8, 114u8, 103u8, 121u8, 109u8, 97u8, 103u8, 110u8, 101u8, 116u8, 105u8,
+                    99u8, 45u8, 102u8, 108u8, 117u8, 120u8, 101u8, 108u8, 101u8, 99u8, 116u8,
+                    114u8, 105u8, 99u8, 45u8, 105u8, 110u8, 100u8, 117u8, 99u8, 116u8, 97u8, 110u8,
+                    99u8, 101u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 112u8, 101u8,
+                    101u8, 100u8, 97u8, 99u8, 99u8, 101u8, 108u8, 101u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 110u8, 105u8, 103u8, 104u8, 116u8, 45u8, 100u8, 117u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 112u8, 111u8, 114u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 97u8, 100u8, 105u8, 111u8, 97u8, 99u8, 116u8, 105u8, 118u8,
+                    105u8, 116u8, 121u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8, 115u8,
+                    114u8, 101u8, 115u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 101u8,
+                    108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8, 45u8, 99u8, 97u8, 112u8, 97u8,
+                    99u8, 105u8, 116u8, 97u8, 110u8, 99u8, 101u8, 97u8, 110u8, 103u8, 108u8, 101u8,
+                    119u8, 97u8, 118u8, 101u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8,
+                    102u8, 114u8, 101u8, 113u8, 117u8, 101u8, 110u8, 99u8, 121u8, 100u8, 117u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 101u8, 108u8, 101u8, 99u8, 116u8,
+                    114u8, 105u8, 99u8, 45u8, 99u8, 104u8, 97u8, 114u8, 103u8, 101u8, 97u8, 114u8,
+                    101u8, 97u8, 105u8, 111u8, 110u8, 105u8, 122u8, 105u8, 110u8, 103u8, 45u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 116u8, 105u8, 111u8, 110u8, 115u8, 111u8,
+                    108u8, 105u8, 100u8, 45u8, 97u8, 110u8, 103u8, 108u8, 101u8, 121u8, 101u8,
+                    97u8, 114u8, 45u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+};
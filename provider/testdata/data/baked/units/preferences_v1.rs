@@ -0,0 +1,913 @@
+// @generated
+type DataStruct =
+    <::icu_units::provider::UnitPreferencesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_units::provider::UnitPreferencesV1 {
+    units: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    194u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 31u8, 0u8, 46u8, 0u8, 60u8, 0u8,
+                    73u8, 0u8, 86u8, 0u8, 99u8, 0u8, 112u8, 0u8, 129u8, 0u8, 145u8, 0u8, 161u8,
+                    0u8, 174u8, 0u8, 186u8, 0u8, 198u8, 0u8, 229u8, 0u8, 3u8, 1u8, 33u8, 1u8, 63u8,
+                    1u8, 93u8, 1u8, 123u8, 1u8, 153u8, 1u8, 183u8, 1u8, 213u8, 1u8, 243u8, 1u8,
+                    17u8, 2u8, 47u8, 2u8, 77u8, 2u8, 107u8, 2u8, 137u8, 2u8, 167u8, 2u8, 197u8,
+                    2u8, 227u8, 2u8, 1u8, 3u8, 31u8, 3u8, 61u8, 3u8, 91u8, 3u8, 121u8, 3u8, 151u8,
+                    3u8, 181u8, 3u8, 211u8, 3u8, 241u8, 3u8, 15u8, 4u8, 45u8, 4u8, 75u8, 4u8,
+                    105u8, 4u8, 135u8, 4u8, 165u8, 4u8, 195u8, 4u8, 225u8, 4u8, 255u8, 4u8, 29u8,
+                    5u8, 59u8, 5u8, 89u8, 5u8, 119u8, 5u8, 149u8, 5u8, 179u8, 5u8, 209u8, 5u8,
+                    239u8, 5u8, 13u8, 6u8, 43u8, 6u8, 73u8, 6u8, 103u8, 6u8, 133u8, 6u8, 163u8,
+                    6u8, 193u8, 6u8, 223u8, 6u8, 253u8, 6u8, 27u8, 7u8, 57u8, 7u8, 87u8, 7u8,
+                    117u8, 7u8, 147u8, 7u8, 177u8, 7u8, 207u8, 7u8, 237u8, 7u8, 11u8, 8u8, 41u8,
+                    8u8, 71u8, 8u8, 101u8, 8u8, 131u8, 8u8, 161u8, 8u8, 191u8, 8u8, 221u8, 8u8,
+                    246u8, 8u8, 13u8, 9u8, 41u8, 9u8, 68u8, 9u8, 95u8, 9u8, 122u8, 9u8, 149u8, 9u8,
+                    176u8, 9u8, 203u8, 9u8, 230u8, 9u8, 1u8, 10u8, 28u8, 10u8, 55u8, 10u8, 82u8,
+                    10u8, 109u8, 10u8, 129u8, 10u8, 147u8, 10u8, 165u8, 10u8, 180u8, 10u8, 194u8,
+                    10u8, 212u8, 10u8, 229u8, 10u8, 246u8, 10u8, 13u8, 11u8, 37u8, 11u8, 60u8,
+                    11u8, 83u8, 11u8, 106u8, 11u8, 129u8, 11u8, 146u8, 11u8, 162u8, 11u8, 178u8,
+                    11u8, 194u8, 11u8, 210u8, 11u8, 229u8, 11u8, 247u8, 11u8, 9u8, 12u8, 24u8,
+                    12u8, 38u8, 12u8, 52u8, 12u8, 66u8, 12u8, 85u8, 12u8, 103u8, 12u8, 121u8, 12u8,
+                    140u8, 12u8, 158u8, 12u8, 176u8, 12u8, 194u8, 12u8, 212u8, 12u8, 236u8, 12u8,
+                    252u8, 12u8, 11u8, 13u8, 26u8, 13u8, 41u8, 13u8, 55u8, 13u8, 72u8, 13u8, 88u8,
+                    13u8, 103u8, 13u8, 118u8, 13u8, 139u8, 13u8, 159u8, 13u8, 179u8, 13u8, 199u8,
+                    13u8, 219u8, 13u8, 239u8, 13u8, 3u8, 14u8, 23u8, 14u8, 43u8, 14u8, 63u8, 14u8,
+                    82u8, 14u8, 101u8, 14u8, 118u8, 14u8, 134u8, 14u8, 150u8, 14u8, 168u8, 14u8,
+                    185u8, 14u8, 202u8, 14u8, 220u8, 14u8, 237u8, 14u8, 251u8, 14u8, 8u8, 15u8,
+                    21u8, 15u8, 34u8, 15u8, 47u8, 15u8, 60u8, 15u8, 73u8, 15u8, 86u8, 15u8, 99u8,
+                    15u8, 112u8, 15u8, 125u8, 15u8, 138u8, 15u8, 161u8, 15u8, 183u8, 15u8, 206u8,
+                    15u8, 228u8, 15u8, 250u8, 15u8, 16u8, 16u8, 38u8, 16u8, 60u8, 16u8, 82u8, 16u8,
+                    100u8, 16u8, 117u8, 16u8, 134u8, 16u8, 150u8, 16u8, 165u8, 16u8, 180u8, 16u8,
+                    194u8, 16u8, 212u8, 16u8, 229u8, 16u8, 254u8, 16u8, 97u8, 114u8, 101u8, 97u8,
+                    47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8,
+                    97u8, 114u8, 101u8, 97u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+                    47u8, 71u8, 66u8, 97u8, 114u8, 101u8, 97u8, 47u8, 100u8, 101u8, 102u8, 97u8,
+                    117u8, 108u8, 116u8, 47u8, 85u8, 83u8, 97u8, 114u8, 101u8, 97u8, 47u8, 102u8,
+                    108u8, 111u8, 111u8, 114u8, 47u8, 48u8, 48u8, 49u8, 97u8, 114u8, 101u8, 97u8,
+                    47u8, 102u8, 108u8, 111u8, 111u8, 114u8, 47u8, 67u8, 65u8, 97u8, 114u8, 101u8,
+                    97u8, 47u8, 102u8, 108u8, 111u8, 111u8, 114u8, 47u8, 71u8, 66u8, 97u8, 114u8,
+                    101u8, 97u8, 47u8, 102u8, 108u8, 111u8, 111u8, 114u8, 47u8, 77u8, 77u8, 97u8,
+                    114u8, 101u8, 97u8, 47u8, 102u8, 108u8, 111u8, 111u8, 114u8, 47u8, 85u8, 83u8,
+                    97u8, 114u8, 101u8, 97u8, 47u8, 103u8, 101u8, 111u8, 103u8, 114u8, 97u8, 112u8,
+                    104u8, 47u8, 48u8, 48u8, 49u8, 97u8, 114u8, 101u8, 97u8, 47u8, 103u8, 101u8,
+                    111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 47u8, 71u8, 66u8, 97u8, 114u8, 101u8,
+                    97u8, 47u8, 103u8, 101u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 47u8, 85u8,
+                    83u8, 97u8, 114u8, 101u8, 97u8, 47u8, 108u8, 97u8, 110u8, 100u8, 47u8, 48u8,
+                    48u8, 49u8, 97u8, 114u8, 101u8, 97u8, 47u8, 108u8, 97u8, 110u8, 100u8, 47u8,
+                    71u8, 66u8, 97u8, 114u8, 101u8, 97u8, 47u8, 108u8, 97u8, 110u8, 100u8, 47u8,
+                    85u8, 83u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+                    108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 48u8, 48u8, 49u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 65u8, 71u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 65u8, 73u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 65u8, 79u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 65u8, 85u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 66u8, 65u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 66u8, 71u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 66u8, 72u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 66u8, 77u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 66u8, 78u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 66u8, 87u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 66u8, 89u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 67u8, 65u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 67u8, 72u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 67u8, 77u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 67u8, 78u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 67u8, 90u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 68u8, 75u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 68u8, 77u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 69u8, 69u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 70u8, 73u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 70u8, 74u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 71u8, 66u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 71u8, 68u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 72u8, 75u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 72u8, 82u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 72u8, 85u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 73u8, 69u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 73u8, 77u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 73u8, 83u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 75u8, 69u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 75u8, 78u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 75u8, 87u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 75u8, 90u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 76u8, 67u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 76u8, 73u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 76u8, 84u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 76u8, 85u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 76u8, 86u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 77u8, 69u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 77u8, 71u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 77u8, 75u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 77u8, 79u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 77u8, 83u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 77u8, 84u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 77u8, 85u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 77u8, 89u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 77u8, 90u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 78u8, 65u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 78u8, 76u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 78u8, 79u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 78u8, 90u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 79u8, 77u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 80u8, 71u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 82u8, 83u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 82u8, 85u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 83u8, 69u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 83u8, 71u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 83u8, 73u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 83u8, 75u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 84u8, 67u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 84u8, 79u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 85u8, 65u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 85u8, 71u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8,
+                    108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 47u8, 86u8, 67u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 86u8, 71u8, 99u8, 111u8,
+                    110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+                    115u8, 101u8, 47u8, 86u8, 78u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8,
+                    100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 86u8, 85u8,
+                    99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+                    117u8, 99u8, 111u8, 115u8, 101u8, 47u8, 90u8, 65u8, 99u8, 111u8, 110u8, 99u8,
+                    101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 99u8, 111u8,
+                    110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 99u8, 111u8,
+                    110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8,
+                    101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8,
+                    47u8, 48u8, 48u8, 49u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8,
+                    105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8,
+                    45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 66u8, 82u8, 99u8, 111u8, 110u8, 115u8,
+                    117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8,
+                    105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 67u8, 65u8,
+                    99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8,
+                    47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+                    101u8, 108u8, 47u8, 71u8, 66u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8,
+                    116u8, 105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8,
+                    101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 73u8, 84u8, 99u8, 111u8, 110u8,
+                    115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8, 101u8,
+                    104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 74u8,
+                    80u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8,
+                    110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8,
+                    117u8, 101u8, 108u8, 47u8, 75u8, 82u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8,
+                    112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8,
+                    108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 77u8, 88u8, 99u8, 111u8,
+                    110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8,
+                    101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8,
+                    47u8, 77u8, 89u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+                    111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8,
+                    102u8, 117u8, 101u8, 108u8, 47u8, 78u8, 76u8, 99u8, 111u8, 110u8, 115u8, 117u8,
+                    109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8,
+                    99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 84u8, 72u8, 99u8,
+                    111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 47u8,
+                    118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8,
+                    108u8, 47u8, 84u8, 82u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8,
+                    105u8, 111u8, 110u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8,
+                    45u8, 102u8, 117u8, 101u8, 108u8, 47u8, 85u8, 83u8, 100u8, 117u8, 114u8, 97u8,
+                    116u8, 105u8, 111u8, 110u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+                    116u8, 47u8, 48u8, 48u8, 49u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 47u8, 109u8, 101u8, 100u8, 105u8, 97u8, 47u8, 48u8, 48u8, 49u8, 101u8,
+                    110u8, 101u8, 114u8, 103u8, 121u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+                    108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 101u8, 110u8, 101u8, 114u8, 103u8, 121u8,
+                    47u8, 102u8, 111u8, 111u8, 100u8, 47u8, 48u8, 48u8, 49u8, 101u8, 110u8, 101u8,
+                    114u8, 103u8, 121u8, 47u8, 102u8, 111u8, 111u8, 100u8, 47u8, 85u8, 83u8, 108u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+                    108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+                    47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 71u8, 66u8, 108u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+                    108u8, 116u8, 47u8, 85u8, 83u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8,
+                    102u8, 111u8, 99u8, 97u8, 108u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+                    104u8, 47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8,
+                    104u8, 116u8, 47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+                    47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8,
+                    103u8, 104u8, 116u8, 47u8, 67u8, 65u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+                    104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8,
+                    105u8, 103u8, 104u8, 116u8, 47u8, 71u8, 66u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8,
+                    101u8, 105u8, 103u8, 104u8, 116u8, 47u8, 73u8, 78u8, 108u8, 101u8, 110u8,
+                    103u8, 116u8, 104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8,
+                    104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 47u8, 85u8, 83u8, 108u8, 101u8,
+                    110u8, 103u8, 116u8, 104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+                    47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 112u8,
+                    101u8, 114u8, 115u8, 111u8, 110u8, 47u8, 67u8, 65u8, 108u8, 101u8, 110u8,
+                    103u8, 116u8, 104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 47u8,
+                    71u8, 66u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 112u8, 101u8,
+                    114u8, 115u8, 111u8, 110u8, 47u8, 73u8, 78u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 47u8, 85u8, 83u8,
+                    108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8, 97u8, 105u8, 110u8,
+                    102u8, 97u8, 108u8, 108u8, 47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8, 108u8, 108u8, 47u8,
+                    66u8, 82u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8, 97u8, 105u8,
+                    110u8, 102u8, 97u8, 108u8, 108u8, 47u8, 85u8, 83u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 114u8, 111u8, 97u8, 100u8, 47u8, 48u8, 48u8, 49u8, 108u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8, 111u8, 97u8, 100u8, 47u8, 71u8,
+                    66u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8, 111u8, 97u8,
+                    100u8, 47u8, 83u8, 69u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8,
+                    111u8, 97u8, 100u8, 47u8, 85u8, 83u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+                    47u8, 115u8, 110u8, 111u8, 119u8, 102u8, 97u8, 108u8, 108u8, 47u8, 48u8, 48u8,
+                    49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 115u8, 110u8, 111u8,
+                    119u8, 102u8, 97u8, 108u8, 108u8, 47u8, 85u8, 83u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 47u8, 48u8,
+                    48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 118u8, 105u8,
+                    115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 47u8, 48u8, 48u8, 49u8, 108u8, 101u8,
+                    110u8, 103u8, 116u8, 104u8, 47u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+                    116u8, 121u8, 47u8, 68u8, 69u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8,
+                    118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 47u8, 71u8, 66u8, 108u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 118u8, 105u8, 115u8, 105u8, 98u8,
+                    108u8, 116u8, 121u8, 47u8, 78u8, 76u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+                    104u8, 47u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 47u8, 85u8,
+                    83u8, 109u8, 97u8, 115u8, 115u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8,
+                    116u8, 121u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8,
+                    48u8, 49u8, 109u8, 97u8, 115u8, 115u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+                    108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 109u8, 97u8, 115u8, 115u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 71u8, 66u8, 109u8, 97u8, 115u8,
+                    115u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 85u8, 83u8,
+                    109u8, 97u8, 115u8, 115u8, 47u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+                    47u8, 48u8, 48u8, 49u8, 109u8, 97u8, 115u8, 115u8, 47u8, 112u8, 101u8, 114u8,
+                    115u8, 111u8, 110u8, 47u8, 85u8, 83u8, 112u8, 111u8, 119u8, 101u8, 114u8, 47u8,
+                    100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 112u8,
+                    111u8, 119u8, 101u8, 114u8, 47u8, 101u8, 110u8, 103u8, 105u8, 110u8, 101u8,
+                    47u8, 48u8, 48u8, 49u8, 112u8, 111u8, 119u8, 101u8, 114u8, 47u8, 101u8, 110u8,
+                    103u8, 105u8, 110u8, 101u8, 47u8, 71u8, 66u8, 112u8, 111u8, 119u8, 101u8,
+                    114u8, 47u8, 101u8, 110u8, 103u8, 105u8, 110u8, 101u8, 47u8, 85u8, 83u8, 112u8,
+                    114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8,
+                    111u8, 109u8, 116u8, 114u8, 99u8, 47u8, 48u8, 48u8, 49u8, 112u8, 114u8, 101u8,
+                    115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8, 111u8, 109u8,
+                    116u8, 114u8, 99u8, 47u8, 66u8, 82u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8,
+                    114u8, 101u8, 47u8, 98u8, 97u8, 114u8, 111u8, 109u8, 116u8, 114u8, 99u8, 47u8,
+                    69u8, 71u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 98u8,
+                    97u8, 114u8, 111u8, 109u8, 116u8, 114u8, 99u8, 47u8, 71u8, 66u8, 112u8, 114u8,
+                    101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8, 111u8,
+                    109u8, 116u8, 114u8, 99u8, 47u8, 73u8, 76u8, 112u8, 114u8, 101u8, 115u8, 115u8,
+                    117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8, 111u8, 109u8, 116u8, 114u8, 99u8,
+                    47u8, 77u8, 88u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8,
+                    98u8, 97u8, 114u8, 111u8, 109u8, 116u8, 114u8, 99u8, 47u8, 82u8, 85u8, 112u8,
+                    114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8,
+                    111u8, 109u8, 116u8, 114u8, 99u8, 47u8, 84u8, 72u8, 112u8, 114u8, 101u8, 115u8,
+                    115u8, 117u8, 114u8, 101u8, 47u8, 98u8, 97u8, 114u8, 111u8, 109u8, 116u8,
+                    114u8, 99u8, 47u8, 85u8, 83u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8,
+                    101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8,
+                    49u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 71u8, 66u8, 112u8, 114u8, 101u8,
+                    115u8, 115u8, 117u8, 114u8, 101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+                    108u8, 116u8, 47u8, 85u8, 83u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 115u8, 112u8,
+                    101u8, 101u8, 100u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+                    47u8, 71u8, 66u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 100u8, 101u8, 102u8,
+                    97u8, 117u8, 108u8, 116u8, 47u8, 85u8, 83u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    47u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8, 108u8, 108u8, 47u8, 48u8, 48u8,
+                    49u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 114u8, 97u8, 105u8, 110u8,
+                    102u8, 97u8, 108u8, 108u8, 47u8, 66u8, 82u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    47u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8, 108u8, 108u8, 47u8, 85u8, 83u8,
+                    115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 115u8, 110u8, 111u8, 119u8, 102u8,
+                    97u8, 108u8, 108u8, 47u8, 48u8, 48u8, 49u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    47u8, 115u8, 110u8, 111u8, 119u8, 102u8, 97u8, 108u8, 108u8, 47u8, 85u8, 83u8,
+                    115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8,
+                    48u8, 48u8, 49u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8,
+                    100u8, 47u8, 67u8, 78u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8,
+                    110u8, 100u8, 47u8, 68u8, 75u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8,
+                    105u8, 110u8, 100u8, 47u8, 70u8, 73u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8,
+                    119u8, 105u8, 110u8, 100u8, 47u8, 71u8, 66u8, 115u8, 112u8, 101u8, 101u8,
+                    100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8, 74u8, 80u8, 115u8, 112u8, 101u8,
+                    101u8, 100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8, 75u8, 82u8, 115u8, 112u8,
+                    101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8, 78u8, 79u8, 115u8,
+                    112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8, 80u8, 76u8,
+                    115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8, 100u8, 47u8,
+                    82u8, 85u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8, 110u8,
+                    100u8, 47u8, 83u8, 69u8, 115u8, 112u8, 101u8, 101u8, 100u8, 47u8, 119u8, 105u8,
+                    110u8, 100u8, 47u8, 85u8, 83u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8,
+                    116u8, 117u8, 114u8, 101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+                    116u8, 47u8, 48u8, 48u8, 49u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8,
+                    116u8, 117u8, 114u8, 101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+                    116u8, 47u8, 85u8, 83u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8,
+                    117u8, 114u8, 101u8, 47u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8,
+                    47u8, 48u8, 48u8, 49u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8,
+                    117u8, 114u8, 101u8, 47u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8,
+                    47u8, 66u8, 83u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8,
+                    114u8, 101u8, 47u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 47u8, 66u8,
+                    90u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8,
+                    101u8, 47u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 47u8, 75u8, 89u8,
+                    116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8,
+                    47u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 47u8, 80u8, 82u8, 116u8,
+                    101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 47u8,
+                    119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 47u8, 80u8, 87u8, 116u8, 101u8,
+                    109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 47u8, 119u8,
+                    101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 47u8, 85u8, 83u8, 118u8, 111u8, 108u8,
+                    117u8, 109u8, 101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+                    47u8, 48u8, 48u8, 49u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 47u8, 100u8,
+                    101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 47u8, 71u8, 66u8, 118u8, 111u8, 108u8,
+                    117u8, 109u8, 101u8, 47u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+                    47u8, 85u8, 83u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 47u8, 102u8, 108u8,
+                    117u8, 105u8, 100u8, 47u8, 48u8, 48u8, 49u8, 118u8, 111u8, 108u8, 117u8, 109u8,
+                    101u8, 47u8, 102u8, 108u8, 117u8, 105u8, 100u8, 47u8, 71u8, 66u8, 118u8, 111u8,
+                    108u8, 117u8, 109u8, 101u8, 47u8, 102u8, 108u8, 117u8, 105u8, 100u8, 47u8,
+                    85u8, 83u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 47u8, 111u8, 105u8,
+                    108u8, 47u8, 48u8, 48u8, 49u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 47u8,
+                    118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 47u8, 48u8, 48u8, 49u8, 118u8,
+                    111u8, 108u8, 117u8, 109u8, 101u8, 47u8, 118u8, 101u8, 104u8, 105u8, 99u8,
+                    108u8, 101u8, 47u8, 85u8, 83u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8,
+                    114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 100u8, 101u8, 102u8, 97u8,
+                    117u8, 108u8, 116u8, 47u8, 48u8, 48u8, 49u8, 121u8, 101u8, 97u8, 114u8, 45u8,
+                    100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 112u8, 101u8,
+                    114u8, 115u8, 111u8, 110u8, 45u8, 97u8, 103u8, 101u8, 47u8, 48u8, 48u8, 49u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8,
+                    0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 21u8,
+                    0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8,
+                    25u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8,
+                    0u8, 29u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 32u8, 0u8,
+                    0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 36u8,
+                    0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8,
+                    40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8,
+                    0u8, 44u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+                    0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8,
+                    0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8,
+                    55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8,
+                    0u8, 59u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 62u8, 0u8,
+                    0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 66u8,
+                    0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 68u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8,
+                    70u8, 0u8, 0u8, 0u8, 71u8, 0u8, 0u8, 0u8, 72u8, 0u8, 0u8, 0u8, 73u8, 0u8, 0u8,
+                    0u8, 74u8, 0u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 77u8, 0u8,
+                    0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 79u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 81u8,
+                    0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 83u8, 0u8, 0u8, 0u8, 84u8, 0u8, 0u8, 0u8,
+                    85u8, 0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8,
+                    0u8, 89u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 92u8, 0u8,
+                    0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 96u8,
+                    0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8,
+                    100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8,
+                    0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8,
+                    107u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8,
+                    0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8,
+                    124u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 129u8, 0u8,
+                    0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8,
+                    133u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 136u8, 0u8,
+                    0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8,
+                    140u8, 0u8, 0u8, 0u8, 141u8, 0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 149u8, 0u8,
+                    0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8,
+                    160u8, 0u8, 0u8, 0u8, 161u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 164u8, 0u8,
+                    0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 167u8, 0u8, 0u8, 0u8, 169u8, 0u8, 0u8, 0u8,
+                    170u8, 0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8, 181u8, 0u8,
+                    0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 189u8, 0u8, 0u8, 0u8,
+                    190u8, 0u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8, 0u8, 193u8, 0u8,
+                    0u8, 0u8, 194u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 196u8, 0u8, 0u8, 0u8,
+                    197u8, 0u8, 0u8, 0u8, 198u8, 0u8, 0u8, 0u8, 199u8, 0u8, 0u8, 0u8, 200u8, 0u8,
+                    0u8, 0u8, 201u8, 0u8, 0u8, 0u8, 203u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8,
+                    205u8, 0u8, 0u8, 0u8, 206u8, 0u8, 0u8, 0u8, 207u8, 0u8, 0u8, 0u8, 208u8, 0u8,
+                    0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 211u8, 0u8, 0u8, 0u8,
+                    212u8, 0u8, 0u8, 0u8, 213u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 215u8, 0u8,
+                    0u8, 0u8, 216u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8,
+                    219u8, 0u8, 0u8, 0u8, 220u8, 0u8, 0u8, 0u8, 221u8, 0u8, 0u8, 0u8, 222u8, 0u8,
+                    0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 225u8, 0u8, 0u8, 0u8,
+                    226u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 228u8, 0u8, 0u8, 0u8, 229u8, 0u8,
+                    0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 231u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8,
+                    233u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 236u8, 0u8, 0u8, 0u8, 238u8, 0u8,
+                    0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 242u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8,
+                    251u8, 0u8, 0u8, 0u8, 252u8, 0u8, 0u8, 0u8, 253u8, 0u8, 0u8, 0u8, 254u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8,
+                    5u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                    3u8, 0u8, 4u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                    4u8, 0u8, 5u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 1u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 23u8, 0u8, 35u8, 0u8, 52u8, 0u8, 63u8,
+                    0u8, 67u8, 0u8, 78u8, 0u8, 89u8, 0u8, 100u8, 0u8, 104u8, 0u8, 115u8, 0u8,
+                    126u8, 0u8, 138u8, 0u8, 149u8, 0u8, 160u8, 0u8, 171u8, 0u8, 182u8, 0u8, 198u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 227u8, 0u8, 231u8, 0u8, 235u8, 0u8, 12u8, 1u8,
+                    31u8, 1u8, 50u8, 1u8, 69u8, 1u8, 88u8, 1u8, 107u8, 1u8, 126u8, 1u8, 145u8, 1u8,
+                    164u8, 1u8, 183u8, 1u8, 202u8, 1u8, 221u8, 1u8, 240u8, 1u8, 3u8, 2u8, 22u8,
+                    2u8, 41u8, 2u8, 60u8, 2u8, 79u8, 2u8, 98u8, 2u8, 117u8, 2u8, 136u8, 2u8, 155u8,
+                    2u8, 174u8, 2u8, 193u8, 2u8, 212u8, 2u8, 231u8, 2u8, 250u8, 2u8, 13u8, 3u8,
+                    32u8, 3u8, 51u8, 3u8, 70u8, 3u8, 89u8, 3u8, 108u8, 3u8, 127u8, 3u8, 146u8, 3u8,
+                    165u8, 3u8, 184u8, 3u8, 203u8, 3u8, 222u8, 3u8, 241u8, 3u8, 4u8, 4u8, 23u8,
+                    4u8, 42u8, 4u8, 61u8, 4u8, 80u8, 4u8, 99u8, 4u8, 118u8, 4u8, 137u8, 4u8, 156u8,
+                    4u8, 175u8, 4u8, 194u8, 4u8, 213u8, 4u8, 232u8, 4u8, 251u8, 4u8, 14u8, 5u8,
+                    33u8, 5u8, 52u8, 5u8, 71u8, 5u8, 90u8, 5u8, 109u8, 5u8, 128u8, 5u8, 147u8, 5u8,
+                    166u8, 5u8, 185u8, 5u8, 204u8, 5u8, 223u8, 5u8, 242u8, 5u8, 5u8, 6u8, 24u8,
+                    6u8, 44u8, 6u8, 67u8, 6u8, 90u8, 6u8, 109u8, 6u8, 133u8, 6u8, 157u8, 6u8,
+                    176u8, 6u8, 195u8, 6u8, 214u8, 6u8, 233u8, 6u8, 252u8, 6u8, 15u8, 7u8, 34u8,
+                    7u8, 53u8, 7u8, 68u8, 7u8, 71u8, 7u8, 75u8, 7u8, 81u8, 7u8, 87u8, 7u8, 98u8,
+                    7u8, 109u8, 7u8, 119u8, 7u8, 125u8, 7u8, 138u8, 7u8, 149u8, 7u8, 160u8, 7u8,
+                    169u8, 7u8, 174u8, 7u8, 184u8, 7u8, 188u8, 7u8, 192u8, 7u8, 196u8, 7u8, 200u8,
+                    7u8, 204u8, 7u8, 208u8, 7u8, 218u8, 7u8, 228u8, 7u8, 232u8, 7u8, 236u8, 7u8,
+                    240u8, 7u8, 244u8, 7u8, 254u8, 7u8, 2u8, 8u8, 6u8, 8u8, 10u8, 8u8, 14u8, 8u8,
+                    24u8, 8u8, 34u8, 8u8, 38u8, 8u8, 47u8, 8u8, 52u8, 8u8, 57u8, 8u8, 62u8, 8u8,
+                    66u8, 8u8, 70u8, 8u8, 74u8, 8u8, 78u8, 8u8, 95u8, 8u8, 104u8, 8u8, 109u8, 8u8,
+                    114u8, 8u8, 119u8, 8u8, 123u8, 8u8, 127u8, 8u8, 131u8, 8u8, 135u8, 8u8, 145u8,
+                    8u8, 149u8, 8u8, 154u8, 8u8, 163u8, 8u8, 168u8, 8u8, 173u8, 8u8, 177u8, 8u8,
+                    181u8, 8u8, 186u8, 8u8, 190u8, 8u8, 194u8, 8u8, 218u8, 8u8, 223u8, 8u8, 231u8,
+                    8u8, 235u8, 8u8, 244u8, 8u8, 253u8, 8u8, 0u8, 9u8, 5u8, 9u8, 10u8, 9u8, 13u8,
+                    9u8, 18u8, 9u8, 23u8, 9u8, 31u8, 9u8, 35u8, 9u8, 40u8, 9u8, 48u8, 9u8, 56u8,
+                    9u8, 64u8, 9u8, 68u8, 9u8, 77u8, 9u8, 85u8, 9u8, 95u8, 9u8, 105u8, 9u8, 116u8,
+                    9u8, 124u8, 9u8, 132u8, 9u8, 140u8, 9u8, 148u8, 9u8, 163u8, 9u8, 178u8, 9u8,
+                    186u8, 9u8, 195u8, 9u8, 205u8, 9u8, 211u8, 9u8, 238u8, 9u8, 9u8, 10u8, 27u8,
+                    10u8, 40u8, 10u8, 53u8, 10u8, 72u8, 10u8, 91u8, 10u8, 104u8, 10u8, 123u8, 10u8,
+                    136u8, 10u8, 154u8, 10u8, 170u8, 10u8, 186u8, 10u8, 202u8, 10u8, 215u8, 10u8,
+                    231u8, 10u8, 247u8, 10u8, 7u8, 11u8, 23u8, 11u8, 39u8, 11u8, 55u8, 11u8, 68u8,
+                    11u8, 75u8, 11u8, 85u8, 11u8, 92u8, 11u8, 102u8, 11u8, 112u8, 11u8, 122u8,
+                    11u8, 132u8, 11u8, 142u8, 11u8, 152u8, 11u8, 163u8, 11u8, 179u8, 11u8, 189u8,
+                    11u8, 199u8, 11u8, 209u8, 11u8, 219u8, 11u8, 224u8, 11u8, 234u8, 11u8, 249u8,
+                    11u8, 13u8, 12u8, 19u8, 12u8, 24u8, 12u8, 28u8, 12u8, 31u8, 12u8, 42u8, 12u8,
+                    52u8, 12u8, 60u8, 12u8, 66u8, 12u8, 71u8, 12u8, 77u8, 12u8, 81u8, 12u8, 86u8,
+                    12u8, 97u8, 12u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 104u8, 101u8, 99u8, 116u8,
+                    97u8, 114u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 97u8, 99u8,
+                    114u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8,
+                    111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 97u8, 99u8, 114u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 97u8, 99u8, 114u8, 101u8,
+                    97u8, 99u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 100u8, 101u8, 99u8, 105u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 105u8, 116u8,
+                    101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8,
+                    109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 100u8, 97u8,
+                    121u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 110u8, 111u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8,
+                    105u8, 101u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8,
+                    105u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 105u8, 110u8, 99u8, 104u8, 109u8, 105u8, 108u8, 101u8, 102u8, 111u8,
+                    111u8, 116u8, 105u8, 110u8, 99u8, 104u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8,
+                    104u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 105u8, 110u8, 99u8,
+                    104u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8,
+                    99u8, 104u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 121u8,
+                    97u8, 114u8, 100u8, 121u8, 97u8, 114u8, 100u8, 121u8, 97u8, 114u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8,
+                    110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8,
+                    111u8, 116u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 101u8, 102u8, 111u8, 111u8, 116u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 116u8, 111u8, 110u8, 110u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 103u8, 114u8,
+                    97u8, 109u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 116u8, 111u8,
+                    110u8, 112u8, 111u8, 117u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    116u8, 111u8, 110u8, 112u8, 111u8, 117u8, 110u8, 100u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 103u8,
+                    114u8, 97u8, 109u8, 112u8, 111u8, 117u8, 110u8, 100u8, 103u8, 105u8, 103u8,
+                    97u8, 119u8, 97u8, 116u8, 116u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 119u8, 97u8,
+                    116u8, 116u8, 109u8, 105u8, 108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 104u8, 111u8, 114u8,
+                    115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 114u8, 115u8,
+                    101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 101u8, 99u8, 116u8, 111u8,
+                    112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8,
+                    97u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 98u8, 97u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8, 103u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8, 105u8, 110u8,
+                    99u8, 104u8, 45u8, 111u8, 102u8, 104u8, 103u8, 109u8, 101u8, 103u8, 97u8,
+                    112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8,
+                    111u8, 114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+                    111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8,
+                    115u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 102u8, 97u8, 104u8, 114u8,
+                    101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8,
+                    105u8, 116u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8,
+                    111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8,
+                    97u8, 108u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 113u8, 117u8, 97u8,
+                    114u8, 116u8, 112u8, 105u8, 110u8, 116u8, 99u8, 117u8, 112u8, 102u8, 108u8,
+                    117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 116u8, 97u8, 98u8,
+                    108u8, 101u8, 115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 121u8,
+                    101u8, 97u8, 114u8, 109u8, 111u8, 110u8, 116u8, 104u8, 121u8, 101u8, 97u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    thresholds: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 29u8, 0u8, 43u8, 0u8, 57u8, 0u8, 76u8,
+                    0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8, 111u8, 97u8, 100u8,
+                    47u8, 48u8, 48u8, 49u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 47u8, 114u8,
+                    111u8, 97u8, 100u8, 47u8, 71u8, 66u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+                    47u8, 114u8, 111u8, 97u8, 100u8, 47u8, 83u8, 69u8, 108u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 47u8, 114u8, 111u8, 97u8, 100u8, 47u8, 85u8, 83u8, 108u8, 101u8,
+                    110u8, 103u8, 116u8, 104u8, 47u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+                    116u8, 121u8, 47u8, 48u8, 48u8, 49u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8,
+                    117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 47u8, 112u8, 101u8, 114u8,
+                    115u8, 111u8, 110u8, 45u8, 97u8, 103u8, 101u8, 47u8, 48u8, 48u8, 49u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 2u8, 0u8, 3u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,9680 @@
+// @generated
+type DataStruct =
+    <::icu_units::provider::ShortUnitsDisplayV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_slice_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 14u8, 0u8, 24u8, 0u8, 30u8, 0u8, 37u8,
+                    0u8, 40u8, 0u8, 43u8, 0u8, 49u8, 0u8, 55u8, 0u8, 66u8, 0u8, 86u8, 0u8, 90u8,
+                    0u8, 96u8, 0u8, 100u8, 0u8, 104u8, 0u8, 109u8, 0u8, 119u8, 0u8, 124u8, 0u8,
+                    129u8, 0u8, 133u8, 0u8, 138u8, 0u8, 151u8, 0u8, 156u8, 0u8, 161u8, 0u8, 167u8,
+                    0u8, 172u8, 0u8, 177u8, 0u8, 184u8, 0u8, 194u8, 0u8, 204u8, 0u8, 212u8, 0u8,
+                    216u8, 0u8, 220u8, 0u8, 97u8, 99u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 99u8,
+                    101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 117u8, 112u8, 100u8, 97u8,
+                    121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    114u8, 97u8, 109u8, 105u8, 116u8, 101u8, 109u8, 107u8, 97u8, 114u8, 97u8,
+                    116u8, 108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    105u8, 103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 97u8,
+                    114u8, 115u8, 101u8, 99u8, 112u8, 111u8, 105u8, 110u8, 116u8, 113u8, 117u8,
+                    97u8, 114u8, 116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8,
+                    101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 116u8, 97u8,
+                    98u8, 108u8, 101u8, 115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8,
+                    115u8, 112u8, 111u8, 111u8, 110u8, 119u8, 101u8, 101u8, 107u8, 121u8, 97u8,
+                    114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 29u8, 0u8, 50u8, 0u8, 60u8, 0u8, 66u8,
+                    0u8, 72u8, 0u8, 78u8, 0u8, 84u8, 0u8, 92u8, 0u8, 105u8, 0u8, 147u8, 0u8, 153u8,
+                    0u8, 163u8, 0u8, 171u8, 0u8, 179u8, 0u8, 189u8, 0u8, 206u8, 0u8, 212u8, 0u8,
+                    218u8, 0u8, 224u8, 0u8, 230u8, 0u8, 245u8, 0u8, 253u8, 0u8, 7u8, 1u8, 24u8,
+                    1u8, 32u8, 1u8, 49u8, 1u8, 55u8, 1u8, 63u8, 1u8, 77u8, 1u8, 90u8, 1u8, 100u8,
+                    1u8, 110u8, 1u8, 217u8, 129u8, 216u8, 175u8, 216u8, 167u8, 217u8, 134u8, 216u8,
+                    175u8, 217u8, 130u8, 217u8, 138u8, 217u8, 130u8, 216u8, 169u8, 32u8, 217u8,
+                    130u8, 217u8, 136u8, 216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 216u8, 171u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 217u8, 130u8,
+                    217u8, 136u8, 216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 216u8, 168u8, 216u8,
+                    177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 217u8, 130u8, 216u8, 177u8,
+                    217u8, 134u8, 217u8, 131u8, 217u8, 136u8, 216u8, 168u8, 217u8, 138u8, 217u8,
+                    136u8, 217u8, 133u8, 216u8, 185u8, 217u8, 130u8, 216u8, 175u8, 216u8, 175u8,
+                    216u8, 177u8, 216u8, 172u8, 216u8, 169u8, 216u8, 163u8, 217u8, 136u8, 217u8,
+                    134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8, 179u8, 216u8, 163u8, 217u8,
+                    136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8, 179u8, 216u8,
+                    167u8, 216u8, 166u8, 217u8, 132u8, 216u8, 169u8, 32u8, 216u8, 165u8, 217u8,
+                    133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8, 217u8, 136u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    133u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8,
+                    216u8, 186u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 216u8, 185u8, 217u8,
+                    134u8, 216u8, 181u8, 216u8, 177u8, 217u8, 130u8, 217u8, 138u8, 216u8, 177u8,
+                    216u8, 167u8, 216u8, 183u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8, 32u8,
+                    216u8, 182u8, 217u8, 136u8, 216u8, 166u8, 217u8, 138u8, 216u8, 169u8, 217u8,
+                    132u8, 216u8, 170u8, 216u8, 177u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 216u8, 180u8, 217u8, 135u8, 216u8,
+                    177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 32u8, 216u8, 168u8, 216u8,
+                    173u8, 216u8, 177u8, 217u8, 138u8, 217u8, 132u8, 217u8, 138u8, 217u8, 132u8,
+                    216u8, 169u8, 216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8,
+                    169u8, 217u8, 129u8, 216u8, 177u8, 216u8, 179u8, 216u8, 174u8, 32u8, 217u8,
+                    129u8, 217u8, 132u8, 217u8, 131u8, 217u8, 138u8, 217u8, 134u8, 217u8, 130u8,
+                    216u8, 183u8, 216u8, 169u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 32u8,
+                    216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 216u8,
+                    177u8, 216u8, 168u8, 216u8, 185u8, 216u8, 175u8, 217u8, 136u8, 216u8, 177u8,
+                    216u8, 169u8, 217u8, 133u8, 217u8, 132u8, 216u8, 185u8, 217u8, 130u8, 216u8,
+                    169u8, 32u8, 217u8, 131u8, 46u8, 217u8, 133u8, 217u8, 132u8, 216u8, 185u8,
+                    217u8, 130u8, 216u8, 169u8, 32u8, 216u8, 181u8, 216u8, 163u8, 216u8, 179u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 185u8, 217u8, 138u8, 216u8, 167u8, 216u8,
+                    177u8, 216u8, 175u8, 216u8, 169u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8,
+                    32u8, 217u8, 136u8, 216u8, 167u8, 216u8, 173u8, 216u8, 175u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 26u8, 0u8, 33u8, 0u8,
+                    36u8, 0u8, 42u8, 0u8, 48u8, 0u8, 59u8, 0u8, 63u8, 0u8, 73u8, 0u8, 78u8, 0u8,
+                    83u8, 0u8, 88u8, 0u8, 92u8, 0u8, 104u8, 0u8, 109u8, 0u8, 116u8, 0u8, 120u8,
+                    0u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8,
+                    114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 98u8, 97u8, 114u8,
+                    114u8, 101u8, 108u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 100u8,
+                    97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 103u8,
+                    114u8, 101u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 105u8, 116u8, 101u8, 109u8, 108u8, 105u8, 103u8, 104u8,
+                    116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8,
+                    97u8, 114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    49u8, 101u8, 57u8, 112u8, 111u8, 105u8, 110u8, 116u8, 113u8, 117u8, 97u8,
+                    114u8, 116u8, 101u8, 114u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 29u8, 0u8, 58u8, 0u8, 72u8, 0u8, 82u8, 0u8,
+                    92u8, 0u8, 102u8, 0u8, 114u8, 0u8, 131u8, 0u8, 143u8, 0u8, 168u8, 0u8, 178u8,
+                    0u8, 188u8, 0u8, 200u8, 0u8, 208u8, 0u8, 227u8, 0u8, 239u8, 0u8, 249u8, 0u8,
+                    7u8, 1u8, 216u8, 175u8, 217u8, 130u8, 217u8, 138u8, 217u8, 130u8, 216u8, 170u8,
+                    216u8, 167u8, 217u8, 134u8, 32u8, 217u8, 130u8, 217u8, 136u8, 216u8, 179u8,
+                    217u8, 138u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8, 216u8, 171u8, 216u8,
+                    167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8,
+                    32u8, 217u8, 130u8, 217u8, 136u8, 216u8, 179u8, 217u8, 138u8, 216u8, 170u8,
+                    216u8, 167u8, 217u8, 134u8, 216u8, 168u8, 216u8, 177u8, 217u8, 133u8, 217u8,
+                    138u8, 217u8, 132u8, 216u8, 167u8, 217u8, 134u8, 217u8, 130u8, 216u8, 177u8,
+                    217u8, 134u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 217u8, 136u8, 217u8,
+                    133u8, 216u8, 167u8, 217u8, 134u8, 216u8, 185u8, 217u8, 130u8, 216u8, 175u8,
+                    216u8, 167u8, 217u8, 134u8, 216u8, 175u8, 216u8, 177u8, 216u8, 172u8, 216u8,
+                    170u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8,
+                    217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8, 179u8,
+                    216u8, 185u8, 217u8, 134u8, 216u8, 181u8, 216u8, 177u8, 216u8, 167u8, 217u8,
+                    134u8, 216u8, 179u8, 217u8, 134u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8,
+                    32u8, 216u8, 182u8, 217u8, 136u8, 216u8, 166u8, 217u8, 138u8, 216u8, 170u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    167u8, 217u8, 134u8, 216u8, 180u8, 217u8, 135u8, 216u8, 177u8, 216u8, 167u8,
+                    217u8, 134u8, 217u8, 132u8, 217u8, 138u8, 217u8, 132u8, 216u8, 170u8, 216u8,
+                    167u8, 217u8, 134u8, 216u8, 172u8, 216u8, 178u8, 216u8, 162u8, 217u8, 134u8,
+                    216u8, 172u8, 216u8, 178u8, 216u8, 162u8, 217u8, 134u8, 47u8, 217u8, 133u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 217u8, 134u8, 217u8,
+                    130u8, 216u8, 183u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8, 216u8, 177u8,
+                    216u8, 168u8, 216u8, 185u8, 216u8, 167u8, 217u8, 134u8, 216u8, 163u8, 216u8,
+                    179u8, 216u8, 168u8, 217u8, 136u8, 216u8, 185u8, 216u8, 167u8, 217u8, 134u8,
+                    216u8, 179u8, 217u8, 134u8, 216u8, 170u8, 216u8, 167u8, 217u8, 134u8,
+                ])
+            },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 26u8, 0u8, 46u8, 0u8,
+                    53u8, 0u8, 56u8, 0u8, 62u8, 0u8, 68u8, 0u8, 86u8, 0u8, 98u8, 0u8, 109u8, 0u8,
+                    113u8, 0u8, 119u8, 0u8, 130u8, 0u8, 140u8, 0u8, 145u8, 0u8, 150u8, 0u8, 155u8,
+                    0u8, 159u8, 0u8, 171u8, 0u8, 176u8, 0u8, 183u8, 0u8, 191u8, 0u8, 195u8, 0u8,
+                    97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8,
+                    99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8,
+                    99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 100u8, 97u8, 121u8, 100u8,
+                    101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8,
+                    100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 102u8, 108u8, 117u8,
+                    105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 105u8, 116u8, 101u8,
+                    109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 108u8, 105u8, 103u8, 104u8,
+                    116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8, 105u8, 103u8, 104u8,
+                    116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8,
+                    97u8, 114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    49u8, 101u8, 57u8, 112u8, 111u8, 105u8, 110u8, 116u8, 113u8, 117u8, 97u8,
+                    114u8, 116u8, 101u8, 114u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8, 117u8,
+                    115u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 50u8, 0u8, 66u8, 0u8, 110u8, 0u8,
+                    122u8, 0u8, 134u8, 0u8, 146u8, 0u8, 160u8, 0u8, 177u8, 0u8, 198u8, 0u8, 225u8,
+                    0u8, 239u8, 0u8, 253u8, 0u8, 11u8, 1u8, 36u8, 1u8, 50u8, 1u8, 62u8, 1u8, 76u8,
+                    1u8, 90u8, 1u8, 115u8, 1u8, 127u8, 1u8, 141u8, 1u8, 183u8, 1u8, 199u8, 1u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 217u8, 130u8, 216u8, 167u8, 216u8,
+                    166u8, 217u8, 130u8, 32u8, 217u8, 130u8, 217u8, 136u8, 216u8, 179u8, 217u8,
+                    138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 171u8, 217u8, 136u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 141u8, 32u8, 217u8, 130u8, 217u8, 136u8,
+                    216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    168u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 216u8, 173u8, 216u8, 175u8, 216u8,
+                    167u8, 216u8, 170u8, 32u8, 216u8, 173u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 168u8, 216u8, 177u8, 217u8,
+                    138u8, 216u8, 183u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 177u8, 217u8, 136u8, 217u8,
+                    134u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8, 217u8, 130u8, 217u8,
+                    136u8, 216u8, 175u8, 123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 216u8, 177u8,
+                    216u8, 172u8, 216u8, 167u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    134u8, 217u8, 130u8, 216u8, 167u8, 216u8, 183u8, 47u8, 216u8, 179u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8, 217u8, 130u8, 216u8, 167u8,
+                    216u8, 183u8, 47u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8,
+                    181u8, 216u8, 167u8, 216u8, 170u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8,
+                    166u8, 217u8, 132u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8,
+                    217u8, 134u8, 216u8, 167u8, 216u8, 181u8, 216u8, 177u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 163u8, 217u8, 130u8, 216u8, 175u8, 216u8, 167u8, 216u8, 173u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 182u8, 217u8, 136u8, 216u8, 166u8, 217u8,
+                    138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 217u8, 134u8,
+                    217u8, 136u8, 216u8, 167u8, 216u8, 170u8, 32u8, 216u8, 182u8, 217u8, 136u8,
+                    216u8, 166u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    163u8, 217u8, 133u8, 216u8, 170u8, 216u8, 167u8, 216u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 163u8, 216u8, 180u8, 217u8, 135u8, 216u8, 177u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 141u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 216u8, 172u8, 216u8,
+                    178u8, 216u8, 167u8, 216u8, 161u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8,
+                    216u8, 172u8, 216u8, 178u8, 216u8, 167u8, 216u8, 161u8, 47u8, 217u8, 133u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 134u8, 217u8, 130u8, 216u8, 167u8, 216u8, 183u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 163u8, 216u8, 177u8, 216u8, 168u8, 216u8, 167u8, 216u8,
+                    185u8, 123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 216u8, 173u8, 216u8, 175u8,
+                    216u8, 167u8, 216u8, 170u8, 32u8, 216u8, 173u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 163u8, 217u8, 133u8,
+                    216u8, 177u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 163u8, 216u8, 179u8, 216u8, 167u8, 216u8, 168u8,
+                    217u8, 138u8, 216u8, 185u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 217u8,
+                    134u8, 217u8, 136u8, 216u8, 167u8, 216u8, 170u8,
+                ])
+            },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 16u8, 0u8, 22u8, 0u8, 33u8,
+                    0u8, 37u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 56u8, 0u8, 68u8, 0u8, 75u8,
+                    0u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 99u8, 101u8, 110u8, 116u8, 117u8,
+                    114u8, 121u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    105u8, 116u8, 101u8, 109u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 111u8,
+                    110u8, 116u8, 104u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 97u8, 114u8,
+                    116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8,
+                    57u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 119u8, 101u8, 101u8,
+                    107u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 32u8, 0u8, 46u8, 0u8, 60u8, 0u8,
+                    77u8, 0u8, 93u8, 0u8, 107u8, 0u8, 121u8, 0u8, 135u8, 0u8, 149u8, 0u8, 174u8,
+                    0u8, 188u8, 0u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8, 177u8, 217u8,
+                    133u8, 217u8, 138u8, 217u8, 132u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 130u8, 216u8, 177u8, 217u8, 134u8, 217u8, 139u8, 216u8,
+                    167u8, 123u8, 48u8, 125u8, 32u8, 217u8, 138u8, 217u8, 136u8, 217u8, 133u8,
+                    217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8, 217u8,
+                    130u8, 216u8, 175u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8,
+                    216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8,
+                    216u8, 179u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8, 217u8, 134u8, 216u8,
+                    181u8, 216u8, 177u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8, 139u8, 216u8, 167u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 180u8, 217u8, 135u8, 216u8, 177u8, 217u8, 139u8,
+                    216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 217u8, 132u8, 217u8, 138u8, 217u8,
+                    132u8, 216u8, 169u8, 217u8, 139u8, 123u8, 48u8, 125u8, 32u8, 216u8, 172u8,
+                    216u8, 178u8, 216u8, 161u8, 217u8, 139u8, 216u8, 167u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 172u8, 216u8, 178u8, 216u8, 161u8, 217u8, 139u8, 216u8, 167u8,
+                    47u8, 217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 217u8,
+                    139u8, 216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 216u8, 179u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 185u8, 217u8, 139u8, 216u8, 167u8,
+                ])
+            },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 180u8, 1u8, 192u8, 1u8, 196u8, 1u8, 200u8,
+                    1u8, 205u8, 1u8, 215u8, 1u8, 227u8, 1u8, 239u8, 1u8, 241u8, 1u8, 251u8, 1u8,
+                    0u8, 2u8, 6u8, 2u8, 17u8, 2u8, 37u8, 2u8, 55u8, 2u8, 66u8, 2u8, 70u8, 2u8,
+                    79u8, 2u8, 82u8, 2u8, 89u8, 2u8, 96u8, 2u8, 102u8, 2u8, 117u8, 2u8, 140u8, 2u8,
+                    147u8, 2u8, 154u8, 2u8, 162u8, 2u8, 171u8, 2u8, 179u8, 2u8, 184u8, 2u8, 188u8,
+                    2u8, 192u8, 2u8, 199u8, 2u8, 209u8, 2u8, 220u8, 2u8, 225u8, 2u8, 230u8, 2u8,
+                    240u8, 2u8, 244u8, 2u8, 248u8, 2u8, 1u8, 3u8, 5u8, 3u8, 11u8, 3u8, 16u8, 3u8,
+                    21u8, 3u8, 26u8, 3u8, 31u8, 3u8, 37u8, 3u8, 40u8, 3u8, 47u8, 3u8, 55u8, 3u8,
+                    66u8, 3u8, 74u8, 3u8, 88u8, 3u8, 97u8, 3u8, 106u8, 3u8, 115u8, 3u8, 133u8, 3u8,
+                    143u8, 3u8, 151u8, 3u8, 164u8, 3u8, 195u8, 3u8, 199u8, 3u8, 203u8, 3u8, 209u8,
+                    3u8, 220u8, 3u8, 230u8, 3u8, 235u8, 3u8, 2u8, 4u8, 21u8, 4u8, 26u8, 4u8, 29u8,
+                    4u8, 36u8, 4u8, 44u8, 4u8, 53u8, 4u8, 62u8, 4u8, 72u8, 4u8, 81u8, 4u8, 89u8,
+                    4u8, 94u8, 4u8, 110u8, 4u8, 133u8, 4u8, 142u8, 4u8, 152u8, 4u8, 163u8, 4u8,
+                    167u8, 4u8, 182u8, 4u8, 206u8, 4u8, 219u8, 4u8, 236u8, 4u8, 247u8, 4u8, 255u8,
+                    4u8, 8u8, 5u8, 41u8, 5u8, 51u8, 5u8, 61u8, 5u8, 76u8, 5u8, 95u8, 5u8, 106u8,
+                    5u8, 115u8, 5u8, 121u8, 5u8, 125u8, 5u8, 130u8, 5u8, 139u8, 5u8, 149u8, 5u8,
+                    162u8, 5u8, 168u8, 5u8, 180u8, 5u8, 185u8, 5u8, 194u8, 5u8, 198u8, 5u8, 201u8,
+                    5u8, 206u8, 5u8, 211u8, 5u8, 221u8, 5u8, 227u8, 5u8, 231u8, 5u8, 243u8, 5u8,
+                    255u8, 5u8, 5u8, 6u8, 12u8, 6u8, 20u8, 6u8, 29u8, 6u8, 37u8, 6u8, 46u8, 6u8,
+                    51u8, 6u8, 55u8, 6u8, 68u8, 6u8, 79u8, 6u8, 84u8, 6u8, 104u8, 6u8, 118u8, 6u8,
+                    123u8, 6u8, 128u8, 6u8, 139u8, 6u8, 155u8, 6u8, 182u8, 6u8, 187u8, 6u8, 201u8,
+                    6u8, 208u8, 6u8, 214u8, 6u8, 221u8, 6u8, 231u8, 6u8, 236u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 250u8, 6u8, 0u8, 7u8, 5u8, 7u8, 16u8, 7u8, 28u8, 7u8,
+                    35u8, 7u8, 42u8, 7u8, 46u8, 7u8, 62u8, 7u8, 72u8, 7u8, 84u8, 7u8, 101u8, 7u8,
+                    112u8, 7u8, 123u8, 7u8, 139u8, 7u8, 151u8, 7u8, 162u8, 7u8, 173u8, 7u8, 182u8,
+                    7u8, 187u8, 7u8, 190u8, 7u8, 200u8, 7u8, 208u8, 7u8, 215u8, 7u8, 223u8, 7u8,
+                    228u8, 7u8, 236u8, 7u8, 241u8, 7u8, 244u8, 7u8, 249u8, 7u8, 253u8, 7u8, 1u8,
+                    8u8, 6u8, 8u8, 10u8, 8u8, 14u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8, 99u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8, 101u8,
+                    114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8,
+                    101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 97u8,
+                    115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8, 108u8,
+                    45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8, 112u8,
+                    104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8, 101u8,
+                    108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8, 101u8, 99u8,
+                    113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8, 105u8, 116u8,
+                    105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8, 108u8,
+                    45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8, 45u8, 106u8,
+                    112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8, 116u8, 101u8,
+                    99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8,
+                    105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8,
+                    99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8, 105u8, 110u8, 99u8,
+                    104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8, 98u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8, 117u8, 112u8,
+                    99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8, 111u8, 110u8,
+                    100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 99u8,
+                    105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8, 99u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 100u8,
+                    101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8,
+                    111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 111u8, 116u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8,
+                    109u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8,
+                    97u8, 114u8, 116u8, 104u8, 45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8,
+                    116u8, 104u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8,
+                    101u8, 99u8, 116u8, 114u8, 111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8,
+                    109u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 97u8, 114u8, 97u8, 100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8,
+                    117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8,
+                    108u8, 111u8, 114u8, 105u8, 101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8,
+                    114u8, 116u8, 110u8, 105u8, 103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8,
+                    117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8,
+                    99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8,
+                    108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 103u8, 97u8, 115u8, 111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8,
+                    110u8, 101u8, 114u8, 103u8, 121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8,
+                    116u8, 121u8, 103u8, 101u8, 110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 103u8, 105u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8,
+                    105u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8,
+                    110u8, 103u8, 114u8, 97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8,
+                    99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8,
+                    115u8, 99u8, 97u8, 108u8, 104u8, 101u8, 110u8, 114u8, 121u8, 104u8, 101u8,
+                    114u8, 116u8, 122u8, 104u8, 111u8, 114u8, 115u8, 101u8, 112u8, 111u8, 119u8,
+                    101u8, 114u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 105u8,
+                    110u8, 99u8, 104u8, 45u8, 111u8, 102u8, 104u8, 103u8, 105u8, 116u8, 101u8,
+                    109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8, 111u8, 45u8, 106u8,
+                    112u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 97u8, 114u8, 97u8, 116u8,
+                    107u8, 97u8, 116u8, 97u8, 108u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8,
+                    107u8, 101u8, 110u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8,
+                    99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 107u8, 105u8, 108u8, 111u8,
+                    104u8, 101u8, 114u8, 116u8, 122u8, 107u8, 105u8, 108u8, 111u8, 106u8, 111u8,
+                    117u8, 108u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 107u8, 105u8, 108u8, 111u8,
+                    119u8, 97u8, 116u8, 116u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8,
+                    116u8, 45u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 119u8,
+                    97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 107u8, 110u8, 111u8, 116u8, 107u8, 111u8, 107u8, 117u8,
+                    107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 108u8, 105u8, 103u8, 104u8, 116u8,
+                    45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8, 105u8, 103u8, 104u8, 116u8,
+                    45u8, 121u8, 101u8, 97u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8,
+                    45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8, 117u8, 109u8, 101u8,
+                    110u8, 108u8, 117u8, 120u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 103u8,
+                    97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8, 101u8, 103u8, 97u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 112u8, 97u8, 115u8,
+                    99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8, 99u8, 114u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 99u8, 114u8, 111u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8,
+                    108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8, 103u8, 108u8, 117u8,
+                    99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 100u8, 101u8, 99u8,
+                    105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8, 103u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 108u8, 105u8, 119u8,
+                    97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8,
+                    108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 97u8, 110u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8, 116u8, 105u8, 99u8, 97u8, 108u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    110u8, 101u8, 119u8, 116u8, 111u8, 110u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 110u8, 105u8, 103u8, 104u8, 116u8, 111u8, 102u8, 103u8, 108u8, 117u8,
+                    99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8, 103u8, 111u8, 104u8, 109u8,
+                    111u8, 115u8, 97u8, 106u8, 105u8, 111u8, 117u8, 110u8, 99u8, 101u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 45u8, 116u8, 114u8, 111u8, 121u8, 112u8, 97u8,
+                    114u8, 115u8, 101u8, 99u8, 112u8, 97u8, 114u8, 116u8, 112u8, 97u8, 114u8,
+                    116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8, 54u8, 112u8, 97u8, 114u8,
+                    116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8, 57u8, 112u8, 97u8, 115u8,
+                    99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 112u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8, 112u8, 101u8, 114u8, 109u8,
+                    121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8, 110u8, 116u8, 112u8, 105u8,
+                    110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8,
+                    112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8, 120u8, 101u8, 108u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8, 108u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8, 111u8, 105u8, 110u8, 116u8,
+                    112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8,
+                    102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8,
+                    102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8, 116u8, 113u8, 117u8,
+                    97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8, 97u8, 100u8,
+                    105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8, 110u8, 101u8, 114u8,
+                    101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 114u8, 105u8,
+                    45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8, 100u8, 115u8, 97u8,
+                    105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8, 107u8, 117u8, 45u8,
+                    108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8, 101u8, 109u8, 101u8,
+                    110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8, 116u8, 115u8, 108u8,
+                    117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 108u8, 117u8, 109u8,
+                    105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8, 111u8, 108u8, 97u8,
+                    114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8,
+                    116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8, 116u8, 101u8, 114u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8, 110u8, 101u8, 115u8,
+                    117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8, 112u8, 116u8, 111u8, 110u8,
+                    116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8, 108u8, 116u8, 119u8, 97u8,
+                    116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8, 119u8, 101u8, 101u8, 107u8,
+                    121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 31u8, 0u8, 45u8, 0u8, 70u8, 0u8,
+                    95u8, 0u8, 105u8, 0u8, 114u8, 0u8, 124u8, 0u8, 138u8, 0u8, 154u8, 0u8, 170u8,
+                    0u8, 178u8, 0u8, 220u8, 0u8, 232u8, 0u8, 243u8, 0u8, 255u8, 0u8, 11u8, 1u8,
+                    19u8, 1u8, 30u8, 1u8, 42u8, 1u8, 56u8, 1u8, 63u8, 1u8, 81u8, 1u8, 89u8, 1u8,
+                    99u8, 1u8, 110u8, 1u8, 122u8, 1u8, 132u8, 1u8, 142u8, 1u8, 154u8, 1u8, 177u8,
+                    1u8, 187u8, 1u8, 195u8, 1u8, 207u8, 1u8, 223u8, 1u8, 233u8, 1u8, 247u8, 1u8,
+                    3u8, 2u8, 22u8, 2u8, 38u8, 2u8, 48u8, 2u8, 58u8, 2u8, 76u8, 2u8, 86u8, 2u8,
+                    98u8, 2u8, 116u8, 2u8, 153u8, 2u8, 170u8, 2u8, 191u8, 2u8, 212u8, 2u8, 224u8,
+                    2u8, 236u8, 2u8, 3u8, 3u8, 20u8, 3u8, 47u8, 3u8, 55u8, 3u8, 62u8, 3u8, 68u8,
+                    3u8, 80u8, 3u8, 105u8, 3u8, 151u8, 3u8, 179u8, 3u8, 187u8, 3u8, 197u8, 3u8,
+                    205u8, 3u8, 217u8, 3u8, 231u8, 3u8, 252u8, 3u8, 10u8, 4u8, 43u8, 4u8, 52u8,
+                    4u8, 57u8, 4u8, 73u8, 4u8, 82u8, 4u8, 97u8, 4u8, 110u8, 4u8, 122u8, 4u8, 134u8,
+                    4u8, 146u8, 4u8, 160u8, 4u8, 178u8, 4u8, 202u8, 4u8, 208u8, 4u8, 220u8, 4u8,
+                    232u8, 4u8, 238u8, 4u8, 250u8, 4u8, 14u8, 5u8, 26u8, 5u8, 36u8, 5u8, 47u8, 5u8,
+                    57u8, 5u8, 71u8, 5u8, 81u8, 5u8, 96u8, 5u8, 108u8, 5u8, 124u8, 5u8, 144u8, 5u8,
+                    159u8, 5u8, 169u8, 5u8, 180u8, 5u8, 195u8, 5u8, 208u8, 5u8, 216u8, 5u8, 227u8,
+                    5u8, 246u8, 5u8, 8u8, 6u8, 20u8, 6u8, 48u8, 6u8, 60u8, 6u8, 73u8, 6u8, 88u8,
+                    6u8, 100u8, 6u8, 121u8, 6u8, 131u8, 6u8, 153u8, 6u8, 168u8, 6u8, 180u8, 6u8,
+                    190u8, 6u8, 206u8, 6u8, 215u8, 6u8, 230u8, 6u8, 248u8, 6u8, 11u8, 7u8, 26u8,
+                    7u8, 39u8, 7u8, 49u8, 7u8, 58u8, 7u8, 69u8, 7u8, 81u8, 7u8, 101u8, 7u8, 111u8,
+                    7u8, 121u8, 7u8, 142u8, 7u8, 175u8, 7u8, 188u8, 7u8, 217u8, 7u8, 234u8, 7u8,
+                    248u8, 7u8, 2u8, 8u8, 23u8, 8u8, 35u8, 8u8, 43u8, 8u8, 64u8, 8u8, 80u8, 8u8,
+                    93u8, 8u8, 110u8, 8u8, 116u8, 8u8, 126u8, 8u8, 136u8, 8u8, 155u8, 8u8, 165u8,
+                    8u8, 184u8, 8u8, 198u8, 8u8, 219u8, 8u8, 231u8, 8u8, 245u8, 8u8, 3u8, 9u8,
+                    13u8, 9u8, 27u8, 9u8, 41u8, 9u8, 70u8, 9u8, 91u8, 9u8, 101u8, 9u8, 122u8, 9u8,
+                    143u8, 9u8, 159u8, 9u8, 164u8, 9u8, 169u8, 9u8, 175u8, 9u8, 195u8, 9u8, 213u8,
+                    9u8, 225u8, 9u8, 239u8, 9u8, 252u8, 9u8, 19u8, 10u8, 31u8, 10u8, 48u8, 10u8,
+                    69u8, 10u8, 81u8, 10u8, 91u8, 10u8, 112u8, 10u8, 133u8, 10u8, 154u8, 10u8,
+                    175u8, 10u8, 215u8, 10u8, 225u8, 10u8, 241u8, 10u8, 250u8, 10u8, 6u8, 11u8,
+                    17u8, 11u8, 29u8, 11u8, 38u8, 11u8, 50u8, 11u8, 61u8, 11u8, 67u8, 11u8, 86u8,
+                    11u8, 107u8, 11u8, 121u8, 11u8, 127u8, 11u8, 137u8, 11u8, 147u8, 11u8, 168u8,
+                    11u8, 191u8, 11u8, 208u8, 11u8, 218u8, 11u8, 230u8, 11u8, 244u8, 11u8, 254u8,
+                    11u8, 6u8, 12u8, 18u8, 12u8, 34u8, 12u8, 54u8, 12u8, 66u8, 12u8, 78u8, 12u8,
+                    96u8, 12u8, 113u8, 12u8, 129u8, 12u8, 149u8, 12u8, 161u8, 12u8, 201u8, 12u8,
+                    212u8, 12u8, 220u8, 12u8, 229u8, 12u8, 241u8, 12u8, 251u8, 12u8, 7u8, 13u8,
+                    21u8, 13u8, 35u8, 13u8, 123u8, 48u8, 125u8, 32u8, 217u8, 129u8, 216u8, 175u8,
+                    216u8, 167u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 217u8, 129u8, 216u8,
+                    175u8, 216u8, 167u8, 217u8, 134u8, 32u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8, 133u8, 216u8, 168u8,
+                    217u8, 138u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 217u8,
+                    130u8, 217u8, 138u8, 217u8, 130u8, 216u8, 169u8, 32u8, 217u8, 130u8, 217u8,
+                    136u8, 216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8,
+                    216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    217u8, 130u8, 217u8, 136u8, 216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 136u8, 46u8, 217u8, 129u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 182u8, 46u8, 216u8, 172u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8,
+                    216u8, 167u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8,
+                    177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 216u8, 168u8, 217u8, 136u8,
+                    217u8, 129u8, 217u8, 136u8, 216u8, 177u8, 216u8, 170u8, 32u8, 123u8, 48u8,
+                    125u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 217u8, 138u8, 217u8, 131u8,
+                    216u8, 177u8, 217u8, 138u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    168u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 216u8, 173u8,
+                    216u8, 175u8, 216u8, 169u8, 32u8, 216u8, 173u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 168u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 183u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8,
+                    169u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 217u8, 136u8, 216u8, 180u8, 217u8,
+                    132u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8, 167u8, 217u8, 138u8,
+                    216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 216u8, 185u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 179u8, 216u8, 185u8, 45u8, 105u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 180u8, 217u8, 133u8, 216u8, 185u8, 216u8, 169u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 130u8, 217u8, 138u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 183u8, 123u8, 48u8, 125u8, 194u8, 176u8, 217u8, 133u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 179u8, 217u8, 134u8, 216u8, 170u8, 217u8, 138u8, 217u8,
+                    132u8, 216u8, 170u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8,
+                    217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 177u8, 217u8,
+                    134u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 136u8, 217u8, 132u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 217u8, 133u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 175u8, 217u8, 133u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8,
+                    169u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 216u8,
+                    169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 133u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 194u8, 179u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8, 169u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 136u8, 216u8,
+                    168u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 136u8, 216u8,
+                    168u8, 32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8, 138u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 175u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    217u8, 136u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 217u8, 138u8, 217u8,
+                    136u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8, 217u8, 130u8,
+                    216u8, 175u8, 123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 217u8, 138u8, 216u8,
+                    179u8, 217u8, 138u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 175u8, 216u8, 179u8, 217u8, 133u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 175u8, 216u8, 177u8, 216u8, 172u8, 216u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 133u8, 217u8, 132u8, 216u8, 185u8, 217u8, 130u8, 216u8,
+                    169u8, 32u8, 216u8, 173u8, 46u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8,
+                    132u8, 216u8, 185u8, 217u8, 130u8, 216u8, 169u8, 32u8, 216u8, 173u8, 46u8,
+                    32u8, 216u8, 165u8, 217u8, 133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 183u8, 217u8, 136u8, 216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 134u8, 217u8, 130u8, 216u8, 183u8, 216u8, 169u8, 47u8, 216u8,
+                    179u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8, 217u8, 130u8,
+                    216u8, 183u8, 216u8, 169u8, 47u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8,
+                    216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 216u8, 177u8, 217u8,
+                    135u8, 217u8, 133u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8, 166u8, 217u8,
+                    132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 183u8, 216u8, 177u8,
+                    216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 175u8, 217u8, 136u8, 217u8,
+                    134u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 216u8, 170u8,
+                    217u8, 132u8, 216u8, 169u8, 32u8, 216u8, 163u8, 216u8, 177u8, 216u8, 182u8,
+                    217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8, 217u8,
+                    130u8, 32u8, 216u8, 163u8, 216u8, 177u8, 216u8, 182u8, 217u8, 138u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 165u8, 217u8, 132u8, 217u8, 131u8, 216u8, 170u8,
+                    216u8, 177u8, 217u8, 136u8, 217u8, 134u8, 32u8, 217u8, 129u8, 217u8, 136u8,
+                    217u8, 132u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 216u8, 165u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 194u8, 176u8, 217u8, 129u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 129u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 167u8,
+                    217u8, 133u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8,
+                    136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8, 179u8, 216u8,
+                    167u8, 216u8, 166u8, 217u8, 132u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8,
+                    216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8,
+                    216u8, 179u8, 216u8, 167u8, 216u8, 166u8, 217u8, 132u8, 216u8, 169u8, 32u8,
+                    216u8, 165u8, 217u8, 133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    183u8, 217u8, 136u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8,
+                    169u8, 32u8, 216u8, 179u8, 32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 216u8,
+                    185u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 175u8, 217u8, 133u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 129u8, 216u8, 177u8, 217u8, 132u8, 217u8, 134u8, 216u8, 186u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 217u8, 136u8, 216u8, 169u8, 32u8,
+                    216u8, 170u8, 216u8, 179u8, 216u8, 167u8, 216u8, 177u8, 216u8, 185u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8,
+                    217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 216u8, 186u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 136u8, 217u8, 134u8, 32u8, 216u8, 165u8, 217u8, 133u8, 216u8,
+                    168u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8, 217u8, 136u8, 216u8, 177u8,
+                    217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8,
+                    48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 216u8, 186u8, 217u8,
+                    138u8, 216u8, 186u8, 216u8, 167u8, 216u8, 168u8, 216u8, 170u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 186u8, 46u8, 216u8, 168u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    186u8, 32u8, 217u8, 135u8, 216u8, 177u8, 216u8, 170u8, 216u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 186u8, 32u8, 217u8, 136u8, 216u8, 167u8, 216u8,
+                    183u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 217u8, 133u8, 216u8, 173u8,
+                    216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 186u8, 216u8, 177u8, 216u8,
+                    167u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 172u8, 216u8, 177u8,
+                    216u8, 167u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 217u8, 135u8, 217u8,
+                    131u8, 216u8, 170u8, 216u8, 167u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 135u8, 217u8, 131u8, 216u8, 170u8, 217u8, 136u8, 217u8, 132u8, 216u8,
+                    170u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 135u8, 217u8, 131u8,
+                    216u8, 170u8, 217u8, 136u8, 216u8, 168u8, 216u8, 167u8, 216u8, 179u8, 217u8,
+                    131u8, 216u8, 167u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 135u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 135u8, 216u8, 177u8, 216u8, 170u8, 216u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 216u8, 173u8, 216u8, 181u8, 216u8, 167u8,
+                    217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 168u8, 46u8, 32u8, 216u8, 178u8, 216u8, 166u8, 216u8, 168u8,
+                    217u8, 130u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    185u8, 217u8, 134u8, 216u8, 181u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 130u8, 216u8, 175u8, 216u8, 173u8, 123u8, 48u8, 125u8, 32u8, 106u8,
+                    111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 216u8, 172u8,
+                    217u8, 136u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 217u8,
+                    138u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 131u8, 216u8, 167u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    175u8, 32u8, 217u8, 131u8, 217u8, 132u8, 217u8, 129u8, 217u8, 134u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8,
+                    216u8, 168u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 168u8, 216u8, 167u8, 217u8, 138u8,
+                    216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 32u8, 216u8, 179u8,
+                    216u8, 185u8, 216u8, 177u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 216u8, 186u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8,
+                    216u8, 186u8, 32u8, 216u8, 171u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 32u8,
+                    217u8, 135u8, 216u8, 177u8, 216u8, 170u8, 216u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 131u8, 32u8, 216u8, 172u8, 217u8, 136u8, 217u8, 132u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 217u8, 133u8, 47u8, 216u8, 179u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 46u8, 216u8, 168u8, 216u8, 167u8, 216u8, 179u8, 217u8, 131u8, 216u8,
+                    167u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 131u8, 217u8, 138u8,
+                    217u8, 132u8, 217u8, 136u8, 217u8, 136u8, 216u8, 167u8, 216u8, 183u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 131u8, 46u8, 217u8, 136u8, 46u8, 216u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 131u8, 46u8, 217u8, 136u8, 46u8, 216u8, 179u8, 32u8,
+                    217u8, 132u8, 217u8, 131u8, 217u8, 132u8, 32u8, 49u8, 48u8, 48u8, 32u8, 217u8,
+                    131u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 185u8, 217u8, 130u8,
+                    216u8, 175u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 107u8,
+                    117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 182u8, 217u8, 136u8, 216u8, 166u8, 217u8, 138u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8, 32u8, 216u8, 182u8,
+                    217u8, 136u8, 216u8, 166u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 47u8, 217u8, 161u8, 217u8, 160u8,
+                    217u8, 160u8, 32u8, 217u8, 131u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 47u8, 217u8, 131u8, 217u8, 133u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 217u8,
+                    134u8, 123u8, 48u8, 125u8, 32u8, 217u8, 132u8, 217u8, 131u8, 216u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 138u8, 216u8, 186u8, 216u8,
+                    167u8, 216u8, 168u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    46u8, 216u8, 168u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 32u8, 217u8, 135u8,
+                    216u8, 177u8, 216u8, 170u8, 216u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    133u8, 217u8, 138u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 46u8, 216u8, 168u8,
+                    216u8, 167u8, 216u8, 179u8, 217u8, 131u8, 216u8, 167u8, 217u8, 132u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 133u8, 46u8, 216u8, 168u8, 217u8, 131u8, 216u8,
+                    179u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 32u8, 217u8,
+                    136u8, 216u8, 167u8, 216u8, 183u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 47u8,
+                    216u8, 171u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 47u8, 216u8, 171u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8,
+                    186u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 138u8,
+                    217u8, 131u8, 216u8, 177u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 46u8, 216u8, 171u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 47u8, 216u8, 186u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 47u8, 216u8, 186u8, 46u8, 32u8,
+                    216u8, 165u8, 217u8, 133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    183u8, 217u8, 136u8, 216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 47u8, 216u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 32u8, 216u8, 167u8,
+                    216u8, 179u8, 217u8, 131u8, 217u8, 134u8, 216u8, 175u8, 217u8, 134u8, 216u8,
+                    167u8, 217u8, 129u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    32u8, 216u8, 163u8, 217u8, 133u8, 216u8, 168u8, 217u8, 138u8, 216u8, 177u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 46u8, 32u8, 216u8, 168u8, 216u8, 167u8,
+                    216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 216u8, 186u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 216u8, 186u8, 217u8, 133u8,
+                    47u8, 216u8, 175u8, 217u8, 138u8, 216u8, 179u8, 217u8, 138u8, 217u8, 132u8,
+                    123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 132u8, 216u8, 170u8, 216u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 133u8, 123u8, 48u8,
+                    125u8, 32u8, 217u8, 133u8, 217u8, 132u8, 217u8, 133u8, 32u8, 216u8, 178u8,
+                    216u8, 166u8, 216u8, 168u8, 217u8, 130u8, 217u8, 138u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 133u8, 46u8, 217u8, 133u8, 217u8, 136u8, 217u8, 132u8, 47u8,
+                    217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 132u8, 217u8,
+                    138u8, 32u8, 216u8, 171u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8,
+                    132u8, 217u8, 138u8, 32u8, 217u8, 136u8, 216u8, 167u8, 216u8, 183u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 175u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8,
+                    136u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 216u8, 180u8, 217u8, 135u8,
+                    216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8, 216u8, 167u8, 217u8,
+                    134u8, 217u8, 136u8, 32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 123u8,
+                    48u8, 125u8, 32u8, 217u8, 134u8, 46u8, 216u8, 171u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 32u8, 216u8, 168u8, 216u8,
+                    173u8, 216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8,
+                    217u8, 138u8, 217u8, 136u8, 216u8, 170u8, 217u8, 134u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 134u8, 217u8, 138u8, 217u8, 136u8, 216u8, 170u8, 217u8, 134u8,
+                    32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 132u8, 217u8, 138u8, 217u8, 132u8, 216u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 179u8, 217u8, 131u8, 217u8, 145u8, 216u8, 177u8, 217u8, 138u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 178u8, 216u8, 166u8, 216u8, 168u8, 217u8,
+                    130u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8, 136u8,
+                    217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 217u8, 136u8,
+                    217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8,
+                    170u8, 216u8, 177u8, 217u8, 136u8, 217u8, 138u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 129u8, 216u8, 177u8, 216u8,
+                    179u8, 216u8, 174u8, 32u8, 217u8, 129u8, 217u8, 132u8, 217u8, 131u8, 217u8,
+                    138u8, 123u8, 48u8, 125u8, 32u8, 216u8, 172u8, 216u8, 178u8, 216u8, 161u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 172u8, 216u8, 178u8, 216u8, 161u8, 47u8,
+                    217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 134u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 172u8, 216u8, 178u8, 216u8, 161u8, 47u8, 217u8,
+                    133u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 168u8, 216u8, 167u8, 216u8, 179u8, 217u8, 131u8, 216u8,
+                    167u8, 217u8, 132u8, 123u8, 48u8, 125u8, 217u8, 170u8, 123u8, 48u8, 125u8,
+                    216u8, 137u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 168u8, 217u8, 138u8, 216u8, 170u8, 216u8, 167u8, 216u8, 168u8,
+                    216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    168u8, 217u8, 138u8, 217u8, 131u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 216u8, 177u8, 216u8, 180u8, 217u8,
+                    145u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8, 167u8,
+                    217u8, 138u8, 217u8, 134u8, 216u8, 170u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 133u8, 217u8, 131u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 32u8,
+                    217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 168u8, 217u8, 131u8, 216u8, 179u8, 217u8, 132u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 168u8, 217u8, 131u8, 216u8, 179u8, 217u8, 132u8, 47u8,
+                    216u8, 179u8, 217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 217u8,
+                    131u8, 216u8, 179u8, 217u8, 132u8, 47u8, 216u8, 168u8, 217u8, 136u8, 216u8,
+                    181u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 217u8, 134u8, 217u8, 130u8,
+                    216u8, 183u8, 216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 177u8, 216u8,
+                    183u8, 217u8, 132u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8, 167u8,
+                    217u8, 136u8, 217u8, 134u8, 216u8, 175u8, 32u8, 217u8, 130u8, 217u8, 136u8,
+                    216u8, 169u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 216u8, 167u8, 217u8,
+                    136u8, 217u8, 134u8, 216u8, 175u8, 32u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 32u8, 216u8, 177u8, 216u8, 183u8, 217u8, 132u8,
+                    47u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8, 169u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 32u8,
+                    216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 32u8, 216u8,
+                    186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 32u8, 216u8,
+                    165u8, 217u8, 133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8,
+                    217u8, 136u8, 216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    177u8, 216u8, 168u8, 216u8, 185u8, 123u8, 48u8, 125u8, 32u8, 216u8, 177u8,
+                    216u8, 167u8, 216u8, 175u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8,
+                    216u8, 175u8, 217u8, 136u8, 216u8, 177u8, 216u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8,
+                    105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 216u8, 171u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8, 111u8,
+                    108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8, 32u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 216u8, 178u8, 217u8, 138u8, 217u8, 129u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 182u8, 217u8, 138u8, 216u8, 167u8, 216u8, 161u8, 32u8, 216u8,
+                    180u8, 217u8, 133u8, 216u8, 179u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 131u8, 216u8, 170u8, 217u8, 132u8, 216u8, 169u8, 32u8, 216u8, 180u8,
+                    217u8, 133u8, 216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 217u8, 134u8, 217u8, 130u8, 32u8, 216u8, 180u8, 217u8, 133u8, 216u8,
+                    179u8, 217u8, 138u8, 123u8, 48u8, 125u8, 32u8, 216u8, 179u8, 217u8, 133u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    133u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 216u8, 168u8, 217u8, 136u8,
+                    216u8, 181u8, 216u8, 169u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    131u8, 217u8, 133u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 138u8, 217u8,
+                    132u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 217u8, 138u8, 216u8, 167u8,
+                    216u8, 177u8, 216u8, 175u8, 216u8, 169u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 179u8, 216u8, 170u8, 216u8, 177u8, 216u8, 167u8, 216u8, 175u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 216u8,
+                    179u8, 216u8, 170u8, 217u8, 136u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    217u8, 133u8, 217u8, 132u8, 216u8, 185u8, 217u8, 130u8, 216u8, 169u8, 32u8,
+                    217u8, 131u8, 46u8, 123u8, 48u8, 125u8, 32u8, 217u8, 133u8, 217u8, 132u8,
+                    216u8, 185u8, 217u8, 130u8, 216u8, 169u8, 32u8, 216u8, 181u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 170u8, 217u8, 138u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    168u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 216u8, 170u8, 217u8, 138u8,
+                    216u8, 177u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 217u8, 138u8, 216u8,
+                    170u8, 123u8, 48u8, 125u8, 32u8, 216u8, 170u8, 216u8, 179u8, 217u8, 132u8,
+                    216u8, 167u8, 123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 216u8, 173u8, 216u8,
+                    175u8, 216u8, 169u8, 32u8, 216u8, 173u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 163u8, 217u8, 133u8, 216u8,
+                    177u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 216u8, 183u8, 217u8, 134u8, 123u8, 48u8, 125u8, 32u8, 216u8, 183u8, 46u8,
+                    217u8, 133u8, 123u8, 48u8, 125u8, 32u8, 217u8, 129u8, 217u8, 136u8, 217u8,
+                    132u8, 216u8, 170u8, 123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 216u8, 167u8,
+                    216u8, 183u8, 123u8, 48u8, 125u8, 32u8, 217u8, 136u8, 217u8, 138u8, 216u8,
+                    168u8, 216u8, 177u8, 123u8, 48u8, 125u8, 32u8, 216u8, 163u8, 216u8, 179u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 185u8, 123u8, 48u8, 125u8, 32u8, 217u8,
+                    138u8, 216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 18u8, 0u8, 26u8, 0u8, 32u8, 0u8, 42u8,
+                    0u8, 56u8, 0u8, 89u8, 0u8, 101u8, 0u8, 107u8, 0u8, 119u8, 0u8, 129u8, 0u8,
+                    137u8, 0u8, 143u8, 0u8, 149u8, 0u8, 155u8, 0u8, 161u8, 0u8, 167u8, 0u8, 181u8,
+                    0u8, 191u8, 0u8, 210u8, 0u8, 216u8, 0u8, 226u8, 0u8, 240u8, 0u8, 250u8, 0u8,
+                    2u8, 1u8, 14u8, 1u8, 20u8, 1u8, 123u8, 48u8, 125u8, 47u8, 216u8, 179u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 47u8, 216u8, 179u8, 217u8, 133u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 47u8, 217u8, 133u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8,
+                    217u8, 138u8, 123u8, 48u8, 125u8, 47u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    133u8, 123u8, 48u8, 125u8, 47u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 136u8, 217u8, 134u8, 123u8, 48u8, 125u8, 47u8, 216u8, 186u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 32u8, 216u8, 165u8, 217u8,
+                    133u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8, 217u8, 136u8,
+                    216u8, 177u8, 217u8, 138u8, 123u8, 48u8, 125u8, 47u8, 216u8, 186u8, 216u8,
+                    177u8, 216u8, 167u8, 217u8, 133u8, 123u8, 48u8, 125u8, 47u8, 216u8, 179u8,
+                    123u8, 48u8, 125u8, 47u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8,
+                    169u8, 123u8, 48u8, 125u8, 47u8, 217u8, 131u8, 216u8, 186u8, 217u8, 133u8,
+                    123u8, 48u8, 125u8, 47u8, 217u8, 131u8, 217u8, 133u8, 123u8, 48u8, 125u8, 47u8,
+                    217u8, 132u8, 123u8, 48u8, 125u8, 47u8, 217u8, 133u8, 123u8, 48u8, 125u8, 47u8,
+                    216u8, 175u8, 123u8, 48u8, 125u8, 47u8, 216u8, 180u8, 123u8, 48u8, 125u8, 47u8,
+                    217u8, 132u8, 123u8, 48u8, 125u8, 47u8, 216u8, 163u8, 217u8, 136u8, 217u8,
+                    134u8, 216u8, 181u8, 216u8, 169u8, 123u8, 48u8, 125u8, 47u8, 216u8, 177u8,
+                    216u8, 183u8, 217u8, 132u8, 123u8, 48u8, 125u8, 47u8, 216u8, 177u8, 216u8,
+                    168u8, 216u8, 185u8, 32u8, 216u8, 179u8, 217u8, 134u8, 217u8, 136u8, 217u8,
+                    138u8, 123u8, 48u8, 125u8, 47u8, 216u8, 171u8, 123u8, 48u8, 125u8, 47u8, 216u8,
+                    179u8, 217u8, 133u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 216u8, 168u8,
+                    217u8, 136u8, 216u8, 181u8, 216u8, 169u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    47u8, 217u8, 131u8, 217u8, 133u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8,
+                    217u8, 133u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 217u8, 133u8, 217u8,
+                    138u8, 217u8, 132u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 216u8, 163u8,
+                    123u8, 48u8, 125u8, 47u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static BN: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 27u8, 0u8, 32u8, 0u8, 100u8,
+                    117u8, 110u8, 97u8, 109u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    104u8, 111u8, 117u8, 114u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 43u8, 0u8, 50u8, 0u8, 72u8, 0u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 166u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 184u8, 123u8, 48u8, 125u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 112u8, 104u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 22u8, 0u8, 27u8, 0u8, 52u8, 0u8,
+                    77u8, 0u8, 83u8, 0u8, 90u8, 0u8, 103u8, 0u8, 110u8, 0u8, 115u8, 0u8, 123u8,
+                    0u8, 136u8, 0u8, 143u8, 0u8, 155u8, 0u8, 166u8, 0u8, 172u8, 0u8, 188u8, 0u8,
+                    195u8, 0u8, 205u8, 0u8, 211u8, 0u8, 217u8, 0u8, 223u8, 0u8, 229u8, 0u8, 245u8,
+                    0u8, 2u8, 1u8, 13u8, 1u8, 25u8, 1u8, 32u8, 1u8, 40u8, 1u8, 48u8, 1u8, 56u8,
+                    1u8, 106u8, 1u8, 113u8, 1u8, 121u8, 1u8, 129u8, 1u8, 134u8, 1u8, 148u8, 1u8,
+                    160u8, 1u8, 166u8, 1u8, 172u8, 1u8, 185u8, 1u8, 198u8, 1u8, 204u8, 1u8, 220u8,
+                    1u8, 241u8, 1u8, 251u8, 1u8, 9u8, 2u8, 19u8, 2u8, 27u8, 2u8, 34u8, 2u8, 45u8,
+                    2u8, 64u8, 2u8, 83u8, 2u8, 91u8, 2u8, 99u8, 2u8, 105u8, 2u8, 111u8, 2u8, 117u8,
+                    2u8, 124u8, 2u8, 131u8, 2u8, 140u8, 2u8, 154u8, 2u8, 168u8, 2u8, 175u8, 2u8,
+                    188u8, 2u8, 196u8, 2u8, 208u8, 2u8, 230u8, 2u8, 235u8, 2u8, 242u8, 2u8, 254u8,
+                    2u8, 7u8, 3u8, 12u8, 3u8, 18u8, 3u8, 24u8, 3u8, 31u8, 3u8, 37u8, 3u8, 46u8,
+                    3u8, 65u8, 3u8, 73u8, 3u8, 95u8, 3u8, 101u8, 3u8, 108u8, 3u8, 115u8, 3u8,
+                    121u8, 3u8, 127u8, 3u8, 146u8, 3u8, 165u8, 3u8, 173u8, 3u8, 192u8, 3u8, 211u8,
+                    3u8, 222u8, 3u8, 227u8, 3u8, 233u8, 3u8, 242u8, 3u8, 247u8, 3u8, 3u8, 4u8, 9u8,
+                    4u8, 15u8, 4u8, 23u8, 4u8, 39u8, 4u8, 48u8, 4u8, 55u8, 4u8, 61u8, 4u8, 77u8,
+                    4u8, 85u8, 4u8, 92u8, 4u8, 98u8, 4u8, 105u8, 4u8, 118u8, 4u8, 124u8, 4u8,
+                    137u8, 4u8, 152u8, 4u8, 168u8, 4u8, 196u8, 4u8, 215u8, 4u8, 241u8, 4u8, 249u8,
+                    4u8, 255u8, 4u8, 5u8, 5u8, 11u8, 5u8, 17u8, 5u8, 24u8, 5u8, 30u8, 5u8, 37u8,
+                    5u8, 43u8, 5u8, 49u8, 5u8, 59u8, 5u8, 66u8, 5u8, 75u8, 5u8, 82u8, 5u8, 89u8,
+                    5u8, 96u8, 5u8, 112u8, 5u8, 119u8, 5u8, 131u8, 5u8, 138u8, 5u8, 145u8, 5u8,
+                    151u8, 5u8, 159u8, 5u8, 165u8, 5u8, 174u8, 5u8, 180u8, 5u8, 196u8, 5u8, 205u8,
+                    5u8, 215u8, 5u8, 221u8, 5u8, 227u8, 5u8, 246u8, 5u8, 3u8, 6u8, 16u8, 6u8, 22u8,
+                    6u8, 28u8, 6u8, 35u8, 6u8, 40u8, 6u8, 49u8, 6u8, 71u8, 6u8, 78u8, 6u8, 86u8,
+                    6u8, 92u8, 6u8, 106u8, 6u8, 125u8, 6u8, 133u8, 6u8, 139u8, 6u8, 158u8, 6u8,
+                    165u8, 6u8, 172u8, 6u8, 178u8, 6u8, 182u8, 6u8, 188u8, 6u8, 194u8, 6u8, 200u8,
+                    6u8, 216u8, 6u8, 235u8, 6u8, 241u8, 6u8, 254u8, 6u8, 5u8, 7u8, 11u8, 7u8, 19u8,
+                    7u8, 26u8, 7u8, 32u8, 7u8, 38u8, 7u8, 45u8, 7u8, 57u8, 7u8, 64u8, 7u8, 70u8,
+                    7u8, 81u8, 7u8, 112u8, 7u8, 119u8, 7u8, 128u8, 7u8, 150u8, 7u8, 161u8, 7u8,
+                    173u8, 7u8, 182u8, 7u8, 194u8, 7u8, 205u8, 7u8, 230u8, 7u8, 249u8, 7u8, 14u8,
+                    8u8, 28u8, 8u8, 35u8, 8u8, 43u8, 8u8, 53u8, 8u8, 61u8, 8u8, 69u8, 8u8, 77u8,
+                    8u8, 85u8, 8u8, 111u8, 8u8, 119u8, 8u8, 127u8, 8u8, 134u8, 8u8, 163u8, 8u8,
+                    171u8, 8u8, 179u8, 8u8, 185u8, 8u8, 197u8, 8u8, 205u8, 8u8, 212u8, 8u8, 218u8,
+                    8u8, 224u8, 8u8, 231u8, 8u8, 253u8, 8u8, 8u8, 9u8, 14u8, 9u8, 19u8, 9u8, 24u8,
+                    9u8, 29u8, 9u8, 37u8, 9u8, 59u8, 9u8, 69u8, 9u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 143u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 65u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 149u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 168u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 149u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 149u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    117u8, 123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 123u8, 48u8, 125u8,
+                    32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8,
+                    85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 159u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 67u8, 68u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 76u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 182u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 149u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 137u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    121u8, 100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    168u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 166u8, 224u8, 166u8, 182u8,
+                    224u8, 166u8, 149u8, 123u8, 48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 161u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 123u8, 48u8, 125u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    128u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 32u8, 73u8,
+                    109u8, 112u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    159u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8,
+                    32u8, 102u8, 108u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 171u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 129u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 166u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8,
+                    149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8,
+                    109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 67u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 171u8, 224u8, 167u8, 129u8, 224u8, 166u8, 159u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8,
+                    171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 178u8, 224u8, 166u8, 130u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8,
+                    32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 114u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8,
+                    151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 174u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 121u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 166u8, 185u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 176u8, 123u8,
+                    48u8, 125u8, 32u8, 104u8, 76u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8,
+                    122u8, 123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 152u8, 224u8, 166u8, 163u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 190u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8,
+                    123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 134u8, 224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 174u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 156u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 156u8, 224u8,
+                    166u8, 191u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8, 102u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    74u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 149u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 149u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 178u8, 224u8, 166u8, 191u8, 47u8, 49u8, 48u8, 48u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8,
+                    125u8, 32u8, 76u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8,
+                    122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 76u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    188u8, 115u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 76u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 109u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8,
+                    111u8, 108u8, 47u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 184u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8,
+                    133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 123u8, 48u8, 125u8, 32u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 137u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 184u8, 123u8, 48u8,
+                    125u8, 32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 159u8, 224u8, 167u8, 135u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8,
+                    102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8,
+                    102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 164u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 136u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8,
+                    152u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 163u8, 224u8, 166u8, 168u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 110u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 114u8,
+                    111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 161u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8,
+                    108u8, 111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 83u8, 118u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8,
+                    117u8, 103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8,
+                    152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 171u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 159u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 98u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 116u8, 115u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8,
+                    66u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8,
+                    85u8, 83u8, 32u8, 224u8, 166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 174u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8,
+                    48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 170u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 151u8, 224u8, 166u8, 156u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    176u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 40u8, 0u8, 47u8, 0u8, 60u8, 0u8,
+                    89u8, 0u8, 99u8, 0u8, 111u8, 0u8, 146u8, 0u8, 181u8, 0u8, 216u8, 0u8, 248u8,
+                    0u8, 24u8, 1u8, 29u8, 1u8, 55u8, 1u8, 90u8, 1u8, 119u8, 1u8, 141u8, 1u8, 176u8,
+                    1u8, 182u8, 1u8, 213u8, 1u8, 254u8, 1u8, 6u8, 2u8, 14u8, 2u8, 43u8, 2u8, 50u8,
+                    2u8, 58u8, 2u8, 96u8, 2u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 224u8,
+                    166u8, 166u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 171u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 159u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8,
+                    85u8, 83u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8,
+                    224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 174u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 152u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 135u8, 224u8, 166u8, 158u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 156u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 47u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 184u8, 123u8, 48u8, 125u8, 47u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8,
+                    224u8, 166u8, 134u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 184u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8,
+                    125u8, 47u8, 224u8, 166u8, 164u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 136u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 161u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 191u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 185u8, 123u8, 48u8, 125u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 155u8, 224u8, 166u8, 176u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static CCP: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 22u8, 0u8, 27u8, 0u8, 103u8, 114u8,
+                    97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 30u8, 0u8, 62u8, 0u8, 123u8, 48u8,
+                    125u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 112u8, 104u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8,
+                    240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    227u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 166u8, 1u8, 170u8, 1u8, 175u8, 1u8, 185u8,
+                    1u8, 197u8, 1u8, 209u8, 1u8, 211u8, 1u8, 221u8, 1u8, 226u8, 1u8, 232u8, 1u8,
+                    243u8, 1u8, 7u8, 2u8, 25u8, 2u8, 29u8, 2u8, 38u8, 2u8, 41u8, 2u8, 48u8, 2u8,
+                    55u8, 2u8, 61u8, 2u8, 76u8, 2u8, 99u8, 2u8, 106u8, 2u8, 113u8, 2u8, 121u8, 2u8,
+                    130u8, 2u8, 138u8, 2u8, 143u8, 2u8, 147u8, 2u8, 151u8, 2u8, 158u8, 2u8, 168u8,
+                    2u8, 179u8, 2u8, 184u8, 2u8, 189u8, 2u8, 199u8, 2u8, 203u8, 2u8, 207u8, 2u8,
+                    216u8, 2u8, 220u8, 2u8, 226u8, 2u8, 231u8, 2u8, 236u8, 2u8, 241u8, 2u8, 246u8,
+                    2u8, 252u8, 2u8, 255u8, 2u8, 6u8, 3u8, 14u8, 3u8, 25u8, 3u8, 33u8, 3u8, 47u8,
+                    3u8, 56u8, 3u8, 65u8, 3u8, 74u8, 3u8, 92u8, 3u8, 102u8, 3u8, 110u8, 3u8, 123u8,
+                    3u8, 154u8, 3u8, 158u8, 3u8, 162u8, 3u8, 168u8, 3u8, 179u8, 3u8, 189u8, 3u8,
+                    194u8, 3u8, 217u8, 3u8, 236u8, 3u8, 241u8, 3u8, 244u8, 3u8, 251u8, 3u8, 3u8,
+                    4u8, 12u8, 4u8, 21u8, 4u8, 31u8, 4u8, 40u8, 4u8, 48u8, 4u8, 53u8, 4u8, 69u8,
+                    4u8, 92u8, 4u8, 101u8, 4u8, 111u8, 4u8, 122u8, 4u8, 126u8, 4u8, 141u8, 4u8,
+                    165u8, 4u8, 178u8, 4u8, 195u8, 4u8, 206u8, 4u8, 214u8, 4u8, 223u8, 4u8, 0u8,
+                    5u8, 10u8, 5u8, 20u8, 5u8, 35u8, 5u8, 54u8, 5u8, 65u8, 5u8, 74u8, 5u8, 80u8,
+                    5u8, 84u8, 5u8, 89u8, 5u8, 98u8, 5u8, 108u8, 5u8, 121u8, 5u8, 127u8, 5u8,
+                    139u8, 5u8, 144u8, 5u8, 153u8, 5u8, 157u8, 5u8, 160u8, 5u8, 165u8, 5u8, 170u8,
+                    5u8, 180u8, 5u8, 186u8, 5u8, 190u8, 5u8, 202u8, 5u8, 214u8, 5u8, 220u8, 5u8,
+                    227u8, 5u8, 235u8, 5u8, 244u8, 5u8, 252u8, 5u8, 5u8, 6u8, 10u8, 6u8, 14u8, 6u8,
+                    27u8, 6u8, 38u8, 6u8, 43u8, 6u8, 63u8, 6u8, 77u8, 6u8, 82u8, 6u8, 87u8, 6u8,
+                    98u8, 6u8, 114u8, 6u8, 141u8, 6u8, 146u8, 6u8, 160u8, 6u8, 167u8, 6u8, 173u8,
+                    6u8, 180u8, 6u8, 190u8, 6u8, 195u8, 6u8, 198u8, 6u8, 201u8, 6u8, 204u8, 6u8,
+                    209u8, 6u8, 215u8, 6u8, 220u8, 6u8, 231u8, 6u8, 243u8, 6u8, 250u8, 6u8, 1u8,
+                    7u8, 5u8, 7u8, 21u8, 7u8, 31u8, 7u8, 43u8, 7u8, 60u8, 7u8, 71u8, 7u8, 82u8,
+                    7u8, 98u8, 7u8, 110u8, 7u8, 121u8, 7u8, 132u8, 7u8, 141u8, 7u8, 146u8, 7u8,
+                    149u8, 7u8, 159u8, 7u8, 167u8, 7u8, 174u8, 7u8, 182u8, 7u8, 187u8, 7u8, 195u8,
+                    7u8, 200u8, 7u8, 203u8, 7u8, 208u8, 7u8, 212u8, 7u8, 216u8, 7u8, 221u8, 7u8,
+                    225u8, 7u8, 229u8, 7u8, 97u8, 99u8, 114u8, 101u8, 97u8, 99u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8,
+                    97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8,
+                    99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 97u8, 115u8, 116u8, 114u8,
+                    111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8, 108u8, 45u8, 117u8, 110u8,
+                    105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8, 112u8, 104u8, 101u8, 114u8,
+                    101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 98u8, 101u8,
+                    97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8, 101u8, 99u8, 113u8, 117u8,
+                    101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8, 98u8, 114u8, 105u8, 116u8,
+                    105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8, 108u8,
+                    45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8,
+                    104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8,
+                    110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8, 45u8, 106u8, 112u8, 98u8,
+                    117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8, 116u8, 101u8, 99u8, 97u8,
+                    108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 99u8,
+                    97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8, 110u8,
+                    116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8, 105u8, 110u8, 99u8, 104u8,
+                    111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8, 98u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8, 117u8, 112u8, 99u8,
+                    117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8, 111u8, 110u8, 100u8,
+                    97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 99u8, 105u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8, 99u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 100u8, 101u8,
+                    115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8,
+                    100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8,
+                    111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8, 110u8,
+                    97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 109u8, 97u8, 115u8, 115u8,
+                    101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8,
+                    115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 111u8, 110u8, 118u8, 111u8,
+                    108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8, 100u8, 102u8, 97u8, 116u8,
+                    104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 102u8, 111u8, 111u8,
+                    116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8, 103u8, 104u8, 116u8, 102u8,
+                    117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8, 45u8,
+                    102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8, 111u8, 108u8, 105u8, 110u8,
+                    101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8, 121u8, 45u8, 100u8, 101u8,
+                    110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8, 110u8, 101u8, 114u8, 105u8,
+                    99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 103u8, 105u8, 103u8, 97u8, 104u8, 101u8, 114u8,
+                    116u8, 122u8, 103u8, 105u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8, 103u8,
+                    114u8, 97u8, 105u8, 110u8, 103u8, 114u8, 97u8, 109u8, 103u8, 114u8, 97u8,
+                    121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 101u8, 99u8,
+                    116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8, 104u8, 101u8, 99u8, 116u8,
+                    111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8, 101u8, 110u8, 114u8,
+                    121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8, 114u8, 115u8, 101u8,
+                    112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8,
+                    99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8, 102u8, 104u8, 103u8,
+                    105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8,
+                    111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 97u8,
+                    114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8, 101u8, 108u8,
+                    118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8, 105u8, 108u8,
+                    111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 107u8,
+                    105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8, 108u8, 111u8,
+                    119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8, 116u8, 107u8,
+                    111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 108u8, 105u8,
+                    103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8, 105u8,
+                    103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8, 101u8,
+                    103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 99u8,
+                    114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8,
+                    101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8,
+                    109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8,
+                    108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8,
+                    105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8, 103u8,
+                    108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8, 103u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8, 116u8,
+                    101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8,
+                    97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8, 110u8,
+                    111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8, 116u8,
+                    105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8, 101u8,
+                    119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8, 111u8,
+                    102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8,
+                    103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8, 114u8,
+                    111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8, 114u8,
+                    116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8,
+                    54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8,
+                    57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8, 112u8,
+                    101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8, 116u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8, 110u8,
+                    116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8, 116u8,
+                    114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8, 120u8,
+                    101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8, 111u8,
+                    105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8, 111u8,
+                    111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8,
+                    99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8, 116u8,
+                    113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8, 110u8,
+                    101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8,
+                    114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8, 100u8,
+                    115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    227u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 37u8, 0u8, 42u8, 0u8, 82u8, 0u8,
+                    122u8, 0u8, 128u8, 0u8, 135u8, 0u8, 142u8, 0u8, 149u8, 0u8, 154u8, 0u8, 162u8,
+                    0u8, 182u8, 0u8, 189u8, 0u8, 201u8, 0u8, 212u8, 0u8, 218u8, 0u8, 238u8, 0u8,
+                    245u8, 0u8, 1u8, 1u8, 7u8, 1u8, 13u8, 1u8, 19u8, 1u8, 25u8, 1u8, 45u8, 1u8,
+                    73u8, 1u8, 84u8, 1u8, 96u8, 1u8, 103u8, 1u8, 111u8, 1u8, 119u8, 1u8, 127u8,
+                    1u8, 200u8, 1u8, 207u8, 1u8, 215u8, 1u8, 223u8, 1u8, 228u8, 1u8, 242u8, 1u8,
+                    254u8, 1u8, 4u8, 2u8, 10u8, 2u8, 30u8, 2u8, 37u8, 2u8, 43u8, 2u8, 63u8, 2u8,
+                    90u8, 2u8, 100u8, 2u8, 114u8, 2u8, 125u8, 2u8, 133u8, 2u8, 142u8, 2u8, 150u8,
+                    2u8, 158u8, 2u8, 164u8, 2u8, 170u8, 2u8, 176u8, 2u8, 183u8, 2u8, 190u8, 2u8,
+                    199u8, 2u8, 213u8, 2u8, 227u8, 2u8, 247u8, 2u8, 255u8, 2u8, 11u8, 3u8, 18u8,
+                    3u8, 23u8, 3u8, 30u8, 3u8, 42u8, 3u8, 51u8, 3u8, 56u8, 3u8, 62u8, 3u8, 68u8,
+                    3u8, 75u8, 3u8, 81u8, 3u8, 90u8, 3u8, 114u8, 3u8, 122u8, 3u8, 158u8, 3u8,
+                    164u8, 3u8, 171u8, 3u8, 178u8, 3u8, 184u8, 3u8, 190u8, 3u8, 214u8, 3u8, 242u8,
+                    3u8, 250u8, 3u8, 2u8, 4u8, 12u8, 4u8, 23u8, 4u8, 28u8, 4u8, 34u8, 4u8, 43u8,
+                    4u8, 48u8, 4u8, 60u8, 4u8, 66u8, 4u8, 72u8, 4u8, 80u8, 4u8, 100u8, 4u8, 109u8,
+                    4u8, 116u8, 4u8, 122u8, 4u8, 142u8, 4u8, 150u8, 4u8, 157u8, 4u8, 163u8, 4u8,
+                    170u8, 4u8, 183u8, 4u8, 189u8, 4u8, 202u8, 4u8, 217u8, 4u8, 226u8, 4u8, 18u8,
+                    5u8, 42u8, 5u8, 53u8, 5u8, 61u8, 5u8, 67u8, 5u8, 73u8, 5u8, 79u8, 5u8, 85u8,
+                    5u8, 92u8, 5u8, 98u8, 5u8, 105u8, 5u8, 111u8, 5u8, 117u8, 5u8, 129u8, 5u8,
+                    136u8, 5u8, 145u8, 5u8, 152u8, 5u8, 159u8, 5u8, 166u8, 5u8, 186u8, 5u8, 193u8,
+                    5u8, 205u8, 5u8, 212u8, 5u8, 219u8, 5u8, 225u8, 5u8, 233u8, 5u8, 239u8, 5u8,
+                    248u8, 5u8, 254u8, 5u8, 18u8, 6u8, 27u8, 6u8, 37u8, 6u8, 43u8, 6u8, 49u8, 6u8,
+                    77u8, 6u8, 84u8, 6u8, 100u8, 6u8, 106u8, 6u8, 112u8, 6u8, 119u8, 6u8, 124u8,
+                    6u8, 133u8, 6u8, 142u8, 6u8, 151u8, 6u8, 159u8, 6u8, 165u8, 6u8, 179u8, 6u8,
+                    211u8, 6u8, 219u8, 6u8, 225u8, 6u8, 235u8, 6u8, 242u8, 6u8, 249u8, 6u8, 255u8,
+                    6u8, 3u8, 7u8, 9u8, 7u8, 15u8, 7u8, 21u8, 7u8, 41u8, 7u8, 50u8, 7u8, 56u8, 7u8,
+                    69u8, 7u8, 76u8, 7u8, 82u8, 7u8, 90u8, 7u8, 97u8, 7u8, 103u8, 7u8, 109u8, 7u8,
+                    116u8, 7u8, 128u8, 7u8, 135u8, 7u8, 141u8, 7u8, 152u8, 7u8, 157u8, 7u8, 164u8,
+                    7u8, 173u8, 7u8, 197u8, 7u8, 208u8, 7u8, 220u8, 7u8, 229u8, 7u8, 241u8, 7u8,
+                    252u8, 7u8, 32u8, 8u8, 51u8, 8u8, 72u8, 8u8, 86u8, 8u8, 93u8, 8u8, 101u8, 8u8,
+                    111u8, 8u8, 119u8, 8u8, 127u8, 8u8, 135u8, 8u8, 143u8, 8u8, 188u8, 8u8, 196u8,
+                    8u8, 204u8, 8u8, 211u8, 8u8, 0u8, 9u8, 8u8, 9u8, 16u8, 9u8, 22u8, 9u8, 34u8,
+                    9u8, 42u8, 9u8, 49u8, 9u8, 55u8, 9u8, 61u8, 9u8, 68u8, 9u8, 80u8, 9u8, 91u8,
+                    9u8, 97u8, 9u8, 102u8, 9u8, 107u8, 9u8, 112u8, 9u8, 120u8, 9u8, 140u8, 9u8,
+                    160u8, 9u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8,
+                    97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 65u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 123u8, 48u8, 125u8, 32u8, 97u8, 117u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 67u8, 68u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 76u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 133u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8,
+                    100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 101u8, 99u8, 123u8, 48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 32u8, 73u8, 109u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 32u8, 102u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 111u8, 112u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8,
+                    149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8,
+                    32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8,
+                    194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 70u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8,
+                    73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8,
+                    119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8,
+                    48u8, 125u8, 32u8, 103u8, 114u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 76u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 138u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 168u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 116u8, 101u8, 109u8, 123u8, 48u8, 125u8, 32u8, 106u8,
+                    105u8, 103u8, 103u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8,
+                    97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8, 102u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8,
+                    48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8,
+                    107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    143u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 49u8, 48u8,
+                    48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 107u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 76u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    168u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 32u8, 72u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 76u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8,
+                    133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 97u8, 114u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 112u8, 98u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8,
+                    37u8, 123u8, 48u8, 125u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 32u8, 112u8, 105u8,
+                    110u8, 99u8, 104u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8, 125u8, 32u8, 113u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 113u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 176u8, 240u8, 145u8, 132u8, 177u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 180u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8, 111u8,
+                    108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8, 32u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 118u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 152u8, 137u8,
+                    123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8,
+                    240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8,
+                    32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 121u8,
+                    100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 98u8,
+                    115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 116u8, 115u8, 112u8, 123u8, 48u8,
+                    125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8,
+                    116u8, 104u8, 101u8, 114u8, 109u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 110u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8, 48u8, 125u8,
+                    32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 143u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 0u8, 53u8, 0u8, 60u8, 0u8, 105u8, 0u8,
+                    150u8, 0u8, 160u8, 0u8, 172u8, 0u8, 221u8, 0u8, 14u8, 1u8, 67u8, 1u8, 112u8,
+                    1u8, 157u8, 1u8, 162u8, 1u8, 199u8, 1u8, 252u8, 1u8, 37u8, 2u8, 46u8, 2u8,
+                    103u8, 2u8, 109u8, 2u8, 114u8, 2u8, 175u8, 2u8, 183u8, 2u8, 191u8, 2u8, 236u8,
+                    2u8, 243u8, 2u8, 251u8, 2u8, 40u8, 3u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8,
+                    32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 47u8, 99u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 156u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8,
+                    180u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 85u8, 83u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8,
+                    240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 123u8, 48u8,
+                    125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8,
+                    32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8,
+                    125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8,
+                    32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 180u8, 123u8, 48u8, 125u8, 47u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 180u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8,
+                    113u8, 123u8, 48u8, 125u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 180u8, 123u8, 48u8, 125u8, 47u8,
+                    99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 168u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8,
+                    168u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 123u8, 48u8, 125u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static EN: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 43u8, 0u8, 46u8, 0u8,
+                    49u8, 0u8, 53u8, 0u8, 57u8, 0u8, 61u8, 0u8, 67u8, 0u8, 72u8, 0u8, 77u8, 0u8,
+                    84u8, 0u8, 92u8, 0u8, 96u8, 0u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8,
+                    117u8, 116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8,
+                    101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8,
+                    105u8, 116u8, 100u8, 97u8, 121u8, 100u8, 111u8, 116u8, 100u8, 114u8, 97u8,
+                    109u8, 100u8, 114u8, 111u8, 112u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8,
+                    103u8, 103u8, 101u8, 114u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 119u8, 101u8, 101u8, 107u8,
+                    121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 30u8, 0u8, 37u8, 0u8,
+                    44u8, 0u8, 52u8, 0u8, 58u8, 0u8, 66u8, 0u8, 76u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    99u8, 0u8, 111u8, 0u8, 117u8, 0u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8,
+                    109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8, 101u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 97u8, 121u8, 123u8, 48u8, 125u8, 32u8, 100u8, 111u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 114u8, 123u8, 48u8, 125u8, 32u8, 105u8, 116u8, 101u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 119u8, 107u8, 123u8, 48u8, 125u8, 32u8, 121u8, 114u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 31u8, 0u8,
+                    42u8, 0u8, 48u8, 0u8, 55u8, 0u8, 62u8, 0u8, 69u8, 0u8, 74u8, 0u8, 80u8, 0u8,
+                    87u8, 0u8, 94u8, 0u8, 103u8, 0u8, 114u8, 0u8, 120u8, 0u8, 128u8, 0u8, 135u8,
+                    0u8, 145u8, 0u8, 151u8, 0u8, 157u8, 0u8, 163u8, 0u8, 169u8, 0u8, 175u8, 0u8,
+                    180u8, 0u8, 186u8, 0u8, 198u8, 0u8, 203u8, 0u8, 211u8, 0u8, 219u8, 0u8, 227u8,
+                    0u8, 235u8, 0u8, 242u8, 0u8, 250u8, 0u8, 2u8, 1u8, 7u8, 1u8, 19u8, 1u8, 31u8,
+                    1u8, 37u8, 1u8, 43u8, 1u8, 51u8, 1u8, 58u8, 1u8, 64u8, 1u8, 70u8, 1u8, 77u8,
+                    1u8, 84u8, 1u8, 96u8, 1u8, 104u8, 1u8, 112u8, 1u8, 119u8, 1u8, 128u8, 1u8,
+                    139u8, 1u8, 148u8, 1u8, 156u8, 1u8, 164u8, 1u8, 170u8, 1u8, 176u8, 1u8, 182u8,
+                    1u8, 187u8, 1u8, 194u8, 1u8, 203u8, 1u8, 217u8, 1u8, 229u8, 1u8, 236u8, 1u8,
+                    242u8, 1u8, 248u8, 1u8, 4u8, 2u8, 11u8, 2u8, 16u8, 2u8, 23u8, 2u8, 35u8, 2u8,
+                    48u8, 2u8, 53u8, 2u8, 59u8, 2u8, 65u8, 2u8, 72u8, 2u8, 78u8, 2u8, 84u8, 2u8,
+                    89u8, 2u8, 95u8, 2u8, 101u8, 2u8, 107u8, 2u8, 114u8, 2u8, 119u8, 2u8, 125u8,
+                    2u8, 131u8, 2u8, 137u8, 2u8, 143u8, 2u8, 151u8, 2u8, 160u8, 2u8, 171u8, 2u8,
+                    182u8, 2u8, 187u8, 2u8, 193u8, 2u8, 200u8, 2u8, 205u8, 2u8, 217u8, 2u8, 223u8,
+                    2u8, 229u8, 2u8, 237u8, 2u8, 243u8, 2u8, 250u8, 2u8, 1u8, 3u8, 7u8, 3u8, 13u8,
+                    3u8, 21u8, 3u8, 28u8, 3u8, 34u8, 3u8, 41u8, 3u8, 54u8, 3u8, 60u8, 3u8, 73u8,
+                    3u8, 88u8, 3u8, 97u8, 3u8, 103u8, 3u8, 108u8, 3u8, 120u8, 3u8, 128u8, 3u8,
+                    134u8, 3u8, 140u8, 3u8, 146u8, 3u8, 152u8, 3u8, 159u8, 3u8, 165u8, 3u8, 172u8,
+                    3u8, 178u8, 3u8, 184u8, 3u8, 189u8, 3u8, 196u8, 3u8, 205u8, 3u8, 212u8, 3u8,
+                    219u8, 3u8, 226u8, 3u8, 232u8, 3u8, 239u8, 3u8, 251u8, 3u8, 2u8, 4u8, 9u8, 4u8,
+                    15u8, 4u8, 23u8, 4u8, 29u8, 4u8, 38u8, 4u8, 44u8, 4u8, 50u8, 4u8, 58u8, 4u8,
+                    68u8, 4u8, 74u8, 4u8, 80u8, 4u8, 87u8, 4u8, 94u8, 4u8, 102u8, 4u8, 108u8, 4u8,
+                    114u8, 4u8, 121u8, 4u8, 126u8, 4u8, 135u8, 4u8, 145u8, 4u8, 152u8, 4u8, 161u8,
+                    4u8, 167u8, 4u8, 181u8, 4u8, 187u8, 4u8, 195u8, 4u8, 201u8, 4u8, 209u8, 4u8,
+                    216u8, 4u8, 223u8, 4u8, 229u8, 4u8, 233u8, 4u8, 239u8, 4u8, 245u8, 4u8, 251u8,
+                    4u8, 1u8, 5u8, 7u8, 5u8, 13u8, 5u8, 24u8, 5u8, 31u8, 5u8, 37u8, 5u8, 45u8, 5u8,
+                    52u8, 5u8, 58u8, 5u8, 64u8, 5u8, 71u8, 5u8, 83u8, 5u8, 90u8, 5u8, 96u8, 5u8,
+                    107u8, 5u8, 115u8, 5u8, 122u8, 5u8, 129u8, 5u8, 136u8, 5u8, 147u8, 5u8, 159u8,
+                    5u8, 165u8, 5u8, 177u8, 5u8, 188u8, 5u8, 195u8, 5u8, 214u8, 5u8, 235u8, 5u8,
+                    249u8, 5u8, 254u8, 5u8, 4u8, 6u8, 12u8, 6u8, 20u8, 6u8, 28u8, 6u8, 36u8, 6u8,
+                    44u8, 6u8, 53u8, 6u8, 61u8, 6u8, 69u8, 6u8, 76u8, 6u8, 85u8, 6u8, 93u8, 6u8,
+                    99u8, 6u8, 105u8, 6u8, 117u8, 6u8, 125u8, 6u8, 132u8, 6u8, 138u8, 6u8, 144u8,
+                    6u8, 149u8, 6u8, 162u8, 6u8, 173u8, 6u8, 179u8, 6u8, 184u8, 6u8, 189u8, 6u8,
+                    194u8, 6u8, 200u8, 6u8, 207u8, 6u8, 213u8, 6u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 109u8, 105u8,
+                    110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8, 101u8, 99u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 97u8, 117u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8,
+                    32u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 105u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 32u8, 66u8, 84u8, 45u8,
+                    73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    121u8, 116u8, 101u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 67u8, 68u8, 123u8, 48u8, 125u8, 194u8,
+                    176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 76u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8,
+                    100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 97u8, 121u8, 115u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 100u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 101u8, 103u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 112u8, 45u8, 73u8, 109u8, 112u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 111u8, 116u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 114u8, 100u8, 114u8, 111u8, 112u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8,
+                    138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8,
+                    125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8,
+                    125u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 32u8, 70u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8,
+                    122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8,
+                    32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 67u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 102u8, 119u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8,
+                    45u8, 101u8, 113u8, 117u8, 105u8, 118u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    87u8, 123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 123u8, 48u8, 125u8, 32u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 76u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 104u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8,
+                    72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 105u8, 116u8, 101u8, 109u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 75u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 107u8, 66u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 107u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8,
+                    104u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 108u8, 105u8, 103u8,
+                    104u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 121u8, 123u8, 48u8, 125u8, 32u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 49u8, 48u8, 48u8, 32u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 104u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 109u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 111u8,
+                    108u8, 47u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 116u8,
+                    104u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8, 133u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 105u8, 103u8, 104u8, 116u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8, 32u8, 111u8, 102u8,
+                    32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 32u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    97u8, 114u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 37u8, 123u8, 48u8, 125u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 110u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8,
+                    102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8,
+                    102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 45u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 114u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 32u8, 194u8,
+                    176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8, 118u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    114u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 83u8, 123u8, 48u8, 125u8, 32u8, 83u8,
+                    118u8, 123u8, 48u8, 125u8, 32u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8,
+                    152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 113u8,
+                    32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 113u8, 32u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 98u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 116u8, 115u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 84u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8,
+                    32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8,
+                    48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 32u8, 87u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 119u8, 107u8, 115u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8,
+                    123u8, 48u8, 125u8, 32u8, 121u8, 114u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 42u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8, 0u8, 68u8, 0u8, 74u8, 0u8, 80u8,
+                    0u8, 85u8, 0u8, 90u8, 0u8, 97u8, 0u8, 102u8, 0u8, 111u8, 0u8, 117u8, 0u8,
+                    123u8, 0u8, 128u8, 0u8, 133u8, 0u8, 141u8, 0u8, 149u8, 0u8, 157u8, 0u8, 164u8,
+                    0u8, 172u8, 0u8, 177u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 123u8, 48u8, 125u8, 47u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 85u8, 83u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 73u8, 109u8, 112u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 76u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 110u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 47u8,
+                    111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8,
+                    113u8, 123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 119u8, 123u8, 48u8, 125u8, 47u8, 121u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 43u8, 0u8, 46u8, 0u8,
+                    49u8, 0u8, 53u8, 0u8, 57u8, 0u8, 61u8, 0u8, 65u8, 0u8, 71u8, 0u8, 77u8, 0u8,
+                    82u8, 0u8, 87u8, 0u8, 92u8, 0u8, 99u8, 0u8, 109u8, 0u8, 115u8, 0u8, 123u8, 0u8,
+                    127u8, 0u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8,
+                    97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 100u8, 97u8,
+                    121u8, 100u8, 111u8, 116u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 104u8, 111u8, 117u8, 114u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8,
+                    103u8, 103u8, 101u8, 114u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8,
+                    111u8, 110u8, 116u8, 104u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 105u8,
+                    110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8,
+                    101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8, 117u8,
+                    115u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 30u8, 0u8, 37u8, 0u8,
+                    44u8, 0u8, 52u8, 0u8, 60u8, 0u8, 66u8, 0u8, 74u8, 0u8, 84u8, 0u8, 91u8, 0u8,
+                    98u8, 0u8, 107u8, 0u8, 116u8, 0u8, 123u8, 0u8, 130u8, 0u8, 137u8, 0u8, 149u8,
+                    0u8, 155u8, 0u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 109u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8, 101u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 97u8, 121u8, 123u8, 48u8, 125u8, 32u8, 100u8, 111u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    114u8, 111u8, 112u8, 123u8, 48u8, 125u8, 32u8, 104u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 116u8, 101u8, 109u8, 123u8, 48u8, 125u8, 32u8, 106u8,
+                    105u8, 103u8, 103u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 109u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 105u8,
+                    110u8, 99u8, 104u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 114u8, 101u8, 118u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 119u8, 107u8, 123u8, 48u8, 125u8, 32u8, 121u8, 114u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 31u8, 0u8,
+                    42u8, 0u8, 48u8, 0u8, 55u8, 0u8, 62u8, 0u8, 69u8, 0u8, 74u8, 0u8, 80u8, 0u8,
+                    87u8, 0u8, 94u8, 0u8, 103u8, 0u8, 114u8, 0u8, 120u8, 0u8, 128u8, 0u8, 135u8,
+                    0u8, 145u8, 0u8, 151u8, 0u8, 157u8, 0u8, 163u8, 0u8, 169u8, 0u8, 175u8, 0u8,
+                    180u8, 0u8, 186u8, 0u8, 198u8, 0u8, 203u8, 0u8, 211u8, 0u8, 219u8, 0u8, 227u8,
+                    0u8, 235u8, 0u8, 242u8, 0u8, 250u8, 0u8, 2u8, 1u8, 7u8, 1u8, 19u8, 1u8, 31u8,
+                    1u8, 37u8, 1u8, 43u8, 1u8, 51u8, 1u8, 58u8, 1u8, 64u8, 1u8, 70u8, 1u8, 77u8,
+                    1u8, 90u8, 1u8, 100u8, 1u8, 108u8, 1u8, 116u8, 1u8, 123u8, 1u8, 132u8, 1u8,
+                    141u8, 1u8, 150u8, 1u8, 158u8, 1u8, 166u8, 1u8, 172u8, 1u8, 178u8, 1u8, 184u8,
+                    1u8, 189u8, 1u8, 196u8, 1u8, 208u8, 1u8, 217u8, 1u8, 229u8, 1u8, 236u8, 1u8,
+                    242u8, 1u8, 248u8, 1u8, 4u8, 2u8, 11u8, 2u8, 16u8, 2u8, 26u8, 2u8, 33u8, 2u8,
+                    46u8, 2u8, 51u8, 2u8, 57u8, 2u8, 63u8, 2u8, 70u8, 2u8, 76u8, 2u8, 82u8, 2u8,
+                    87u8, 2u8, 93u8, 2u8, 99u8, 2u8, 105u8, 2u8, 112u8, 2u8, 117u8, 2u8, 123u8,
+                    2u8, 129u8, 2u8, 136u8, 2u8, 142u8, 2u8, 150u8, 2u8, 159u8, 2u8, 170u8, 2u8,
+                    181u8, 2u8, 186u8, 2u8, 192u8, 2u8, 199u8, 2u8, 204u8, 2u8, 216u8, 2u8, 222u8,
+                    2u8, 228u8, 2u8, 236u8, 2u8, 242u8, 2u8, 249u8, 2u8, 0u8, 3u8, 6u8, 3u8, 12u8,
+                    3u8, 20u8, 3u8, 27u8, 3u8, 33u8, 3u8, 40u8, 3u8, 53u8, 3u8, 59u8, 3u8, 72u8,
+                    3u8, 87u8, 3u8, 96u8, 3u8, 102u8, 3u8, 107u8, 3u8, 119u8, 3u8, 127u8, 3u8,
+                    133u8, 3u8, 139u8, 3u8, 145u8, 3u8, 151u8, 3u8, 158u8, 3u8, 164u8, 3u8, 171u8,
+                    3u8, 177u8, 3u8, 183u8, 3u8, 188u8, 3u8, 195u8, 3u8, 204u8, 3u8, 211u8, 3u8,
+                    218u8, 3u8, 225u8, 3u8, 231u8, 3u8, 241u8, 3u8, 248u8, 3u8, 255u8, 3u8, 6u8,
+                    4u8, 12u8, 4u8, 20u8, 4u8, 26u8, 4u8, 35u8, 4u8, 41u8, 4u8, 47u8, 4u8, 55u8,
+                    4u8, 65u8, 4u8, 71u8, 4u8, 77u8, 4u8, 85u8, 4u8, 92u8, 4u8, 100u8, 4u8, 106u8,
+                    4u8, 112u8, 4u8, 119u8, 4u8, 124u8, 4u8, 133u8, 4u8, 143u8, 4u8, 150u8, 4u8,
+                    159u8, 4u8, 165u8, 4u8, 179u8, 4u8, 185u8, 4u8, 193u8, 4u8, 199u8, 4u8, 207u8,
+                    4u8, 214u8, 4u8, 221u8, 4u8, 227u8, 4u8, 231u8, 4u8, 237u8, 4u8, 243u8, 4u8,
+                    249u8, 4u8, 255u8, 4u8, 10u8, 5u8, 16u8, 5u8, 27u8, 5u8, 34u8, 5u8, 40u8, 5u8,
+                    48u8, 5u8, 55u8, 5u8, 61u8, 5u8, 67u8, 5u8, 74u8, 5u8, 86u8, 5u8, 93u8, 5u8,
+                    102u8, 5u8, 108u8, 5u8, 116u8, 5u8, 123u8, 5u8, 130u8, 5u8, 138u8, 5u8, 149u8,
+                    5u8, 161u8, 5u8, 167u8, 5u8, 179u8, 5u8, 190u8, 5u8, 198u8, 5u8, 217u8, 5u8,
+                    238u8, 5u8, 252u8, 5u8, 1u8, 6u8, 7u8, 6u8, 15u8, 6u8, 23u8, 6u8, 31u8, 6u8,
+                    39u8, 6u8, 47u8, 6u8, 56u8, 6u8, 64u8, 6u8, 72u8, 6u8, 79u8, 6u8, 88u8, 6u8,
+                    96u8, 6u8, 102u8, 6u8, 108u8, 6u8, 120u8, 6u8, 128u8, 6u8, 135u8, 6u8, 141u8,
+                    6u8, 147u8, 6u8, 152u8, 6u8, 165u8, 6u8, 176u8, 6u8, 182u8, 6u8, 187u8, 6u8,
+                    192u8, 6u8, 197u8, 6u8, 203u8, 6u8, 210u8, 6u8, 216u8, 6u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8,
+                    109u8, 105u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8,
+                    101u8, 99u8, 115u8, 123u8, 48u8, 125u8, 32u8, 97u8, 117u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8,
+                    48u8, 125u8, 32u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 105u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 32u8, 66u8,
+                    84u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 121u8, 116u8, 101u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 99u8, 116u8, 123u8, 48u8,
+                    125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 104u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 67u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 97u8, 121u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 101u8, 99u8, 123u8, 48u8, 125u8, 32u8, 100u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 85u8, 83u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 111u8, 116u8, 115u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    114u8, 111u8, 112u8, 115u8, 123u8, 48u8, 125u8, 32u8, 100u8, 117u8, 110u8,
+                    97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8,
+                    125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8,
+                    123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8,
+                    123u8, 48u8, 125u8, 32u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 67u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 103u8,
+                    97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 103u8, 97u8, 115u8, 45u8, 101u8, 113u8, 117u8, 105u8, 118u8, 123u8, 48u8,
+                    125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 123u8, 48u8, 125u8, 32u8, 71u8, 121u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 97u8, 123u8, 48u8, 125u8, 32u8, 104u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 72u8, 123u8, 48u8, 125u8,
+                    32u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 105u8, 116u8,
+                    101u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 102u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 107u8,
+                    117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8,
+                    121u8, 123u8, 48u8, 125u8, 32u8, 108u8, 123u8, 48u8, 125u8, 32u8, 108u8, 47u8,
+                    49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 47u8,
+                    107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 108u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 206u8, 188u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 85u8, 83u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8,
+                    108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 72u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    105u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 116u8, 104u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8,
+                    226u8, 139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 105u8, 103u8,
+                    104u8, 116u8, 115u8, 123u8, 48u8, 125u8, 32u8, 71u8, 108u8, 99u8, 123u8, 48u8,
+                    125u8, 32u8, 111u8, 102u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    169u8, 123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 97u8, 114u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8, 125u8, 32u8, 85u8,
+                    83u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 113u8, 116u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8,
+                    114u8, 101u8, 118u8, 115u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 114u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 101u8, 99u8, 115u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8,
+                    111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 83u8, 123u8, 48u8, 125u8, 32u8, 83u8, 118u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8,
+                    137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 113u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 113u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8,
+                    121u8, 100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 98u8, 115u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 116u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8,
+                    84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8,
+                    109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 110u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 86u8, 123u8, 48u8, 125u8, 32u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 119u8, 107u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 123u8, 48u8, 125u8, 32u8, 121u8, 114u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 42u8, 0u8, 49u8, 0u8, 54u8, 0u8, 59u8, 0u8, 65u8, 0u8, 71u8, 0u8, 77u8,
+                    0u8, 82u8, 0u8, 87u8, 0u8, 94u8, 0u8, 99u8, 0u8, 108u8, 0u8, 114u8, 0u8, 120u8,
+                    0u8, 125u8, 0u8, 130u8, 0u8, 138u8, 0u8, 146u8, 0u8, 154u8, 0u8, 161u8, 0u8,
+                    169u8, 0u8, 174u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8, 48u8,
+                    125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 123u8, 48u8, 125u8, 47u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 47u8, 85u8, 83u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 47u8, 103u8, 123u8, 48u8,
+                    125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8, 125u8, 47u8, 110u8,
+                    105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 47u8, 111u8, 122u8, 123u8,
+                    48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8, 113u8, 123u8, 48u8,
+                    125u8, 47u8, 115u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 119u8,
+                    123u8, 48u8, 125u8, 47u8, 121u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static ES: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 9u8, 0u8, 13u8, 0u8, 19u8, 0u8, 24u8,
+                    0u8, 28u8, 0u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8, 110u8, 97u8, 109u8,
+                    105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 110u8,
+                    105u8, 103u8, 104u8, 116u8, 112u8, 97u8, 114u8, 116u8, 112u8, 111u8, 105u8,
+                    110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 17u8, 0u8, 26u8, 0u8, 37u8, 0u8, 46u8,
+                    0u8, 55u8, 0u8, 123u8, 48u8, 125u8, 32u8, 103u8, 111u8, 116u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    195u8, 173u8, 116u8, 101u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8,
+                    100u8, 105u8, 100u8, 111u8, 114u8, 123u8, 48u8, 125u8, 32u8, 110u8, 111u8,
+                    99u8, 104u8, 101u8, 123u8, 48u8, 125u8, 32u8, 112u8, 97u8, 114u8, 116u8, 101u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 111u8, 46u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 62u8, 2u8,
+                    71u8, 2u8, 74u8, 2u8, 81u8, 2u8, 88u8, 2u8, 94u8, 2u8, 109u8, 2u8, 132u8, 2u8,
+                    139u8, 2u8, 146u8, 2u8, 154u8, 2u8, 163u8, 2u8, 171u8, 2u8, 176u8, 2u8, 180u8,
+                    2u8, 184u8, 2u8, 191u8, 2u8, 201u8, 2u8, 212u8, 2u8, 217u8, 2u8, 222u8, 2u8,
+                    232u8, 2u8, 236u8, 2u8, 240u8, 2u8, 249u8, 2u8, 253u8, 2u8, 3u8, 3u8, 8u8, 3u8,
+                    13u8, 3u8, 18u8, 3u8, 23u8, 3u8, 29u8, 3u8, 32u8, 3u8, 39u8, 3u8, 47u8, 3u8,
+                    58u8, 3u8, 66u8, 3u8, 80u8, 3u8, 89u8, 3u8, 98u8, 3u8, 107u8, 3u8, 125u8, 3u8,
+                    135u8, 3u8, 143u8, 3u8, 156u8, 3u8, 187u8, 3u8, 191u8, 3u8, 195u8, 3u8, 201u8,
+                    3u8, 212u8, 3u8, 222u8, 3u8, 227u8, 3u8, 250u8, 3u8, 13u8, 4u8, 18u8, 4u8,
+                    21u8, 4u8, 28u8, 4u8, 36u8, 4u8, 45u8, 4u8, 54u8, 4u8, 64u8, 4u8, 73u8, 4u8,
+                    81u8, 4u8, 86u8, 4u8, 102u8, 4u8, 125u8, 4u8, 134u8, 4u8, 144u8, 4u8, 155u8,
+                    4u8, 159u8, 4u8, 174u8, 4u8, 198u8, 4u8, 211u8, 4u8, 228u8, 4u8, 239u8, 4u8,
+                    247u8, 4u8, 0u8, 5u8, 33u8, 5u8, 43u8, 5u8, 53u8, 5u8, 68u8, 5u8, 87u8, 5u8,
+                    98u8, 5u8, 107u8, 5u8, 113u8, 5u8, 117u8, 5u8, 122u8, 5u8, 131u8, 5u8, 141u8,
+                    5u8, 154u8, 5u8, 160u8, 5u8, 172u8, 5u8, 177u8, 5u8, 186u8, 5u8, 190u8, 5u8,
+                    193u8, 5u8, 198u8, 5u8, 203u8, 5u8, 213u8, 5u8, 219u8, 5u8, 223u8, 5u8, 235u8,
+                    5u8, 247u8, 5u8, 253u8, 5u8, 4u8, 6u8, 12u8, 6u8, 21u8, 6u8, 29u8, 6u8, 38u8,
+                    6u8, 43u8, 6u8, 47u8, 6u8, 60u8, 6u8, 71u8, 6u8, 76u8, 6u8, 96u8, 6u8, 110u8,
+                    6u8, 115u8, 6u8, 120u8, 6u8, 131u8, 6u8, 147u8, 6u8, 174u8, 6u8, 179u8, 6u8,
+                    193u8, 6u8, 200u8, 6u8, 206u8, 6u8, 213u8, 6u8, 223u8, 6u8, 228u8, 6u8, 231u8,
+                    6u8, 234u8, 6u8, 237u8, 6u8, 242u8, 6u8, 248u8, 6u8, 253u8, 6u8, 8u8, 7u8,
+                    20u8, 7u8, 27u8, 7u8, 34u8, 7u8, 38u8, 7u8, 54u8, 7u8, 64u8, 7u8, 76u8, 7u8,
+                    93u8, 7u8, 104u8, 7u8, 115u8, 7u8, 131u8, 7u8, 143u8, 7u8, 154u8, 7u8, 165u8,
+                    7u8, 174u8, 7u8, 179u8, 7u8, 182u8, 7u8, 192u8, 7u8, 200u8, 7u8, 207u8, 7u8,
+                    215u8, 7u8, 220u8, 7u8, 228u8, 7u8, 233u8, 7u8, 236u8, 7u8, 241u8, 7u8, 245u8,
+                    7u8, 249u8, 7u8, 254u8, 7u8, 2u8, 8u8, 6u8, 8u8, 97u8, 99u8, 114u8, 101u8,
+                    97u8, 99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 30u8, 0u8,
+                    40u8, 0u8, 46u8, 0u8, 53u8, 0u8, 60u8, 0u8, 67u8, 0u8, 72u8, 0u8, 80u8, 0u8,
+                    85u8, 0u8, 92u8, 0u8, 104u8, 0u8, 115u8, 0u8, 121u8, 0u8, 126u8, 0u8, 133u8,
+                    0u8, 143u8, 0u8, 149u8, 0u8, 155u8, 0u8, 162u8, 0u8, 168u8, 0u8, 174u8, 0u8,
+                    180u8, 0u8, 191u8, 0u8, 203u8, 0u8, 210u8, 0u8, 218u8, 0u8, 226u8, 0u8, 234u8,
+                    0u8, 242u8, 0u8, 249u8, 0u8, 1u8, 1u8, 9u8, 1u8, 16u8, 1u8, 30u8, 1u8, 42u8,
+                    1u8, 51u8, 1u8, 57u8, 1u8, 62u8, 1u8, 71u8, 1u8, 77u8, 1u8, 83u8, 1u8, 88u8,
+                    1u8, 95u8, 1u8, 110u8, 1u8, 116u8, 1u8, 124u8, 1u8, 131u8, 1u8, 140u8, 1u8,
+                    149u8, 1u8, 159u8, 1u8, 167u8, 1u8, 175u8, 1u8, 181u8, 1u8, 187u8, 1u8, 194u8,
+                    1u8, 201u8, 1u8, 208u8, 1u8, 217u8, 1u8, 231u8, 1u8, 245u8, 1u8, 251u8, 1u8,
+                    3u8, 2u8, 15u8, 2u8, 22u8, 2u8, 28u8, 2u8, 35u8, 2u8, 47u8, 2u8, 56u8, 2u8,
+                    61u8, 2u8, 67u8, 2u8, 73u8, 2u8, 80u8, 2u8, 86u8, 2u8, 92u8, 2u8, 97u8, 2u8,
+                    105u8, 2u8, 111u8, 2u8, 117u8, 2u8, 124u8, 2u8, 131u8, 2u8, 137u8, 2u8, 143u8,
+                    2u8, 148u8, 2u8, 154u8, 2u8, 162u8, 2u8, 172u8, 2u8, 185u8, 2u8, 196u8, 2u8,
+                    201u8, 2u8, 207u8, 2u8, 216u8, 2u8, 221u8, 2u8, 233u8, 2u8, 239u8, 2u8, 245u8,
+                    2u8, 253u8, 2u8, 3u8, 3u8, 10u8, 3u8, 17u8, 3u8, 23u8, 3u8, 29u8, 3u8, 37u8,
+                    3u8, 44u8, 3u8, 50u8, 3u8, 57u8, 3u8, 71u8, 3u8, 77u8, 3u8, 90u8, 3u8, 105u8,
+                    3u8, 112u8, 3u8, 121u8, 3u8, 126u8, 3u8, 138u8, 3u8, 146u8, 3u8, 152u8, 3u8,
+                    158u8, 3u8, 164u8, 3u8, 170u8, 3u8, 177u8, 3u8, 183u8, 3u8, 190u8, 3u8, 197u8,
+                    3u8, 203u8, 3u8, 208u8, 3u8, 215u8, 3u8, 224u8, 3u8, 231u8, 3u8, 238u8, 3u8,
+                    245u8, 3u8, 251u8, 3u8, 5u8, 4u8, 20u8, 4u8, 28u8, 4u8, 39u8, 4u8, 45u8, 4u8,
+                    53u8, 4u8, 59u8, 4u8, 68u8, 4u8, 74u8, 4u8, 80u8, 4u8, 88u8, 4u8, 98u8, 4u8,
+                    104u8, 4u8, 110u8, 4u8, 117u8, 4u8, 124u8, 4u8, 130u8, 4u8, 136u8, 4u8, 142u8,
+                    4u8, 147u8, 4u8, 152u8, 4u8, 158u8, 4u8, 168u8, 4u8, 177u8, 4u8, 186u8, 4u8,
+                    192u8, 4u8, 206u8, 4u8, 212u8, 4u8, 220u8, 4u8, 226u8, 4u8, 236u8, 4u8, 243u8,
+                    4u8, 250u8, 4u8, 0u8, 5u8, 6u8, 5u8, 14u8, 5u8, 21u8, 5u8, 27u8, 5u8, 33u8,
+                    5u8, 40u8, 5u8, 45u8, 5u8, 58u8, 5u8, 65u8, 5u8, 71u8, 5u8, 80u8, 5u8, 89u8,
+                    5u8, 98u8, 5u8, 104u8, 5u8, 111u8, 5u8, 121u8, 5u8, 128u8, 5u8, 134u8, 5u8,
+                    145u8, 5u8, 154u8, 5u8, 161u8, 5u8, 170u8, 5u8, 177u8, 5u8, 188u8, 5u8, 200u8,
+                    5u8, 209u8, 5u8, 221u8, 5u8, 232u8, 5u8, 237u8, 5u8, 0u8, 6u8, 21u8, 6u8, 35u8,
+                    6u8, 42u8, 6u8, 50u8, 6u8, 60u8, 6u8, 68u8, 6u8, 76u8, 6u8, 84u8, 6u8, 92u8,
+                    6u8, 100u8, 6u8, 108u8, 6u8, 116u8, 6u8, 123u8, 6u8, 131u8, 6u8, 139u8, 6u8,
+                    147u8, 6u8, 153u8, 6u8, 165u8, 6u8, 172u8, 6u8, 180u8, 6u8, 186u8, 6u8, 192u8,
+                    6u8, 199u8, 6u8, 214u8, 6u8, 225u8, 6u8, 231u8, 6u8, 236u8, 6u8, 241u8, 6u8,
+                    246u8, 6u8, 254u8, 6u8, 6u8, 7u8, 12u8, 7u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 109u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8, 101u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 97u8, 117u8, 123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    66u8, 84u8, 85u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8,
+                    45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 116u8, 123u8, 48u8, 125u8, 32u8, 194u8, 176u8,
+                    67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 108u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 115u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8,
+                    111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 116u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 122u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 122u8, 97u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 123u8, 48u8, 125u8, 32u8, 100u8, 195u8, 169u8, 99u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 108u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 99u8, 47u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8,
+                    32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 112u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 100u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 111u8, 116u8, 97u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8,
+                    123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8,
+                    32u8, 111u8, 122u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8,
+                    119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    70u8, 103u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8,
+                    48u8, 125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8,
+                    48u8, 125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 104u8, 108u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    80u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8,
+                    32u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 67u8, 86u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 195u8, 173u8, 116u8,
+                    101u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8, 100u8, 105u8,
+                    100u8, 111u8, 114u8, 101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 97u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8,
+                    97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8,
+                    48u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 108u8, 117u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 46u8, 32u8, 108u8, 46u8, 123u8, 48u8, 125u8, 32u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 47u8, 49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 112u8, 120u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 47u8,
+                    103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 47u8, 103u8, 97u8,
+                    108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 32u8, 101u8, 115u8, 99u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8,
+                    72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 111u8, 99u8, 104u8, 101u8, 115u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    101u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8,
+                    32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 97u8, 114u8, 116u8, 101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 194u8, 160u8, 37u8, 123u8, 48u8, 125u8,
+                    194u8, 160u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 32u8, 226u8, 128u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 122u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 116u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 47u8, 99u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 47u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 111u8, 115u8, 46u8, 123u8, 48u8, 125u8, 32u8, 108u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 98u8, 102u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8,
+                    116u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 116u8, 114u8,
+                    105u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8,
+                    114u8, 101u8, 118u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8,
+                    111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    83u8, 118u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8,
+                    137u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 116u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 97u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 100u8, 116u8, 97u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8,
+                    123u8, 48u8, 125u8, 32u8, 116u8, 104u8, 109u8, 32u8, 69u8, 69u8, 46u8, 32u8,
+                    85u8, 85u8, 46u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8, 48u8, 125u8, 32u8, 87u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    101u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 39u8, 0u8, 51u8, 0u8, 56u8, 0u8, 61u8, 0u8, 67u8, 0u8, 73u8, 0u8, 79u8,
+                    0u8, 84u8, 0u8, 89u8, 0u8, 96u8, 0u8, 102u8, 0u8, 111u8, 0u8, 117u8, 0u8,
+                    123u8, 0u8, 132u8, 0u8, 137u8, 0u8, 145u8, 0u8, 153u8, 0u8, 161u8, 0u8, 168u8,
+                    0u8, 176u8, 0u8, 184u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 123u8, 48u8, 125u8, 47u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 47u8,
+                    103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 47u8,
+                    103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 110u8, 111u8, 99u8, 104u8, 101u8, 123u8, 48u8, 125u8, 47u8,
+                    111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8,
+                    116u8, 114u8, 105u8, 109u8, 46u8, 123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8,
+                    125u8, 47u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 115u8, 101u8, 109u8, 46u8, 123u8, 48u8,
+                    125u8, 47u8, 97u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static ES_AR: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 12u8, 0u8, 16u8, 0u8, 22u8,
+                    0u8, 32u8, 0u8, 37u8, 0u8, 42u8, 0u8, 47u8, 0u8, 57u8, 0u8, 65u8, 0u8, 69u8,
+                    0u8, 100u8, 97u8, 121u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8, 110u8, 97u8,
+                    109u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 109u8,
+                    111u8, 110u8, 116u8, 104u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 111u8,
+                    105u8, 110u8, 116u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8, 112u8, 111u8,
+                    111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8, 110u8, 119u8,
+                    101u8, 101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 23u8, 0u8, 32u8, 0u8, 43u8,
+                    0u8, 52u8, 0u8, 58u8, 0u8, 67u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8, 100u8,
+                    0u8, 123u8, 48u8, 125u8, 32u8, 100u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8,
+                    111u8, 116u8, 97u8, 123u8, 48u8, 125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 195u8, 173u8, 116u8, 101u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 101u8, 100u8, 105u8, 100u8, 111u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 97u8, 46u8, 32u8, 108u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 110u8, 111u8, 99u8, 104u8, 101u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 111u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8,
+                    97u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 116u8, 97u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 101u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    195u8, 177u8, 111u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 62u8, 2u8,
+                    71u8, 2u8, 74u8, 2u8, 81u8, 2u8, 88u8, 2u8, 94u8, 2u8, 109u8, 2u8, 132u8, 2u8,
+                    139u8, 2u8, 146u8, 2u8, 154u8, 2u8, 163u8, 2u8, 171u8, 2u8, 176u8, 2u8, 180u8,
+                    2u8, 184u8, 2u8, 191u8, 2u8, 201u8, 2u8, 212u8, 2u8, 217u8, 2u8, 222u8, 2u8,
+                    232u8, 2u8, 236u8, 2u8, 240u8, 2u8, 249u8, 2u8, 253u8, 2u8, 3u8, 3u8, 8u8, 3u8,
+                    13u8, 3u8, 18u8, 3u8, 23u8, 3u8, 29u8, 3u8, 32u8, 3u8, 39u8, 3u8, 47u8, 3u8,
+                    58u8, 3u8, 66u8, 3u8, 80u8, 3u8, 89u8, 3u8, 98u8, 3u8, 107u8, 3u8, 125u8, 3u8,
+                    135u8, 3u8, 143u8, 3u8, 156u8, 3u8, 187u8, 3u8, 191u8, 3u8, 195u8, 3u8, 201u8,
+                    3u8, 212u8, 3u8, 222u8, 3u8, 227u8, 3u8, 250u8, 3u8, 13u8, 4u8, 18u8, 4u8,
+                    21u8, 4u8, 28u8, 4u8, 36u8, 4u8, 45u8, 4u8, 54u8, 4u8, 64u8, 4u8, 73u8, 4u8,
+                    81u8, 4u8, 86u8, 4u8, 102u8, 4u8, 125u8, 4u8, 134u8, 4u8, 144u8, 4u8, 155u8,
+                    4u8, 159u8, 4u8, 174u8, 4u8, 198u8, 4u8, 211u8, 4u8, 228u8, 4u8, 239u8, 4u8,
+                    247u8, 4u8, 0u8, 5u8, 33u8, 5u8, 43u8, 5u8, 53u8, 5u8, 68u8, 5u8, 87u8, 5u8,
+                    98u8, 5u8, 107u8, 5u8, 113u8, 5u8, 117u8, 5u8, 122u8, 5u8, 131u8, 5u8, 141u8,
+                    5u8, 154u8, 5u8, 160u8, 5u8, 172u8, 5u8, 177u8, 5u8, 186u8, 5u8, 190u8, 5u8,
+                    193u8, 5u8, 198u8, 5u8, 203u8, 5u8, 213u8, 5u8, 219u8, 5u8, 223u8, 5u8, 235u8,
+                    5u8, 247u8, 5u8, 253u8, 5u8, 4u8, 6u8, 12u8, 6u8, 21u8, 6u8, 29u8, 6u8, 38u8,
+                    6u8, 43u8, 6u8, 47u8, 6u8, 60u8, 6u8, 71u8, 6u8, 76u8, 6u8, 96u8, 6u8, 110u8,
+                    6u8, 115u8, 6u8, 120u8, 6u8, 131u8, 6u8, 147u8, 6u8, 174u8, 6u8, 179u8, 6u8,
+                    193u8, 6u8, 200u8, 6u8, 206u8, 6u8, 213u8, 6u8, 223u8, 6u8, 228u8, 6u8, 231u8,
+                    6u8, 234u8, 6u8, 237u8, 6u8, 242u8, 6u8, 248u8, 6u8, 253u8, 6u8, 8u8, 7u8,
+                    20u8, 7u8, 27u8, 7u8, 34u8, 7u8, 38u8, 7u8, 54u8, 7u8, 64u8, 7u8, 76u8, 7u8,
+                    93u8, 7u8, 104u8, 7u8, 115u8, 7u8, 131u8, 7u8, 143u8, 7u8, 154u8, 7u8, 165u8,
+                    7u8, 174u8, 7u8, 179u8, 7u8, 182u8, 7u8, 192u8, 7u8, 200u8, 7u8, 207u8, 7u8,
+                    215u8, 7u8, 220u8, 7u8, 228u8, 7u8, 233u8, 7u8, 236u8, 7u8, 241u8, 7u8, 245u8,
+                    7u8, 249u8, 7u8, 254u8, 7u8, 2u8, 8u8, 6u8, 8u8, 97u8, 99u8, 114u8, 101u8,
+                    97u8, 99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 30u8, 0u8,
+                    40u8, 0u8, 46u8, 0u8, 53u8, 0u8, 60u8, 0u8, 67u8, 0u8, 72u8, 0u8, 80u8, 0u8,
+                    85u8, 0u8, 92u8, 0u8, 104u8, 0u8, 115u8, 0u8, 121u8, 0u8, 129u8, 0u8, 136u8,
+                    0u8, 146u8, 0u8, 152u8, 0u8, 158u8, 0u8, 165u8, 0u8, 171u8, 0u8, 177u8, 0u8,
+                    182u8, 0u8, 193u8, 0u8, 205u8, 0u8, 212u8, 0u8, 220u8, 0u8, 228u8, 0u8, 236u8,
+                    0u8, 244u8, 0u8, 251u8, 0u8, 3u8, 1u8, 11u8, 1u8, 19u8, 1u8, 33u8, 1u8, 45u8,
+                    1u8, 54u8, 1u8, 60u8, 1u8, 67u8, 1u8, 76u8, 1u8, 82u8, 1u8, 88u8, 1u8, 93u8,
+                    1u8, 100u8, 1u8, 115u8, 1u8, 120u8, 1u8, 128u8, 1u8, 135u8, 1u8, 144u8, 1u8,
+                    153u8, 1u8, 163u8, 1u8, 171u8, 1u8, 179u8, 1u8, 185u8, 1u8, 191u8, 1u8, 198u8,
+                    1u8, 205u8, 1u8, 212u8, 1u8, 221u8, 1u8, 235u8, 1u8, 248u8, 1u8, 254u8, 1u8,
+                    6u8, 2u8, 18u8, 2u8, 25u8, 2u8, 30u8, 2u8, 37u8, 2u8, 49u8, 2u8, 58u8, 2u8,
+                    63u8, 2u8, 69u8, 2u8, 75u8, 2u8, 82u8, 2u8, 88u8, 2u8, 94u8, 2u8, 99u8, 2u8,
+                    107u8, 2u8, 113u8, 2u8, 119u8, 2u8, 126u8, 2u8, 133u8, 2u8, 139u8, 2u8, 145u8,
+                    2u8, 150u8, 2u8, 156u8, 2u8, 164u8, 2u8, 174u8, 2u8, 187u8, 2u8, 198u8, 2u8,
+                    203u8, 2u8, 209u8, 2u8, 218u8, 2u8, 223u8, 2u8, 235u8, 2u8, 241u8, 2u8, 247u8,
+                    2u8, 255u8, 2u8, 5u8, 3u8, 12u8, 3u8, 19u8, 3u8, 25u8, 3u8, 31u8, 3u8, 39u8,
+                    3u8, 46u8, 3u8, 52u8, 3u8, 59u8, 3u8, 73u8, 3u8, 79u8, 3u8, 92u8, 3u8, 107u8,
+                    3u8, 114u8, 3u8, 124u8, 3u8, 129u8, 3u8, 141u8, 3u8, 149u8, 3u8, 155u8, 3u8,
+                    161u8, 3u8, 167u8, 3u8, 173u8, 3u8, 180u8, 3u8, 186u8, 3u8, 193u8, 3u8, 200u8,
+                    3u8, 206u8, 3u8, 211u8, 3u8, 218u8, 3u8, 227u8, 3u8, 234u8, 3u8, 241u8, 3u8,
+                    248u8, 3u8, 254u8, 3u8, 8u8, 4u8, 20u8, 4u8, 28u8, 4u8, 39u8, 4u8, 45u8, 4u8,
+                    53u8, 4u8, 59u8, 4u8, 68u8, 4u8, 74u8, 4u8, 80u8, 4u8, 88u8, 4u8, 98u8, 4u8,
+                    104u8, 4u8, 110u8, 4u8, 117u8, 4u8, 124u8, 4u8, 131u8, 4u8, 137u8, 4u8, 143u8,
+                    4u8, 150u8, 4u8, 155u8, 4u8, 161u8, 4u8, 171u8, 4u8, 180u8, 4u8, 189u8, 4u8,
+                    195u8, 4u8, 209u8, 4u8, 215u8, 4u8, 222u8, 4u8, 228u8, 4u8, 239u8, 4u8, 246u8,
+                    4u8, 254u8, 4u8, 4u8, 5u8, 10u8, 5u8, 18u8, 5u8, 25u8, 5u8, 31u8, 5u8, 37u8,
+                    5u8, 44u8, 5u8, 50u8, 5u8, 63u8, 5u8, 70u8, 5u8, 76u8, 5u8, 85u8, 5u8, 94u8,
+                    5u8, 103u8, 5u8, 109u8, 5u8, 116u8, 5u8, 126u8, 5u8, 133u8, 5u8, 139u8, 5u8,
+                    150u8, 5u8, 159u8, 5u8, 166u8, 5u8, 175u8, 5u8, 182u8, 5u8, 193u8, 5u8, 205u8,
+                    5u8, 214u8, 5u8, 226u8, 5u8, 237u8, 5u8, 245u8, 5u8, 8u8, 6u8, 29u8, 6u8, 43u8,
+                    6u8, 50u8, 6u8, 58u8, 6u8, 68u8, 6u8, 76u8, 6u8, 84u8, 6u8, 92u8, 6u8, 100u8,
+                    6u8, 108u8, 6u8, 116u8, 6u8, 124u8, 6u8, 131u8, 6u8, 139u8, 6u8, 147u8, 6u8,
+                    155u8, 6u8, 161u8, 6u8, 173u8, 6u8, 182u8, 6u8, 192u8, 6u8, 198u8, 6u8, 204u8,
+                    6u8, 211u8, 6u8, 226u8, 6u8, 237u8, 6u8, 244u8, 6u8, 249u8, 6u8, 254u8, 6u8,
+                    3u8, 7u8, 11u8, 7u8, 20u8, 7u8, 26u8, 7u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 109u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8, 115u8, 101u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 117u8, 97u8, 123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    66u8, 84u8, 85u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8,
+                    45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 121u8, 116u8, 101u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 99u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 115u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 105u8,
+                    110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 116u8, 122u8, 97u8, 46u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 122u8, 97u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 68u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 100u8, 100u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 195u8, 169u8, 99u8, 46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 47u8, 112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8,
+                    115u8, 112u8, 110u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 112u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 100u8,
+                    114u8, 123u8, 48u8, 125u8, 32u8, 103u8, 111u8, 116u8, 97u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8,
+                    123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8,
+                    32u8, 111u8, 122u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8,
+                    119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8,
+                    48u8, 125u8, 32u8, 103u8, 114u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 108u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8,
+                    97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8, 32u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 105u8,
+                    110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 195u8, 173u8, 116u8, 101u8,
+                    109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 101u8, 100u8, 105u8, 100u8,
+                    111u8, 114u8, 101u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8,
+                    108u8, 123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8,
+                    104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8,
+                    32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 108u8, 117u8, 122u8, 123u8, 48u8, 125u8, 32u8,
+                    97u8, 97u8, 46u8, 32u8, 108u8, 46u8, 123u8, 48u8, 125u8, 32u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 47u8, 49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 112u8, 120u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 47u8,
+                    103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 105u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 47u8, 104u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 32u8, 101u8, 115u8, 99u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8,
+                    47u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 72u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 111u8, 99u8, 104u8, 101u8, 115u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 101u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8,
+                    111u8, 122u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 112u8, 97u8, 114u8, 116u8, 101u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 194u8, 160u8,
+                    37u8, 123u8, 48u8, 125u8, 194u8, 160u8, 226u8, 128u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 122u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 120u8, 47u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    120u8, 47u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 111u8,
+                    115u8, 46u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 32u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8, 125u8, 32u8,
+                    113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 105u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 116u8, 114u8, 105u8, 109u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8, 118u8, 123u8, 48u8,
+                    125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8,
+                    103u8, 46u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8,
+                    91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8,
+                    104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 118u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 116u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 97u8, 115u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 100u8, 116u8, 97u8, 115u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8, 116u8, 104u8, 109u8, 32u8,
+                    69u8, 69u8, 46u8, 32u8, 85u8, 85u8, 46u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8,
+                    48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 109u8, 115u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 121u8, 100u8, 123u8, 48u8, 125u8, 32u8, 97u8, 195u8, 177u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 27u8, 0u8, 33u8,
+                    0u8, 40u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8, 0u8, 68u8, 0u8, 74u8, 0u8, 80u8,
+                    0u8, 85u8, 0u8, 90u8, 0u8, 97u8, 0u8, 103u8, 0u8, 112u8, 0u8, 118u8, 0u8,
+                    124u8, 0u8, 133u8, 0u8, 141u8, 0u8, 149u8, 0u8, 157u8, 0u8, 165u8, 0u8, 172u8,
+                    0u8, 180u8, 0u8, 188u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 46u8, 123u8, 48u8, 125u8, 47u8,
+                    102u8, 116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 46u8,
+                    123u8, 48u8, 125u8, 47u8, 110u8, 111u8, 99u8, 104u8, 101u8, 123u8, 48u8, 125u8,
+                    47u8, 111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8,
+                    47u8, 116u8, 114u8, 105u8, 109u8, 46u8, 123u8, 48u8, 125u8, 47u8, 115u8, 101u8,
+                    103u8, 46u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 115u8,
+                    101u8, 109u8, 46u8, 123u8, 48u8, 125u8, 47u8, 97u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static FIL: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 30u8, 0u8, 36u8, 0u8,
+                    42u8, 0u8, 46u8, 0u8, 56u8, 0u8, 71u8, 0u8, 84u8, 0u8, 90u8, 0u8, 99u8, 0u8,
+                    104u8, 0u8, 111u8, 0u8, 121u8, 0u8, 125u8, 0u8, 97u8, 114u8, 99u8, 45u8, 109u8,
+                    105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 99u8, 117u8, 112u8, 45u8, 109u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 103u8, 97u8, 108u8,
+                    108u8, 111u8, 110u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 103u8, 104u8,
+                    116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 110u8,
+                    97u8, 117u8, 116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8,
+                    112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 111u8, 117u8, 110u8, 100u8, 113u8, 117u8,
+                    97u8, 114u8, 116u8, 101u8, 114u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8,
+                    116u8, 105u8, 111u8, 110u8, 119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8,
+                    100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 26u8, 0u8, 33u8, 0u8,
+                    40u8, 0u8, 48u8, 0u8, 54u8, 0u8, 61u8, 0u8, 68u8, 0u8, 74u8, 0u8, 80u8, 0u8,
+                    86u8, 0u8, 93u8, 0u8, 100u8, 0u8, 110u8, 0u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    114u8, 99u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 97u8, 114u8, 99u8,
+                    115u8, 101u8, 99u8, 123u8, 48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 101u8, 103u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 32u8, 108u8,
+                    121u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 113u8, 116u8, 114u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8, 118u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 105u8, 110u8, 103u8, 103u8, 111u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 33u8, 0u8,
+                    46u8, 0u8, 52u8, 0u8, 59u8, 0u8, 66u8, 0u8, 73u8, 0u8, 78u8, 0u8, 84u8, 0u8,
+                    91u8, 0u8, 98u8, 0u8, 110u8, 0u8, 121u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8,
+                    0u8, 152u8, 0u8, 158u8, 0u8, 164u8, 0u8, 170u8, 0u8, 176u8, 0u8, 182u8, 0u8,
+                    191u8, 0u8, 202u8, 0u8, 214u8, 0u8, 219u8, 0u8, 227u8, 0u8, 235u8, 0u8, 243u8,
+                    0u8, 251u8, 0u8, 2u8, 1u8, 10u8, 1u8, 18u8, 1u8, 23u8, 1u8, 37u8, 1u8, 49u8,
+                    1u8, 58u8, 1u8, 64u8, 1u8, 72u8, 1u8, 79u8, 1u8, 85u8, 1u8, 91u8, 1u8, 101u8,
+                    1u8, 111u8, 1u8, 125u8, 1u8, 135u8, 1u8, 143u8, 1u8, 150u8, 1u8, 161u8, 1u8,
+                    170u8, 1u8, 179u8, 1u8, 187u8, 1u8, 198u8, 1u8, 204u8, 1u8, 210u8, 1u8, 216u8,
+                    1u8, 221u8, 1u8, 228u8, 1u8, 237u8, 1u8, 251u8, 1u8, 9u8, 2u8, 16u8, 2u8, 22u8,
+                    2u8, 30u8, 2u8, 42u8, 2u8, 49u8, 2u8, 54u8, 2u8, 64u8, 2u8, 76u8, 2u8, 85u8,
+                    2u8, 90u8, 2u8, 96u8, 2u8, 102u8, 2u8, 109u8, 2u8, 115u8, 2u8, 124u8, 2u8,
+                    129u8, 2u8, 135u8, 2u8, 141u8, 2u8, 147u8, 2u8, 154u8, 2u8, 159u8, 2u8, 165u8,
+                    2u8, 171u8, 2u8, 182u8, 2u8, 188u8, 2u8, 196u8, 2u8, 204u8, 2u8, 214u8, 2u8,
+                    225u8, 2u8, 230u8, 2u8, 236u8, 2u8, 245u8, 2u8, 251u8, 2u8, 7u8, 3u8, 13u8,
+                    3u8, 19u8, 3u8, 27u8, 3u8, 33u8, 3u8, 42u8, 3u8, 49u8, 3u8, 55u8, 3u8, 61u8,
+                    3u8, 68u8, 3u8, 75u8, 3u8, 81u8, 3u8, 88u8, 3u8, 101u8, 3u8, 107u8, 3u8, 120u8,
+                    3u8, 135u8, 3u8, 144u8, 3u8, 153u8, 3u8, 158u8, 3u8, 172u8, 3u8, 180u8, 3u8,
+                    186u8, 3u8, 192u8, 3u8, 198u8, 3u8, 204u8, 3u8, 211u8, 3u8, 217u8, 3u8, 224u8,
+                    3u8, 230u8, 3u8, 236u8, 3u8, 241u8, 3u8, 248u8, 3u8, 1u8, 4u8, 8u8, 4u8, 15u8,
+                    4u8, 22u8, 4u8, 28u8, 4u8, 38u8, 4u8, 50u8, 4u8, 57u8, 4u8, 64u8, 4u8, 70u8,
+                    4u8, 76u8, 4u8, 82u8, 4u8, 91u8, 4u8, 97u8, 4u8, 103u8, 4u8, 112u8, 4u8, 122u8,
+                    4u8, 128u8, 4u8, 134u8, 4u8, 142u8, 4u8, 149u8, 4u8, 158u8, 4u8, 164u8, 4u8,
+                    170u8, 4u8, 180u8, 4u8, 185u8, 4u8, 194u8, 4u8, 202u8, 4u8, 211u8, 4u8, 220u8,
+                    4u8, 226u8, 4u8, 240u8, 4u8, 246u8, 4u8, 254u8, 4u8, 7u8, 5u8, 17u8, 5u8, 24u8,
+                    5u8, 31u8, 5u8, 37u8, 5u8, 41u8, 5u8, 47u8, 5u8, 53u8, 5u8, 59u8, 5u8, 68u8,
+                    5u8, 77u8, 5u8, 83u8, 5u8, 96u8, 5u8, 106u8, 5u8, 112u8, 5u8, 120u8, 5u8,
+                    127u8, 5u8, 133u8, 5u8, 140u8, 5u8, 147u8, 5u8, 159u8, 5u8, 166u8, 5u8, 172u8,
+                    5u8, 183u8, 5u8, 191u8, 5u8, 198u8, 5u8, 207u8, 5u8, 217u8, 5u8, 228u8, 5u8,
+                    240u8, 5u8, 249u8, 5u8, 5u8, 6u8, 16u8, 6u8, 24u8, 6u8, 43u8, 6u8, 64u8, 6u8,
+                    78u8, 6u8, 85u8, 6u8, 91u8, 6u8, 101u8, 6u8, 109u8, 6u8, 117u8, 6u8, 125u8,
+                    6u8, 133u8, 6u8, 142u8, 6u8, 150u8, 6u8, 158u8, 6u8, 165u8, 6u8, 174u8, 6u8,
+                    182u8, 6u8, 190u8, 6u8, 196u8, 6u8, 208u8, 6u8, 216u8, 6u8, 223u8, 6u8, 229u8,
+                    6u8, 235u8, 6u8, 242u8, 6u8, 254u8, 6u8, 9u8, 7u8, 15u8, 7u8, 20u8, 7u8, 25u8,
+                    7u8, 30u8, 7u8, 38u8, 7u8, 51u8, 7u8, 60u8, 7u8, 123u8, 48u8, 125u8, 32u8,
+                    97u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 97u8,
+                    114u8, 99u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8,
+                    97u8, 114u8, 99u8, 115u8, 101u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 117u8,
+                    123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8,
+                    48u8, 125u8, 123u8, 48u8, 125u8, 32u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 105u8, 116u8, 123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8, 98u8, 121u8, 116u8, 101u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8,
+                    108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8,
+                    125u8, 32u8, 75u8, 68u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 76u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 105u8, 103u8, 108u8, 111u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 67u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 116u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 117u8,
+                    112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 117u8,
+                    112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8,
+                    32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 114u8, 97u8, 119u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 100u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 100u8, 101u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 115u8, 116u8, 115u8, 112u8, 110u8, 32u8, 73u8, 109u8, 112u8,
+                    123u8, 48u8, 125u8, 32u8, 116u8, 117u8, 108u8, 100u8, 111u8, 107u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 32u8, 102u8,
+                    108u8, 123u8, 48u8, 125u8, 32u8, 112u8, 97u8, 116u8, 97u8, 107u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 82u8, 226u8,
+                    138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8,
+                    101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 32u8,
+                    70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 67u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8,
+                    117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 102u8,
+                    117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    97u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8,
+                    32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 117u8, 116u8, 105u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 76u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 111u8,
+                    114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8, 125u8,
+                    32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 105u8, 116u8, 101u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 194u8,
+                    176u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8,
+                    103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 112u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 97u8, 32u8, 108u8, 121u8, 123u8, 48u8, 125u8, 32u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 110u8, 97u8, 32u8, 76u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8,
+                    109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8,
+                    109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 98u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 109u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 111u8,
+                    108u8, 47u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8,
+                    119u8, 97u8, 110u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 110u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8,
+                    139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 98u8, 105u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 103u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8, 123u8,
+                    48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 111u8,
+                    122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 112u8, 99u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 97u8, 114u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8,
+                    123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8,
+                    32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 117u8, 114u8, 111u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 110u8, 97u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 115u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8,
+                    123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8, 125u8, 32u8,
+                    113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 73u8, 109u8, 112u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 114u8, 115u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 194u8,
+                    176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 114u8, 101u8, 118u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 101u8, 103u8, 46u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8,
+                    111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 32u8, 83u8, 118u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8,
+                    152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 113u8,
+                    32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8, 113u8, 32u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 98u8, 115u8, 112u8, 123u8, 48u8, 125u8,
+                    32u8, 116u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 123u8,
+                    48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 86u8, 123u8, 48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 108u8, 105u8,
+                    110u8, 103u8, 103u8, 111u8, 123u8, 48u8, 125u8, 32u8, 110u8, 97u8, 32u8, 121u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 116u8, 97u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 29u8, 0u8, 35u8,
+                    0u8, 42u8, 0u8, 54u8, 0u8, 59u8, 0u8, 72u8, 0u8, 78u8, 0u8, 84u8, 0u8, 90u8,
+                    0u8, 95u8, 0u8, 100u8, 0u8, 107u8, 0u8, 116u8, 0u8, 124u8, 0u8, 130u8, 0u8,
+                    136u8, 0u8, 141u8, 0u8, 146u8, 0u8, 159u8, 0u8, 172u8, 0u8, 180u8, 0u8, 192u8,
+                    0u8, 200u8, 0u8, 210u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 97u8, 114u8, 97u8, 119u8, 123u8, 48u8,
+                    125u8, 47u8, 102u8, 116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 100u8, 97u8,
+                    32u8, 111u8, 114u8, 97u8, 115u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 123u8,
+                    48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8, 125u8,
+                    47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 98u8, 117u8, 119u8, 97u8,
+                    110u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 98u8, 105u8, 123u8, 48u8, 125u8,
+                    47u8, 111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8,
+                    47u8, 113u8, 123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    97u8, 100u8, 97u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 97u8, 100u8, 97u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 100u8,
+                    97u8, 32u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 108u8, 105u8, 110u8, 103u8, 103u8, 111u8,
+                    123u8, 48u8, 125u8, 47u8, 116u8, 97u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static FR: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 9u8, 0u8, 15u8, 0u8, 20u8,
+                    0u8, 25u8, 0u8, 30u8, 0u8, 35u8, 0u8, 42u8, 0u8, 50u8, 0u8, 98u8, 97u8, 114u8,
+                    99u8, 117u8, 112u8, 100u8, 111u8, 116u8, 106u8, 105u8, 103u8, 103u8, 101u8,
+                    114u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 105u8, 110u8, 99u8, 104u8,
+                    112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 111u8, 105u8, 110u8, 116u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    45u8, 117u8, 115u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 16u8, 0u8, 23u8, 0u8, 33u8, 0u8, 41u8,
+                    0u8, 52u8, 0u8, 59u8, 0u8, 72u8, 0u8, 82u8, 0u8, 94u8, 0u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 116u8, 97u8, 115u8, 115u8,
+                    101u8, 123u8, 48u8, 125u8, 194u8, 160u8, 112u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 117u8, 105u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 105u8, 110u8,
+                    99u8, 195u8, 169u8, 101u8, 123u8, 48u8, 125u8, 194u8, 160u8, 112u8, 120u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 32u8, 116u8, 121u8, 112u8, 111u8,
+                    103u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 98u8, 105u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 32u8, 85u8, 83u8,
+                    123u8, 48u8, 125u8, 194u8, 160u8, 97u8, 110u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 17u8, 0u8, 24u8, 0u8, 30u8, 0u8,
+                    36u8, 0u8, 44u8, 0u8, 53u8, 0u8, 61u8, 0u8, 70u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 103u8, 0u8, 115u8, 0u8, 126u8, 0u8, 134u8, 0u8, 141u8, 0u8, 150u8,
+                    0u8, 160u8, 0u8, 166u8, 0u8, 174u8, 0u8, 183u8, 0u8, 189u8, 0u8, 197u8, 0u8,
+                    203u8, 0u8, 214u8, 0u8, 226u8, 0u8, 233u8, 0u8, 243u8, 0u8, 253u8, 0u8, 7u8,
+                    1u8, 17u8, 1u8, 26u8, 1u8, 36u8, 1u8, 46u8, 1u8, 56u8, 1u8, 70u8, 1u8, 82u8,
+                    1u8, 88u8, 1u8, 96u8, 1u8, 103u8, 1u8, 112u8, 1u8, 118u8, 1u8, 126u8, 1u8,
+                    131u8, 1u8, 143u8, 1u8, 160u8, 1u8, 166u8, 1u8, 175u8, 1u8, 184u8, 1u8, 193u8,
+                    1u8, 200u8, 1u8, 212u8, 1u8, 222u8, 1u8, 230u8, 1u8, 238u8, 1u8, 245u8, 1u8,
+                    254u8, 1u8, 5u8, 2u8, 11u8, 2u8, 20u8, 2u8, 36u8, 2u8, 50u8, 2u8, 58u8, 2u8,
+                    66u8, 2u8, 74u8, 2u8, 86u8, 2u8, 93u8, 2u8, 106u8, 2u8, 113u8, 2u8, 125u8, 2u8,
+                    134u8, 2u8, 139u8, 2u8, 149u8, 2u8, 157u8, 2u8, 166u8, 2u8, 174u8, 2u8, 184u8,
+                    2u8, 191u8, 2u8, 197u8, 2u8, 205u8, 2u8, 213u8, 2u8, 222u8, 2u8, 229u8, 2u8,
+                    237u8, 2u8, 245u8, 2u8, 252u8, 2u8, 4u8, 3u8, 14u8, 3u8, 23u8, 3u8, 34u8, 3u8,
+                    45u8, 3u8, 52u8, 3u8, 60u8, 3u8, 69u8, 3u8, 76u8, 3u8, 88u8, 3u8, 98u8, 3u8,
+                    106u8, 3u8, 116u8, 3u8, 124u8, 3u8, 133u8, 3u8, 142u8, 3u8, 150u8, 3u8, 158u8,
+                    3u8, 168u8, 3u8, 177u8, 3u8, 185u8, 3u8, 194u8, 3u8, 208u8, 3u8, 216u8, 3u8,
+                    229u8, 3u8, 244u8, 3u8, 0u8, 4u8, 8u8, 4u8, 15u8, 4u8, 31u8, 4u8, 41u8, 4u8,
+                    47u8, 4u8, 55u8, 4u8, 65u8, 4u8, 73u8, 4u8, 82u8, 4u8, 90u8, 4u8, 99u8, 4u8,
+                    107u8, 4u8, 115u8, 4u8, 122u8, 4u8, 131u8, 4u8, 142u8, 4u8, 151u8, 4u8, 160u8,
+                    4u8, 169u8, 4u8, 177u8, 4u8, 189u8, 4u8, 208u8, 4u8, 218u8, 4u8, 227u8, 4u8,
+                    235u8, 4u8, 245u8, 4u8, 253u8, 4u8, 8u8, 5u8, 14u8, 5u8, 22u8, 5u8, 32u8, 5u8,
+                    44u8, 5u8, 52u8, 5u8, 60u8, 5u8, 68u8, 5u8, 77u8, 5u8, 85u8, 5u8, 93u8, 5u8,
+                    101u8, 5u8, 110u8, 5u8, 117u8, 5u8, 128u8, 5u8, 137u8, 5u8, 146u8, 5u8, 157u8,
+                    5u8, 165u8, 5u8, 179u8, 5u8, 187u8, 5u8, 199u8, 5u8, 207u8, 5u8, 218u8, 5u8,
+                    227u8, 5u8, 234u8, 5u8, 241u8, 5u8, 246u8, 5u8, 255u8, 5u8, 8u8, 6u8, 16u8,
+                    6u8, 24u8, 6u8, 36u8, 6u8, 43u8, 6u8, 56u8, 6u8, 63u8, 6u8, 69u8, 6u8, 78u8,
+                    6u8, 87u8, 6u8, 101u8, 6u8, 109u8, 6u8, 118u8, 6u8, 132u8, 6u8, 145u8, 6u8,
+                    151u8, 6u8, 162u8, 6u8, 171u8, 6u8, 180u8, 6u8, 189u8, 6u8, 197u8, 6u8, 208u8,
+                    6u8, 220u8, 6u8, 229u8, 6u8, 241u8, 6u8, 252u8, 6u8, 3u8, 7u8, 22u8, 7u8, 43u8,
+                    7u8, 57u8, 7u8, 64u8, 7u8, 70u8, 7u8, 80u8, 7u8, 90u8, 7u8, 100u8, 7u8, 110u8,
+                    7u8, 120u8, 7u8, 130u8, 7u8, 140u8, 7u8, 150u8, 7u8, 159u8, 7u8, 169u8, 7u8,
+                    179u8, 7u8, 187u8, 7u8, 193u8, 7u8, 205u8, 7u8, 217u8, 7u8, 229u8, 7u8, 237u8,
+                    7u8, 245u8, 7u8, 252u8, 7u8, 9u8, 8u8, 20u8, 8u8, 33u8, 8u8, 40u8, 8u8, 47u8,
+                    8u8, 54u8, 8u8, 62u8, 8u8, 72u8, 8u8, 80u8, 8u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 97u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 112u8,
+                    105u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 65u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 179u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 117u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 115u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 98u8, 98u8, 108u8, 123u8, 48u8, 125u8,
+                    32u8, 66u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 113u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 98u8, 105u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 98u8, 117u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 111u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 116u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 108u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 112u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    112u8, 111u8, 194u8, 179u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 105u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 121u8, 100u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 97u8, 115u8, 115u8, 101u8, 115u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 109u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 68u8,
+                    97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 106u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 195u8, 169u8, 99u8, 46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 108u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 100u8, 109u8, 123u8, 48u8, 125u8, 194u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 99u8, 46u8, 32u8, 195u8, 160u8, 32u8, 100u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 46u8, 32u8, 195u8, 160u8, 32u8, 100u8,
+                    46u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 47u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 116u8, 47u8, 112u8, 111u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8,
+                    32u8, 100u8, 114u8, 123u8, 48u8, 125u8, 32u8, 103u8, 116u8, 101u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 100u8, 111u8, 117u8, 110u8, 97u8, 109u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8,
+                    125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 101u8, 86u8, 123u8, 48u8, 125u8, 194u8, 160u8, 101u8, 109u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 105u8, 109u8, 112u8,
+                    46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 108u8, 32u8, 111u8,
+                    122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 105u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8,
+                    114u8, 123u8, 48u8, 125u8, 194u8, 160u8, 102u8, 111u8, 114u8, 99u8, 101u8,
+                    194u8, 160u8, 103u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 98u8, 105u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 71u8, 111u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 87u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 97u8, 105u8, 110u8, 115u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 103u8, 123u8, 48u8, 125u8, 32u8, 71u8, 121u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 104u8, 97u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 104u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    104u8, 80u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 72u8, 122u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 99u8, 104u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 104u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 111u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 105u8,
+                    116u8, 101u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8,
+                    103u8, 101u8, 114u8, 115u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 74u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 99u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 75u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 98u8, 105u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 111u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8,
+                    102u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 72u8, 122u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 74u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 107u8, 109u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8,
+                    109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 80u8, 97u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 87u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8,
+                    104u8, 47u8, 49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 110u8, 100u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 107u8,
+                    117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 117u8, 109u8, 105u8, 195u8, 168u8, 114u8, 101u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 108u8, 47u8, 49u8, 48u8,
+                    48u8, 226u8, 128u8, 175u8, 107u8, 109u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 108u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 108u8, 120u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 77u8, 98u8, 105u8, 116u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 77u8, 111u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 77u8, 72u8,
+                    122u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 77u8, 108u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 194u8, 160u8,
+                    77u8, 112u8, 120u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 77u8, 87u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8,
+                    47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 206u8,
+                    188u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 206u8, 188u8, 109u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 206u8, 188u8, 115u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 109u8, 105u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 109u8, 105u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 109u8, 105u8, 47u8, 103u8, 97u8, 108u8, 226u8, 128u8, 175u8,
+                    105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8,
+                    105u8, 47u8, 104u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 65u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 109u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    109u8, 103u8, 47u8, 100u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 109u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 109u8, 109u8, 72u8, 103u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 109u8, 109u8, 111u8, 108u8, 47u8, 108u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 109u8, 115u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    109u8, 87u8, 123u8, 48u8, 125u8, 194u8, 160u8, 109u8, 105u8, 110u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 111u8, 108u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 109u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    110u8, 109u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 110u8, 115u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 110u8, 109u8, 105u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 78u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 78u8,
+                    226u8, 139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 117u8, 105u8,
+                    116u8, 115u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 100u8, 101u8, 32u8, 72u8, 103u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 206u8, 169u8, 123u8, 48u8, 125u8,
+                    32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 111u8, 122u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 111u8, 122u8, 226u8, 128u8, 175u8, 116u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 112u8, 99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    112u8, 97u8, 114u8, 116u8, 115u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8,
+                    125u8, 194u8, 160u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 37u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    80u8, 111u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 105u8, 110u8, 99u8, 195u8, 169u8, 101u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 101u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 120u8, 47u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    120u8, 47u8, 112u8, 111u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 115u8, 32u8,
+                    116u8, 121u8, 112u8, 111u8, 103u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 108u8, 98u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 108u8, 98u8,
+                    102u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 108u8, 98u8, 102u8, 226u8,
+                    139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 108u8,
+                    98u8, 47u8, 112u8, 111u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 114u8, 105u8, 109u8, 46u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 116u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 115u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8,
+                    111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 32u8, 83u8, 118u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    112u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8,
+                    111u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 99u8, 46u8, 32u8, 195u8, 160u8, 32u8, 115u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 46u8, 32u8, 195u8, 160u8, 32u8, 99u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 105u8, 116u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 84u8, 111u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 115u8, 32u8,
+                    85u8, 83u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 104u8, 226u8, 128u8,
+                    175u8, 116u8, 110u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 86u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 115u8, 101u8, 109u8, 46u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 121u8, 100u8, 123u8, 48u8, 125u8, 194u8, 160u8, 97u8,
+                    110u8, 115u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 39u8, 0u8, 51u8, 0u8, 56u8, 0u8, 61u8, 0u8, 67u8, 0u8, 73u8, 0u8, 79u8,
+                    0u8, 84u8, 0u8, 89u8, 0u8, 96u8, 0u8, 102u8, 0u8, 110u8, 0u8, 116u8, 0u8,
+                    122u8, 0u8, 131u8, 0u8, 136u8, 0u8, 144u8, 0u8, 152u8, 0u8, 160u8, 0u8, 167u8,
+                    0u8, 175u8, 0u8, 183u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 106u8, 123u8, 48u8, 125u8, 47u8, 112u8,
+                    105u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 108u8, 32u8, 105u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 47u8,
+                    103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8, 47u8, 112u8, 111u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 110u8, 117u8, 105u8, 116u8, 123u8, 48u8, 125u8, 47u8, 111u8,
+                    122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8, 116u8,
+                    114u8, 105u8, 109u8, 46u8, 123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8, 125u8,
+                    47u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 112u8, 111u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 115u8, 101u8, 109u8, 46u8, 123u8, 48u8,
+                    125u8, 47u8, 97u8, 110u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static JA: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 27u8, 0u8,
+                    34u8, 0u8, 40u8, 0u8, 47u8, 0u8, 54u8, 0u8, 61u8, 0u8, 66u8, 0u8, 72u8, 0u8,
+                    79u8, 0u8, 86u8, 0u8, 98u8, 0u8, 105u8, 0u8, 111u8, 0u8, 119u8, 0u8, 126u8,
+                    0u8, 136u8, 0u8, 142u8, 0u8, 148u8, 0u8, 154u8, 0u8, 160u8, 0u8, 166u8, 0u8,
+                    176u8, 0u8, 187u8, 0u8, 194u8, 0u8, 199u8, 0u8, 207u8, 0u8, 215u8, 0u8, 223u8,
+                    0u8, 231u8, 0u8, 238u8, 0u8, 246u8, 0u8, 254u8, 0u8, 20u8, 1u8, 34u8, 1u8,
+                    47u8, 1u8, 53u8, 1u8, 59u8, 1u8, 66u8, 1u8, 76u8, 1u8, 82u8, 1u8, 88u8, 1u8,
+                    95u8, 1u8, 108u8, 1u8, 139u8, 1u8, 152u8, 1u8, 160u8, 1u8, 167u8, 1u8, 186u8,
+                    1u8, 193u8, 1u8, 208u8, 1u8, 216u8, 1u8, 224u8, 1u8, 230u8, 1u8, 236u8, 1u8,
+                    242u8, 1u8, 247u8, 1u8, 254u8, 1u8, 7u8, 2u8, 20u8, 2u8, 32u8, 2u8, 39u8, 2u8,
+                    45u8, 2u8, 53u8, 2u8, 69u8, 2u8, 76u8, 2u8, 81u8, 2u8, 88u8, 2u8, 100u8, 2u8,
+                    109u8, 2u8, 114u8, 2u8, 120u8, 2u8, 126u8, 2u8, 133u8, 2u8, 139u8, 2u8, 155u8,
+                    2u8, 160u8, 2u8, 166u8, 2u8, 172u8, 2u8, 178u8, 2u8, 185u8, 2u8, 190u8, 2u8,
+                    196u8, 2u8, 206u8, 2u8, 216u8, 2u8, 222u8, 2u8, 230u8, 2u8, 240u8, 2u8, 253u8,
+                    2u8, 4u8, 3u8, 9u8, 3u8, 16u8, 3u8, 23u8, 3u8, 28u8, 3u8, 35u8, 3u8, 41u8, 3u8,
+                    47u8, 3u8, 55u8, 3u8, 61u8, 3u8, 68u8, 3u8, 75u8, 3u8, 81u8, 3u8, 87u8, 3u8,
+                    95u8, 3u8, 102u8, 3u8, 108u8, 3u8, 115u8, 3u8, 128u8, 3u8, 134u8, 3u8, 141u8,
+                    3u8, 154u8, 3u8, 161u8, 3u8, 171u8, 3u8, 176u8, 3u8, 187u8, 3u8, 195u8, 3u8,
+                    201u8, 3u8, 207u8, 3u8, 213u8, 3u8, 219u8, 3u8, 226u8, 3u8, 232u8, 3u8, 239u8,
+                    3u8, 245u8, 3u8, 251u8, 3u8, 0u8, 4u8, 7u8, 4u8, 16u8, 4u8, 23u8, 4u8, 30u8,
+                    4u8, 37u8, 4u8, 43u8, 4u8, 50u8, 4u8, 62u8, 4u8, 69u8, 4u8, 76u8, 4u8, 82u8,
+                    4u8, 88u8, 4u8, 94u8, 4u8, 103u8, 4u8, 109u8, 4u8, 115u8, 4u8, 124u8, 4u8,
+                    134u8, 4u8, 140u8, 4u8, 146u8, 4u8, 153u8, 4u8, 160u8, 4u8, 170u8, 4u8, 176u8,
+                    4u8, 182u8, 4u8, 192u8, 4u8, 197u8, 4u8, 206u8, 4u8, 213u8, 4u8, 220u8, 4u8,
+                    225u8, 4u8, 231u8, 4u8, 244u8, 4u8, 250u8, 4u8, 2u8, 5u8, 8u8, 5u8, 21u8, 5u8,
+                    28u8, 5u8, 35u8, 5u8, 41u8, 5u8, 45u8, 5u8, 51u8, 5u8, 57u8, 5u8, 63u8, 5u8,
+                    69u8, 5u8, 82u8, 5u8, 88u8, 5u8, 101u8, 5u8, 108u8, 5u8, 114u8, 5u8, 122u8,
+                    5u8, 129u8, 5u8, 135u8, 5u8, 141u8, 5u8, 148u8, 5u8, 160u8, 5u8, 167u8, 5u8,
+                    173u8, 5u8, 184u8, 5u8, 196u8, 5u8, 203u8, 5u8, 212u8, 5u8, 219u8, 5u8, 226u8,
+                    5u8, 233u8, 5u8, 242u8, 5u8, 249u8, 5u8, 0u8, 6u8, 7u8, 6u8, 14u8, 6u8, 24u8,
+                    6u8, 31u8, 6u8, 36u8, 6u8, 42u8, 6u8, 52u8, 6u8, 60u8, 6u8, 68u8, 6u8, 76u8,
+                    6u8, 84u8, 6u8, 92u8, 6u8, 100u8, 6u8, 108u8, 6u8, 115u8, 6u8, 123u8, 6u8,
+                    131u8, 6u8, 137u8, 6u8, 143u8, 6u8, 150u8, 6u8, 158u8, 6u8, 165u8, 6u8, 171u8,
+                    6u8, 177u8, 6u8, 182u8, 6u8, 198u8, 6u8, 205u8, 6u8, 212u8, 6u8, 217u8, 6u8,
+                    222u8, 6u8, 227u8, 6u8, 233u8, 6u8, 243u8, 6u8, 249u8, 6u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 229u8, 136u8, 134u8,
+                    123u8, 48u8, 125u8, 32u8, 231u8, 167u8, 146u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    117u8, 123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8,
+                    123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 32u8, 66u8, 113u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 105u8, 116u8, 123u8, 48u8, 125u8, 32u8, 66u8, 84u8, 85u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8,
+                    48u8, 125u8, 32u8, 230u8, 173u8, 169u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 121u8, 116u8, 101u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8, 108u8, 45u8, 73u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    116u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 228u8,
+                    184u8, 150u8, 231u8, 180u8, 128u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 231u8, 148u8, 186u8,
+                    123u8, 48u8, 125u8, 32u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8,
+                    100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 227u8, 130u8, 171u8, 227u8,
+                    131u8, 131u8, 227u8, 131u8, 151u8, 32u8, 45u8, 32u8, 231u8, 177u8, 179u8,
+                    229u8, 155u8, 189u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 117u8,
+                    112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 227u8, 130u8,
+                    171u8, 227u8, 131u8, 131u8, 227u8, 131u8, 151u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    230u8, 151u8, 165u8, 123u8, 48u8, 125u8, 32u8, 229u8, 141u8, 129u8, 229u8,
+                    185u8, 180u8, 123u8, 48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 109u8, 123u8, 48u8, 125u8, 32u8, 229u8, 186u8, 166u8, 228u8, 184u8,
+                    173u8, 227u8, 129u8, 149u8, 227u8, 129u8, 152u8, 32u8, 123u8, 48u8, 125u8,
+                    123u8, 48u8, 125u8, 32u8, 232u8, 139u8, 177u8, 227u8, 131u8, 135u8, 227u8,
+                    130u8, 182u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 130u8, 185u8,
+                    227u8, 131u8, 151u8, 227u8, 131u8, 188u8, 227u8, 131u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 227u8, 131u8, 137u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 230u8, 182u8, 178u8, 233u8,
+                    135u8, 143u8, 227u8, 131u8, 137u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8,
+                    123u8, 48u8, 125u8, 32u8, 230u8, 187u8, 180u8, 123u8, 48u8, 125u8, 227u8,
+                    131u8, 137u8, 227u8, 130u8, 165u8, 227u8, 131u8, 138u8, 227u8, 131u8, 160u8,
+                    123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8,
+                    82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8,
+                    125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8, 123u8, 48u8,
+                    125u8, 32u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 32u8, 73u8, 109u8, 112u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 229u8, 136u8,
+                    134u8, 32u8, 45u8, 32u8, 232u8, 179u8, 170u8, 233u8, 135u8, 143u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8,
+                    32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8,
+                    227u8, 130u8, 176u8, 227u8, 131u8, 172u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8, 125u8, 32u8, 71u8, 121u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 97u8, 123u8, 48u8, 125u8, 32u8, 104u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 72u8,
+                    123u8, 48u8, 125u8, 32u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 233u8, 166u8,
+                    172u8, 229u8, 138u8, 155u8, 123u8, 48u8, 125u8, 32u8, 230u8, 153u8, 130u8,
+                    233u8, 150u8, 147u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 233u8, 160u8,
+                    133u8, 231u8, 155u8, 174u8, 123u8, 48u8, 125u8, 32u8, 227u8, 130u8, 184u8,
+                    227u8, 130u8, 172u8, 227u8, 131u8, 188u8, 123u8, 48u8, 125u8, 32u8, 228u8,
+                    184u8, 136u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 233u8,
+                    135u8, 145u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 233u8, 150u8, 147u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 75u8, 66u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8,
+                    122u8, 123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8,
+                    47u8, 49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 231u8, 159u8, 179u8, 229u8, 176u8, 143u8, 227u8,
+                    129u8, 149u8, 227u8, 129u8, 152u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8,
+                    125u8, 32u8, 229u8, 133u8, 137u8, 123u8, 48u8, 125u8, 32u8, 229u8, 133u8,
+                    137u8, 229u8, 185u8, 180u8, 123u8, 48u8, 125u8, 32u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 76u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    76u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 76u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 112u8, 103u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 112u8, 104u8, 123u8, 48u8, 125u8, 32u8, 115u8, 109u8, 105u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8,
+                    100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 32u8, 72u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 229u8, 136u8, 134u8, 123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 227u8, 129u8, 139u8, 230u8, 156u8, 136u8, 123u8,
+                    48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 230u8, 181u8, 183u8, 233u8, 135u8, 140u8, 123u8, 48u8,
+                    125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8, 133u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 230u8, 179u8, 138u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    108u8, 99u8, 123u8, 48u8, 125u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    169u8, 229u8, 164u8, 167u8, 227u8, 129u8, 149u8, 227u8, 129u8, 152u8, 32u8,
+                    123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 227u8, 131u8, 145u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    132u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 112u8, 98u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8,
+                    37u8, 123u8, 48u8, 125u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 227u8, 129u8, 164u8, 227u8, 129u8,
+                    190u8, 227u8, 129u8, 191u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 112u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8, 139u8, 133u8, 102u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8, 123u8, 48u8, 125u8, 32u8, 113u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 229u8, 155u8, 155u8, 229u8, 141u8, 138u8, 230u8, 156u8,
+                    159u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8,
+                    118u8, 123u8, 48u8, 125u8, 32u8, 233u8, 135u8, 140u8, 123u8, 48u8, 125u8, 32u8,
+                    229u8, 142u8, 152u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 230u8, 137u8, 141u8, 123u8, 48u8, 125u8, 32u8,
+                    231u8, 149u8, 157u8, 123u8, 48u8, 125u8, 32u8, 231u8, 167u8, 146u8, 123u8,
+                    48u8, 125u8, 32u8, 229u8, 139u8, 186u8, 123u8, 48u8, 125u8, 32u8, 233u8, 175u8,
+                    168u8, 229u8, 176u8, 186u8, 123u8, 48u8, 125u8, 32u8, 229u8, 176u8, 186u8,
+                    123u8, 48u8, 125u8, 32u8, 83u8, 123u8, 48u8, 125u8, 32u8, 83u8, 118u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8,
+                    152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 229u8,
+                    175u8, 184u8, 123u8, 48u8, 125u8, 32u8, 116u8, 98u8, 115u8, 112u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8, 32u8, 84u8, 123u8, 48u8,
+                    125u8, 32u8, 231u8, 177u8, 179u8, 227u8, 130u8, 181u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 160u8, 123u8, 48u8, 125u8, 32u8, 230u8, 150u8, 151u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 47u8, 116u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 86u8, 123u8, 48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 233u8, 128u8, 177u8, 233u8, 150u8,
+                    147u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 123u8, 48u8, 125u8, 32u8, 229u8,
+                    185u8, 180u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 28u8, 0u8, 34u8,
+                    0u8, 41u8, 0u8, 53u8, 0u8, 58u8, 0u8, 68u8, 0u8, 74u8, 0u8, 80u8, 0u8, 86u8,
+                    0u8, 91u8, 0u8, 96u8, 0u8, 103u8, 0u8, 110u8, 0u8, 117u8, 0u8, 123u8, 0u8,
+                    129u8, 0u8, 142u8, 0u8, 149u8, 0u8, 157u8, 0u8, 165u8, 0u8, 173u8, 0u8, 180u8,
+                    0u8, 188u8, 0u8, 195u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 230u8, 151u8, 165u8, 123u8, 48u8,
+                    125u8, 47u8, 102u8, 116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 230u8, 153u8, 130u8, 233u8,
+                    150u8, 147u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8,
+                    107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 47u8,
+                    76u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8, 48u8, 125u8, 47u8, 229u8, 136u8,
+                    134u8, 123u8, 48u8, 125u8, 47u8, 230u8, 156u8, 136u8, 123u8, 48u8, 125u8, 47u8,
+                    230u8, 179u8, 138u8, 123u8, 48u8, 125u8, 47u8, 111u8, 122u8, 123u8, 48u8,
+                    125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8, 229u8, 155u8, 155u8, 229u8,
+                    141u8, 138u8, 230u8, 156u8, 159u8, 123u8, 48u8, 125u8, 47u8, 231u8, 167u8,
+                    146u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 233u8,
+                    128u8, 177u8, 123u8, 48u8, 125u8, 47u8, 229u8, 185u8, 180u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static RU: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 16u8, 0u8, 22u8, 0u8, 28u8,
+                    0u8, 33u8, 0u8, 37u8, 0u8, 41u8, 0u8, 47u8, 0u8, 71u8, 0u8, 76u8, 0u8, 84u8,
+                    0u8, 97u8, 99u8, 114u8, 101u8, 97u8, 99u8, 114u8, 101u8, 45u8, 102u8, 111u8,
+                    111u8, 116u8, 98u8, 105u8, 116u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 103u8, 114u8, 97u8, 105u8, 110u8,
+                    105u8, 110u8, 99u8, 104u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8,
+                    103u8, 101u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 115u8, 116u8, 111u8, 110u8, 101u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 121u8, 97u8, 114u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 28u8, 0u8, 38u8, 0u8, 55u8, 0u8,
+                    80u8, 0u8, 92u8, 0u8, 104u8, 0u8, 120u8, 0u8, 138u8, 0u8, 165u8, 0u8, 180u8,
+                    0u8, 199u8, 0u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8,
+                    128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8, 128u8,
+                    208u8, 190u8, 209u8, 132u8, 209u8, 131u8, 209u8, 130u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 49u8,
+                    48u8, 45u8, 208u8, 187u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8,
+                    181u8, 123u8, 48u8, 125u8, 194u8, 160u8, 208u8, 188u8, 208u8, 190u8, 209u8,
+                    128u8, 46u8, 32u8, 209u8, 129u8, 208u8, 176u8, 208u8, 182u8, 208u8, 181u8,
+                    208u8, 189u8, 209u8, 140u8, 123u8, 48u8, 125u8, 32u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 189u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8,
+                    209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    190u8, 208u8, 177u8, 209u8, 138u8, 208u8, 181u8, 208u8, 186u8, 209u8, 130u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 182u8, 208u8, 184u8, 208u8,
+                    179u8, 208u8, 179u8, 208u8, 181u8, 209u8, 128u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8, 143u8, 47u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 191u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8,
+                    187u8, 123u8, 48u8, 125u8, 194u8, 160u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    190u8, 209u8, 131u8, 208u8, 189u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 188u8, 32u8, 208u8, 161u8, 208u8, 168u8,
+                    208u8, 144u8, 123u8, 48u8, 125u8, 32u8, 209u8, 143u8, 209u8, 128u8, 208u8,
+                    180u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 15u8, 0u8, 20u8, 0u8, 24u8,
+                    0u8, 30u8, 0u8, 40u8, 0u8, 64u8, 0u8, 69u8, 0u8, 77u8, 0u8, 98u8, 105u8, 116u8,
+                    100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 102u8, 97u8, 116u8, 104u8, 111u8,
+                    109u8, 103u8, 114u8, 97u8, 105u8, 110u8, 105u8, 116u8, 101u8, 109u8, 106u8,
+                    105u8, 103u8, 103u8, 101u8, 114u8, 108u8, 105u8, 103u8, 104u8, 116u8, 45u8,
+                    121u8, 101u8, 97u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8,
+                    112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 115u8, 116u8, 111u8, 110u8, 101u8,
+                    116u8, 104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 27u8, 0u8, 53u8, 0u8, 69u8, 0u8,
+                    89u8, 0u8, 111u8, 0u8, 124u8, 0u8, 151u8, 0u8, 169u8, 0u8, 192u8, 0u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 123u8, 48u8,
+                    125u8, 32u8, 49u8, 48u8, 45u8, 208u8, 187u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    184u8, 208u8, 185u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 190u8,
+                    209u8, 128u8, 46u8, 32u8, 209u8, 129u8, 208u8, 176u8, 208u8, 182u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 181u8, 208u8, 185u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 190u8, 208u8, 177u8, 209u8, 138u8,
+                    208u8, 181u8, 208u8, 186u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 182u8, 208u8, 184u8, 208u8, 179u8,
+                    208u8, 179u8, 208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 178u8, 46u8, 32u8, 208u8, 187u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8,
+                    140u8, 47u8, 208u8, 184u8, 208u8, 188u8, 208u8, 191u8, 46u8, 32u8, 208u8,
+                    179u8, 208u8, 176u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 190u8, 209u8, 131u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 188u8, 208u8, 190u8, 208u8, 178u8, 32u8, 208u8, 161u8, 208u8, 168u8,
+                    208u8, 144u8, 123u8, 48u8, 125u8, 32u8, 208u8, 187u8, 46u8,
+                ])
+            },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 62u8, 2u8,
+                    71u8, 2u8, 74u8, 2u8, 81u8, 2u8, 88u8, 2u8, 94u8, 2u8, 109u8, 2u8, 132u8, 2u8,
+                    139u8, 2u8, 146u8, 2u8, 154u8, 2u8, 163u8, 2u8, 171u8, 2u8, 176u8, 2u8, 180u8,
+                    2u8, 184u8, 2u8, 191u8, 2u8, 201u8, 2u8, 212u8, 2u8, 217u8, 2u8, 222u8, 2u8,
+                    232u8, 2u8, 236u8, 2u8, 240u8, 2u8, 249u8, 2u8, 253u8, 2u8, 3u8, 3u8, 8u8, 3u8,
+                    13u8, 3u8, 18u8, 3u8, 23u8, 3u8, 29u8, 3u8, 32u8, 3u8, 39u8, 3u8, 47u8, 3u8,
+                    58u8, 3u8, 66u8, 3u8, 80u8, 3u8, 89u8, 3u8, 98u8, 3u8, 107u8, 3u8, 125u8, 3u8,
+                    135u8, 3u8, 143u8, 3u8, 156u8, 3u8, 187u8, 3u8, 191u8, 3u8, 195u8, 3u8, 201u8,
+                    3u8, 212u8, 3u8, 222u8, 3u8, 227u8, 3u8, 250u8, 3u8, 13u8, 4u8, 18u8, 4u8,
+                    21u8, 4u8, 28u8, 4u8, 36u8, 4u8, 45u8, 4u8, 54u8, 4u8, 64u8, 4u8, 73u8, 4u8,
+                    81u8, 4u8, 86u8, 4u8, 102u8, 4u8, 125u8, 4u8, 134u8, 4u8, 144u8, 4u8, 155u8,
+                    4u8, 159u8, 4u8, 174u8, 4u8, 198u8, 4u8, 211u8, 4u8, 228u8, 4u8, 239u8, 4u8,
+                    247u8, 4u8, 0u8, 5u8, 33u8, 5u8, 43u8, 5u8, 53u8, 5u8, 68u8, 5u8, 87u8, 5u8,
+                    98u8, 5u8, 107u8, 5u8, 113u8, 5u8, 117u8, 5u8, 122u8, 5u8, 131u8, 5u8, 141u8,
+                    5u8, 154u8, 5u8, 160u8, 5u8, 172u8, 5u8, 177u8, 5u8, 186u8, 5u8, 190u8, 5u8,
+                    193u8, 5u8, 198u8, 5u8, 203u8, 5u8, 213u8, 5u8, 219u8, 5u8, 223u8, 5u8, 235u8,
+                    5u8, 247u8, 5u8, 253u8, 5u8, 4u8, 6u8, 12u8, 6u8, 21u8, 6u8, 29u8, 6u8, 38u8,
+                    6u8, 43u8, 6u8, 47u8, 6u8, 60u8, 6u8, 71u8, 6u8, 76u8, 6u8, 96u8, 6u8, 110u8,
+                    6u8, 115u8, 6u8, 120u8, 6u8, 131u8, 6u8, 147u8, 6u8, 174u8, 6u8, 179u8, 6u8,
+                    193u8, 6u8, 200u8, 6u8, 206u8, 6u8, 213u8, 6u8, 223u8, 6u8, 228u8, 6u8, 231u8,
+                    6u8, 234u8, 6u8, 237u8, 6u8, 242u8, 6u8, 248u8, 6u8, 253u8, 6u8, 8u8, 7u8,
+                    20u8, 7u8, 27u8, 7u8, 34u8, 7u8, 38u8, 7u8, 54u8, 7u8, 64u8, 7u8, 76u8, 7u8,
+                    93u8, 7u8, 104u8, 7u8, 115u8, 7u8, 131u8, 7u8, 143u8, 7u8, 154u8, 7u8, 165u8,
+                    7u8, 174u8, 7u8, 179u8, 7u8, 182u8, 7u8, 192u8, 7u8, 200u8, 7u8, 207u8, 7u8,
+                    215u8, 7u8, 220u8, 7u8, 228u8, 7u8, 233u8, 7u8, 236u8, 7u8, 241u8, 7u8, 245u8,
+                    7u8, 249u8, 7u8, 254u8, 7u8, 2u8, 8u8, 6u8, 8u8, 97u8, 99u8, 114u8, 101u8,
+                    97u8, 99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 30u8, 0u8, 36u8, 0u8, 42u8, 0u8,
+                    48u8, 0u8, 60u8, 0u8, 70u8, 0u8, 80u8, 0u8, 93u8, 0u8, 103u8, 0u8, 111u8, 0u8,
+                    123u8, 0u8, 133u8, 0u8, 145u8, 0u8, 156u8, 0u8, 167u8, 0u8, 173u8, 0u8, 183u8,
+                    0u8, 195u8, 0u8, 203u8, 0u8, 213u8, 0u8, 220u8, 0u8, 228u8, 0u8, 236u8, 0u8,
+                    243u8, 0u8, 254u8, 0u8, 10u8, 1u8, 18u8, 1u8, 28u8, 1u8, 38u8, 1u8, 52u8, 1u8,
+                    62u8, 1u8, 70u8, 1u8, 80u8, 1u8, 92u8, 1u8, 109u8, 1u8, 123u8, 1u8, 135u8, 1u8,
+                    150u8, 1u8, 158u8, 1u8, 167u8, 1u8, 184u8, 1u8, 192u8, 1u8, 200u8, 1u8, 205u8,
+                    1u8, 220u8, 1u8, 243u8, 1u8, 253u8, 1u8, 12u8, 2u8, 31u8, 2u8, 50u8, 2u8, 61u8,
+                    2u8, 72u8, 2u8, 80u8, 2u8, 88u8, 2u8, 96u8, 2u8, 104u8, 2u8, 111u8, 2u8, 117u8,
+                    2u8, 141u8, 2u8, 168u8, 2u8, 197u8, 2u8, 222u8, 2u8, 230u8, 2u8, 238u8, 2u8,
+                    250u8, 2u8, 7u8, 3u8, 12u8, 3u8, 29u8, 3u8, 48u8, 3u8, 57u8, 3u8, 62u8, 3u8,
+                    74u8, 3u8, 82u8, 3u8, 92u8, 3u8, 102u8, 3u8, 116u8, 3u8, 122u8, 3u8, 130u8,
+                    3u8, 138u8, 3u8, 146u8, 3u8, 156u8, 3u8, 164u8, 3u8, 172u8, 3u8, 183u8, 3u8,
+                    189u8, 3u8, 202u8, 3u8, 221u8, 3u8, 239u8, 3u8, 3u8, 4u8, 14u8, 4u8, 22u8, 4u8,
+                    32u8, 4u8, 42u8, 4u8, 47u8, 4u8, 59u8, 4u8, 71u8, 4u8, 79u8, 4u8, 91u8, 4u8,
+                    99u8, 4u8, 109u8, 4u8, 119u8, 4u8, 129u8, 4u8, 137u8, 4u8, 148u8, 4u8, 158u8,
+                    4u8, 168u8, 4u8, 183u8, 4u8, 207u8, 4u8, 215u8, 4u8, 228u8, 4u8, 243u8, 4u8,
+                    7u8, 5u8, 20u8, 5u8, 26u8, 5u8, 41u8, 5u8, 52u8, 5u8, 60u8, 5u8, 68u8, 5u8,
+                    80u8, 5u8, 88u8, 5u8, 98u8, 5u8, 106u8, 5u8, 116u8, 5u8, 128u8, 5u8, 138u8,
+                    5u8, 144u8, 5u8, 153u8, 5u8, 164u8, 5u8, 174u8, 5u8, 184u8, 5u8, 194u8, 5u8,
+                    202u8, 5u8, 223u8, 5u8, 250u8, 5u8, 5u8, 6u8, 19u8, 6u8, 27u8, 6u8, 39u8, 6u8,
+                    47u8, 6u8, 60u8, 6u8, 68u8, 6u8, 76u8, 6u8, 96u8, 6u8, 113u8, 6u8, 121u8, 6u8,
+                    131u8, 6u8, 141u8, 6u8, 153u8, 6u8, 164u8, 6u8, 172u8, 6u8, 180u8, 6u8, 196u8,
+                    6u8, 202u8, 6u8, 213u8, 6u8, 224u8, 6u8, 242u8, 6u8, 1u8, 7u8, 9u8, 7u8, 23u8,
+                    7u8, 34u8, 7u8, 51u8, 7u8, 59u8, 7u8, 66u8, 7u8, 73u8, 7u8, 80u8, 7u8, 88u8,
+                    7u8, 93u8, 7u8, 100u8, 7u8, 107u8, 7u8, 115u8, 7u8, 123u8, 7u8, 138u8, 7u8,
+                    157u8, 7u8, 170u8, 7u8, 180u8, 7u8, 190u8, 7u8, 205u8, 7u8, 224u8, 7u8, 234u8,
+                    7u8, 244u8, 7u8, 1u8, 8u8, 16u8, 8u8, 33u8, 8u8, 54u8, 8u8, 77u8, 8u8, 91u8,
+                    8u8, 101u8, 8u8, 110u8, 8u8, 119u8, 8u8, 130u8, 8u8, 142u8, 8u8, 151u8, 8u8,
+                    163u8, 8u8, 174u8, 8u8, 180u8, 8u8, 199u8, 8u8, 220u8, 8u8, 234u8, 8u8, 242u8,
+                    8u8, 250u8, 8u8, 4u8, 9u8, 12u8, 9u8, 20u8, 9u8, 28u8, 9u8, 38u8, 9u8, 48u8,
+                    9u8, 62u8, 9u8, 72u8, 9u8, 80u8, 9u8, 90u8, 9u8, 102u8, 9u8, 110u8, 9u8, 126u8,
+                    9u8, 138u8, 9u8, 151u8, 9u8, 162u8, 9u8, 174u8, 9u8, 182u8, 9u8, 190u8, 9u8,
+                    211u8, 9u8, 222u8, 9u8, 238u8, 9u8, 244u8, 9u8, 250u8, 9u8, 2u8, 10u8, 10u8,
+                    10u8, 21u8, 10u8, 32u8, 10u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8,
+                    186u8, 209u8, 128u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8,
+                    186u8, 209u8, 128u8, 208u8, 190u8, 209u8, 132u8, 209u8, 131u8, 209u8, 130u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 144u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 176u8, 46u8, 194u8, 160u8, 208u8, 181u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 176u8, 209u8, 130u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    177u8, 208u8, 176u8, 209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 177u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 128u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 145u8, 209u8, 132u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    145u8, 208u8, 186u8, 123u8, 48u8, 125u8, 32u8, 208u8, 177u8, 208u8, 184u8,
+                    209u8, 130u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 145u8, 208u8,
+                    162u8, 208u8, 149u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8,
+                    45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 208u8, 177u8, 209u8, 131u8, 209u8, 136u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 145u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    186u8, 208u8, 180u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 209u8, 129u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8,
+                    208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 178u8, 46u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 154u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8,
+                    188u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 209u8, 132u8, 209u8, 130u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 209u8, 142u8, 208u8,
+                    185u8, 208u8, 188u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 188u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 143u8, 209u8, 128u8, 208u8, 180u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 209u8,
+                    135u8, 208u8, 176u8, 209u8, 136u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 188u8, 46u8, 32u8, 209u8, 135u8, 208u8, 176u8, 209u8, 136u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 148u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 180u8, 208u8, 189u8, 46u8, 123u8, 48u8, 125u8, 32u8, 49u8, 48u8, 45u8,
+                    208u8, 187u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 209u8, 143u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    180u8, 208u8, 188u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 180u8, 208u8, 181u8, 209u8, 129u8, 46u8, 32u8, 208u8, 187u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 188u8, 208u8, 191u8, 46u8, 32u8,
+                    208u8, 180u8, 208u8, 181u8, 209u8, 129u8, 46u8, 32u8, 208u8, 187u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 209u8, 135u8, 208u8, 186u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 130u8, 209u8, 135u8, 208u8, 186u8, 47u8, 209u8,
+                    129u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 209u8, 135u8,
+                    208u8, 186u8, 47u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 182u8, 208u8, 184u8, 208u8, 180u8, 208u8,
+                    186u8, 46u8, 32u8, 208u8, 180u8, 209u8, 128u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 191u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 180u8, 209u8, 131u8, 208u8, 189u8, 46u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 141u8, 208u8, 146u8, 123u8, 48u8, 125u8, 32u8,
+                    209u8, 141u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 70u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 164u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 128u8, 46u8, 32u8, 209u8, 129u8, 208u8, 176u8, 208u8,
+                    182u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 208u8, 182u8, 208u8, 184u8, 208u8,
+                    180u8, 208u8, 186u8, 46u8, 32u8, 209u8, 131u8, 208u8, 189u8, 209u8, 134u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 188u8, 208u8, 191u8, 46u8,
+                    32u8, 208u8, 182u8, 208u8, 184u8, 208u8, 180u8, 208u8, 186u8, 46u8, 32u8,
+                    209u8, 131u8, 208u8, 189u8, 209u8, 134u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 46u8, 32u8, 208u8, 182u8, 208u8, 184u8, 208u8, 180u8, 208u8,
+                    186u8, 46u8, 32u8, 209u8, 131u8, 208u8, 189u8, 209u8, 134u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 132u8, 209u8, 130u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 209u8, 132u8, 209u8, 131u8, 209u8,
+                    128u8, 208u8, 187u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8,
+                    208u8, 187u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 191u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8,
+                    194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 147u8, 208u8, 177u8, 208u8,
+                    184u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 147u8, 208u8, 145u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 147u8, 208u8, 147u8, 209u8, 134u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 147u8, 208u8, 146u8, 209u8, 130u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    147u8, 209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 179u8, 208u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 179u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 179u8, 208u8, 159u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    147u8, 208u8, 189u8, 123u8, 48u8, 125u8, 32u8, 208u8, 147u8, 209u8, 134u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 187u8, 46u8, 32u8, 209u8, 129u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 135u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 209u8,
+                    142u8, 208u8, 185u8, 208u8, 188u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    180u8, 46u8, 32u8, 209u8, 128u8, 209u8, 130u8, 46u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 190u8, 208u8, 177u8, 209u8,
+                    138u8, 208u8, 181u8, 208u8, 186u8, 209u8, 130u8, 208u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 180u8, 208u8, 182u8, 208u8, 184u8, 208u8, 179u8, 208u8,
+                    179u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    148u8, 208u8, 182u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 176u8, 209u8,
+                    130u8, 123u8, 48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    186u8, 208u8, 145u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 179u8, 209u8, 129u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 147u8, 209u8, 134u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 148u8, 208u8, 182u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 186u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    186u8, 208u8, 188u8, 47u8, 209u8, 135u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    186u8, 208u8, 159u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 146u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8,
+                    146u8, 209u8, 130u8, 226u8, 139u8, 133u8, 209u8, 135u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 186u8, 208u8, 146u8, 209u8, 130u8, 226u8, 139u8, 133u8, 209u8,
+                    135u8, 47u8, 49u8, 48u8, 48u8, 32u8, 208u8, 186u8, 208u8, 188u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 131u8, 208u8, 183u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 186u8, 46u8, 32u8, 209u8, 129u8, 208u8,
+                    178u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    209u8, 129u8, 208u8, 178u8, 46u8, 32u8, 208u8, 179u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 208u8, 187u8, 47u8, 49u8, 48u8,
+                    48u8, 32u8, 208u8, 186u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 187u8,
+                    47u8, 208u8, 186u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 187u8,
+                    208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 187u8, 208u8, 186u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 156u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 156u8, 208u8, 145u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 156u8, 208u8, 147u8, 209u8, 134u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    156u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 208u8, 156u8, 208u8, 159u8,
+                    208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 156u8, 208u8, 191u8, 208u8,
+                    186u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 156u8, 208u8, 146u8,
+                    209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 47u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 47u8,
+                    209u8, 129u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8,
+                    186u8, 208u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 186u8,
+                    208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 186u8, 209u8,
+                    129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 47u8, 208u8, 176u8, 208u8, 188u8,
+                    46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 47u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 191u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8,
+                    187u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 47u8, 209u8,
+                    135u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 186u8, 46u8, 32u8, 208u8,
+                    188u8, 208u8, 184u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 144u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 177u8, 208u8, 176u8, 209u8,
+                    128u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 188u8, 208u8, 179u8, 47u8, 208u8, 180u8, 208u8, 187u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8,
+                    188u8, 32u8, 209u8, 128u8, 209u8, 130u8, 46u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 187u8, 209u8, 140u8, 47u8, 208u8, 187u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 188u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8,
+                    208u8, 146u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8,
+                    184u8, 208u8, 189u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 187u8, 209u8, 140u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 129u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8, 208u8,
+                    188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8, 209u8, 129u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 188u8, 208u8, 190u8, 209u8, 128u8, 46u8, 32u8, 208u8,
+                    188u8, 208u8, 184u8, 123u8, 48u8, 125u8, 32u8, 208u8, 157u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 157u8, 226u8, 139u8, 133u8, 208u8, 188u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 189u8, 208u8, 190u8, 209u8, 135u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 179u8, 208u8, 187u8, 209u8, 142u8, 208u8, 186u8, 208u8,
+                    190u8, 208u8, 183u8, 209u8, 139u8, 123u8, 48u8, 125u8, 32u8, 209u8, 128u8,
+                    209u8, 130u8, 46u8, 32u8, 209u8, 129u8, 209u8, 130u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 158u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8,
+                    106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    131u8, 208u8, 189u8, 209u8, 134u8, 46u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    130u8, 209u8, 128u8, 46u8, 32u8, 209u8, 131u8, 208u8, 189u8, 209u8, 134u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 191u8, 208u8, 186u8, 123u8, 48u8, 125u8,
+                    32u8, 209u8, 135u8, 46u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8, 32u8, 208u8, 159u8,
+                    208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 37u8, 123u8, 48u8, 125u8, 32u8, 226u8,
+                    128u8, 176u8, 123u8, 48u8, 125u8, 32u8, 226u8, 128u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 159u8, 208u8, 145u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    191u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 209u8, 137u8, 208u8, 181u8,
+                    208u8, 191u8, 208u8, 190u8, 209u8, 130u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 208u8, 191u8, 208u8, 184u8, 208u8,
+                    189u8, 209u8, 130u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8,
+                    116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8,
+                    208u8, 191u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 191u8, 208u8,
+                    186u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 208u8, 191u8, 208u8, 186u8,
+                    209u8, 129u8, 47u8, 209u8, 129u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 191u8, 208u8, 186u8, 209u8, 129u8, 47u8, 208u8, 180u8, 209u8, 142u8,
+                    208u8, 185u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 191u8, 208u8,
+                    186u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 209u8, 132u8, 208u8, 189u8,
+                    209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 209u8, 132u8, 208u8, 189u8, 209u8,
+                    130u8, 45u8, 209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 209u8, 132u8, 208u8,
+                    189u8, 209u8, 130u8, 45u8, 209u8, 132u8, 209u8, 130u8, 123u8, 48u8, 125u8,
+                    32u8, 209u8, 132u8, 47u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8,
+                    188u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8, 188u8,
+                    46u8, 32u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                    130u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 188u8, 208u8,
+                    191u8, 46u8, 32u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 130u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 178u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 180u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 208u8, 190u8, 208u8, 177u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    209u8, 129u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8,
+                    91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8,
+                    104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 161u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 208u8, 151u8, 208u8,
+                    178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 188u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 132u8, 209u8, 130u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 188u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 188u8, 208u8, 184u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    143u8, 209u8, 128u8, 208u8, 180u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    209u8, 129u8, 209u8, 128u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 190u8, 209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 129u8, 209u8, 130u8, 46u8, 32u8, 208u8, 187u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 209u8, 135u8, 46u8, 32u8, 208u8, 187u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 162u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 162u8, 208u8, 145u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    162u8, 208u8, 187u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 161u8, 208u8, 168u8,
+                    208u8, 144u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 128u8, 46u8, 32u8, 209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 146u8, 123u8, 48u8, 125u8, 32u8, 208u8, 146u8,
+                    209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 146u8, 208u8, 177u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 180u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 143u8, 209u8, 128u8, 208u8, 180u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 179u8, 46u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 18u8, 0u8, 26u8, 0u8, 32u8, 0u8, 40u8,
+                    0u8, 56u8, 0u8, 74u8, 0u8, 80u8, 0u8, 86u8, 0u8, 98u8, 0u8, 106u8, 0u8, 114u8,
+                    0u8, 120u8, 0u8, 126u8, 0u8, 136u8, 0u8, 146u8, 0u8, 157u8, 0u8, 167u8, 0u8,
+                    177u8, 0u8, 191u8, 0u8, 196u8, 0u8, 206u8, 0u8, 220u8, 0u8, 230u8, 0u8, 238u8,
+                    0u8, 248u8, 0u8, 2u8, 1u8, 123u8, 48u8, 125u8, 47u8, 209u8, 129u8, 208u8,
+                    188u8, 123u8, 48u8, 125u8, 47u8, 209u8, 129u8, 208u8, 188u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 47u8, 208u8, 188u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8,
+                    208u8, 180u8, 123u8, 48u8, 125u8, 47u8, 209u8, 132u8, 209u8, 130u8, 123u8,
+                    48u8, 125u8, 47u8, 208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 208u8, 179u8, 208u8,
+                    176u8, 208u8, 187u8, 123u8, 48u8, 125u8, 47u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 191u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 123u8,
+                    48u8, 125u8, 47u8, 208u8, 179u8, 123u8, 48u8, 125u8, 47u8, 209u8, 135u8, 123u8,
+                    48u8, 125u8, 47u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8,
+                    123u8, 48u8, 125u8, 47u8, 208u8, 186u8, 208u8, 179u8, 123u8, 48u8, 125u8, 47u8,
+                    208u8, 186u8, 208u8, 188u8, 123u8, 48u8, 125u8, 47u8, 208u8, 187u8, 123u8,
+                    48u8, 125u8, 47u8, 208u8, 188u8, 123u8, 48u8, 125u8, 47u8, 208u8, 188u8, 208u8,
+                    184u8, 208u8, 189u8, 123u8, 48u8, 125u8, 47u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 129u8, 123u8, 48u8, 125u8, 47u8, 208u8, 189u8, 208u8, 190u8, 209u8,
+                    135u8, 46u8, 123u8, 48u8, 125u8, 47u8, 209u8, 131u8, 208u8, 189u8, 209u8,
+                    134u8, 123u8, 48u8, 125u8, 47u8, 209u8, 132u8, 208u8, 189u8, 209u8, 130u8,
+                    123u8, 48u8, 125u8, 47u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 209u8,
+                    128u8, 209u8, 130u8, 123u8, 48u8, 125u8, 47u8, 99u8, 123u8, 48u8, 125u8, 47u8,
+                    209u8, 129u8, 208u8, 188u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 208u8,
+                    180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 208u8, 186u8, 208u8, 188u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    47u8, 208u8, 188u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 208u8, 188u8,
+                    208u8, 184u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 208u8, 189u8, 208u8,
+                    181u8, 208u8, 180u8, 123u8, 48u8, 125u8, 47u8, 208u8, 179u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static SR_LATN: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 24u8, 0u8, 36u8, 0u8, 40u8,
+                    0u8, 45u8, 0u8, 49u8, 0u8, 53u8, 0u8, 57u8, 0u8, 63u8, 0u8, 74u8, 0u8, 79u8,
+                    0u8, 83u8, 0u8, 88u8, 0u8, 96u8, 0u8, 100u8, 97u8, 121u8, 100u8, 111u8, 116u8,
+                    100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8, 105u8,
+                    110u8, 99u8, 104u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8,
+                    101u8, 100u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 97u8, 114u8, 116u8,
+                    112u8, 105u8, 110u8, 99u8, 104u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8,
+                    117u8, 115u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 25u8, 0u8, 32u8, 0u8, 39u8,
+                    0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 73u8, 0u8, 84u8, 0u8, 94u8, 0u8, 102u8,
+                    0u8, 109u8, 0u8, 122u8, 0u8, 134u8, 0u8, 123u8, 48u8, 125u8, 32u8, 100u8, 97u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 97u8, 196u8, 141u8, 107u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 112u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 196u8, 141u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 116u8, 97u8, 118u8, 107u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 197u8, 190u8, 105u8, 103u8, 101u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 118u8, 101u8, 116u8, 108u8, 111u8, 123u8, 48u8, 125u8, 32u8, 110u8,
+                    111u8, 196u8, 135u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 111u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 114u8, 115u8, 116u8, 111u8, 104u8, 118u8, 97u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 103u8, 111u8, 100u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 21u8, 0u8, 33u8, 0u8, 37u8, 0u8, 41u8,
+                    0u8, 52u8, 0u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 104u8, 111u8, 117u8, 114u8, 105u8, 116u8, 101u8, 109u8, 108u8,
+                    105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 112u8,
+                    97u8, 114u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 18u8, 0u8, 25u8, 0u8, 33u8, 0u8, 43u8,
+                    0u8, 53u8, 0u8, 123u8, 48u8, 125u8, 32u8, 116u8, 97u8, 196u8, 141u8, 107u8,
+                    101u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 116u8, 97u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 97u8, 118u8, 107u8, 101u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 118u8, 101u8, 116u8, 108u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 101u8, 108u8, 97u8,
+                ])
+            },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 26u8, 0u8,
+                    32u8, 0u8, 38u8, 0u8, 45u8, 0u8, 52u8, 0u8, 59u8, 0u8, 64u8, 0u8, 72u8, 0u8,
+                    77u8, 0u8, 84u8, 0u8, 96u8, 0u8, 107u8, 0u8, 113u8, 0u8, 118u8, 0u8, 125u8,
+                    0u8, 137u8, 0u8, 143u8, 0u8, 149u8, 0u8, 155u8, 0u8, 161u8, 0u8, 167u8, 0u8,
+                    172u8, 0u8, 183u8, 0u8, 195u8, 0u8, 202u8, 0u8, 210u8, 0u8, 218u8, 0u8, 226u8,
+                    0u8, 234u8, 0u8, 241u8, 0u8, 249u8, 0u8, 1u8, 1u8, 8u8, 1u8, 22u8, 1u8, 34u8,
+                    1u8, 40u8, 1u8, 46u8, 1u8, 54u8, 1u8, 62u8, 1u8, 68u8, 1u8, 74u8, 1u8, 79u8,
+                    1u8, 93u8, 1u8, 112u8, 1u8, 123u8, 1u8, 131u8, 1u8, 138u8, 1u8, 149u8, 1u8,
+                    157u8, 1u8, 167u8, 1u8, 175u8, 1u8, 183u8, 1u8, 189u8, 1u8, 195u8, 1u8, 201u8,
+                    1u8, 208u8, 1u8, 215u8, 1u8, 224u8, 1u8, 238u8, 1u8, 252u8, 1u8, 3u8, 2u8, 9u8,
+                    2u8, 17u8, 2u8, 29u8, 2u8, 36u8, 2u8, 41u8, 2u8, 48u8, 2u8, 60u8, 2u8, 69u8,
+                    2u8, 74u8, 2u8, 80u8, 2u8, 86u8, 2u8, 93u8, 2u8, 99u8, 2u8, 108u8, 2u8, 113u8,
+                    2u8, 121u8, 2u8, 127u8, 2u8, 133u8, 2u8, 140u8, 2u8, 147u8, 2u8, 153u8, 2u8,
+                    159u8, 2u8, 167u8, 2u8, 176u8, 2u8, 184u8, 2u8, 194u8, 2u8, 206u8, 2u8, 217u8,
+                    2u8, 222u8, 2u8, 228u8, 2u8, 237u8, 2u8, 242u8, 2u8, 254u8, 2u8, 4u8, 3u8,
+                    10u8, 3u8, 18u8, 3u8, 24u8, 3u8, 33u8, 3u8, 40u8, 3u8, 46u8, 3u8, 52u8, 3u8,
+                    60u8, 3u8, 67u8, 3u8, 73u8, 3u8, 80u8, 3u8, 93u8, 3u8, 99u8, 3u8, 112u8, 3u8,
+                    127u8, 3u8, 138u8, 3u8, 144u8, 3u8, 149u8, 3u8, 161u8, 3u8, 169u8, 3u8, 175u8,
+                    3u8, 181u8, 3u8, 187u8, 3u8, 193u8, 3u8, 200u8, 3u8, 206u8, 3u8, 213u8, 3u8,
+                    219u8, 3u8, 225u8, 3u8, 230u8, 3u8, 237u8, 3u8, 246u8, 3u8, 253u8, 3u8, 4u8,
+                    4u8, 11u8, 4u8, 17u8, 4u8, 24u8, 4u8, 36u8, 4u8, 44u8, 4u8, 51u8, 4u8, 57u8,
+                    4u8, 65u8, 4u8, 71u8, 4u8, 80u8, 4u8, 86u8, 4u8, 92u8, 4u8, 101u8, 4u8, 111u8,
+                    4u8, 117u8, 4u8, 123u8, 4u8, 130u8, 4u8, 137u8, 4u8, 145u8, 4u8, 151u8, 4u8,
+                    157u8, 4u8, 164u8, 4u8, 169u8, 4u8, 178u8, 4u8, 187u8, 4u8, 196u8, 4u8, 204u8,
+                    4u8, 210u8, 4u8, 224u8, 4u8, 230u8, 4u8, 238u8, 4u8, 244u8, 4u8, 254u8, 4u8,
+                    5u8, 5u8, 12u8, 5u8, 18u8, 5u8, 22u8, 5u8, 28u8, 5u8, 34u8, 5u8, 40u8, 5u8,
+                    46u8, 5u8, 60u8, 5u8, 66u8, 5u8, 79u8, 5u8, 86u8, 5u8, 92u8, 5u8, 100u8, 5u8,
+                    107u8, 5u8, 113u8, 5u8, 119u8, 5u8, 126u8, 5u8, 138u8, 5u8, 145u8, 5u8, 151u8,
+                    5u8, 162u8, 5u8, 168u8, 5u8, 175u8, 5u8, 184u8, 5u8, 191u8, 5u8, 202u8, 5u8,
+                    214u8, 5u8, 223u8, 5u8, 235u8, 5u8, 246u8, 5u8, 253u8, 5u8, 16u8, 6u8, 37u8,
+                    6u8, 51u8, 6u8, 58u8, 6u8, 66u8, 6u8, 76u8, 6u8, 84u8, 6u8, 92u8, 6u8, 100u8,
+                    6u8, 108u8, 6u8, 116u8, 6u8, 124u8, 6u8, 132u8, 6u8, 139u8, 6u8, 147u8, 6u8,
+                    155u8, 6u8, 163u8, 6u8, 169u8, 6u8, 181u8, 6u8, 190u8, 6u8, 202u8, 6u8, 208u8,
+                    6u8, 214u8, 6u8, 221u8, 6u8, 234u8, 6u8, 245u8, 6u8, 251u8, 6u8, 0u8, 7u8, 5u8,
+                    7u8, 10u8, 7u8, 18u8, 7u8, 26u8, 7u8, 33u8, 7u8, 123u8, 48u8, 125u8, 32u8,
+                    97u8, 99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 226u8, 128u8, 178u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 179u8, 123u8, 48u8, 125u8, 32u8, 97u8, 106u8, 123u8, 48u8,
+                    125u8, 32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8,
+                    98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    117u8, 123u8, 48u8, 125u8, 32u8, 66u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8,
+                    108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 97u8, 108u8, 45u8, 73u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 67u8,
+                    68u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 118u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    121u8, 100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 197u8, 161u8, 46u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    68u8, 97u8, 123u8, 48u8, 125u8, 32u8, 100u8, 97u8, 110u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 101u8, 99u8, 46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 115u8, 46u8, 32u8, 107u8, 97u8, 197u8,
+                    161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 105u8, 109u8, 112u8, 46u8, 32u8, 100u8,
+                    101u8, 115u8, 46u8, 32u8, 107u8, 97u8, 197u8, 161u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 116u8, 97u8, 196u8, 141u8, 97u8, 107u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8, 32u8, 102u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 97u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    117u8, 110u8, 97u8, 109u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8,
+                    149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8,
+                    32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8,
+                    194u8, 176u8, 70u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 70u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8,
+                    73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 67u8,
+                    97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8,
+                    194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 97u8, 105u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8,
+                    121u8, 123u8, 48u8, 125u8, 32u8, 104u8, 97u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 116u8, 105u8, 123u8,
+                    48u8, 125u8, 32u8, 105u8, 110u8, 196u8, 141u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 97u8, 118u8,
+                    107u8, 105u8, 123u8, 48u8, 125u8, 32u8, 100u8, 197u8, 190u8, 105u8, 103u8,
+                    101u8, 114u8, 97u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 107u8, 103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8,
+                    49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 118u8, 101u8, 116u8, 97u8,
+                    108u8, 97u8, 123u8, 48u8, 125u8, 32u8, 115u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 49u8, 48u8, 48u8, 32u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 120u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8, 77u8, 66u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 77u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 47u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8,
+                    115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 47u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 109u8, 32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    109u8, 111u8, 108u8, 47u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    101u8, 115u8, 46u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8, 133u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 111u8, 196u8, 135u8, 105u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8,
+                    32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 101u8, 108u8, 111u8, 118u8, 97u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    114u8, 115u8, 116u8, 111u8, 104u8, 118u8, 97u8, 116u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8,
+                    116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8,
+                    226u8, 139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8,
+                    116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 107u8, 118u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8, 118u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 101u8, 107u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8,
+                    111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    83u8, 118u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8,
+                    137u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 116u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 97u8, 197u8, 161u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 97u8, 197u8, 161u8, 105u8, 196u8, 141u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8,
+                    32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8,
+                    48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 110u8, 101u8, 100u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    106u8, 114u8, 100u8, 123u8, 48u8, 125u8, 32u8, 103u8, 111u8, 100u8, 46u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 39u8, 0u8, 51u8, 0u8, 56u8, 0u8, 62u8, 0u8, 68u8, 0u8, 74u8, 0u8, 80u8,
+                    0u8, 85u8, 0u8, 90u8, 0u8, 97u8, 0u8, 102u8, 0u8, 110u8, 0u8, 116u8, 0u8,
+                    122u8, 0u8, 127u8, 0u8, 132u8, 0u8, 140u8, 0u8, 148u8, 0u8, 156u8, 0u8, 163u8,
+                    0u8, 171u8, 0u8, 176u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 123u8, 48u8, 125u8, 47u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 47u8,
+                    103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 47u8,
+                    103u8, 123u8, 48u8, 125u8, 47u8, 196u8, 141u8, 123u8, 48u8, 125u8, 47u8, 105u8,
+                    110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 110u8, 111u8, 196u8, 135u8, 123u8, 48u8, 125u8, 47u8, 111u8,
+                    122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8, 107u8,
+                    123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 47u8, 110u8, 123u8, 48u8, 125u8, 47u8, 103u8, 111u8, 100u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static SR_SR_CYRL: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 24u8, 0u8, 36u8, 0u8, 40u8,
+                    0u8, 45u8, 0u8, 49u8, 0u8, 53u8, 0u8, 57u8, 0u8, 63u8, 0u8, 74u8, 0u8, 79u8,
+                    0u8, 83u8, 0u8, 88u8, 0u8, 96u8, 0u8, 100u8, 97u8, 121u8, 100u8, 111u8, 116u8,
+                    100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8, 105u8,
+                    110u8, 99u8, 104u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8,
+                    101u8, 100u8, 110u8, 105u8, 103u8, 104u8, 116u8, 112u8, 97u8, 114u8, 116u8,
+                    112u8, 105u8, 110u8, 99u8, 104u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8,
+                    117u8, 115u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 24u8, 0u8, 32u8, 0u8, 39u8, 0u8,
+                    49u8, 0u8, 63u8, 0u8, 73u8, 0u8, 83u8, 0u8, 99u8, 0u8, 113u8, 0u8, 129u8, 0u8,
+                    139u8, 0u8, 149u8, 0u8, 171u8, 0u8, 183u8, 0u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    130u8, 208u8, 176u8, 209u8, 135u8, 208u8, 186u8, 208u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 191u8,
+                    123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 209u8, 131u8, 208u8, 189u8, 208u8,
+                    176u8, 208u8, 188u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 176u8,
+                    209u8, 130u8, 123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                    135u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    159u8, 208u8, 184u8, 208u8, 179u8, 208u8, 181u8, 209u8, 128u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 129u8, 208u8, 178u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    187u8, 208u8, 190u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 190u8,
+                    209u8, 155u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 181u8, 208u8,
+                    190u8, 123u8, 48u8, 125u8, 32u8, 208u8, 191u8, 209u8, 128u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 190u8, 209u8, 133u8, 208u8, 178u8, 208u8, 176u8, 209u8,
+                    130u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 208u8, 179u8, 208u8, 190u8, 208u8, 180u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 21u8, 0u8, 33u8, 0u8, 37u8, 0u8, 41u8,
+                    0u8, 52u8, 0u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 104u8, 111u8, 117u8, 114u8, 105u8, 116u8, 101u8, 109u8, 108u8,
+                    105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 112u8,
+                    97u8, 114u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 22u8, 0u8, 29u8, 0u8, 41u8, 0u8, 57u8,
+                    0u8, 73u8, 0u8, 123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 208u8, 176u8, 209u8,
+                    135u8, 208u8, 186u8, 208u8, 181u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8,
+                    99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8,
+                    32u8, 209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 178u8, 208u8,
+                    186u8, 208u8, 181u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 178u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 187u8, 208u8, 176u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 180u8, 208u8, 181u8, 208u8, 187u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 26u8, 0u8,
+                    32u8, 0u8, 40u8, 0u8, 47u8, 0u8, 54u8, 0u8, 61u8, 0u8, 66u8, 0u8, 74u8, 0u8,
+                    79u8, 0u8, 86u8, 0u8, 98u8, 0u8, 109u8, 0u8, 115u8, 0u8, 120u8, 0u8, 127u8,
+                    0u8, 139u8, 0u8, 145u8, 0u8, 151u8, 0u8, 157u8, 0u8, 163u8, 0u8, 169u8, 0u8,
+                    175u8, 0u8, 186u8, 0u8, 198u8, 0u8, 205u8, 0u8, 213u8, 0u8, 221u8, 0u8, 229u8,
+                    0u8, 237u8, 0u8, 244u8, 0u8, 252u8, 0u8, 4u8, 1u8, 11u8, 1u8, 25u8, 1u8, 37u8,
+                    1u8, 43u8, 1u8, 49u8, 1u8, 61u8, 1u8, 72u8, 1u8, 78u8, 1u8, 84u8, 1u8, 89u8,
+                    1u8, 108u8, 1u8, 135u8, 1u8, 151u8, 1u8, 159u8, 1u8, 166u8, 1u8, 177u8, 1u8,
+                    189u8, 1u8, 205u8, 1u8, 213u8, 1u8, 221u8, 1u8, 227u8, 1u8, 233u8, 1u8, 239u8,
+                    1u8, 246u8, 1u8, 253u8, 1u8, 6u8, 2u8, 20u8, 2u8, 34u8, 2u8, 41u8, 2u8, 47u8,
+                    2u8, 55u8, 2u8, 67u8, 2u8, 74u8, 2u8, 79u8, 2u8, 86u8, 2u8, 98u8, 2u8, 107u8,
+                    2u8, 112u8, 2u8, 118u8, 2u8, 124u8, 2u8, 131u8, 2u8, 137u8, 2u8, 146u8, 2u8,
+                    151u8, 2u8, 159u8, 2u8, 165u8, 2u8, 171u8, 2u8, 178u8, 2u8, 185u8, 2u8, 191u8,
+                    2u8, 197u8, 2u8, 209u8, 2u8, 221u8, 2u8, 229u8, 2u8, 245u8, 2u8, 5u8, 3u8,
+                    16u8, 3u8, 21u8, 3u8, 27u8, 3u8, 36u8, 3u8, 41u8, 3u8, 53u8, 3u8, 59u8, 3u8,
+                    65u8, 3u8, 73u8, 3u8, 79u8, 3u8, 88u8, 3u8, 95u8, 3u8, 101u8, 3u8, 107u8, 3u8,
+                    115u8, 3u8, 122u8, 3u8, 128u8, 3u8, 135u8, 3u8, 148u8, 3u8, 154u8, 3u8, 167u8,
+                    3u8, 182u8, 3u8, 200u8, 3u8, 208u8, 3u8, 213u8, 3u8, 225u8, 3u8, 233u8, 3u8,
+                    239u8, 3u8, 245u8, 3u8, 251u8, 3u8, 1u8, 4u8, 8u8, 4u8, 14u8, 4u8, 21u8, 4u8,
+                    27u8, 4u8, 33u8, 4u8, 38u8, 4u8, 45u8, 4u8, 54u8, 4u8, 61u8, 4u8, 68u8, 4u8,
+                    75u8, 4u8, 81u8, 4u8, 88u8, 4u8, 100u8, 4u8, 108u8, 4u8, 115u8, 4u8, 121u8,
+                    4u8, 129u8, 4u8, 135u8, 4u8, 144u8, 4u8, 150u8, 4u8, 156u8, 4u8, 165u8, 4u8,
+                    175u8, 4u8, 181u8, 4u8, 187u8, 4u8, 197u8, 4u8, 204u8, 4u8, 215u8, 4u8, 221u8,
+                    4u8, 227u8, 4u8, 234u8, 4u8, 239u8, 4u8, 248u8, 4u8, 4u8, 5u8, 13u8, 5u8, 21u8,
+                    5u8, 27u8, 5u8, 41u8, 5u8, 47u8, 5u8, 55u8, 5u8, 61u8, 5u8, 77u8, 5u8, 84u8,
+                    5u8, 91u8, 5u8, 97u8, 5u8, 101u8, 5u8, 107u8, 5u8, 113u8, 5u8, 119u8, 5u8,
+                    125u8, 5u8, 149u8, 5u8, 155u8, 5u8, 168u8, 5u8, 175u8, 5u8, 181u8, 5u8, 189u8,
+                    5u8, 196u8, 5u8, 202u8, 5u8, 208u8, 5u8, 215u8, 5u8, 227u8, 5u8, 234u8, 5u8,
+                    240u8, 5u8, 251u8, 5u8, 3u8, 6u8, 10u8, 6u8, 19u8, 6u8, 26u8, 6u8, 37u8, 6u8,
+                    49u8, 6u8, 58u8, 6u8, 70u8, 6u8, 81u8, 6u8, 91u8, 6u8, 110u8, 6u8, 131u8, 6u8,
+                    145u8, 6u8, 152u8, 6u8, 160u8, 6u8, 170u8, 6u8, 178u8, 6u8, 186u8, 6u8, 194u8,
+                    6u8, 202u8, 6u8, 210u8, 6u8, 218u8, 6u8, 226u8, 6u8, 233u8, 6u8, 241u8, 6u8,
+                    249u8, 6u8, 1u8, 7u8, 7u8, 7u8, 19u8, 7u8, 30u8, 7u8, 45u8, 7u8, 51u8, 7u8,
+                    57u8, 7u8, 64u8, 7u8, 77u8, 7u8, 88u8, 7u8, 94u8, 7u8, 99u8, 7u8, 104u8, 7u8,
+                    109u8, 7u8, 117u8, 7u8, 128u8, 7u8, 138u8, 7u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 226u8, 128u8, 178u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 179u8, 123u8, 48u8, 125u8, 32u8, 208u8, 176u8, 209u8, 152u8,
+                    123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8,
+                    48u8, 125u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8, 113u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 117u8, 123u8, 48u8, 125u8, 32u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 97u8, 108u8,
+                    45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 67u8, 68u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 76u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    136u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8,
+                    73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8,
+                    125u8, 32u8, 68u8, 97u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 208u8,
+                    181u8, 209u8, 134u8, 46u8, 123u8, 48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 100u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 180u8, 208u8, 181u8, 209u8, 129u8, 46u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 136u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    184u8, 208u8, 188u8, 208u8, 191u8, 46u8, 32u8, 208u8, 180u8, 208u8, 181u8,
+                    209u8, 129u8, 46u8, 32u8, 208u8, 186u8, 208u8, 176u8, 209u8, 136u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 209u8, 130u8, 208u8, 176u8, 209u8, 135u8, 208u8,
+                    176u8, 208u8, 186u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8,
+                    99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 105u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 114u8, 97u8, 109u8, 32u8, 102u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 191u8, 208u8, 184u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 180u8, 209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8,
+                    125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8,
+                    123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8,
+                    116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 67u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8,
+                    125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 108u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 97u8, 115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8,
+                    87u8, 123u8, 48u8, 125u8, 32u8, 103u8, 114u8, 97u8, 105u8, 110u8, 123u8, 48u8,
+                    125u8, 32u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 121u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 97u8, 123u8, 48u8, 125u8, 32u8, 104u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8,
+                    104u8, 112u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8,
+                    130u8, 208u8, 184u8, 123u8, 48u8, 125u8, 32u8, 208u8, 184u8, 208u8, 189u8,
+                    209u8, 135u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 72u8,
+                    103u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 178u8, 208u8, 186u8, 208u8, 184u8, 123u8, 48u8, 125u8, 32u8, 209u8,
+                    159u8, 208u8, 184u8, 208u8, 179u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 75u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 107u8, 66u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8, 102u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 129u8, 208u8, 178u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 209u8, 129u8,
+                    208u8, 179u8, 123u8, 48u8, 125u8, 32u8, 108u8, 123u8, 48u8, 125u8, 32u8, 76u8,
+                    47u8, 49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 76u8,
+                    47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 76u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8,
+                    206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    112u8, 103u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    105u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8,
+                    47u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 76u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 32u8, 72u8, 103u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 76u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 129u8, 46u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8, 139u8,
+                    133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 190u8, 209u8,
+                    155u8, 208u8, 184u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8,
+                    99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 206u8, 169u8, 123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8,
+                    48u8, 125u8, 32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    99u8, 123u8, 48u8, 125u8, 32u8, 208u8, 180u8, 208u8, 181u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 32u8, 80u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 208u8,
+                    191u8, 209u8, 128u8, 209u8, 129u8, 209u8, 130u8, 208u8, 190u8, 209u8, 133u8,
+                    208u8, 178u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 116u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8,
+                    99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8,
+                    139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8, 208u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8,
+                    118u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8, 111u8, 108u8, 44u8, 32u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8, 32u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 83u8, 118u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8,
+                    137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 121u8,
+                    100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8, 117u8,
+                    110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 136u8, 46u8, 123u8, 48u8, 125u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 136u8, 208u8, 184u8, 209u8, 135u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8, 85u8, 83u8, 32u8,
+                    116u8, 104u8, 101u8, 114u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 116u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8, 123u8, 48u8,
+                    125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 180u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 209u8, 152u8, 209u8, 128u8, 208u8, 180u8, 123u8, 48u8, 125u8,
+                    32u8, 208u8, 179u8, 208u8, 190u8, 208u8, 180u8, 46u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 27u8, 0u8, 33u8,
+                    0u8, 40u8, 0u8, 52u8, 0u8, 57u8, 0u8, 63u8, 0u8, 69u8, 0u8, 75u8, 0u8, 81u8,
+                    0u8, 86u8, 0u8, 91u8, 0u8, 101u8, 0u8, 107u8, 0u8, 117u8, 0u8, 123u8, 0u8,
+                    129u8, 0u8, 135u8, 0u8, 141u8, 0u8, 149u8, 0u8, 157u8, 0u8, 165u8, 0u8, 172u8,
+                    0u8, 180u8, 0u8, 186u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 208u8, 180u8, 123u8, 48u8, 125u8, 47u8,
+                    102u8, 116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8,
+                    47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 209u8, 135u8, 123u8, 48u8, 125u8, 47u8,
+                    105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8, 47u8,
+                    107u8, 109u8, 123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    123u8, 48u8, 125u8, 47u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 123u8,
+                    48u8, 125u8, 47u8, 208u8, 188u8, 123u8, 48u8, 125u8, 47u8, 208u8, 189u8, 208u8,
+                    190u8, 209u8, 155u8, 123u8, 48u8, 125u8, 47u8, 111u8, 122u8, 123u8, 48u8,
+                    125u8, 47u8, 108u8, 98u8, 123u8, 48u8, 125u8, 47u8, 208u8, 186u8, 123u8, 48u8,
+                    125u8, 47u8, 209u8, 129u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8,
+                    107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8,
+                    208u8, 189u8, 123u8, 48u8, 125u8, 47u8, 208u8, 179u8, 208u8, 190u8, 208u8,
+                    180u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static TH: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 69u8, 2u8,
+                    73u8, 2u8, 82u8, 2u8, 85u8, 2u8, 92u8, 2u8, 99u8, 2u8, 105u8, 2u8, 120u8, 2u8,
+                    143u8, 2u8, 150u8, 2u8, 157u8, 2u8, 165u8, 2u8, 174u8, 2u8, 182u8, 2u8, 187u8,
+                    2u8, 191u8, 2u8, 195u8, 2u8, 202u8, 2u8, 212u8, 2u8, 223u8, 2u8, 228u8, 2u8,
+                    233u8, 2u8, 243u8, 2u8, 247u8, 2u8, 251u8, 2u8, 4u8, 3u8, 8u8, 3u8, 14u8, 3u8,
+                    19u8, 3u8, 24u8, 3u8, 29u8, 3u8, 34u8, 3u8, 40u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    58u8, 3u8, 69u8, 3u8, 77u8, 3u8, 91u8, 3u8, 100u8, 3u8, 109u8, 3u8, 118u8, 3u8,
+                    136u8, 3u8, 146u8, 3u8, 154u8, 3u8, 167u8, 3u8, 198u8, 3u8, 202u8, 3u8, 206u8,
+                    3u8, 212u8, 3u8, 223u8, 3u8, 233u8, 3u8, 238u8, 3u8, 5u8, 4u8, 24u8, 4u8, 29u8,
+                    4u8, 32u8, 4u8, 39u8, 4u8, 47u8, 4u8, 56u8, 4u8, 65u8, 4u8, 75u8, 4u8, 84u8,
+                    4u8, 92u8, 4u8, 97u8, 4u8, 113u8, 4u8, 136u8, 4u8, 145u8, 4u8, 155u8, 4u8,
+                    166u8, 4u8, 170u8, 4u8, 185u8, 4u8, 209u8, 4u8, 222u8, 4u8, 239u8, 4u8, 250u8,
+                    4u8, 2u8, 5u8, 11u8, 5u8, 44u8, 5u8, 54u8, 5u8, 64u8, 5u8, 79u8, 5u8, 98u8,
+                    5u8, 109u8, 5u8, 118u8, 5u8, 124u8, 5u8, 128u8, 5u8, 133u8, 5u8, 142u8, 5u8,
+                    152u8, 5u8, 165u8, 5u8, 171u8, 5u8, 183u8, 5u8, 188u8, 5u8, 197u8, 5u8, 201u8,
+                    5u8, 204u8, 5u8, 209u8, 5u8, 214u8, 5u8, 224u8, 5u8, 230u8, 5u8, 234u8, 5u8,
+                    246u8, 5u8, 2u8, 6u8, 8u8, 6u8, 15u8, 6u8, 23u8, 6u8, 32u8, 6u8, 40u8, 6u8,
+                    49u8, 6u8, 54u8, 6u8, 58u8, 6u8, 71u8, 6u8, 82u8, 6u8, 87u8, 6u8, 107u8, 6u8,
+                    121u8, 6u8, 126u8, 6u8, 131u8, 6u8, 142u8, 6u8, 158u8, 6u8, 185u8, 6u8, 190u8,
+                    6u8, 204u8, 6u8, 211u8, 6u8, 217u8, 6u8, 224u8, 6u8, 234u8, 6u8, 239u8, 6u8,
+                    242u8, 6u8, 245u8, 6u8, 248u8, 6u8, 253u8, 6u8, 3u8, 7u8, 8u8, 7u8, 19u8, 7u8,
+                    31u8, 7u8, 38u8, 7u8, 45u8, 7u8, 49u8, 7u8, 65u8, 7u8, 75u8, 7u8, 87u8, 7u8,
+                    104u8, 7u8, 115u8, 7u8, 126u8, 7u8, 142u8, 7u8, 154u8, 7u8, 165u8, 7u8, 176u8,
+                    7u8, 185u8, 7u8, 190u8, 7u8, 193u8, 7u8, 203u8, 7u8, 211u8, 7u8, 218u8, 7u8,
+                    226u8, 7u8, 231u8, 7u8, 239u8, 7u8, 244u8, 7u8, 247u8, 7u8, 252u8, 7u8, 0u8,
+                    8u8, 4u8, 8u8, 9u8, 8u8, 13u8, 8u8, 17u8, 8u8, 97u8, 99u8, 114u8, 101u8, 97u8,
+                    99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    231u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 60u8, 0u8, 79u8, 0u8, 98u8, 0u8,
+                    123u8, 0u8, 172u8, 0u8, 200u8, 0u8, 216u8, 0u8, 223u8, 0u8, 228u8, 0u8, 234u8,
+                    0u8, 247u8, 0u8, 254u8, 0u8, 10u8, 1u8, 21u8, 1u8, 27u8, 1u8, 43u8, 1u8, 65u8,
+                    1u8, 90u8, 1u8, 96u8, 1u8, 115u8, 1u8, 121u8, 1u8, 132u8, 1u8, 143u8, 1u8,
+                    165u8, 1u8, 176u8, 1u8, 188u8, 1u8, 193u8, 1u8, 211u8, 1u8, 231u8, 1u8, 254u8,
+                    1u8, 16u8, 2u8, 31u8, 2u8, 54u8, 2u8, 74u8, 2u8, 82u8, 2u8, 96u8, 2u8, 108u8,
+                    2u8, 135u8, 2u8, 141u8, 2u8, 154u8, 2u8, 176u8, 2u8, 187u8, 2u8, 198u8, 2u8,
+                    203u8, 2u8, 228u8, 2u8, 18u8, 3u8, 31u8, 3u8, 39u8, 3u8, 46u8, 3u8, 57u8, 3u8,
+                    70u8, 3u8, 89u8, 3u8, 97u8, 3u8, 105u8, 3u8, 111u8, 3u8, 117u8, 3u8, 123u8,
+                    3u8, 142u8, 3u8, 149u8, 3u8, 186u8, 3u8, 200u8, 3u8, 212u8, 3u8, 234u8, 3u8,
+                    247u8, 3u8, 255u8, 3u8, 11u8, 4u8, 18u8, 4u8, 23u8, 4u8, 45u8, 4u8, 85u8, 4u8,
+                    94u8, 4u8, 99u8, 4u8, 105u8, 4u8, 111u8, 4u8, 118u8, 4u8, 149u8, 4u8, 165u8,
+                    4u8, 173u8, 4u8, 192u8, 4u8, 217u8, 4u8, 228u8, 4u8, 9u8, 5u8, 28u8, 5u8, 34u8,
+                    5u8, 56u8, 5u8, 67u8, 5u8, 83u8, 5u8, 111u8, 5u8, 133u8, 5u8, 161u8, 5u8,
+                    172u8, 5u8, 185u8, 5u8, 204u8, 5u8, 223u8, 5u8, 228u8, 5u8, 240u8, 5u8, 246u8,
+                    5u8, 252u8, 5u8, 30u8, 6u8, 41u8, 6u8, 78u8, 6u8, 85u8, 6u8, 110u8, 6u8, 121u8,
+                    6u8, 140u8, 6u8, 147u8, 6u8, 178u8, 6u8, 197u8, 6u8, 247u8, 6u8, 4u8, 7u8,
+                    17u8, 7u8, 32u8, 7u8, 45u8, 7u8, 64u8, 7u8, 72u8, 7u8, 92u8, 7u8, 108u8, 7u8,
+                    114u8, 7u8, 133u8, 7u8, 139u8, 7u8, 145u8, 7u8, 152u8, 7u8, 180u8, 7u8, 187u8,
+                    7u8, 193u8, 7u8, 224u8, 7u8, 232u8, 7u8, 247u8, 7u8, 8u8, 8u8, 22u8, 8u8, 29u8,
+                    8u8, 54u8, 8u8, 70u8, 8u8, 105u8, 8u8, 166u8, 8u8, 190u8, 8u8, 197u8, 8u8,
+                    231u8, 8u8, 6u8, 9u8, 17u8, 9u8, 36u8, 9u8, 47u8, 9u8, 58u8, 9u8, 82u8, 9u8,
+                    101u8, 9u8, 138u8, 9u8, 172u8, 9u8, 188u8, 9u8, 201u8, 9u8, 220u8, 9u8, 232u8,
+                    9u8, 254u8, 9u8, 26u8, 10u8, 31u8, 10u8, 40u8, 10u8, 53u8, 10u8, 75u8, 10u8,
+                    91u8, 10u8, 97u8, 10u8, 111u8, 10u8, 130u8, 10u8, 161u8, 10u8, 186u8, 10u8,
+                    202u8, 10u8, 216u8, 10u8, 233u8, 10u8, 239u8, 10u8, 243u8, 10u8, 249u8, 10u8,
+                    255u8, 10u8, 5u8, 11u8, 33u8, 11u8, 58u8, 11u8, 77u8, 11u8, 90u8, 11u8, 127u8,
+                    11u8, 133u8, 11u8, 141u8, 11u8, 148u8, 11u8, 161u8, 11u8, 180u8, 11u8, 187u8,
+                    11u8, 199u8, 11u8, 238u8, 11u8, 249u8, 11u8, 4u8, 12u8, 26u8, 12u8, 51u8, 12u8,
+                    60u8, 12u8, 73u8, 12u8, 84u8, 12u8, 96u8, 12u8, 105u8, 12u8, 117u8, 12u8,
+                    128u8, 12u8, 138u8, 12u8, 157u8, 12u8, 178u8, 12u8, 192u8, 12u8, 217u8, 12u8,
+                    245u8, 12u8, 255u8, 12u8, 7u8, 13u8, 15u8, 13u8, 23u8, 13u8, 41u8, 13u8, 61u8,
+                    13u8, 84u8, 13u8, 102u8, 13u8, 117u8, 13u8, 140u8, 13u8, 160u8, 13u8, 166u8,
+                    13u8, 172u8, 13u8, 184u8, 13u8, 195u8, 13u8, 206u8, 13u8, 212u8, 13u8, 218u8,
+                    13u8, 237u8, 13u8, 3u8, 14u8, 14u8, 14u8, 27u8, 14u8, 35u8, 14u8, 40u8, 14u8,
+                    59u8, 14u8, 65u8, 14u8, 90u8, 14u8, 103u8, 14u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 45u8, 224u8, 184u8, 159u8, 224u8, 184u8, 184u8, 224u8, 184u8,
+                    149u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 155u8, 224u8, 185u8, 140u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 171u8, 224u8, 184u8, 153u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 162u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 163u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 98u8, 98u8, 108u8, 66u8, 32u8,
+                    123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 32u8, 66u8, 113u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 123u8,
+                    48u8, 125u8, 32u8, 66u8, 84u8, 85u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    149u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    100u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8, 176u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 149u8, 123u8, 48u8,
+                    125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 139u8,
+                    224u8, 184u8, 165u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 139u8,
+                    224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 168u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 167u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 169u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8,
+                    104u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 139u8,
+                    224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 159u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 149u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    154u8, 46u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 167u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 154u8, 46u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8, 224u8,
+                    184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 154u8, 46u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 150u8, 46u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 150u8, 46u8, 32u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 151u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 163u8, 224u8, 184u8, 163u8, 224u8, 184u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 148u8, 224u8, 184u8, 165u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 148u8, 224u8, 184u8, 161u8, 46u8,
+                    123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    138u8, 224u8, 185u8, 137u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 138u8, 224u8, 185u8, 137u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 130u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8,
+                    32u8, 45u8, 32u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    151u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 97u8, 109u8,
+                    32u8, 102u8, 108u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 148u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 185u8, 224u8, 184u8, 153u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 161u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8,
+                    125u8, 32u8, 82u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8,
+                    123u8, 48u8, 125u8, 32u8, 101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 159u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 116u8, 104u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 159u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8,
+                    32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 109u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 159u8, 224u8, 184u8, 184u8, 224u8, 184u8,
+                    149u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 164u8, 224u8, 184u8, 169u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8,
+                    115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 176u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 149u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 153u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    129u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 162u8, 224u8, 185u8, 140u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8, 174u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 174u8, 224u8, 184u8,
+                    165u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    174u8, 224u8, 184u8, 129u8, 224u8, 185u8, 130u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 174u8, 224u8, 184u8, 153u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 161u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 138u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    167u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 137u8, 224u8, 184u8, 167u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 151u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 136u8, 224u8, 184u8, 185u8,
+                    224u8, 184u8, 165u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 149u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 132u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 165u8, 123u8, 48u8, 125u8,
+                    32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 129u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 135u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 185u8, 224u8, 184u8, 165u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 47u8, 224u8, 184u8, 138u8, 224u8,
+                    184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 167u8, 46u8, 45u8, 224u8, 184u8, 138u8,
+                    224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 149u8, 224u8, 184u8, 149u8, 224u8,
+                    185u8, 140u8, 45u8, 224u8, 184u8, 138u8, 224u8, 184u8, 161u8, 46u8, 47u8, 49u8,
+                    48u8, 48u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 149u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 135u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 129u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    135u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 165u8, 46u8, 47u8, 49u8, 48u8, 48u8, 32u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    165u8, 46u8, 47u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 139u8, 224u8, 185u8,
+                    140u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8, 77u8,
+                    66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    176u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 176u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 149u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 161u8, 46u8, 47u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 46u8, 47u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 180u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 132u8, 224u8, 184u8, 129u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 206u8, 188u8, 109u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8,
+                    224u8, 184u8, 161u8, 224u8, 185u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 185u8,
+                    140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 47u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 153u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 184u8, 149u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8,
+                    224u8, 185u8, 140u8, 47u8, 224u8, 184u8, 138u8, 224u8, 184u8, 161u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 155u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 129u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 129u8, 46u8, 47u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    165u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    165u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    161u8, 46u8, 32u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 151u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 161u8, 46u8, 47u8, 224u8, 184u8, 165u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 181u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 165u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 153u8, 46u8, 224u8, 184u8, 161u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 123u8,
+                    48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 140u8, 224u8, 184u8, 151u8, 224u8, 184u8, 176u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8,
+                    125u8, 32u8, 78u8, 226u8, 139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 183u8, 224u8, 184u8, 153u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8, 170u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 151u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8, 123u8, 48u8, 125u8, 32u8,
+                    111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    139u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 158u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 129u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 170u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    153u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 170u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 165u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 158u8, 224u8, 184u8, 165u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8,
+                    125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 171u8, 224u8, 184u8, 162u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8,
+                    173u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 158u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 112u8, 116u8, 32u8, 73u8, 109u8, 112u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8,
+                    48u8, 125u8, 32u8, 112u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 105u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    184u8, 224u8, 184u8, 148u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8, 185u8,
+                    140u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 98u8, 102u8, 226u8, 139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 148u8, 224u8, 185u8, 140u8, 47u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8, 46u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 167u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 132u8, 224u8,
+                    184u8, 167u8, 46u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 132u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 170u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 153u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 154u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 114u8, 105u8, 110u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 114u8,
+                    111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 101u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8,
+                    111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8,
+                    32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8,
+                    117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 170u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 149u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 115u8, 108u8,
+                    117u8, 103u8, 123u8, 48u8, 125u8, 32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8,
+                    152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8, 46u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8,
+                    159u8, 224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 137u8, 224u8, 184u8, 167u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 32u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 185u8, 132u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8,
+                    32u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 171u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 149u8, 46u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 138u8, 46u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 84u8, 66u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 170u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 178u8, 123u8, 48u8, 125u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    152u8, 224u8, 184u8, 180u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 161u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8,
+                    93u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 153u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 149u8, 46u8,
+                    123u8, 48u8, 125u8, 32u8, 86u8, 123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 149u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    140u8, 123u8, 48u8, 125u8, 32u8, 87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 177u8, 224u8, 184u8, 155u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 123u8, 48u8,
+                    125u8, 32u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8,
+                    123u8, 48u8, 125u8, 32u8, 224u8, 184u8, 155u8, 224u8, 184u8, 181u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 29u8, 0u8, 44u8, 0u8, 57u8, 0u8,
+                    70u8, 0u8, 92u8, 0u8, 132u8, 0u8, 140u8, 0u8, 151u8, 0u8, 167u8, 0u8, 178u8,
+                    0u8, 189u8, 0u8, 197u8, 0u8, 205u8, 0u8, 221u8, 0u8, 240u8, 0u8, 253u8, 0u8,
+                    16u8, 1u8, 35u8, 1u8, 57u8, 1u8, 67u8, 1u8, 85u8, 1u8, 108u8, 1u8, 126u8, 1u8,
+                    141u8, 1u8, 164u8, 1u8, 189u8, 1u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8,
+                    46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8,
+                    224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 123u8, 48u8, 125u8, 47u8, 224u8,
+                    184u8, 159u8, 224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 123u8, 48u8, 125u8,
+                    47u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 123u8, 48u8, 125u8,
+                    47u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    164u8, 224u8, 184u8, 169u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 129u8,
+                    46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 138u8, 224u8, 184u8, 161u8, 46u8,
+                    123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 167u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 129u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8,
+                    165u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 161u8, 46u8, 123u8, 48u8,
+                    125u8, 47u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 181u8, 123u8, 48u8, 125u8, 47u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8,
+                    123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 132u8, 224u8, 184u8, 183u8, 224u8,
+                    184u8, 153u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 123u8,
+                    48u8, 125u8, 47u8, 224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 148u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 47u8,
+                    224u8, 185u8, 132u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 123u8, 48u8, 125u8, 47u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 123u8, 48u8, 125u8, 47u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 139u8, 224u8, 184u8,
+                    161u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8, 46u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 167u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 46u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8,
+                    184u8, 161u8, 46u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 46u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 47u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 155u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 123u8, 48u8, 125u8, 47u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 181u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static TR: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8,
+                    105u8, 116u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 14u8, 0u8, 123u8, 48u8, 125u8, 32u8,
+                    194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 194u8, 176u8, 70u8, 123u8, 48u8,
+                    125u8, 75u8,
+                ])
+            },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 165u8, 1u8, 183u8, 1u8, 195u8, 1u8, 199u8,
+                    1u8, 203u8, 1u8, 208u8, 1u8, 218u8, 1u8, 230u8, 1u8, 242u8, 1u8, 244u8, 1u8,
+                    254u8, 1u8, 3u8, 2u8, 9u8, 2u8, 20u8, 2u8, 40u8, 2u8, 58u8, 2u8, 62u8, 2u8,
+                    71u8, 2u8, 74u8, 2u8, 81u8, 2u8, 88u8, 2u8, 94u8, 2u8, 109u8, 2u8, 132u8, 2u8,
+                    139u8, 2u8, 146u8, 2u8, 154u8, 2u8, 163u8, 2u8, 171u8, 2u8, 176u8, 2u8, 180u8,
+                    2u8, 184u8, 2u8, 191u8, 2u8, 201u8, 2u8, 212u8, 2u8, 217u8, 2u8, 222u8, 2u8,
+                    232u8, 2u8, 236u8, 2u8, 240u8, 2u8, 249u8, 2u8, 253u8, 2u8, 3u8, 3u8, 8u8, 3u8,
+                    13u8, 3u8, 18u8, 3u8, 23u8, 3u8, 29u8, 3u8, 32u8, 3u8, 39u8, 3u8, 47u8, 3u8,
+                    58u8, 3u8, 66u8, 3u8, 80u8, 3u8, 89u8, 3u8, 98u8, 3u8, 107u8, 3u8, 125u8, 3u8,
+                    135u8, 3u8, 143u8, 3u8, 156u8, 3u8, 187u8, 3u8, 191u8, 3u8, 195u8, 3u8, 201u8,
+                    3u8, 212u8, 3u8, 222u8, 3u8, 227u8, 3u8, 250u8, 3u8, 13u8, 4u8, 18u8, 4u8,
+                    21u8, 4u8, 28u8, 4u8, 36u8, 4u8, 45u8, 4u8, 54u8, 4u8, 64u8, 4u8, 73u8, 4u8,
+                    81u8, 4u8, 86u8, 4u8, 102u8, 4u8, 125u8, 4u8, 134u8, 4u8, 144u8, 4u8, 155u8,
+                    4u8, 159u8, 4u8, 174u8, 4u8, 198u8, 4u8, 211u8, 4u8, 228u8, 4u8, 239u8, 4u8,
+                    247u8, 4u8, 0u8, 5u8, 33u8, 5u8, 43u8, 5u8, 53u8, 5u8, 68u8, 5u8, 87u8, 5u8,
+                    98u8, 5u8, 107u8, 5u8, 113u8, 5u8, 117u8, 5u8, 122u8, 5u8, 131u8, 5u8, 141u8,
+                    5u8, 154u8, 5u8, 160u8, 5u8, 172u8, 5u8, 177u8, 5u8, 186u8, 5u8, 190u8, 5u8,
+                    193u8, 5u8, 198u8, 5u8, 203u8, 5u8, 213u8, 5u8, 219u8, 5u8, 223u8, 5u8, 235u8,
+                    5u8, 247u8, 5u8, 253u8, 5u8, 4u8, 6u8, 12u8, 6u8, 21u8, 6u8, 29u8, 6u8, 38u8,
+                    6u8, 43u8, 6u8, 47u8, 6u8, 60u8, 6u8, 71u8, 6u8, 76u8, 6u8, 96u8, 6u8, 110u8,
+                    6u8, 115u8, 6u8, 120u8, 6u8, 131u8, 6u8, 147u8, 6u8, 174u8, 6u8, 179u8, 6u8,
+                    193u8, 6u8, 200u8, 6u8, 206u8, 6u8, 213u8, 6u8, 223u8, 6u8, 228u8, 6u8, 231u8,
+                    6u8, 234u8, 6u8, 237u8, 6u8, 242u8, 6u8, 248u8, 6u8, 253u8, 6u8, 8u8, 7u8,
+                    20u8, 7u8, 27u8, 7u8, 34u8, 7u8, 38u8, 7u8, 54u8, 7u8, 64u8, 7u8, 76u8, 7u8,
+                    93u8, 7u8, 104u8, 7u8, 115u8, 7u8, 131u8, 7u8, 143u8, 7u8, 154u8, 7u8, 165u8,
+                    7u8, 174u8, 7u8, 179u8, 7u8, 182u8, 7u8, 192u8, 7u8, 200u8, 7u8, 207u8, 7u8,
+                    215u8, 7u8, 220u8, 7u8, 228u8, 7u8, 233u8, 7u8, 236u8, 7u8, 241u8, 7u8, 245u8,
+                    7u8, 249u8, 7u8, 254u8, 7u8, 2u8, 8u8, 6u8, 8u8, 97u8, 99u8, 114u8, 101u8,
+                    97u8, 99u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 97u8, 114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8,
+                    108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8,
+                    112u8, 104u8, 101u8, 114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8,
+                    101u8, 108u8, 98u8, 101u8, 97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8,
+                    101u8, 99u8, 113u8, 117u8, 101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8,
+                    98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8,
+                    114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8,
+                    105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8,
+                    97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8,
+                    45u8, 106u8, 112u8, 98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8,
+                    116u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8,
+                    111u8, 114u8, 105u8, 101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8,
+                    101u8, 108u8, 97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 99u8, 104u8, 111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8,
+                    98u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8,
+                    117u8, 112u8, 99u8, 117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8,
+                    45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8,
+                    111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8,
+                    45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 100u8, 111u8, 116u8, 100u8, 111u8, 116u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 111u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8,
+                    112u8, 100u8, 117u8, 110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8,
+                    45u8, 109u8, 97u8, 115u8, 115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8,
+                    97u8, 100u8, 105u8, 117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8,
+                    111u8, 110u8, 118u8, 111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8,
+                    100u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    99u8, 102u8, 111u8, 111u8, 116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 102u8, 117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8,
+                    110u8, 103u8, 103u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8,
+                    111u8, 108u8, 105u8, 110u8, 101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+                    121u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 105u8, 99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8, 105u8,
+                    103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 103u8, 105u8, 103u8, 97u8,
+                    119u8, 97u8, 116u8, 116u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8,
+                    97u8, 109u8, 103u8, 114u8, 97u8, 121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8,
+                    101u8, 104u8, 101u8, 99u8, 116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8,
+                    101u8, 110u8, 114u8, 121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8,
+                    114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8,
+                    114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8,
+                    102u8, 104u8, 103u8, 105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8,
+                    101u8, 114u8, 106u8, 111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8,
+                    101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8,
+                    101u8, 108u8, 118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8,
+                    105u8, 108u8, 111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8,
+                    108u8, 107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8,
+                    117u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8,
+                    116u8, 107u8, 111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+                    108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 108u8, 117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8,
+                    101u8, 103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8,
+                    105u8, 120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8,
+                    116u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8,
+                    103u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8,
+                    101u8, 119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8,
+                    104u8, 103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8,
+                    114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8,
+                    114u8, 116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8,
+                    101u8, 57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8,
+                    110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8,
+                    108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8,
+                    111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8,
+                    117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8,
+                    114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8,
+                    114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8,
+                    110u8, 101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8,
+                    100u8, 115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    230u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 18u8, 0u8, 23u8, 0u8, 39u8, 0u8,
+                    55u8, 0u8, 61u8, 0u8, 68u8, 0u8, 75u8, 0u8, 82u8, 0u8, 87u8, 0u8, 95u8, 0u8,
+                    102u8, 0u8, 109u8, 0u8, 121u8, 0u8, 132u8, 0u8, 138u8, 0u8, 146u8, 0u8, 153u8,
+                    0u8, 165u8, 0u8, 171u8, 0u8, 177u8, 0u8, 183u8, 0u8, 189u8, 0u8, 195u8, 0u8,
+                    201u8, 0u8, 212u8, 0u8, 224u8, 0u8, 231u8, 0u8, 239u8, 0u8, 248u8, 0u8, 2u8,
+                    1u8, 10u8, 1u8, 17u8, 1u8, 26u8, 1u8, 37u8, 1u8, 43u8, 1u8, 57u8, 1u8, 69u8,
+                    1u8, 76u8, 1u8, 82u8, 1u8, 90u8, 1u8, 101u8, 1u8, 107u8, 1u8, 113u8, 1u8,
+                    118u8, 1u8, 137u8, 1u8, 162u8, 1u8, 171u8, 1u8, 179u8, 1u8, 186u8, 1u8, 201u8,
+                    1u8, 210u8, 1u8, 219u8, 1u8, 227u8, 1u8, 235u8, 1u8, 241u8, 1u8, 247u8, 1u8,
+                    253u8, 1u8, 4u8, 2u8, 11u8, 2u8, 25u8, 2u8, 45u8, 2u8, 59u8, 2u8, 66u8, 2u8,
+                    74u8, 2u8, 86u8, 2u8, 93u8, 2u8, 98u8, 2u8, 105u8, 2u8, 118u8, 2u8, 127u8, 2u8,
+                    132u8, 2u8, 138u8, 2u8, 144u8, 2u8, 151u8, 2u8, 157u8, 2u8, 165u8, 2u8, 170u8,
+                    2u8, 178u8, 2u8, 184u8, 2u8, 190u8, 2u8, 197u8, 2u8, 204u8, 2u8, 210u8, 2u8,
+                    216u8, 2u8, 223u8, 2u8, 231u8, 2u8, 239u8, 2u8, 248u8, 2u8, 2u8, 3u8, 13u8,
+                    3u8, 18u8, 3u8, 26u8, 3u8, 35u8, 3u8, 40u8, 3u8, 52u8, 3u8, 58u8, 3u8, 64u8,
+                    3u8, 72u8, 3u8, 78u8, 3u8, 87u8, 3u8, 94u8, 3u8, 100u8, 3u8, 106u8, 3u8, 115u8,
+                    3u8, 122u8, 3u8, 128u8, 3u8, 135u8, 3u8, 149u8, 3u8, 155u8, 3u8, 168u8, 3u8,
+                    183u8, 3u8, 194u8, 3u8, 200u8, 3u8, 205u8, 3u8, 217u8, 3u8, 225u8, 3u8, 231u8,
+                    3u8, 240u8, 3u8, 246u8, 3u8, 252u8, 3u8, 3u8, 4u8, 9u8, 4u8, 16u8, 4u8, 22u8,
+                    4u8, 28u8, 4u8, 33u8, 4u8, 41u8, 4u8, 51u8, 4u8, 58u8, 4u8, 65u8, 4u8, 73u8,
+                    4u8, 80u8, 4u8, 87u8, 4u8, 104u8, 4u8, 114u8, 4u8, 121u8, 4u8, 127u8, 4u8,
+                    135u8, 4u8, 141u8, 4u8, 150u8, 4u8, 156u8, 4u8, 162u8, 4u8, 170u8, 4u8, 180u8,
+                    4u8, 187u8, 4u8, 193u8, 4u8, 200u8, 4u8, 207u8, 4u8, 213u8, 4u8, 219u8, 4u8,
+                    226u8, 4u8, 233u8, 4u8, 238u8, 4u8, 247u8, 4u8, 255u8, 4u8, 8u8, 5u8, 16u8,
+                    5u8, 22u8, 5u8, 36u8, 5u8, 42u8, 5u8, 50u8, 5u8, 56u8, 5u8, 66u8, 5u8, 73u8,
+                    5u8, 80u8, 5u8, 86u8, 5u8, 90u8, 5u8, 96u8, 5u8, 102u8, 5u8, 108u8, 5u8, 114u8,
+                    5u8, 123u8, 5u8, 131u8, 5u8, 144u8, 5u8, 151u8, 5u8, 157u8, 5u8, 165u8, 5u8,
+                    172u8, 5u8, 178u8, 5u8, 184u8, 5u8, 191u8, 5u8, 203u8, 5u8, 214u8, 5u8, 220u8,
+                    5u8, 235u8, 5u8, 244u8, 5u8, 251u8, 5u8, 4u8, 6u8, 11u8, 6u8, 22u8, 6u8, 34u8,
+                    6u8, 43u8, 6u8, 55u8, 6u8, 66u8, 6u8, 73u8, 6u8, 92u8, 6u8, 113u8, 6u8, 127u8,
+                    6u8, 134u8, 6u8, 142u8, 6u8, 152u8, 6u8, 160u8, 6u8, 168u8, 6u8, 176u8, 6u8,
+                    184u8, 6u8, 192u8, 6u8, 200u8, 6u8, 208u8, 6u8, 215u8, 6u8, 223u8, 6u8, 231u8,
+                    6u8, 239u8, 6u8, 245u8, 6u8, 1u8, 7u8, 7u8, 7u8, 14u8, 7u8, 20u8, 7u8, 26u8,
+                    7u8, 33u8, 7u8, 53u8, 7u8, 64u8, 7u8, 77u8, 7u8, 82u8, 7u8, 87u8, 7u8, 92u8,
+                    7u8, 100u8, 7u8, 107u8, 7u8, 113u8, 7u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8,
+                    123u8, 48u8, 125u8, 32u8, 97u8, 107u8, 114u8, 101u8, 32u8, 102u8, 105u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 65u8, 123u8, 48u8, 125u8, 32u8, 97u8, 195u8, 167u8,
+                    196u8, 177u8, 115u8, 97u8, 108u8, 32u8, 100u8, 107u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 97u8, 195u8, 167u8, 196u8, 177u8, 115u8, 97u8, 108u8, 32u8, 115u8, 110u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 65u8, 85u8, 123u8, 48u8, 125u8, 32u8, 97u8,
+                    116u8, 109u8, 123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8,
+                    32u8, 98u8, 98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 105u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 66u8, 84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 121u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 97u8, 108u8,
+                    45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 116u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 108u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 121u8, 121u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8,
+                    105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 105u8, 116u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 195u8, 167u8, 194u8,
+                    179u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 194u8, 179u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    108u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 121u8, 97u8, 114u8, 100u8, 97u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 115u8, 98u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 115u8, 98u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 103u8, 195u8, 188u8, 110u8, 123u8, 48u8, 125u8, 32u8, 111u8,
+                    110u8, 32u8, 121u8, 196u8, 177u8, 108u8, 123u8, 48u8, 125u8, 32u8, 100u8,
+                    108u8, 123u8, 48u8, 125u8, 32u8, 100u8, 109u8, 123u8, 48u8, 125u8, 194u8,
+                    176u8, 123u8, 48u8, 125u8, 32u8, 116u8, 97u8, 116u8, 46u8, 32u8, 107u8, 97u8,
+                    197u8, 159u8, 196u8, 177u8, 196u8, 159u8, 196u8, 177u8, 123u8, 48u8, 125u8,
+                    32u8, 196u8, 176u8, 110u8, 103u8, 46u8, 32u8, 116u8, 97u8, 116u8, 46u8, 32u8,
+                    107u8, 97u8, 197u8, 159u8, 196u8, 177u8, 196u8, 159u8, 196u8, 177u8, 123u8,
+                    48u8, 125u8, 32u8, 110u8, 111u8, 107u8, 116u8, 97u8, 123u8, 48u8, 125u8, 32u8,
+                    100u8, 112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 112u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 196u8, 177u8, 118u8, 196u8, 177u8, 32u8,
+                    100u8, 114u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 100u8, 97u8, 109u8, 108u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8,
+                    138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8,
+                    101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 115u8, 196u8, 177u8, 118u8, 196u8, 177u8, 32u8,
+                    111u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8, 196u8, 176u8, 110u8, 103u8,
+                    46u8, 32u8, 115u8, 196u8, 177u8, 118u8, 196u8, 177u8, 32u8, 111u8, 110u8,
+                    115u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 108u8, 32u8, 111u8,
+                    122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 102u8, 105u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 196u8, 176u8, 110u8, 103u8, 46u8,
+                    32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8, 115u8, 32u8,
+                    69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 71u8, 98u8,
+                    123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8, 71u8, 72u8,
+                    122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    97u8, 110u8, 101u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 104u8, 108u8, 123u8, 48u8, 125u8, 32u8, 104u8, 80u8, 97u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8, 32u8, 72u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 103u8, 123u8, 48u8, 125u8, 32u8, 115u8, 97u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 195u8, 167u8, 123u8, 48u8, 125u8,
+                    32u8, 105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 195u8, 182u8, 196u8,
+                    159u8, 101u8, 123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8, 101u8,
+                    114u8, 123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 97u8, 121u8, 97u8,
+                    114u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 75u8, 123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 66u8, 123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 107u8, 103u8, 102u8, 123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 47u8, 115u8, 97u8, 123u8, 48u8, 125u8,
+                    32u8, 107u8, 80u8, 97u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 87u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8,
+                    47u8, 49u8, 48u8, 48u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 107u8, 117u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 196u8, 177u8, 197u8,
+                    159u8, 196u8, 177u8, 107u8, 123u8, 48u8, 125u8, 32u8, 73u8, 89u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 123u8, 48u8, 125u8, 32u8, 108u8, 47u8, 49u8, 48u8, 48u8,
+                    32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 47u8, 107u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 195u8, 188u8,
+                    107u8, 115u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 108u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 110u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 110u8, 194u8, 178u8, 123u8, 48u8, 125u8,
+                    32u8, 206u8, 188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 115u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 105u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 105u8, 108u8, 47u8, 196u8, 176u8, 110u8, 103u8, 46u8,
+                    32u8, 103u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 108u8, 47u8,
+                    115u8, 97u8, 123u8, 48u8, 125u8, 32u8, 115u8, 109u8, 105u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8, 98u8, 97u8, 114u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 47u8,
+                    100u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 72u8, 103u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 115u8, 110u8, 123u8, 48u8, 125u8, 32u8, 109u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 100u8, 107u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 111u8, 108u8, 123u8,
+                    48u8, 125u8, 32u8, 97u8, 121u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8, 123u8,
+                    48u8, 125u8, 32u8, 110u8, 115u8, 110u8, 123u8, 48u8, 125u8, 32u8, 110u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8, 78u8, 226u8,
+                    139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 103u8, 101u8, 99u8, 101u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 169u8,
+                    123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8, 48u8, 125u8, 32u8,
+                    111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 99u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 112u8, 97u8, 114u8, 116u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 98u8, 123u8,
+                    48u8, 125u8, 32u8, 80u8, 97u8, 37u8, 123u8, 48u8, 125u8, 226u8, 128u8, 176u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 177u8, 123u8, 48u8, 125u8, 123u8, 48u8,
+                    125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 112u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 116u8, 117u8, 116u8, 97u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    105u8, 110u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 116u8,
+                    32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8,
+                    99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8, 125u8,
+                    32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8, 125u8,
+                    32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8, 226u8,
+                    139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 47u8, 105u8,
+                    110u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 196u8, 176u8, 110u8, 103u8, 46u8, 32u8, 113u8, 117u8, 97u8, 114u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 195u8, 167u8, 101u8, 121u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 118u8, 123u8, 48u8,
+                    125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 110u8,
+                    46u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8,
+                    118u8, 111u8, 108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8,
+                    44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 83u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 118u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 152u8,
+                    137u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8,
+                    32u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 107u8, 123u8, 48u8, 125u8, 32u8, 195u8, 167u8, 107u8,
+                    123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8, 84u8, 66u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8, 32u8, 65u8,
+                    66u8, 68u8, 32u8, 196u8, 177u8, 115u8, 196u8, 177u8, 32u8, 98u8, 105u8, 114u8,
+                    105u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 116u8, 111u8, 32u8, 91u8, 74u8,
+                    80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8, 196u8, 177u8, 115u8, 97u8, 32u8,
+                    116u8, 111u8, 110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    86u8, 123u8, 48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    87u8, 98u8, 123u8, 48u8, 125u8, 32u8, 104u8, 102u8, 46u8, 123u8, 48u8, 125u8,
+                    32u8, 121u8, 100u8, 123u8, 48u8, 125u8, 32u8, 121u8, 196u8, 177u8, 108u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 29u8, 0u8, 35u8,
+                    0u8, 42u8, 0u8, 55u8, 0u8, 60u8, 0u8, 66u8, 0u8, 72u8, 0u8, 78u8, 0u8, 84u8,
+                    0u8, 89u8, 0u8, 94u8, 0u8, 101u8, 0u8, 107u8, 0u8, 115u8, 0u8, 121u8, 0u8,
+                    127u8, 0u8, 138u8, 0u8, 144u8, 0u8, 152u8, 0u8, 160u8, 0u8, 168u8, 0u8, 175u8,
+                    0u8, 183u8, 0u8, 190u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 103u8, 195u8, 188u8, 110u8, 123u8,
+                    48u8, 125u8, 47u8, 102u8, 116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8,
+                    123u8, 48u8, 125u8, 47u8, 196u8, 176u8, 110u8, 103u8, 46u8, 32u8, 103u8, 97u8,
+                    108u8, 123u8, 48u8, 125u8, 47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 115u8, 97u8,
+                    123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 47u8, 108u8, 123u8,
+                    48u8, 125u8, 47u8, 109u8, 123u8, 48u8, 125u8, 47u8, 100u8, 107u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 97u8, 121u8, 123u8, 48u8, 125u8, 47u8, 103u8, 101u8, 99u8,
+                    101u8, 123u8, 48u8, 125u8, 47u8, 111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8,
+                    98u8, 123u8, 48u8, 125u8, 47u8, 195u8, 167u8, 101u8, 121u8, 114u8, 101u8,
+                    107u8, 123u8, 48u8, 125u8, 47u8, 115u8, 110u8, 123u8, 48u8, 125u8, 47u8, 99u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8,
+                    109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 194u8, 178u8,
+                    123u8, 48u8, 125u8, 47u8, 104u8, 102u8, 46u8, 123u8, 48u8, 125u8, 47u8, 121u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
+static UND: &DataStruct = &::icu_units::provider::UnitsDisplayV1 {
+    zero: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    one: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    two: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    few: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    many: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    other: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    227u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8,
+                    39u8, 0u8, 56u8, 0u8, 66u8, 0u8, 69u8, 0u8, 75u8, 0u8, 83u8, 0u8, 92u8, 0u8,
+                    95u8, 0u8, 115u8, 0u8, 138u8, 0u8, 143u8, 0u8, 149u8, 0u8, 153u8, 0u8, 160u8,
+                    0u8, 170u8, 0u8, 177u8, 0u8, 182u8, 0u8, 189u8, 0u8, 199u8, 0u8, 209u8, 0u8,
+                    216u8, 0u8, 221u8, 0u8, 224u8, 0u8, 231u8, 0u8, 247u8, 0u8, 1u8, 1u8, 11u8,
+                    1u8, 26u8, 1u8, 37u8, 1u8, 47u8, 1u8, 57u8, 1u8, 60u8, 1u8, 72u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 94u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 121u8, 1u8,
+                    127u8, 1u8, 140u8, 1u8, 162u8, 1u8, 166u8, 1u8, 170u8, 1u8, 175u8, 1u8, 185u8,
+                    1u8, 197u8, 1u8, 209u8, 1u8, 211u8, 1u8, 221u8, 1u8, 226u8, 1u8, 232u8, 1u8,
+                    243u8, 1u8, 7u8, 2u8, 25u8, 2u8, 29u8, 2u8, 38u8, 2u8, 41u8, 2u8, 48u8, 2u8,
+                    55u8, 2u8, 61u8, 2u8, 76u8, 2u8, 99u8, 2u8, 106u8, 2u8, 113u8, 2u8, 121u8, 2u8,
+                    130u8, 2u8, 138u8, 2u8, 143u8, 2u8, 147u8, 2u8, 151u8, 2u8, 158u8, 2u8, 168u8,
+                    2u8, 179u8, 2u8, 184u8, 2u8, 189u8, 2u8, 199u8, 2u8, 203u8, 2u8, 207u8, 2u8,
+                    216u8, 2u8, 220u8, 2u8, 226u8, 2u8, 231u8, 2u8, 236u8, 2u8, 241u8, 2u8, 246u8,
+                    2u8, 252u8, 2u8, 255u8, 2u8, 6u8, 3u8, 14u8, 3u8, 25u8, 3u8, 33u8, 3u8, 47u8,
+                    3u8, 56u8, 3u8, 65u8, 3u8, 74u8, 3u8, 92u8, 3u8, 102u8, 3u8, 110u8, 3u8, 123u8,
+                    3u8, 154u8, 3u8, 158u8, 3u8, 162u8, 3u8, 168u8, 3u8, 179u8, 3u8, 189u8, 3u8,
+                    194u8, 3u8, 217u8, 3u8, 236u8, 3u8, 241u8, 3u8, 244u8, 3u8, 251u8, 3u8, 3u8,
+                    4u8, 12u8, 4u8, 21u8, 4u8, 31u8, 4u8, 40u8, 4u8, 48u8, 4u8, 53u8, 4u8, 69u8,
+                    4u8, 92u8, 4u8, 101u8, 4u8, 111u8, 4u8, 122u8, 4u8, 126u8, 4u8, 141u8, 4u8,
+                    165u8, 4u8, 178u8, 4u8, 195u8, 4u8, 206u8, 4u8, 214u8, 4u8, 223u8, 4u8, 0u8,
+                    5u8, 10u8, 5u8, 20u8, 5u8, 35u8, 5u8, 54u8, 5u8, 65u8, 5u8, 74u8, 5u8, 80u8,
+                    5u8, 84u8, 5u8, 89u8, 5u8, 98u8, 5u8, 108u8, 5u8, 121u8, 5u8, 127u8, 5u8,
+                    139u8, 5u8, 144u8, 5u8, 153u8, 5u8, 157u8, 5u8, 160u8, 5u8, 165u8, 5u8, 170u8,
+                    5u8, 180u8, 5u8, 186u8, 5u8, 190u8, 5u8, 202u8, 5u8, 214u8, 5u8, 220u8, 5u8,
+                    227u8, 5u8, 235u8, 5u8, 244u8, 5u8, 252u8, 5u8, 5u8, 6u8, 10u8, 6u8, 14u8, 6u8,
+                    27u8, 6u8, 38u8, 6u8, 43u8, 6u8, 63u8, 6u8, 77u8, 6u8, 82u8, 6u8, 87u8, 6u8,
+                    98u8, 6u8, 114u8, 6u8, 141u8, 6u8, 146u8, 6u8, 160u8, 6u8, 167u8, 6u8, 173u8,
+                    6u8, 180u8, 6u8, 190u8, 6u8, 195u8, 6u8, 198u8, 6u8, 201u8, 6u8, 204u8, 6u8,
+                    209u8, 6u8, 215u8, 6u8, 220u8, 6u8, 231u8, 6u8, 243u8, 6u8, 250u8, 6u8, 1u8,
+                    7u8, 5u8, 7u8, 21u8, 7u8, 31u8, 7u8, 43u8, 7u8, 60u8, 7u8, 71u8, 7u8, 82u8,
+                    7u8, 98u8, 7u8, 110u8, 7u8, 121u8, 7u8, 132u8, 7u8, 141u8, 7u8, 146u8, 7u8,
+                    149u8, 7u8, 159u8, 7u8, 167u8, 7u8, 174u8, 7u8, 182u8, 7u8, 187u8, 7u8, 195u8,
+                    7u8, 200u8, 7u8, 203u8, 7u8, 208u8, 7u8, 212u8, 7u8, 216u8, 7u8, 221u8, 7u8,
+                    225u8, 7u8, 229u8, 7u8, 97u8, 99u8, 114u8, 101u8, 97u8, 99u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8,
+                    97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8, 114u8,
+                    99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 97u8, 115u8, 116u8, 114u8,
+                    111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8, 108u8, 45u8, 117u8, 110u8,
+                    105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8, 112u8, 104u8, 101u8, 114u8,
+                    101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 98u8, 101u8,
+                    97u8, 117u8, 102u8, 111u8, 114u8, 116u8, 98u8, 101u8, 99u8, 113u8, 117u8,
+                    101u8, 114u8, 101u8, 108u8, 98u8, 105u8, 116u8, 98u8, 114u8, 105u8, 116u8,
+                    105u8, 115u8, 104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8, 108u8,
+                    45u8, 117u8, 110u8, 105u8, 116u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8,
+                    104u8, 45u8, 116u8, 104u8, 101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8,
+                    110u8, 105u8, 116u8, 45u8, 105u8, 116u8, 98u8, 117u8, 45u8, 106u8, 112u8, 98u8,
+                    117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8, 116u8, 101u8, 99u8, 97u8,
+                    108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8,
+                    101u8, 45u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 99u8,
+                    97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 101u8, 110u8,
+                    116u8, 117u8, 114u8, 121u8, 99u8, 104u8, 97u8, 105u8, 110u8, 99u8, 104u8,
+                    111u8, 99u8, 111u8, 117u8, 108u8, 111u8, 109u8, 98u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 99u8, 117u8, 112u8, 99u8,
+                    117u8, 112u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    99u8, 117u8, 112u8, 45u8, 106u8, 112u8, 99u8, 117u8, 112u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 100u8, 97u8, 108u8, 116u8, 111u8, 110u8, 100u8,
+                    97u8, 121u8, 100u8, 101u8, 99u8, 97u8, 100u8, 101u8, 100u8, 101u8, 99u8, 105u8,
+                    108u8, 105u8, 116u8, 101u8, 114u8, 100u8, 101u8, 99u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 100u8, 101u8,
+                    115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8,
+                    100u8, 101u8, 115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8,
+                    111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8,
+                    100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8, 110u8,
+                    97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 109u8, 97u8, 115u8, 115u8,
+                    101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8,
+                    115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 111u8, 110u8, 118u8, 111u8,
+                    108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 102u8, 97u8, 114u8, 97u8, 100u8, 102u8, 97u8, 116u8,
+                    104u8, 111u8, 109u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8,
+                    108u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 102u8, 111u8, 111u8,
+                    116u8, 102u8, 111u8, 114u8, 116u8, 110u8, 105u8, 103u8, 104u8, 116u8, 102u8,
+                    117u8, 110u8, 102u8, 117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8, 45u8,
+                    102u8, 111u8, 114u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+                    114u8, 105u8, 97u8, 108u8, 103u8, 97u8, 115u8, 111u8, 108u8, 105u8, 110u8,
+                    101u8, 45u8, 101u8, 110u8, 101u8, 114u8, 103u8, 121u8, 45u8, 100u8, 101u8,
+                    110u8, 115u8, 105u8, 116u8, 121u8, 103u8, 101u8, 110u8, 101u8, 114u8, 105u8,
+                    99u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 103u8, 105u8, 103u8, 97u8, 104u8, 101u8, 114u8,
+                    116u8, 122u8, 103u8, 105u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8, 103u8,
+                    114u8, 97u8, 105u8, 110u8, 103u8, 114u8, 97u8, 109u8, 103u8, 114u8, 97u8,
+                    121u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 101u8, 99u8,
+                    116u8, 111u8, 108u8, 105u8, 116u8, 101u8, 114u8, 104u8, 101u8, 99u8, 116u8,
+                    111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 104u8, 101u8, 110u8, 114u8,
+                    121u8, 104u8, 101u8, 114u8, 116u8, 122u8, 104u8, 111u8, 114u8, 115u8, 101u8,
+                    112u8, 111u8, 119u8, 101u8, 114u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8,
+                    99u8, 104u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8, 102u8, 104u8, 103u8,
+                    105u8, 116u8, 101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8,
+                    111u8, 45u8, 106u8, 112u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 97u8,
+                    114u8, 97u8, 116u8, 107u8, 97u8, 116u8, 97u8, 108u8, 107u8, 101u8, 108u8,
+                    118u8, 105u8, 110u8, 107u8, 101u8, 110u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 104u8, 101u8, 114u8, 116u8, 122u8, 107u8, 105u8, 108u8,
+                    111u8, 106u8, 111u8, 117u8, 108u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 107u8,
+                    105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 107u8, 105u8, 108u8, 111u8,
+                    119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 110u8, 111u8, 116u8, 107u8,
+                    111u8, 107u8, 117u8, 107u8, 111u8, 115u8, 97u8, 106u8, 105u8, 108u8, 105u8,
+                    103u8, 104u8, 116u8, 45u8, 115u8, 112u8, 101u8, 101u8, 100u8, 108u8, 105u8,
+                    103u8, 104u8, 116u8, 45u8, 121u8, 101u8, 97u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    117u8, 109u8, 101u8, 110u8, 108u8, 117u8, 120u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 103u8, 97u8, 104u8, 101u8, 114u8, 116u8, 122u8, 109u8, 101u8,
+                    103u8, 97u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 109u8, 101u8, 103u8, 97u8, 112u8, 105u8,
+                    120u8, 101u8, 108u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 99u8,
+                    114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8,
+                    101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8,
+                    109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 109u8, 105u8, 108u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8,
+                    108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8,
+                    105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8, 103u8,
+                    108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    100u8, 101u8, 99u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8, 103u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 109u8, 105u8, 110u8, 117u8, 116u8,
+                    101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8,
+                    97u8, 110u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 97u8, 110u8,
+                    111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 110u8, 97u8, 117u8, 116u8,
+                    105u8, 99u8, 97u8, 108u8, 45u8, 109u8, 105u8, 108u8, 101u8, 110u8, 101u8,
+                    119u8, 116u8, 111u8, 110u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 110u8, 105u8, 103u8, 104u8, 116u8, 111u8,
+                    102u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8,
+                    103u8, 111u8, 104u8, 109u8, 111u8, 115u8, 97u8, 106u8, 105u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8, 116u8, 114u8,
+                    111u8, 121u8, 112u8, 97u8, 114u8, 115u8, 101u8, 99u8, 112u8, 97u8, 114u8,
+                    116u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8,
+                    54u8, 112u8, 97u8, 114u8, 116u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 101u8,
+                    57u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8, 112u8,
+                    101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 101u8, 116u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 105u8, 99u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 112u8, 105u8, 110u8, 99u8, 104u8, 112u8, 105u8, 110u8,
+                    116u8, 112u8, 105u8, 110u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 112u8, 105u8, 110u8, 116u8, 45u8, 109u8, 101u8, 116u8,
+                    114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8, 120u8,
+                    101u8, 108u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 105u8, 110u8, 99u8, 104u8, 112u8, 111u8,
+                    105u8, 110u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 102u8, 111u8,
+                    111u8, 116u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8,
+                    99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 113u8, 117u8, 97u8, 114u8, 116u8,
+                    113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 97u8, 110u8, 107u8, 105u8, 110u8,
+                    101u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8,
+                    114u8, 105u8, 45u8, 106u8, 112u8, 114u8, 105u8, 110u8, 114u8, 111u8, 100u8,
+                    115u8, 97u8, 105u8, 115u8, 101u8, 45u8, 106u8, 112u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 115u8, 104u8, 97u8, 107u8, 117u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 99u8, 108u8, 111u8, 116u8, 104u8, 115u8, 104u8, 97u8,
+                    107u8, 117u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 105u8,
+                    101u8, 109u8, 101u8, 110u8, 115u8, 115u8, 105u8, 101u8, 118u8, 101u8, 114u8,
+                    116u8, 115u8, 108u8, 117u8, 103u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8,
+                    108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 115u8, 105u8, 116u8, 121u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 109u8, 97u8, 115u8, 115u8, 115u8, 111u8,
+                    108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8, 105u8, 117u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 100u8, 105u8, 97u8, 110u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 117u8, 110u8, 116u8, 97u8, 98u8, 108u8, 101u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8,
+                    110u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 116u8, 101u8, 115u8, 108u8, 97u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 45u8, 117u8, 115u8, 116u8, 111u8, 45u8, 106u8,
+                    112u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 98u8, 101u8, 114u8,
+                    119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    227u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 20u8, 0u8, 26u8, 0u8,
+                    32u8, 0u8, 38u8, 0u8, 45u8, 0u8, 52u8, 0u8, 59u8, 0u8, 64u8, 0u8, 72u8, 0u8,
+                    79u8, 0u8, 86u8, 0u8, 98u8, 0u8, 109u8, 0u8, 115u8, 0u8, 123u8, 0u8, 130u8,
+                    0u8, 142u8, 0u8, 148u8, 0u8, 154u8, 0u8, 160u8, 0u8, 166u8, 0u8, 172u8, 0u8,
+                    177u8, 0u8, 188u8, 0u8, 200u8, 0u8, 207u8, 0u8, 215u8, 0u8, 223u8, 0u8, 231u8,
+                    0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8, 6u8, 1u8, 11u8, 1u8, 25u8, 1u8, 37u8,
+                    1u8, 43u8, 1u8, 49u8, 1u8, 54u8, 1u8, 61u8, 1u8, 67u8, 1u8, 73u8, 1u8, 78u8,
+                    1u8, 88u8, 1u8, 102u8, 1u8, 113u8, 1u8, 121u8, 1u8, 130u8, 1u8, 138u8, 1u8,
+                    146u8, 1u8, 152u8, 1u8, 158u8, 1u8, 164u8, 1u8, 171u8, 1u8, 178u8, 1u8, 190u8,
+                    1u8, 204u8, 1u8, 218u8, 1u8, 224u8, 1u8, 232u8, 1u8, 244u8, 1u8, 251u8, 1u8,
+                    0u8, 2u8, 10u8, 2u8, 22u8, 2u8, 31u8, 2u8, 36u8, 2u8, 42u8, 2u8, 48u8, 2u8,
+                    55u8, 2u8, 61u8, 2u8, 70u8, 2u8, 75u8, 2u8, 83u8, 2u8, 89u8, 2u8, 95u8, 2u8,
+                    102u8, 2u8, 109u8, 2u8, 115u8, 2u8, 121u8, 2u8, 126u8, 2u8, 132u8, 2u8, 140u8,
+                    2u8, 148u8, 2u8, 158u8, 2u8, 169u8, 2u8, 174u8, 2u8, 180u8, 2u8, 189u8, 2u8,
+                    194u8, 2u8, 206u8, 2u8, 212u8, 2u8, 218u8, 2u8, 226u8, 2u8, 232u8, 2u8, 241u8,
+                    2u8, 248u8, 2u8, 254u8, 2u8, 4u8, 3u8, 12u8, 3u8, 19u8, 3u8, 25u8, 3u8, 32u8,
+                    3u8, 45u8, 3u8, 51u8, 3u8, 64u8, 3u8, 79u8, 3u8, 88u8, 3u8, 94u8, 3u8, 99u8,
+                    3u8, 110u8, 3u8, 118u8, 3u8, 124u8, 3u8, 130u8, 3u8, 136u8, 3u8, 142u8, 3u8,
+                    149u8, 3u8, 155u8, 3u8, 162u8, 3u8, 168u8, 3u8, 174u8, 3u8, 179u8, 3u8, 186u8,
+                    3u8, 195u8, 3u8, 202u8, 3u8, 209u8, 3u8, 216u8, 3u8, 222u8, 3u8, 232u8, 3u8,
+                    244u8, 3u8, 252u8, 3u8, 3u8, 4u8, 9u8, 4u8, 17u8, 4u8, 23u8, 4u8, 32u8, 4u8,
+                    38u8, 4u8, 44u8, 4u8, 53u8, 4u8, 63u8, 4u8, 69u8, 4u8, 75u8, 4u8, 82u8, 4u8,
+                    89u8, 4u8, 94u8, 4u8, 100u8, 4u8, 106u8, 4u8, 113u8, 4u8, 118u8, 4u8, 127u8,
+                    4u8, 136u8, 4u8, 145u8, 4u8, 153u8, 4u8, 159u8, 4u8, 173u8, 4u8, 179u8, 4u8,
+                    187u8, 4u8, 193u8, 4u8, 203u8, 4u8, 210u8, 4u8, 217u8, 4u8, 223u8, 4u8, 227u8,
+                    4u8, 233u8, 4u8, 239u8, 4u8, 245u8, 4u8, 251u8, 4u8, 4u8, 5u8, 10u8, 5u8, 23u8,
+                    5u8, 30u8, 5u8, 36u8, 5u8, 44u8, 5u8, 51u8, 5u8, 57u8, 5u8, 63u8, 5u8, 70u8,
+                    5u8, 82u8, 5u8, 89u8, 5u8, 95u8, 5u8, 106u8, 5u8, 111u8, 5u8, 118u8, 5u8,
+                    127u8, 5u8, 134u8, 5u8, 145u8, 5u8, 157u8, 5u8, 166u8, 5u8, 178u8, 5u8, 189u8,
+                    5u8, 194u8, 5u8, 213u8, 5u8, 234u8, 5u8, 248u8, 5u8, 255u8, 5u8, 7u8, 6u8,
+                    17u8, 6u8, 25u8, 6u8, 33u8, 6u8, 41u8, 6u8, 49u8, 6u8, 57u8, 6u8, 65u8, 6u8,
+                    73u8, 6u8, 80u8, 6u8, 88u8, 6u8, 96u8, 6u8, 104u8, 6u8, 110u8, 6u8, 122u8, 6u8,
+                    130u8, 6u8, 137u8, 6u8, 143u8, 6u8, 149u8, 6u8, 156u8, 6u8, 168u8, 6u8, 179u8,
+                    6u8, 185u8, 6u8, 190u8, 6u8, 195u8, 6u8, 200u8, 6u8, 208u8, 6u8, 213u8, 6u8,
+                    219u8, 6u8, 123u8, 48u8, 125u8, 32u8, 97u8, 99u8, 123u8, 48u8, 125u8, 32u8,
+                    97u8, 99u8, 32u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 65u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 178u8, 123u8, 48u8, 125u8, 226u8, 128u8, 179u8, 123u8,
+                    48u8, 125u8, 32u8, 97u8, 117u8, 123u8, 48u8, 125u8, 32u8, 97u8, 116u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 98u8,
+                    98u8, 108u8, 66u8, 32u8, 123u8, 48u8, 125u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    175u8, 66u8, 113u8, 123u8, 48u8, 125u8, 32u8, 98u8, 105u8, 116u8, 123u8, 48u8,
+                    125u8, 32u8, 66u8, 116u8, 117u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 66u8,
+                    84u8, 85u8, 45u8, 73u8, 84u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 32u8,
+                    91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 98u8, 117u8, 123u8, 48u8,
+                    125u8, 32u8, 98u8, 121u8, 116u8, 101u8, 123u8, 48u8, 125u8, 32u8, 99u8, 97u8,
+                    108u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 97u8, 108u8, 45u8, 73u8,
+                    84u8, 123u8, 48u8, 125u8, 32u8, 99u8, 100u8, 123u8, 48u8, 125u8, 32u8, 67u8,
+                    68u8, 123u8, 48u8, 125u8, 194u8, 176u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 99u8, 104u8, 97u8, 105u8, 110u8,
+                    123u8, 48u8, 125u8, 32u8, 99u8, 104u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 67u8, 123u8, 48u8, 125u8, 32u8, 99u8,
+                    109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 109u8, 194u8, 179u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8,
+                    121u8, 100u8, 194u8, 179u8, 123u8, 48u8, 125u8, 32u8, 99u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 99u8, 117u8, 112u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 32u8, 99u8, 117u8, 112u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 109u8, 99u8, 123u8, 48u8, 125u8, 32u8, 68u8, 97u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 123u8, 48u8, 125u8, 32u8, 100u8, 101u8, 99u8, 123u8,
+                    48u8, 125u8, 32u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 100u8, 109u8, 123u8,
+                    48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8,
+                    115u8, 112u8, 110u8, 123u8, 48u8, 125u8, 32u8, 100u8, 115u8, 116u8, 115u8,
+                    112u8, 110u8, 32u8, 73u8, 109u8, 112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8,
+                    97u8, 109u8, 32u8, 102u8, 108u8, 123u8, 48u8, 125u8, 32u8, 100u8, 114u8, 111u8,
+                    112u8, 123u8, 48u8, 125u8, 32u8, 100u8, 117u8, 110u8, 97u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 226u8, 138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 82u8, 226u8,
+                    138u8, 149u8, 123u8, 48u8, 125u8, 32u8, 101u8, 86u8, 123u8, 48u8, 125u8, 32u8,
+                    101u8, 109u8, 123u8, 48u8, 125u8, 194u8, 176u8, 70u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 70u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 104u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 85u8, 83u8,
+                    123u8, 48u8, 125u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 32u8, 73u8, 109u8,
+                    112u8, 46u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 32u8, 109u8, 46u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 102u8, 119u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    102u8, 117u8, 114u8, 123u8, 48u8, 125u8, 32u8, 71u8, 123u8, 48u8, 125u8, 32u8,
+                    103u8, 97u8, 108u8, 32u8, 85u8, 83u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8,
+                    108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 103u8, 97u8,
+                    115u8, 32u8, 69u8, 123u8, 48u8, 125u8, 194u8, 176u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 98u8, 123u8, 48u8, 125u8, 32u8, 71u8, 66u8, 123u8, 48u8, 125u8, 32u8,
+                    71u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 71u8, 87u8, 123u8, 48u8, 125u8,
+                    32u8, 103u8, 114u8, 97u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8, 103u8, 123u8,
+                    48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 121u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    97u8, 123u8, 48u8, 125u8, 32u8, 104u8, 76u8, 123u8, 48u8, 125u8, 32u8, 104u8,
+                    80u8, 97u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 123u8, 48u8, 125u8,
+                    32u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 104u8, 112u8, 123u8, 48u8, 125u8,
+                    32u8, 104u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    105u8, 110u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 105u8, 116u8, 101u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8,
+                    123u8, 48u8, 125u8, 32u8, 106u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 116u8, 123u8, 48u8,
+                    125u8, 226u8, 128u8, 175u8, 107u8, 97u8, 116u8, 123u8, 48u8, 125u8, 32u8, 75u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 101u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 98u8, 123u8, 48u8, 125u8, 32u8, 107u8, 66u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 99u8, 97u8, 108u8, 123u8, 48u8, 125u8, 32u8,
+                    107u8, 103u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 107u8, 103u8, 102u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 72u8, 122u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    74u8, 123u8, 48u8, 125u8, 32u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    109u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 107u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 87u8, 123u8, 48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 123u8,
+                    48u8, 125u8, 32u8, 107u8, 87u8, 104u8, 47u8, 49u8, 48u8, 48u8, 107u8, 109u8,
+                    123u8, 48u8, 125u8, 32u8, 107u8, 110u8, 123u8, 48u8, 125u8, 32u8, 107u8, 111u8,
+                    107u8, 117u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 107u8,
+                    111u8, 115u8, 97u8, 106u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 105u8, 103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 121u8, 123u8, 48u8, 125u8, 32u8, 108u8, 123u8, 48u8, 125u8, 32u8, 76u8,
+                    47u8, 49u8, 48u8, 48u8, 107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 76u8, 47u8,
+                    107u8, 109u8, 123u8, 48u8, 125u8, 32u8, 108u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    108u8, 120u8, 123u8, 48u8, 125u8, 32u8, 77u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    77u8, 66u8, 123u8, 48u8, 125u8, 32u8, 77u8, 72u8, 122u8, 123u8, 48u8, 125u8,
+                    32u8, 77u8, 76u8, 123u8, 48u8, 125u8, 32u8, 77u8, 80u8, 97u8, 123u8, 48u8,
+                    125u8, 32u8, 77u8, 80u8, 123u8, 48u8, 125u8, 32u8, 77u8, 87u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 47u8, 115u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 47u8, 115u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 206u8,
+                    188u8, 103u8, 123u8, 48u8, 125u8, 32u8, 206u8, 188u8, 109u8, 123u8, 48u8,
+                    125u8, 32u8, 206u8, 188u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 85u8, 83u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 112u8, 103u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8,
+                    125u8, 32u8, 109u8, 105u8, 47u8, 104u8, 123u8, 48u8, 125u8, 32u8, 115u8, 109u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 109u8, 65u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    98u8, 97u8, 114u8, 123u8, 48u8, 125u8, 32u8, 109u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 109u8, 103u8, 47u8, 100u8, 76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 76u8,
+                    123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8,
+                    32u8, 72u8, 103u8, 123u8, 48u8, 125u8, 32u8, 109u8, 109u8, 111u8, 108u8, 47u8,
+                    76u8, 123u8, 48u8, 125u8, 32u8, 109u8, 115u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    87u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 32u8,
+                    109u8, 111u8, 108u8, 123u8, 48u8, 125u8, 32u8, 109u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 115u8, 123u8, 48u8, 125u8, 32u8,
+                    110u8, 109u8, 105u8, 123u8, 48u8, 125u8, 32u8, 78u8, 123u8, 48u8, 125u8, 32u8,
+                    78u8, 226u8, 139u8, 133u8, 109u8, 123u8, 48u8, 125u8, 32u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 71u8, 108u8,
+                    99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 72u8, 103u8, 123u8, 48u8, 125u8,
+                    32u8, 206u8, 169u8, 123u8, 48u8, 125u8, 32u8, 111u8, 115u8, 97u8, 106u8, 105u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 111u8, 122u8, 123u8,
+                    48u8, 125u8, 32u8, 111u8, 122u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    99u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8, 97u8, 114u8, 116u8,
+                    123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 98u8, 123u8, 48u8, 125u8, 32u8, 80u8, 97u8, 123u8, 48u8, 125u8, 37u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 176u8, 123u8, 48u8, 125u8, 226u8, 128u8,
+                    177u8, 123u8, 48u8, 125u8, 32u8, 80u8, 66u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 105u8, 110u8, 99u8, 104u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 112u8,
+                    116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 109u8, 112u8,
+                    116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 120u8, 123u8, 48u8, 125u8, 32u8, 112u8,
+                    112u8, 99u8, 109u8, 123u8, 48u8, 125u8, 32u8, 112u8, 112u8, 105u8, 123u8, 48u8,
+                    125u8, 32u8, 112u8, 116u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 123u8, 48u8,
+                    125u8, 32u8, 108u8, 98u8, 102u8, 123u8, 48u8, 125u8, 32u8, 108u8, 98u8, 102u8,
+                    226u8, 139u8, 133u8, 102u8, 116u8, 123u8, 48u8, 125u8, 32u8, 112u8, 115u8,
+                    105u8, 123u8, 48u8, 125u8, 32u8, 113u8, 116u8, 123u8, 48u8, 125u8, 32u8, 113u8,
+                    116u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8, 48u8, 125u8, 32u8, 113u8, 123u8,
+                    48u8, 125u8, 32u8, 114u8, 97u8, 100u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8,
+                    194u8, 176u8, 82u8, 123u8, 48u8, 125u8, 32u8, 114u8, 101u8, 118u8, 123u8, 48u8,
+                    125u8, 32u8, 114u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    32u8, 114u8, 105u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 114u8, 111u8, 100u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    97u8, 105u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8,
+                    101u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8, 32u8, 91u8, 118u8, 111u8,
+                    108u8, 44u8, 32u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8,
+                    97u8, 107u8, 117u8, 32u8, 91u8, 99u8, 108u8, 111u8, 116u8, 104u8, 44u8, 32u8,
+                    74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8, 115u8, 104u8, 97u8, 107u8, 117u8,
+                    32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8,
+                    123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 83u8, 118u8, 123u8, 48u8, 125u8,
+                    226u8, 128u8, 175u8, 115u8, 108u8, 117u8, 103u8, 123u8, 48u8, 125u8, 32u8,
+                    76u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8, 77u8, 226u8, 152u8, 137u8,
+                    123u8, 48u8, 125u8, 32u8, 82u8, 226u8, 152u8, 137u8, 123u8, 48u8, 125u8, 32u8,
+                    99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 102u8, 116u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 32u8, 105u8, 110u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 32u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 32u8, 109u8, 105u8, 194u8, 178u8, 123u8,
+                    48u8, 125u8, 32u8, 121u8, 100u8, 194u8, 178u8, 123u8, 48u8, 125u8, 226u8,
+                    128u8, 175u8, 115u8, 114u8, 123u8, 48u8, 125u8, 32u8, 115u8, 116u8, 123u8,
+                    48u8, 125u8, 32u8, 115u8, 117u8, 110u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8,
+                    48u8, 125u8, 32u8, 116u8, 98u8, 115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 116u8,
+                    115u8, 112u8, 123u8, 48u8, 125u8, 32u8, 84u8, 98u8, 123u8, 48u8, 125u8, 32u8,
+                    84u8, 66u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 84u8, 123u8, 48u8, 125u8,
+                    32u8, 85u8, 83u8, 32u8, 116u8, 104u8, 101u8, 114u8, 109u8, 123u8, 48u8, 125u8,
+                    32u8, 116u8, 111u8, 32u8, 91u8, 74u8, 80u8, 93u8, 123u8, 48u8, 125u8, 32u8,
+                    116u8, 110u8, 123u8, 48u8, 125u8, 32u8, 116u8, 123u8, 48u8, 125u8, 32u8, 86u8,
+                    123u8, 48u8, 125u8, 32u8, 87u8, 123u8, 48u8, 125u8, 226u8, 128u8, 175u8, 87u8,
+                    98u8, 123u8, 48u8, 125u8, 32u8, 119u8, 123u8, 48u8, 125u8, 32u8, 121u8, 100u8,
+                    123u8, 48u8, 125u8, 32u8, 121u8,
+                ])
+            },
+        )
+    },
+    per_unit_patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 37u8, 0u8, 40u8, 0u8,
+                    44u8, 0u8, 50u8, 0u8, 65u8, 0u8, 69u8, 0u8, 73u8, 0u8, 77u8, 0u8, 85u8, 0u8,
+                    94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 115u8, 0u8, 120u8, 0u8, 125u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 143u8, 0u8, 160u8, 0u8, 171u8, 0u8, 187u8, 0u8,
+                    199u8, 0u8, 210u8, 0u8, 214u8, 0u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 100u8, 97u8, 121u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 109u8, 104u8, 111u8, 117u8, 114u8,
+                    105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 105u8,
+                    103u8, 104u8, 116u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 21u8, 0u8, 26u8, 0u8, 32u8,
+                    0u8, 42u8, 0u8, 54u8, 0u8, 59u8, 0u8, 64u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8,
+                    0u8, 87u8, 0u8, 92u8, 0u8, 99u8, 0u8, 104u8, 0u8, 113u8, 0u8, 119u8, 0u8,
+                    125u8, 0u8, 130u8, 0u8, 135u8, 0u8, 143u8, 0u8, 151u8, 0u8, 159u8, 0u8, 166u8,
+                    0u8, 174u8, 0u8, 179u8, 0u8, 123u8, 48u8, 125u8, 47u8, 99u8, 109u8, 123u8,
+                    48u8, 125u8, 47u8, 99u8, 109u8, 194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 109u8,
+                    194u8, 179u8, 123u8, 48u8, 125u8, 47u8, 100u8, 123u8, 48u8, 125u8, 47u8, 102u8,
+                    116u8, 123u8, 48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 85u8, 83u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 97u8, 108u8, 32u8, 73u8, 109u8, 112u8, 46u8, 123u8,
+                    48u8, 125u8, 47u8, 103u8, 123u8, 48u8, 125u8, 47u8, 104u8, 123u8, 48u8, 125u8,
+                    47u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8, 107u8, 103u8, 123u8, 48u8, 125u8,
+                    47u8, 107u8, 109u8, 123u8, 48u8, 125u8, 47u8, 108u8, 123u8, 48u8, 125u8, 47u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8, 110u8, 123u8, 48u8, 125u8, 47u8,
+                    109u8, 123u8, 48u8, 125u8, 47u8, 110u8, 105u8, 103u8, 104u8, 116u8, 123u8,
+                    48u8, 125u8, 47u8, 111u8, 122u8, 123u8, 48u8, 125u8, 47u8, 108u8, 98u8, 123u8,
+                    48u8, 125u8, 47u8, 113u8, 123u8, 48u8, 125u8, 47u8, 115u8, 123u8, 48u8, 125u8,
+                    47u8, 99u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 105u8, 110u8, 194u8,
+                    178u8, 123u8, 48u8, 125u8, 47u8, 107u8, 109u8, 194u8, 178u8, 123u8, 48u8,
+                    125u8, 47u8, 109u8, 194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 109u8, 105u8,
+                    194u8, 178u8, 123u8, 48u8, 125u8, 47u8, 119u8, 123u8, 48u8, 125u8, 47u8, 121u8,
+                ])
+            },
+        )
+    },
+    per_pattern: alloc::borrow::Cow::Borrowed("{0}/{1}"),
+};
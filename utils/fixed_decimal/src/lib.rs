@@ -54,6 +54,7 @@
 
 pub mod decimal;
 mod ops;
+mod scientific;
 mod uint_iterator;
 
 #[cfg(feature = "ryu")]
@@ -63,6 +64,7 @@ pub use decimal::FixedDecimal;
 pub use decimal::Sign;
 pub use decimal::SignDisplay;
 use displaydoc::Display;
+pub use scientific::ScientificDecimal;
 
 #[derive(Display, Debug, PartialEq)]
 pub enum Error {
@@ -0,0 +1,292 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Error;
+use crate::FixedDecimal;
+
+/// A struct containing a [`FixedDecimal`] significand together with an exponent, representing a
+/// number written in scientific notation, such as 1.23×10⁴.
+///
+/// Unlike multiplying a [`FixedDecimal`] by a power of ten, this keeps the exponent separate, so
+/// that it can be formatted, such as "1.23E4".
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use fixed_decimal::ScientificDecimal;
+///
+/// let sci = ScientificDecimal::from(FixedDecimal::from(12300));
+/// assert_eq!("1.2300e4", sci.to_string());
+/// assert_eq!(4, sci.exponent());
+///
+/// let sci: ScientificDecimal = "-6.02e23".parse().expect("valid syntax");
+/// assert_eq!("-6.02", sci.significand().to_string());
+/// assert_eq!(23, sci.exponent());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScientificDecimal {
+    significand: FixedDecimal,
+    exponent: i16,
+}
+
+impl ScientificDecimal {
+    /// Creates a [`ScientificDecimal`] from a significand and an exponent.
+    ///
+    /// The significand is not normalized, so it may have any number of integer digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::ScientificDecimal;
+    ///
+    /// let sci = ScientificDecimal::new(FixedDecimal::from(123), -2);
+    /// assert_eq!("123e-2", sci.to_string());
+    /// ```
+    pub fn new(significand: FixedDecimal, exponent: i16) -> Self {
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Converts a [`FixedDecimal`] to engineering notation, where the exponent is a multiple of
+    /// three and the significand has between one and three integer digits.
+    ///
+    /// Trailing zeros of the [`FixedDecimal`] are kept in the significand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::ScientificDecimal;
+    ///
+    /// let sci = ScientificDecimal::from_fixed_decimal_engineering(FixedDecimal::from(12345));
+    /// assert_eq!("12.345e3", sci.to_string());
+    ///
+    /// let dec: FixedDecimal = "0.00123".parse().expect("valid syntax");
+    /// let sci = ScientificDecimal::from_fixed_decimal_engineering(dec);
+    /// assert_eq!("1.23e-3", sci.to_string());
+    /// ```
+    pub fn from_fixed_decimal_engineering(value: FixedDecimal) -> Self {
+        let exponent = value.nonzero_magnitude_left().div_euclid(3) * 3;
+        Self::from_fixed_decimal_with_exponent(value, exponent)
+    }
+
+    fn from_fixed_decimal_with_exponent(value: FixedDecimal, exponent: i16) -> Self {
+        let mut significand = value.multiplied_pow10(-exponent);
+        // Shifting a fraction to the left can leave leading zeros, such as 0.00123 to 0001.23.
+        significand.trim_start();
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Returns the significand.
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns a mutable reference to the significand, such as for rounding it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::ScientificDecimal;
+    ///
+    /// let mut sci = ScientificDecimal::from(FixedDecimal::from(12345));
+    /// sci.significand_mut().half_even(-2);
+    /// assert_eq!("1.23e4", sci.to_string());
+    /// ```
+    pub fn significand_mut(&mut self) -> &mut FixedDecimal {
+        &mut self.significand
+    }
+
+    /// Returns the exponent.
+    pub fn exponent(&self) -> i16 {
+        self.exponent
+    }
+
+    /// Converts the [`ScientificDecimal`] to a [`FixedDecimal`] by applying the exponent to the
+    /// significand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ScientificDecimal;
+    ///
+    /// let sci: ScientificDecimal = "1.5e3".parse().expect("valid syntax");
+    /// assert_eq!("1500", sci.into_fixed_decimal().to_string());
+    /// ```
+    pub fn into_fixed_decimal(self) -> FixedDecimal {
+        self.significand.multiplied_pow10(self.exponent)
+    }
+}
+
+/// Converts a [`FixedDecimal`] to scientific notation, where the significand has a single
+/// integer digit, keeping trailing zeros in the significand. Zero has the exponent zero.
+impl From<FixedDecimal> for ScientificDecimal {
+    fn from(value: FixedDecimal) -> Self {
+        let exponent = value.nonzero_magnitude_left();
+        Self::from_fixed_decimal_with_exponent(value, exponent)
+    }
+}
+
+impl writeable::Writeable for ScientificDecimal {
+    /// Renders the [`ScientificDecimal`] as the significand, followed by `e` and the exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use fixed_decimal::ScientificDecimal;
+    /// use writeable::Writeable;
+    ///
+    /// let sci = ScientificDecimal::new(FixedDecimal::from(-25).multiplied_pow10(-1), -3);
+    /// assert_eq!("-2.5e-3", sci.write_to_string());
+    /// ```
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.significand.write_to(sink)?;
+        sink.write_char('e')?;
+        FixedDecimal::from(self.exponent).write_to(sink)
+    }
+
+    fn write_len(&self) -> writeable::LengthHint {
+        self.significand.write_len() + 1 + FixedDecimal::from(self.exponent).write_len()
+    }
+}
+
+/// Renders the `ScientificDecimal` according to the syntax documented in
+/// `ScientificDecimal::write_to`.
+impl fmt::Display for ScientificDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeable::Writeable::write_to(self, f)
+    }
+}
+
+/// Parses a significand and an optional exponent separated by `e` or `E`, such as "1.23e-4".
+/// The significand is not normalized.
+impl FromStr for ScientificDecimal {
+    type Err = Error;
+    fn from_str(input_str: &str) -> Result<Self, Self::Err> {
+        let (significand, exponent) = match input_str.find(['e', 'E']) {
+            Some(index) => {
+                #[allow(clippy::indexing_slicing)] // The index is at an ASCII character.
+                let exponent = input_str[index + 1..]
+                    .parse::<i16>()
+                    .map_err(|_| Error::Syntax)?;
+                #[allow(clippy::indexing_slicing)] // The index is at an ASCII character.
+                let significand = &input_str[..index];
+                (significand, exponent)
+            }
+            None => (input_str, 0),
+        };
+        Ok(Self {
+            significand: significand.parse()?,
+            exponent,
+        })
+    }
+}
+
+#[test]
+fn test_from_fixed_decimal() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub scientific: &'static str,
+        pub engineering: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "0",
+            scientific: "0e0",
+            engineering: "0e0",
+        },
+        TestCase {
+            input: "7",
+            scientific: "7e0",
+            engineering: "7e0",
+        },
+        TestCase {
+            input: "12345",
+            scientific: "1.2345e4",
+            engineering: "12.345e3",
+        },
+        TestCase {
+            input: "-1200",
+            scientific: "-1.200e3",
+            engineering: "-1.200e3",
+        },
+        TestCase {
+            input: "123456.7",
+            scientific: "1.234567e5",
+            engineering: "123.4567e3",
+        },
+        TestCase {
+            input: "0.5",
+            scientific: "5e-1",
+            engineering: "500e-3",
+        },
+        TestCase {
+            input: "0.00123",
+            scientific: "1.23e-3",
+            engineering: "1.23e-3",
+        },
+        TestCase {
+            input: "0.000120",
+            scientific: "1.20e-4",
+            engineering: "120e-6",
+        },
+    ];
+    for cas in &cases {
+        let dec: FixedDecimal = cas.input.parse().unwrap();
+        let scientific = ScientificDecimal::from(dec.clone());
+        assert_eq!(cas.scientific, scientific.to_string(), "{:?}", cas);
+        assert_eq!(dec, scientific.into_fixed_decimal(), "{:?}", cas);
+        let engineering = ScientificDecimal::from_fixed_decimal_engineering(dec.clone());
+        assert_eq!(cas.engineering, engineering.to_string(), "{:?}", cas);
+        // Engineering notation can add fraction zeros, such as 0.5 to 500e-3 and back to 0.500.
+        assert_eq!(
+            dec.clone().trimmed_end(),
+            engineering.into_fixed_decimal().trimmed_end(),
+            "{:?}",
+            cas
+        );
+    }
+}
+
+#[test]
+fn test_from_str() {
+    for (input, expected) in [
+        ("1.23e4", Ok("1.23e4")),
+        ("1.23E-4", Ok("1.23e-4")),
+        ("-5e+2", Ok("-5e2")),
+        ("42", Ok("42e0")),
+        ("1e", Err(Error::Syntax)),
+        ("e5", Err(Error::Syntax)),
+        ("1e2e3", Err(Error::Syntax)),
+        ("1.5e99999", Err(Error::Syntax)),
+    ] {
+        let actual = input.parse::<ScientificDecimal>().map(|s| s.to_string());
+        assert_eq!(actual, expected.map(String::from), "{}", input);
+    }
+}
+
+#[test]
+fn test_write_len() {
+    use writeable::Writeable;
+    for input in ["0e0", "-1.5e-10", "+12e300", "9.99e-9"] {
+        let sci: ScientificDecimal = input.parse().unwrap();
+        assert_eq!(
+            sci.write_len(),
+            writeable::LengthHint::exact(sci.write_to_string().len())
+        );
+    }
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::time::Seconds;
+use std::fmt;
 
 /// A struct to hold a time-zone variant name and its offset.
 /// The offset is how many hours must be added to the time to reach UTC.
@@ -68,3 +69,151 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+impl PosixTzString {
+    /// Returns the time-zone variant in effect at `time` in seconds since the Unix epoch,
+    /// along with whether it is the DST variant.
+    pub fn variant_at(&self, time: Seconds) -> (&ZoneVariantInfo, bool) {
+        let dst_info = match &self.dst_info {
+            Some(dst_info) => dst_info,
+            None => return (&self.std_info, false),
+        };
+        // The year is determined by the local standard time, since that is what the
+        // transition rules are expressed in.
+        let year = year_from_days((time.0 - self.std_info.offset.0).div_euclid(SECONDS_IN_DAY));
+        let (start, end) = self.dst_transitions(dst_info, year);
+        let is_dst = if start <= end {
+            start <= time && time < end
+        } else {
+            // The DST period spans the new year, e.g. in the southern hemisphere.
+            !(end <= time && time < start)
+        };
+        if is_dst {
+            (&dst_info.variant_info, true)
+        } else {
+            (&self.std_info, false)
+        }
+    }
+
+    /// Returns the instants at which DST starts and ends in the given proleptic Gregorian
+    /// `year`, in seconds since the Unix epoch, or [`None`] if there is no DST.
+    ///
+    /// The end may be earlier than the start, e.g. in the southern hemisphere.
+    ///
+    /// ```rust
+    /// use tzif::data::time::Seconds;
+    ///
+    /// let tz = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+    /// assert_eq!(
+    ///     tz.dst_transitions_in_year(2022),
+    ///     Some((Seconds(1647165600), Seconds(1667725200)))
+    /// );
+    /// ```
+    pub fn dst_transitions_in_year(&self, year: i64) -> Option<(Seconds, Seconds)> {
+        self.dst_info
+            .as_ref()
+            .map(|dst_info| self.dst_transitions(dst_info, year))
+    }
+
+    fn dst_transitions(&self, dst_info: &DstTransitionInfo, year: i64) -> (Seconds, Seconds) {
+        // The start time is given in local standard time, and the end time in local DST.
+        let start = dst_info.start_date.local_seconds_in_year(year) + self.std_info.offset;
+        let end = dst_info.end_date.local_seconds_in_year(year) + dst_info.variant_info.offset;
+        (start, end)
+    }
+}
+
+impl fmt::Display for PosixTzString {
+    /// Writes the POSIX time-zone string in the canonical form produced by `zic`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::write::posix::posix_tz_string(f, self)
+    }
+}
+
+impl TransitionDate {
+    /// Returns the local time of this transition in `year`, in seconds since the Unix epoch.
+    fn local_seconds_in_year(&self, year: i64) -> Seconds {
+        let day = days_from_civil(year, 1, 1) + self.day.day_of_year(year);
+        Seconds(day * SECONDS_IN_DAY) + self.time
+    }
+}
+
+impl TransitionDay {
+    /// Returns the zero-based day of the year on which this transition day falls in `year`.
+    fn day_of_year(&self, year: i64) -> i64 {
+        match *self {
+            TransitionDay::NoLeap(day) => {
+                let day = i64::from(day) - 1;
+                // Feb. 29 is never counted, so days from March 1 onwards shift by one.
+                if is_leap_year(year) && day >= 59 {
+                    day + 1
+                } else {
+                    day
+                }
+            }
+            TransitionDay::WithLeap(day) => i64::from(day),
+            TransitionDay::Mwd(month, week, weekday) => {
+                let month = i64::from(month);
+                let first_of_month = days_from_civil(year, month, 1);
+                let first_weekday = (first_of_month + 4).rem_euclid(7);
+                let mut day =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + 7 * (i64::from(week) - 1);
+                // Week 5 means the last such weekday in the month.
+                while day >= days_in_month(year, month) {
+                    day -= 7;
+                }
+                first_of_month + day - days_from_civil(year, 1, 1)
+            }
+        }
+    }
+}
+
+/// The number of seconds in a day.
+pub(crate) const SECONDS_IN_DAY: i64 = 86400;
+
+/// Returns whether `year` is a leap year in the proleptic Gregorian calendar.
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in `month` of `year`.
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since the Unix epoch of the given proleptic Gregorian date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the proleptic Gregorian year of the given number of days since the Unix epoch.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) fn year_from_days(days: i64) -> i64 {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400;
+    // The computation above uses years starting on March 1.
+    if month >= 10 {
+        year + 1
+    } else {
+        year
+    }
+}
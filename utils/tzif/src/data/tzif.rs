@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::time::Seconds;
-use crate::data::posix::PosixTzString;
+use crate::data::posix::{year_from_days, PosixTzString, SECONDS_IN_DAY};
 
 /// A `TZif` file header.
 /// See <https://datatracker.ietf.org/doc/html/rfc8536> for more information.
@@ -150,6 +150,197 @@ impl TzifData {
             _ => self.header2.as_ref().map(TzifHeader::block_size::<V>),
         }
     }
+
+    /// Returns the most precise data block, which is the version-2+ data block if present.
+    pub fn data_block(&self) -> &DataBlock {
+        self.data_block2.as_ref().unwrap_or(&self.data_block1)
+    }
+
+    /// Returns the local time type in effect at `time` in seconds since the Unix epoch.
+    ///
+    /// Times before the first transition use the first local time type record, and
+    /// times after the last transition are resolved with the footer's POSIX time-zone
+    /// string if present. Returns [`None`] if the data block has no local time type records.
+    pub fn local_time_type(&self, time: Seconds) -> Option<LocalTimeType> {
+        let block = self.data_block();
+        let index = block
+            .transition_times
+            .partition_point(|&transition| transition <= time);
+        if index == block.transition_times.len() {
+            if let Some(footer) = &self.footer {
+                let (variant, is_dst) = footer.variant_at(time);
+                return Some(LocalTimeType {
+                    utoff: Seconds(-variant.offset.0),
+                    is_dst,
+                    designation: variant.name.clone(),
+                });
+            }
+        }
+        let record = match index.checked_sub(1) {
+            Some(transition) => *block.transition_types.get(transition)?,
+            None => 0,
+        };
+        block.local_time_type(record)
+    }
+
+    /// Returns the transition in effect at `time` in seconds since the Unix epoch, i.e. the
+    /// latest transition at or before `time`, or [`None`] if there is no such transition.
+    pub fn transition_at(&self, time: Seconds) -> Option<Transition> {
+        self.previous_transition(time + Seconds(1))
+    }
+
+    /// Returns the earliest transition strictly after `time` in seconds since the Unix epoch,
+    /// or [`None`] if there is no such transition.
+    ///
+    /// Transitions after the last one in the data block are computed with the footer's POSIX
+    /// time-zone string.
+    pub fn next_transition(&self, time: Seconds) -> Option<Transition> {
+        let transition_times = &self.data_block().transition_times;
+        let index = transition_times.partition_point(|&transition| transition <= time);
+        let transition_time = match transition_times.get(index) {
+            Some(&transition_time) => Some(transition_time),
+            None => {
+                let bound = transition_times.last().map_or(time, |&last| last.max(time));
+                self.footer_transition_times(time)
+                    .filter(|&candidate| candidate > bound)
+                    .min()
+            }
+        };
+        transition_time.and_then(|time| self.transition(time))
+    }
+
+    /// Returns the latest transition strictly before `time` in seconds since the Unix epoch,
+    /// or [`None`] if there is no such transition.
+    ///
+    /// Transitions after the last one in the data block are computed with the footer's POSIX
+    /// time-zone string.
+    pub fn previous_transition(&self, time: Seconds) -> Option<Transition> {
+        let transition_times = &self.data_block().transition_times;
+        let index = transition_times.partition_point(|&transition| transition < time);
+        let footer_transition_time = if index == transition_times.len() {
+            let bound = transition_times.last().copied();
+            self.footer_transition_times(time)
+                .filter(|&candidate| candidate < time && bound.map_or(true, |b| candidate > b))
+                .max()
+        } else {
+            None
+        };
+        footer_transition_time
+            .or_else(|| index.checked_sub(1).map(|i| transition_times[i]))
+            .and_then(|time| self.transition(time))
+    }
+
+    /// Converts the local time `local_time`, in seconds since the Unix epoch in local time,
+    /// to seconds since the Unix epoch in UTC.
+    ///
+    /// Local times that are skipped (e.g. when DST starts) or repeated (e.g. when DST ends)
+    /// are resolved according to `disambiguation`. Returns [`None`] if the local time is
+    /// rejected, or if the data block has no local time type records.
+    pub fn local_to_utc(
+        &self,
+        local_time: Seconds,
+        disambiguation: Disambiguation,
+    ) -> Option<Seconds> {
+        let day = Seconds(SECONDS_IN_DAY);
+        // The offsets on either side of any transition near `local_time`. This assumes that
+        // transitions are at least two days apart.
+        let offset_before = self.local_time_type(local_time - day)?.utoff;
+        let offset_after = self.local_time_type(local_time + day)?.utoff;
+
+        let mut candidates = Vec::with_capacity(2);
+        for offset in [offset_before, offset_after] {
+            let candidate = local_time - offset;
+            if !candidates.contains(&candidate)
+                && self.local_time_type(candidate).map(|t| t.utoff) == Some(offset)
+            {
+                candidates.push(candidate);
+            }
+        }
+        candidates.sort();
+
+        match (candidates.as_slice(), disambiguation) {
+            ([only], _) => Some(*only),
+            // The local time is repeated.
+            ([earlier, _], Disambiguation::Compatible | Disambiguation::Earlier) => Some(*earlier),
+            ([_, later], Disambiguation::Later) => Some(*later),
+            // The local time is skipped. Shift it by the length of the gap.
+            ([], Disambiguation::Earlier) => Some(local_time - offset_after),
+            ([], Disambiguation::Compatible | Disambiguation::Later) => {
+                Some(local_time - offset_before)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the transition at `time`, with the local time types on either side.
+    fn transition(&self, time: Seconds) -> Option<Transition> {
+        Some(Transition {
+            time,
+            before: self.local_time_type(time - Seconds(1))?,
+            after: self.local_time_type(time)?,
+        })
+    }
+
+    /// Returns the candidate transition times computed with the footer's POSIX time-zone
+    /// string in the years around `time`.
+    fn footer_transition_times(&self, time: Seconds) -> impl Iterator<Item = Seconds> + '_ {
+        let year = year_from_days(time.0.div_euclid(SECONDS_IN_DAY));
+        (year - 1..=year + 1)
+            .filter_map(move |year| self.footer.as_ref()?.dst_transitions_in_year(year))
+            .flat_map(|(start, end)| [start, end])
+            .filter(move |&candidate| {
+                // Rules can produce transitions that don't change anything, e.g. for
+                // permanent DST.
+                self.footer.as_ref().map_or(false, |footer| {
+                    footer.variant_at(candidate - Seconds(1)) != footer.variant_at(candidate)
+                })
+            })
+    }
+}
+
+/// A transition between two local time types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    /// The instant of the transition in seconds since the Unix epoch.
+    pub time: Seconds,
+    /// The local time type in effect before the transition.
+    pub before: LocalTimeType,
+    /// The local time type in effect from the transition onwards.
+    pub after: LocalTimeType,
+}
+
+/// A policy for converting local times that are skipped or repeated by a transition,
+/// modeled after the `disambiguation` option of
+/// [Temporal](https://tc39.es/proposal-temporal/docs/timezone.html#getInstantFor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// Use the earlier instant for repeated local times, and the later instant for skipped
+    /// local times. This matches the behavior of most other libraries.
+    Compatible,
+    /// Use the earlier instant.
+    Earlier,
+    /// Use the later instant.
+    Later,
+    /// Reject local times that are skipped or repeated.
+    Reject,
+}
+
+impl Default for Disambiguation {
+    fn default() -> Self {
+        Disambiguation::Compatible
+    }
+}
+
+/// A local time type, as determined by a local time type record and its designation,
+/// or by a POSIX time-zone string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    /// The number of seconds to be added to UT in order to determine local time.
+    pub utoff: Seconds,
+    /// Whether local time is considered Daylight Saving Time (DST).
+    pub is_dst: bool,
+    /// The time-zone designation, such as "PST" or "PDT".
+    pub designation: String,
 }
 
 /// A record specifying a local time type.
@@ -297,3 +488,19 @@ pub struct DataBlock {
     /// A series of [`UtLocalIndicator`] objects.
     pub ut_local_indicators: Vec<UtLocalIndicator>,
 }
+
+impl DataBlock {
+    /// Returns the local time type described by the local time type record at `index`.
+    pub fn local_time_type(&self, index: usize) -> Option<LocalTimeType> {
+        let record = self.local_time_type_records.get(index)?;
+        Some(LocalTimeType {
+            utoff: record.utoff,
+            is_dst: record.is_dst,
+            designation: self
+                .time_zone_designations
+                .get(index)
+                .cloned()
+                .unwrap_or_default(),
+        })
+    }
+}
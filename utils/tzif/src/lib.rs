@@ -2,11 +2,13 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! A parser for [Time Zone Information Format (`TZif`)](https://tools.ietf.org/id/draft-murchison-tzdist-tzif-00.html) files.
+//! A parser and writer for [Time Zone Information Format (`TZif`)](https://tools.ietf.org/id/draft-murchison-tzdist-tzif-00.html) files.
 //!
 //! Also includes a parser for [POSIX time-zone strings](https://www.gnu.org/software/libc/manual/html_node/TZ-Variable.html),
 //! which is used by the TZif parser, but also available separately.
 //!
+//! Both formats can also be written back out, producing the same bytes as `zic`.
+//!
 //! Resources to generate `TZif` files are provided by the [IANA database](https://www.iana.org/time-zones).
 //! `TZif` files are also included in some operating systems.
 //!
@@ -21,6 +23,18 @@
 //! ```rust
 //! let data = tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
 //! ```
+//!
+//! ### Write TZif Files
+//! ```no_run
+//! let data = tzif::parse_tzif_file("path_to_file").unwrap();
+//! tzif::write_tzif_file("path_to_copy", &data).unwrap();
+//! ```
+//!
+//! ### Write POSIX time-zone strings
+//! ```rust
+//! let data = tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
+//! assert_eq!(data.to_string(), "WGT3WGST,M3.5.0/-2,M10.5.0/-1");
+//! ```
 
 #![warn(missing_docs)]
 
@@ -28,6 +42,7 @@ use combine::{stream, Parser};
 use data::{posix::PosixTzString, tzif::TzifData};
 use error::Error;
 use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 /// The parsed data representations.
@@ -39,6 +54,9 @@ pub mod parse;
 /// Error types an implementations.
 pub mod error;
 
+/// The writer implementations.
+pub mod write;
+
 /// Parses a `TZif` file at the provided `path`.
 pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     let file = File::open(path)?;
@@ -53,3 +71,23 @@ pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
 }
+
+/// Parses `TZif` data from the given bytes.
+pub fn parse_tzif(bytes: &[u8]) -> Result<TzifData, Error> {
+    Ok(parse::tzif::tzif().parse(bytes)?.0)
+}
+
+/// Writes `TZif` data to a file at the provided `path`.
+pub fn write_tzif_file<P: AsRef<Path>>(path: P, data: &TzifData) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write::tzif::tzif(&mut writer, data)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes `TZif` data to bytes.
+pub fn write_tzif(data: &TzifData) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    write::tzif::tzif(&mut bytes, data)?;
+    Ok(bytes)
+}
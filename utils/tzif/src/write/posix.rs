@@ -0,0 +1,115 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::posix::{
+    DstTransitionInfo, PosixTzString, TransitionDate, TransitionDay, ZoneVariantInfo,
+};
+use crate::data::time::{Hours, Seconds};
+use std::fmt::{self, Write};
+
+/// Writes the name of a time-zone variant. Names that are not purely alphabetic are
+/// enclosed in angled brackets, e.g. `<+03>`.
+fn zone_variant_name<W: Write>(writer: &mut W, name: &str) -> fmt::Result {
+    if name.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        writer.write_str(name)
+    } else {
+        write!(writer, "<{name}>")
+    }
+}
+
+/// Writes a time as `[-]hh[:mm[:ss]]`, omitting trailing zero minutes and seconds.
+fn time<W: Write>(writer: &mut W, Seconds(seconds): Seconds) -> fmt::Result {
+    if seconds < 0 {
+        writer.write_char('-')?;
+    }
+    let seconds = seconds.abs();
+    write!(writer, "{}", seconds / 3600)?;
+    if seconds % 3600 != 0 {
+        write!(writer, ":{:02}", seconds / 60 % 60)?;
+    }
+    if seconds % 60 != 0 {
+        write!(writer, ":{:02}", seconds % 60)?;
+    }
+    Ok(())
+}
+
+/// Writes the name and offset of a time-zone variant. The offset is omitted if it is
+/// equal to `default_offset`.
+fn zone_variant_info<W: Write>(
+    writer: &mut W,
+    info: &ZoneVariantInfo,
+    default_offset: Option<Seconds>,
+) -> fmt::Result {
+    zone_variant_name(writer, &info.name)?;
+    if Some(info.offset) != default_offset {
+        time(writer, info.offset)?;
+    }
+    Ok(())
+}
+
+/// Writes a transition day as `Mm.w.d`, `Jn`, or `n`.
+fn transition_day<W: Write>(writer: &mut W, day: TransitionDay) -> fmt::Result {
+    match day {
+        TransitionDay::NoLeap(day) => write!(writer, "J{day}"),
+        TransitionDay::WithLeap(day) => write!(writer, "{day}"),
+        TransitionDay::Mwd(month, week, day) => write!(writer, "M{month}.{week}.{day}"),
+    }
+}
+
+/// Writes a transition date. The transition time is omitted if it is the default of 02:00.
+fn transition_date<W: Write>(writer: &mut W, date: &TransitionDate) -> fmt::Result {
+    transition_day(writer, date.day)?;
+    if date.time != Hours(2).as_seconds() {
+        writer.write_char('/')?;
+        time(writer, date.time)?;
+    }
+    Ok(())
+}
+
+/// Writes the DST variant info and transition dates.
+fn dst_transition_info<W: Write>(
+    writer: &mut W,
+    info: &DstTransitionInfo,
+    std_offset: Seconds,
+) -> fmt::Result {
+    zone_variant_info(
+        writer,
+        &info.variant_info,
+        Some(std_offset - Hours(1).as_seconds()),
+    )?;
+    writer.write_char(',')?;
+    transition_date(writer, &info.start_date)?;
+    writer.write_char(',')?;
+    transition_date(writer, &info.end_date)
+}
+
+/// Writes a POSIX time-zone string in the canonical form produced by `zic`.
+pub fn posix_tz_string<W: Write>(writer: &mut W, tz: &PosixTzString) -> fmt::Result {
+    zone_variant_info(writer, &tz.std_info, None)?;
+    if let Some(dst_info) = &tz.dst_info {
+        dst_transition_info(writer, dst_info, tz.std_info.offset)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn write_posix_tz_string() {
+        for tz in [
+            "PST8PDT,M3.2.0,M11.1.0",
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+            "<-06>6<-05>,M9.1.6/22,M4.1.6/22",
+            "<+00>0<+02>-2,M3.5.0/1,M10.5.0/3",
+            "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+            "<+0545>-5:45",
+            "WGT3WGST,M3.5.0/-2,M10.5.0/-1",
+            "EST5EDT,0/0,J365/25",
+            "XXX3:25:45YYY,59/-1:30,300/167",
+        ] {
+            let parsed = crate::parse_posix_tz_string(tz.as_bytes()).unwrap();
+            assert_eq!(parsed.to_string(), tz);
+        }
+    }
+}
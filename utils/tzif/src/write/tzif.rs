@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::time::Seconds;
+use crate::data::tzif::{DataBlock, StandardWallIndicator, TzifData, UtLocalIndicator};
+use std::io::{self, Write};
+
+/// Returns an [`io::Error`] for data that cannot be represented in a `TZif` file.
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Writes a four-byte unsigned count.
+fn count<W: Write>(writer: &mut W, count: usize) -> io::Result<()> {
+    let count = u32::try_from(count).map_err(|_| invalid_data("count does not fit in 32 bits"))?;
+    writer.write_all(&count.to_be_bytes())
+}
+
+/// Writes a four- or eight-byte time value, depending on the version `V` of the data block.
+fn time<const V: usize, W: Write>(writer: &mut W, Seconds(time): Seconds) -> io::Result<()> {
+    match V {
+        1 => {
+            let time = i32::try_from(time)
+                .map_err(|_| invalid_data("time does not fit in a version 1 data block"))?;
+            writer.write_all(&time.to_be_bytes())
+        }
+        _ => writer.write_all(&time.to_be_bytes()),
+    }
+}
+
+/// Builds the NUL-terminated time zone designations, placing each local time type's
+/// designation at the index of its record.
+fn time_zone_designations(block: &DataBlock) -> io::Result<Vec<u8>> {
+    let mut designations = Vec::new();
+    for (record, designation) in block
+        .local_time_type_records
+        .iter()
+        .zip(&block.time_zone_designations)
+    {
+        let end = record.idx + designation.len();
+        if designations.len() <= end {
+            designations.resize(end + 1, 0);
+        }
+        let existing = designations
+            .get_mut(record.idx..end)
+            .ok_or_else(|| invalid_data("designation index out of range"))?;
+        existing.copy_from_slice(designation.as_bytes());
+    }
+    Ok(designations)
+}
+
+/// Writes a header and data block of version `V` (1 for the version 1 data block, and
+/// 2 for the version 2+ data block). The counts in the header are computed from the
+/// data block.
+fn header_and_data_block<const V: usize, W: Write>(
+    writer: &mut W,
+    version: usize,
+    block: &DataBlock,
+) -> io::Result<()> {
+    let designations = time_zone_designations(block)?;
+
+    writer.write_all(b"TZif")?;
+    writer.write_all(&[match version {
+        1 => 0,
+        2 => b'2',
+        3 => b'3',
+        _ => return Err(invalid_data("unsupported TZif version")),
+    }])?;
+    writer.write_all(&[0; 15])?;
+    count(writer, block.ut_local_indicators.len())?;
+    count(writer, block.standard_wall_indicators.len())?;
+    count(writer, block.leap_second_records.len())?;
+    count(writer, block.transition_times.len())?;
+    count(writer, block.local_time_type_records.len())?;
+    count(writer, designations.len())?;
+
+    for &transition_time in &block.transition_times {
+        time::<V, _>(writer, transition_time)?;
+    }
+    for &transition_type in &block.transition_types {
+        let transition_type = u8::try_from(transition_type)
+            .map_err(|_| invalid_data("transition type does not fit in a byte"))?;
+        writer.write_all(&[transition_type])?;
+    }
+    for record in &block.local_time_type_records {
+        let utoff = i32::try_from(record.utoff.0)
+            .map_err(|_| invalid_data("utoff does not fit in 32 bits"))?;
+        let idx =
+            u8::try_from(record.idx).map_err(|_| invalid_data("idx does not fit in a byte"))?;
+        writer.write_all(&utoff.to_be_bytes())?;
+        writer.write_all(&[u8::from(record.is_dst), idx])?;
+    }
+    writer.write_all(&designations)?;
+    for record in &block.leap_second_records {
+        time::<V, _>(writer, record.occurrence)?;
+        writer.write_all(&record.correction.to_be_bytes())?;
+    }
+    for indicator in &block.standard_wall_indicators {
+        writer.write_all(&[u8::from(*indicator == StandardWallIndicator::Standard)])?;
+    }
+    for indicator in &block.ut_local_indicators {
+        writer.write_all(&[u8::from(*indicator == UtLocalIndicator::Ut)])?;
+    }
+    Ok(())
+}
+
+/// Writes `TZif` data as specified by <https://datatracker.ietf.org/doc/html/rfc8536>.
+///
+/// The counts in the headers are computed from the data blocks rather than taken from the
+/// parsed headers, so data blocks can be modified before writing. Version 2+ data is written
+/// if the version-2+ data block is present.
+pub fn tzif<W: Write>(writer: &mut W, data: &TzifData) -> io::Result<()> {
+    let version = data.version_number();
+    header_and_data_block::<1, _>(writer, version, &data.data_block1)?;
+    if let Some(block) = &data.data_block2 {
+        header_and_data_block::<2, _>(writer, version, block)?;
+        writer.write_all(b"\n")?;
+        if let Some(footer) = &data.footer {
+            writer.write_all(footer.to_string().as_bytes())?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
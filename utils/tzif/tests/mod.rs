@@ -27,3 +27,117 @@ fn parse_tzif_testdata() -> Result<(), tzif::error::Error> {
 fn parse_posix_tz_string() {
     assert!(tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").is_ok());
 }
+
+#[test]
+fn local_time_types() -> Result<(), tzif::error::Error> {
+    use tzif::data::time::Seconds;
+
+    let cases = [
+        ("America/Los_Angeles", 1640995200, -28800, false, "PST"),
+        ("America/Los_Angeles", 1647165599, -28800, false, "PST"),
+        ("America/Los_Angeles", 1647165600, -25200, true, "PDT"),
+        ("America/Los_Angeles", 1656633600, -25200, true, "PDT"),
+        // Beyond the last transition, resolved with the POSIX TZ string footer
+        ("America/Los_Angeles", 4103654400, -28800, false, "PST"),
+        ("America/Los_Angeles", 4118083200, -25200, true, "PDT"),
+        // Southern hemisphere DST spans the new year
+        ("Chile/EasterIsland", 4103654400, -18000, true, "-05"),
+        ("Chile/EasterIsland", 4119292800, -21600, false, "-06"),
+        ("Asia/Tokyo", -3000000000, 33539, false, "LMT"),
+        ("Asia/Tokyo", 0, 32400, false, "JST"),
+    ];
+    for (zone, time, utoff, is_dst, designation) in cases {
+        let data = tzif::parse_tzif_file(format!("testdata/{zone}"))?;
+        let local_time_type = data.local_time_type(Seconds(time)).unwrap();
+        assert_eq!(local_time_type.utoff, Seconds(utoff), "{zone} {time}");
+        assert_eq!(local_time_type.is_dst, is_dst, "{zone} {time}");
+        assert_eq!(local_time_type.designation, designation, "{zone} {time}");
+    }
+    Ok(())
+}
+
+#[test]
+fn transitions() -> Result<(), tzif::error::Error> {
+    use tzif::data::time::Seconds;
+
+    // (zone, time, previous transition, next transition)
+    let cases = [
+        ("America/Los_Angeles", 1640995200, 1636275600, 1647165600),
+        // Beyond the last transition, computed with the POSIX TZ string footer
+        ("America/Los_Angeles", 4103654400, 4097206800, 4108701600),
+        ("America/Los_Angeles", 4108701600, 4097206800, 4129261200),
+        ("Chile/EasterIsland", 4103654400, 4092350400, 4110490800),
+    ];
+    for (zone, time, previous, next) in cases {
+        let data = tzif::parse_tzif_file(format!("testdata/{zone}"))?;
+
+        let next_transition = data.next_transition(Seconds(time)).unwrap();
+        assert_eq!(next_transition.time, Seconds(next), "{zone} {time}");
+        assert_ne!(next_transition.before, next_transition.after);
+        assert_eq!(
+            data.previous_transition(Seconds(next)),
+            data.transition_at(Seconds(next - 1)),
+            "{zone} {time}"
+        );
+        assert_eq!(data.transition_at(Seconds(next)), Some(next_transition));
+
+        let previous_transition = data.previous_transition(Seconds(time)).unwrap();
+        assert_eq!(previous_transition.time, Seconds(previous), "{zone} {time}");
+        assert_eq!(
+            data.next_transition(Seconds(previous - 1)),
+            Some(previous_transition),
+            "{zone} {time}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn local_to_utc() -> Result<(), tzif::error::Error> {
+    use tzif::data::time::Seconds;
+    use tzif::data::tzif::Disambiguation::*;
+
+    let data = tzif::parse_tzif_file("testdata/America/Los_Angeles")?;
+    // 2022-01-01T00:00, 2022-03-13T02:30 (skipped), 2022-11-06T01:30 (repeated)
+    let (regular, skipped, repeated) = (1640995200, 1647138600, 1667698200);
+    let cases = [
+        (regular, Compatible, Some(regular + 28800)),
+        (regular, Earlier, Some(regular + 28800)),
+        (regular, Later, Some(regular + 28800)),
+        (regular, Reject, Some(regular + 28800)),
+        (skipped, Compatible, Some(skipped + 28800)),
+        (skipped, Earlier, Some(skipped + 25200)),
+        (skipped, Later, Some(skipped + 28800)),
+        (skipped, Reject, None),
+        (repeated, Compatible, Some(repeated + 25200)),
+        (repeated, Earlier, Some(repeated + 25200)),
+        (repeated, Later, Some(repeated + 28800)),
+        (repeated, Reject, None),
+    ];
+    for (local, disambiguation, utc) in cases {
+        assert_eq!(
+            data.local_to_utc(Seconds(local), disambiguation),
+            utc.map(Seconds),
+            "{local} {disambiguation:?}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn write_tzif_testdata() -> Result<(), tzif::error::Error> {
+    for entry in WalkDir::new("testdata").follow_links(true) {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            let bytes = std::fs::read(entry.path())?;
+            let parsed = tzif::parse_tzif(&bytes)?;
+            assert_eq!(
+                tzif::write_tzif(&parsed)?,
+                bytes,
+                "{:?}",
+                entry.path().to_str()
+            );
+        }
+    }
+    Ok(())
+}